@@ -24,6 +24,7 @@
 #include "display.h"
 #include "embed/extmod/trezorobj.h"
 
+#define HOST_KEY_IFACE (252)
 #define USB_DATA_IFACE (253)
 #define BUTTON_IFACE (254)
 #define TOUCH_IFACE (255)
@@ -47,6 +48,7 @@ extern uint32_t last_touch_sample_time;
 ///                   - for button event (T1), tuple of:
 ///                     (event type, button number)
 ///                   - for USB read event, received bytes
+///                   - for host key event (emulator), the key code
 ///
 ///     If timeout occurs, False is returned, True otherwise.
 ///     """
@@ -83,6 +85,16 @@ STATIC mp_obj_t mod_trezorio_poll(mp_obj_t ifaces, mp_obj_t list_ref,
 
       if (false) {
       }
+#if defined TREZOR_EMULATOR
+      else if (iface == HOST_KEY_IFACE) {
+        const uint32_t key = emulator_host_key_read();
+        if (key) {
+          ret->items[0] = MP_OBJ_NEW_SMALL_INT(i);
+          ret->items[1] = MP_OBJ_NEW_SMALL_INT(key);
+          return mp_const_true;
+        }
+      }
+#endif
 #if defined USE_TOUCH
       else if (iface == TOUCH_IFACE) {
 
@@ -80,6 +80,8 @@ uint32_t last_touch_sample_time = 0;
 
 /// USB_CHECK: int # interface id for check of USB data connection
 
+/// HOST_KEY: int  # interface id of host keyboard events (emulator only)
+
 /// WireInterface = Union[HID, WebUSB]
 
 STATIC const mp_rom_map_elem_t mp_module_trezorio_globals_table[] = {
@@ -114,6 +116,10 @@ STATIC const mp_rom_map_elem_t mp_module_trezorio_globals_table[] = {
     {MP_ROM_QSTR(MP_QSTR_BUTTON_RIGHT), MP_ROM_INT(BTN_RIGHT)},
 #endif
 
+#ifdef TREZOR_EMULATOR
+    {MP_ROM_QSTR(MP_QSTR_HOST_KEY), MP_ROM_INT(HOST_KEY_IFACE)},
+#endif
+
     {MP_ROM_QSTR(MP_QSTR_FlashOTP), MP_ROM_PTR(&mod_trezorio_FlashOTP_type)},
 
     {MP_ROM_QSTR(MP_QSTR_USB), MP_ROM_PTR(&mod_trezorio_USB_type)},
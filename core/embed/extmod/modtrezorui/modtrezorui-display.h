@@ -124,22 +124,44 @@ STATIC MP_DEFINE_CONST_FUN_OBJ_VAR_BETWEEN(mod_trezorui_Display_backlight_obj,
                                            1, 2,
                                            mod_trezorui_Display_backlight);
 
-/// def save(self, prefix: str) -> None:
+/// def save(self, prefix: str, trace: str | None = None) -> str | None:
 ///     """
-///     Saves current display contents to PNG file with given prefix.
+///     Saves current display contents to PNG file with given prefix, unless
+///     they are the same as in the previous file. If `trace` is given, it is
+///     written next to the PNG file, with the `.json` extension.
+///     Returns the name of the PNG file, None if nothing was saved.
 ///     """
-STATIC mp_obj_t mod_trezorui_Display_save(mp_obj_t self, mp_obj_t prefix) {
+STATIC mp_obj_t mod_trezorui_Display_save(size_t n_args, const mp_obj_t *args) {
 #ifdef TREZOR_EMULATOR
   mp_buffer_info_t pfx = {0};
-  mp_get_buffer_raise(prefix, &pfx, MP_BUFFER_READ);
-  if (pfx.len > 0) {
-    display_save(pfx.buf);
+  mp_get_buffer_raise(args[1], &pfx, MP_BUFFER_READ);
+  if (pfx.len == 0) {
+    return mp_const_none;
   }
-#endif
+  const char *filename = display_save(pfx.buf);
+  if (filename == NULL) {
+    return mp_const_none;
+  }
+  if (n_args > 2 && args[2] != mp_const_none) {
+    mp_buffer_info_t trace = {0};
+    mp_get_buffer_raise(args[2], &trace, MP_BUFFER_READ);
+    // replace the ".png" extension
+    char trace_filename[256];
+    snprintf(trace_filename, sizeof(trace_filename), "%.*s.json",
+             (int)(strlen(filename) - 4), filename);
+    FILE *f = fopen(trace_filename, "w");
+    if (f != NULL) {
+      fwrite(trace.buf, 1, trace.len, f);
+      fclose(f);
+    }
+  }
+  return mp_obj_new_str(filename, strlen(filename));
+#else
   return mp_const_none;
+#endif
 }
-STATIC MP_DEFINE_CONST_FUN_OBJ_2(mod_trezorui_Display_save_obj,
-                                 mod_trezorui_Display_save);
+STATIC MP_DEFINE_CONST_FUN_OBJ_VAR_BETWEEN(mod_trezorui_Display_save_obj, 2, 3,
+                                           mod_trezorui_Display_save);
 
 /// def clear_save(self) -> None:
 ///     """
@@ -154,6 +176,21 @@ STATIC mp_obj_t mod_trezorui_Display_clear_save(mp_obj_t self) {
 STATIC MP_DEFINE_CONST_FUN_OBJ_1(mod_trezorui_Display_clear_save_obj,
                                  mod_trezorui_Display_clear_save);
 
+/// def flushed_pixels(self) -> int:
+///     """
+///     Returns the number of pixels flushed to the screen so far. Emulator
+///     only, returns 0 elsewhere.
+///     """
+STATIC mp_obj_t mod_trezorui_Display_flushed_pixels(mp_obj_t self) {
+#if defined TREZOR_EMULATOR && NEW_RENDERING
+  return mp_obj_new_int_from_ull(display_flushed_pixels());
+#else
+  return MP_OBJ_NEW_SMALL_INT(0);
+#endif
+}
+STATIC MP_DEFINE_CONST_FUN_OBJ_1(mod_trezorui_Display_flushed_pixels_obj,
+                                 mod_trezorui_Display_flushed_pixels);
+
 STATIC const mp_rom_map_elem_t mod_trezorui_Display_locals_dict_table[] = {
     {MP_ROM_QSTR(MP_QSTR_refresh),
      MP_ROM_PTR(&mod_trezorui_Display_refresh_obj)},
@@ -165,6 +202,8 @@ STATIC const mp_rom_map_elem_t mod_trezorui_Display_locals_dict_table[] = {
     {MP_ROM_QSTR(MP_QSTR_save), MP_ROM_PTR(&mod_trezorui_Display_save_obj)},
     {MP_ROM_QSTR(MP_QSTR_clear_save),
      MP_ROM_PTR(&mod_trezorui_Display_clear_save_obj)},
+    {MP_ROM_QSTR(MP_QSTR_flushed_pixels),
+     MP_ROM_PTR(&mod_trezorui_Display_flushed_pixels_obj)},
     {MP_ROM_QSTR(MP_QSTR_WIDTH), MP_ROM_INT(DISPLAY_RESX)},
     {MP_ROM_QSTR(MP_QSTR_HEIGHT), MP_ROM_INT(DISPLAY_RESY)},
     {MP_ROM_QSTR(MP_QSTR_FONT_NORMAL), MP_ROM_INT(FONT_NORMAL)},
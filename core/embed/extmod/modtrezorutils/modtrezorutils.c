@@ -418,6 +418,8 @@ STATIC mp_obj_tuple_t mod_trezorutils_version_obj = {
 /// """Whether the hardware supports backlight brightness control."""
 /// USE_HAPTIC: bool
 /// """Whether the hardware supports haptic feedback."""
+/// USE_RGB_LED: bool
+/// """Whether the hardware has the RGB LED."""
 /// USE_OPTIGA: bool
 /// """Whether the hardware supports Optiga secure element."""
 /// MODEL: str
@@ -481,6 +483,11 @@ STATIC const mp_rom_map_elem_t mp_module_trezorutils_globals_table[] = {
 #else
     {MP_ROM_QSTR(MP_QSTR_USE_HAPTIC), mp_const_false},
 #endif
+#ifdef USE_RGB_LED
+    {MP_ROM_QSTR(MP_QSTR_USE_RGB_LED), mp_const_true},
+#else
+    {MP_ROM_QSTR(MP_QSTR_USE_RGB_LED), mp_const_false},
+#endif
 #ifdef USE_OPTIGA
     {MP_ROM_QSTR(MP_QSTR_USE_OPTIGA), mp_const_true},
 #else
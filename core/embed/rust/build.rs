@@ -357,6 +357,7 @@ fn generate_trezorhal_bindings() {
         .allowlist_var("DISPLAY_RESY")
         .allowlist_type("display_fb_info_t")
         .allowlist_function("display_get_frame_buffer")
+        .allowlist_function("display_set_refresh_region")
         .allowlist_function("display_fill")
         .allowlist_function("display_copy_rgb565")
         // gfx_bitblt
@@ -383,6 +384,7 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("font_max_height")
         .allowlist_function("font_baseline")
         .allowlist_function("font_get_glyph")
+        .allowlist_function("font_nonprintable_glyph")
         .allowlist_function("font_text_width")
         // uzlib
         .allowlist_function("uzlib_uncompress_init")
@@ -397,7 +399,7 @@ fn generate_trezorhal_bindings() {
         .allowlist_var("SLIP39_WORDLIST")
         .allowlist_var("SLIP39_WORD_COUNT")
         // random
-        .allowlist_function("random_uniform")
+        .allowlist_function("random32")
         // rgb led
         .allowlist_function("rgb_led_set_color")
         // time
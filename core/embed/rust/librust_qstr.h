@@ -50,6 +50,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_address_details__account_info;
   MP_QSTR_address_details__derivation_path;
   MP_QSTR_address_details__derivation_path_colon;
+  MP_QSTR_address_details__script_type;
   MP_QSTR_address_details__title_receive_address;
   MP_QSTR_address_details__title_receiving_to;
   MP_QSTR_address_label;
@@ -65,11 +66,13 @@ static void _librust_qstrs(void) {
   MP_QSTR_app_name;
   MP_QSTR_area_bytesize;
   MP_QSTR_attach_timer_fn;
+  MP_QSTR_attempts_remaining;
   MP_QSTR_authenticate__confirm_template;
   MP_QSTR_authenticate__header;
   MP_QSTR_auto_lock__change_template;
   MP_QSTR_auto_lock__title;
   MP_QSTR_auto_lock__turned_on;
+  MP_QSTR_autofill;
   MP_QSTR_backup__can_back_up_anytime;
   MP_QSTR_backup__create_backup_to_prevent_loss;
   MP_QSTR_backup__info_multi_share_backup;
@@ -84,6 +87,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_backup__title_create_wallet_backup;
   MP_QSTR_backup__title_skip;
   MP_QSTR_backup__want_to_skip;
+  MP_QSTR_bip39;
   MP_QSTR_bitcoin__commitment_data;
   MP_QSTR_bitcoin__confirm_locktime;
   MP_QSTR_bitcoin__create_proof_of_ownership;
@@ -182,6 +186,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_confirm_firmware_update;
   MP_QSTR_confirm_homescreen;
   MP_QSTR_confirm_joint_total;
+  MP_QSTR_confirm_list;
   MP_QSTR_confirm_modify_fee;
   MP_QSTR_confirm_modify_output;
   MP_QSTR_confirm_more;
@@ -205,12 +210,14 @@ static void _librust_qstrs(void) {
   MP_QSTR_data_len;
   MP_QSTR_debug__loading_seed;
   MP_QSTR_debug__loading_seed_not_recommended;
+  MP_QSTR_debug_overlay;
   MP_QSTR_decode;
   MP_QSTR_deinit;
   MP_QSTR_description;
   MP_QSTR_details_title;
   MP_QSTR_device_name__change_template;
   MP_QSTR_device_name__title;
+  MP_QSTR_direct_entry;
   MP_QSTR_disable_animation;
   MP_QSTR_encode;
   MP_QSTR_encoded_length;
@@ -218,6 +225,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_entropy__title;
   MP_QSTR_entropy__title_confirm;
   MP_QSTR_erase;
+  MP_QSTR_eta;
   MP_QSTR_experimental_mode__enable;
   MP_QSTR_experimental_mode__only_for_dev;
   MP_QSTR_experimental_mode__title;
@@ -228,6 +236,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_fee_rate_amount;
   MP_QSTR_fee_title;
   MP_QSTR_fee_value;
+  MP_QSTR_filter_last_word;
   MP_QSTR_fingerprint;
   MP_QSTR_firmware_update__title;
   MP_QSTR_firmware_update__title_fingerprint;
@@ -246,6 +255,9 @@ static void _librust_qstrs(void) {
   MP_QSTR_haptic_feedback__enable;
   MP_QSTR_haptic_feedback__subtitle;
   MP_QSTR_haptic_feedback__title;
+  MP_QSTR_high_contrast__disable;
+  MP_QSTR_high_contrast__enable;
+  MP_QSTR_high_contrast__title;
   MP_QSTR_hold;
   MP_QSTR_hold_danger;
   MP_QSTR_homescreen__click_to_connect;
@@ -260,6 +272,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_homescreen__title_seedless;
   MP_QSTR_homescreen__title_set;
   MP_QSTR_horizontal;
+  MP_QSTR_host_key_event;
   MP_QSTR_icon_name;
   MP_QSTR_image;
   MP_QSTR_indeterminate;
@@ -293,12 +306,26 @@ static void _librust_qstrs(void) {
   MP_QSTR_joint__title;
   MP_QSTR_joint__to_the_total_amount;
   MP_QSTR_joint__you_are_contributing;
+  MP_QSTR_keyboard_text;
+  MP_QSTR_keyboard_timings__erase_hold_template;
+  MP_QSTR_keyboard_timings__key_debounce_template;
+  MP_QSTR_keyboard_timings__last_char_reveal_template;
+  MP_QSTR_keyboard_timings__multi_tap_template;
+  MP_QSTR_keyboard_timings__title;
+  MP_QSTR_keyboard_type;
   MP_QSTR_label;
   MP_QSTR_language;
   MP_QSTR_language__change_to_template;
   MP_QSTR_language__changed;
   MP_QSTR_language__progress;
   MP_QSTR_language__title;
+  MP_QSTR_large_text__disable;
+  MP_QSTR_large_text__enable;
+  MP_QSTR_large_text__title;
+  MP_QSTR_led_set_enabled;
+  MP_QSTR_led_signals__disable;
+  MP_QSTR_led_signals__enable;
+  MP_QSTR_led_signals__title;
   MP_QSTR_lines;
   MP_QSTR_load_from_flash;
   MP_QSTR_lockscreen__tap_to_connect;
@@ -306,13 +333,16 @@ static void _librust_qstrs(void) {
   MP_QSTR_lockscreen__title_locked;
   MP_QSTR_lockscreen__title_not_connected;
   MP_QSTR_max_count;
+  MP_QSTR_max_decimals;
   MP_QSTR_max_feerate;
+  MP_QSTR_max_integer_digits;
   MP_QSTR_max_len;
   MP_QSTR_max_rounds;
   MP_QSTR_min_count;
   MP_QSTR_misc__decrypt_value;
   MP_QSTR_misc__encrypt_value;
   MP_QSTR_misc__title_suite_labeling;
+  MP_QSTR_mnemonic_word_event;
   MP_QSTR_modify_amount__decrease_amount;
   MP_QSTR_modify_amount__increase_amount;
   MP_QSTR_modify_amount__new_amount;
@@ -326,18 +356,24 @@ static void _librust_qstrs(void) {
   MP_QSTR_modify_fee__transaction_fee;
   MP_QSTR_multiple_pages_texts;
   MP_QSTR_notification;
+  MP_QSTR_notification_event;
   MP_QSTR_notification_level;
+  MP_QSTR_numbered;
   MP_QSTR_page_count;
   MP_QSTR_pages;
   MP_QSTR_paint;
   MP_QSTR_passphrase__access_hidden_wallet;
   MP_QSTR_passphrase__always_on_device;
+  MP_QSTR_passphrase__double_space;
   MP_QSTR_passphrase__from_host_not_shown;
   MP_QSTR_passphrase__hidden_wallet;
   MP_QSTR_passphrase__hide;
   MP_QSTR_passphrase__next_screen_will_show_passphrase;
   MP_QSTR_passphrase__please_enter;
   MP_QSTR_passphrase__revoke_on_device;
+  MP_QSTR_passphrase__space_at_end;
+  MP_QSTR_passphrase__space_at_start;
+  MP_QSTR_passphrase__tap_to_trim;
   MP_QSTR_passphrase__title_confirm;
   MP_QSTR_passphrase__title_enter;
   MP_QSTR_passphrase__title_hide;
@@ -347,6 +383,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_passphrase__turn_off;
   MP_QSTR_passphrase__turn_on;
   MP_QSTR_path;
+  MP_QSTR_phase;
   MP_QSTR_pin__cancel_description;
   MP_QSTR_pin__cancel_info;
   MP_QSTR_pin__cancel_setup;
@@ -375,6 +412,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_pin__turn_off;
   MP_QSTR_pin__turn_on;
   MP_QSTR_pin__wrong_pin;
+  MP_QSTR_pin__x_tries_left_template_plural;
   MP_QSTR_plurals__contains_x_keys;
   MP_QSTR_plurals__lock_after_x_hours;
   MP_QSTR_plurals__lock_after_x_milliseconds;
@@ -384,6 +422,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_plurals__transaction_of_x_operations;
   MP_QSTR_plurals__x_groups_needed;
   MP_QSTR_plurals__x_shares_needed;
+  MP_QSTR_plurals__x_tries_left;
   MP_QSTR_prefill_word;
   MP_QSTR_progress__authenticity_check;
   MP_QSTR_progress__done;
@@ -396,6 +435,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_progress__syncing;
   MP_QSTR_progress__x_seconds_left_template;
   MP_QSTR_progress_event;
+  MP_QSTR_progress_info_event;
   MP_QSTR_prompt;
   MP_QSTR_prompt_screen;
   MP_QSTR_prompt_title;
@@ -435,6 +475,8 @@ static void _librust_qstrs(void) {
   MP_QSTR_recovery__share_does_not_match;
   MP_QSTR_recovery__share_from_another_multi_share_backup;
   MP_QSTR_recovery__share_num_template;
+  MP_QSTR_recovery__share_num_word_x_of_y_template;
+  MP_QSTR_recovery__share_word_x_of_y_template;
   MP_QSTR_recovery__title;
   MP_QSTR_recovery__title_cancel_dry_run;
   MP_QSTR_recovery__title_cancel_recovery;
@@ -442,6 +484,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_recovery__title_recover;
   MP_QSTR_recovery__title_remaining_shares;
   MP_QSTR_recovery__title_unlock_repeated_backup;
+  MP_QSTR_recovery__type_word;
   MP_QSTR_recovery__type_word_x_of_y_template;
   MP_QSTR_recovery__unlock_repeated_backup;
   MP_QSTR_recovery__unlock_repeated_backup_verb;
@@ -455,8 +498,10 @@ static void _librust_qstrs(void) {
   MP_QSTR_recovery__x_of_y_entered_template;
   MP_QSTR_recovery__you_have_entered;
   MP_QSTR_recovery_type;
+  MP_QSTR_request_amount;
   MP_QSTR_request_bip39;
   MP_QSTR_request_complete_repaint;
+  MP_QSTR_request_datetime;
   MP_QSTR_request_number;
   MP_QSTR_request_passphrase;
   MP_QSTR_request_pin;
@@ -560,6 +605,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_safety_checks__enforce_strict;
   MP_QSTR_safety_checks__title;
   MP_QSTR_safety_checks__title_safety_override;
+  MP_QSTR_script_type;
   MP_QSTR_sd_card__all_data_will_be_lost;
   MP_QSTR_sd_card__card_required;
   MP_QSTR_sd_card__disable;
@@ -582,6 +628,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_sd_card__use_different_card;
   MP_QSTR_sd_card__wanna_format;
   MP_QSTR_sd_card__wrong_sd_card;
+  MP_QSTR_seed_random;
   MP_QSTR_select_word;
   MP_QSTR_select_word_count;
   MP_QSTR_send__address_path;
@@ -607,6 +654,9 @@ static void _librust_qstrs(void) {
   MP_QSTR_send__transaction_signed;
   MP_QSTR_send__you_are_contributing;
   MP_QSTR_set_brightness;
+  MP_QSTR_set_high_contrast;
+  MP_QSTR_set_keyboard_timings;
+  MP_QSTR_set_large_text;
   MP_QSTR_share_words;
   MP_QSTR_share_words__words_in_order;
   MP_QSTR_share_words__wrote_down_all;
@@ -681,6 +731,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_tutorial__welcome_safe5;
   MP_QSTR_type_for_name;
   MP_QSTR_type_for_wire;
+  MP_QSTR_unit;
   MP_QSTR_usb_event;
   MP_QSTR_user_fee_change;
   MP_QSTR_value;
@@ -692,6 +743,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_wipe__info;
   MP_QSTR_wipe__title;
   MP_QSTR_wipe__want_to_wipe;
+  MP_QSTR_wipe_code;
   MP_QSTR_wipe_code__change;
   MP_QSTR_wipe_code__changed;
   MP_QSTR_wipe_code__diff_from_pin;
@@ -709,6 +761,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_wipe_code__turn_off;
   MP_QSTR_wipe_code__turn_on;
   MP_QSTR_wipe_code__wipe_code_mismatch;
+  MP_QSTR_word_count;
   MP_QSTR_word_count__title;
   MP_QSTR_words;
   MP_QSTR_words__account;
@@ -1151,4 +1204,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_u2f__title_get;
   MP_QSTR_u2f__title_set;
 #endif
+#if PYOPT == 0
+  MP_QSTR_debug_click;
+#endif
 }
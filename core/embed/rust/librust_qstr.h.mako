@@ -34,6 +34,11 @@ def find_unique_patterns_in_dir(directory: Union[str, Path], pattern: str) -> Se
     output_lines = result.stdout.strip().split("\n")
     return set([line.split(":", 1)[1] for line in output_lines if line])
 
+# Only used by the `ui_debug` feature, which debug builds enable.
+DEBUG_QSTRINGS = {
+    "MP_QSTR_debug_click",
+}
+
 pattern = r"\bMP_QSTR_\w*"
 qstrings = find_unique_patterns_in_dir(RUST_SRC, pattern)
 
@@ -42,11 +47,14 @@ for prefix in ALTCOIN_PREFIXES:
     mp_prefix = f"MP_QSTR_{prefix}__"
     qstrings_universal |= {qstr for qstr in qstrings if qstr.startswith(mp_prefix)}
 
-qstrings_btconly = qstrings - qstrings_universal
+qstrings_debug = qstrings & DEBUG_QSTRINGS
+
+qstrings_btconly = qstrings - qstrings_universal - qstrings_debug
 
 # sort result alphabetically
 qstrings_btconly_sorted = sorted(qstrings_btconly)
 qstrings_universal_sorted = sorted(qstrings_universal)
+qstrings_debug_sorted = sorted(qstrings_debug)
 %>\
 % for qstr in qstrings_btconly_sorted:
   ${qstr};
@@ -56,4 +64,9 @@ qstrings_universal_sorted = sorted(qstrings_universal)
   ${qstr};
 % endfor
 #endif
+#if PYOPT == 0
+% for qstr in qstrings_debug_sorted:
+  ${qstr};
+% endfor
+#endif
 }
@@ -102,17 +102,19 @@ macro_rules! obj_fn_kw {
     }};
 }
 
-/// Construct fixed static const `Map` from `key` => `val` pairs.
+/// Construct fixed static const `Map` from `key` => `val` pairs. The keys are
+/// paths, so that a pair can be preceded by a `#[cfg(...)]` attribute.
 macro_rules! obj_map {
-    ($($key:expr => $val:expr),*) => ({
+    ($($(#[$attr:meta])* $key:path => $val:expr),*) => ({
         Map::from_fixed_static(&[
             $(
+                $(#[$attr])*
                 Map::at($key, $val),
             )*
         ])
     });
-    ($($key:expr => $val:expr),* ,) => ({
-        obj_map!($($key => $val),*)
+    ($($(#[$attr:meta])* $key:path => $val:expr),* ,) => ({
+        obj_map!($($(#[$attr])* $key => $val),*)
     });
 }
 
@@ -8,6 +8,8 @@ use crate::micropython::{buffer::StrBuffer, obj::Obj};
 
 #[cfg(feature = "translations")]
 use crate::translations::TR;
+#[cfg(feature = "ui")]
+use crate::ui::display::Font;
 
 /// Trait for internal representation of strings. This is a legacy crutch before
 /// we fully transition to `TString`. For now, it allows some manner of
@@ -25,8 +27,10 @@ impl<T> StringType for T where T: AsRef<str> + From<&'static str> + Into<TString
 /// Unified-length String type, long enough for most simple use-cases.
 pub type ShortString = String<50>;
 
+const HEX_LOWER: [u8; 16] = *b"0123456789abcdef";
+const HEX_UPPER: [u8; 16] = *b"0123456789ABCDEF";
+
 pub fn hexlify(data: &[u8], buffer: &mut [u8]) {
-    const HEX_LOWER: [u8; 16] = *b"0123456789abcdef";
     let mut i: usize = 0;
     for b in data.iter().take(buffer.len() / 2) {
         let hi: usize = ((b & 0xf0) >> 4).into();
@@ -37,6 +41,40 @@ pub fn hexlify(data: &[u8], buffer: &mut [u8]) {
     }
 }
 
+/// Append the hex representation of `data` to `out`. Only the bytes fitting
+/// into `out` as a whole are appended. Returns `false` if anything was left
+/// out.
+pub fn hexlify_into<const N: usize>(data: &[u8], out: &mut String<N>, uppercase: bool) -> bool {
+    let digits = if uppercase { &HEX_UPPER } else { &HEX_LOWER };
+    for b in data {
+        if out.capacity() - out.len() < 2 {
+            return false;
+        }
+        // Cannot fail, the capacity was checked above.
+        let _ = out.push(digits[(b >> 4) as usize] as char);
+        let _ = out.push(digits[(b & 0x0f) as usize] as char);
+    }
+    true
+}
+
+/// Append a short hex preview of `data` to `out`, made of the first and the
+/// last `n` bytes with an ellipsis between them, e.g. "ab12cd...ef90". Data of
+/// up to `2 * n` bytes is appended whole. Returns `false` if the preview did
+/// not fit into `out` and was truncated.
+pub fn hex_preview_into<const N: usize>(
+    data: &[u8],
+    n: usize,
+    out: &mut String<N>,
+    uppercase: bool,
+) -> bool {
+    if data.len() <= 2 * n {
+        return hexlify_into(data, out, uppercase);
+    }
+    hexlify_into(&data[..n], out, uppercase)
+        && push_str_fitting(out, "...")
+        && hexlify_into(&data[data.len() - n..], out, uppercase)
+}
+
 pub fn format_i64(num: i64, buffer: &mut [u8]) -> Option<&str> {
     let mut i = 0;
     let mut num = num;
@@ -68,6 +106,122 @@ pub fn format_i64(num: i64, buffer: &mut [u8]) -> Option<&str> {
     }
 }
 
+/// Append the decimal representation of `num` to `s`. Returns `Err` and leaves
+/// `s` intact if it does not fit.
+pub fn push_int<const N: usize>(s: &mut String<N>, num: i64) -> Result<(), ()> {
+    // Enough for the sign and 19 digits of `i64::MAX`.
+    let mut buf = [0u8; 20];
+    let text = format_i64(num, &mut buf).ok_or(())?;
+    s.push_str(text)
+}
+
+/// Append `template` to `out` with each `{name}` placeholder replaced by
+/// `value(name)`. Placeholders for which it returns `None` and unmatched braces
+/// are kept as they are. Returns `Err` if the result does not fit into `out`,
+/// which then holds the part that did.
+pub fn format_placeholders<'a, const N: usize>(
+    template: &str,
+    out: &mut String<N>,
+    value: impl Fn(&str) -> Option<&'a str>,
+) -> Result<(), ()> {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start])?;
+        rest = &rest[start..];
+        let found = rest
+            .find('}')
+            .and_then(|end| Some((value(&rest[1..end])?, end)));
+        match found {
+            Some((value, end)) => {
+                out.push_str(value)?;
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('{').map_err(|_| ())?;
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest)
+}
+
+/// Fill the `{0}`, `{1}`, ... placeholders of a translated template with
+/// `args`, like the `format` method of Python strings. Placeholders without a
+/// matching argument are kept as they are. Returns `Err` if the result does
+/// not fit into `out`.
+pub fn format_template<const N: usize>(
+    template: &str,
+    args: &[&str],
+    out: &mut String<N>,
+) -> Result<(), ()> {
+    format_placeholders(template, out, |name| {
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        args.get(name.parse::<usize>().ok()?).copied()
+    })
+}
+
+/// Decimal representation of `num` as a `ShortString`, without going through
+/// `core::fmt`.
+pub fn format_int(num: i64) -> ShortString {
+    let mut s = ShortString::new();
+    // Any `i64` fits into `ShortString`.
+    unwrap!(push_int(&mut s, num));
+    s
+}
+
+/// Uppercase variant of `ch` if it is a lowercase letter of ASCII, Latin-1 or
+/// Latin Extended-A (covering e.g. Czech and German), `ch` itself otherwise.
+/// Unlike `char::to_uppercase`, it does not need the full Unicode tables and
+/// never changes the encoded length of the char.
+pub fn char_to_uppercase(ch: char) -> char {
+    let c = ch as u32;
+    let upper = match c {
+        0x61..=0x7a => c - 0x20,
+        // Except for the division sign.
+        0xe0..=0xfe if c != 0xf7 => c - 0x20,
+        0xff => 0x178,
+        // Except for the dotless i, its uppercase is the ASCII I.
+        0x100..=0x137 | 0x14a..=0x177 if c % 2 == 1 && c != 0x131 => c - 1,
+        0x139..=0x148 | 0x179..=0x17e if c % 2 == 0 => c - 1,
+        _ => c,
+    };
+    char::from_u32(upper).unwrap_or(ch)
+}
+
+/// Append `text` converted by `char_to_uppercase` to `s`. Returns `false` and
+/// leaves `s` intact if it does not fit.
+pub fn push_uppercased<const N: usize>(s: &mut String<N>, text: &str) -> bool {
+    if s.capacity() - s.len() < text.len() {
+        return false;
+    }
+    for ch in text.chars() {
+        // Cannot fail, the encoded length stays the same.
+        let _ = s.push(char_to_uppercase(ch));
+    }
+    true
+}
+
+/// Append as much of `text` to `s` as fits, truncating at a char boundary.
+/// Truncation is considered a bug in debug builds.
+pub fn push_str_truncated<const N: usize>(s: &mut String<N>, text: &str) {
+    let fits = push_str_fitting(s, text);
+    debug_assert!(fits, "string truncated");
+}
+
+/// Append as much of `text` to `s` as fits, truncating at a char boundary.
+/// Returns `false` if `text` had to be truncated.
+fn push_str_fitting<const N: usize>(s: &mut String<N>, text: &str) -> bool {
+    let mut end = text.len().min(N - s.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    // Cannot fail, the length was checked above.
+    let _ = s.push_str(&text[..end]);
+    end == text.len()
+}
+
 #[derive(Copy, Clone)]
 pub enum TString<'a> {
     #[cfg(feature = "micropython")]
@@ -126,6 +280,49 @@ impl TString<'_> {
             Self::Str(s) => Self::Str(&s[skip_bytes..]),
         }
     }
+
+    /// Maps the uppercase variant of the string (see `char_to_uppercase`) to a
+    /// value using a closure. Strings too long for `ShortString` are passed
+    /// unchanged.
+    pub fn map_uppercased<F, T>(&self, fun: F) -> T
+    where
+        F: for<'a> FnOnce(&'a str) -> T,
+    {
+        self.map(|s| {
+            let mut upper = ShortString::new();
+            if push_uppercased(&mut upper, s) {
+                fun(&upper)
+            } else {
+                fun(s)
+            }
+        })
+    }
+
+    /// Whether any of the characters would be drawn as a placeholder box in
+    /// `font`. Screens confirming the exact text should refuse it then.
+    #[cfg(feature = "ui")]
+    pub fn has_unrenderable_chars(&self, font: Font) -> bool {
+        self.map(|s| s.chars().any(|c| !font.has_glyph(c)))
+    }
+
+    /// Append the `parts` one after another to `out`, resolving translations.
+    /// Whatever does not fit into `out` is cut off.
+    pub fn concat_into(parts: &[TString<'_>], out: &mut ShortString) {
+        Self::join_into(parts, "", out)
+    }
+
+    /// Append the `parts` to `out` with `separator` between each two of them,
+    /// resolving translations. Whatever does not fit into `out` is cut off.
+    pub fn join_into(parts: &[TString<'_>], separator: &str, out: &mut ShortString) {
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 && !push_str_fitting(out, separator) {
+                return;
+            }
+            if !part.map(|s| push_str_fitting(out, s)) {
+                return;
+            }
+        }
+    }
 }
 
 impl TString<'static> {
@@ -166,7 +166,7 @@ impl Default for Stopwatch {
 
 impl Stopwatch {
     /// Creates a new stopped stopwatch with duration of zero
-    pub fn new_stopped() -> Self {
+    pub const fn new_stopped() -> Self {
         Self::Stopped(Duration::ZERO)
     }
 
@@ -1,4 +1,7 @@
-use crate::strutil::{format_i64, TString};
+use crate::{
+    strutil::{format_i64, TString},
+    ui::geometry::Rect,
+};
 
 pub trait Tracer {
     fn child(&mut self, key: &str, value: &dyn Trace);
@@ -13,6 +16,26 @@ pub trait Tracer {
     fn component(&mut self, name: &str) {
         self.string("component", name.into());
     }
+
+    fn u8(&mut self, key: &str, n: u8) {
+        self.int(key, n.into());
+    }
+
+    fn i32(&mut self, key: &str, n: i32) {
+        self.int(key, n.into());
+    }
+
+    /// Placed area of the component, so that tests can check the geometry.
+    fn area(&mut self, r: Rect) {
+        self.in_child("area", &|t| trace_rect(t, r));
+    }
+}
+
+fn trace_rect(t: &mut dyn Tracer, r: Rect) {
+    t.i32("x", r.x0.into());
+    t.i32("y", r.y0.into());
+    t.i32("width", r.width().into());
+    t.i32("height", r.height().into());
 }
 
 pub trait ListTracer {
@@ -20,9 +43,15 @@ pub trait ListTracer {
     fn int(&mut self, i: i64);
     fn string(&mut self, s: &TString<'_>);
     fn bool(&mut self, b: bool);
+    fn null(&mut self);
 
     fn in_child(&mut self, block: &dyn Fn(&mut dyn Tracer));
     fn in_list(&mut self, block: &dyn Fn(&mut dyn ListTracer));
+
+    /// Area as an object in the list, e.g. for the geometry of several buttons.
+    fn area(&mut self, r: Rect) {
+        self.in_child(&|t| trace_rect(t, r));
+    }
 }
 
 /// Generic tracer based on a TraceWriter.
@@ -84,14 +113,31 @@ impl<F: FnMut(&str)> JsonTracer<F> {
         // correct.
         unsafe {
             self.push_or_flush('"');
-            for mut ch in s.chars() {
-                if ch == '\\' || ch == '"' {
-                    self.push_or_flush('\\');
-                } else if ch == '\n' {
-                    self.push_or_flush('\\');
-                    ch = 'n';
-                }
-                self.push_or_flush(ch);
+            for ch in s.chars() {
+                let escaped = match ch {
+                    '"' => '"',
+                    '\\' => '\\',
+                    '\n' => 'n',
+                    '\r' => 'r',
+                    '\t' => 't',
+                    '\u{8}' => 'b',
+                    '\u{c}' => 'f',
+                    ch if (ch as u32) < 0x20 => {
+                        // Remaining control characters only have the `\u00XX` form.
+                        let hex = |n: u32| unwrap!(char::from_digit(n, 16));
+                        let code = ch as u32;
+                        for ch in ['\\', 'u', '0', '0', hex(code >> 4), hex(code & 0xf)] {
+                            self.push_or_flush(ch);
+                        }
+                        continue;
+                    }
+                    ch => {
+                        self.push_or_flush(ch);
+                        continue;
+                    }
+                };
+                self.push_or_flush('\\');
+                self.push_or_flush(escaped);
             }
             self.push_or_flush('"');
             self.flush_buf();
@@ -131,6 +177,11 @@ impl<F: FnMut(&str)> ListTracer for JsonTracer<F> {
         (self.write_fn)(if b { "true" } else { "false" });
     }
 
+    fn null(&mut self) {
+        self.maybe_comma();
+        (self.write_fn)("null");
+    }
+
     fn in_child(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
         self.maybe_comma();
         self.first = true;
@@ -205,6 +256,7 @@ pub mod tests {
     use serde_json::Value;
 
     use super::*;
+    use crate::ui::geometry::Point;
 
     pub fn trace(val: &impl Trace) -> Value {
         let mut buf = Vec::new();
@@ -214,4 +266,60 @@ pub mod tests {
         //crate::micropython::print::print(s.as_str());
         s.parse().unwrap()
     }
+
+    #[cfg(feature = "model_tt")]
+    fn trace_label(text: &'static str) -> Value {
+        use crate::ui::{component::Label, model_tt::theme};
+
+        trace(&Label::left_aligned(text.into(), theme::TEXT_NORMAL))
+    }
+
+    #[test]
+    #[cfg(feature = "model_tt")]
+    fn escape_quotes_and_backslashes() {
+        let text = r#"say "hi" \ "bye"\"#;
+        assert_eq!(trace_label(text)["text"], text);
+    }
+
+    #[test]
+    #[cfg(feature = "model_tt")]
+    fn escape_control_characters() {
+        let text = "line\nbreak\r\ttab\u{8}\u{c}\u{0}\u{1f}\u{7f}";
+        assert_eq!(trace_label(text)["text"], text);
+    }
+
+    #[test]
+    #[cfg(feature = "model_tt")]
+    fn non_ascii_passes_through() {
+        let text = "Satoshi Nakamoto ₿ — žluťoučký kůň 🐴";
+        assert_eq!(trace_label(text)["text"], text);
+    }
+
+    #[test]
+    fn numbers_and_nested_lists() {
+        struct Numbers;
+
+        impl Trace for Numbers {
+            fn trace(&self, t: &mut dyn Tracer) {
+                t.u8("small", 255);
+                t.i32("negative", -100_000);
+                t.in_list("areas", &|t| {
+                    t.area(Rect::new(Point::new(1, 2), Point::new(11, 22)));
+                    t.null();
+                    t.in_list(&|t| t.int(0));
+                });
+            }
+        }
+
+        let expected = serde_json::json!({
+            "small": 255,
+            "negative": -100000,
+            "areas": [
+                {"x": 1, "y": 2, "width": 10, "height": 20},
+                null,
+                [0],
+            ],
+        });
+        assert_eq!(trace(&Numbers), expected);
+    }
 }
@@ -95,6 +95,9 @@ pub fn deinit() -> Result<(), Error> {
         return Err(value_error!("Translations are in use."));
     };
     *blob = None;
+    // Glyphs decoded from the blob may not match the next one.
+    #[cfg(feature = "ui_glyph_cache")]
+    crate::ui::display::glyph_cache::clear();
     Ok(())
 }
 
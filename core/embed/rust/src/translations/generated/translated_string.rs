@@ -1336,6 +1336,30 @@ pub enum TranslatedString {
     homescreen__set_default = 936,  // "Do you really want to set default homescreen image?"
     reset__words_may_repeat = 937,  // "Words may repeat."
     reset__repeat_for_all_shares = 938,  // "Repeat for all shares."
+    address_details__script_type = 939,  // "Script type"
+    pin__x_tries_left_template_plural = 940,  // "{count} {plural} left"
+    plurals__x_tries_left = 941,  // "try|tries"
+    passphrase__double_space = 942,  // "Double space"
+    passphrase__space_at_end = 943,  // "Space at end"
+    passphrase__space_at_start = 944,  // "Space at start"
+    passphrase__tap_to_trim = 945,  // "Tap to trim"
+    recovery__share_word_x_of_y_template = 946,  // "Share {0}/{1}, word {2}/{3}"
+    led_signals__title = 947,  // "LED signals"
+    led_signals__enable = 948,  // "Enable LED signals?"
+    led_signals__disable = 949,  // "Disable LED signals?"
+    high_contrast__title = 950,  // "High contrast"
+    high_contrast__enable = 951,  // "Do you want to enable high contrast text?"
+    high_contrast__disable = 952,  // "Do you want to disable high contrast text?"
+    keyboard_timings__title = 953,  // "Keyboard timing"
+    keyboard_timings__erase_hold_template = 954,  // "Hold the erase key for {0} ms to clear the input?"
+    keyboard_timings__multi_tap_template = 955,  // "Confirm a multi-tap key after {0} ms?"
+    large_text__title = 956,  // "Large text"
+    large_text__enable = 957,  // "Do you want to enable large text?"
+    large_text__disable = 958,  // "Do you want to disable large text?"
+    recovery__share_num_word_x_of_y_template = 959,  // "Share {0}, word {1}/{2}"
+    recovery__type_word = 960,  // "Type word"
+    keyboard_timings__last_char_reveal_template = 961,  // "Show the last typed character of a hidden passphrase for {0} ms?"
+    keyboard_timings__key_debounce_template = 962,  // "Ignore repeated presses of a key within {0} ms?"
 }
 
 impl TranslatedString {
@@ -2667,6 +2691,30 @@ impl TranslatedString {
             Self::homescreen__set_default => "Do you really want to set default homescreen image?",
             Self::reset__words_may_repeat => "Words may repeat.",
             Self::reset__repeat_for_all_shares => "Repeat for all shares.",
+            Self::address_details__script_type => "Script type",
+            Self::pin__x_tries_left_template_plural => "{count} {plural} left",
+            Self::plurals__x_tries_left => "try|tries",
+            Self::passphrase__double_space => "Double space",
+            Self::passphrase__space_at_end => "Space at end",
+            Self::passphrase__space_at_start => "Space at start",
+            Self::passphrase__tap_to_trim => "Tap to trim",
+            Self::recovery__share_word_x_of_y_template => "Share {0}/{1}, word {2}/{3}",
+            Self::led_signals__title => "LED signals",
+            Self::led_signals__enable => "Enable LED signals?",
+            Self::led_signals__disable => "Disable LED signals?",
+            Self::high_contrast__title => "High contrast",
+            Self::high_contrast__enable => "Do you want to enable high contrast text?",
+            Self::high_contrast__disable => "Do you want to disable high contrast text?",
+            Self::keyboard_timings__title => "Keyboard timing",
+            Self::keyboard_timings__erase_hold_template => "Hold the erase key for {0} ms to clear the input?",
+            Self::keyboard_timings__multi_tap_template => "Confirm a multi-tap key after {0} ms?",
+            Self::large_text__title => "Large text",
+            Self::large_text__enable => "Do you want to enable large text?",
+            Self::large_text__disable => "Do you want to disable large text?",
+            Self::recovery__share_num_word_x_of_y_template => "Share {0}, word {1}/{2}",
+            Self::recovery__type_word => "Type word",
+            Self::keyboard_timings__last_char_reveal_template => "Show the last typed character of a hidden passphrase for {0} ms?",
+            Self::keyboard_timings__key_debounce_template => "Ignore repeated presses of a key within {0} ms?",
         }
     }
 
@@ -3999,6 +4047,30 @@ impl TranslatedString {
             Qstr::MP_QSTR_homescreen__set_default => Some(Self::homescreen__set_default),
             Qstr::MP_QSTR_reset__words_may_repeat => Some(Self::reset__words_may_repeat),
             Qstr::MP_QSTR_reset__repeat_for_all_shares => Some(Self::reset__repeat_for_all_shares),
+            Qstr::MP_QSTR_address_details__script_type => Some(Self::address_details__script_type),
+            Qstr::MP_QSTR_pin__x_tries_left_template_plural => Some(Self::pin__x_tries_left_template_plural),
+            Qstr::MP_QSTR_plurals__x_tries_left => Some(Self::plurals__x_tries_left),
+            Qstr::MP_QSTR_passphrase__double_space => Some(Self::passphrase__double_space),
+            Qstr::MP_QSTR_passphrase__space_at_end => Some(Self::passphrase__space_at_end),
+            Qstr::MP_QSTR_passphrase__space_at_start => Some(Self::passphrase__space_at_start),
+            Qstr::MP_QSTR_passphrase__tap_to_trim => Some(Self::passphrase__tap_to_trim),
+            Qstr::MP_QSTR_recovery__share_word_x_of_y_template => Some(Self::recovery__share_word_x_of_y_template),
+            Qstr::MP_QSTR_led_signals__title => Some(Self::led_signals__title),
+            Qstr::MP_QSTR_led_signals__enable => Some(Self::led_signals__enable),
+            Qstr::MP_QSTR_led_signals__disable => Some(Self::led_signals__disable),
+            Qstr::MP_QSTR_high_contrast__title => Some(Self::high_contrast__title),
+            Qstr::MP_QSTR_high_contrast__enable => Some(Self::high_contrast__enable),
+            Qstr::MP_QSTR_high_contrast__disable => Some(Self::high_contrast__disable),
+            Qstr::MP_QSTR_keyboard_timings__title => Some(Self::keyboard_timings__title),
+            Qstr::MP_QSTR_keyboard_timings__erase_hold_template => Some(Self::keyboard_timings__erase_hold_template),
+            Qstr::MP_QSTR_keyboard_timings__multi_tap_template => Some(Self::keyboard_timings__multi_tap_template),
+            Qstr::MP_QSTR_large_text__title => Some(Self::large_text__title),
+            Qstr::MP_QSTR_large_text__enable => Some(Self::large_text__enable),
+            Qstr::MP_QSTR_large_text__disable => Some(Self::large_text__disable),
+            Qstr::MP_QSTR_recovery__share_num_word_x_of_y_template => Some(Self::recovery__share_num_word_x_of_y_template),
+            Qstr::MP_QSTR_recovery__type_word => Some(Self::recovery__type_word),
+            Qstr::MP_QSTR_keyboard_timings__last_char_reveal_template => Some(Self::keyboard_timings__last_char_reveal_template),
+            Qstr::MP_QSTR_keyboard_timings__key_debounce_template => Some(Self::keyboard_timings__key_debounce_template),
             _ => None,
         }
     }
@@ -3,6 +3,7 @@ mod flash;
 mod generated;
 #[cfg(feature = "micropython")]
 mod obj;
+mod plural;
 mod public_keys;
 mod translated_string;
 
@@ -0,0 +1,103 @@
+//! Counts in translated strings, e.g. "3 more shares needed". The plural forms
+//! of a word are stored in a single translation separated by `|`, and which
+//! one goes with a count depends on how many forms the language has, same as
+//! in `strings.format_plural` of the Python code:
+//! - two forms (e.g. English): singular for 1, plural otherwise
+//! - three forms (e.g. Czech): singular for 1, "few" for 2 to 4, "many"
+//!   otherwise
+
+use crate::strutil::{format_int, format_placeholders, ShortString};
+
+use super::TR;
+
+/// Form of `plurals` that goes with `count`.
+fn plural_form(plurals: &str, count: u32) -> &str {
+    let num_forms = plurals.split('|').count();
+    let index = match (num_forms, count) {
+        (_, 1) => 0,
+        (3, 2..=4) => 1,
+        _ => num_forms - 1,
+    };
+    plurals.split('|').nth(index).unwrap_or(plurals)
+}
+
+/// Fill the `{count}` placeholder of `template` with `count` and the
+/// `{plural}` placeholder with the matching form of `plurals`. Other
+/// placeholders are kept as they are, the result is cut short if it does not
+/// fit.
+pub fn format_plural(template: &str, count: u32, plurals: &str) -> ShortString {
+    let form = plural_form(plurals, count);
+    let count = format_int(count.into());
+    let mut out = ShortString::new();
+    // Whatever did not fit is left out.
+    let _ = format_placeholders(template, &mut out, |name| match name {
+        "count" => Some(count.as_str()),
+        "plural" => Some(form),
+        _ => None,
+    });
+    out
+}
+
+impl TR {
+    /// `format_plural` of this template and the plural forms in `plurals`.
+    pub fn format_plural(self, count: u32, plurals: TR) -> ShortString {
+        self.map_translated(|template| {
+            plurals.map_translated(|plurals| format_plural(template, count, plurals))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "{count} more {plural} needed";
+
+    fn check(plurals: &str, expected: &[(u32, &str)]) {
+        for &(count, text) in expected {
+            assert_eq!(format_plural(TEMPLATE, count, plurals).as_str(), text);
+        }
+    }
+
+    #[test]
+    fn english() {
+        check(
+            "share|shares",
+            &[
+                (0, "0 more shares needed"),
+                (1, "1 more share needed"),
+                (2, "2 more shares needed"),
+                (5, "5 more shares needed"),
+                (21, "21 more shares needed"),
+            ],
+        );
+    }
+
+    #[test]
+    fn czech() {
+        check(
+            "část|části|částí",
+            &[
+                (0, "0 more částí needed"),
+                (1, "1 more část needed"),
+                (2, "2 more části needed"),
+                (4, "4 more části needed"),
+                (5, "5 more částí needed"),
+                (12, "12 more částí needed"),
+            ],
+        );
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            format_plural("{plural}: {count}", 2, "key|keys").as_str(),
+            "keys: 2"
+        );
+        assert_eq!(
+            format_plural("{count} {0} {", 1, "key|keys").as_str(),
+            "1 {0} {"
+        );
+        assert_eq!(format_plural("{count} {plural}", 3, "ms").as_str(), "3 ms");
+    }
+}
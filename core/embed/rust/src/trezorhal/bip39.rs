@@ -1,10 +1,15 @@
 use super::{ffi, wordlist::Wordlist};
 
+#[cfg(feature = "crypto")]
+use crate::crypto::sha256;
+#[cfg(feature = "crypto")]
+use zeroize::Zeroize;
+
 pub fn complete_word(prefix: &str) -> Option<&'static str> {
     if prefix.is_empty() {
         None
     } else {
-        Wordlist::bip39().filter_prefix(prefix).iter().next()
+        Wordlist::bip39().matches(prefix).next()
     }
 }
 
@@ -12,7 +17,7 @@ pub fn options_num(prefix: &str) -> Option<usize> {
     if prefix.is_empty() {
         None
     } else {
-        Some(Wordlist::bip39().filter_prefix(prefix).len())
+        Some(Wordlist::bip39().matches(prefix).count())
     }
 }
 
@@ -21,3 +26,151 @@ pub fn word_completion_mask(prefix: &str) -> u32 {
     // passed byte string, making the call safe.
     unsafe { ffi::mnemonic_word_completion_mask(prefix.as_ptr() as _, prefix.len() as _) }
 }
+
+/// Index of `word` in the BIP-39 wordlist.
+pub fn word_index(word: &str) -> Option<usize> {
+    let words = Wordlist::bip39();
+    let (start, _) = words.prefix_range(word);
+    (words.get(start) == Some(word)).then_some(start)
+}
+
+#[cfg(feature = "crypto")]
+const WORD_COUNT: usize = ffi::BIP39_WORD_COUNT as usize;
+
+/// Bits of entropy and checksum encoded by a single word.
+#[cfg(feature = "crypto")]
+const BITS_PER_WORD: usize = 11;
+
+/// Final words of a mnemonic that give a valid checksum together with the
+/// words before them, one bit per word of the wordlist.
+#[cfg(feature = "crypto")]
+pub struct ValidLastWords([u32; WORD_COUNT / 32]);
+
+#[cfg(feature = "crypto")]
+impl ValidLastWords {
+    /// Compute the valid final words following `previous`, the wordlist
+    /// indices of all the other words. `None` if one more word does not make
+    /// a mnemonic of a valid length.
+    ///
+    /// The last word holds the checksum and the remaining bits of the entropy,
+    /// so there is one valid word for every value of those bits, e.g. 128
+    /// words for 12-word mnemonics and 8 for 24-word ones.
+    pub fn new(previous: &[u16]) -> Option<Self> {
+        let word_count = previous.len() + 1;
+        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+            return None;
+        }
+        // One bit of checksum for every 32 bits of entropy.
+        let checksum_bits = word_count / 3;
+        let entropy_len = checksum_bits * 4;
+        let free_bits = BITS_PER_WORD - checksum_bits;
+
+        let mut entropy = [0u8; 32];
+        for (i, &index) in previous.iter().enumerate() {
+            write_bits(&mut entropy, i * BITS_PER_WORD, index.into(), BITS_PER_WORD);
+        }
+        let free_pos = previous.len() * BITS_PER_WORD;
+
+        let mut valid = Self([0; WORD_COUNT / 32]);
+        for free in 0..1u32 << free_bits {
+            let mut candidate = entropy;
+            write_bits(&mut candidate, free_pos, free, free_bits);
+            let mut hash = sha256::digest(&candidate[..entropy_len]);
+            let checksum = u32::from(hash[0]) >> (8 - checksum_bits);
+            valid.insert(((free << checksum_bits) | checksum) as usize);
+            candidate.zeroize();
+            hash.zeroize();
+        }
+        entropy.zeroize();
+        Some(valid)
+    }
+
+    fn insert(&mut self, index: usize) {
+        self.0[index / 32] |= 1 << (index % 32);
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0[index / 32] & (1 << (index % 32)) != 0
+    }
+
+    /// Iterator of valid words starting with `prefix`, in alphabetical order.
+    pub fn matches<'a>(&'a self, prefix: &str) -> impl Iterator<Item = &'static str> + 'a {
+        let words = Wordlist::bip39();
+        let (start, end) = words.prefix_range(prefix);
+        (start..end)
+            .filter(move |&index| self.contains(index))
+            .filter_map(move |index| words.get(index))
+    }
+
+    /// Bitmask of all letters that can follow `prefix` towards a valid word,
+    /// same as `Wordlist::letter_mask`.
+    pub fn letter_mask(&self, prefix: &str) -> u32 {
+        self.matches(prefix)
+            .filter_map(|word| word.as_bytes().get(prefix.len()))
+            // Wordlists contain only lower-case alphabetic ASCII, making the
+            // subtraction and the shift panic-free.
+            .fold(0, |mask, ch| mask | 1 << (ch - b'a'))
+    }
+}
+
+/// Write the lowest `len` bits of `value` into the zeroed bits of `buf`
+/// starting at bit `pos`, most significant bit first.
+#[cfg(feature = "crypto")]
+fn write_bits(buf: &mut [u8], pos: usize, value: u32, len: usize) {
+    for i in 0..len {
+        if (value >> (len - 1 - i)) & 1 != 0 {
+            let bit = pos + i;
+            buf[bit / 8] |= 0x80 >> (bit % 8);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "crypto"))]
+mod tests {
+    use super::*;
+
+    fn valid_last_words(previous: &[&str]) -> Option<ValidLastWords> {
+        let indices: Vec<u16> = previous
+            .iter()
+            .map(|word| word_index(word).unwrap() as u16)
+            .collect();
+        ValidLastWords::new(&indices)
+    }
+
+    #[test]
+    fn test_word_index() {
+        assert_eq!(word_index("abandon"), Some(0));
+        assert_eq!(word_index("about"), Some(3));
+        assert_eq!(word_index("zoo"), Some(2047));
+        assert_eq!(word_index("abando"), None);
+        assert_eq!(word_index("zoology"), None);
+        assert_eq!(word_index(""), None);
+    }
+
+    #[test]
+    fn test_valid_last_words() {
+        let valid = valid_last_words(&["abandon"; 11]).unwrap();
+        assert_eq!((0..WORD_COUNT).filter(|&i| valid.contains(i)).count(), 128);
+        assert!(valid.contains(word_index("about").unwrap()));
+        assert!(!valid.contains(word_index("abandon").unwrap()));
+        assert_eq!(valid.matches("abo").collect::<Vec<_>>(), vec!["about"]);
+        assert_eq!(valid.matches("ab").collect::<Vec<_>>(), vec!["about"]);
+        assert_eq!(valid.letter_mask("ab"), 1 << (b'o' - b'a'));
+
+        let valid = valid_last_words(&["abandon"; 23]).unwrap();
+        assert_eq!(valid.matches("").count(), 8);
+        assert!(valid.contains(word_index("art").unwrap()));
+
+        let previous = [
+            "legal", "winner", "thank", "year", "wave", "sausage", "worth", "useful", "legal",
+            "winner", "thank",
+        ];
+        assert!(valid_last_words(&previous)
+            .unwrap()
+            .contains(word_index("yellow").unwrap()));
+
+        // Not one word short of a valid length.
+        assert!(valid_last_words(&["abandon"; 12]).is_none());
+        assert!(valid_last_words(&[]).is_none());
+    }
+}
@@ -57,16 +57,14 @@ pub fn text_width(text: &str, font: i32) -> i16 {
     }
 }
 
-pub fn char_width(ch: char, font: i32) -> i16 {
-    let mut buf = [0u8; 4];
-    let encoding = ch.encode_utf8(&mut buf);
-    text_width(encoding, font)
-}
-
 pub fn get_char_glyph(ch: u16, font: i32) -> *const u8 {
     unsafe { ffi::font_get_glyph(font, ch) }
 }
 
+pub fn get_nonprintable_glyph(font: i32) -> *const u8 {
+    unsafe { ffi::font_nonprintable_glyph(font) }
+}
+
 pub fn text_height(font: i32) -> i16 {
     unsafe { ffi::font_height(font).try_into().unwrap_or(i16::MAX) }
 }
@@ -181,6 +179,13 @@ pub fn clear() {
     }
 }
 
+/// Limits the next refresh to the given region, `x1` and `y1` exclusive.
+/// Returns `false` if the display does not support partial updates.
+#[cfg(feature = "xframebuffer")]
+pub fn set_refresh_region(x0: i16, y0: i16, x1: i16, y1: i16) -> bool {
+    unsafe { ffi::display_set_refresh_region(x0.into(), y0.into(), x1.into(), y1.into()) }
+}
+
 #[cfg(feature = "xframebuffer")]
 pub fn get_frame_buffer() -> (&'static mut [u8], usize) {
     let fb_info = unsafe { ffi::display_get_frame_buffer() };
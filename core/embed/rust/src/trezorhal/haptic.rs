@@ -1,17 +1,32 @@
 use super::ffi;
 
+/// Haptic feedback for UI interactions. The amplitude, duration and priority
+/// of each effect are tuned in the driver.
 #[derive(PartialEq, Debug, Eq, FromPrimitive, Clone, Copy)]
 pub enum HapticEffect {
-    ButtonPress = ffi::haptic_effect_t_HAPTIC_BUTTON_PRESS as _,
-    HoldToConfirm = ffi::haptic_effect_t_HAPTIC_HOLD_TO_CONFIRM as _,
+    /// Subtle feedback, e.g. a value stepping in a slider.
+    Tick = ffi::haptic_effect_t_HAPTIC_TICK as _,
+    /// Button or key press.
+    Press = ffi::haptic_effect_t_HAPTIC_BUTTON_PRESS as _,
+    /// Holding of a hold-to-confirm button started.
+    HoldStart = ffi::haptic_effect_t_HAPTIC_HOLD_START as _,
+    /// Holding reached the confirmation threshold.
+    HoldConfirm = ffi::haptic_effect_t_HAPTIC_HOLD_TO_CONFIRM as _,
+    Error = ffi::haptic_effect_t_HAPTIC_ERROR as _,
+    Success = ffi::haptic_effect_t_HAPTIC_SUCCESS as _,
 }
 
+/// Start playing the `effect` without blocking. A playing effect of a higher
+/// priority is not interrupted, the new one is dropped instead. Nothing is
+/// played if haptic feedback is disabled in the settings.
 pub fn play(effect: HapticEffect) {
     unsafe {
         ffi::haptic_play(effect as _);
     }
 }
 
+/// Play a vibration of the given amplitude, with the lowest priority. Can be
+/// called repeatedly to change the amplitude over time.
 pub fn play_custom(amplitude_pct: i8, duration_ms: u16) {
     unsafe {
         ffi::haptic_play_custom(amplitude_pct, duration_ms);
@@ -1,19 +1,51 @@
+/// Returns a random number from the hardware RNG.
+pub fn random32() -> u32 {
+    unsafe { super::ffi::random32() }
+}
+
+/// Returns a random number in the range [0, n).
 pub fn uniform(n: u32) -> u32 {
-    unsafe { super::ffi::random_uniform(n) }
+    assert!(n > 0);
+    uniform_between(0, n - 1)
 }
 
 pub fn shuffle<T>(slice: &mut [T]) {
+    shuffle_with(&mut random32, slice)
+}
+
+/// Shuffles `slice` drawing the random numbers from `rng`.
+pub fn shuffle_with<T>(rng: &mut impl FnMut() -> u32, slice: &mut [T]) {
     // Fisher-Yates shuffle.
     for i in (1..slice.len()).rev() {
-        let j = uniform(i as u32 + 1) as usize;
+        let j = uniform_between_with(rng, 0, i as u32) as usize;
         slice.swap(i, j);
     }
 }
 
 /// Returns a random number in the range [min, max].
+///
+/// Raw random numbers falling into the incomplete last block of `n` values
+/// are rejected, so that the result is not biased towards the low end of the
+/// range by the modulo.
 pub fn uniform_between(min: u32, max: u32) -> u32 {
+    uniform_between_with(&mut random32, min, max)
+}
+
+/// Like `uniform_between`, drawing the random numbers from `rng`.
+pub fn uniform_between_with(rng: &mut impl FnMut() -> u32, min: u32, max: u32) -> u32 {
     assert!(max >= min);
-    uniform(max - min + 1) + min
+    let Some(n) = (max - min).checked_add(1) else {
+        // The whole range of `u32`.
+        return rng();
+    };
+    // 2^32 mod n
+    let rejected = (u32::MAX % n + 1) % n;
+    loop {
+        let x = rng();
+        if x <= u32::MAX - rejected {
+            return min + x % n;
+        }
+    }
 }
 
 /// Returns a random number in the range [min, max] except one `except` number.
@@ -41,6 +73,58 @@ mod tests {
         }
     }
 
+    /// Pearson's chi-squared statistic of `counts` against the uniform
+    /// distribution.
+    fn chi_squared(counts: &[u32]) -> f64 {
+        let total: u32 = counts.iter().sum();
+        let expected = total as f64 / counts.len() as f64;
+        counts
+            .iter()
+            .map(|&c| (c as f64 - expected) * (c as f64 - expected) / expected)
+            .sum()
+    }
+
+    // Critical value of the chi-squared distribution with 5 degrees of freedom
+    // for p = 0.001, i.e. the tests fail spuriously once in a thousand runs.
+    const CHI_SQUARED_5_DOF: f64 = 20.52;
+
+    #[test]
+    fn uniform_between_full_range() {
+        assert_eq!(uniform_between(7, 7), 7);
+        // Must not overflow.
+        uniform_between(0, u32::MAX);
+        assert!(uniform_between(u32::MAX - 1, u32::MAX) >= u32::MAX - 1);
+    }
+
+    #[test]
+    fn uniform_between_is_uniform() {
+        let mut counts = [0; 6];
+        for _ in 0..60_000 {
+            counts[(uniform_between(1000, 1005) - 1000) as usize] += 1;
+        }
+        assert!(chi_squared(&counts) < CHI_SQUARED_5_DOF);
+    }
+
+    #[test]
+    fn shuffle_is_uniform() {
+        // Count all 6 permutations of 3 elements.
+        let mut counts = [0; 6];
+        for _ in 0..60_000 {
+            let mut items = [0, 1, 2];
+            shuffle(&mut items);
+            let index = match items {
+                [0, 1, 2] => 0,
+                [0, 2, 1] => 1,
+                [1, 0, 2] => 2,
+                [1, 2, 0] => 3,
+                [2, 0, 1] => 4,
+                _ => 5,
+            };
+            counts[index] += 1;
+        }
+        assert!(chi_squared(&counts) < CHI_SQUARED_5_DOF);
+    }
+
     #[test]
     fn uniform_between_except_test() {
         for _ in 0..10 {
@@ -1,6 +1,7 @@
 use super::ffi;
 use core::cmp::Ordering;
 use cstr_core::CStr;
+use heapless::String;
 
 /// Holds all the possible words with the possibility to interact
 /// with the "list" - filtering it further, getting their count, etc.
@@ -29,34 +30,83 @@ impl Wordlist {
     /// Returns all possible letters from current wordlist that form a valid
     /// word. Alphabetically sorted.
     pub fn get_available_letters(&self) -> impl Iterator<Item = char> {
-        let mut prev_char = '\0';
-        let prefix_len = self.prefix_len;
-        self.iter().filter_map(move |word| {
-            if word.len() <= prefix_len {
-                return None;
-            }
-            let following_char = unwrap!(word.chars().nth(prefix_len));
-            if following_char != prev_char {
-                prev_char = following_char;
-                Some(following_char)
-            } else {
-                None
+        let words = Self::new(self.words, self.prefix_len);
+        let mut start = 0;
+        core::iter::from_fn(move || loop {
+            let ch = words.letter_at(start)?;
+            start = words.letter_range_end(start, ch);
+            if let Some(ch) = ch {
+                return Some(ch as char);
             }
         })
     }
 
-    /// Only leaves words that have a specified prefix. Throw away others.
-    pub fn filter_prefix(&self, prefix: &str) -> Self {
+    /// Returns a bitmask of all letters that can follow the common prefix of
+    /// the current words. Lowest bit is 'a', second lowest 'b', etc.
+    pub fn letter_mask(&self) -> u32 {
+        let mut mask = 0;
+        let mut start = 0;
+        while let Some(ch) = self.letter_at(start) {
+            if let Some(ch) = ch {
+                // Wordlists contain only lower-case alphabetic ASCII, making the
+                // subtraction and the shift panic-free.
+                mask |= 1 << (ch - b'a');
+            }
+            start = self.letter_range_end(start, ch);
+        }
+        mask
+    }
+
+    /// Returns the range of indices of words starting with `prefix`, found by
+    /// binary search. The range is empty if no word matches, and covers the
+    /// whole list for an empty prefix.
+    pub fn prefix_range(&self, prefix: &str) -> (usize, usize) {
         // SAFETY: We assume our slice is an array of 0-terminated strings.
         let start = self
             .words
             .partition_point(|&word| matches!(unsafe { prefix_cmp(prefix, word) }, Ordering::Less));
-        let end = self.words.partition_point(|&word| {
-            !matches!(unsafe { prefix_cmp(prefix, word) }, Ordering::Greater)
-        });
+        let end = start
+            + self.words[start..].partition_point(|&word| {
+                matches!(unsafe { prefix_cmp(prefix, word) }, Ordering::Equal)
+            });
+        (start, end)
+    }
+
+    /// Iterator of words in a range returned by `prefix_range`.
+    pub fn iter_range(&self, (start, end): (usize, usize)) -> Matches {
+        Matches::new(&self.words[start..end])
+    }
+
+    /// Iterator of words starting with `prefix`, in alphabetical order.
+    pub fn matches(&self, prefix: &str) -> Matches {
+        self.iter_range(self.prefix_range(prefix))
+    }
+
+    /// Only leaves words that have a specified prefix. Throw away others.
+    pub fn filter_prefix(&self, prefix: &str) -> Self {
+        let (start, end) = self.prefix_range(prefix);
         Self::new(&self.words[start..end], prefix.len())
     }
 
+    /// Letter following the common prefix in the word at `index`, `Some(None)`
+    /// if the word is just the prefix itself, `None` past the end of the list.
+    fn letter_at(&self, index: usize) -> Option<Option<u8>> {
+        self.get(index)
+            .map(|word| word.as_bytes().get(self.prefix_len).copied())
+    }
+
+    /// Index just past the words continuing with `letter` after the common
+    /// prefix, starting at `start`. Such words are next to each other in the
+    /// sorted list, so the end is found by binary search.
+    fn letter_range_end(&self, start: usize, letter: Option<u8>) -> usize {
+        // SAFETY: We assume our slice is an array of 0-terminated strings.
+        start
+            + self.words[start..].partition_point(|&word| {
+                let word = unsafe { from_utf8_unchecked(word) };
+                word.as_bytes().get(self.prefix_len).copied() == letter
+            })
+    }
+
     /// Get a word at the certain position.
     pub fn get(&self, index: usize) -> Option<&'static str> {
         // SAFETY: we assume every word in the wordlist is a valid 0-terminated UTF-8
@@ -72,11 +122,115 @@ impl Wordlist {
     }
 
     /// Iterator of all current words.
-    pub fn iter(&self) -> impl Iterator<Item = &'static str> {
+    pub fn iter(&self) -> Matches {
+        Matches::new(self.words)
+    }
+}
+
+/// Iterator over a part of a wordlist, see `Wordlist::matches`.
+///
+/// Knows the words it has left, so `count()`, `nth()` and `last()` don't have
+/// to walk through them.
+pub struct Matches {
+    words: core::slice::Iter<'static, *const cty::c_char>,
+}
+
+impl Matches {
+    fn new(words: &'static [*const cty::c_char]) -> Self {
+        Self {
+            words: words.iter(),
+        }
+    }
+}
+
+impl Iterator for Matches {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: we assume every word in the wordlist is a valid 0-terminated UTF-8
+        // string.
         self.words
-            .iter()
+            .next()
+            .map(|word| unsafe { from_utf8_unchecked(*word) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.words.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.words.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // SAFETY: see `next`.
+        self.words
+            .nth(n)
             .map(|word| unsafe { from_utf8_unchecked(*word) })
     }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Matches {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: see `Iterator::next`.
+        self.words
+            .next_back()
+            .map(|word| unsafe { from_utf8_unchecked(*word) })
+    }
+}
+
+impl ExactSizeIterator for Matches {}
+
+/// Wordlist filtered by a prefix which is being typed letter by letter.
+///
+/// Keeps the filtered wordlist for every prefix of the input, so appending a
+/// letter only narrows the last list and removing a letter just drops it,
+/// instead of searching the whole wordlist again.
+pub struct IncrementalFilter<const L: usize> {
+    /// The unfiltered wordlist.
+    root: Wordlist,
+    /// Prefix the last list was filtered by.
+    prefix: String<L>,
+    /// Filtered lists, `lists[i]` holds the words starting with the first
+    /// `i + 1` letters of `prefix`.
+    lists: heapless::Vec<Wordlist, L>,
+}
+
+impl<const L: usize> IncrementalFilter<L> {
+    pub fn new(root: Wordlist) -> Self {
+        Self {
+            root,
+            prefix: String::new(),
+            lists: heapless::Vec::new(),
+        }
+    }
+
+    /// Update the filter to match `prefix`, narrowing the lists of the longest
+    /// prefix shared with the previous input.
+    pub fn set_prefix(&mut self, prefix: &str) {
+        let common_len = self
+            .prefix
+            .bytes()
+            .zip(prefix.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        self.lists.truncate(common_len);
+        for len in common_len + 1..=prefix.len().min(L) {
+            let narrowed = self.current().filter_prefix(&prefix[..len]);
+            unwrap!(self.lists.push(narrowed));
+        }
+        self.prefix.clear();
+        unwrap!(self.prefix.push_str(&prefix[..self.lists.len()]));
+    }
+
+    /// Words matching the current prefix.
+    pub fn current(&self) -> &Wordlist {
+        self.lists.last().unwrap_or(&self.root)
+    }
 }
 
 /// Compare word from wordlist to a prefix.
@@ -156,6 +310,97 @@ mod tests {
         assert_eq!(empty.len(), 0);
     }
 
+    #[test]
+    fn test_prefix_range() {
+        let words = Wordlist::bip39();
+        assert_eq!(words.prefix_range(""), (0, BIP39_WORD_COUNT));
+        assert_eq!(words.prefix_range("a"), (0, 136));
+        assert_eq!(words.prefix_range("str"), (1718, 1723));
+        assert_eq!(words.prefix_range("ve"), (1935, 1946));
+        assert_eq!(words.prefix_range("zoo"), (2047, 2048));
+        // No matches, empty range at the position the prefix would be at.
+        assert_eq!(words.prefix_range("x"), (2038, 2038));
+        // Prefix longer than any matching word.
+        assert_eq!(words.prefix_range("academics"), (10, 10));
+        assert_eq!(words.prefix_range("zoology"), (2048, 2048));
+
+        let words = Wordlist::slip39();
+        assert_eq!(words.prefix_range(""), (0, SLIP39_WORD_COUNT));
+        assert_eq!(words.prefix_range("a"), (0, 67));
+        assert_eq!(words.prefix_range("str"), (870, 872));
+        assert_eq!(words.prefix_range("ve"), (970, 978));
+        assert_eq!(words.prefix_range("x"), (1019, 1019));
+        assert_eq!(words.prefix_range("academics"), (1, 1));
+        assert_eq!(words.prefix_range("zoo"), (1024, 1024));
+    }
+
+    #[test]
+    fn test_matches_counts() {
+        // (prefix, BIP-39 count, SLIP-39 count)
+        let cases = [
+            ("", BIP39_WORD_COUNT, SLIP39_WORD_COUNT),
+            ("a", 136, 67),
+            ("ab", 10, 0),
+            ("ac", 14, 7),
+            ("act", 5, 2),
+            ("q", 8, 4),
+            ("str", 5, 2),
+            ("sw", 11, 4),
+            ("ve", 11, 8),
+            ("w", 69, 27),
+            ("x", 0, 0),
+            ("y", 6, 4),
+            ("zoo", 1, 0),
+            ("acad", 0, 1),
+            ("academic", 0, 1),
+            ("academics", 0, 0),
+        ];
+        for (prefix, bip39_count, slip39_count) in cases {
+            assert_eq!(Wordlist::bip39().matches(prefix).count(), bip39_count);
+            assert_eq!(Wordlist::slip39().matches(prefix).count(), slip39_count);
+            assert_eq!(Wordlist::bip39().filter_prefix(prefix).len(), bip39_count);
+            assert_eq!(Wordlist::slip39().filter_prefix(prefix).len(), slip39_count);
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let words = Wordlist::bip39();
+        let expected_result = vec!["strategy", "street", "strike", "strong", "struggle"];
+        assert_eq!(words.matches("str").collect::<Vec<_>>(), expected_result);
+        // Order is the same on every iteration and matches the indexed access.
+        assert_eq!(words.matches("str").collect::<Vec<_>>(), expected_result);
+        let range = words.prefix_range("str");
+        assert_eq!(
+            words.iter_range(range).collect::<Vec<_>>(),
+            (range.0..range.1)
+                .map(|i| words.get(i).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            words.matches("str").rev().collect::<Vec<_>>(),
+            expected_result.iter().rev().copied().collect::<Vec<_>>()
+        );
+
+        let mut matches = words.matches("str");
+        assert_eq!(matches.len(), 5);
+        assert_eq!(matches.nth(1), Some("street"));
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches.nth(0), Some("strike"));
+        assert_eq!(matches.last(), Some("struggle"));
+        assert_eq!(words.matches("str").nth(5), None);
+
+        // No matches.
+        for prefix in ["x", "zoology", "strx"] {
+            let mut matches = words.matches(prefix);
+            assert_eq!(matches.len(), 0);
+            assert_eq!(matches.nth(0), None);
+            assert_eq!(matches.next(), None);
+            assert_eq!(words.matches(prefix).last(), None);
+            assert_eq!(words.matches(prefix).count(), 0);
+        }
+    }
+
     #[test]
     fn test_wordlist_get() {
         let words = Wordlist::bip39();
@@ -178,6 +423,59 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(result, expected_result);
     }
+
+    fn letters_to_mask(letters: &str) -> u32 {
+        letters.bytes().fold(0, |mask, ch| mask | 1 << (ch - b'a'))
+    }
+
+    #[test]
+    fn test_letter_mask() {
+        let words = Wordlist::bip39();
+        assert_eq!(
+            words.letter_mask(),
+            letters_to_mask("abcdefghijklmnopqrstuvwyz")
+        );
+        assert_eq!(
+            words.filter_prefix("ab").letter_mask(),
+            letters_to_mask("ailosu")
+        );
+        assert_eq!(words.filter_prefix("zoo").letter_mask(), 0);
+        assert_eq!(words.filter_prefix("x").letter_mask(), 0);
+    }
+
+    #[test]
+    fn test_incremental_filter() {
+        let mut filter = IncrementalFilter::<8>::new(Wordlist::bip39());
+        assert_eq!(filter.current().len(), BIP39_WORD_COUNT);
+
+        filter.set_prefix("a");
+        assert_eq!(filter.current().len(), 136);
+        filter.set_prefix("ab");
+        assert_eq!(filter.current().len(), 10);
+        assert_eq!(filter.current().letter_mask(), letters_to_mask("ailosu"));
+        // Narrowing down to nothing.
+        filter.set_prefix("abx");
+        assert_eq!(filter.current().len(), 0);
+        assert_eq!(filter.current().letter_mask(), 0);
+        // Replacing the last letter re-uses the list of the common prefix.
+        filter.set_prefix("abs");
+        assert_eq!(
+            filter.current().iter().collect::<Vec<_>>(),
+            vec!["absent", "absorb", "abstract", "absurd"]
+        );
+        // Going back.
+        filter.set_prefix("a");
+        assert_eq!(filter.current().len(), 136);
+        filter.set_prefix("");
+        assert_eq!(filter.current().len(), BIP39_WORD_COUNT);
+
+        let mut filter = IncrementalFilter::<8>::new(Wordlist::slip39());
+        filter.set_prefix("a");
+        assert_eq!(filter.current().len(), 67);
+        filter.set_prefix("ab");
+        assert_eq!(filter.current().len(), 0);
+    }
+
     #[test]
     fn test_get_available_letters() {
         let result = Wordlist::bip39()
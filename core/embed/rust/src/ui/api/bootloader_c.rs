@@ -1,5 +1,7 @@
+use heapless::String;
+
 use crate::{
-    strutil::hexlify,
+    strutil::hexlify_into,
     trezorhal::secbool::secbool,
     ui::{
         ui_features::{ModelUI, UIFeaturesBootloader},
@@ -45,17 +47,14 @@ extern "C" fn screen_install_confirm(
     let text = unwrap!(unsafe { from_c_array(vendor_str, vendor_str_len as usize) });
     let version = unwrap!(unsafe { from_c_str(version) });
 
-    let mut fingerprint_buffer: [u8; 64] = [0; 64];
-    let fingerprint_str = unsafe {
-        let fingerprint_slice = core::slice::from_raw_parts(fingerprint, 32);
-        hexlify(fingerprint_slice, &mut fingerprint_buffer);
-        core::str::from_utf8_unchecked(fingerprint_buffer.as_ref())
-    };
+    let mut fingerprint_str = String::<64>::new();
+    let fingerprint_slice = unsafe { core::slice::from_raw_parts(fingerprint, 32) };
+    hexlify_into(fingerprint_slice, &mut fingerprint_str, false);
 
     ModelUI::screen_install_confirm(
         text,
         version,
-        fingerprint_str,
+        &fingerprint_str,
         should_keep_seed,
         is_newvendor,
         version_cmp,
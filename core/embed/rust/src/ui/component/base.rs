@@ -8,6 +8,7 @@ use crate::{
     ui::{
         button_request::{ButtonRequest, ButtonRequestCode},
         component::{maybe::PaintOverlapping, MsgMap, PageMap},
+        constant::screen,
         display::{self, Color},
         geometry::{Offset, Rect},
         shape::Renderer,
@@ -16,13 +17,15 @@ use crate::{
 
 #[cfg(feature = "button")]
 use crate::ui::event::ButtonEvent;
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
 use crate::ui::event::USBEvent;
 #[cfg(feature = "touch")]
 use crate::ui::event::{SwipeEvent, TouchEvent};
 
 use super::Paginate;
 #[cfg(feature = "touch")]
-use super::SwipeDirection;
+use super::{SwipeDirection, SwipeMotion};
 
 /// Type used by components that do not return any messages.
 ///
@@ -77,10 +80,15 @@ pub trait Component {
 /// dirty flag for it. Any mutation of `T` has to happen through the `mutate`
 /// accessor, `T` can then request a paint call to be scheduled later by calling
 /// `EventCtx::request_paint` in its `event` pass.
+///
+/// `Child` also remembers the area returned from `place`, and skips rendering
+/// of the inner component if that area lies outside of the region being
+/// redrawn (see `EventCtx::request_paint_region`).
 #[derive(Clone)]
 pub struct Child<T> {
     component: T,
     marked_for_paint: bool,
+    area: Rect,
 }
 
 impl<T> Child<T> {
@@ -88,6 +96,7 @@ impl<T> Child<T> {
         Self {
             component,
             marked_for_paint: true,
+            area: Rect::zero(),
         }
     }
 
@@ -139,7 +148,8 @@ where
     type Msg = T::Msg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        self.component.place(bounds)
+        self.area = self.component.place(bounds);
+        self.area
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
@@ -162,7 +172,11 @@ where
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        self.component.render(target);
+        // Components that were not placed (or report an empty area) are always
+        // rendered, we cannot tell where they draw.
+        if self.area.is_empty() || target.is_visible(self.area) {
+            self.component.render(target);
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -203,7 +217,10 @@ where
     T: crate::trace::Trace,
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
-        self.component.trace(t)
+        self.component.trace(t);
+        if !self.area.is_empty() {
+            t.area(self.area);
+        }
     }
 }
 
@@ -432,6 +449,7 @@ where
 }
 
 pub trait ComponentExt: Sized {
+    /// Translate the messages of the component with `func`, see `MsgMap`.
     fn map<F>(self, func: F) -> MsgMap<Self, F>;
     fn with_pages<F>(self, func: F) -> PageMap<Self, F>;
     fn into_child(self) -> Child<Self>;
@@ -487,6 +505,14 @@ pub enum Event {
     Timer(TimerToken),
     /// Advance progress bar. Progress screens only.
     Progress(u16, TString<'static>),
+    /// Replace the phase label and the estimated time remaining shown with the
+    /// progress bar, empty strings hide them. Progress screens only.
+    ProgressInfo(TString<'static>, TString<'static>),
+    /// Replace the notification and its level, or remove it. Homescreens only.
+    Notification(Option<(TString<'static>, u8)>),
+    /// Move on to the word with the given index and show the progress text.
+    /// Mnemonic keyboards only.
+    MnemonicWord(u16, TString<'static>),
     /// Component has been attached to component tree. This event is sent once
     /// before any other events.
     Attach(AttachType),
@@ -496,6 +522,9 @@ pub enum Event {
     /// Swipe and transition events
     #[cfg(feature = "touch")]
     Swipe(SwipeEvent),
+    /// Key typed on the host keyboard. Emulator only.
+    #[cfg(feature = "host_keyboard")]
+    HostKey(HostKey),
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -514,17 +543,65 @@ impl TimerToken {
     }
 }
 
+/// Timer a component can embed instead of keeping the raw token of the
+/// requested timer around. Stopping the timer cancels it, so that a stopped
+/// timer never expires.
+#[derive(Clone, Default)]
+pub struct Timer(Option<TimerToken>);
+
+impl Timer {
+    pub const fn new() -> Self {
+        Self(None)
+    }
+
+    /// Start the timer to expire after `duration`. A running timer is
+    /// restarted.
+    pub fn start(&mut self, ctx: &mut EventCtx, duration: Duration) {
+        self.stop(ctx);
+        self.0 = Some(ctx.request_timer(duration));
+    }
+
+    /// Stop the timer, if running.
+    pub fn stop(&mut self, ctx: &mut EventCtx) {
+        if let Some(token) = self.0.take() {
+            ctx.cancel_timer(token);
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns `true` if `event` is the expiration of this timer, which also
+    /// stops it.
+    pub fn expire(&mut self, event: Event) -> bool {
+        match (event, self.0) {
+            (Event::Timer(token), Some(own)) if token == own => {
+                self.0 = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 pub struct EventCtx {
     timers: Vec<(TimerToken, Duration), { Self::MAX_TIMERS }>,
+    cancelled_timers: Vec<TimerToken, { Self::MAX_CANCELLED_TIMERS }>,
     next_token: u32,
     place_requested: bool,
     paint_requested: bool,
+    /// Union of the screen regions invalidated during the event pass.
+    paint_region: Option<Rect>,
     anim_frame_scheduled: bool,
     page_count: Option<usize>,
     button_request: Option<ButtonRequest>,
     root_repaint_requested: bool,
     swipe_disable_req: bool,
     swipe_enable_req: bool,
+    /// Motion of the swipe the current `Event::Swipe` belongs to.
+    #[cfg(feature = "touch")]
+    swipe_motion: Option<SwipeMotion>,
 }
 
 impl EventCtx {
@@ -541,19 +618,26 @@ impl EventCtx {
     /// Maximum amount of timers requested in one event tick.
     const MAX_TIMERS: usize = 4;
 
+    /// Maximum amount of cancelled timers waiting to be fired.
+    const MAX_CANCELLED_TIMERS: usize = 8;
+
     pub fn new() -> Self {
         Self {
             timers: Vec::new(),
+            cancelled_timers: Vec::new(),
             next_token: Self::STARTING_TIMER_TOKEN,
             place_requested: true, // We need to perform a place pass in the beginning.
             paint_requested: false, /* We also need to paint, but this is supplemented by
                                     * `Child::marked_for_paint` being true. */
+            paint_region: None,
             anim_frame_scheduled: false,
             page_count: None,
             button_request: None,
             root_repaint_requested: false,
             swipe_disable_req: false,
             swipe_enable_req: false,
+            #[cfg(feature = "touch")]
+            swipe_motion: None,
         }
     }
 
@@ -575,6 +659,25 @@ impl EventCtx {
     /// again by the nearest `Child` wrapper.
     pub fn request_paint(&mut self) {
         self.paint_requested = true;
+        self.paint_region = Some(screen());
+    }
+
+    /// Like `request_paint`, but only the content inside `region` has changed.
+    /// Displays supporting partial updates then redraw just the union of all
+    /// the requested regions.
+    pub fn request_paint_region(&mut self, region: Rect) {
+        self.paint_requested = true;
+        if !region.is_empty() {
+            self.paint_region = Some(match self.paint_region {
+                Some(r) => r.union(region),
+                None => region,
+            });
+        }
+    }
+
+    /// Part of the screen invalidated since the last `clear`, if any.
+    pub fn paint_region(&self) -> Option<Rect> {
+        self.paint_region
     }
 
     /// Request a timer event to be delivered after `deadline` elapses.
@@ -584,6 +687,39 @@ impl EventCtx {
         token
     }
 
+    /// Cancel a previously requested timer, its event will never be delivered.
+    pub fn cancel_timer(&mut self, token: TimerToken) {
+        if token == Self::ANIM_FRAME_TIMER {
+            // Shared by all the components, cannot be cancelled.
+            return;
+        }
+        if let Some(index) = self.timers.iter().position(|(t, _)| *t == token) {
+            // Not yet handed over to be scheduled, just forget it.
+            self.timers.remove(index);
+            return;
+        }
+        if self.cancelled_timers.contains(&token) {
+            return;
+        }
+        if self.cancelled_timers.is_full() {
+            // Forget the oldest one. Should it still fire, the component ignores a token it
+            // doesn't know anymore.
+            self.cancelled_timers.remove(0);
+        }
+        unwrap!(self.cancelled_timers.push(token));
+    }
+
+    /// Returns `true` if the fired timer has been cancelled and its event must
+    /// not be delivered. The cancellation is forgotten afterwards.
+    pub fn take_cancelled_timer(&mut self, token: TimerToken) -> bool {
+        if let Some(index) = self.cancelled_timers.iter().position(|t| *t == token) {
+            self.cancelled_timers.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Request an animation frame timer to fire as soon as possible.
     pub fn request_anim_frame(&mut self) {
         if !self.anim_frame_scheduled {
@@ -644,9 +780,22 @@ impl EventCtx {
         self.swipe_enable_req
     }
 
+    /// Displacement and velocity of the swipe, available while handling an
+    /// `Event::Swipe` sent by a flow.
+    #[cfg(feature = "touch")]
+    pub fn swipe_motion(&self) -> Option<SwipeMotion> {
+        self.swipe_motion
+    }
+
+    #[cfg(feature = "touch")]
+    pub fn set_swipe_motion(&mut self, motion: Option<SwipeMotion>) {
+        self.swipe_motion = motion;
+    }
+
     pub fn clear(&mut self) {
         self.place_requested = false;
         self.paint_requested = false;
+        self.paint_region = None;
         self.anim_frame_scheduled = false;
         self.page_count = None;
         #[cfg(feature = "ui_debug")]
@@ -678,3 +827,163 @@ impl EventCtx {
         token
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        geometry::Point,
+        shape::{BoundsRenderer, Viewport},
+    };
+
+    /// Hand over the requested timers to be scheduled, like the layout does
+    /// after each event.
+    fn drain(ctx: &mut EventCtx) -> std::vec::Vec<TimerToken> {
+        core::iter::from_fn(|| ctx.pop_timer())
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    /// Component counting how many times it was rendered.
+    struct Counter(core::cell::Cell<usize>);
+
+    impl Component for Counter {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            None
+        }
+
+        fn paint(&mut self) {}
+
+        fn render<'s>(&'s self, _target: &mut impl Renderer<'s>) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn child_render_outside_region() {
+        let mut child = Child::new(Counter(core::cell::Cell::new(0)));
+        let rendered = |child: &Child<Counter>| child.inner().0.get();
+
+        // Not placed yet, always rendered.
+        child.render(&mut BoundsRenderer::new(Viewport::new(Rect::zero())));
+        assert_eq!(rendered(&child), 1);
+
+        child.place(Rect::new(Point::new(0, 50), Point::new(100, 100)));
+        child.render(&mut BoundsRenderer::new(Viewport::new(screen())));
+        assert_eq!(rendered(&child), 2);
+
+        let above = Rect::new(Point::new(0, 0), Point::new(100, 50));
+        child.render(&mut BoundsRenderer::new(Viewport::new(above)));
+        assert_eq!(rendered(&child), 2);
+
+        let overlapping = Rect::new(Point::new(50, 40), Point::new(60, 60));
+        child.render(&mut BoundsRenderer::new(Viewport::new(overlapping)));
+        assert_eq!(rendered(&child), 3);
+    }
+
+    #[test]
+    fn unchanged_child_not_rendered() {
+        let mut ctx = EventCtx::new();
+        let top = Rect::new(Point::new(0, 0), Point::new(100, 50));
+        let bottom = Rect::new(Point::new(0, 50), Point::new(100, 100));
+        let mut changed = Child::new(Counter(core::cell::Cell::new(0)));
+        let mut unchanged = Child::new(Counter(core::cell::Cell::new(0)));
+        changed.place(top);
+        unchanged.place(bottom);
+
+        // Only the first child changed, repainting just its area.
+        changed.mutate(&mut ctx, |ctx, _| ctx.request_paint_region(top));
+        let mut renderer = BoundsRenderer::new(Viewport::new(unwrap!(ctx.paint_region())));
+        changed.render(&mut renderer);
+        unchanged.render(&mut renderer);
+        assert_eq!(changed.inner().0.get(), 1);
+        assert_eq!(unchanged.inner().0.get(), 0);
+    }
+
+    #[test]
+    fn paint_region_union() {
+        let mut ctx = EventCtx::new();
+        assert!(ctx.paint_region().is_none());
+        ctx.request_paint_region(Rect::new(Point::new(10, 10), Point::new(20, 20)));
+        ctx.request_paint_region(Rect::zero());
+        ctx.request_paint_region(Rect::new(Point::new(30, 5), Point::new(40, 15)));
+        assert!(ctx.paint_region() == Some(Rect::new(Point::new(10, 5), Point::new(40, 20))));
+
+        // A full repaint covers everything.
+        ctx.request_paint();
+        ctx.request_paint_region(Rect::new(Point::new(10, 10), Point::new(20, 20)));
+        assert!(ctx.paint_region() == Some(screen()));
+
+        ctx.clear();
+        assert!(ctx.paint_region().is_none());
+    }
+
+    #[test]
+    fn cancel_pending_timer() {
+        let mut ctx = EventCtx::new();
+        let first = ctx.request_timer(Duration::from_millis(100));
+        let second = ctx.request_timer(Duration::from_millis(200));
+        ctx.cancel_timer(first);
+        assert!(drain(&mut ctx) == &[second]);
+        assert!(!ctx.take_cancelled_timer(first));
+    }
+
+    #[test]
+    fn cancel_fired_timer() {
+        let mut ctx = EventCtx::new();
+        let token = ctx.request_timer(Duration::from_millis(100));
+        assert!(drain(&mut ctx) == &[token]);
+        // The timer has been scheduled, and possibly fired already, but not delivered.
+        ctx.cancel_timer(token);
+        ctx.clear();
+        assert!(ctx.take_cancelled_timer(token));
+        // Only swallowed once.
+        assert!(!ctx.take_cancelled_timer(token));
+    }
+
+    #[test]
+    fn cancel_anim_frame() {
+        let mut ctx = EventCtx::new();
+        ctx.request_anim_frame();
+        ctx.cancel_timer(EventCtx::ANIM_FRAME_TIMER);
+        assert!(drain(&mut ctx) == &[EventCtx::ANIM_FRAME_TIMER]);
+        assert!(!ctx.take_cancelled_timer(EventCtx::ANIM_FRAME_TIMER));
+    }
+
+    #[test]
+    fn timer_expire() {
+        let mut ctx = EventCtx::new();
+        let mut timer = Timer::new();
+        timer.start(&mut ctx, Duration::from_millis(100));
+        let tokens = drain(&mut ctx);
+        assert!(timer.is_running());
+        assert!(!timer.expire(Event::Timer(EventCtx::ANIM_FRAME_TIMER)));
+        assert!(timer.expire(Event::Timer(tokens[0])));
+        assert!(!timer.is_running());
+        // Expires only once.
+        assert!(!timer.expire(Event::Timer(tokens[0])));
+    }
+
+    #[test]
+    fn timer_stop_and_restart() {
+        let mut ctx = EventCtx::new();
+        let mut timer = Timer::new();
+        timer.start(&mut ctx, Duration::from_millis(100));
+        let first = drain(&mut ctx)[0];
+        timer.start(&mut ctx, Duration::from_millis(100));
+        let second = drain(&mut ctx)[0];
+        // The first timer fires after being replaced, it is not delivered.
+        assert!(ctx.take_cancelled_timer(first));
+        assert!(!ctx.take_cancelled_timer(second));
+        timer.stop(&mut ctx);
+        assert!(!timer.is_running());
+        assert!(ctx.take_cancelled_timer(second));
+        assert!(!timer.expire(Event::Timer(second)));
+    }
+}
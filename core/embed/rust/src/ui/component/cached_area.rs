@@ -0,0 +1,142 @@
+use crate::ui::{
+    component::{Child, Component, Event, EventCtx},
+    display::Color,
+    geometry::{Offset, Rect},
+    shape::{self, render_on_canvas, ImageBuffer, Renderer, Rgb565Canvas},
+};
+
+/// Renders a rarely changing component subtree once into an offscreen buffer
+/// and blits it on subsequent frames.
+///
+/// The buffer is re-rendered only after a paint has been requested somewhere
+/// inside of the subtree during `event` or `mutate`, or after an explicit
+/// `invalidate`. Short-lived visual feedback (e.g. a pressed button) should go
+/// through `mutate_transient` instead, and the owner renders the affected parts
+/// on top of the cached image. As there is only a single image buffer, the
+/// content is rendered directly whenever the buffer is not available.
+pub struct CachedArea<T> {
+    inner: Child<T>,
+    area: Rect,
+    bg_color: Color,
+    buffer: Option<ImageBuffer<Rgb565Canvas<'static>>>,
+    valid: bool,
+}
+
+impl<T> CachedArea<T>
+where
+    T: Component,
+{
+    pub fn new(bg_color: Color, inner: T) -> Self {
+        Self {
+            inner: Child::new(inner),
+            area: Rect::zero(),
+            bg_color,
+            buffer: None,
+            valid: false,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        self.inner.inner()
+    }
+
+    /// Access the inner component mutably, the cache is refreshed if a paint
+    /// was requested inside.
+    pub fn mutate<F, U>(&mut self, ctx: &mut EventCtx, component_func: F) -> U
+    where
+        F: FnOnce(&mut EventCtx, &mut T) -> U,
+    {
+        let result = self.inner.mutate(ctx, component_func);
+        self.refresh();
+        result
+    }
+
+    /// Access the inner component mutably without touching the cache. Paint
+    /// requests from inside are propagated, but the cached image keeps showing
+    /// the settled state of the content.
+    pub fn mutate_transient<F, U>(&mut self, ctx: &mut EventCtx, component_func: F) -> U
+    where
+        F: FnOnce(&mut EventCtx, &mut T) -> U,
+    {
+        let result = self.inner.mutate(ctx, component_func);
+        self.inner.skip_paint();
+        result
+    }
+
+    /// Force re-rendering of the content on the next refresh.
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+    }
+
+    /// Re-render the content into the buffer if it has changed.
+    fn refresh(&mut self) {
+        if self.inner.will_paint() {
+            self.inner.skip_paint();
+            self.valid = false;
+        }
+        if self.valid {
+            return;
+        }
+        let Self {
+            inner,
+            area,
+            bg_color,
+            buffer,
+            ..
+        } = self;
+        if let Some(buffer) = buffer {
+            let origin: Offset = (-area.top_left()).into();
+            render_on_canvas(buffer.canvas(), Some(*bg_color), |target| {
+                target.with_origin(origin, &|target| inner.render(target));
+            });
+            self.valid = true;
+        }
+    }
+}
+
+impl<T> Component for CachedArea<T>
+where
+    T: Component,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = self.inner.place(bounds);
+        // Release the buffer first, there is only one.
+        self.buffer = None;
+        self.buffer = ImageBuffer::new(self.area.size());
+        self.valid = false;
+        self.refresh();
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.mutate(ctx, |ctx, c| c.event(ctx, event))
+    }
+
+    fn paint(&mut self) {}
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        match &self.buffer {
+            Some(buffer) if self.valid => {
+                shape::RawImage::new(self.area, buffer.view()).render(target);
+            }
+            _ => self.inner.render(target),
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.inner.bounds(sink)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for CachedArea<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        self.inner.trace(t)
+    }
+}
@@ -0,0 +1,273 @@
+use heapless::Vec;
+
+use crate::{
+    error::Error,
+    time::{Duration, Instant},
+    ui::{
+        animation::Animation,
+        component::{Component, Event, EventCtx, Swipe, SwipeDirection},
+        display::Color,
+        geometry::{Offset, Rect},
+        shape::{self, Renderer},
+        util::animation_disabled,
+    },
+};
+
+const SLIDE_DURATION: Duration = Duration::from_millis(250);
+const DOT_RADIUS: i16 = 3;
+const DOT_SPACING: i16 = 12;
+/// Height of the area below the items reserved for the page indicator.
+const DOTS_HEIGHT: i16 = 16;
+
+pub enum CarouselMsg<T> {
+    /// Swiped to the item with the given index.
+    PageChanged(usize),
+    /// Message of the active item.
+    Content(T),
+}
+
+/// Slide between the previous and the active item, `offset` goes from 0 to
+/// the width of the items.
+struct Slide {
+    from: usize,
+    dir: SwipeDirection,
+    offset: Animation<i16>,
+}
+
+/// Shows one of at most `N` items at a time and switches between them by
+/// horizontal swipes. Dots below the items indicate the active one. With a
+/// single item, swiping is disabled. There must be at least one item.
+pub struct Carousel<T, const N: usize> {
+    items: Vec<T, N>,
+    active: usize,
+    swipe: Swipe,
+    slide: Option<Slide>,
+    content_area: Rect,
+    dots_area: Rect,
+    dot_active: Color,
+    dot_inactive: Color,
+}
+
+impl<T, const N: usize> Carousel<T, N>
+where
+    T: Component,
+{
+    pub fn new(items: Vec<T, N>) -> Result<Self, Error> {
+        if items.is_empty() {
+            return Err(value_error!("Carousel needs at least one item."));
+        }
+        let swipe = if items.len() > 1 {
+            Swipe::horizontal()
+        } else {
+            Swipe::new()
+        };
+        Ok(Self {
+            items,
+            active: 0,
+            swipe,
+            slide: None,
+            content_area: Rect::zero(),
+            dots_area: Rect::zero(),
+            dot_active: Color::white(),
+            dot_inactive: Color::rgb(0x66, 0x66, 0x66),
+        })
+    }
+
+    pub fn with_dot_colors(mut self, active: Color, inactive: Color) -> Self {
+        self.dot_active = active;
+        self.dot_inactive = inactive;
+        self
+    }
+
+    pub fn active_page(&self) -> usize {
+        self.active
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn active_item(&self) -> &T {
+        &self.items[self.active]
+    }
+
+    fn change_page(&mut self, ctx: &mut EventCtx, dir: SwipeDirection) -> Option<usize> {
+        let to = match dir {
+            SwipeDirection::Left if self.active + 1 < self.items.len() => self.active + 1,
+            SwipeDirection::Right if self.active > 0 => self.active - 1,
+            _ => return None,
+        };
+        if !animation_disabled() {
+            self.slide = Some(Slide {
+                from: self.active,
+                dir,
+                offset: Animation::new(
+                    0,
+                    self.content_area.width(),
+                    SLIDE_DURATION,
+                    Instant::now(),
+                ),
+            });
+            ctx.request_anim_frame();
+        }
+        self.active = to;
+        ctx.request_paint();
+        Some(to)
+    }
+
+    fn render_dots<'s>(&self, target: &mut impl Renderer<'s>) {
+        let count = self.items.len() as i16;
+        if count < 2 {
+            return;
+        }
+        let center = self.dots_area.center();
+        let first = center - Offset::x((count - 1) * DOT_SPACING / 2);
+        for i in 0..count {
+            let color = if i as usize == self.active {
+                self.dot_active
+            } else {
+                self.dot_inactive
+            };
+            shape::Circle::new(first + Offset::x(i * DOT_SPACING), DOT_RADIUS)
+                .with_fg(color)
+                .with_bg(color)
+                .render(target);
+        }
+    }
+}
+
+impl<T, const N: usize> Component for Carousel<T, N>
+where
+    T: Component,
+{
+    type Msg = CarouselMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (content_area, dots_area) = bounds.split_bottom(DOTS_HEIGHT);
+        self.content_area = content_area;
+        self.dots_area = dots_area;
+        self.swipe.place(bounds);
+        for item in self.items.iter_mut() {
+            item.place(content_area);
+        }
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
+            if let Some(slide) = &self.slide {
+                if slide.offset.finished(Instant::now()) {
+                    self.slide = None;
+                } else {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+        }
+
+        if let Some(dir) = self.swipe.event(ctx, event) {
+            if let Some(page) = self.change_page(ctx, dir) {
+                // The new item appears on the screen.
+                self.items[page].event(ctx, Event::RequestPaint);
+                return Some(CarouselMsg::PageChanged(page));
+            }
+        }
+
+        self.items[self.active]
+            .event(ctx, event)
+            .map(CarouselMsg::Content)
+    }
+
+    fn paint(&mut self) {}
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        match &self.slide {
+            Some(slide) => {
+                let width = self.content_area.width();
+                let shift = slide.offset.value(Instant::now());
+                // Swiping left moves the items to the left, the new one follows from the
+                // right.
+                let (from_offset, to_offset) = match slide.dir {
+                    SwipeDirection::Left => (-shift, width - shift),
+                    _ => (shift, shift - width),
+                };
+                target.in_clip(self.content_area, &|target| {
+                    target.with_origin(Offset::x(from_offset), &|target| {
+                        self.items[slide.from].render(target);
+                    });
+                    target.with_origin(Offset::x(to_offset), &|target| {
+                        self.items[self.active].render(target);
+                    });
+                });
+            }
+            None => self.items[self.active].render(target),
+        }
+        self.render_dots(target);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.content_area);
+        sink(self.dots_area);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, const N: usize> crate::trace::Trace for Carousel<T, N>
+where
+    T: Component + crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("Carousel");
+        t.int("active_page", self.active as i64);
+        t.int("page_count", self.items.len() as i64);
+        t.child("content", self.active_item());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::component::Empty;
+
+    fn carousel(count: usize) -> Result<Carousel<Empty, 3>, Error> {
+        let mut items = Vec::new();
+        for _ in 0..count {
+            unwrap!(items.push(Empty));
+        }
+        Carousel::new(items)
+    }
+
+    #[test]
+    fn rejects_no_items() {
+        assert!(carousel(0).is_err());
+    }
+
+    #[test]
+    fn single_item() {
+        let carousel = unwrap!(carousel(1));
+        assert_eq!(carousel.page_count(), 1);
+        assert_eq!(carousel.active_page(), 0);
+    }
+
+    #[test]
+    fn change_page_stays_in_bounds() {
+        let mut ctx = EventCtx::new();
+        let mut carousel = unwrap!(carousel(3));
+        assert_eq!(carousel.change_page(&mut ctx, SwipeDirection::Right), None);
+        assert_eq!(
+            carousel.change_page(&mut ctx, SwipeDirection::Left),
+            Some(1)
+        );
+        assert_eq!(
+            carousel.change_page(&mut ctx, SwipeDirection::Left),
+            Some(2)
+        );
+        assert_eq!(carousel.change_page(&mut ctx, SwipeDirection::Left), None);
+        assert_eq!(carousel.active_page(), 2);
+        assert_eq!(
+            carousel.change_page(&mut ctx, SwipeDirection::Right),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Overlay drawn on top of the layout, outlining the area of every placed
+//! component and shading the touch areas expanded beyond the component, to
+//! see what a touch is going to hit. Uses the same data as
+//! `Component::bounds`, so only the components implementing it show up.
+
+use heapless::Vec;
+
+use crate::ui::{
+    display::Color,
+    geometry::Rect,
+    shape::{self, Renderer},
+};
+
+const MAX_RECTS: usize = 64;
+const MAX_TOUCH_AREAS: usize = 32;
+
+const TOUCH_AREA_COLOR: Color = Color::rgb(0xFF, 0x00, 0xFF);
+const TOUCH_AREA_ALPHA: u8 = 64;
+
+static mut ENABLED: bool = false;
+static mut TOUCH_AREAS: Vec<Rect, MAX_TOUCH_AREAS> = Vec::new();
+
+fn touch_areas() -> &'static mut Vec<Rect, MAX_TOUCH_AREAS> {
+    // SAFETY: single-threaded access
+    unsafe { &mut *core::ptr::addr_of_mut!(TOUCH_AREAS) }
+}
+
+pub fn is_enabled() -> bool {
+    // SAFETY: single-threaded access
+    unsafe { ENABLED }
+}
+
+/// The layout needs to be repainted completely afterwards.
+pub fn set_enabled(enabled: bool) {
+    // SAFETY: single-threaded access
+    unsafe {
+        ENABLED = enabled;
+    }
+}
+
+/// Report the touch area of a component expanded to `area`, called from
+/// `Component::bounds`.
+pub fn touch_area(area: Rect) {
+    // Overflowing areas are not shaded.
+    let _ = touch_areas().push(area);
+}
+
+/// Pseudorandom color, so that neighboring rectangles are distinguishable.
+pub fn wireframe_color(r: Rect) -> Color {
+    let w = r.width() as u16;
+    let h = r.height() as u16;
+    Color::from_u16(w.rotate_right(w.into()).wrapping_add(h * 8))
+}
+
+/// Draw the overlay of the rectangles `bounds` feeds to its sink.
+pub fn render<'s>(target: &mut impl Renderer<'s>, bounds: impl FnOnce(&mut dyn FnMut(Rect))) {
+    touch_areas().clear();
+    // Collected first so that the outlines are drawn over the touch areas.
+    let mut rects: Vec<Rect, MAX_RECTS> = Vec::new();
+    bounds(&mut |r| {
+        let _ = rects.push(r);
+    });
+
+    for area in touch_areas().iter() {
+        shape::Bar::new(*area)
+            .with_bg(TOUCH_AREA_COLOR)
+            .with_alpha(TOUCH_AREA_ALPHA)
+            .render(target);
+    }
+    for r in rects.iter() {
+        shape::Bar::new(*r)
+            .with_fg(wireframe_color(*r))
+            .render(target);
+    }
+}
@@ -0,0 +1,117 @@
+//! Named click targets, so that debuglink can click a component without
+//! knowing its position on the screen. Components register their targets while
+//! being placed, the registry is reset whenever the root component is placed.
+//! The registry is global, the layout that placed its components last can
+//! claim it as its owner.
+
+use heapless::{String, Vec};
+
+use crate::ui::geometry::{Point, Rect};
+
+const MAX_TARGETS: usize = 32;
+const MAX_NAME_LEN: usize = 24;
+const SEPARATOR: char = '/';
+
+type Name = String<MAX_NAME_LEN>;
+
+struct Target {
+    name: Name,
+    area: Rect,
+}
+
+static mut TARGETS: Vec<Target, MAX_TARGETS> = Vec::new();
+
+/// Identity of the layout owning the targets, zero if not known.
+static mut OWNER: usize = 0;
+
+fn targets() -> &'static mut Vec<Target, MAX_TARGETS> {
+    // SAFETY: single-threaded access
+    unsafe { &mut *core::ptr::addr_of_mut!(TARGETS) }
+}
+
+/// Forget all the targets, before the component tree is placed again.
+pub fn clear() {
+    targets().clear();
+    set_owner(0);
+}
+
+/// Claim the targets for the layout identified by `owner`, right after it has
+/// placed its components.
+pub fn set_owner(owner: usize) {
+    // SAFETY: single-threaded access
+    unsafe { OWNER = owner };
+}
+
+/// Whether the targets are the ones registered by the layout `owner`.
+pub fn is_owner(owner: usize) -> bool {
+    // SAFETY: single-threaded access
+    owner != 0 && unsafe { OWNER } == owner
+}
+
+/// Register the component placed at `area` under the name made of `parts`
+/// joined by slashes, e.g. `["pin", "digit", "5"]` becomes `pin/digit/5`. A
+/// later registration of the same name replaces the earlier one.
+pub fn register(parts: &[&str], area: Rect) {
+    let mut name = Name::new();
+    for (i, part) in parts.iter().enumerate() {
+        let separated = i == 0 || name.push(SEPARATOR).is_ok();
+        if !separated || name.push_str(part).is_err() {
+            // Name too long, not worth a panic in a debug facility.
+            return;
+        }
+    }
+    let targets = targets();
+    targets.retain(|t| t.name != name);
+    // Overflowing targets are not clickable by name.
+    let _ = targets.push(Target { name, area });
+}
+
+/// Point to click in order to hit the target `name`.
+pub fn find(name: &str) -> Option<Point> {
+    targets()
+        .iter()
+        .find(|t| t.name == name)
+        .map(|t| t.area.center())
+}
+
+/// Call `func` with the name of each registered target.
+pub fn for_each(mut func: impl FnMut(&str)) {
+    for t in targets().iter() {
+        func(&t.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_and_find() {
+        clear();
+        let area = Rect::new(Point::new(10, 20), Point::new(30, 40));
+        register(&["pin", "digit", "5"], area);
+        register(&["confirm"], Rect::zero());
+        register(&["confirm"], area);
+        assert_eq!(find("pin/digit/5"), Some(Point::new(20, 30)));
+        assert_eq!(find("confirm"), Some(Point::new(20, 30)));
+        assert_eq!(find("erase"), None);
+
+        let mut count = 0;
+        for_each(|_| count += 1);
+        assert_eq!(count, 2);
+
+        clear();
+        assert_eq!(find("confirm"), None);
+    }
+
+    #[test]
+    fn owner_reset_by_clear() {
+        clear();
+        assert!(!is_owner(0));
+        set_owner(42);
+        assert!(is_owner(42));
+        assert!(!is_owner(7));
+        clear();
+        assert!(!is_owner(42));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{component::Event, event::TouchEvent, geometry::Point},
+};
+
+/// Gestures recognized on top of the raw touch events.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum GestureMsg {
+    /// Two taps close to each other in quick succession, carrying the point of
+    /// the first tap.
+    DoubleTap(Point),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum State {
+    Idle,
+    /// First touch is in progress.
+    FirstDown(Point),
+    /// First tap ended, waiting for the second one.
+    FirstUp(Point, Instant),
+    /// Second touch is in progress.
+    SecondDown(Point),
+}
+
+/// Helper recognizing gestures from the touch events passed to
+/// `Component::event`. The events themselves are not consumed, so the
+/// component keeps handling them as before.
+#[derive(Clone)]
+pub struct GestureDetector {
+    interval: Duration,
+    slop: i16,
+    state: State,
+}
+
+impl GestureDetector {
+    /// Maximum delay between the end of the first tap and the start of the
+    /// second one.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(300);
+    /// Maximum distance, in both axes, between the points of a double tap.
+    pub const DEFAULT_SLOP: i16 = 20;
+
+    pub const fn new() -> Self {
+        Self {
+            interval: Self::DEFAULT_INTERVAL,
+            slop: Self::DEFAULT_SLOP,
+            state: State::Idle,
+        }
+    }
+
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub const fn with_slop(mut self, slop: i16) -> Self {
+        self.slop = slop;
+        self
+    }
+
+    pub fn reset(&mut self) {
+        self.state = State::Idle;
+    }
+
+    fn is_near(&self, a: Point, b: Point) -> bool {
+        let d = (a - b).abs();
+        d.x <= self.slop && d.y <= self.slop
+    }
+
+    pub fn event(&mut self, event: Event) -> Option<GestureMsg> {
+        let Event::Touch(touch) = event else {
+            return None;
+        };
+        let (state, msg) = match (self.state, touch) {
+            (State::FirstUp(first, ended), TouchEvent::TouchStart(pos))
+                if self.is_near(first, pos)
+                    && Instant::now().saturating_duration_since(ended) <= self.interval =>
+            {
+                (State::SecondDown(first), None)
+            }
+            (_, TouchEvent::TouchStart(pos)) => (State::FirstDown(pos), None),
+            (State::FirstDown(first) | State::SecondDown(first), TouchEvent::TouchMove(pos))
+                if !self.is_near(first, pos) =>
+            {
+                // Moving too far turns the touch into a swipe.
+                (State::Idle, None)
+            }
+            (State::FirstDown(first), TouchEvent::TouchEnd(pos)) if self.is_near(first, pos) => {
+                (State::FirstUp(first, Instant::now()), None)
+            }
+            (State::SecondDown(first), TouchEvent::TouchEnd(pos)) if self.is_near(first, pos) => {
+                (State::Idle, Some(GestureMsg::DoubleTap(first)))
+            }
+            (state, TouchEvent::TouchMove(_)) => (state, None),
+            (_, TouchEvent::TouchEnd(_)) => (State::Idle, None),
+        };
+        self.state = state;
+        msg
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for GestureDetector {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("GestureDetector");
+        let state = match self.state {
+            State::Idle => "Idle",
+            State::FirstDown(_) => "FirstDown",
+            State::FirstUp(..) => "FirstUp",
+            State::SecondDown(_) => "SecondDown",
+        };
+        t.string("state", state.into());
+        t.int("interval_ms", self.interval.to_millis() as i64);
+        t.int("slop", self.slop as i64);
+    }
+}
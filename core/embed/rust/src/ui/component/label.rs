@@ -1,20 +1,116 @@
 use crate::{
-    strutil::TString,
+    strutil::{ShortString, TString},
+    time::{Duration, Instant},
     ui::{
-        component::{Component, Event, EventCtx, Never},
+        animation::Animation,
+        component::{Component, Event, EventCtx, Never, Timer},
         display::Font,
         geometry::{Alignment, Insets, Offset, Point, Rect},
-        shape::Renderer,
+        shape::{self, Renderer},
+        util::animation_disabled,
     },
 };
 
-use super::{text::TextStyle, TextLayout};
+#[cfg(feature = "touch")]
+use crate::ui::event::SwipeEvent;
+
+use super::{
+    text::{
+        layout::{LayoutFit, LayoutSink},
+        util::truncate_middle,
+        TextStyle,
+    },
+    PageBreaking, TextLayout,
+};
+
+/// How long the marquee stays at the start of the text before scrolling.
+const MARQUEE_PAUSE: Duration = Duration::from_millis(1500);
+/// Scrolling speed of the marquee, in pixels per second.
+const MARQUEE_SPEED: u32 = 40;
+/// Space between the end of the text and its next copy in the marquee.
+const MARQUEE_GAP: i16 = 24;
+
+/// State of the `with_marquee` scrolling.
+#[derive(Clone)]
+struct Marquee {
+    pause: Timer,
+    scroll: Option<Animation<i16>>,
+}
+
+/// Text shown on a single line, measured in `place`.
+#[derive(Clone, Copy)]
+struct Line {
+    /// Width of the text, including the letter spacing.
+    width: i16,
+    /// Width of the text in its font, see `shape::Text::with_measured_width`.
+    font_width: i16,
+    /// Position of the text relative to the top left corner of the area, if
+    /// it is laid out as a single line without any hyphen or ellipsis.
+    pos: Option<Offset>,
+}
+
+/// Finds out whether the text is laid out as a single plain line, and where.
+struct LineSink {
+    len: usize,
+    start: Option<Point>,
+    plain: bool,
+}
+
+impl LineSink {
+    fn new(text: &str) -> Self {
+        Self {
+            len: text.len(),
+            start: None,
+            plain: true,
+        }
+    }
+
+    fn plain_start(&self) -> Option<Point> {
+        self.start.filter(|_| self.plain)
+    }
+}
+
+impl LayoutSink for LineSink {
+    fn text(&mut self, cursor: Point, _layout: &TextLayout, text: &str) {
+        self.plain &= self.start.is_none() && text.len() == self.len;
+        self.start = Some(cursor);
+    }
+
+    fn hyphen(&mut self, _cursor: Point, _layout: &TextLayout) {
+        self.plain = false;
+    }
+
+    fn ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {
+        self.plain = false;
+    }
+
+    fn prev_page_ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {
+        self.plain = false;
+    }
+
+    fn line_break(&mut self, _cursor: Point) {
+        self.plain = false;
+    }
+
+    fn out_of_bounds(&mut self) {
+        self.plain = false;
+    }
+}
 
 #[derive(Clone)]
 pub struct Label<'a> {
     text: TString<'a>,
     layout: TextLayout,
     vertical: Alignment,
+    /// Candidate fonts for `with_auto_fit`, empty if the font is fixed.
+    auto_fit: &'static [Font],
+    /// Area given to `place`, needed to lay out the text again on `set_text`.
+    fit_bounds: Rect,
+    marquee: Option<Marquee>,
+    truncate_middle: bool,
+    uppercase: bool,
+    /// Set in `place` if the text is shown on a single line.
+    line: Option<Line>,
 }
 
 impl<'a> Label<'a> {
@@ -23,6 +119,12 @@ impl<'a> Label<'a> {
             text,
             layout: TextLayout::new(style).with_align(align),
             vertical: Alignment::Start,
+            auto_fit: &[],
+            fit_bounds: Rect::zero(),
+            marquee: None,
+            truncate_middle: false,
+            uppercase: false,
+            line: None,
         }
     }
 
@@ -58,12 +160,60 @@ impl<'a> Label<'a> {
         self
     }
 
+    /// Use the first of `fonts` (ordered from the largest) in which the whole
+    /// text fits the placed area. If none does, the last one is used and the
+    /// text is cut with an ellipsis.
+    pub const fn with_auto_fit(mut self, fonts: &'static [Font]) -> Self {
+        self.auto_fit = fonts;
+        self
+    }
+
+    /// Show the text on a single line. If it does not fit, it is scrolled
+    /// horizontally in a loop, with a pause every time the start of the text
+    /// is back in place. With animations disabled, the text is cut with an
+    /// ellipsis instead.
+    ///
+    /// Scrolling starts when the label is attached and stops while a swipe
+    /// transition moves it.
+    pub const fn with_marquee(mut self) -> Self {
+        self.layout.style.page_breaking = PageBreaking::CutAndInsertEllipsis;
+        self.marquee = Some(Marquee {
+            pause: Timer::new(),
+            scroll: None,
+        });
+        self
+    }
+
+    /// Show the text on a single line. If it does not fit, its middle part is
+    /// replaced with an ellipsis, keeping both the beginning and the end
+    /// visible. Useful for addresses and other identifiers.
+    pub const fn with_middle_truncation(mut self) -> Self {
+        self.truncate_middle = true;
+        self
+    }
+
+    /// Show the text in uppercase, e.g. for headers. The text itself is kept
+    /// as is, it is converted every time it is laid out or rendered.
+    pub const fn with_uppercase(mut self) -> Self {
+        self.uppercase = true;
+        self
+    }
+
     pub fn text(&self) -> &TString<'a> {
         &self.text
     }
 
     pub fn set_text(&mut self, text: TString<'a>) {
         self.text = text;
+        self.line = None;
+        if let Some(marquee) = &mut self.marquee {
+            marquee.scroll = None;
+        }
+        // The font or the number of lines may change, and with it the vertical
+        // position of the text.
+        if !self.fit_bounds.is_empty() {
+            self.place(self.fit_bounds);
+        }
     }
 
     pub fn font(&self) -> Font {
@@ -80,21 +230,20 @@ impl<'a> Label<'a> {
 
     pub fn max_size(&self) -> Offset {
         let font = self.font();
-        let width = self.text.map(|c| font.text_width(c));
+        let width = self.text_width();
         Offset::new(width, font.text_max_height())
     }
 
     pub fn text_height(&self, width: i16) -> i16 {
         let bounds = Rect::from_top_left_and_size(Point::zero(), Offset::new(width, i16::MAX));
 
-        self.text
-            .map(|c| self.layout.with_bounds(bounds).fit_text(c).height())
+        self.map_text(|c| self.layout.with_bounds(bounds).fit_text(c).height())
     }
 
     pub fn text_area(&self) -> Rect {
         // XXX only works on single-line labels
         let available_width = self.layout.bounds.width();
-        let width = self.text.map(|c| self.font().text_width(c));
+        let width = self.text_width();
         let height = self.font().text_height();
         let cursor = self.layout.initial_cursor();
         let baseline = match self.alignment() {
@@ -105,9 +254,166 @@ impl<'a> Label<'a> {
         Rect::from_bottom_left_and_size(baseline, Offset::new(width, height))
     }
 
+    /// Maps the text as it is shown, i.e. uppercased if requested.
+    fn map_text<F, T>(&self, fun: F) -> T
+    where
+        F: for<'b> FnOnce(&'b str) -> T,
+    {
+        if self.uppercase {
+            self.text.map_uppercased(fun)
+        } else {
+            self.text.map(fun)
+        }
+    }
+
+    fn text_width(&self) -> i16 {
+        if let Some(line) = self.line {
+            #[cfg(feature = "ui_debug")]
+            debug_assert_eq!(line.width, self.measure_text_width(), "stale text width");
+            return line.width;
+        }
+        self.measure_text_width()
+    }
+
+    fn measure_text_width(&self) -> i16 {
+        self.map_text(|c| self.layout.style.text_width(c))
+    }
+
+    fn measure_line(&self, pos: Option<Offset>) -> Line {
+        Line {
+            width: self.measure_text_width(),
+            font_width: self.map_text(|c| self.font().text_width(c)),
+            pos,
+        }
+    }
+
+    /// Whether the text is scrolled rather than laid out normally.
+    fn is_marquee(&self) -> bool {
+        self.marquee.is_some()
+            && !animation_disabled()
+            && self.text_width() > self.layout.bounds.width()
+    }
+
+    fn marquee_event(&mut self, ctx: &mut EventCtx, event: Event) {
+        let overflows = self.is_marquee();
+        let distance = self.text_width() + MARQUEE_GAP;
+        let Some(marquee) = &mut self.marquee else {
+            return;
+        };
+        match event {
+            Event::Attach(_) => {
+                marquee.scroll = None;
+                if overflows {
+                    marquee.pause.start(ctx, MARQUEE_PAUSE);
+                } else {
+                    marquee.pause.stop(ctx);
+                }
+                ctx.request_paint();
+            }
+            #[cfg(feature = "touch")]
+            Event::Swipe(SwipeEvent::Move(..)) => {
+                marquee.pause.stop(ctx);
+                if marquee.scroll.take().is_some() {
+                    ctx.request_paint();
+                }
+            }
+            #[cfg(feature = "touch")]
+            Event::Swipe(SwipeEvent::End(_)) if overflows => {
+                // Swiped back to the same screen.
+                marquee.pause.start(ctx, MARQUEE_PAUSE);
+            }
+            _ if marquee.pause.expire(event) => {
+                let duration = Duration::from_millis(distance as u32 * 1000 / MARQUEE_SPEED);
+                marquee.scroll = Some(Animation::new(0, distance, duration, Instant::now()));
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            }
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => {
+                let Some(scroll) = &marquee.scroll else {
+                    return;
+                };
+                if scroll.finished(Instant::now()) {
+                    // The next copy of the text is now exactly where the first one started.
+                    marquee.scroll = None;
+                    marquee.pause.start(ctx, MARQUEE_PAUSE);
+                } else {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+    }
+
+    fn render_marquee<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        let offset = self
+            .marquee
+            .as_ref()
+            .and_then(|m| m.scroll.as_ref())
+            .map_or(0, |scroll| scroll.value(Instant::now()));
+        let distance = self.text_width() + MARQUEE_GAP;
+        let baseline = self.layout.initial_cursor();
+        target.in_window(self.layout.bounds, &|target| {
+            for start in [-offset, distance - offset] {
+                self.map_text(|c| {
+                    shape::Text::new(baseline + Offset::x(start), c)
+                        .with_font(self.font())
+                        .with_letter_spacing(self.layout.style.letter_spacing)
+                        .with_fg(self.layout.style.text_color)
+                        .render(target);
+                });
+            }
+        });
+    }
+
+    fn truncated(&self) -> ShortString {
+        let width = self.layout.bounds.width();
+        self.map_text(|c| truncate_middle(c, self.layout.style.metrics(), width))
+    }
+
+    fn fit_font(&mut self, bounds: Rect) {
+        let Some(&smallest) = self.auto_fit.last() else {
+            return;
+        };
+        let fits = |font: Font| {
+            let mut layout = self.layout.with_bounds(bounds);
+            layout.style.text_font = font;
+            let fit = self.map_text(|c| layout.fit_text(c));
+            matches!(fit, LayoutFit::Fitting { .. })
+        };
+        match self.auto_fit.iter().copied().find(|&font| fits(font)) {
+            Some(font) => self.layout.style.text_font = font,
+            None => {
+                self.layout.style.text_font = smallest;
+                self.layout.style.page_breaking = PageBreaking::CutAndInsertEllipsis;
+            }
+        }
+    }
+
+    /// Renders a text laid out as a single plain line at `pos`, without
+    /// measuring it again.
+    fn render_line<'s>(&self, target: &mut impl Renderer<'s>, line: Line, pos: Offset, alpha: u8) {
+        let style = &self.layout.style;
+        self.map_text(|c| {
+            shape::Text::new(self.layout.bounds.top_left() + pos, c)
+                .with_font(style.text_font)
+                .with_letter_spacing(style.letter_spacing)
+                .with_underline(style.underline)
+                .with_strikethrough(style.strikethrough)
+                .with_fg(style.text_color)
+                .with_alpha(alpha)
+                .with_measured_width(line.font_width)
+                .render(target);
+        });
+    }
+
     pub fn render_with_alpha<'s>(&self, target: &mut impl Renderer<'s>, alpha: u8) {
-        self.text
-            .map(|c| self.layout.render_text_with_alpha(c, target, alpha));
+        match self.line {
+            Some(line @ Line { pos: Some(pos), .. }) => self.render_line(target, line, pos, alpha),
+            _ => self.map_text(|c| {
+                self.layout.render_text_with_alpha(c, target, alpha);
+            }),
+        }
     }
 }
 
@@ -115,9 +421,24 @@ impl Component for Label<'_> {
     type Msg = Never;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let height = self
-            .text
-            .map(|c| self.layout.with_bounds(bounds).fit_text(c).height());
+        self.fit_bounds = bounds;
+        self.fit_font(bounds);
+        let height = if self.marquee.is_some() || self.truncate_middle {
+            // Measured on every render of the marquee, keep it.
+            self.line = Some(self.measure_line(None));
+            self.font().text_max_height().min(bounds.height())
+        } else {
+            let layout = self.layout.with_bounds(bounds);
+            let (fit, start) = self.map_text(|c| {
+                let mut sink = LineSink::new(c);
+                let fit = layout.layout_text(c, &mut layout.initial_cursor(), &mut sink);
+                (fit, sink.plain_start())
+            });
+            // A plain single line is rendered directly from the measurements,
+            // without laying the text out again on every frame.
+            self.line = start.map(|start| self.measure_line(Some(start - bounds.top_left())));
+            fit.height()
+        };
         let diff = bounds.height() - height;
         let insets = match self.vertical {
             Alignment::Start => Insets::bottom(diff),
@@ -128,16 +449,27 @@ impl Component for Label<'_> {
         self.layout.bounds
     }
 
-    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.marquee_event(ctx, event);
         None
     }
 
     fn paint(&mut self) {
-        self.text.map(|c| self.layout.render_text(c));
+        if self.truncate_middle {
+            self.layout.render_text(&self.truncated());
+        } else {
+            self.map_text(|c| self.layout.render_text(c));
+        }
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        self.text.map(|c| self.layout.render_text2(c, target));
+        if self.is_marquee() {
+            self.render_marquee(target);
+        } else if self.truncate_middle {
+            self.layout.render_text2(&self.truncated(), target);
+        } else {
+            self.render_with_alpha(target, 255);
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -153,3 +485,54 @@ impl crate::trace::Trace for Label<'_> {
         t.string("text", self.text);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        display::Color,
+        shape::{BoundsRenderer, Viewport},
+    };
+
+    fn renderer() -> BoundsRenderer {
+        BoundsRenderer::new(Viewport::new(Rect::new(
+            Point::zero(),
+            Point::new(240, 240),
+        )))
+    }
+
+    const STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    fn placed(text: &'static str, align: Alignment) -> Label<'static> {
+        let mut label = Label::new(text.into(), align, STYLE).vertically_centered();
+        label.place(Rect::new(Point::new(10, 10), Point::new(110, 50)));
+        label
+    }
+
+    #[test]
+    fn single_line_rendered_as_laid_out() {
+        for align in [Alignment::Start, Alignment::Center, Alignment::End] {
+            let label = placed("Hello", align);
+            assert!(matches!(label.line, Some(Line { pos: Some(_), .. })));
+
+            let mut cached = renderer();
+            label.render(&mut cached);
+            let mut laid_out = renderer();
+            label.map_text(|c| label.layout.render_text2(c, &mut laid_out));
+            assert!(cached.shapes == laid_out.shapes);
+        }
+    }
+
+    #[test]
+    fn multiple_lines_laid_out() {
+        assert!(placed("Hello\nworld", Alignment::Start).line.is_none());
+        let long = "The quick brown fox jumps over the lazy dog";
+        assert!(placed(long, Alignment::Center).line.is_none());
+    }
+}
@@ -4,6 +4,9 @@ use crate::ui::{geometry::Rect, shape::Renderer};
 #[cfg(all(feature = "micropython", feature = "touch", feature = "new_rendering"))]
 use crate::ui::component::swipe_detect::SwipeConfig;
 
+/// Translates the messages of the inner component with `func`, dropping those
+/// it maps to `None`. Everything else is forwarded to the inner component
+/// untouched, including the trace.
 pub struct MsgMap<T, F> {
     inner: T,
     func: F,
@@ -13,6 +16,14 @@ impl<T, F> MsgMap<T, F> {
     pub fn new(inner: T, func: F) -> Self {
         Self { inner, func }
     }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
 }
 
 impl<T, F, U> Component for MsgMap<T, F>
@@ -1,14 +1,29 @@
-use crate::ui::{
-    component::{Component, ComponentExt, Event, EventCtx, Pad},
-    display::{self, Color},
-    geometry::Rect,
-    shape::Renderer,
+use crate::{
+    time::{Duration, Stopwatch},
+    ui::{
+        component::{Component, ComponentExt, Event, EventCtx, Pad},
+        display::{self, Color},
+        geometry::Rect,
+        lerp::Lerp,
+        shape::{self, Renderer},
+        util::animation_disabled,
+    },
 };
 
 pub struct Maybe<T> {
     inner: T,
     pad: Pad,
     visible: bool,
+    fade: Option<Fade>,
+}
+
+/// Gradual showing and hiding of the content, see `Maybe::with_fade`.
+struct Fade {
+    duration: Duration,
+    timer: Stopwatch,
+    /// Opacity of the content when the running transition started.
+    from: f32,
+    active: bool,
 }
 
 impl<T> Maybe<T> {
@@ -18,9 +33,22 @@ impl<T> Maybe<T> {
             inner,
             visible,
             pad,
+            fade: None,
         }
     }
 
+    /// Fade the content in and out over `duration` instead of showing and
+    /// hiding it instantly.
+    pub fn with_fade(mut self, duration: Duration) -> Self {
+        self.fade = Some(Fade {
+            duration,
+            timer: Stopwatch::new_stopped(),
+            from: 0.0,
+            active: false,
+        });
+        self
+    }
+
     pub fn visible(bg_color: Color, inner: T) -> Self {
         Self::new(bg_color, inner, true)
     }
@@ -36,21 +64,52 @@ where
 {
     pub fn show_if(&mut self, ctx: &mut EventCtx, show: bool) {
         if self.visible != show {
+            let fading = self.start_fade(ctx);
             self.visible = show;
 
-            // Invalidate the pad, so either we prepare a fresh canvas for the content, or
-            // paint over it.
-            self.pad.clear();
             if show {
-                // Make sure the whole inner tree is painted.
+                // Prepare a fresh canvas for the content and make sure the whole inner tree
+                // is painted.
+                self.pad.clear();
                 self.inner.request_complete_repaint(ctx);
             } else {
-                // Just make sure out `paint` method is called, to clear the pad.
+                // Paint over the content, when fading only after it has faded out.
+                if !fading {
+                    self.pad.clear();
+                }
                 ctx.request_paint();
             }
         }
     }
 
+    /// Start fading from the current opacity, returns whether fading at all.
+    fn start_fade(&mut self, ctx: &mut EventCtx) -> bool {
+        let from = self.opacity();
+        match &mut self.fade {
+            Some(fade) if !animation_disabled() => {
+                fade.from = from;
+                fade.timer.start();
+                fade.active = true;
+                ctx.request_anim_frame();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Opacity of the content, from 0.0 (hidden) to 1.0 (visible).
+    fn opacity(&self) -> f32 {
+        let target = if self.visible { 1.0 } else { 0.0 };
+        match &self.fade {
+            Some(fade) if fade.active => {
+                let progress =
+                    fade.timer.elapsed().to_millis() as f32 / fade.duration.to_millis() as f32;
+                fade.from + (target - fade.from) * progress.min(1.0)
+            }
+            _ => target,
+        }
+    }
+
     pub fn show(&mut self, ctx: &mut EventCtx) {
         self.show_if(ctx, true)
     }
@@ -63,6 +122,15 @@ where
         &self.inner
     }
 
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Whether the content is being faded in or out.
+    pub fn is_fading(&self) -> bool {
+        self.fade.as_ref().is_some_and(|fade| fade.active)
+    }
+
     pub fn inner_mut(&mut self) -> &mut T {
         &mut self.inner
     }
@@ -81,6 +149,20 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let (Event::Timer(EventCtx::ANIM_FRAME_TIMER), Some(fade)) = (event, &mut self.fade) {
+            if fade.active {
+                if fade.timer.is_running_within(fade.duration) {
+                    ctx.request_anim_frame();
+                } else {
+                    fade.active = false;
+                    if !self.visible {
+                        self.pad.clear();
+                    }
+                }
+                ctx.request_paint();
+            }
+        }
+        // Content being hidden doesn't receive events even while fading out.
         if self.visible {
             self.inner.event(ctx, event)
         } else {
@@ -97,9 +179,17 @@ where
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
         self.pad.render(target);
-        if self.visible {
+        let opacity = self.opacity();
+        if opacity > 0.0 {
             self.inner.render(target);
         }
+        if opacity > 0.0 && opacity < 1.0 {
+            // Fade the content by covering it with the background.
+            shape::Bar::new(self.pad.area)
+                .with_bg(self.pad.color)
+                .with_alpha(u8::lerp(255, 0, opacity))
+                .render(target);
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -4,8 +4,18 @@ pub mod bar;
 pub mod base;
 pub mod border;
 pub mod button_request;
+#[cfg(feature = "model_mercury")]
+pub mod cached_area;
+#[cfg(feature = "touch")]
+pub mod carousel;
 pub mod connect;
+#[cfg(feature = "ui_debug")]
+pub mod debug_overlay;
+#[cfg(feature = "ui_debug")]
+pub mod debug_target;
 pub mod empty;
+#[cfg(feature = "touch")]
+pub mod gesture;
 pub mod image;
 #[cfg(all(feature = "jpeg", feature = "micropython"))]
 pub mod jpeg;
@@ -25,10 +35,16 @@ pub mod text;
 pub mod timeout;
 
 pub use bar::Bar;
-pub use base::{Child, Component, ComponentExt, Event, EventCtx, Never, Root, TimerToken};
+pub use base::{Child, Component, ComponentExt, Event, EventCtx, Never, Root, Timer, TimerToken};
 pub use border::Border;
 pub use button_request::{ButtonRequestExt, OneButtonRequest};
+#[cfg(feature = "model_mercury")]
+pub use cached_area::CachedArea;
+#[cfg(feature = "touch")]
+pub use carousel::{Carousel, CarouselMsg};
 pub use empty::Empty;
+#[cfg(feature = "touch")]
+pub use gesture::{GestureDetector, GestureMsg};
 #[cfg(all(feature = "jpeg", feature = "micropython"))]
 pub use jpeg::Jpeg;
 pub use label::Label;
@@ -42,9 +58,9 @@ pub use qr_code::Qr;
 #[cfg(feature = "touch")]
 pub use swipe::{Swipe, SwipeDirection};
 #[cfg(feature = "touch")]
-pub use swipe_detect::{SwipeDetect, SwipeDetectMsg};
+pub use swipe_detect::{SwipeDetect, SwipeDetectMsg, SwipeMotion};
 pub use text::{
     formatted::FormattedText,
     layout::{LineBreaking, PageBreaking, TextLayout},
 };
-pub use timeout::Timeout;
+pub use timeout::{Timeout, TimeoutMsg};
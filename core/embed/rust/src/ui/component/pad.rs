@@ -8,6 +8,9 @@ use crate::ui::{
 pub struct Pad {
     pub area: Rect,
     pub color: Color,
+    radius: i16,
+    /// Color and thickness of the outline, if any.
+    outline: Option<(Color, i16)>,
     clear: bool,
 }
 
@@ -16,10 +19,26 @@ impl Pad {
         Self {
             color,
             area: Rect::zero(),
+            radius: 0,
+            outline: None,
             clear: false,
         }
     }
 
+    /// Round the corners of the background. Only applies to `render`.
+    pub fn with_radius(self, radius: i16) -> Self {
+        Self { radius, ..self }
+    }
+
+    /// Draw an outline of 1 or 2 px along the edges. Only applies to `render`.
+    pub fn with_outline(self, color: Color, thickness: i16) -> Self {
+        debug_assert!((1..=2).contains(&thickness));
+        Self {
+            outline: Some((color, thickness)),
+            ..self
+        }
+    }
+
     pub fn with_clear(self) -> Self {
         Self {
             clear: true,
@@ -56,8 +75,15 @@ impl Pad {
     }
 
     pub fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        shape::Bar::new(self.area)
-            .with_bg(self.color)
-            .render(target);
+        let bar = shape::Bar::new(self.area).with_bg(self.color);
+        match self.outline {
+            None if self.radius == 0 => bar,
+            None => bar.with_radius(self.radius),
+            Some((color, thickness)) => bar
+                .with_radius(self.radius)
+                .with_fg(color)
+                .with_thickness(thickness),
+        }
+        .render(target);
     }
 }
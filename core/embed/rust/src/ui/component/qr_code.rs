@@ -27,6 +27,7 @@ const LIGHT: Color = Color::rgb(0xff, 0xff, 0xff);
 
 pub struct Qr {
     text: String<MAX_DATA>,
+    ecc: QrCodeEcc,
     border: i16,
     area: Rect,
 }
@@ -51,18 +52,61 @@ impl Qr {
             s.push_str(indata).map_err(|_| Error::OutOfRange)?;
         }
 
-        Ok(Self {
+        let qr = Self {
             text: s,
+            ecc: QrCodeEcc::Medium,
             border: 0,
             area: Rect::zero(),
-        })
+        };
+        qr.check_fits()?;
+        Ok(qr)
     }
 
+    /// Width of the light quiet zone around the code, in pixels.
     pub fn with_border(mut self, border: i16) -> Self {
         self.border = border;
         self
     }
 
+    /// Use the error correction level `ecc` (default medium). The smallest QR
+    /// version fitting the data at this level is used, fails if there is none.
+    pub fn with_ecc(mut self, ecc: QrCodeEcc) -> Result<Self, Error> {
+        self.ecc = ecc;
+        self.check_fits()?;
+        Ok(self)
+    }
+
+    fn encode<'a>(
+        &self,
+        tempbuffer: &mut [u8],
+        outbuffer: &'a mut [u8],
+    ) -> Result<QrCode<'a>, Error> {
+        QrCode::encode_text(
+            self.text.as_ref(),
+            tempbuffer,
+            outbuffer,
+            self.ecc,
+            Version::MIN,
+            QR_MAX_VERSION,
+            None,
+            true,
+        )
+        .map_err(|_| value_error!("Data too long for QR code."))
+    }
+
+    fn check_fits(&self) -> Result<(), Error> {
+        let mut outbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
+        let mut tempbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
+        self.encode(&mut tempbuffer, &mut outbuffer).map(|_| ())
+    }
+
+    /// Largest integer module size for which the code with the quiet zone
+    /// fits into the area.
+    fn scale(&self, qr: &QrCode) -> i16 {
+        let avail_space = self.area.width().min(self.area.height()) - 2 * self.border;
+        avail_space / qr.size() as i16
+    }
+
     fn is_alphanumeric_after_conversion(data: &str) -> bool {
         data.chars()
             .all(|c| ALPHANUMERIC_CHARSET.contains(c.to_ascii_uppercase()))
@@ -121,22 +165,11 @@ impl Component for Qr {
         let mut outbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
         let mut tempbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
 
-        let qr = QrCode::encode_text(
-            self.text.as_ref(),
-            &mut tempbuffer,
-            &mut outbuffer,
-            QrCodeEcc::Medium,
-            Version::MIN,
-            QR_MAX_VERSION,
-            None,
-            true,
-        );
-        let qr = unwrap!(qr);
+        // Checked when created.
+        let qr = unwrap!(self.encode(&mut tempbuffer, &mut outbuffer));
         let size = qr.size() as i16;
 
-        let avail_space = self.area.width().min(self.area.height());
-        let avail_space = avail_space - 2 * self.border;
-        let scale = avail_space / size;
+        let scale = self.scale(&qr);
         assert!((1..=10).contains(&scale));
 
         let area = Rect::from_center_and_size(self.area.center(), Offset::uniform(size * scale));
@@ -147,19 +180,10 @@ impl Component for Qr {
         let mut outbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
         let mut tempbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
 
-        let qr = QrCode::encode_text(
-            self.text.as_ref(),
-            &mut tempbuffer,
-            &mut outbuffer,
-            QrCodeEcc::Medium,
-            Version::MIN,
-            QR_MAX_VERSION,
-            None,
-            true,
-        );
-        let qr = unwrap!(qr);
+        // Checked when created.
+        let qr = unwrap!(self.encode(&mut tempbuffer, &mut outbuffer));
 
-        let scale = (self.area.width().min(self.area.height()) - self.border) / (qr.size() as i16);
+        let scale = self.scale(&qr);
         let side = scale * qr.size() as i16;
         let qr_area = Rect::from_center_and_size(self.area.center(), Offset::uniform(side));
 
@@ -199,3 +223,28 @@ mod micropython {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_short_payload() {
+        let qr = Qr::new("bc1qar0srrr7xfkvy5l6", true);
+        assert!(qr.is_ok());
+        assert!(unwrap!(qr).with_ecc(QrCodeEcc::High).is_ok());
+    }
+
+    #[test]
+    fn qr_long_payload() {
+        let text = "a".repeat(500);
+        assert!(Qr::new(&text, true).is_err());
+    }
+
+    #[test]
+    fn qr_ecc_too_high() {
+        let text = "a".repeat(200);
+        let qr = unwrap!(Qr::new(&text, true));
+        assert!(qr.with_ecc(QrCodeEcc::High).is_err());
+    }
+}
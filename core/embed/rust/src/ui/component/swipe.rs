@@ -23,6 +23,15 @@ impl SwipeDirection {
         }
     }
 
+    pub fn opposite(self) -> Self {
+        match self {
+            SwipeDirection::Up => SwipeDirection::Down,
+            SwipeDirection::Down => SwipeDirection::Up,
+            SwipeDirection::Left => SwipeDirection::Right,
+            SwipeDirection::Right => SwipeDirection::Left,
+        }
+    }
+
     pub fn iter() -> SwipeDirectionIterator {
         SwipeDirectionIterator::new()
     }
@@ -145,11 +145,36 @@ pub enum SwipeDetectMsg {
     Trigger(SwipeDirection),
 }
 
+/// Movement of the touch belonging to the current (or the last) swipe.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub struct SwipeMotion {
+    /// Total displacement from the point where the touch started.
+    pub displacement: Offset,
+    /// Velocity of the touch in pixels per second, measured between the last
+    /// two touch samples.
+    pub velocity: Offset,
+}
+
+impl SwipeMotion {
+    /// Velocity along `dir`, positive when moving towards `dir`.
+    pub fn velocity_towards(&self, dir: SwipeDirection) -> i16 {
+        match dir {
+            SwipeDirection::Up => -self.velocity.y,
+            SwipeDirection::Down => self.velocity.y,
+            SwipeDirection::Left => -self.velocity.x,
+            SwipeDirection::Right => self.velocity.x,
+        }
+    }
+}
+
 pub struct SwipeDetect {
     origin: Option<Point>,
     locked: Option<SwipeDirection>,
     final_animation: Option<Animation<i16>>,
     moved: u16,
+    /// Last touch sample, for computing the velocity.
+    last_sample: Option<(Point, Instant)>,
+    motion: SwipeMotion,
 }
 
 impl SwipeDetect {
@@ -160,15 +185,68 @@ impl SwipeDetect {
     const TRIGGER_THRESHOLD: f32 = 0.3;
     const DETECT_THRESHOLD: f32 = 0.1;
 
-    const MIN_LOCK: u16 = (Self::DISTANCE as f32 * Self::DETECT_THRESHOLD) as u16;
+    /// Distance a touch travels before the swipe locks onto a direction.
+    pub(crate) const MIN_LOCK: u16 = (Self::DISTANCE as f32 * Self::DETECT_THRESHOLD) as u16;
     const MIN_TRIGGER: u16 = (Self::DISTANCE as f32 * Self::TRIGGER_THRESHOLD) as u16;
 
+    /// Releasing the touch at least this fast (px/s) commits the swipe even
+    /// if it did not reach the trigger distance, and releasing it this fast in
+    /// the opposite direction snaps it back even if it did.
+    const FLICK_VELOCITY: i16 = 500;
+    /// Samples further apart than this do not count towards the velocity, the
+    /// finger was resting.
+    const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+
     pub fn new() -> Self {
         Self {
             origin: None,
             locked: None,
             final_animation: None,
             moved: 0,
+            last_sample: None,
+            motion: SwipeMotion::default(),
+        }
+    }
+
+    /// Displacement and velocity of the current swipe. Stays available after
+    /// the touch ended, until a new touch starts.
+    pub fn motion(&self) -> SwipeMotion {
+        self.motion
+    }
+
+    fn sample(&mut self, origin: Point, pos: Point, now: Instant) {
+        self.motion.displacement = pos - origin;
+        self.motion.velocity = match self.last_sample {
+            Some((last_pos, last_time)) => {
+                let elapsed = now.saturating_duration_since(last_time).to_millis();
+                if elapsed == 0 {
+                    self.motion.velocity
+                } else if elapsed > Self::VELOCITY_WINDOW.to_millis() {
+                    Offset::zero()
+                } else {
+                    let delta = pos - last_pos;
+                    let per_second = |d: i16| {
+                        (d as i32 * 1000 / elapsed as i32).clamp(i16::MIN as i32, i16::MAX as i32)
+                            as i16
+                    };
+                    Offset::new(per_second(delta.x), per_second(delta.y))
+                }
+            }
+            None => Offset::zero(),
+        };
+        self.last_sample = Some((pos, now));
+    }
+
+    /// Update the motion when the touch is released at `pos`. The release
+    /// usually reports the position of the last move, so sampling it would
+    /// measure no movement. Keep the velocity of the last move instead, unless
+    /// the finger was resting since.
+    fn release(&mut self, origin: Point, pos: Point, now: Instant) {
+        self.motion.displacement = pos - origin;
+        if let Some((_, last_time)) = self.last_sample {
+            if now.saturating_duration_since(last_time) > Self::VELOCITY_WINDOW {
+                self.motion.velocity = Offset::zero();
+            }
         }
     }
 
@@ -206,6 +284,8 @@ impl SwipeDetect {
         self.locked = None;
         self.final_animation = None;
         self.moved = 0;
+        self.last_sample = None;
+        self.motion = SwipeMotion::default();
     }
 
     pub(crate) fn event(
@@ -218,9 +298,13 @@ impl SwipeDetect {
             (Event::Touch(TouchEvent::TouchStart(pos)), _) => {
                 // Mark the starting position of this touch.
                 self.origin.replace(pos);
+                self.last_sample = None;
+                self.sample(pos, pos, Instant::now());
             }
             (Event::Touch(TouchEvent::TouchMove(pos)), Some(origin)) => {
                 if self.final_animation.is_none() {
+                    self.sample(origin, pos, Instant::now());
+
                     // Compare the touch distance with our allowed directions and determine if it
                     // constitutes a valid swipe.
                     let ofs = pos - origin;
@@ -262,12 +346,24 @@ impl SwipeDetect {
                 if self.final_animation.is_none() {
                     // Touch interaction is over, reset the position.
                     self.origin.take();
+                    self.release(origin, pos, Instant::now());
 
                     // Compare the touch distance with our allowed directions and determine if it
                     // constitutes a valid swipe.
                     let ofs = pos - origin;
 
                     let final_value = match self.locked {
+                        // a decisive flick decides regardless of the distance
+                        Some(locked)
+                            if self.motion.velocity_towards(locked) >= Self::FLICK_VELOCITY =>
+                        {
+                            Self::PROGRESS_MAX
+                        }
+                        Some(locked)
+                            if self.motion.velocity_towards(locked) <= -Self::FLICK_VELOCITY =>
+                        {
+                            0
+                        }
                         // advance in locked direction only trigger animation towards ending
                         // position
                         Some(locked) if config.progress(locked, ofs, self.min_trigger()) > 0 => {
@@ -368,3 +464,68 @@ impl SwipeDetect {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGIN: Point = Point::new(100, 100);
+
+    /// Swipe left through `moves`, each a position and the milliseconds since
+    /// the touch started, rest for `rest_ms` and release. Returns the progress
+    /// the swipe animates to.
+    fn swipe_left(moves: &[(Point, u32)], rest_ms: u32) -> Option<i16> {
+        let config = SwipeConfig::new().with_swipe(SwipeDirection::Left, SwipeSettings::default());
+        let mut ctx = EventCtx::new();
+        let mut detect = SwipeDetect::new();
+
+        let (_, last_ms) = unwrap!(moves.last());
+        let total = Duration::from_millis(last_ms + rest_ms);
+        let start = unwrap!(Instant::now().checked_sub(total));
+        detect.origin = Some(ORIGIN);
+        detect.sample(ORIGIN, ORIGIN, start);
+        for &(pos, ms) in moves {
+            let at = unwrap!(start.checked_add(Duration::from_millis(ms)));
+            detect.sample(ORIGIN, pos, at);
+        }
+        // Locked as by the first move past the lock distance.
+        detect.locked = Some(SwipeDirection::Left);
+
+        let (end, _) = unwrap!(moves.last());
+        detect.event(&mut ctx, Event::Touch(TouchEvent::TouchEnd(*end)), config);
+        detect
+            .final_animation
+            .as_ref()
+            .map(|animation| animation.to)
+    }
+
+    #[test]
+    fn flick_commits_short_swipe() {
+        let moves = [(Point::new(80, 100), 20)];
+        assert_eq!(swipe_left(&moves, 0), Some(SwipeDetect::PROGRESS_MAX));
+    }
+
+    #[test]
+    fn slow_short_swipe_returns() {
+        let moves = [(Point::new(90, 100), 80), (Point::new(80, 100), 160)];
+        assert_eq!(swipe_left(&moves, 0), Some(0));
+    }
+
+    #[test]
+    fn flick_back_cancels_long_swipe() {
+        let moves = [(Point::new(40, 100), 100), (Point::new(50, 100), 110)];
+        assert_eq!(swipe_left(&moves, 0), Some(0));
+    }
+
+    #[test]
+    fn long_swipe_commits() {
+        let moves = [(Point::new(70, 100), 100), (Point::new(50, 100), 200)];
+        assert_eq!(swipe_left(&moves, 0), Some(SwipeDetect::PROGRESS_MAX));
+    }
+
+    #[test]
+    fn resting_finger_is_no_flick() {
+        let moves = [(Point::new(80, 100), 20)];
+        assert_eq!(swipe_left(&moves, 300), Some(0));
+    }
+}
@@ -1,6 +1,63 @@
-use crate::ui::{component::EventCtx, util::ResultExt};
+use crate::{
+    time::Duration,
+    ui::{component::EventCtx, util::ResultExt},
+};
 use heapless::String;
 
+/// Timing of the keyboard interactions, adjustable by a device setting. The
+/// keyboards read it when they are created, so a change applies to the next
+/// keyboard shown.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct KeyboardTimings {
+    /// Holding the erase button this long clears the whole input.
+    pub erase_hold: Duration,
+    /// Pending key of a multi-tap keyboard is committed after this long.
+    pub multi_tap_timeout: Duration,
+    /// Last typed character of a hidden passphrase stays readable this long.
+    pub last_char_reveal: Duration,
+    /// Presses of a keyboard key this soon after its release are ignored.
+    pub key_debounce: Duration,
+}
+
+static mut KEYBOARD_TIMINGS: KeyboardTimings = KeyboardTimings::DEFAULT;
+
+impl KeyboardTimings {
+    pub const DEFAULT: Self = Self {
+        erase_hold: Duration::from_millis(1500),
+        multi_tap_timeout: Duration::from_secs(1),
+        last_char_reveal: Duration::from_secs(1),
+        key_debounce: Duration::ZERO,
+    };
+
+    const ERASE_HOLD_MS: (u32, u32) = (500, 5000);
+    const MULTI_TAP_TIMEOUT_MS: (u32, u32) = (300, 3000);
+    const LAST_CHAR_REVEAL_MS: (u32, u32) = (0, 3000);
+    const KEY_DEBOUNCE_MS: (u32, u32) = (0, 300);
+
+    pub fn get() -> Self {
+        // SAFETY: single-threaded access
+        unsafe { KEYBOARD_TIMINGS }
+    }
+
+    /// Set the timings used by keyboards created from now on. Values are
+    /// clamped to a range in which the keyboards stay usable.
+    pub fn set(timings: Self) {
+        let clamp = |d: Duration, (min, max): (u32, u32)| {
+            Duration::from_millis(d.to_millis().clamp(min, max))
+        };
+        let timings = Self {
+            erase_hold: clamp(timings.erase_hold, Self::ERASE_HOLD_MS),
+            multi_tap_timeout: clamp(timings.multi_tap_timeout, Self::MULTI_TAP_TIMEOUT_MS),
+            last_char_reveal: clamp(timings.last_char_reveal, Self::LAST_CHAR_REVEAL_MS),
+            key_debounce: clamp(timings.key_debounce, Self::KEY_DEBOUNCE_MS),
+        };
+        // SAFETY: single-threaded access
+        unsafe {
+            KEYBOARD_TIMINGS = timings;
+        }
+    }
+}
+
 /// Reified editing operations of `TextBox`.
 ///
 /// Note: This does not contain all supported editing operations, only the ones
@@ -32,6 +89,22 @@ impl<const L: usize> TextBox<L> {
         &self.text
     }
 
+    /// Content with every character replaced by `*`, except the last one if
+    /// `show_last`.
+    pub fn masked_content(&self, show_last: bool) -> String<L> {
+        let mut chars = self.text.chars();
+        let last = if show_last { chars.next_back() } else { None };
+        // Every character takes at least as many bytes as the `*` replacing it.
+        let mut masked = String::new();
+        for _ in chars {
+            unwrap!(masked.push('*'));
+        }
+        if let Some(ch) = last {
+            unwrap!(masked.push(ch));
+        }
+        masked
+    }
+
     pub fn len(&self) -> usize {
         self.text.len()
     }
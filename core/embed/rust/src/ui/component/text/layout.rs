@@ -6,7 +6,7 @@ use crate::ui::{
     shape::Renderer,
 };
 
-const ELLIPSIS: &str = "...";
+pub const ELLIPSIS: &str = "...";
 
 #[derive(Copy, Clone)]
 pub enum LineBreaking {
@@ -78,6 +78,15 @@ impl Chunks {
         self.max_rows = Some(max_rows);
         self
     }
+
+    /// Width of the first chunk of `text`, without the gap after it.
+    fn width(&self, text: &str, font: impl GlyphMetrics) -> i16 {
+        let end = text
+            .char_indices()
+            .nth(self.chunk_size)
+            .map_or(text.len(), |(i, _)| i);
+        font.text_width(&text[..end])
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -112,6 +121,17 @@ pub struct TextStyle {
     /// Optionally increase the vertical space between text lines
     /// (can be even negative, in which case it will decrease it).
     pub line_spacing: i16,
+
+    /// Extra horizontal space after every character (can be negative).
+    pub letter_spacing: i16,
+    /// Distance between baselines of subsequent lines, instead of the one
+    /// given by the font.
+    pub line_height_override: Option<i16>,
+
+    /// Draw a line below the text.
+    pub underline: bool,
+    /// Draw a line through the middle of the text.
+    pub strikethrough: bool,
 }
 
 impl TextStyle {
@@ -134,6 +154,10 @@ impl TextStyle {
             page_breaking: PageBreaking::CutAndInsertEllipsis,
             chunks: None,
             line_spacing: 0,
+            letter_spacing: 0,
+            line_height_override: None,
+            underline: false,
+            strikethrough: false,
         }
     }
 
@@ -171,11 +195,54 @@ impl TextStyle {
         self
     }
 
+    /// Adding optional extra space between characters.
+    pub const fn with_letter_spacing(mut self, letter_spacing: i16) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Adding optional fixed distance between lines, replacing the line
+    /// height of the font.
+    pub const fn with_line_height(mut self, line_height: i16) -> Self {
+        self.line_height_override = Some(line_height);
+        self
+    }
+
+    /// Adding a line below the text, e.g. for links.
+    pub const fn with_underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Adding a line through the text, e.g. for replaced values.
+    pub const fn with_strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Metrics of the text font, adjusted by the letter spacing and line
+    /// height of this style.
+    pub fn metrics(&self) -> StyleMetrics {
+        StyleMetrics {
+            font: self.text_font,
+            letter_spacing: self.letter_spacing,
+            line_height: self.line_height_override,
+        }
+    }
+
+    pub fn text_width(&self, text: &str) -> i16 {
+        self.metrics().text_width(text)
+    }
+
+    pub fn line_height(&self) -> i16 {
+        self.metrics().line_height()
+    }
+
     fn ellipsis_width(&self) -> i16 {
         if let Some((icon, margin)) = self.ellipsis_icon {
             icon.toif.width() + margin
         } else {
-            self.text_font.text_width(ELLIPSIS)
+            self.text_width(ELLIPSIS)
         }
     }
 
@@ -183,7 +250,7 @@ impl TextStyle {
         if let Some((icon, margin)) = self.prev_page_ellipsis_icon {
             icon.toif.width() + margin
         } else {
-            self.text_font.text_width(ELLIPSIS)
+            self.text_width(ELLIPSIS)
         }
     }
 
@@ -289,7 +356,7 @@ impl TextLayout {
             if let Some(chunk_config) = self.style.chunks {
                 // Showing the arrow at the last chunk position
                 // Assuming mono-font, so all the letters have the same width
-                let letter_size = self.style.text_font.text_width("a");
+                let letter_size = self.style.text_width("a");
                 let icon_offset = self.style.prev_page_ellipsis_icon_width() + 2;
                 cursor.x += chunk_config.chunk_size as i16 * letter_size - icon_offset;
                 sink.prev_page_ellipsis(*cursor, self);
@@ -301,7 +368,7 @@ impl TextLayout {
         }
 
         while !remaining_text.is_empty() {
-            let is_last_line = cursor.y + self.style.text_font.line_height() > self.bottom_y();
+            let is_last_line = cursor.y + self.style.line_height() > self.bottom_y();
             let mut force_next_page = false;
 
             // Check if we have not reached the maximum number of lines we want to draw.
@@ -318,14 +385,26 @@ impl TextLayout {
             };
 
             let remaining_width = self.bounds.x1 - cursor.x;
-            let mut span = Span::fit_horizontally(
-                remaining_text,
-                remaining_width,
-                self.style.text_font,
-                self.style.line_breaking,
-                line_ending_space,
-                self.style.chunks,
-            );
+            let mut span = match self.style.chunks {
+                // Chunks are never split between lines, unless a whole chunk doesn't fit even
+                // on an empty one. With monospace fonts, this keeps the same number of
+                // characters on every line.
+                Some(chunk_config)
+                    if cursor.x > self.bounds.x0
+                        && chunk_config.width(remaining_text, self.style.metrics())
+                            > remaining_width =>
+                {
+                    Span::line_break(self.style.line_height())
+                }
+                _ => Span::fit_horizontally(
+                    remaining_text,
+                    remaining_width,
+                    self.style.metrics(),
+                    self.style.line_breaking,
+                    line_ending_space,
+                    self.style.chunks,
+                ),
+            };
 
             if let Some(chunk_config) = self.style.chunks {
                 // Last chunk on the page should not be rendered, put just ellipsis there
@@ -341,10 +420,17 @@ impl TextLayout {
                 }
             }
 
+            // The advance includes the letter spacing after the last character,
+            // which is not rendered.
+            let span_width = if span.length > 0 {
+                span.advance.x - self.style.letter_spacing
+            } else {
+                span.advance.x
+            };
             cursor.x += match self.align {
                 Alignment::Start => 0,
-                Alignment::Center => (remaining_width - span.advance.x) / 2,
-                Alignment::End => remaining_width - span.advance.x,
+                Alignment::Center => (remaining_width - span_width) / 2,
+                Alignment::End => remaining_width - span_width,
             };
 
             // Report the span at the cursor position.
@@ -583,6 +669,9 @@ where
     fn text(&mut self, cursor: Point, layout: &TextLayout, text: &str) {
         shape::Text::new(cursor, text)
             .with_font(layout.style.text_font)
+            .with_letter_spacing(layout.style.letter_spacing)
+            .with_underline(layout.style.underline)
+            .with_strikethrough(layout.style.strikethrough)
             .with_fg(layout.style.text_color)
             .with_alpha(self.alpha)
             .render(self.renderer);
@@ -591,6 +680,9 @@ where
     fn hyphen(&mut self, cursor: Point, layout: &TextLayout) {
         shape::Text::new(cursor, "-")
             .with_font(layout.style.text_font)
+            .with_letter_spacing(layout.style.letter_spacing)
+            .with_underline(layout.style.underline)
+            .with_strikethrough(layout.style.strikethrough)
             .with_fg(layout.style.hyphen_color)
             .with_alpha(self.alpha)
             .render(self.renderer);
@@ -607,6 +699,7 @@ where
         } else {
             shape::Text::new(cursor, ELLIPSIS)
                 .with_font(layout.style.text_font)
+                .with_letter_spacing(layout.style.letter_spacing)
                 .with_fg(layout.style.ellipsis_color)
                 .with_alpha(self.alpha)
                 .render(self.renderer);
@@ -623,6 +716,7 @@ where
         } else {
             shape::Text::new(cursor, ELLIPSIS)
                 .with_font(layout.style.text_font)
+                .with_letter_spacing(layout.style.letter_spacing)
                 .with_fg(layout.style.ellipsis_color)
                 .with_alpha(self.alpha)
                 .render(self.renderer);
@@ -662,6 +756,34 @@ pub mod trace {
     }
 }
 
+/// Font metrics with letter spacing and line height adjustments of a
+/// `TextStyle`.
+#[derive(Copy, Clone)]
+pub struct StyleMetrics {
+    font: Font,
+    letter_spacing: i16,
+    line_height: Option<i16>,
+}
+
+impl GlyphMetrics for StyleMetrics {
+    fn char_width(&self, ch: char) -> i16 {
+        self.font.char_width(ch) + self.letter_spacing
+    }
+
+    fn text_width(&self, text: &str) -> i16 {
+        // The same as `shape::Text` renders it.
+        self.font.text_width(text) + shape::Text::letter_spacing_width(text, self.letter_spacing)
+    }
+
+    fn line_height(&self) -> i16 {
+        self.line_height.unwrap_or_else(|| self.font.line_height())
+    }
+
+    fn letter_spacing(&self) -> i16 {
+        self.letter_spacing
+    }
+}
+
 /// Carries info about the content that was processed
 /// on the current line.
 #[derive(Debug, PartialEq, Eq)]
@@ -680,6 +802,16 @@ struct Span {
 }
 
 impl Span {
+    /// Empty span moving to the next line.
+    fn line_break(line_height: i16) -> Self {
+        Self {
+            length: 0,
+            advance: Offset::y(line_height),
+            insert_hyphen_before_line_break: false,
+            skip_next_chars: 0,
+        }
+    }
+
     pub fn fit_horizontally(
         text: &str,
         max_width: i16,
@@ -770,8 +902,9 @@ impl Span {
                     return line;
                 }
                 found_any_whitespace = true;
-            } else if span_width + char_width > max_width {
-                // Cannot fit on this line. Return the last breakpoint.
+            } else if span_width + char_width - text_font.letter_spacing() > max_width {
+                // Cannot fit on this line, not counting the spacing after the
+                // character. Return the last breakpoint.
                 return line;
             } else {
                 let have_space_for_break =
@@ -807,6 +940,7 @@ impl Span {
 mod tests {
     use super::*;
 
+    #[derive(Clone, Copy)]
     pub struct Fixed {
         pub width: i16,
         pub height: i16,
@@ -878,14 +1012,89 @@ mod tests {
         );
     }
 
+    /// Two pixels wide characters, one of it is the letter spacing.
+    #[derive(Clone, Copy)]
+    struct Spaced;
+
+    impl GlyphMetrics for Spaced {
+        fn char_width(&self, _ch: char) -> i16 {
+            2
+        }
+
+        fn line_height(&self) -> i16 {
+            1
+        }
+
+        fn text_width(&self, text: &str) -> i16 {
+            2 * text.len() as i16 - 1
+        }
+
+        fn letter_spacing(&self) -> i16 {
+            1
+        }
+    }
+
+    #[test]
+    fn test_letter_spacing() {
+        // Not counting the spacing after the last letter.
+        assert_eq!(spans_with("hello", 9, Spaced), vec![("hello", false)]);
+        assert_eq!(
+            spans_with("hello", 8, Spaced),
+            vec![("hel", true), ("lo", false)]
+        );
+    }
+
+    /// Bounds of `text` rendered on a single line.
+    fn rendered(text: &str, align: Alignment, letter_spacing: i16) -> Rect {
+        let style = TextStyle::new(
+            Font::NORMAL,
+            Color::white(),
+            Color::black(),
+            Color::white(),
+            Color::white(),
+        )
+        .with_letter_spacing(letter_spacing);
+        let bounds = Rect::new(Point::new(10, 0), Point::new(110, 40));
+        let layout = TextLayout::new(style).with_bounds(bounds).with_align(align);
+        let mut renderer = shape::BoundsRenderer::new(shape::Viewport::new(bounds));
+        layout.render_text2(text, &mut renderer);
+        assert_eq!(renderer.shapes.len(), 1);
+        let text_bounds = renderer.shapes[0];
+        assert_eq!(text_bounds.width(), style.text_width(text));
+        text_bounds
+    }
+
+    #[test]
+    fn test_letter_spacing_aligned() {
+        let width = Font::NORMAL.text_width("Hello");
+        for spacing in [0, 3] {
+            // One spacing between every two letters.
+            let spaced_width = width + 4 * spacing;
+            let start = rendered("Hello", Alignment::Start, spacing);
+            assert_eq!((start.x0, start.width()), (10, spaced_width));
+            let center = rendered("Hello", Alignment::Center, spacing);
+            assert!((center.center().x - 60).abs() <= 1);
+            let end = rendered("Hello", Alignment::End, spacing);
+            assert_eq!((end.x1, end.width()), (110, spaced_width));
+        }
+    }
+
     fn spans_from(text: &str, max_width: i16) -> Vec<(&str, bool)> {
+        spans_with(text, max_width, FIXED_FONT)
+    }
+
+    fn spans_with(
+        text: &str,
+        max_width: i16,
+        metrics: impl GlyphMetrics + Copy,
+    ) -> Vec<(&str, bool)> {
         let mut spans = vec![];
         let mut remaining_text = text;
         loop {
             let span = Span::fit_horizontally(
                 remaining_text,
                 max_width,
-                FIXED_FONT,
+                metrics,
                 LineBreaking::BreakAtWhitespace,
                 0,
                 None,
@@ -97,6 +97,12 @@ impl<'a> OpTextLayout<'a> {
                 Op::LineSpacing(line_spacing) => {
                     layout.style.line_spacing = line_spacing;
                 }
+                Op::Underline(underline) => {
+                    layout.style.underline = underline;
+                }
+                Op::Strikethrough(strikethrough) => {
+                    layout.style.strikethrough = strikethrough;
+                }
                 // Moving to the next page
                 Op::NextPage => {
                     // Pretending that nothing more fits on current page to force
@@ -237,6 +243,14 @@ impl<'a> OpTextLayout<'a> {
     pub fn line_spacing(self, spacing: i16) -> Self {
         self.with_new_item(Op::LineSpacing(spacing))
     }
+
+    pub fn underline(self, underline: bool) -> Self {
+        self.with_new_item(Op::Underline(underline))
+    }
+
+    pub fn strikethrough(self, strikethrough: bool) -> Self {
+        self.with_new_item(Op::Strikethrough(strikethrough))
+    }
 }
 
 // Op-adding aggregation operations
@@ -292,4 +306,8 @@ pub enum Op<'a> {
     Chunkify(Option<Chunks>),
     /// Change the line vertical line spacing.
     LineSpacing(i16),
+    /// Enable or disable underlining of the following text.
+    Underline(bool),
+    /// Enable or disable striking through the following text.
+    Strikethrough(bool),
 }
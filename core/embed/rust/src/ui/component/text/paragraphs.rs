@@ -1,15 +1,20 @@
+use core::cmp::Ordering;
+
 use heapless::Vec;
 
 use crate::{
     strutil::TString,
+    time::{Duration, Stopwatch},
     ui::{
         component::{Component, Event, EventCtx, Never, Paginate},
         display::{toif::Icon, Color, Font},
         geometry::{
             Alignment, Alignment2D, Dimensions, Insets, LinearPlacement, Offset, Point, Rect,
         },
+        lerp::Lerp,
         shape,
         shape::Renderer,
+        util::animation_disabled,
     },
 };
 
@@ -28,6 +33,13 @@ pub const DEFAULT_SPACING: i16 = 0;
 pub const PARAGRAPH_TOP_SPACE: i16 = -1;
 /// Offset of paragraph bounding box bottom relative to bottom of its text.
 pub const PARAGRAPH_BOTTOM_SPACE: i16 = 5;
+/// Minimum width of the space left of list items, holding the bullet or the
+/// number.
+pub const LIST_GUTTER_WIDTH: i16 = 20;
+/// Space between the number of a list item and its text.
+const LIST_NUMBER_SPACING: i16 = 6;
+/// Radius of the dot in front of bulleted list items.
+const LIST_BULLET_RADIUS: i16 = 2;
 
 pub type ParagraphVecLong<'a> = Vec<Paragraph<'a>, 32>;
 pub type ParagraphVecShort<'a> = Vec<Paragraph<'a>, 8>;
@@ -91,6 +103,17 @@ where
         &mut self.source
     }
 
+    /// Same paragraphs, with the source wrapped by `func`.
+    fn map_source<U>(self, func: impl FnOnce(T) -> U) -> Paragraphs<U> {
+        Paragraphs {
+            area: self.area,
+            placement: self.placement,
+            offset: self.offset,
+            visible: self.visible,
+            source: func(self.source),
+        }
+    }
+
     /// Update bounding boxes of paragraphs on the current page. First determine
     /// the number of visible paragraphs and their sizes. These are then
     /// arranged according to the layout.
@@ -140,20 +163,23 @@ where
         source: &'b dyn ParagraphSource<'a>,
         visible: &'b [TextLayoutProxy],
         offset: PageOffset,
-        func: &mut dyn FnMut(&TextLayout, &str),
+        func: &mut dyn FnMut(&TextLayout, &str, Option<ListMarker>),
     ) {
         let mut vis_iter = visible.iter();
         let mut chr = offset.chr;
 
         for par in offset.par..source.size() {
-            let s = source.at(par, chr).content;
+            let paragraph = source.at(par, chr);
+            let s = paragraph.content;
             if s.is_empty() {
                 chr = 0;
                 continue;
             }
             if let Some(layout_proxy) = vis_iter.next() {
                 let layout = layout_proxy.layout(source);
-                s.map(|t| func(&layout, t));
+                // Continuation of a list item on the next page has no marker.
+                let marker = paragraph.marker.filter(|_| chr == 0);
+                s.map(|t| func(&layout, t, marker));
             } else {
                 break;
             }
@@ -183,21 +209,32 @@ where
             &self.source,
             &self.visible,
             self.offset,
-            &mut |layout, content| {
+            &mut |layout, content, _marker| {
                 layout.render_text(content);
             },
         )
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        Self::foreach_visible(
-            &self.source,
-            &self.visible,
-            self.offset,
-            &mut |layout, content| {
-                layout.render_text2(content, target);
-            },
-        )
+        let render = |target: &mut _| {
+            Self::foreach_visible(
+                &self.source,
+                &self.visible,
+                self.offset,
+                &mut |layout, content, marker| {
+                    if let Some(marker) = marker {
+                        marker.render(layout, target);
+                    }
+                    layout.render_text2(content, target);
+                },
+            )
+        };
+        // A line overflowing a page too small for it is cut off.
+        if self.visible.iter().any(|l| l.bounds.y1 > self.area.y1) {
+            target.in_clip(self.area, &render);
+        } else {
+            render(target);
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -243,8 +280,11 @@ pub mod trace {
                     &self.source,
                     &self.visible,
                     self.offset,
-                    &mut |layout, content| {
+                    &mut |layout, content, marker| {
                         par_list.in_list(&|par| {
+                            if let Some(marker) = marker {
+                                par.string(&marker.text().as_str().into());
+                            }
                             layout.layout_text(
                                 content,
                                 &mut layout.initial_cursor(),
@@ -273,6 +313,8 @@ pub struct Paragraph<'a> {
     no_break: bool,
     padding_top: i16,
     padding_bottom: i16,
+    /// Render as a list item with this marker in front of it.
+    marker: Option<ListMarker>,
 }
 
 impl<'a> Paragraph<'a> {
@@ -285,6 +327,7 @@ impl<'a> Paragraph<'a> {
             no_break: false,
             padding_top: PARAGRAPH_TOP_SPACE,
             padding_bottom: PARAGRAPH_BOTTOM_SPACE,
+            marker: None,
         }
     }
 
@@ -313,6 +356,20 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Make the paragraph an item of a bulleted list. The text is indented
+    /// and the bullet is shown in the space left of it.
+    pub const fn with_bullet(mut self) -> Self {
+        self.marker = Some(ListMarker::Bullet);
+        self
+    }
+
+    /// Make the paragraph the `number`-th item of a numbered list of `count`
+    /// items. All items are indented the same, to fit the widest number.
+    pub const fn with_number(mut self, number: u8, count: u8) -> Self {
+        self.marker = Some(ListMarker::Number { number, count });
+        self
+    }
+
     pub fn content(&self) -> &TString<'a> {
         &self.content
     }
@@ -327,6 +384,11 @@ impl<'a> Paragraph<'a> {
     }
 
     fn layout(&self, area: Rect) -> TextLayout {
+        // Wrapped lines of list items stay aligned with the first one.
+        let area = match self.marker {
+            Some(marker) => area.inset(Insets::left(marker.gutter_width(self.style.text_font))),
+            None => area,
+        };
         TextLayout {
             padding_top: self.padding_top,
             padding_bottom: self.padding_bottom,
@@ -337,6 +399,61 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+#[derive(Clone, Copy)]
+pub enum ListMarker {
+    Bullet,
+    Number { number: u8, count: u8 },
+}
+
+impl ListMarker {
+    fn text(self) -> String<4> {
+        let mut text = String::new();
+        match self {
+            ListMarker::Bullet => unwrap!(text.push_str("•")),
+            ListMarker::Number { number, .. } => unwrap!(ufmt::uwrite!(text, "{}.", number)),
+        }
+        text
+    }
+
+    /// Width of the space left of the item text, fitting the marker of any
+    /// item of the list.
+    fn gutter_width(self, font: Font) -> i16 {
+        let ListMarker::Number { count, .. } = self else {
+            return LIST_GUTTER_WIDTH;
+        };
+        // Any number with as many digits as `count`.
+        let digits = count.checked_ilog10().unwrap_or(0) as i16 + 1;
+        let digit_width = ('0'..='9').map(|d| font.char_width(d)).max().unwrap_or(0);
+        let widest = digits * digit_width + font.char_width('.');
+        (widest + LIST_NUMBER_SPACING).max(LIST_GUTTER_WIDTH)
+    }
+
+    /// Render the marker into the gutter left of the first line of `layout`.
+    fn render<'s>(self, layout: &TextLayout, target: &mut impl Renderer<'s>) {
+        let style = &layout.style;
+        let baseline = layout.initial_cursor() - Offset::x(self.gutter_width(style.text_font));
+        match self {
+            ListMarker::Bullet => {
+                let center = baseline
+                    + Offset::new(
+                        LIST_BULLET_RADIUS,
+                        -style.text_font.allcase_text_height() / 2,
+                    );
+                shape::Circle::new(center, LIST_BULLET_RADIUS)
+                    .with_fg(style.text_color)
+                    .with_bg(style.text_color)
+                    .render(target);
+            }
+            ListMarker::Number { .. } => {
+                shape::Text::new(baseline, &self.text())
+                    .with_font(style.text_font)
+                    .with_fg(style.text_color)
+                    .render(target);
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct TextLayoutProxy {
     offset: PageOffset,
@@ -389,7 +506,7 @@ impl PageOffset {
     /// `next_offset.par == self.par + 1`.
     fn advance(
         mut self,
-        area: Rect,
+        mut area: Rect,
         source: &dyn ParagraphSource<'_>,
         full_height: i16,
     ) -> (PageOffset, Option<Rect>, Option<TextLayoutProxy>) {
@@ -421,7 +538,27 @@ impl PageOffset {
         // Find out the dimensions of the paragraph at given char offset.
         let mut layout = paragraph.layout(area);
         layout.continues_from_prev_page = self.chr > 0;
-        let fit = paragraph.content().map(|t| layout.fit_text(t));
+        let mut fit = paragraph.content().map(|t| layout.fit_text(t));
+
+        // Not even a single line fits on an empty page, e.g. a large font in a small
+        // area. Let the first line overflow the page, cut off when rendering, rather
+        // than never getting past this paragraph.
+        if matches!(
+            fit,
+            LayoutFit::OutOfBounds {
+                processed_chars: 0,
+                ..
+            }
+        ) && area.height() >= full_height
+        {
+            let font = paragraph.style.text_font;
+            area = area.with_height(
+                paragraph.padding_top + font.text_max_height() + paragraph.padding_bottom,
+            );
+            layout = paragraph.layout(area);
+            layout.continues_from_prev_page = self.chr > 0;
+            fit = paragraph.content().map(|t| layout.fit_text(t));
+        }
         let (used, remaining_area) = area.split_top(fit.height());
 
         let layout = TextLayoutProxy::new(self, used);
@@ -471,8 +608,8 @@ impl PageOffset {
         let val_height = next_paragraph
             .content()
             .map(|t| next_paragraph.layout(full_area).fit_text(t).height());
-        let screen_full_threshold = this_paragraph.style.text_font.line_height()
-            + next_paragraph.style.text_font.line_height();
+        let screen_full_threshold =
+            this_paragraph.style.line_height() + next_paragraph.style.line_height();
 
         if key_height + val_height > remaining_height {
             return
@@ -552,12 +689,56 @@ impl<'a, T: ParagraphSource<'a>> Iterator for PageBreakIterator<'_, T> {
     }
 }
 
+/// Text styles of `Checklist` items by their state.
+#[derive(Clone, Copy)]
+pub struct ChecklistStyles {
+    pub done: &'static TextStyle,
+    pub active: &'static TextStyle,
+    pub pending: &'static TextStyle,
+}
+
+/// Paragraph source of `Checklist`, restyling the items according to the
+/// active one.
+#[derive(Clone)]
+pub struct ChecklistItems<T> {
+    source: T,
+    current: usize,
+    styles: Option<ChecklistStyles>,
+}
+
+impl<'a, T> ParagraphSource<'a> for ChecklistItems<T>
+where
+    T: ParagraphSource<'a>,
+{
+    fn at(&self, index: usize, offset: usize) -> Paragraph<'a> {
+        let mut paragraph = self.source.at(index, offset);
+        if let Some(styles) = self.styles {
+            paragraph.style = match index.cmp(&self.current) {
+                Ordering::Less => styles.done,
+                Ordering::Equal => styles.active,
+                Ordering::Greater => styles.pending,
+            };
+        }
+        paragraph
+    }
+
+    fn size(&self) -> usize {
+        self.source.size()
+    }
+}
+
+/// Duration of the active icon moving to the next item.
+const CHECKLIST_TRANSITION: Duration = Duration::from_millis(300);
+
 pub struct Checklist<T> {
     area: Rect,
-    paragraphs: Paragraphs<T>,
-    current: usize,
+    paragraphs: Paragraphs<ChecklistItems<T>>,
+    /// Previously active item, for the transition animation.
+    previous: usize,
+    transition: Stopwatch,
     icon_current: Icon,
     icon_done: Icon,
+    icon_pending: Option<Icon>,
     icon_done_color: Option<Color>,
     show_numerals: bool,
     /// How wide will the left icon column be
@@ -566,6 +747,8 @@ pub struct Checklist<T> {
     done_offset: Offset,
     /// Offset of the icon representing CURRENT
     current_offset: Offset,
+    /// Offset of the icon representing PENDING
+    pending_offset: Offset,
 }
 
 impl<'a, T> Checklist<T>
@@ -580,15 +763,22 @@ where
     ) -> Self {
         Self {
             area: Rect::zero(),
-            paragraphs,
-            current,
+            paragraphs: paragraphs.map_source(|source| ChecklistItems {
+                source,
+                current,
+                styles: None,
+            }),
+            previous: current,
+            transition: Stopwatch::new_stopped(),
             icon_current,
             icon_done,
+            icon_pending: None,
             icon_done_color: None,
             show_numerals: false,
             check_width: 0,
             done_offset: Offset::zero(),
             current_offset: Offset::zero(),
+            pending_offset: Offset::zero(),
         }
     }
 
@@ -612,29 +802,109 @@ where
         self
     }
 
+    /// Icon in front of the items after the active one. Not shown together
+    /// with numerals.
+    pub fn with_icon_pending(mut self, icon: Icon, offset: Offset) -> Self {
+        self.icon_pending = Some(icon);
+        self.pending_offset = offset;
+        self
+    }
+
+    /// Style the items by their state instead of using the styles of the
+    /// paragraphs, so that they are restyled when the active item changes.
+    pub fn with_styles(mut self, styles: ChecklistStyles) -> Self {
+        self.paragraphs.inner_mut().styles = Some(styles);
+        self
+    }
+
     pub fn with_numerals(mut self, show_numerals: bool) -> Self {
         self.show_numerals = show_numerals;
         self
     }
 
+    pub fn current(&self) -> usize {
+        self.paragraphs.inner().current
+    }
+
+    /// Make `current` the active item, the previous ones are done. Equal to
+    /// the number of items when everything is done.
+    pub fn set_current(&mut self, ctx: &mut EventCtx, current: usize) {
+        if current == self.current() {
+            return;
+        }
+        self.previous = self.current();
+        self.paragraphs.inner_mut().current = current;
+        if !self.area.is_empty() {
+            // Restyled items may wrap differently.
+            self.place(self.area);
+        }
+        if !animation_disabled() {
+            self.transition.start();
+            ctx.request_anim_frame();
+        }
+        ctx.request_paint();
+    }
+
+    /// Mark the active item done and activate the next one.
+    pub fn advance(&mut self, ctx: &mut EventCtx) {
+        let next = (self.current() + 1).min(self.paragraphs.inner().size());
+        self.set_current(ctx, next);
+    }
+
+    fn transition_progress(&self) -> Option<f32> {
+        if self.transition.is_running_within(CHECKLIST_TRANSITION) {
+            Some(self.transition.elapsed() / CHECKLIST_TRANSITION)
+        } else {
+            None
+        }
+    }
+
+    /// Top left corner of the item with the given index, if on this page.
+    fn item_base(&self, index: usize) -> Option<Point> {
+        let visible = index.checked_sub(self.paragraphs.offset.par)?;
+        let layout = self.paragraphs.visible.get(visible)?;
+        let l = layout.layout(&self.paragraphs.source);
+        Some(Point::new(self.area.x0, l.bounds.y0))
+    }
+
     fn render_left_column<'s>(&self, target: &mut impl Renderer<'s>) {
-        let current_visible = self.current.saturating_sub(self.paragraphs.offset.par);
+        let current = self.current();
+        let current_visible = current.saturating_sub(self.paragraphs.offset.par);
+        let transition = self.transition_progress();
         for (i, layout) in self.paragraphs.visible.iter().enumerate() {
             let l = &layout.layout(&self.paragraphs.source);
             let base = Point::new(self.area.x0, l.bounds.y0);
             if i < current_visible {
                 // finished tasks - labeled with icon "done"
                 let color = self.icon_done_color.unwrap_or(l.style.text_color);
-                self.render_icon(base + self.done_offset, self.icon_done, color, target)
-            } else {
-                // current and future tasks - ordinal numbers or icon on current task
-                if self.show_numerals {
-                    let num_offset = Offset::y(Font::NORMAL.visible_text_height("1"));
-                    self.render_numeral(base + num_offset, i, l.style.text_color, target);
-                } else if i == current_visible {
-                    let color = l.style.text_color;
-                    self.render_icon(base + self.current_offset, self.icon_current, color, target);
+                let item = i + self.paragraphs.offset.par;
+                let alpha = match transition {
+                    // fade in the icon of the task just finished
+                    Some(t) if item >= self.previous => u8::lerp(0, 255, t),
+                    _ => 255,
+                };
+                self.render_icon(
+                    base + self.done_offset,
+                    self.icon_done,
+                    color,
+                    alpha,
+                    target,
+                )
+            } else if self.show_numerals {
+                // current and future tasks - ordinal numbers
+                let num_offset = Offset::y(Font::NORMAL.visible_text_height("1"));
+                self.render_numeral(base + num_offset, i, l.style.text_color, target);
+            } else if i == current_visible {
+                let color = l.style.text_color;
+                let mut point = base + self.current_offset;
+                if let (Some(t), Some(from)) = (transition, self.item_base(self.previous)) {
+                    // slide from the previously active task
+                    point = Point::lerp(from + self.current_offset, point, t);
                 }
+                self.render_icon(point, self.icon_current, color, 255, target);
+            } else if let Some(icon) = self.icon_pending {
+                let color = l.style.text_color;
+                self.render_icon(base + self.pending_offset, icon, color, 255, target);
             }
         }
     }
@@ -668,10 +938,12 @@ where
         base_point: Point,
         icon: Icon,
         color: Color,
+        alpha: u8,
         target: &mut impl Renderer<'s>,
     ) {
         shape::ToifImage::new(base_point, icon.toif)
             .with_fg(color)
+            .with_alpha(alpha)
             .render(target);
     }
 }
@@ -690,13 +962,23 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
+            if self.transition.is_running() {
+                if self.transition_progress().is_none() {
+                    self.transition.stop();
+                } else {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+        }
         self.paragraphs.event(ctx, event)
     }
 
     fn paint(&mut self) {
         self.paragraphs.paint();
 
-        let current_visible = self.current.saturating_sub(self.paragraphs.offset.par);
+        let current_visible = self.current().saturating_sub(self.paragraphs.offset.par);
         for layout in self.paragraphs.visible.iter().take(current_visible) {
             self.paint_icon(
                 &layout.layout(&self.paragraphs.source),
@@ -730,17 +1012,19 @@ where
     T: ParagraphSource<'a>,
 {
     fn page_count(&mut self) -> usize {
-        1
+        self.paragraphs.page_count()
     }
 
-    fn change_page(&mut self, _to_page: usize) {}
+    fn change_page(&mut self, to_page: usize) {
+        self.paragraphs.change_page(to_page)
+    }
 }
 
 #[cfg(feature = "ui_debug")]
 impl<'a, T: ParagraphSource<'a>> crate::trace::Trace for Checklist<T> {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Checklist");
-        t.int("current", self.current as i64);
+        t.int("current", self.current() as i64);
         t.child("items", &self.paragraphs);
     }
 }
@@ -804,3 +1088,22 @@ impl<'a> ParagraphSource<'a> for Paragraph<'a> {
         1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_gutter_fits_numbers() {
+        let font = Font::NORMAL;
+        for count in [1, 9, 10, 99, 100, u8::MAX] {
+            let gutter = ListMarker::Number { number: 1, count }.gutter_width(font);
+            assert!(gutter >= LIST_GUTTER_WIDTH);
+            for number in 1..=count {
+                let marker = ListMarker::Number { number, count };
+                assert!(font.text_width(&marker.text()) + LIST_NUMBER_SPACING <= gutter);
+            }
+        }
+        assert_eq!(ListMarker::Bullet.gutter_width(font), LIST_GUTTER_WIDTH);
+    }
+}
@@ -1,14 +1,14 @@
 use crate::{
-    strutil::TString,
+    strutil::{ShortString, TString},
     ui::{
-        display::{Color, Font},
+        display::{Color, Font, GlyphMetrics},
         geometry::{Alignment, Rect},
         shape::Renderer,
     },
 };
 
 use super::{
-    layout::{LayoutFit, TextLayout},
+    layout::{LayoutFit, TextLayout, ELLIPSIS},
     TextStyle,
 };
 
@@ -125,3 +125,103 @@ pub fn text_multiline_bottom2<'s>(
         }
     })
 }
+
+/// Shortens `text` to fit into `max_width` by replacing its middle part with an
+/// ellipsis, e.g. "bc1qxy...0wlh". The beginning and the end of the text are
+/// kept at roughly the same length, the beginning gets the extra character if
+/// the available width is not split evenly. Text that already fits is returned
+/// unchanged, and if not even the ellipsis fits, the result is empty.
+pub fn truncate_middle(text: &str, font: impl GlyphMetrics, max_width: i16) -> ShortString {
+    let mut result = ShortString::new();
+    if font.text_width(text) <= max_width && result.push_str(text).is_ok() {
+        return result;
+    }
+    result.clear();
+
+    let budget = max_width - font.text_width(ELLIPSIS);
+    if budget < 0 {
+        return result;
+    }
+    // Space for the kept characters in the result.
+    let capacity = result.capacity() - ELLIPSIS.len();
+
+    // Byte offsets of the end of the prefix and of the start of the suffix.
+    let mut head = 0;
+    let mut tail = text.len();
+    let mut width = 0;
+    let mut from_head = true;
+    loop {
+        let rest = &text[head..tail];
+        let next = if from_head {
+            rest.chars().next()
+        } else {
+            rest.chars().next_back()
+        };
+        let Some(ch) = next else {
+            break;
+        };
+        width += font.char_width(ch);
+        let kept = head + (text.len() - tail) + ch.len_utf8();
+        if width > budget || kept > capacity {
+            break;
+        }
+        if from_head {
+            head += ch.len_utf8();
+        } else {
+            tail -= ch.len_utf8();
+        }
+        from_head = !from_head;
+    }
+
+    unwrap!(result.push_str(&text[..head]));
+    unwrap!(result.push_str(ELLIPSIS));
+    unwrap!(result.push_str(&text[tail..]));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every character is 1px wide.
+    struct Fixed;
+
+    impl GlyphMetrics for Fixed {
+        fn char_width(&self, _ch: char) -> i16 {
+            1
+        }
+
+        fn text_width(&self, text: &str) -> i16 {
+            text.chars().count() as i16
+        }
+
+        fn line_height(&self) -> i16 {
+            1
+        }
+    }
+
+    #[test]
+    fn test_truncate_middle() {
+        assert_eq!(truncate_middle("bc1qxy2k0wlh", Fixed, 12), "bc1qxy2k0wlh");
+        assert_eq!(truncate_middle("bc1qxy2k0wlh", Fixed, 11), "bc1q...0wlh");
+        // Odd split, the beginning is longer.
+        assert_eq!(truncate_middle("bc1qxy2k0wlh", Fixed, 10), "bc1q...wlh");
+        assert_eq!(truncate_middle("bc1qxy2k0wlh", Fixed, 3), "...");
+        assert_eq!(truncate_middle("bc1qxy2k0wlh", Fixed, 2), "");
+        assert_eq!(truncate_middle("", Fixed, 0), "");
+    }
+
+    #[test]
+    fn test_truncate_middle_utf8() {
+        assert_eq!(truncate_middle("čšřžýáíé", Fixed, 7), "čš...íé");
+        assert_eq!(truncate_middle("čšřžýáíé", Fixed, 6), "čš...é");
+    }
+
+    #[test]
+    fn test_truncate_middle_capacity() {
+        let long = "a".repeat(60);
+        let short = truncate_middle(&long, Fixed, 100);
+        assert_eq!(short.len(), short.capacity());
+        assert!(short.contains(ELLIPSIS));
+    }
+}
@@ -1,59 +1,192 @@
 use crate::{
     time::Duration,
     ui::{
-        component::{Component, Event, EventCtx, TimerToken},
+        component::{Component, Empty, Event, EventCtx, Timer},
         geometry::Rect,
         shape::Renderer,
     },
 };
 
+pub enum TimeoutMsg<T> {
+    /// The time ran out before the content emitted anything.
+    TimedOut,
+    Content(T),
+}
+
+/// Wraps `content` and emits `TimeoutMsg::TimedOut` when a given time passes
+/// since the component was attached. The timer is restarted every time the
+/// component is attached again (e.g. when swiping back onto the screen), and
+/// stopped once the content emits a message.
+///
+/// Without content, `Timeout` can be used on its own as a part of a larger
+/// layout.
 #[derive(Clone)]
-pub struct Timeout {
+pub struct Timeout<T = Empty> {
+    content: T,
     time_ms: u32,
-    timer: Option<TimerToken>,
+    timer: Timer,
+    restart_on_input: bool,
 }
 
 impl Timeout {
     pub fn new(time_ms: u32) -> Self {
+        Self::wrap(Empty, time_ms)
+    }
+}
+
+impl<T> Timeout<T>
+where
+    T: Component,
+{
+    pub fn wrap(content: T, time_ms: u32) -> Self {
         Self {
+            content,
             time_ms,
-            timer: None,
+            timer: Timer::new(),
+            restart_on_input: false,
         }
     }
+
+    /// Start counting again from zero on every touch or button event, so that
+    /// the timeout only fires after a period of inactivity.
+    pub fn with_restart_on_input(mut self) -> Self {
+        self.restart_on_input = true;
+        self
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.content
+    }
+
+    fn start(&mut self, ctx: &mut EventCtx) {
+        self.timer.start(ctx, Duration::from_millis(self.time_ms));
+    }
 }
 
-impl Component for Timeout {
-    type Msg = ();
+fn is_input(event: Event) -> bool {
+    match event {
+        #[cfg(feature = "button")]
+        Event::Button(_) => true,
+        #[cfg(feature = "touch")]
+        Event::Touch(_) | Event::Swipe(_) => true,
+        _ => false,
+    }
+}
 
-    fn place(&mut self, _bounds: Rect) -> Rect {
-        Rect::zero()
+impl<T> Component for Timeout<T>
+where
+    T: Component,
+{
+    type Msg = TimeoutMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.content.place(bounds)
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         match event {
-            // Set up timer.
-            Event::Attach(_) => {
-                self.timer = Some(ctx.request_timer(Duration::from_millis(self.time_ms)));
-                None
-            }
-            // Fire.
-            Event::Timer(token) if Some(token) == self.timer => {
-                self.timer = None;
-                Some(())
+            Event::Attach(_) => self.start(ctx),
+            _ if self.timer.expire(event) => return Some(TimeoutMsg::TimedOut),
+            _ if self.restart_on_input && self.timer.is_running() && is_input(event) => {
+                self.start(ctx)
             }
-            _ => None,
+            _ => {}
         }
+        let msg = self.content.event(ctx, event)?;
+        self.timer.stop(ctx);
+        Some(TimeoutMsg::Content(msg))
     }
 
-    fn paint(&mut self) {}
+    fn paint(&mut self) {
+        self.content.paint()
+    }
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        self.content.render(target)
+    }
 
-    fn render<'s>(&'s self, _target: &mut impl Renderer<'s>) {}
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.content.bounds(sink)
+    }
 }
 
 #[cfg(feature = "ui_debug")]
-impl crate::trace::Trace for Timeout {
+impl<T> crate::trace::Trace for Timeout<T>
+where
+    T: crate::trace::Trace,
+{
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Timeout");
         t.int("time_ms", self.time_ms as i64);
+        t.child("content", &self.content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::component::{AttachType, TimerToken};
+
+    /// Content emitting a message on every animation frame.
+    struct Eager;
+
+    impl Component for Eager {
+        type Msg = ();
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            matches!(event, Event::Timer(EventCtx::ANIM_FRAME_TIMER)).then_some(())
+        }
+
+        fn paint(&mut self) {}
+
+        fn render<'s>(&'s self, _target: &mut impl Renderer<'s>) {}
+    }
+
+    /// Attach the component and return the token of the timer it requested.
+    fn attach<T: Component>(timeout: &mut Timeout<T>, ctx: &mut EventCtx) -> TimerToken {
+        timeout.event(ctx, Event::Attach(AttachType::Initial));
+        let (token, _) = ctx.pop_timer().unwrap();
+        token
+    }
+
+    fn timed_out<T>(msg: Option<TimeoutMsg<T>>) -> bool {
+        matches!(msg, Some(TimeoutMsg::TimedOut))
+    }
+
+    #[test]
+    fn times_out() {
+        let mut ctx = EventCtx::new();
+        let mut timeout = Timeout::new(1000);
+        let fired = Event::Timer(attach(&mut timeout, &mut ctx));
+        assert!(timed_out(timeout.event(&mut ctx, fired)));
+        // Only once.
+        assert!(timeout.event(&mut ctx, fired).is_none());
+    }
+
+    #[test]
+    fn reattach_restarts() {
+        let mut ctx = EventCtx::new();
+        let mut timeout = Timeout::new(1000);
+        let first = attach(&mut timeout, &mut ctx);
+        let second = attach(&mut timeout, &mut ctx);
+        assert!(ctx.take_cancelled_timer(first));
+        assert!(timeout.event(&mut ctx, Event::Timer(first)).is_none());
+        assert!(timed_out(timeout.event(&mut ctx, Event::Timer(second))));
+    }
+
+    #[test]
+    fn content_first() {
+        let mut ctx = EventCtx::new();
+        let mut timeout = Timeout::wrap(Eager, 1000);
+        let fired = Event::Timer(attach(&mut timeout, &mut ctx));
+        let msg = timeout.event(&mut ctx, Event::Timer(EventCtx::ANIM_FRAME_TIMER));
+        assert!(matches!(msg, Some(TimeoutMsg::Content(()))));
+        assert!(!timeout.timer.is_running());
+        assert!(timeout.event(&mut ctx, fired).is_none());
     }
 }
@@ -13,6 +13,7 @@ use super::{get_color_table, get_offset, pixeldata, set_window, Color};
 /// Representation of a single glyph.
 /// We use standard typographic terms. For a nice explanation, see, e.g.,
 /// the FreeType docs at https://www.freetype.org/freetype2/docs/glyphs/glyphs-3.html
+#[derive(Clone, Copy)]
 pub struct Glyph {
     /// Total width of the glyph itself
     pub width: i16,
@@ -121,6 +122,27 @@ impl Glyph {
         }
     }
 
+    /// Decode the glyph into `buf` as a MONO4 bitmap with rows aligned to
+    /// bytes, the format of `bitmap()` for 4-bit fonts. Returns the number of
+    /// bytes written, `None` if `buf` is too small.
+    pub fn decode_mono4(&self, buf: &mut [u8]) -> Option<usize> {
+        let stride = (self.width as usize + 1) / 2;
+        let len = stride * self.height as usize;
+        let buf = buf.get_mut(..len)?;
+        if constant::FONT_BPP == 4 {
+            buf.copy_from_slice(self.data);
+        } else {
+            buf.fill(0);
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let pixel = self.get_pixel_data(Offset::new(x, y));
+                    buf[y as usize * stride + x as usize / 2] |= pixel << (4 - (x % 2) * 4);
+                }
+            }
+        }
+        Some(len)
+    }
+
     pub fn bitmap(&self) -> Bitmap<'static> {
         match constant::FONT_BPP {
             1 => unwrap!(Bitmap::new(
@@ -165,8 +187,11 @@ impl From<Font> for i32 {
 }
 
 impl Font {
+    /// Sum of the advances of the glyphs drawn for `text`, including the
+    /// fallback and placeholder ones.
     pub fn text_width(self, text: &str) -> i16 {
-        display::text_width(text, self.into())
+        text.chars()
+            .fold(0, |width, c| width.saturating_add(self.get_glyph(c).adv))
     }
 
     /// Supports UTF8 characters
@@ -242,7 +267,7 @@ impl Font {
     }
 
     pub fn char_width(self, ch: char) -> i16 {
-        display::char_width(ch, self.into())
+        self.get_glyph(ch).adv
     }
 
     pub fn text_height(self) -> i16 {
@@ -284,14 +309,80 @@ impl Font {
         (start + end + self.visible_text_height(text)) / 2
     }
 
+    /// Glyph of `ch` from this font or the first of its fallbacks having it.
+    /// Characters none of them has are drawn as a placeholder box instead of
+    /// being skipped, so that the text shown for confirmation is not silently
+    /// altered.
     pub fn get_glyph(self, ch: char) -> Glyph {
-        let gl_data = display::get_char_glyph(ch as u16, self.into());
+        self.find_glyph(ch)
+            .unwrap_or_else(|| self.placeholder_glyph())
+    }
+
+    /// Whether `ch` is drawn as itself and not as the placeholder.
+    pub fn has_glyph(self, ch: char) -> bool {
+        self.find_glyph(ch).is_some()
+    }
+
+    /// Box with a question mark drawn in place of missing characters.
+    pub fn placeholder_glyph(self) -> Glyph {
+        let gl_data = display::get_nonprintable_glyph(self.into());
 
         ensure!(!gl_data.is_null(), "Failed to load glyph");
-        // SAFETY: Glyph::load is valid for data returned by get_char_glyph
+        // SAFETY: Glyph::load is valid for data returned by get_nonprintable_glyph
         unsafe { Glyph::load(gl_data) }
     }
 
+    fn find_glyph(self, ch: char) -> Option<Glyph> {
+        let mut font = Some(self);
+        while let Some(f) = font {
+            if let Some(glyph) = f.own_glyph(ch) {
+                return Some(glyph);
+            }
+            font = f.fallback();
+        }
+        None
+    }
+
+    /// Glyph of `ch` in this very font, if it has one.
+    fn own_glyph(self, ch: char) -> Option<Glyph> {
+        // Fonts are indexed by 16-bit codepoints, anything above is missing.
+        let ch = u16::try_from(u32::from(ch)).ok()?;
+        let gl_data = display::get_char_glyph(ch, self.into());
+        // Missing characters get the placeholder, disabled fonts nothing at all.
+        if gl_data.is_null() || gl_data == display::get_nonprintable_glyph(self.into()) {
+            return None;
+        }
+        // SAFETY: Glyph::load is valid for data returned by get_char_glyph
+        Some(unsafe { Glyph::load(gl_data) })
+    }
+
+    /// Next font to look into for characters missing from this one. Fonts not
+    /// enabled for the model are skipped.
+    const fn fallback(self) -> Option<Font> {
+        match self {
+            Font::NORMAL => None,
+            Font::NORMAL_UPPER => Some(Font::NORMAL),
+            Font::BOLD_UPPER => Some(Font::BOLD),
+            _ => Some(Font::NORMAL),
+        }
+    }
+
+    /// Call `f` with the bearing, the advance and the bitmap of the glyph of
+    /// `ch`, through the cache of recently drawn glyphs if enabled.
+    pub fn with_glyph_bitmap<R>(self, ch: char, f: impl FnOnce(Offset, i16, &Bitmap) -> R) -> R {
+        #[cfg(feature = "ui_glyph_cache")]
+        return super::glyph_cache::with_glyph_bitmap(self, ch, f);
+        #[cfg(not(feature = "ui_glyph_cache"))]
+        {
+            let glyph = self.get_glyph(ch);
+            f(
+                Offset::new(glyph.bearing_x, glyph.bearing_y),
+                glyph.adv,
+                &glyph.bitmap(),
+            )
+        }
+    }
+
     pub fn display_text(self, text: &str, baseline: Point, fg_color: Color, bg_color: Color) {
         let colortable = get_color_table(fg_color, bg_color);
         let mut adv_total = 0;
@@ -333,6 +424,12 @@ pub trait GlyphMetrics {
     fn char_width(&self, ch: char) -> i16;
     fn text_width(&self, text: &str) -> i16;
     fn line_height(&self) -> i16;
+
+    /// Space after a character, included in its `char_width`. The last
+    /// character of a text has none in `text_width`.
+    fn letter_spacing(&self) -> i16 {
+        0
+    }
 }
 
 impl GlyphMetrics for Font {
@@ -348,3 +445,32 @@ impl GlyphMetrics for Font {
         Font::line_height(*self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strutil::TString;
+
+    #[test]
+    fn missing_glyph_placeholder() {
+        // Emoji are never in the fonts, Cyrillic only with a translations blob.
+        const TEXT: &str = "a😀Ж";
+        let placeholder = Font::NORMAL.placeholder_glyph();
+
+        assert!(Font::NORMAL.has_glyph('a'));
+        for ch in ['😀', 'Ж'] {
+            assert!(!Font::NORMAL.has_glyph(ch));
+            let glyph = Font::NORMAL.get_glyph(ch);
+            assert_eq!(glyph.adv, placeholder.adv);
+            assert_eq!(glyph.width, placeholder.width);
+            assert!(glyph.adv > 0);
+        }
+        assert_eq!(
+            Font::NORMAL.text_width(TEXT),
+            Font::NORMAL.char_width('a') + 2 * placeholder.adv
+        );
+
+        assert!(TString::from_str(TEXT).has_unrenderable_chars(Font::NORMAL));
+        assert!(!TString::from_str("abc").has_unrenderable_chars(Font::NORMAL));
+    }
+}
@@ -0,0 +1,214 @@
+//! Recently drawn glyphs decoded into 4-bit bitmaps in RAM, so that the same
+//! few characters repeated in a frame, e.g. the digits of a PIN or an address,
+//! are not looked up and decoded from the font data over and over. The oldest
+//! entry is replaced when the cache is full.
+//!
+//! The entries keep a copy of the glyph data instead of pointing into the
+//! font, so glyphs from the translations blob are cached as well. The cache is
+//! cleared when the blob is replaced.
+
+use crate::ui::{
+    geometry::Offset,
+    shape::{Bitmap, BitmapFormat},
+};
+
+use super::font::{Font, Glyph};
+
+/// Number of glyphs kept.
+const GLYPH_CACHE_SIZE: usize = 16;
+/// Bytes of decoded bitmap kept for each glyph. Enough for the digits and
+/// letters of the 20px fonts, larger glyphs are drawn from the font directly.
+const GLYPH_DATA_SIZE: usize = 128;
+
+struct Entry {
+    font: Font,
+    ch: char,
+    size: Offset,
+    bearing: Offset,
+    adv: i16,
+    /// MONO4 bitmap of the glyph, see `Glyph::decode_mono4`.
+    data: [u8; GLYPH_DATA_SIZE],
+}
+
+impl Entry {
+    /// Decode `glyph` of `ch`, `None` if it is too large to be cached.
+    fn decode(font: Font, ch: char, glyph: &Glyph) -> Option<Self> {
+        let mut data = [0; GLYPH_DATA_SIZE];
+        glyph.decode_mono4(&mut data)?;
+        Some(Self {
+            font,
+            ch,
+            size: Offset::new(glyph.width, glyph.height),
+            bearing: Offset::new(glyph.bearing_x, glyph.bearing_y),
+            adv: glyph.adv,
+            data,
+        })
+    }
+
+    fn bitmap(&self) -> Bitmap<'_> {
+        let len = (self.size.x as usize + 1) / 2 * self.size.y as usize;
+        unwrap!(Bitmap::new(
+            BitmapFormat::MONO4,
+            None,
+            self.size,
+            None,
+            &self.data[..len],
+        ))
+    }
+}
+
+struct GlyphCache {
+    entries: [Option<Entry>; GLYPH_CACHE_SIZE],
+    /// Entry to be replaced next.
+    next: usize,
+}
+
+impl GlyphCache {
+    const EMPTY: Option<Entry> = None;
+
+    const fn new() -> Self {
+        Self {
+            entries: [Self::EMPTY; GLYPH_CACHE_SIZE],
+            next: 0,
+        }
+    }
+
+    fn get(&self, font: Font, ch: char) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|e| e.font == font && e.ch == ch)
+    }
+
+    fn insert(&mut self, entry: Entry) -> &Entry {
+        let index = self.next;
+        self.next = (self.next + 1) % GLYPH_CACHE_SIZE;
+        self.entries[index].insert(entry)
+    }
+
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+static mut GLYPH_CACHE: GlyphCache = GlyphCache::new();
+
+fn glyph_cache() -> &'static mut GlyphCache {
+    // SAFETY: single-threaded access
+    unsafe { &mut *core::ptr::addr_of_mut!(GLYPH_CACHE) }
+}
+
+/// Call `f` with the bearing, the advance and the bitmap of the glyph of `ch`
+/// in `font`, decoded into the cache unless drawn recently.
+pub fn with_glyph_bitmap<R>(font: Font, ch: char, f: impl FnOnce(Offset, i16, &Bitmap) -> R) -> R {
+    let cache = glyph_cache();
+    if let Some(entry) = cache.get(font, ch) {
+        return f(entry.bearing, entry.adv, &entry.bitmap());
+    }
+    let glyph = font.get_glyph(ch);
+    match Entry::decode(font, ch, &glyph) {
+        Some(entry) => {
+            let entry = cache.insert(entry);
+            f(entry.bearing, entry.adv, &entry.bitmap())
+        }
+        None => f(
+            Offset::new(glyph.bearing_x, glyph.bearing_y),
+            glyph.adv,
+            &glyph.bitmap(),
+        ),
+    }
+}
+
+/// Forget all the glyphs, to be called when the fonts change.
+pub fn clear() {
+    glyph_cache().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::constant;
+    use core::hint::black_box;
+    use std::time::{Duration, Instant};
+
+    fn entry(font: Font, ch: char) -> Entry {
+        unwrap!(Entry::decode(font, ch, &font.get_glyph(ch)))
+    }
+
+    #[test]
+    fn round_robin() {
+        let mut cache = GlyphCache::new();
+        for (i, ch) in ('a'..).take(GLYPH_CACHE_SIZE).enumerate() {
+            cache.insert(entry(Font::MONO, ch));
+            assert_eq!(cache.next, (i + 1) % GLYPH_CACHE_SIZE);
+        }
+        assert!(cache.get(Font::MONO, 'a').is_some());
+        assert!(cache.get(Font::NORMAL, 'a').is_none());
+
+        // The oldest one goes first.
+        cache.insert(entry(Font::MONO, '0'));
+        assert!(cache.get(Font::MONO, 'a').is_none());
+        assert!(cache.get(Font::MONO, 'b').is_some());
+        assert!(cache.get(Font::MONO, '0').is_some());
+
+        cache.clear();
+        assert!(cache.get(Font::MONO, '0').is_none());
+    }
+
+    /// Pixel of a MONO4 bitmap.
+    fn mono4_pixel(bitmap: &Bitmap, x: i16, y: i16) -> u8 {
+        let row: &[u8] = unwrap!(bitmap.row(y));
+        (row[x as usize / 2] >> (4 - (x % 2) * 4)) & 0x0F
+    }
+
+    #[test]
+    fn decoded_as_drawn() {
+        for ch in "0123456789ABCabc".chars() {
+            let glyph = Font::MONO.get_glyph(ch);
+            let bitmap = glyph.bitmap();
+            let entry = entry(Font::MONO, ch);
+            let decoded = entry.bitmap();
+            assert!(decoded.size() == bitmap.size());
+            for y in 0..glyph.height {
+                for x in 0..glyph.width {
+                    let expected = match constant::FONT_BPP {
+                        4 => mono4_pixel(&bitmap, x, y),
+                        _ => glyph.get_pixel_data(Offset::new(x, y)),
+                    };
+                    assert_eq!(mono4_pixel(&decoded, x, y), expected);
+                }
+            }
+        }
+    }
+
+    /// Run with `--ignored`. Looks up the glyphs of a 50-character MONO string
+    /// for drawing, from the font and through the cache, and fails unless the
+    /// cache is faster.
+    #[test]
+    #[ignore]
+    fn bench_mono_text() {
+        const TEXT: &str = "1234 5678 1234 5678 1234 5678 1234 5678 1234 5678";
+        const ROUNDS: u32 = 10000;
+
+        fn measure(lookup: impl Fn(char) -> i16) -> Duration {
+            let start = Instant::now();
+            for _ in 0..ROUNDS {
+                for ch in TEXT.chars() {
+                    black_box(lookup(black_box(ch)));
+                }
+            }
+            start.elapsed() / ROUNDS
+        }
+
+        clear();
+        let uncached = measure(|ch| Font::MONO.get_glyph(ch).bitmap().width());
+        let cached = measure(|ch| with_glyph_bitmap(Font::MONO, ch, |_, _, bitmap| bitmap.width()));
+        assert!(
+            cached < uncached,
+            "{} glyphs: {:?} cached, {:?} uncached",
+            TEXT.len(),
+            cached,
+            uncached
+        );
+    }
+}
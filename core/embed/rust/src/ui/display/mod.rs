@@ -1,5 +1,7 @@
 pub mod color;
 pub mod font;
+#[cfg(feature = "ui_glyph_cache")]
+pub mod glyph_cache;
 pub mod image;
 pub mod loader;
 #[cfg(feature = "jpeg")]
@@ -1153,6 +1155,20 @@ pub fn refresh() {
     display::refresh();
 }
 
+/// Limit the next refresh to `region`. Returns `false` if the display does
+/// not support partial updates, the whole screen has to be drawn then.
+pub fn set_refresh_region(region: Rect) -> bool {
+    #[cfg(feature = "xframebuffer")]
+    {
+        display::set_refresh_region(region.x0, region.y0, region.x1, region.y1)
+    }
+    #[cfg(not(feature = "xframebuffer"))]
+    {
+        let _ = region;
+        false
+    }
+}
+
 pub fn get_color_table(fg_color: Color, bg_color: Color) -> [Color; 16] {
     let mut table: [Color; 16] = [Color::from_u16(0); 16];
 
@@ -69,6 +69,31 @@ pub enum USBEvent {
     Connected(bool),
 }
 
+/// Key typed on the keyboard of the computer running the emulator.
+#[cfg(feature = "host_keyboard")]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum HostKey {
+    /// Printable ASCII character.
+    Char(char),
+    Backspace,
+    Enter,
+    Escape,
+}
+
+#[cfg(feature = "host_keyboard")]
+impl HostKey {
+    pub fn new(code: u32) -> Result<Self, error::Error> {
+        let result = match code {
+            0x08 => Self::Backspace,
+            0x0D => Self::Enter,
+            0x1B => Self::Escape,
+            0x20..=0x7E => Self::Char(char::from(code as u8)),
+            _ => return Err(error::Error::OutOfRange),
+        };
+        Ok(result)
+    }
+}
+
 #[cfg(feature = "touch")]
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum SwipeEvent {
@@ -33,6 +33,21 @@ pub enum Decision<Q> {
     /// Yield a message to the caller of the flow (i.e. micropython), end event
     /// processing.
     Return(FlowMsg),
+
+    /// Move directly to any state, not only the adjacent ones, end event
+    /// processing. The target is attached as if swiped in from the given
+    /// direction, or without animation. See `SwipeFlow::jump`.
+    Jump(Q, Option<SwipeDirection>, History),
+}
+
+/// What a jump does with the back-swipe history of the flow.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum History {
+    /// Remember the state jumped from, swiping back from the target returns
+    /// there.
+    Record,
+    /// Forget all the recorded jumps.
+    Clear,
 }
 
 impl<Q> Decision<Q> {
@@ -3,7 +3,7 @@ pub mod page;
 mod store;
 mod swipe;
 
-pub use base::{FlowMsg, FlowState, Swipable};
+pub use base::{FlowMsg, FlowState, History, Swipable};
 pub use page::SwipePage;
 pub use store::{flow_store, FlowStore};
 pub use swipe::SwipeFlow;
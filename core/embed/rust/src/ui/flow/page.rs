@@ -1,10 +1,28 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx, Paginate, SwipeDirection},
-    event::SwipeEvent,
-    geometry::{Axis, Rect},
-    shape::Renderer,
+#[cfg(feature = "haptic")]
+use crate::trezorhal::haptic::{play, HapticEffect};
+use crate::{
+    time::{Duration, Stopwatch},
+    ui::{
+        component::{Component, Event, EventCtx, Paginate, SwipeDetect, SwipeDirection},
+        event::{SwipeEvent, TouchEvent},
+        geometry::{Axis, Offset, Point, Rect},
+        lerp::Lerp,
+        shape::Renderer,
+        util::animation_disabled,
+    },
 };
 
+use easer::functions::{Cubic, Easing};
+
+/// Dragging past the first or last page moves the content by the drag
+/// distance divided by this.
+const OVERSCROLL_DAMPING: i16 = 4;
+/// Maximum displacement of the content when dragged past the first or last
+/// page.
+const OVERSCROLL_MAX: i16 = 30;
+/// Duration of the content returning back after released.
+const BOUNCE_DURATION: Duration = Duration::from_millis(250);
+
 /// Allows any implementor of `Paginate` to be part of `Swipable` UI flow.
 /// Renders sliding animation when changing pages.
 pub struct SwipePage<T> {
@@ -13,26 +31,141 @@ pub struct SwipePage<T> {
     axis: Axis,
     pages: usize,
     current: usize,
+    /// Origin of the touch in progress, unless it turned into a swipe handled
+    /// by the flow.
+    drag_origin: Option<Point>,
+    /// The touch went past the first or last page further than the flow needs
+    /// to lock a swipe, so the flow does not allow swiping that way.
+    overscroll_locked: bool,
+    /// Displacement of the content dragged past the first or last page.
+    overscroll: i16,
+    /// Running while the content is returning back from `overscroll`.
+    bounce: Stopwatch,
+    haptic: bool,
 }
 
 impl<T: Component + Paginate> SwipePage<T> {
     pub fn vertical(inner: T) -> Self {
-        Self {
-            inner,
-            bounds: Rect::zero(),
-            axis: Axis::Vertical,
-            pages: 1,
-            current: 0,
-        }
+        Self::new(inner, Axis::Vertical)
     }
 
     pub fn horizontal(inner: T) -> Self {
+        Self::new(inner, Axis::Horizontal)
+    }
+
+    fn new(inner: T, axis: Axis) -> Self {
         Self {
             inner,
             bounds: Rect::zero(),
-            axis: Axis::Horizontal,
+            axis,
             pages: 1,
             current: 0,
+            drag_origin: None,
+            overscroll_locked: false,
+            overscroll: 0,
+            bounce: Stopwatch::new_stopped(),
+            haptic: true,
+        }
+    }
+
+    /// Whether to play a haptic tick when dragged past the first or last page.
+    pub fn with_haptic(mut self, haptic: bool) -> Self {
+        self.haptic = haptic;
+        self
+    }
+
+    /// Damped displacement of the content dragged by `delta`, zero unless
+    /// dragged past the first or last page.
+    ///
+    /// Swipes the flow allows are locked by `SwipeDetect` once they travel
+    /// `SwipeDetect::MIN_LOCK` and reach us as `Event::Swipe` from then on.
+    /// Only a raw touch moving further than that was refused by the flow, so
+    /// the overscroll starts there and legitimate swipes never jiggle.
+    fn overscroll_for(&mut self, delta: Offset) -> i16 {
+        let distance = delta.axis(self.axis);
+        let past_first = self.current == 0 && distance > 0;
+        let past_last = self.current + 1 >= self.pages && distance < 0;
+        if !(past_first || past_last) {
+            return 0;
+        }
+        let beyond_lock = distance
+            .unsigned_abs()
+            .saturating_sub(SwipeDetect::MIN_LOCK);
+        if beyond_lock > 0 {
+            self.overscroll_locked = true;
+        }
+        if !self.overscroll_locked {
+            return 0;
+        }
+        let overscroll = (beyond_lock as i16 / OVERSCROLL_DAMPING).min(OVERSCROLL_MAX);
+        if distance > 0 {
+            overscroll
+        } else {
+            -overscroll
+        }
+    }
+
+    fn set_overscroll(&mut self, ctx: &mut EventCtx, overscroll: i16) {
+        if overscroll == self.overscroll {
+            return;
+        }
+        #[cfg(feature = "haptic")]
+        if self.haptic && self.overscroll == 0 {
+            play(HapticEffect::Tick);
+        }
+        self.overscroll = overscroll;
+        ctx.request_paint();
+    }
+
+    /// Current displacement of the content.
+    fn offset(&self) -> Offset {
+        let overscroll = if self.bounce.is_running() {
+            let t = self.bounce.elapsed() / BOUNCE_DURATION;
+            i16::lerp_eased(self.overscroll, 0, t, Cubic::ease_out)
+        } else {
+            self.overscroll
+        };
+        Offset::on_axis(self.axis, overscroll)
+    }
+
+    fn handle_overscroll(&mut self, ctx: &mut EventCtx, event: Event) {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => {
+                self.drag_origin = Some(pos);
+                self.overscroll_locked = false;
+                self.bounce.stop();
+                self.set_overscroll(ctx, 0);
+            }
+            Event::Touch(TouchEvent::TouchMove(pos)) => {
+                if let Some(origin) = self.drag_origin {
+                    let overscroll = self.overscroll_for(pos - origin);
+                    self.set_overscroll(ctx, overscroll);
+                }
+            }
+            Event::Touch(TouchEvent::TouchEnd(_)) => {
+                self.drag_origin = None;
+                if self.overscroll != 0 {
+                    self.bounce.start();
+                    ctx.request_anim_frame();
+                }
+            }
+            Event::Swipe(_) | Event::Attach(_) => {
+                // The flow took over, drop the touch.
+                self.drag_origin = None;
+                self.overscroll_locked = false;
+                self.bounce.stop();
+                self.set_overscroll(ctx, 0);
+            }
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) if self.bounce.is_running() => {
+                if self.bounce.is_running_within(BOUNCE_DURATION) {
+                    ctx.request_anim_frame();
+                } else {
+                    self.bounce.stop();
+                    self.overscroll = 0;
+                }
+                ctx.request_paint();
+            }
+            _ => {}
         }
     }
 }
@@ -49,6 +182,10 @@ impl<T: Component + Paginate> Component for SwipePage<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         ctx.set_page_count(self.pages);
 
+        if !animation_disabled() {
+            self.handle_overscroll(ctx, event);
+        }
+
         if let Event::Swipe(SwipeEvent::End(direction)) = event {
             match (self.axis, direction) {
                 (Axis::Vertical, SwipeDirection::Up) => {
@@ -83,7 +220,14 @@ impl<T: Component + Paginate> Component for SwipePage<T> {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        self.inner.render(target)
+        let offset = self.offset();
+        if offset == Offset::zero() {
+            self.inner.render(target)
+        } else {
+            target.in_clip(self.bounds, &|target| {
+                target.with_origin(offset, &|target| self.inner.render(target));
+            });
+        }
     }
 }
 
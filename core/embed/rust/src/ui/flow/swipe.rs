@@ -1,3 +1,5 @@
+use heapless::Vec;
+
 use crate::{
     error,
     ui::{
@@ -6,13 +8,24 @@ use crate::{
             SwipeDetectMsg, SwipeDirection,
         },
         event::SwipeEvent,
-        flow::{base::Decision, FlowMsg, FlowState, FlowStore},
+        flow::{base::Decision, FlowMsg, FlowState, FlowStore, History},
         geometry::Rect,
         shape::Renderer,
         util::animation_disabled,
     },
 };
 
+/// Maximum number of recorded jumps, the oldest ones are forgotten.
+const MAX_HISTORY: usize = 4;
+
+/// Recorded jump, swiping in `back` direction while in `to` returns to `from`.
+#[derive(Copy, Clone)]
+struct Jump<Q> {
+    from: Q,
+    to: Q,
+    back: SwipeDirection,
+}
+
 /// Given a state enum and a corresponding FlowStore, create a Component that
 /// implements a swipe navigation between the states with animated transitions.
 ///
@@ -35,6 +48,8 @@ pub struct SwipeFlow<Q, S> {
     /// If triggering swipe by event, make this decision instead of default
     /// after the swipe.
     decision_override: Option<Decision<Q>>,
+    /// Jumps that can be returned from by swiping back.
+    history: Vec<Jump<Q>, MAX_HISTORY>,
 }
 
 impl<Q: FlowState, S: FlowStore> SwipeFlow<Q, S> {
@@ -47,9 +62,71 @@ impl<Q: FlowState, S: FlowStore> SwipeFlow<Q, S> {
             internal_state: 0,
             internal_pages: 1,
             decision_override: None,
+            history: Vec::new(),
         })
     }
+
+    pub fn state(&self) -> Q {
+        self.state
+    }
+
+    /// Move to `state` without going through the states in between. The target
+    /// is attached as if swiped in from `direction`, or without animation if
+    /// `None`, and starts at its first internal page (last one when coming
+    /// from above). With `History::Record`, swiping in the opposite direction
+    /// (right when there is no animation) returns back.
+    pub fn jump(
+        &mut self,
+        ctx: &mut EventCtx,
+        state: Q,
+        direction: Option<SwipeDirection>,
+        history: History,
+    ) {
+        match history {
+            History::Record => {
+                if self.history.is_full() {
+                    self.history.remove(0);
+                }
+                let back = direction.map_or(SwipeDirection::Right, SwipeDirection::opposite);
+                unwrap!(self.history.push(Jump {
+                    from: self.state,
+                    to: state,
+                    back,
+                }));
+            }
+            History::Clear => self.history.clear(),
+        }
+
+        self.state = state;
+        self.swipe = SwipeDetect::new();
+        self.allow_swipe = true;
+        self.decision_override = None;
+
+        self.internal_pages = self.store.get_internal_page_count(state.index()) as u16;
+        self.internal_state = match direction {
+            Some(SwipeDirection::Down) => self.internal_pages.saturating_sub(1),
+            _ => 0,
+        };
+
+        let attach = direction.map_or(AttachType::Initial, AttachType::Swipe);
+        self.store.event(state.index(), ctx, Event::Attach(attach));
+
+        ctx.request_paint();
+    }
+
+    /// Direction of the swipe returning from the last jump to the current
+    /// state, if recorded.
+    fn back_direction(&self) -> Option<SwipeDirection> {
+        self.history
+            .last()
+            .filter(|jump| jump.to == self.state)
+            .map(|jump| jump.back)
+    }
     fn goto(&mut self, ctx: &mut EventCtx, direction: SwipeDirection, state: Q) {
+        // Leaving the page by swiping back returns from the last jump.
+        if self.back_direction() == Some(direction) {
+            self.history.pop();
+        }
         self.state = state;
         self.swipe = SwipeDetect::new();
         self.allow_swipe = true;
@@ -84,6 +161,11 @@ impl<Q: FlowState, S: FlowStore> SwipeFlow<Q, S> {
         _ctx: &mut EventCtx,
         direction: SwipeDirection,
     ) -> Decision<Q> {
+        if self.back_direction() == Some(direction) {
+            // Kept in the history, the swipe may still turn an internal page.
+            let jump = unwrap!(self.history.last());
+            return Decision::Goto(jump.from, direction);
+        }
         self.state.handle_swipe(direction)
     }
 
@@ -130,6 +212,9 @@ impl<Q: FlowState, S: FlowStore> Component for SwipeFlow<Q, S> {
             if config.horizontal_pages && self.internal_state < self.internal_pages - 1 {
                 config = config.with_swipe(SwipeDirection::Left, SwipeSettings::default())
             }
+            if let Some(back) = self.back_direction() {
+                config = config.with_swipe(back, SwipeSettings::default())
+            }
 
             match self.swipe.event(ctx, event, config) {
                 Some(SwipeDetectMsg::Trigger(dir)) => {
@@ -181,7 +266,10 @@ impl<Q: FlowState, S: FlowStore> Component for SwipeFlow<Q, S> {
         if let Some(e) = e {
             match decision {
                 Decision::Nothing => {
+                    let motion = matches!(e, Event::Swipe(_)).then(|| self.swipe.motion());
+                    ctx.set_swipe_motion(motion);
                     decision = self.handle_event_child(ctx, e);
+                    ctx.set_swipe_motion(None);
 
                     // when doing internal transition, pass attach event to the child after sending
                     // swipe end.
@@ -206,7 +294,12 @@ impl<Q: FlowState, S: FlowStore> Component for SwipeFlow<Q, S> {
 
                     let config = self.store.get_swipe_config(self.state.index());
 
-                    if let Decision::Goto(_, direction) = decision {
+                    let direction = match decision {
+                        Decision::Goto(_, direction) => Some(direction),
+                        Decision::Jump(_, direction, _) => direction,
+                        _ => None,
+                    };
+                    if let Some(direction) = direction {
                         if config.is_allowed(direction) {
                             if !animation_disabled() {
                                 self.swipe.trigger(ctx, direction, config);
@@ -229,6 +322,10 @@ impl<Q: FlowState, S: FlowStore> Component for SwipeFlow<Q, S> {
                 self.goto(ctx, direction, next_state);
                 None
             }
+            Decision::Jump(next_state, direction, history) => {
+                self.jump(ctx, next_state, direction, history);
+                None
+            }
             Decision::Return(msg) => {
                 self.swipe.reset();
                 self.allow_swipe = true;
@@ -268,3 +365,144 @@ impl<Q: FlowState, S: FlowStore> crate::ui::layout::obj::ComponentMsgObj for Swi
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        micropython::testutil::mpy_init,
+        ui::{
+            component::swipe_detect::SwipeConfig,
+            event::USBEvent,
+            flow::{flow_store, Swipable},
+        },
+    };
+
+    use super::*;
+
+    #[derive(Copy, Clone, PartialEq, Eq, ToPrimitive)]
+    enum Step {
+        First,
+        Second,
+        Third,
+    }
+
+    impl FlowState for Step {
+        fn handle_swipe(&self, direction: SwipeDirection) -> Decision<Self> {
+            match (self, direction) {
+                (Step::First, SwipeDirection::Left) => Decision::Goto(Step::Second, direction),
+                (Step::Second, SwipeDirection::Left) => Decision::Goto(Step::Third, direction),
+                (Step::Second, SwipeDirection::Right) => Decision::Goto(Step::First, direction),
+                (Step::Third, SwipeDirection::Right) => Decision::Goto(Step::Second, direction),
+                _ => Decision::Nothing,
+            }
+        }
+
+        fn handle_event(&self, msg: FlowMsg) -> Decision<Self> {
+            match (self, msg) {
+                (Step::First, FlowMsg::Info) => Decision::Jump(Step::Third, None, History::Record),
+                _ => Decision::Nothing,
+            }
+        }
+    }
+
+    /// Screen reporting USB connection as `FlowMsg::Info`.
+    struct Screen;
+
+    impl Component for Screen {
+        type Msg = FlowMsg;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            match event {
+                Event::USB(USBEvent::Connected(true)) => Some(FlowMsg::Info),
+                _ => None,
+            }
+        }
+
+        fn paint(&mut self) {}
+
+        fn render<'s>(&'s self, _target: &mut impl Renderer<'s>) {}
+    }
+
+    impl Swipable for Screen {
+        fn get_swipe_config(&self) -> SwipeConfig {
+            SwipeConfig::new()
+        }
+
+        fn get_internal_page_count(&self) -> usize {
+            1
+        }
+    }
+
+    #[cfg(feature = "ui_debug")]
+    impl crate::trace::Trace for Screen {
+        fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+            t.component("Screen");
+        }
+    }
+
+    #[test]
+    fn jump_across_three_states() {
+        unsafe { mpy_init() };
+
+        let store = unwrap!(unwrap!(unwrap!(flow_store().add(Screen)).add(Screen)).add(Screen));
+        let mut flow = unwrap!(SwipeFlow::new(Step::First, store));
+        let mut ctx = EventCtx::new();
+
+        // Decision of the first screen skips the second one.
+        let msg = flow.event(&mut ctx, Event::USB(USBEvent::Connected(true)));
+        assert!(msg.is_none());
+        assert!(flow.state() == Step::Third);
+
+        // Swiping back returns where the jump started.
+        let decision = flow.handle_swipe_child(&mut ctx, SwipeDirection::Right);
+        assert!(matches!(
+            decision,
+            Decision::Goto(Step::First, SwipeDirection::Right)
+        ));
+        flow.jump(
+            &mut ctx,
+            Step::Third,
+            Some(SwipeDirection::Left),
+            History::Clear,
+        );
+        let decision = flow.handle_swipe_child(&mut ctx, SwipeDirection::Right);
+        assert!(matches!(
+            decision,
+            Decision::Goto(Step::Second, SwipeDirection::Right)
+        ));
+
+        // Recorded jump in the other direction.
+        flow.jump(
+            &mut ctx,
+            Step::First,
+            Some(SwipeDirection::Right),
+            History::Record,
+        );
+        assert!(flow.state() == Step::First);
+        assert!(flow.back_direction() == Some(SwipeDirection::Left));
+        let decision = flow.handle_swipe_child(&mut ctx, SwipeDirection::Left);
+        assert!(matches!(
+            decision,
+            Decision::Goto(Step::Third, SwipeDirection::Left)
+        ));
+
+        // Not returned yet, the swipe might have only turned an internal page.
+        assert!(flow.back_direction() == Some(SwipeDirection::Left));
+        let decision = flow.handle_swipe_child(&mut ctx, SwipeDirection::Left);
+        assert!(matches!(
+            decision,
+            Decision::Goto(Step::Third, SwipeDirection::Left)
+        ));
+
+        // Returned only once.
+        flow.goto(&mut ctx, SwipeDirection::Left, Step::Third);
+        assert!(flow.state() == Step::Third);
+        assert!(flow.back_direction().is_none());
+        flow.goto(&mut ctx, SwipeDirection::Right, Step::First);
+        assert!(flow.back_direction().is_none());
+    }
+}
@@ -465,6 +465,22 @@ impl Rect {
         (left, center, right)
     }
 
+    /// Split `Rect` into `count` slices stacked from the top to the bottom with
+    /// `spacing` between them. All the slices have the same height, except
+    /// for the last one which also takes the pixels left over by the integer
+    /// division.
+    pub const fn split_evenly_vertical(self, count: usize, spacing: i16) -> RectSlices {
+        RectSlices::new(self, Axis::Vertical, count, spacing)
+    }
+
+    /// Split `Rect` into `count` slices side by side from the left to the
+    /// right with `spacing` between them. All the slices have the same width,
+    /// except for the last one which also takes the pixels left over by the
+    /// integer division.
+    pub const fn split_evenly_horizontal(self, count: usize, spacing: i16) -> RectSlices {
+        RectSlices::new(self, Axis::Horizontal, count, spacing)
+    }
+
     /// Calculates the intersection of two rectangles.
     ///
     /// If the rectangles do not intersect, an "empty" rectangle is returned.
@@ -512,6 +528,72 @@ impl Rect {
     }
 }
 
+impl Lerp for Rect {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        Rect::new(
+            Point::lerp(a.top_left(), b.top_left(), t),
+            Point::lerp(a.bottom_right(), b.bottom_right(), t),
+        )
+    }
+}
+
+/// Iterator over slices of a `Rect`, see `Rect::split_evenly_vertical`.
+pub struct RectSlices {
+    remaining: Rect,
+    axis: Axis,
+    count: usize,
+    size: i16,
+    spacing: i16,
+}
+
+impl RectSlices {
+    const fn new(rect: Rect, axis: Axis, count: usize, spacing: i16) -> Self {
+        let size = if count > 0 {
+            let n = count as i16;
+            (rect.size().axis(axis) - spacing * (n - 1)) / n
+        } else {
+            0
+        };
+        Self {
+            remaining: rect,
+            axis,
+            count,
+            size,
+            spacing,
+        }
+    }
+}
+
+impl Iterator for RectSlices {
+    type Item = Rect;
+
+    fn next(&mut self) -> Option<Rect> {
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        if self.count == 0 {
+            // The last slice takes the leftover pixels.
+            return Some(self.remaining);
+        }
+        let (slice, rest) = match self.axis {
+            Axis::Vertical => self.remaining.split_top(self.size),
+            Axis::Horizontal => self.remaining.split_left(self.size),
+        };
+        self.remaining = match self.axis {
+            Axis::Vertical => rest.split_top(self.spacing).1,
+            Axis::Horizontal => rest.split_left(self.spacing).1,
+        };
+        Some(slice)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl ExactSizeIterator for RectSlices {}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Insets {
     pub top: i16,
@@ -553,6 +635,54 @@ impl Insets {
     pub const fn sides(d: i16) -> Self {
         Self::new(0, d, 0, d)
     }
+
+    /// Same `horizontal` insets on the left and right, same `vertical` insets
+    /// on the top and bottom.
+    pub const fn symmetric(horizontal: i16, vertical: i16) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    pub const fn add(self, rhs: Insets) -> Self {
+        Self::new(
+            self.top + rhs.top,
+            self.right + rhs.right,
+            self.bottom + rhs.bottom,
+            self.left + rhs.left,
+        )
+    }
+
+    /// Subtract `rhs` side by side, sides going below zero are clamped to zero.
+    pub const fn sub(self, rhs: Insets) -> Self {
+        const fn side(a: i16, b: i16) -> i16 {
+            if a > b {
+                a - b
+            } else {
+                0
+            }
+        }
+        Self::new(
+            side(self.top, rhs.top),
+            side(self.right, rhs.right),
+            side(self.bottom, rhs.bottom),
+            side(self.left, rhs.left),
+        )
+    }
+}
+
+impl Add<Insets> for Insets {
+    type Output = Insets;
+
+    fn add(self, rhs: Insets) -> Self::Output {
+        Insets::add(self, rhs)
+    }
+}
+
+impl Sub<Insets> for Insets {
+    type Output = Insets;
+
+    fn sub(self, rhs: Insets) -> Self::Output {
+        Insets::sub(self, rhs)
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -608,6 +738,10 @@ pub struct Grid {
     pub spacing: i16,
     /// Total area covered by this grid.
     pub area: Rect,
+    /// Fixed heights of the rows from the top, see `with_row_heights`.
+    pub row_heights: &'static [i16],
+    /// Fixed widths of the columns from the left, see `with_col_widths`.
+    pub col_widths: &'static [i16],
 }
 
 impl Grid {
@@ -617,6 +751,8 @@ impl Grid {
             cols,
             spacing: 0,
             area,
+            row_heights: &[],
+            col_widths: &[],
         }
     }
 
@@ -624,44 +760,75 @@ impl Grid {
         Self { spacing, ..self }
     }
 
+    /// Fixes the heights of the rows from the top. The remaining height is
+    /// divided among the rows not listed or listed with zero height.
+    pub const fn with_row_heights(self, row_heights: &'static [i16]) -> Self {
+        Self {
+            row_heights,
+            ..self
+        }
+    }
+
+    /// Fixes the widths of the columns from the left. The remaining width is
+    /// divided among the columns not listed or listed with zero width.
+    pub const fn with_col_widths(self, col_widths: &'static [i16]) -> Self {
+        Self { col_widths, ..self }
+    }
+
+    /// Offset from the grid start and size of the cell at `index` out of
+    /// `count` cells along one axis of `length` pixels.
+    const fn track(&self, length: i16, count: i16, fixed: &[i16], index: i16) -> (i16, i16) {
+        // Pixels left after the spacing and the fixed cells.
+        let mut flexible = length - self.spacing * (count - 1);
+        let mut flexible_count = count;
+        let mut i = 0;
+        while i < fixed.len() && i < count as usize {
+            if fixed[i] > 0 {
+                flexible -= fixed[i];
+                flexible_count -= 1;
+            }
+            i += 1;
+        }
+
+        // Divide what is left by number of the remaining cells. Not every area can be
+        // fully covered by equal-sized cells and spaces, there might be several pixels
+        // left unused. We'll distribute them by 1px to the leftmost cells.
+        let (cell_size, mut leftover) = if flexible_count > 0 {
+            (flexible / flexible_count, flexible % flexible_count)
+        } else {
+            (0, 0)
+        };
+
+        let mut start = 0;
+        let mut i = 0;
+        loop {
+            let mut size = cell_size;
+            if (i as usize) < fixed.len() && fixed[i as usize] > 0 {
+                size = fixed[i as usize];
+            } else if leftover > 0 {
+                // This cell is 1px wider.
+                size += 1;
+                leftover -= 1;
+            }
+            if i >= index {
+                return (start, size);
+            }
+            start += size + self.spacing;
+            i += 1;
+        }
+    }
+
     pub const fn row_col(&self, row: usize, col: usize) -> Rect {
         let ncols = self.cols as i16;
         let nrows = self.rows as i16;
         let col = min(col as i16, ncols - 1);
         let row = min(row as i16, nrows - 1);
 
-        // Total number of horizontal pixels used for spacing.
-        let spacing_width = self.spacing * (ncols - 1);
-        let spacing_height = self.spacing * (nrows - 1);
-
-        // Divide what is left by number of cells to obtain width of each cell.
-        let cell_width = (self.area.width() - spacing_width) / ncols;
-        let cell_height = (self.area.height() - spacing_height) / nrows;
-
-        // Not every area can be fully covered by equal-sized cells and spaces, there
-        // might be several pixels left unused. We'll distribute them by 1px to
-        // the leftmost cells.
-        let leftover_width = (self.area.width() - spacing_width) % ncols;
-        let leftover_height = (self.area.height() - spacing_height) % nrows;
-
-        let mut top_left = self.area.top_left().ofs(Offset::new(
-            col * (cell_width + self.spacing),
-            row * (cell_height + self.spacing),
-        ));
-        // Some previous cells were 1px wider.
-        top_left.x += min(leftover_width, col);
-        top_left.y += min(leftover_height, row);
-
-        let mut size = Offset::new(cell_width, cell_height);
-        // This cell might be 1px wider.
-        if col < leftover_width {
-            size.x += 1
-        }
-        if row < leftover_height {
-            size.y += 1
-        }
+        let (x, width) = self.track(self.area.width(), ncols, self.col_widths, col);
+        let (y, height) = self.track(self.area.height(), nrows, self.row_heights, row);
 
-        Rect::from_top_left_and_size(top_left, size)
+        let top_left = self.area.top_left().ofs(Offset::new(x, y));
+        Rect::from_top_left_and_size(top_left, Offset::new(width, height))
     }
 
     pub const fn cell(&self, index: usize) -> Rect {
@@ -673,6 +840,24 @@ impl Grid {
         let to = self.row_col(cells.to.0, cells.to.1);
         from.union(to)
     }
+
+    /// Cell spanning columns `from_col` to `to_col` (inclusive) of `row`,
+    /// including the spacing between them.
+    pub const fn span_cols(&self, row: usize, from_col: usize, to_col: usize) -> Rect {
+        self.cells(GridCellSpan {
+            from: (row, from_col),
+            to: (row, to_col),
+        })
+    }
+
+    /// Cell spanning rows `from_row` to `to_row` (inclusive) of `col`,
+    /// including the spacing between them.
+    pub const fn span_rows(&self, col: usize, from_row: usize, to_row: usize) -> Rect {
+        self.cells(GridCellSpan {
+            from: (from_row, col),
+            to: (to_row, col),
+        })
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -803,3 +988,86 @@ pub trait Dimensions {
     fn fit(&mut self, bounds: Rect);
     fn area(&self) -> Rect;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x0: i16, y0: i16, x1: i16, y1: i16) -> Rect {
+        Rect::new(Point::new(x0, y0), Point::new(x1, y1))
+    }
+
+    #[test]
+    fn rect_split_evenly() {
+        let r = rect(0, 0, 100, 50);
+
+        // 100 - 2 * 3 = 94 = 3 * 31 + 1, the last slice is 1px wider.
+        let mut cols = r.split_evenly_horizontal(3, 3);
+        assert_eq!(cols.len(), 3);
+        assert!(cols.next() == Some(rect(0, 0, 31, 50)));
+        assert!(cols.next() == Some(rect(34, 0, 65, 50)));
+        assert!(cols.next() == Some(rect(68, 0, 100, 50)));
+        assert!(cols.next().is_none());
+
+        let mut rows = r.split_evenly_vertical(2, 0);
+        assert!(rows.next() == Some(rect(0, 0, 100, 25)));
+        assert!(rows.next() == Some(rect(0, 25, 100, 50)));
+        assert!(rows.next().is_none());
+
+        assert!(r.split_evenly_vertical(1, 10).next() == Some(r));
+        assert!(r.split_evenly_vertical(0, 10).next().is_none());
+    }
+
+    #[test]
+    fn grid_uniform() {
+        let grid = Grid::new(rect(0, 0, 101, 50), 2, 3).with_spacing(2);
+        // 97px for cells, the first column gets the leftover 1px.
+        assert!(grid.row_col(0, 0) == rect(0, 0, 33, 24));
+        assert!(grid.row_col(0, 1) == rect(35, 0, 67, 24));
+        assert!(grid.row_col(1, 2) == rect(69, 26, 101, 50));
+    }
+
+    #[test]
+    fn grid_fixed_row() {
+        let grid = Grid::new(rect(10, 20, 110, 120), 4, 1)
+            .with_spacing(2)
+            .with_row_heights(&[0, 0, 0, 22]);
+        // 100 - 3 * 2 - 22 = 72px left for three rows.
+        assert!(grid.row_col(0, 0) == rect(10, 20, 110, 44));
+        assert!(grid.row_col(1, 0) == rect(10, 46, 110, 70));
+        assert!(grid.row_col(2, 0) == rect(10, 72, 110, 96));
+        assert!(grid.row_col(3, 0) == rect(10, 98, 110, 120));
+    }
+
+    #[test]
+    fn grid_fixed_col() {
+        let grid = Grid::new(rect(0, 0, 100, 10), 1, 3).with_col_widths(&[50]);
+        assert!(grid.cell(0) == rect(0, 0, 50, 10));
+        assert!(grid.cell(1) == rect(50, 0, 75, 10));
+        assert!(grid.cell(2) == rect(75, 0, 100, 10));
+    }
+
+    #[test]
+    fn grid_span() {
+        let grid = Grid::new(rect(0, 0, 100, 100), 4, 3).with_spacing(4);
+        let (left, right) = (grid.row_col(1, 0), grid.row_col(1, 1));
+        assert!(left.x1 + 4 == right.x0);
+        assert!(grid.span_cols(1, 0, 1) == rect(left.x0, left.y0, right.x1, right.y1));
+
+        let (top, bottom) = (grid.row_col(2, 2), grid.row_col(3, 2));
+        assert!(top.y1 + 4 == bottom.y0);
+        assert!(grid.span_rows(2, 2, 3) == rect(top.x0, top.y0, bottom.x1, bottom.y1));
+    }
+
+    #[test]
+    fn insets_arithmetic() {
+        assert!(Insets::symmetric(2, 4) == Insets::new(4, 2, 4, 2));
+        assert!(Insets::sides(5) + Insets::top(3) + Insets::bottom(1) == Insets::new(3, 5, 1, 5));
+        assert!(Insets::uniform(6) - Insets::bottom(6) == Insets::new(6, 6, 0, 6));
+        assert!(Insets::uniform(2) - Insets::new(1, 3, 2, 0) == Insets::new(1, 0, 0, 2));
+
+        let r = rect(10, 20, 110, 220);
+        let insets = Insets::new(1, 2, 3, 4);
+        assert!(r.inset(insets).outset(insets) == r);
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
         typ::Type,
         util,
     },
+    strutil::TString,
     time::Duration,
     ui::{
         button_request::ButtonRequest,
@@ -22,15 +23,23 @@ use crate::{
         constant,
         display::sync,
         geometry::Rect,
+        led,
     },
 };
 
 use crate::ui::component::base::AttachType;
 #[cfg(feature = "new_rendering")]
-use crate::ui::{display::Color, shape::render_on_display};
+use crate::ui::{
+    display::{set_refresh_region, Color},
+    shape::{render_on_display, Viewport},
+};
 
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::{debug_overlay, debug_target};
 #[cfg(feature = "button")]
 use crate::ui::event::ButtonEvent;
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
 #[cfg(feature = "touch")]
 use crate::ui::event::TouchEvent;
 use crate::ui::event::USBEvent;
@@ -39,6 +48,29 @@ use crate::ui::event::USBEvent;
 /// message values into MicroPython `Obj`s.
 pub trait ComponentMsgObj: Component {
     fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error>;
+
+    /// Keyboard of the layout, to be inspected by debuglink.
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        None
+    }
+
+    /// Keyboard of the layout, to be typed into by debuglink.
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        None
+    }
+}
+
+/// Keyboards that debuglink can read and type into without synthesizing the
+/// touches of the individual keys.
+#[cfg(feature = "ui_debug")]
+pub trait DebugKeyboard {
+    /// Current content of the textbox.
+    fn debug_text(&self) -> &str;
+    /// Type `ch` as if its key was tapped, going through the same path so the
+    /// keyboard state is updated accordingly. Ignored if the key is disabled.
+    fn debug_type(&mut self, ctx: &mut EventCtx, ch: char);
 }
 
 /// Object-safe interface between trait `Component` and MicroPython world. It
@@ -51,11 +83,18 @@ pub trait ComponentMsgObj: Component {
 pub trait ObjComponent: MaybeTrace {
     fn obj_place(&mut self, bounds: Rect) -> Rect;
     fn obj_event(&mut self, ctx: &mut EventCtx, event: Event) -> Result<Obj, Error>;
-    fn obj_paint(&mut self) -> bool;
+    /// Paint the component if requested. Only the content inside `region` is
+    /// expected to have changed.
+    fn obj_paint(&mut self, region: Rect) -> bool;
     fn obj_bounds(&self, _sink: &mut dyn FnMut(Rect)) {}
     fn obj_skip_paint(&mut self) {}
     fn obj_request_clear(&mut self) {}
     fn obj_delete(&mut self) {}
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_text(&self) -> Option<&str>;
+    /// Returns false if there is no keyboard to type into.
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_type(&mut self, ctx: &mut EventCtx, text: &str) -> bool;
 }
 
 impl<T> ObjComponent for Root<T>
@@ -63,6 +102,9 @@ where
     T: ComponentMsgObj + MaybeTrace,
 {
     fn obj_place(&mut self, bounds: Rect) -> Rect {
+        // The components register their targets anew.
+        #[cfg(feature = "ui_debug")]
+        debug_target::clear();
         self.place(bounds)
     }
 
@@ -74,9 +116,10 @@ where
         }
     }
 
-    fn obj_paint(&mut self) -> bool {
+    fn obj_paint(&mut self, region: Rect) -> bool {
         #[cfg(not(feature = "new_rendering"))]
         {
+            let _ = region;
             let will_paint = self.inner().will_paint();
             self.paint();
             will_paint
@@ -86,8 +129,14 @@ where
         {
             let will_paint = self.inner().will_paint();
             if will_paint {
-                render_on_display(None, Some(Color::black()), |target| {
+                // Draw only the changed region if the rest of the screen is kept.
+                let viewport = set_refresh_region(region).then(|| Viewport::new(region));
+                render_on_display(viewport, Some(Color::black()), |target| {
                     self.render(target);
+                    #[cfg(feature = "ui_debug")]
+                    if debug_overlay::is_enabled() {
+                        debug_overlay::render(target, |sink| self.obj_bounds(sink));
+                    }
                 });
                 self.skip_paint();
             }
@@ -111,6 +160,24 @@ where
     fn obj_delete(&mut self) {
         self.delete()
     }
+
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_text(&self) -> Option<&str> {
+        Some(self.inner().inner().debug_keyboard()?.debug_text())
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_type(&mut self, ctx: &mut EventCtx, text: &str) -> bool {
+        self.inner_mut().mutate(ctx, |ctx, c| {
+            let Some(keyboard) = c.debug_keyboard_mut() else {
+                return false;
+            };
+            for ch in text.chars() {
+                keyboard.debug_type(ctx, ch);
+            }
+            true
+        })
+    }
 }
 
 /// `LayoutObj` is a GC-allocated object exported to MicroPython, with type
@@ -127,6 +194,48 @@ struct LayoutObjInner {
     event_ctx: EventCtx,
     timer_fn: Obj,
     page_count: u16,
+    /// Part of the screen to redraw on the next paint, accumulated over the
+    /// event passes since the last one.
+    paint_region: Option<Rect>,
+}
+
+impl LayoutObjInner {
+    /// Process the requests the components made during an event pass. Pending
+    /// timers are drained into `self.timer_fn` and the region to paint is
+    /// accumulated.
+    fn finish_event_pass(&mut self) -> Result<(), Error> {
+        // All concerning `Child` wrappers should have already marked themselves for
+        // painting by now, and we're prepared for a paint pass.
+
+        // Drain any pending timers into the callback.
+        while let Some((token, deadline)) = self.event_ctx.pop_timer() {
+            let token = token.try_into();
+            let deadline = deadline.try_into();
+            if let (Ok(token), Ok(deadline)) = (token, deadline) {
+                self.timer_fn.call_with_n_args(&[token, deadline])?;
+            } else {
+                // Failed to convert token or deadline into `Obj`, skip.
+            }
+        }
+
+        if let Some(count) = self.event_ctx.page_count() {
+            self.page_count = count as u16;
+        }
+
+        let region = if self.event_ctx.needs_repaint_root() {
+            Some(constant::screen())
+        } else {
+            self.event_ctx.paint_region()
+        };
+        if let Some(region) = region {
+            self.paint_region = Some(match self.paint_region {
+                Some(r) => r.union(region),
+                None => region,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl LayoutObj {
@@ -149,6 +258,7 @@ impl LayoutObj {
                     event_ctx: EventCtx::new(),
                     timer_fn: Obj::const_none(),
                     page_count: 1,
+                    paint_region: Some(constant::screen()),
                 }),
             })
         }
@@ -159,6 +269,14 @@ impl LayoutObj {
 
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
         unsafe { Gc::as_mut(&mut inner.root) }.obj_delete();
+
+        led::layout_deleted(self.owner_id());
+    }
+
+    /// Identity of the layout for the bookkeeping of global state, like the
+    /// LED pulse or the debug targets.
+    fn owner_id(&self) -> usize {
+        self as *const Self as usize
     }
 
     pub fn skip_first_paint(&self) {
@@ -190,33 +308,28 @@ impl LayoutObj {
         // Clear the leftover flags from the previous event pass.
         inner.event_ctx.clear();
 
+        // Timers cancelled after being scheduled still fire, drop them here.
+        if let Event::Timer(token) = event {
+            if inner.event_ctx.take_cancelled_timer(token) {
+                return Ok(Obj::const_none());
+            }
+        }
+
         // Send the event down the component tree. Bail out in case of failure.
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
         let msg = unsafe { Gc::as_mut(&mut inner.root) }.obj_event(&mut inner.event_ctx, event)?;
 
-        // All concerning `Child` wrappers should have already marked themselves for
-        // painting by now, and we're prepared for a paint pass.
+        // Fade out the LED pulse, independently of the components.
+        led::event(&mut inner.event_ctx, event, self.owner_id());
 
-        // Drain any pending timers into the callback.
-        while let Some((token, deadline)) = inner.event_ctx.pop_timer() {
-            let token = token.try_into();
-            let deadline = deadline.try_into();
-            if let (Ok(token), Ok(deadline)) = (token, deadline) {
-                inner.timer_fn.call_with_n_args(&[token, deadline])?;
-            } else {
-                // Failed to convert token or deadline into `Obj`, skip.
-            }
-        }
-
-        if let Some(count) = inner.event_ctx.page_count() {
-            inner.page_count = count as u16;
-        }
+        inner.finish_event_pass()?;
 
         Ok(msg)
     }
 
     fn obj_request_clear(&self) {
         let mut inner = self.inner.borrow_mut();
+        inner.paint_region = Some(constant::screen());
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
         unsafe { Gc::as_mut(&mut inner.root) }.obj_request_clear();
     }
@@ -234,8 +347,15 @@ impl LayoutObj {
 
         sync();
 
+        // Without any region requested, e.g. the first paint, redraw everything.
+        let region = inner.paint_region.unwrap_or(constant::screen());
+
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
-        unsafe { Gc::as_mut(&mut inner.root) }.obj_paint()
+        let painted = unsafe { Gc::as_mut(&mut inner.root) }.obj_paint(region);
+        if painted {
+            inner.paint_region = None;
+        }
+        painted
     }
 
     /// Run a tracing pass over the component tree. Passed `callback` is called
@@ -259,9 +379,66 @@ impl LayoutObj {
         // Luckily, calling `root.trace()` works perfectly fine in spite of the above.)
         tracer.root(&|t| {
             self.inner.borrow().root.trace(t);
+            t.in_list("debug_targets", &|list| {
+                debug_target::for_each(|name| list.string(&name.into()));
+            });
         });
     }
 
+    /// Click the component registered as debug target `name`, by a touch in
+    /// the middle of it.
+    #[cfg(all(feature = "ui_debug", feature = "touch"))]
+    fn obj_debug_click(&self, name: &str) -> Result<Obj, Error> {
+        {
+            // Place again only if another layout has registered its targets
+            // meanwhile, or the components are about to move anyway.
+            let inner = &mut *self.inner.borrow_mut();
+            if inner.event_ctx.needs_place_before_next_event_or_paint()
+                || !debug_target::is_owner(self.owner_id())
+            {
+                // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+                unsafe { Gc::as_mut(&mut inner.root) }.obj_place(constant::screen());
+                inner.event_ctx.clear();
+                debug_target::set_owner(self.owner_id());
+            }
+        }
+        let point = debug_target::find(name).ok_or_else(|| value_error!("Unknown target"))?;
+        let msg = self.obj_event(Event::Touch(TouchEvent::TouchStart(point)))?;
+        if msg != Obj::const_none() {
+            return Ok(msg);
+        }
+        self.obj_event(Event::Touch(TouchEvent::TouchEnd(point)))
+    }
+
+    /// Content of the keyboard of the layout, `None` if there is no keyboard.
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_text(&self) -> Result<Obj, Error> {
+        match self.inner.borrow().root.obj_keyboard_text() {
+            Some(text) => text.try_into(),
+            None => Ok(Obj::const_none()),
+        }
+    }
+
+    /// Type `text` into the keyboard of the layout, as if the respective keys
+    /// were tapped one after another.
+    #[cfg(feature = "ui_debug")]
+    fn obj_keyboard_type(&self, text: &str) -> Result<(), Error> {
+        let inner = &mut *self.inner.borrow_mut();
+
+        if inner.event_ctx.needs_place_before_next_event_or_paint() {
+            // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+            unsafe { Gc::as_mut(&mut inner.root) }.obj_place(constant::screen());
+        }
+        inner.event_ctx.clear();
+
+        // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+        let root = unsafe { Gc::as_mut(&mut inner.root) };
+        if !root.obj_keyboard_type(&mut inner.event_ctx, text) {
+            return Err(value_error!("Layout has no keyboard"));
+        }
+        inner.finish_event_pass()
+    }
+
     fn obj_page_count(&self) -> Obj {
         self.inner.borrow().page_count.into()
     }
@@ -283,10 +460,7 @@ impl LayoutObj {
 
         // Sink for `Trace::bounds` that draws the boundaries using pseudorandom color.
         fn wireframe(r: Rect) {
-            let w = r.width() as u16;
-            let h = r.height() as u16;
-            let color = display::Color::from_u16(w.rotate_right(w.into()).wrapping_add(h * 8));
-            display::rect_stroke(r, color)
+            display::rect_stroke(r, debug_overlay::wireframe_color(r))
         }
 
         // use crate::ui::model_tt::theme;
@@ -302,7 +476,11 @@ impl LayoutObj {
                 Qstr::MP_QSTR_touch_event => obj_fn_var!(4, 4, ui_layout_touch_event).as_obj(),
                 Qstr::MP_QSTR_button_event => obj_fn_var!(3, 3, ui_layout_button_event).as_obj(),
                 Qstr::MP_QSTR_progress_event => obj_fn_var!(3, 3, ui_layout_progress_event).as_obj(),
+                Qstr::MP_QSTR_progress_info_event => obj_fn_var!(3, 3, ui_layout_progress_info_event).as_obj(),
+                Qstr::MP_QSTR_notification_event => obj_fn_var!(3, 3, ui_layout_notification_event).as_obj(),
+                Qstr::MP_QSTR_mnemonic_word_event => obj_fn_var!(3, 3, ui_layout_mnemonic_word_event).as_obj(),
                 Qstr::MP_QSTR_usb_event => obj_fn_var!(2, 2, ui_layout_usb_event).as_obj(),
+                Qstr::MP_QSTR_host_key_event => obj_fn_2!(ui_layout_host_key_event).as_obj(),
                 Qstr::MP_QSTR_timer => obj_fn_2!(ui_layout_timer).as_obj(),
                 Qstr::MP_QSTR_paint => obj_fn_1!(ui_layout_paint).as_obj(),
                 Qstr::MP_QSTR_request_complete_repaint => obj_fn_1!(ui_layout_request_complete_repaint).as_obj(),
@@ -311,6 +489,12 @@ impl LayoutObj {
                 Qstr::MP_QSTR___del__ => obj_fn_1!(ui_layout_delete).as_obj(),
                 Qstr::MP_QSTR_page_count => obj_fn_1!(ui_layout_page_count).as_obj(),
                 Qstr::MP_QSTR_button_request => obj_fn_1!(ui_layout_button_request).as_obj(),
+                #[cfg(feature = "ui_debug")]
+                Qstr::MP_QSTR_keyboard_text => obj_fn_1!(ui_layout_keyboard_text).as_obj(),
+                #[cfg(feature = "ui_debug")]
+                Qstr::MP_QSTR_keyboard_type => obj_fn_2!(ui_layout_keyboard_type).as_obj(),
+                #[cfg(feature = "ui_debug")]
+                Qstr::MP_QSTR_debug_click => obj_fn_2!(ui_layout_debug_click).as_obj(),
             }),
         };
         &TYPE
@@ -444,6 +628,48 @@ extern "C" fn ui_layout_progress_event(n_args: usize, args: *const Obj) -> Obj {
     unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
 }
 
+extern "C" fn ui_layout_progress_info_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 3 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let phase: StrBuffer = args[1].try_into()?;
+        let eta: StrBuffer = args[2].try_into()?;
+        let msg = this.obj_event(Event::ProgressInfo(phase.into(), eta.into()))?;
+        Ok(msg)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
+extern "C" fn ui_layout_notification_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 3 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let text: Option<TString<'static>> = args[1].try_into_option()?;
+        let level: u8 = args[2].try_into()?;
+        let msg = this.obj_event(Event::Notification(text.map(|t| (t, level))))?;
+        Ok(msg)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
+extern "C" fn ui_layout_mnemonic_word_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 3 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let word_index: u16 = args[1].try_into()?;
+        let progress: TString<'static> = args[2].try_into()?;
+        let msg = this.obj_event(Event::MnemonicWord(word_index, progress))?;
+        Ok(msg)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
 extern "C" fn ui_layout_usb_event(n_args: usize, args: *const Obj) -> Obj {
     let block = |args: &[Obj], _kwargs: &Map| {
         if args.len() != 2 {
@@ -457,6 +683,22 @@ extern "C" fn ui_layout_usb_event(n_args: usize, args: *const Obj) -> Obj {
     unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
 }
 
+#[cfg(feature = "host_keyboard")]
+extern "C" fn ui_layout_host_key_event(this: Obj, key: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let event = HostKey::new(key.try_into()?)?;
+        let msg = this.obj_event(Event::HostKey(event))?;
+        Ok(msg)
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(not(feature = "host_keyboard"))]
+extern "C" fn ui_layout_host_key_event(_this: Obj, _key: Obj) -> Obj {
+    Obj::const_none()
+}
+
 extern "C" fn ui_layout_timer(this: Obj, token: Obj) -> Obj {
     let block = || {
         let this: Gc<LayoutObj> = this.try_into()?;
@@ -530,6 +772,41 @@ extern "C" fn ui_layout_trace(_this: Obj, _callback: Obj) -> Obj {
     Obj::const_none()
 }
 
+#[cfg(feature = "ui_debug")]
+extern "C" fn ui_layout_keyboard_text(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        this.obj_keyboard_text()
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(feature = "ui_debug")]
+extern "C" fn ui_layout_keyboard_type(this: Obj, text: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let text: StrBuffer = text.try_into()?;
+        this.obj_keyboard_type(text.as_ref())?;
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(all(feature = "ui_debug", feature = "touch"))]
+extern "C" fn ui_layout_debug_click(this: Obj, name: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let name: StrBuffer = name.try_into()?;
+        this.obj_debug_click(name.as_ref())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(all(feature = "ui_debug", not(feature = "touch")))]
+extern "C" fn ui_layout_debug_click(_this: Obj, _name: Obj) -> Obj {
+    unsafe { util::try_or_raise(|| Err(value_error!("Not available"))) }
+}
+
 #[cfg(feature = "ui_bounds")]
 extern "C" fn ui_layout_bounds(this: Obj) -> Obj {
     let block = || {
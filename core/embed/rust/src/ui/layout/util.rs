@@ -5,16 +5,20 @@ use crate::{
         buffer::{hexlify_bytes, StrBuffer},
         gc::Gc,
         list::List,
+        map::Map,
         obj::Obj,
-        util::{iter_into_array, try_or_raise},
+        util::{iter_into_array, try_or_raise, try_with_args_and_kwargs},
     },
     storage::{get_avatar_len, load_avatar},
     strutil::TString,
+    time::Duration,
     ui::{
         component::text::{
+            common::KeyboardTimings,
             paragraphs::{Paragraph, ParagraphSource},
             TextStyle,
         },
+        led,
         util::set_animation_disabled,
     },
 };
@@ -104,6 +108,46 @@ impl PropsList {
     }
 }
 
+/// Items of a bulleted or numbered list, one paragraph per item.
+pub struct ListItems {
+    items: Gc<List>,
+    style: &'static TextStyle,
+    numbered: bool,
+}
+
+impl ListItems {
+    pub fn new(obj: Obj, style: &'static TextStyle, numbered: bool) -> Result<Self, Error> {
+        Ok(Self {
+            items: obj.try_into()?,
+            style,
+            numbered,
+        })
+    }
+}
+
+impl ParagraphSource<'static> for ListItems {
+    fn at(&self, index: usize, offset: usize) -> Paragraph<'static> {
+        let block = move || {
+            let content: TString = self.items.get(index)?.try_into()?;
+            let para = Paragraph::new(self.style, content.skip_prefix(offset));
+            Ok::<_, Error>(if self.numbered {
+                let count = self.items.len().min(u8::MAX as usize) as u8;
+                para.with_number((index + 1) as u8, count)
+            } else {
+                para.with_bullet()
+            })
+        };
+        match block() {
+            Ok(para) => para,
+            Err(_) => Paragraph::new(self.style, StrBuffer::from("ERROR")),
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+}
+
 impl ParagraphSource<'static> for PropsList {
     fn at(&self, index: usize, offset: usize) -> Paragraph<'static> {
         let block = move || {
@@ -170,6 +214,59 @@ pub extern "C" fn upy_disable_animation(disable: Obj) -> Obj {
     unsafe { try_or_raise(block) }
 }
 
+pub extern "C" fn upy_debug_overlay(show: Obj) -> Obj {
+    let block = || {
+        let show: bool = show.try_into()?;
+        #[cfg(feature = "ui_debug")]
+        crate::ui::component::debug_overlay::set_enabled(show);
+        #[cfg(not(feature = "ui_debug"))]
+        let _ = show;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_seed_random(seed: Obj) -> Obj {
+    let block = || {
+        let seed: Option<u32> = if seed == Obj::const_none() {
+            None
+        } else {
+            Some(seed.try_into()?)
+        };
+        #[cfg(feature = "ui_debug")]
+        crate::ui::random::set_seed(seed);
+        #[cfg(not(feature = "ui_debug"))]
+        let _ = seed;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_led_set_enabled(enable: Obj) -> Obj {
+    let block = || {
+        led::set_enabled(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_keyboard_timings(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        let &[erase_hold_ms, multi_tap_timeout_ms, last_char_reveal_ms, key_debounce_ms] = args
+        else {
+            return Err(Error::TypeError);
+        };
+        KeyboardTimings::set(KeyboardTimings {
+            erase_hold: Duration::from_millis(erase_hold_ms.try_into()?),
+            multi_tap_timeout: Duration::from_millis(multi_tap_timeout_ms.try_into()?),
+            last_char_reveal: Duration::from_millis(last_char_reveal_ms.try_into()?),
+            key_debounce: Duration::from_millis(key_debounce_ms.try_into()?),
+        });
+        Ok(Obj::const_none())
+    };
+    unsafe { try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
 pub fn get_user_custom_image() -> Result<BinaryData<'static>, Error> {
     let len = get_avatar_len()?;
     let mut data = Gc::<[u8]>::new_slice(len)?;
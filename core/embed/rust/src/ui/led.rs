@@ -0,0 +1,176 @@
+//! Short color pulses of the RGB LED signalling outcomes of user interactions.
+//!
+//! A pulse starts at full brightness and fades out on the animation frames
+//! delivered to the layout root, so it is finished even if the component that
+//! started it is gone. A newly attached layout takes over the fade of a running
+//! pulse, and the LED is switched off when the layout driving the fade is
+//! deleted, so that it never stays lit with nothing to fade it out. On models
+//! without the LED all the calls are no-ops.
+
+use crate::ui::component::{Event, EventCtx};
+
+#[cfg(feature = "rgb_led")]
+use crate::{
+    time::{Duration, Instant},
+    trezorhal::rgb_led,
+};
+
+/// Meaning of the LED pulse.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Signal {
+    Success,
+    Warning,
+    Attention,
+}
+
+#[cfg(feature = "rgb_led")]
+impl Signal {
+    /// Color of the pulse, as `0xRRGGBB`.
+    const fn color(self) -> u32 {
+        match self {
+            Signal::Success => 0x00_c0_30,
+            Signal::Warning => 0xff_40_00,
+            Signal::Attention => 0x00_60_ff,
+        }
+    }
+}
+
+#[cfg(feature = "rgb_led")]
+const PULSE_DURATION: Duration = Duration::from_millis(800);
+
+#[cfg(feature = "rgb_led")]
+static mut ENABLED: bool = true;
+
+/// Running pulse.
+#[cfg(feature = "rgb_led")]
+#[derive(Copy, Clone)]
+struct Pulse {
+    color: u32,
+    started: Instant,
+    /// Identity of the layout fading the pulse out, `None` until the pulse
+    /// reaches the layout root.
+    owner: Option<usize>,
+}
+
+#[cfg(feature = "rgb_led")]
+static mut PULSE: Option<Pulse> = None;
+
+pub fn signal_success(ctx: &mut EventCtx) {
+    signal(ctx, Signal::Success);
+}
+
+pub fn signal_warning(ctx: &mut EventCtx) {
+    signal(ctx, Signal::Warning);
+}
+
+pub fn signal_attention(ctx: &mut EventCtx) {
+    signal(ctx, Signal::Attention);
+}
+
+/// Start a pulse of the LED, replacing the running one.
+#[cfg(feature = "rgb_led")]
+pub fn signal(ctx: &mut EventCtx, signal: Signal) {
+    if !enabled() {
+        return;
+    }
+    // SAFETY: single-threaded access
+    unsafe {
+        PULSE = Some(Pulse {
+            color: signal.color(),
+            started: Instant::now(),
+            owner: None,
+        });
+    }
+    rgb_led::set_color(signal.color());
+    ctx.request_anim_frame();
+}
+
+/// Fade out the running pulse, to be called with every event passed to the
+/// root of the layout identified by `layout`.
+#[cfg(feature = "rgb_led")]
+pub fn event(ctx: &mut EventCtx, event: Event, layout: usize) {
+    // SAFETY: single-threaded access
+    let Some(mut pulse) = (unsafe { PULSE }) else {
+        return;
+    };
+    match event {
+        // Pulse started during this event pass, or left over from the previous
+        // layout.
+        _ if pulse.owner.is_none() => {}
+        Event::Attach(_) => {}
+        Event::Timer(EventCtx::ANIM_FRAME_TIMER) if pulse.owner == Some(layout) => {}
+        _ => return,
+    }
+    pulse.owner = Some(layout);
+    // SAFETY: single-threaded access
+    unsafe {
+        PULSE = Some(pulse);
+    }
+    let Pulse { color, started, .. } = pulse;
+    let elapsed = Instant::now()
+        .saturating_duration_since(started)
+        .to_millis();
+    let total = PULSE_DURATION.to_millis();
+    if elapsed >= total {
+        stop();
+        return;
+    }
+    let remaining = total - elapsed;
+    let fade = |shift: u32| (((color >> shift) & 0xff) * remaining / total) << shift;
+    rgb_led::set_color(fade(16) | fade(8) | fade(0));
+    ctx.request_anim_frame();
+}
+
+/// Enable or disable the LED signals, e.g. according to a device setting.
+#[cfg(feature = "rgb_led")]
+pub fn set_enabled(enable: bool) {
+    // SAFETY: single-threaded access
+    unsafe {
+        ENABLED = enable;
+    }
+    if !enable {
+        stop();
+    }
+}
+
+#[cfg(feature = "rgb_led")]
+pub fn enabled() -> bool {
+    // SAFETY: single-threaded access
+    unsafe { ENABLED }
+}
+
+/// Switch the LED off if its pulse is being faded out by the deleted layout
+/// identified by `layout`.
+#[cfg(feature = "rgb_led")]
+pub fn layout_deleted(layout: usize) {
+    // SAFETY: single-threaded access
+    if unsafe { PULSE }.is_some_and(|pulse| pulse.owner == Some(layout)) {
+        stop();
+    }
+}
+
+#[cfg(feature = "rgb_led")]
+fn stop() {
+    // SAFETY: single-threaded access
+    unsafe {
+        PULSE = None;
+    }
+    rgb_led::set_color(0);
+}
+
+#[cfg(not(feature = "rgb_led"))]
+pub fn signal(_ctx: &mut EventCtx, _signal: Signal) {}
+
+#[cfg(not(feature = "rgb_led"))]
+pub fn event(_ctx: &mut EventCtx, _event: Event, _layout: usize) {}
+
+#[cfg(not(feature = "rgb_led"))]
+pub fn set_enabled(_enable: bool) {}
+
+#[cfg(not(feature = "rgb_led"))]
+pub fn layout_deleted(_layout: usize) {}
+
+#[cfg(not(feature = "rgb_led"))]
+pub fn enabled() -> bool {
+    false
+}
@@ -16,6 +16,35 @@ pub trait Lerp: Copy {
             t => Self::lerp(a, b, t),
         }
     }
+
+    /// Interpolate between `a` and `b` by the fixed-point factor `t` in the
+    /// range `0..=max`, e.g. loader progress. Returns exactly `a` for `t == 0`
+    /// and `b` for `t >= max`.
+    fn lerp_fixed(a: Self, b: Self, t: u16, max: u16) -> Self
+    where
+        Self: Sized,
+    {
+        match t {
+            0 => a,
+            t if t >= max => b,
+            t => Self::lerp(a, b, t as f32 / max as f32),
+        }
+    }
+
+    /// Interpolate between `a` and `b` with the factor `t` in the range
+    /// `0..=1.0` transformed by the easing function `ease`, e.g.
+    /// `easer::functions::Cubic::ease_out`. Returns exactly `a` and `b` at the
+    /// ends of the range.
+    fn lerp_eased(a: Self, b: Self, t: f32, ease: fn(f32, f32, f32, f32) -> f32) -> Self
+    where
+        Self: Sized,
+    {
+        match t {
+            t if t <= 0.0 => a,
+            t if t >= 1.0 => b,
+            t => Self::lerp(a, b, ease(t, 0.0, 1.0, 1.0)),
+        }
+    }
 }
 
 /// Type that can compute an inverse of linear interpolation.
@@ -76,6 +105,8 @@ impl_lerp_for_signed!(f32);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::geometry::{Offset, Point, Rect};
+    use easer::functions::{Cubic, Easing};
 
     #[test]
     fn lerp_for_int_and_uint() {
@@ -93,4 +124,46 @@ mod tests {
         assert!((i32::inv_lerp(8, 0, 4) - 0.5).abs() < f32::EPSILON);
         assert!((u32::inv_lerp(0, 8, 4) - 0.5).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn lerp_endpoints() {
+        let a = Offset::new(-40, 7);
+        let b = Offset::new(13, -240);
+        assert_eq!(Offset::lerp(a, b, 0.0), a);
+        assert_eq!(Offset::lerp(a, b, 1.0), b);
+        assert_eq!(Offset::lerp_fixed(a, b, 0, 1000), a);
+        assert_eq!(Offset::lerp_fixed(a, b, 1000, 1000), b);
+        assert_eq!(Offset::lerp_eased(a, b, 0.0, Cubic::ease_out), a);
+        assert_eq!(Offset::lerp_eased(a, b, 1.0, Cubic::ease_out), b);
+
+        let a = Point::new(239, -1);
+        let b = Point::new(0, 120);
+        assert!(Point::lerp(a, b, 0.0) == a);
+        assert!(Point::lerp(a, b, 1.0) == b);
+        assert!(Point::lerp_fixed(a, b, 0, 1000) == a);
+        assert!(Point::lerp_fixed(a, b, 1000, 1000) == b);
+        assert!(Point::lerp_eased(a, b, 0.0, Cubic::ease_in_out) == a);
+        assert!(Point::lerp_eased(a, b, 1.0, Cubic::ease_in_out) == b);
+
+        let a = Rect::new(Point::new(-3, 10), Point::new(17, 43));
+        let b = Rect::new(Point::new(0, 0), Point::new(240, 240));
+        assert!(Rect::lerp(a, b, 0.0) == a);
+        assert!(Rect::lerp(a, b, 1.0) == b);
+        assert!(Rect::lerp_fixed(a, b, 0, 1000) == a);
+        assert!(Rect::lerp_fixed(a, b, 1000, 1000) == b);
+        assert!(Rect::lerp_eased(a, b, 0.0, Cubic::ease_in) == a);
+        assert!(Rect::lerp_eased(a, b, 1.0, Cubic::ease_in) == b);
+    }
+
+    #[test]
+    fn lerp_fixed_and_eased() {
+        assert_eq!(i16::lerp_fixed(0, 360, 500, 1000), 180);
+        assert_eq!(i16::lerp_fixed(0, 360, 2000, 1000), 360);
+        assert_eq!(
+            i16::lerp_eased(0, 50, 0.5, Cubic::ease_out),
+            i16::lerp(0, 50, Cubic::ease_out(0.5, 0.0, 1.0, 1.0))
+        );
+        assert_eq!(u8::lerp_eased(0, 255, -0.5, Cubic::ease_out), 0);
+        assert_eq!(u8::lerp_eased(0, 255, 1.5, Cubic::ease_out), 255);
+    }
 }
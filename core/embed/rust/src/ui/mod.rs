@@ -12,7 +12,9 @@ pub mod event;
 #[cfg(all(feature = "micropython", feature = "touch", feature = "new_rendering"))]
 pub mod flow;
 pub mod geometry;
+pub mod led;
 pub mod lerp;
+pub mod random;
 pub mod shape;
 #[macro_use]
 pub mod util;
@@ -21,9 +21,13 @@ use super::{theme, Frame, FrameMsg};
 
 const MAX_XPUBS: usize = 16;
 
+/// Account info: a page with account, derivation path and script type,
+/// followed by the pages of multisig XPUBs. The first page is skipped when
+/// none of the account details are known.
 #[derive(Clone)]
 pub struct AddressDetails {
     details: Frame<Paragraphs<ParagraphVecShort<'static>>>,
+    has_details: bool,
     xpub_view: Frame<Paragraphs<Paragraph<'static>>>,
     xpubs: Vec<(TString<'static>, TString<'static>), MAX_XPUBS>,
     xpub_page_count: Vec<u8, MAX_XPUBS>,
@@ -35,38 +39,51 @@ impl AddressDetails {
         details_title: TString<'static>,
         account: Option<TString<'static>>,
         path: Option<TString<'static>>,
+        script_type: Option<TString<'static>>,
     ) -> Result<Self, Error> {
         let mut para = ParagraphVecShort::new();
-        if let Some(a) = account {
-            para.add(Paragraph::new::<TString>(
-                &theme::TEXT_SUB_GREY,
-                TR::words__account.into(),
-            ));
-            para.add(Paragraph::new(&theme::TEXT_MONO_GREY_LIGHT, a));
-        }
-        if account.is_some() & path.is_some() {
-            para.add(Paragraph::new(
-                &theme::TEXT_SUB_GREY,
-                TString::from_str(" "),
-            ));
-        }
-        if let Some(p) = path {
+        let items = [
+            (TR::words__account, account, &theme::TEXT_MONO_GREY_LIGHT),
+            (
+                TR::address_details__derivation_path,
+                path,
+                &theme::TEXT_MONO_PATH_CHUNKS,
+            ),
+            (
+                TR::address_details__script_type,
+                script_type,
+                &theme::TEXT_MONO_GREY_LIGHT,
+            ),
+        ];
+        for (label, value, style) in items {
+            let Some(value) = value else {
+                continue;
+            };
+            if !para.is_empty() {
+                para.add(Paragraph::new(
+                    &theme::TEXT_SUB_GREY,
+                    TString::from_str(" "),
+                ));
+            }
             para.add(Paragraph::new::<TString>(
                 &theme::TEXT_SUB_GREY,
-                TR::address_details__derivation_path.into(),
+                label.into(),
             ));
-            para.add(Paragraph::new(&theme::TEXT_MONO_GREY_LIGHT, p));
+            para.add(Paragraph::new(style, value));
         }
+        let has_details = !para.is_empty();
         let result = Self {
             details: Frame::left_aligned(details_title, para.into_paragraphs())
                 .with_cancel_button()
                 .with_swipe(SwipeDirection::Right, SwipeSettings::immediate())
                 .with_horizontal_pages(),
+            has_details,
             xpub_view: Frame::left_aligned(
                 " \n ".into(),
-                Paragraph::new(&theme::TEXT_MONO_GREY_LIGHT, "").into_paragraphs(),
+                Paragraph::new(&theme::TEXT_MONO_XPUB_CHUNKS, "").into_paragraphs(),
             )
             .with_cancel_button()
+            .with_swipe(SwipeDirection::Right, SwipeSettings::immediate())
             .with_horizontal_pages(),
             xpubs: Vec::new(),
             xpub_page_count: Vec::new(),
@@ -85,6 +102,16 @@ impl AddressDetails {
             .map_err(|_| Error::OutOfRange)
     }
 
+    /// Number of pages before the XPUBs. Without XPUBs the details page is
+    /// shown even if empty.
+    fn details_pages(&self) -> usize {
+        (self.has_details || self.xpubs.is_empty()) as usize
+    }
+
+    fn showing_details(&self) -> bool {
+        self.current_page < self.details_pages()
+    }
+
     fn switch_xpub(&mut self, i: usize, page: usize) -> usize {
         // Context is needed for updating child so that it can request repaint. In this
         // case the parent component that handles paging always requests complete
@@ -124,8 +151,7 @@ impl Paginate for AddressDetails {
 
     fn change_page(&mut self, to_page: usize) {
         self.current_page = to_page;
-        if to_page > 0 {
-            let i = to_page - 1;
+        if let Some(i) = to_page.checked_sub(self.details_pages()) {
             let (xpub_index, xpub_page) = self.lookup(i);
             self.switch_xpub(xpub_index, xpub_page);
         }
@@ -144,6 +170,8 @@ impl Component for AddressDetails {
             let npages = self.switch_xpub(i, 0) as u8;
             unwrap!(self.xpub_page_count.push(npages));
         }
+        // The loop above leaves the last XPUB in the view.
+        self.change_page(self.current_page.min(self.page_count() - 1));
 
         bounds
     }
@@ -165,9 +193,10 @@ impl Component for AddressDetails {
             _ => {}
         }
 
-        let msg = match self.current_page {
-            0 => self.details.event(ctx, event),
-            _ => self.xpub_view.event(ctx, event),
+        let msg = if self.showing_details() {
+            self.details.event(ctx, event)
+        } else {
+            self.xpub_view.event(ctx, event)
         };
         match msg {
             Some(FrameMsg::Button(_)) => Some(()),
@@ -176,39 +205,43 @@ impl Component for AddressDetails {
     }
 
     fn paint(&mut self) {
-        match self.current_page {
-            0 => self.details.paint(),
-            _ => self.xpub_view.paint(),
+        if self.showing_details() {
+            self.details.paint()
+        } else {
+            self.xpub_view.paint()
         }
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        match self.current_page {
-            0 => self.details.render(target),
-            _ => self.xpub_view.render(target),
+        if self.showing_details() {
+            self.details.render(target)
+        } else {
+            self.xpub_view.render(target)
         }
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
-        match self.current_page {
-            0 => self.details.bounds(sink),
-            _ => self.xpub_view.bounds(sink),
+        if self.showing_details() {
+            self.details.bounds(sink)
+        } else {
+            self.xpub_view.bounds(sink)
         }
     }
 }
 
 impl Swipable for AddressDetails {
     fn get_swipe_config(&self) -> SwipeConfig {
-        match self.current_page {
-            0 => self.details.get_swipe_config(),
-            _ => self.xpub_view.get_swipe_config(),
+        if self.showing_details() {
+            self.details.get_swipe_config()
+        } else {
+            self.xpub_view.get_swipe_config()
         }
     }
 
     fn get_internal_page_count(&self) -> usize {
         let total_xpub_pages: u8 = self.xpub_page_count.iter().copied().sum();
-        1usize.saturating_add(total_xpub_pages.into())
+        self.details_pages().saturating_add(total_xpub_pages.into())
     }
 }
 
@@ -216,9 +249,12 @@ impl Swipable for AddressDetails {
 impl crate::trace::Trace for AddressDetails {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("AddressDetails");
-        match self.current_page {
-            0 => t.child("details", &self.details),
-            _ => t.child("xpub_view", &self.xpub_view),
+        t.int("page", self.current_page as i64);
+        t.int("page_count", self.get_internal_page_count() as i64);
+        if self.showing_details() {
+            t.child("details", &self.details)
+        } else {
+            t.child("xpub_view", &self.xpub_view)
         }
     }
 }
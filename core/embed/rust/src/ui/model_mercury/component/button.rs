@@ -2,26 +2,48 @@
 use crate::trezorhal::haptic::{play, HapticEffect};
 use crate::{
     strutil::TString,
-    time::Duration,
+    time::{Duration, Stopwatch},
     ui::{
         component::{
-            Component, ComponentExt, Event, EventCtx, FixedHeightBar, MsgMap, Split, TimerToken,
+            text::util::truncate_middle, Component, ComponentExt, Event, EventCtx, FixedHeightBar,
+            MsgMap, Split, Timer, TimerToken,
         },
         display::{self, toif::Icon, Color, Font},
         event::TouchEvent,
         geometry::{Alignment, Alignment2D, Insets, Offset, Point, Rect},
+        lerp::Lerp,
         shape,
         shape::Renderer,
+        util::animation_disabled,
     },
 };
 
 use super::theme;
 
+/// Long press progress is only shown after the button is held for this long,
+/// so that ordinary taps don't flash it.
+const LONG_PRESS_GRACE: Duration = Duration::from_millis(150);
+/// Height of the long press progress bar along the bottom edge.
+const LONG_PRESS_BAR_HEIGHT: i16 = 2;
+/// Pause of the marquee text before scrolling and at the end of it.
+const MARQUEE_PAUSE: Duration = Duration::from_millis(1000);
+/// Speed of the marquee text scrolling [px/s].
+const MARQUEE_SPEED: u32 = 30;
+/// Duration of the blend from the normal to the active style on press.
+const PRESS_TRANSITION: Duration = Duration::from_millis(80);
+/// Duration of the blend back to the normal style on release.
+const RELEASE_TRANSITION: Duration = Duration::from_millis(150);
+
 pub enum ButtonMsg {
     Pressed,
     Released,
     Clicked,
     LongPressed,
+    DoubleClicked,
+    Repeated,
+    /// Touch started on the disabled button, only sent if enabled by
+    /// `with_disabled_press`.
+    PressedDisabled,
 }
 
 #[derive(Clone)]
@@ -34,8 +56,48 @@ pub struct Button {
     radius: Option<u8>,
     state: State,
     long_press: Option<Duration>,
-    long_timer: Option<TimerToken>,
+    long_timer: Timer,
+    /// Measures how long the button is held, if long press is enabled.
+    hold: Stopwatch,
+    /// Blend between the normal and active style after a press or release.
+    transition: Option<PressTransition>,
+    double_press: Option<Duration>,
+    /// Report the first click of a possible double click right away.
+    double_eager: bool,
+    /// Running while a click waits for a possible second one.
+    double_timer: Option<TimerToken>,
+    /// Started by the last click, in case the timer is not delivered while the
+    /// button is hidden.
+    last_click: Stopwatch,
+    /// Presses starting this soon after the previous release are ignored.
+    debounce: Option<Duration>,
+    /// Started by the last release.
+    released: Stopwatch,
+    /// Initial delay and interval of repeating while held.
+    auto_repeat: Option<(Duration, Duration)>,
+    repeat_timer: Option<TimerToken>,
+    /// Whether the current press already repeated, replacing the click.
+    repeated: bool,
+    /// Time since the marquee text started scrolling, if enabled.
+    marquee: Option<Stopwatch>,
+    /// Running while the marquee text pauses, animation frames are only
+    /// requested while it moves.
+    marquee_pause: Timer,
+    /// Shorten text that doesn't fit by an ellipsis in the middle.
+    truncate_middle: bool,
     haptic: bool,
+    report_disabled: bool,
+    /// Text content measured in `place`, not to measure it on every render.
+    text_measure: Option<TextMeasure>,
+}
+
+/// Width of the text content of a button in `font`, and where the text starts
+/// to be aligned within the button.
+#[derive(Clone, Copy)]
+struct TextMeasure {
+    font: Font,
+    width: i16,
+    x: i16,
 }
 
 impl Button {
@@ -54,8 +116,24 @@ impl Button {
             radius: None,
             state: State::Initial,
             long_press: None,
-            long_timer: None,
+            long_timer: Timer::new(),
+            hold: Stopwatch::new_stopped(),
+            transition: None,
+            double_press: None,
+            double_eager: false,
+            double_timer: None,
+            last_click: Stopwatch::new_stopped(),
+            debounce: None,
+            released: Stopwatch::new_stopped(),
+            auto_repeat: None,
+            repeat_timer: None,
+            repeated: false,
+            marquee: None,
+            marquee_pause: Timer::new(),
+            truncate_middle: false,
             haptic: true,
+            report_disabled: false,
+            text_measure: None,
         }
     }
 
@@ -75,6 +153,19 @@ impl Button {
         Self::new(ButtonContent::IconBlend(bg, fg, fg_offset))
     }
 
+    /// Two lines of text, `subtext` below `text` drawn with `subtext_style`.
+    pub const fn with_text_and_subtext(
+        text: TString<'static>,
+        subtext: TString<'static>,
+        subtext_style: &'static ButtonStyle,
+    ) -> Self {
+        Self::new(ButtonContent::TextAndSubtext {
+            text,
+            subtext,
+            subtext_style,
+        })
+    }
+
     pub const fn empty() -> Self {
         Self::new(ButtonContent::Empty)
     }
@@ -95,10 +186,57 @@ impl Button {
     }
 
     pub fn with_long_press(mut self, duration: Duration) -> Self {
+        debug_assert!(self.auto_repeat.is_none(), "long press with auto-repeat");
         self.long_press = Some(duration);
         self
     }
 
+    /// Keep sending `Repeated` while the button is held, first after
+    /// `initial_delay` and then every `interval`. A press that repeated is not
+    /// reported as `Clicked` on release. Cannot be combined with long press.
+    pub fn with_auto_repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        debug_assert!(self.long_press.is_none(), "auto-repeat with long press");
+        self.auto_repeat = Some((initial_delay, interval));
+        self
+    }
+
+    /// Report two clicks following within `window` as `DoubleClicked`. Single
+    /// clicks are only reported once the window expires.
+    pub fn with_double_press(mut self, window: Duration) -> Self {
+        self.double_press = Some(window);
+        self
+    }
+
+    /// Like `with_double_press`, but every click is reported as `Clicked` right
+    /// away, and the second one within `window` as `DoubleClicked` instead. The
+    /// double click has to undo the effect of the first click if needed.
+    pub fn with_eager_double_press(mut self, window: Duration) -> Self {
+        self.double_press = Some(window);
+        self.double_eager = true;
+        self
+    }
+
+    /// Ignore presses starting within `window` after the button was released,
+    /// to filter out accidental double taps. Zero `window` disables it.
+    pub fn with_debounce(mut self, window: Duration) -> Self {
+        self.debounce = (window != Duration::ZERO).then_some(window);
+        self
+    }
+
+    /// Scroll text that doesn't fit into the button horizontally, pausing at
+    /// both ends, instead of clipping it.
+    pub fn with_marquee(mut self) -> Self {
+        self.marquee = Some(Stopwatch::new_stopped());
+        self
+    }
+
+    /// Replace the middle part of text that doesn't fit into the button with
+    /// an ellipsis, keeping its beginning and end visible.
+    pub fn with_middle_truncation(mut self) -> Self {
+        self.truncate_middle = true;
+        self
+    }
+
     pub fn with_radius(mut self, radius: u8) -> Self {
         self.radius = Some(radius);
         self
@@ -109,6 +247,13 @@ impl Button {
         self
     }
 
+    /// Report touches of the disabled button as `PressedDisabled`, e.g. for
+    /// keyboards to tell the user that the key cannot be used right now.
+    pub fn with_disabled_press(mut self) -> Self {
+        self.report_disabled = true;
+        self
+    }
+
     pub fn enable_if(&mut self, ctx: &mut EventCtx, enabled: bool) {
         if enabled {
             self.enable(ctx);
@@ -139,14 +284,33 @@ impl Button {
         )
     }
 
+    /// Whether a click was just released and another one would make it a
+    /// double click.
+    pub fn awaits_double_click(&self) -> bool {
+        self.double_timer.is_some()
+            && self
+                .double_press
+                .is_some_and(|window| self.last_click.is_running_within(window))
+    }
+
     pub fn is_disabled(&self) -> bool {
         matches!(self.state, State::Disabled)
     }
 
+    pub fn is_pressed(&self) -> bool {
+        matches!(self.state, State::Pressed)
+    }
+
     pub fn set_content(&mut self, ctx: &mut EventCtx, content: ButtonContent) {
         if self.content != content {
             self.content = content;
-            ctx.request_paint();
+            self.measure_text();
+            if self.marquee.is_some() {
+                // Start scrolling the new text from the beginning.
+                self.marquee = Some(Stopwatch::new_stopped());
+                self.start_marquee(ctx);
+            }
+            ctx.request_paint_region(self.area);
         }
     }
 
@@ -157,7 +321,8 @@ impl Button {
     pub fn set_stylesheet(&mut self, ctx: &mut EventCtx, styles: ButtonStyleSheet) {
         if self.styles != styles {
             self.styles = styles;
-            ctx.request_paint();
+            self.measure_text();
+            ctx.request_paint_region(self.area);
         }
     }
 
@@ -173,10 +338,210 @@ impl Button {
         self.area
     }
 
+    /// Area accepting touches. Bigger than the button area if expanded by
+    /// `with_expanded_touch_area`, only affecting hit-testing, not rendering.
+    pub fn touch_area(&self) -> Rect {
+        if let Some(expand) = self.touch_expand {
+            self.area.outset(expand)
+        } else {
+            self.area
+        }
+    }
+
     fn set(&mut self, ctx: &mut EventCtx, state: State) {
         if self.state != state {
+            if state != State::Pressed {
+                self.hold = Stopwatch::new_stopped();
+                self.long_timer.stop(ctx);
+                self.repeat_timer = None;
+            }
+            self.update_transition(ctx, &state);
             self.state = state;
-            ctx.request_paint();
+            ctx.request_paint_region(self.area);
+        }
+    }
+
+    /// Start blending the styles when going between pressed and not pressed.
+    /// A transition interrupted by another one continues from the current
+    /// blend, so that quick taps don't jump between the styles.
+    fn update_transition(&mut self, ctx: &mut EventCtx, state: &State) {
+        let was_pressed = self.state == State::Pressed;
+        let pressed = *state == State::Pressed;
+        let involves_disabled = self.state == State::Disabled || *state == State::Disabled;
+        if was_pressed == pressed || involves_disabled || animation_disabled() {
+            self.transition = None;
+            return;
+        }
+        self.transition = Some(PressTransition::start(self.press_level(), pressed));
+        ctx.request_anim_frame();
+    }
+
+    /// How much of the active style is shown, from 0.0 (normal) to 1.0.
+    fn press_level(&self) -> f32 {
+        match &self.transition {
+            Some(transition) => transition.level(),
+            None if self.state == State::Pressed => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Style blended between normal and active, while transitioning.
+    fn transition_style(&self) -> Option<ButtonStyle> {
+        self.transition.as_ref()?;
+        let (normal, active) = (self.styles.normal, self.styles.active);
+        let level = self.press_level();
+        Some(ButtonStyle {
+            font: if level < 0.5 {
+                normal.font
+            } else {
+                active.font
+            },
+            text_color: Color::lerp(normal.text_color, active.text_color, level),
+            button_color: Color::lerp(normal.button_color, active.button_color, level),
+            icon_color: Color::lerp(normal.icon_color, active.icon_color, level),
+            background_color: Color::lerp(normal.background_color, active.background_color, level),
+        })
+    }
+
+    /// Measure the text content in the font of the normal style, which the
+    /// other styles mostly share. Needs to be called whenever the text, the
+    /// styles or the area change.
+    fn measure_text(&mut self) {
+        self.text_measure = match &self.content {
+            ButtonContent::Text(text) => {
+                let font = self.styles.normal.font;
+                text.map(|t| {
+                    let width = font.text_width(t);
+                    let x = self.text_x(width);
+                    Some(TextMeasure { font, width, x })
+                })
+            }
+            _ => None,
+        };
+    }
+
+    /// Start of the text baseline according to `text_align`, for a text of
+    /// the measured `width`.
+    fn text_x(&self, width: i16) -> i16 {
+        match self.text_align {
+            Alignment::Start => self.area.x0 + Self::BASELINE_OFFSET.x,
+            Alignment::Center => self.area.center().x - width / 2,
+            Alignment::End => self.area.x1 - Self::BASELINE_OFFSET.x - width,
+        }
+    }
+
+    /// The text content as measured in `place`, unless it was in another font.
+    fn text_measure(&self, font: Font) -> Option<TextMeasure> {
+        let measure = self.text_measure.filter(|m| m.font == font)?;
+        #[cfg(feature = "ui_debug")]
+        if let ButtonContent::Text(text) = &self.content {
+            debug_assert_eq!(
+                measure.width,
+                text.map(|t| font.text_width(t)),
+                "stale text measure"
+            );
+        }
+        Some(measure)
+    }
+
+    /// By how many pixels the text is wider than the space for it, if the
+    /// marquee is enabled and the text overflows.
+    fn marquee_overflow(&self) -> Option<i16> {
+        self.marquee.as_ref()?;
+        let ButtonContent::Text(text) = &self.content else {
+            return None;
+        };
+        let available = self.area.width() - 2 * Self::BASELINE_OFFSET.x;
+        let font = self.style().font;
+        let width = match self.text_measure(font) {
+            Some(measure) => measure.width,
+            None => text.map(|t| font.text_width(t)),
+        };
+        let overflow = width - available;
+        (overflow > 0).then_some(overflow)
+    }
+
+    fn start_marquee(&mut self, ctx: &mut EventCtx) {
+        if self.marquee_overflow().is_none() {
+            return;
+        }
+        if let Some(timer) = self.marquee.as_mut() {
+            if !timer.is_running() {
+                timer.start();
+            }
+        }
+        self.schedule_marquee(ctx);
+    }
+
+    /// Request the next update of the marquee text, an animation frame while
+    /// it scrolls or the end of the current pause.
+    fn schedule_marquee(&mut self, ctx: &mut EventCtx) {
+        let Some(overflow) = self.marquee_overflow() else {
+            return;
+        };
+        match self.marquee_pause_left(overflow) {
+            Some(left) => self.marquee_pause.start(ctx, left),
+            None => {
+                self.marquee_pause.stop(ctx);
+                ctx.request_anim_frame();
+            }
+        }
+    }
+
+    /// Position of the marquee text within its cycle: pause, scroll to the
+    /// end, pause again and jump back to the beginning.
+    fn marquee_phase(&self, overflow: i16) -> (u32, u32, u32) {
+        let pause = MARQUEE_PAUSE.to_millis();
+        let scroll = overflow as u32 * 1000 / MARQUEE_SPEED;
+        let t = self.marquee.as_ref().map_or(0, |timer| {
+            timer.elapsed().to_millis() % (pause + scroll + pause)
+        });
+        (t, pause, scroll)
+    }
+
+    /// Time until the marquee text moves again, `None` while it scrolls.
+    fn marquee_pause_left(&self, overflow: i16) -> Option<Duration> {
+        let (t, pause, scroll) = self.marquee_phase(overflow);
+        if t < pause {
+            Some(Duration::from_millis(pause - t))
+        } else if t >= pause + scroll {
+            // Jumping back to the beginning at the end of the cycle.
+            Some(Duration::from_millis(pause + scroll + pause - t))
+        } else {
+            None
+        }
+    }
+
+    /// How far the marquee text is scrolled.
+    fn marquee_offset(&self, overflow: i16) -> i16 {
+        let (t, pause, _) = self.marquee_phase(overflow);
+        if t < pause {
+            0
+        } else {
+            ((t - pause) * MARQUEE_SPEED / 1000).min(overflow as u32) as i16
+        }
+    }
+
+    /// Portion of the long press duration the button has been held for, or
+    /// `None` if there is no long press progress to show.
+    fn long_press_progress(&self) -> Option<f32> {
+        let duration = self.long_press?;
+        let elapsed = self.hold.elapsed();
+        if !self.hold.is_running() || elapsed < LONG_PRESS_GRACE || animation_disabled() {
+            return None;
+        }
+        Some((elapsed.to_millis() as f32 / duration.to_millis() as f32).min(1.0))
+    }
+
+    fn render_long_press_progress<'s>(&self, target: &mut impl Renderer<'s>, style: &ButtonStyle) {
+        if let Some(progress) = self.long_press_progress() {
+            let width = i16::lerp(0, self.area.width(), progress);
+            shape::Bar::new(Rect::from_bottom_left_and_size(
+                self.area.bottom_left(),
+                Offset::new(width, LONG_PRESS_BAR_HEIGHT),
+            ))
+            .with_bg(style.icon_color)
+            .render(target);
         }
     }
 
@@ -243,6 +608,33 @@ impl Button {
             ButtonContent::IconAndText(child) => {
                 child.paint(self.area, self.style(), Self::BASELINE_OFFSET);
             }
+            ButtonContent::TextAndSubtext {
+                text,
+                subtext,
+                subtext_style,
+            } => {
+                let (text_baseline, subtext_baseline) =
+                    self.text_and_subtext_baselines(style, subtext_style);
+                let x = self.area.x0 + Self::BASELINE_OFFSET.x;
+                text.map(|text| {
+                    display::text_left(
+                        Point::new(x, text_baseline),
+                        text,
+                        style.font,
+                        style.text_color,
+                        style.button_color,
+                    );
+                });
+                subtext.map(|subtext| {
+                    display::text_left(
+                        Point::new(x, subtext_baseline),
+                        subtext,
+                        subtext_style.font,
+                        subtext_style.text_color,
+                        style.button_color,
+                    );
+                });
+            }
             ButtonContent::IconBlend(bg, fg, offset) => display::icon_over_icon(
                 Some(self.area),
                 (*bg, Offset::zero(), style.button_color),
@@ -261,7 +653,11 @@ impl Button {
         match &self.content {
             ButtonContent::Empty => {}
             ButtonContent::Text(text) => {
-                let y_offset = Offset::y(self.style().font.allcase_text_height() / 2);
+                if let Some(overflow) = self.marquee_overflow() {
+                    self.render_marquee(target, text, style, alpha, overflow);
+                    return;
+                }
+                let y_offset = Offset::y(style.font.allcase_text_height() / 2);
                 let start_of_baseline = match self.text_align {
                     Alignment::Start => {
                         self.area.left_center() + Offset::x(Self::BASELINE_OFFSET.x)
@@ -269,14 +665,44 @@ impl Button {
                     Alignment::Center => self.area.center(),
                     Alignment::End => self.area.right_center() - Offset::x(Self::BASELINE_OFFSET.x),
                 } + y_offset;
-                text.map(|text| {
-                    shape::Text::new(start_of_baseline, text)
+                let measure = self
+                    .text_measure(style.font)
+                    .filter(|_| !self.truncate_middle);
+                let mut render = |text: &str| {
+                    let text_shape = match measure {
+                        Some(m) => shape::Text::new(Point::new(m.x, start_of_baseline.y), text)
+                            .with_measured_width(m.width),
+                        None => {
+                            shape::Text::new(start_of_baseline, text).with_align(self.text_align)
+                        }
+                    };
+                    text_shape
                         .with_font(style.font)
                         .with_fg(style.text_color)
-                        .with_align(self.text_align)
                         .with_alpha(alpha)
                         .render(target);
-                });
+                };
+                let available = self.area.width() - 2 * Self::BASELINE_OFFSET.x;
+                if self.truncate_middle {
+                    render(&text.map(|t| truncate_middle(t, style.font, available)));
+                } else if measure
+                    .map_or_else(|| text.map(|t| style.font.text_width(t)), |m| m.width)
+                    > available
+                {
+                    // Too long, e.g. in a larger font, cut it with an ellipsis.
+                    text.map(|t| {
+                        self.render_line(
+                            target,
+                            start_of_baseline.y,
+                            t,
+                            style.font,
+                            style.text_color,
+                            alpha,
+                        )
+                    });
+                } else {
+                    text.map(render);
+                }
             }
             ButtonContent::Icon(icon) => {
                 shape::ToifImage::new(self.area.center(), icon.toif)
@@ -289,11 +715,40 @@ impl Button {
                 child.render(
                     target,
                     self.area,
-                    self.style(),
+                    style,
                     Self::BASELINE_OFFSET,
+                    self.text_align,
                     alpha,
                 );
             }
+            ButtonContent::TextAndSubtext {
+                text,
+                subtext,
+                subtext_style,
+            } => {
+                let (text_baseline, subtext_baseline) =
+                    self.text_and_subtext_baselines(style, subtext_style);
+                text.map(|text| {
+                    self.render_line(
+                        target,
+                        text_baseline,
+                        text,
+                        style.font,
+                        style.text_color,
+                        alpha,
+                    )
+                });
+                subtext.map(|subtext| {
+                    self.render_line(
+                        target,
+                        subtext_baseline,
+                        subtext,
+                        subtext_style.font,
+                        subtext_style.text_color,
+                        alpha,
+                    )
+                });
+            }
             ButtonContent::IconBlend(bg, fg, offset) => {
                 shape::Bar::new(self.area)
                     .with_bg(style.background_color)
@@ -311,6 +766,83 @@ impl Button {
         }
     }
 
+    /// Baselines of the text and the subtext, the two lines together centered
+    /// vertically.
+    fn text_and_subtext_baselines(
+        &self,
+        style: &ButtonStyle,
+        subtext_style: &ButtonStyle,
+    ) -> (i16, i16) {
+        let text_height = style.font.allcase_text_height();
+        let subtext_height = subtext_style.font.allcase_text_height();
+        let total_height = text_height + theme::BUTTON_SUBTEXT_GAP + subtext_height;
+        let text_baseline = self.area.center().y - total_height / 2 + text_height;
+        (
+            text_baseline,
+            text_baseline + theme::BUTTON_SUBTEXT_GAP + subtext_height,
+        )
+    }
+
+    /// Render a single line of text aligned according to `text_align`,
+    /// truncated with an ellipsis if it does not fit into the button.
+    fn render_line<'s>(
+        &self,
+        target: &mut impl Renderer<'s>,
+        baseline: i16,
+        text: &str,
+        font: Font,
+        color: Color,
+        alpha: u8,
+    ) {
+        let max_width = self.area.width() - 2 * Self::BASELINE_OFFSET.x;
+        let (visible, truncated) = IconText::fit_text(text, font, max_width);
+        let ellipsis = if truncated { IconText::ELLIPSIS } else { "" };
+        let width = font.text_width(visible) + font.text_width(ellipsis);
+        let x = match self.text_align {
+            Alignment::Start => self.area.x0 + Self::BASELINE_OFFSET.x,
+            Alignment::Center => self.area.center().x - width / 2,
+            Alignment::End => self.area.x1 - Self::BASELINE_OFFSET.x - width,
+        };
+        let pos = Point::new(x, baseline);
+        shape::Text::new(pos, visible)
+            .with_font(font)
+            .with_fg(color)
+            .with_alpha(alpha)
+            .render(target);
+        if truncated {
+            shape::Text::new(pos + Offset::x(font.text_width(visible)), ellipsis)
+                .with_font(font)
+                .with_fg(color)
+                .with_alpha(alpha)
+                .render(target);
+        }
+    }
+
+    fn render_marquee<'s>(
+        &self,
+        target: &mut impl Renderer<'s>,
+        text: &TString<'static>,
+        style: &ButtonStyle,
+        alpha: u8,
+        overflow: i16,
+    ) {
+        let start_of_baseline = self.area.left_center()
+            + Offset::new(
+                Self::BASELINE_OFFSET.x - self.marquee_offset(overflow),
+                style.font.allcase_text_height() / 2,
+            );
+        let clip = self.area.inset(Insets::sides(Self::BASELINE_OFFSET.x));
+        target.in_clip(clip, &|target| {
+            text.map(|text| {
+                shape::Text::new(start_of_baseline, text)
+                    .with_font(style.font)
+                    .with_fg(style.text_color)
+                    .with_alpha(alpha)
+                    .render(target);
+            });
+        });
+    }
+
     pub fn render_with_alpha<'s>(&self, target: &mut impl Renderer<'s>, alpha: u8) {
         let style = self.style();
         self.render_background(target, style, alpha);
@@ -323,32 +855,43 @@ impl Component for Button {
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
+        self.measure_text();
         self.area
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let touch_area = if let Some(expand) = self.touch_expand {
-            self.area.outset(expand)
-        } else {
-            self.area
-        };
+        let touch_area = self.touch_area();
 
         match event {
             Event::Touch(TouchEvent::TouchStart(pos)) => {
                 match self.state {
                     State::Disabled => {
-                        // Do nothing.
+                        if self.report_disabled && touch_area.contains(pos) {
+                            return Some(ButtonMsg::PressedDisabled);
+                        }
                     }
                     _ => {
                         // Touch started in our area, transform to `Pressed` state.
                         if touch_area.contains(pos) {
+                            if self
+                                .debounce
+                                .is_some_and(|window| self.released.is_running_within(window))
+                            {
+                                return None;
+                            }
                             #[cfg(feature = "haptic")]
                             if self.haptic {
-                                play(HapticEffect::ButtonPress);
+                                play(HapticEffect::Press);
                             }
                             self.set(ctx, State::Pressed);
                             if let Some(duration) = self.long_press {
-                                self.long_timer = Some(ctx.request_timer(duration));
+                                self.long_timer.start(ctx, duration);
+                                self.hold.start();
+                                ctx.request_anim_frame();
+                            }
+                            if let Some((initial_delay, _)) = self.auto_repeat {
+                                self.repeat_timer = Some(ctx.request_timer(initial_delay));
+                                self.repeated = false;
                             }
                             ctx.disable_swipe();
                             return Some(ButtonMsg::Pressed);
@@ -377,29 +920,92 @@ impl Component for Button {
                     State::Pressed if touch_area.contains(pos) => {
                         // Touch finished in our area, we got clicked.
                         self.set(ctx, State::Initial);
+                        self.released = Stopwatch::new_started();
                         ctx.enable_swipe();
-                        return Some(ButtonMsg::Clicked);
+                        if self.repeated {
+                            // Already handled by the repeats.
+                            return None;
+                        }
+                        let double = self.awaits_double_click();
+                        match self.double_press {
+                            None => return Some(ButtonMsg::Clicked),
+                            Some(_) if double => {
+                                // Second click within the window.
+                                self.double_timer = None;
+                                return Some(ButtonMsg::DoubleClicked);
+                            }
+                            Some(window) => {
+                                // Wait whether another click follows.
+                                self.double_timer = Some(ctx.request_timer(window));
+                                self.last_click = Stopwatch::new_started();
+                                if self.double_eager {
+                                    return Some(ButtonMsg::Clicked);
+                                }
+                            }
+                        }
                     }
                     _ => {
                         // Touch finished outside our area.
                         self.set(ctx, State::Initial);
                         ctx.enable_swipe();
-                        self.long_timer = None;
                     }
                 }
             }
+            Event::Attach(_) | Event::RequestPaint => {
+                // Scroll only while being attached and shown.
+                self.start_marquee(ctx);
+            }
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => {
+                // Frames requested for other reasons don't move a pausing text.
+                if self.marquee_overflow().is_some() && !self.marquee_pause.is_running() {
+                    ctx.request_paint_region(self.area);
+                    self.schedule_marquee(ctx);
+                }
+                if self.transition.as_ref().is_some_and(|t| t.is_finished()) {
+                    // Settle on the style of the current state.
+                    self.transition = None;
+                    ctx.request_paint_region(self.area);
+                }
+                if self.hold.is_running() || self.transition.is_some() {
+                    // Keep updating the long press progress while held, or the
+                    // blended style.
+                    ctx.request_anim_frame();
+                    ctx.request_paint_region(self.area);
+                }
+            }
             Event::Timer(token) => {
-                if self.long_timer == Some(token) {
-                    self.long_timer = None;
+                if self.marquee_pause.expire(event) {
+                    ctx.request_paint_region(self.area);
+                    self.schedule_marquee(ctx);
+                }
+                if self.long_timer.expire(event) {
                     if matches!(self.state, State::Pressed) {
                         #[cfg(feature = "haptic")]
                         if self.haptic {
-                            play(HapticEffect::ButtonPress);
+                            play(HapticEffect::Press);
                         }
                         self.set(ctx, State::Initial);
+                        // A click waiting for its second one becomes part of the long press.
+                        self.double_timer = None;
                         return Some(ButtonMsg::LongPressed);
                     }
                 }
+                if self.repeat_timer == Some(token) {
+                    self.repeat_timer = None;
+                    if let (State::Pressed, Some((_, interval))) = (&self.state, self.auto_repeat) {
+                        self.repeated = true;
+                        self.repeat_timer = Some(ctx.request_timer(interval));
+                        return Some(ButtonMsg::Repeated);
+                    }
+                }
+                if self.double_timer == Some(token) {
+                    // No second click came in time, the first one was a single click. If the
+                    // button is being pressed again, that press is handled on its own.
+                    self.double_timer = None;
+                    if !self.double_eager {
+                        return Some(ButtonMsg::Clicked);
+                    }
+                }
             }
             _ => {}
         };
@@ -413,17 +1019,50 @@ impl Component for Button {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        let style = self.style();
+        let transition_style = self.transition_style();
+        let style = transition_style.as_ref().unwrap_or(self.style());
         self.render_background(target, style, 0xFF);
         self.render_content(target, style, 0xFF);
+        self.render_long_press_progress(target, style);
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         sink(self.area);
+        #[cfg(feature = "ui_debug")]
+        if self.touch_area() != self.area {
+            crate::ui::component::debug_overlay::touch_area(self.touch_area());
+        }
     }
 }
 
+/// Returns the index of the button a touch at `pos` belongs to. When `pos` is
+/// inside touch areas of more buttons, which happens when neighbors expand
+/// their touch areas, the one whose real area is closest to `pos` wins. `None`
+/// items stand for hidden buttons, these are skipped along with the disabled
+/// ones.
+pub fn closest_touch_target<'a>(
+    buttons: impl IntoIterator<Item = Option<&'a Button>>,
+    pos: Point,
+) -> Option<usize> {
+    buttons
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, btn)| {
+            let btn = btn.filter(|btn| btn.is_enabled() && btn.touch_area().contains(pos))?;
+            Some((distance_squared(btn.area(), pos), i))
+        })
+        .min()
+        .map(|(_, i)| i)
+}
+
+/// Squared distance of `pos` from the closest point of `area`.
+fn distance_squared(area: Rect, pos: Point) -> i32 {
+    let dx = (area.x0 - pos.x).max(pos.x - (area.x1 - 1)).max(0) as i32;
+    let dy = (area.y0 - pos.y).max(pos.y - (area.y1 - 1)).max(0) as i32;
+    dx * dx + dy * dy
+}
+
 #[cfg(feature = "ui_debug")]
 impl crate::trace::Trace for Button {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
@@ -437,6 +1076,55 @@ impl crate::trace::Trace for Button {
                 t.bool("icon", true);
             }
             ButtonContent::IconBlend(_, _, _) => t.bool("icon", true),
+            ButtonContent::TextAndSubtext { text, subtext, .. } => {
+                t.string("text", *text);
+                t.string("subtext", *subtext);
+            }
+        }
+        t.bool("enabled", self.is_enabled());
+    }
+}
+
+#[derive(Clone)]
+struct PressTransition {
+    /// Press level the transition started from, see `Button::press_level`.
+    from: f32,
+    /// Whether transitioning towards the active style.
+    pressed: bool,
+    timer: Stopwatch,
+}
+
+impl PressTransition {
+    fn start(from: f32, pressed: bool) -> Self {
+        Self {
+            from,
+            pressed,
+            timer: Stopwatch::new_started(),
+        }
+    }
+
+    fn level(&self) -> f32 {
+        // The whole way between the styles takes the full duration, a partial one
+        // proportionally less.
+        let duration = if self.pressed {
+            PRESS_TRANSITION
+        } else {
+            RELEASE_TRANSITION
+        };
+        let step = self.timer.elapsed().to_millis() as f32 / duration.to_millis() as f32;
+        if self.pressed {
+            (self.from + step).min(1.0)
+        } else {
+            (self.from - step).max(0.0)
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        let level = self.level();
+        if self.pressed {
+            level >= 1.0
+        } else {
+            level <= 0.0
         }
     }
 }
@@ -456,6 +1144,11 @@ pub enum ButtonContent {
     Icon(Icon),
     IconAndText(IconText),
     IconBlend(Icon, Icon, Offset),
+    TextAndSubtext {
+        text: TString<'static>,
+        subtext: TString<'static>,
+        subtext_style: &'static ButtonStyle,
+    },
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -588,6 +1281,7 @@ impl IconText {
     const ICON_SPACE: i16 = 46;
     const ICON_MARGIN: i16 = 4;
     const TEXT_MARGIN: i16 = 6;
+    const ELLIPSIS: &'static str = "...";
 
     pub fn new(text: impl Into<TString<'static>>, icon: Icon) -> Self {
         Self {
@@ -643,54 +1337,397 @@ impl IconText {
             );
         }
     }
+
+    /// Render the icon left of the text. With `Alignment::Start` the icon
+    /// occupies a fixed column so that texts of stacked buttons line up,
+    /// otherwise the pair is aligned as a whole, separated by
+    /// `theme::BUTTON_ICON_TEXT_GAP`. Text not fitting next to the icon is
+    /// truncated with an ellipsis.
     pub fn render<'s>(
         &self,
         target: &mut impl Renderer<'s>,
         area: Rect,
         style: &ButtonStyle,
         baseline_offset: Offset,
+        align: Alignment,
         alpha: u8,
     ) {
-        let width = self.text.map(|t| style.font.text_width(t));
+        let icon_width = self.icon.toif.width();
+        // The offset suits the default button font, larger ones (e.g. in the
+        // large-text mode) move down by half of their extra height.
+        let default_font = theme::button_default().normal.font;
+        let extra_height = (style.font.text_height() - default_font.text_height()).max(0);
+        let baseline = area.left_center().y + baseline_offset.y + extra_height / 2;
+
+        let (icon_x, text_x, max_text_width) = match align {
+            Alignment::Start => (
+                area.x0 + (Self::ICON_SPACE + Self::ICON_MARGIN) / 2,
+                area.x0 + Self::ICON_SPACE,
+                area.width() - Self::ICON_SPACE - Self::TEXT_MARGIN,
+            ),
+            _ => {
+                let max_text_width =
+                    area.width() - 2 * Self::TEXT_MARGIN - icon_width - theme::BUTTON_ICON_TEXT_GAP;
+                // Truncated text fills the width between the margins.
+                let pair_width = self
+                    .content_width(style.font)
+                    .min(area.width() - 2 * Self::TEXT_MARGIN);
+                let pair_x = if matches!(align, Alignment::Center) {
+                    area.center().x - pair_width / 2
+                } else {
+                    area.x1 - Self::TEXT_MARGIN - pair_width
+                };
+                (
+                    pair_x + icon_width / 2,
+                    pair_x + icon_width + theme::BUTTON_ICON_TEXT_GAP,
+                    max_text_width,
+                )
+            }
+        };
 
-        let mut use_icon = false;
-        let mut use_text = false;
+        shape::ToifImage::new(Point::new(icon_x, area.center().y), self.icon.toif)
+            .with_align(Alignment2D::CENTER)
+            .with_fg(style.icon_color)
+            .with_alpha(alpha)
+            .render(target);
+
+        self.text.map(|t| {
+            let (visible, truncated) = Self::fit_text(t, style.font, max_text_width);
+            let text_pos = Point::new(text_x, baseline);
+            shape::Text::new(text_pos, visible)
+                .with_font(style.font)
+                .with_fg(style.text_color)
+                .with_alpha(alpha)
+                .render(target);
+            if truncated {
+                shape::Text::new(
+                    text_pos + Offset::x(style.font.text_width(visible)),
+                    Self::ELLIPSIS,
+                )
+                .with_font(style.font)
+                .with_fg(style.text_color)
+                .with_alpha(alpha)
+                .render(target);
+            }
+        });
+    }
 
-        let mut icon_pos = Point::new(
-            area.top_left().x + ((Self::ICON_SPACE + Self::ICON_MARGIN) / 2),
-            area.center().y,
-        );
-        let mut text_pos = area.left_center() + baseline_offset;
+    /// Width of the icon and the text side by side, separated by
+    /// `theme::BUTTON_ICON_TEXT_GAP`.
+    fn content_width(&self, font: Font) -> i16 {
+        self.icon.toif.width() + theme::BUTTON_ICON_TEXT_GAP + self.text.map(|t| font.text_width(t))
+    }
 
-        if area.width() > (Self::ICON_SPACE + Self::TEXT_MARGIN + width) {
-            //display both icon and text
-            text_pos = Point::new(area.top_left().x + Self::ICON_SPACE, text_pos.y);
-            use_text = true;
-            use_icon = true;
-        } else if area.width() > (width + Self::TEXT_MARGIN) {
-            use_text = true;
-        } else {
-            //if we can't fit the text, retreat to centering the icon
-            icon_pos = area.center();
-            use_icon = true;
+    /// Returns the longest prefix of `text` which fits into `max_width`
+    /// followed by an ellipsis, and whether it had to be truncated at all.
+    fn fit_text(text: &str, font: Font, max_width: i16) -> (&str, bool) {
+        if font.text_width(text) <= max_width {
+            return (text, false);
         }
+        let max_width = max_width - font.text_width(Self::ELLIPSIS);
+        let visible = text
+            .char_indices()
+            .map(|(i, _)| &text[..i])
+            .rev()
+            .find(|prefix| font.text_width(prefix) <= max_width)
+            .unwrap_or("");
+        (visible, true)
+    }
+}
 
-        if use_text {
-            self.text.map(|t| {
-                shape::Text::new(text_pos, t)
-                    .with_font(style.font)
-                    .with_fg(style.text_color)
-                    .with_alpha(alpha)
-                    .render(target)
-            });
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::component::base::AttachType;
+
+    const AREA: Rect = Rect::from_top_left_and_size(Point::zero(), Offset::uniform(40));
+    const INSIDE: Point = Point::new(20, 20);
+
+    struct Tester {
+        button: Button,
+        ctx: EventCtx,
+        /// Timers requested by the button, in the order they would fire.
+        timers: Vec<(TimerToken, Duration)>,
+    }
+
+    impl Tester {
+        fn new(mut button: Button) -> Self {
+            button.place(AREA);
+            Self {
+                button,
+                ctx: EventCtx::new(),
+                timers: Vec::new(),
+            }
         }
 
-        if use_icon {
-            shape::ToifImage::new(icon_pos, self.icon.toif)
-                .with_align(Alignment2D::CENTER)
-                .with_fg(style.icon_color)
-                .with_alpha(alpha)
-                .render(target);
+        fn event(&mut self, event: Event) -> Option<ButtonMsg> {
+            let msg = self.button.event(&mut self.ctx, event);
+            while let Some(timer) = self.ctx.pop_timer() {
+                // Animation frames only refresh the long press progress.
+                if timer.0 != EventCtx::ANIM_FRAME_TIMER {
+                    self.timers.push(timer);
+                }
+            }
+            self.timers
+                .sort_by_key(|(_, deadline)| deadline.to_millis());
+            self.ctx.clear();
+            msg
+        }
+
+        fn press(&mut self) -> Option<ButtonMsg> {
+            self.event(Event::Touch(TouchEvent::TouchStart(INSIDE)))
+        }
+
+        fn release(&mut self) -> Option<ButtonMsg> {
+            self.event(Event::Touch(TouchEvent::TouchEnd(INSIDE)))
         }
+
+        /// Fire the earliest pending timer, cancelled timers are not delivered.
+        fn fire_timer(&mut self) -> Option<ButtonMsg> {
+            let (token, _) = self.timers.remove(0);
+            if self.ctx.take_cancelled_timer(token) {
+                return None;
+            }
+            self.event(Event::Timer(token))
+        }
+    }
+
+    const WINDOW: Duration = Duration::from_millis(300);
+    const LONG_PRESS: Duration = Duration::from_millis(1000);
+
+    #[test]
+    fn button_without_double_press() {
+        let mut t = Tester::new(Button::empty());
+        assert!(matches!(t.press(), Some(ButtonMsg::Pressed)));
+        // Clicked right away, no timer involved.
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        assert!(t.timers.is_empty());
+        assert!(matches!(t.press(), Some(ButtonMsg::Pressed)));
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+    }
+
+    #[test]
+    fn button_disabled_press() {
+        let mut t = Tester::new(Button::empty().initially_enabled(false));
+        assert!(t.press().is_none());
+        assert!(t.release().is_none());
+
+        let mut t = Tester::new(
+            Button::empty()
+                .initially_enabled(false)
+                .with_disabled_press(),
+        );
+        assert!(matches!(t.press(), Some(ButtonMsg::PressedDisabled)));
+        assert!(t.release().is_none());
+    }
+
+    #[test]
+    fn button_double_press() {
+        let mut t = Tester::new(Button::empty().with_double_press(WINDOW));
+        // Single click is reported when the window expires.
+        t.press();
+        assert!(t.release().is_none());
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Clicked)));
+
+        // Two clicks within the window.
+        t.press();
+        assert!(t.release().is_none());
+        t.press();
+        assert!(matches!(t.release(), Some(ButtonMsg::DoubleClicked)));
+        // The expired window doesn't report anything more.
+        assert!(t.fire_timer().is_none());
+        assert!(t.timers.is_empty());
+    }
+
+    #[test]
+    fn button_double_press_then_hold() {
+        let mut t = Tester::new(
+            Button::empty()
+                .with_double_press(WINDOW)
+                .with_long_press(LONG_PRESS),
+        );
+        // Press, release, press and hold.
+        t.press();
+        assert!(t.release().is_none());
+        // First long press timer is already scheduled, the release cancelled it.
+        assert_eq!(t.timers.len(), 2);
+        t.press();
+        // The window expires while being held, reporting the first click.
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Clicked)));
+        let (cancelled, _) = t.timers[0];
+        assert!(t.fire_timer().is_none());
+        assert!(!t.ctx.take_cancelled_timer(cancelled));
+        // Holding on results in a long press, never a double click.
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::LongPressed)));
+        assert!(t.release().is_none());
+        assert!(t.timers.is_empty());
+    }
+
+    #[test]
+    fn button_eager_double_press() {
+        let mut t = Tester::new(
+            Button::empty()
+                .with_eager_double_press(WINDOW)
+                .with_long_press(LONG_PRESS),
+        );
+        // Single click is reported right away, the window expires silently.
+        t.press();
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        assert!(t.button.awaits_double_click());
+        assert!(t.fire_timer().is_none());
+        assert!(!t.button.awaits_double_click());
+
+        // The second click within the window is a double click.
+        t.press();
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        t.press();
+        assert!(t.button.awaits_double_click());
+        assert!(matches!(t.release(), Some(ButtonMsg::DoubleClicked)));
+        assert!(!t.button.awaits_double_click());
+        assert!(t.fire_timer().is_none());
+    }
+
+    #[test]
+    fn button_debounce() {
+        let mut t = Tester::new(Button::empty().with_debounce(WINDOW));
+        assert!(matches!(t.press(), Some(ButtonMsg::Pressed)));
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        // Pressed again right after the release, ignored.
+        assert!(t.press().is_none());
+        assert!(t.release().is_none());
+
+        // Zero window doesn't ignore anything.
+        let mut t = Tester::new(Button::empty().with_debounce(Duration::ZERO));
+        t.press();
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        assert!(matches!(t.press(), Some(ButtonMsg::Pressed)));
+    }
+
+    #[test]
+    fn button_marquee_pause() {
+        let mut button =
+            Button::with_text("a text much too long for the button".into()).with_marquee();
+        button.place(AREA);
+        let mut ctx = EventCtx::new();
+        button.event(&mut ctx, Event::Attach(AttachType::Initial));
+        // The pause before scrolling is a timer, no animation frames meanwhile.
+        let (token, deadline) = unwrap!(ctx.pop_timer());
+        assert!(token != EventCtx::ANIM_FRAME_TIMER);
+        assert!(deadline <= MARQUEE_PAUSE);
+        assert!(ctx.pop_timer().is_none());
+    }
+
+    #[test]
+    fn button_long_press_cancelled() {
+        let mut t = Tester::new(Button::empty().with_long_press(LONG_PRESS));
+        t.press();
+        let (token, _) = t.timers[0];
+        // Released outside, the scheduled timer must never be delivered.
+        assert!(t
+            .event(Event::Touch(TouchEvent::TouchEnd(Point::new(100, 100))))
+            .is_none());
+        assert!(t.ctx.take_cancelled_timer(token));
+        t.timers.clear();
+
+        // Released inside, the same.
+        t.press();
+        let (token, _) = t.timers[0];
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        assert!(t.ctx.take_cancelled_timer(token));
+    }
+
+    #[test]
+    fn button_closest_touch_target() {
+        // Two buttons next to each other with a 2px gap, each with the touch area
+        // expanded by 6px all around.
+        let expand = Insets::uniform(6);
+        let mut left = Button::empty().with_expanded_touch_area(expand);
+        let mut right = Button::empty().with_expanded_touch_area(expand);
+        left.place(Rect::new(Point::new(0, 0), Point::new(40, 40)));
+        right.place(Rect::new(Point::new(42, 0), Point::new(82, 40)));
+        let target = |x, y| closest_touch_target([Some(&left), Some(&right)], Point::new(x, y));
+
+        // Real areas always win.
+        assert_eq!(target(39, 20), Some(0));
+        assert_eq!(target(42, 20), Some(1));
+        assert_eq!(target(45, 20), Some(1));
+        assert_eq!(target(36, 20), Some(0));
+        // The gap is split between the two.
+        assert_eq!(target(40, 20), Some(0));
+        assert_eq!(target(41, 20), Some(1));
+        // Expanded areas without a neighbor.
+        assert_eq!(target(-6, 20), Some(0));
+        assert_eq!(target(-7, 20), None);
+        assert_eq!(target(87, 20), Some(1));
+        assert_eq!(target(88, 20), None);
+        assert_eq!(target(20, -6), Some(0));
+        assert_eq!(target(41, 45), Some(1));
+        assert_eq!(target(41, 46), None);
+
+        // Hidden or disabled buttons don't take touches.
+        assert_eq!(
+            closest_touch_target([None, Some(&right)], Point::new(39, 20)),
+            Some(1)
+        );
+        let mut ctx = EventCtx::new();
+        right.disable(&mut ctx);
+        assert_eq!(
+            closest_touch_target([Some(&left), Some(&right)], Point::new(45, 20)),
+            Some(0)
+        );
+        assert_eq!(
+            closest_touch_target([Some(&left), Some(&right)], Point::new(50, 20)),
+            None
+        );
+    }
+
+    #[test]
+    fn button_auto_repeat() {
+        const DELAY: Duration = Duration::from_millis(500);
+        const INTERVAL: Duration = Duration::from_millis(100);
+        let mut t = Tester::new(Button::empty().with_auto_repeat(DELAY, INTERVAL));
+
+        // Ordinary click.
+        t.press();
+        assert!(matches!(t.release(), Some(ButtonMsg::Clicked)));
+        // Stale repeat timer of the released press.
+        assert!(t.fire_timer().is_none());
+
+        // Holding repeats, releasing then doesn't click.
+        t.press();
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Repeated)));
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Repeated)));
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Repeated)));
+        assert!(t.release().is_none());
+        assert!(t.fire_timer().is_none());
+        assert!(t.timers.is_empty());
+
+        // Becoming disabled while held stops the repeating.
+        t.press();
+        assert!(matches!(t.fire_timer(), Some(ButtonMsg::Repeated)));
+        t.button.disable(&mut t.ctx);
+        assert!(t.fire_timer().is_none());
+        assert!(t.timers.is_empty());
+        assert!(t.release().is_none());
+    }
+
+    #[test]
+    fn text_measure_follows_content() {
+        let mut t = Tester::new(Button::with_text("1".into()).with_text_align(Alignment::Center));
+        let font = t.button.style().font;
+        let width = |text: &str| font.text_width(text);
+        assert_eq!(
+            t.button.text_measure(font).map(|m| m.width),
+            Some(width("1"))
+        );
+
+        t.button
+            .set_content(&mut t.ctx, ButtonContent::Text("123".into()));
+        let measure = t.button.text_measure(font).unwrap();
+        assert_eq!(measure.width, width("123"));
+        assert_eq!(measure.x, 20 - width("123") / 2);
+
+        t.button.set_content(&mut t.ctx, ButtonContent::Empty);
+        assert!(t.button.text_measure(font).is_none());
     }
 }
@@ -1,5 +1,3 @@
-use core::mem;
-
 use crate::{
     error::Error,
     maybe_trace::MaybeTrace,
@@ -26,10 +24,27 @@ const LOADER_OUTER: i16 = 39;
 const LOADER_INNER: i16 = 28;
 const LOADER_OFFSET: i16 = -34;
 const LOADER_SPEED: u16 = 5;
+/// Length of the rotating arc in indeterminate mode, in thousandths of the
+/// circle.
+const LOADER_ARC: u16 = 200;
+
+/// Progress value switching the loader to indeterminate mode.
+pub const PROGRESS_INDETERMINATE: u16 = 0xFFFE;
+/// Progress value pausing the loader. Non-empty description replaces the label
+/// while paused.
+pub const PROGRESS_PAUSED: u16 = 0xFFFF;
 
 pub struct CoinJoinProgress<U> {
+    /// Determinate progress, 0..=1000.
     value: u16,
+    /// Head of the rotating arc in indeterminate mode, 0..1000.
+    spin: u16,
+    /// Where the determinate sweep starts, 0..1000. Follows the rotating arc so
+    /// that switching modes does not make the ring jump.
+    origin: u16,
     indeterminate: bool,
+    paused: bool,
+    text: TString<'static>,
     content: Child<Frame<Split<Empty, U>>>,
     // Label is not a child since circular loader paints large black rectangle which overlaps it.
     // To work around this, draw label every time loader is drawn.
@@ -61,7 +76,11 @@ where
     ) -> Result<Self, Error> {
         Ok(Self {
             value: 0,
+            spin: LOADER_ARC,
+            origin: 0,
             indeterminate,
+            paused: false,
+            text,
             content: Frame::centered(
                 TR::coinjoin__title_progress.into(),
                 Split::bottom(RECTANGLE_HEIGHT, 0, Empty, inner),
@@ -70,6 +89,40 @@ where
             label: Label::centered(text, theme::TEXT_NORMAL),
         })
     }
+
+    fn set_indeterminate(&mut self, indeterminate: bool) {
+        if indeterminate && !self.indeterminate {
+            // Continue rotating from the end of the sweep.
+            self.spin = (self.origin + self.value) % 1000;
+        } else if !indeterminate && self.indeterminate {
+            // Grow the sweep from the tail of the rotating arc.
+            self.origin = (self.spin + 1000 - LOADER_ARC) % 1000;
+        }
+        self.indeterminate = indeterminate;
+    }
+
+    fn set_paused(&mut self, paused: bool, description: TString<'static>) {
+        let text = if paused && !description.is_empty() {
+            description
+        } else {
+            self.text
+        };
+        self.paused = paused;
+        self.label.set_text(text);
+    }
+
+    /// Start and length of the active arc, in thousandths of the circle.
+    fn arc(&self) -> (u16, u16) {
+        if self.indeterminate {
+            ((self.spin + 1000 - LOADER_ARC) % 1000, LOADER_ARC)
+        } else {
+            (self.origin, self.value.min(1000))
+        }
+    }
+
+    fn is_animating(&self) -> bool {
+        self.indeterminate && !self.paused
+    }
 }
 
 impl<U> Component for CoinJoinProgress<U>
@@ -89,22 +142,35 @@ where
         self.content.event(ctx, event);
         self.label.event(ctx, event);
         match event {
-            _ if animation_disabled() => {
-                return None;
+            Event::Progress(PROGRESS_PAUSED, new_description) => {
+                self.set_paused(true, new_description);
+                ctx.request_paint();
+            }
+            Event::Progress(new_value, _new_description) => {
+                let was_animating = self.is_animating();
+                if self.paused {
+                    self.set_paused(false, TString::empty());
+                }
+                if new_value == PROGRESS_INDETERMINATE {
+                    self.set_indeterminate(true);
+                } else {
+                    self.set_indeterminate(false);
+                    self.value = new_value.min(1000);
+                }
+                if self.is_animating() && !was_animating && !animation_disabled() {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
             }
-            Event::Attach(_) if self.indeterminate => {
+            _ if animation_disabled() => {}
+            Event::Attach(_) if self.is_animating() => {
                 ctx.request_anim_frame();
             }
-            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => {
-                self.value = (self.value + LOADER_SPEED) % 1000;
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) if self.is_animating() => {
+                self.spin = (self.spin + LOADER_SPEED) % 1000;
                 ctx.request_anim_frame();
                 ctx.request_paint();
             }
-            Event::Progress(new_value, _new_description) => {
-                if mem::replace(&mut self.value, new_value) != new_value {
-                    ctx.request_paint();
-                }
-            }
             _ => {}
         }
         None
@@ -117,7 +183,11 @@ where
             LOADER_OFFSET,
             theme::FG,
             theme::BG,
-            self.value,
+            if self.indeterminate {
+                self.spin
+            } else {
+                self.value
+            },
             self.indeterminate,
             None,
         );
@@ -128,24 +198,27 @@ where
         self.content.render(target);
 
         let center = constant::screen().center() + Offset::y(LOADER_OFFSET);
-        let active_color = theme::FG;
+        let active_color = if self.paused { theme::GREY } else { theme::FG };
         let background_color = theme::BG;
         let inactive_color = background_color.blend(active_color, 85);
 
-        let start = (self.value as i16 - 100) % 1000;
-        let end = (self.value as i16 + 100) % 1000;
-        let start = 360.0 * start as f32 / 1000.0;
-        let end = 360.0 * end as f32 / 1000.0;
-
         shape::Circle::new(center, LOADER_OUTER)
             .with_bg(inactive_color)
             .render(target);
 
-        shape::Circle::new(center, LOADER_OUTER)
-            .with_bg(active_color)
-            .with_start_angle(start)
-            .with_end_angle(end)
-            .render(target);
+        let (start, length) = self.arc();
+        if length >= 1000 {
+            shape::Circle::new(center, LOADER_OUTER)
+                .with_bg(active_color)
+                .render(target);
+        } else if length > 0 {
+            let end = start + length;
+            shape::Circle::new(center, LOADER_OUTER)
+                .with_bg(active_color)
+                .with_start_angle(360.0 * start as f32 / 1000.0)
+                .with_end_angle(360.0 * end as f32 / 1000.0)
+                .render(target);
+        }
 
         shape::Circle::new(center, LOADER_INNER + 2)
             .with_bg(active_color)
@@ -155,7 +228,11 @@ where
             .with_bg(background_color)
             .render(target);
 
-        self.label.render(target);
+        if self.paused {
+            self.label.render_with_alpha(target, 128);
+        } else {
+            self.label.render(target);
+        }
     }
 }
 
@@ -166,6 +243,9 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("CoinJoinProgress");
+        t.int("value", self.value as i64);
+        t.bool("indeterminate", self.indeterminate);
+        t.bool("paused", self.paused);
         t.child("label", &self.label);
         t.child("content", &self.content);
     }
@@ -2,9 +2,9 @@ use crate::{
     strutil::TString,
     ui::{
         component::{text::TextStyle, Component, Event, EventCtx, Never, SwipeDirection},
-        display::Color,
+        display::{Color, Icon},
         event::SwipeEvent,
-        geometry::{Alignment, Offset, Rect},
+        geometry::{Alignment, Alignment2D, Offset, Point, Rect},
         lerp::Lerp,
         model_mercury::theme,
         shape,
@@ -12,16 +12,19 @@ use crate::{
     },
 };
 
+use easer::functions::{Cubic, Easing};
+
 /// Component showing a task instruction (e.g. "Swipe up") and optionally task
 /// description (e.g. "Confirm transaction") to a user. A host of this component
 /// is responsible of providing the exact area considering also the spacing. The
 /// height must be 18px (only instruction) or 37px (both description and
 /// instruction). The content and style of both description and instruction is
-/// configurable separatedly.
+/// configurable separatedly. The instruction can be accompanied by an icon.
 #[derive(Clone)]
 pub struct Footer<'a> {
     area: Rect,
     text_instruction: TString<'a>,
+    icon_instruction: Option<Icon>,
     text_description: Option<TString<'a>>,
     style_instruction: &'static TextStyle,
     style_description: &'static TextStyle,
@@ -36,14 +39,17 @@ impl<'a> Footer<'a> {
     pub const HEIGHT_SIMPLE: i16 = 18;
     /// height of the component with both description and instruction [px]
     pub const HEIGHT_DEFAULT: i16 = 37;
+    /// space between the instruction icon and text [px]
+    const ICON_SPACING: i16 = 6;
 
     pub fn new<T: Into<TString<'a>>>(instruction: T) -> Self {
         Self {
             area: Rect::zero(),
             text_instruction: instruction.into(),
+            icon_instruction: None,
             text_description: None,
-            style_instruction: &theme::TEXT_SUB_GREY,
-            style_description: &theme::TEXT_SUB_GREY_LIGHT,
+            style_instruction: theme::text_footer_instruction(),
+            style_description: theme::text_footer_description(),
             swipe_allow_down: false,
             swipe_allow_up: false,
             progress: 0,
@@ -58,11 +64,23 @@ impl<'a> Footer<'a> {
         }
     }
 
+    pub fn with_instruction_icon(self, icon: Icon) -> Self {
+        Self {
+            icon_instruction: Some(icon),
+            ..self
+        }
+    }
+
     pub fn update_instruction<T: Into<TString<'a>>>(&mut self, ctx: &mut EventCtx, s: T) {
         self.text_instruction = s.into();
         ctx.request_paint();
     }
 
+    pub fn update_instruction_icon(&mut self, ctx: &mut EventCtx, icon: Option<Icon>) {
+        self.icon_instruction = icon;
+        ctx.request_paint();
+    }
+
     pub fn update_description<T: Into<TString<'a>>>(&mut self, ctx: &mut EventCtx, s: T) {
         self.text_description = Some(s.into());
         ctx.request_paint();
@@ -144,16 +162,9 @@ impl<'a> Component for Footer<'a> {
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
         let progress = self.progress as f32 / 1000.0;
 
-        let shift = pareen::constant(0.0).seq_ease_out(
-            0.0,
-            easer::functions::Cubic,
-            1.0,
-            pareen::constant(1.0),
-        );
-
-        let offset = i16::lerp(0, 20, shift.eval(progress));
+        let offset = i16::lerp_eased(0, 20, progress, Cubic::ease_out);
 
-        let mask = u8::lerp(0, 255, shift.eval(progress));
+        let mask = u8::lerp_eased(0, 255, progress, Cubic::ease_out);
 
         let offset = match self.dir {
             SwipeDirection::Up => Offset::y(-offset),
@@ -190,8 +201,24 @@ impl<'a> Component for Footer<'a> {
                 .text_font
                 .visible_text_height_ex("Ay")
                 .1;
-            let text_instruction_baseline =
+            let mut text_instruction_baseline =
                 area_instruction.bottom_center() - Offset::y(text_instruction_font_descent);
+            if let Some(icon) = self.icon_instruction {
+                // center the icon and the text together
+                let text_width = self
+                    .text_instruction
+                    .map(|t| self.style_instruction.text_font.text_width(t));
+                let shift = (icon.toif.width() + Footer::ICON_SPACING) / 2;
+                let icon_pos = Point::new(
+                    text_instruction_baseline.x - text_width / 2 - shift,
+                    area_instruction.center().y,
+                );
+                shape::ToifImage::new(icon_pos, icon.toif)
+                    .with_align(Alignment2D::CENTER_LEFT)
+                    .with_fg(self.style_instruction.text_color)
+                    .render(target);
+                text_instruction_baseline = text_instruction_baseline + Offset::x(shift);
+            }
             self.text_instruction.map(|t| {
                 Text::new(text_instruction_baseline, t)
                     .with_font(self.style_instruction.text_font)
@@ -222,5 +249,6 @@ impl crate::trace::Trace for Footer<'_> {
             t.string("description", description);
         }
         t.string("instruction", self.text_instruction);
+        t.bool("instruction_icon", self.icon_instruction.is_some());
     }
 }
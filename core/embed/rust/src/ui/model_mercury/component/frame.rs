@@ -19,7 +19,12 @@ use crate::{
     },
 };
 
-use super::{theme, Button, ButtonMsg, ButtonStyleSheet, CancelInfoConfirmMsg, Footer};
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::debug_target;
+
+use super::{
+    theme, Button, ButtonContent, ButtonMsg, ButtonStyleSheet, CancelInfoConfirmMsg, Footer,
+};
 
 const BUTTON_EXPAND_BORDER: i16 = 32;
 
@@ -27,6 +32,8 @@ const BUTTON_EXPAND_BORDER: i16 = 32;
 pub struct Frame<T> {
     border: Insets,
     bounds: Rect,
+    /// Area of the title and subtitle.
+    header_area: Rect,
     title: Label<'static>,
     subtitle: Option<Label<'static>>,
     button: Option<Button>,
@@ -44,6 +51,17 @@ pub enum FrameMsg<T> {
     Button(CancelInfoConfirmMsg),
 }
 
+/// Change of the `Frame` header or footer requested by its content, see
+/// `Frame::with_updates`.
+#[derive(Copy, Clone)]
+pub enum FrameUpdate {
+    Subtitle(TString<'static>),
+    /// Icon of the header button.
+    Icon(Icon),
+    /// Instruction of the footer, empty string hides it.
+    Footer(TString<'static>),
+}
+
 impl<T> Frame<T>
 where
     T: Component,
@@ -52,6 +70,7 @@ where
         Self {
             title: Label::new(title, alignment, theme::label_title_main()).vertically_centered(),
             bounds: Rect::zero(),
+            header_area: Rect::zero(),
             subtitle: None,
             border: theme::borders(),
             button: None,
@@ -93,12 +112,22 @@ where
 
     #[inline(never)]
     pub fn with_subtitle(mut self, subtitle: TString<'static>) -> Self {
-        let style = theme::TEXT_SUB_GREY;
+        let style = theme::label_subtitle();
         self.title = self.title.top_aligned();
         self.subtitle = Some(Label::new(subtitle, self.title.alignment(), style));
         self
     }
 
+    /// Debug target name of the header button, after the message it sends.
+    #[cfg(feature = "ui_debug")]
+    fn button_target(&self) -> &'static str {
+        match self.button_msg {
+            CancelInfoConfirmMsg::Cancelled => "cancel",
+            CancelInfoConfirmMsg::Info => "info",
+            CancelInfoConfirmMsg::Confirmed => "confirm",
+        }
+    }
+
     #[inline(never)]
     fn with_button(mut self, icon: Icon, msg: CancelInfoConfirmMsg, enabled: bool) -> Self {
         let touch_area = Insets::uniform(BUTTON_EXPAND_BORDER);
@@ -146,6 +175,13 @@ where
         self
     }
 
+    /// Icon shown next to the footer instruction, e.g. the button the
+    /// instruction refers to.
+    pub fn with_footer_icon(mut self, icon: Icon) -> Self {
+        self.footer = self.footer.map(|f| f.with_instruction_icon(icon));
+        self
+    }
+
     pub fn with_danger(self) -> Self {
         self.button_styled(theme::button_danger())
             .title_styled(theme::label_title_danger())
@@ -155,11 +191,111 @@ where
         &self.content
     }
 
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.content
+    }
+
     pub fn update_title(&mut self, ctx: &mut EventCtx, new_title: TString<'static>) {
         self.title.set_text(new_title);
         ctx.request_paint();
     }
 
+    /// Replace the subtitle, adding one if there is none. Only the header is
+    /// repainted unless the new subtitle needs a different number of lines.
+    pub fn update_subtitle(&mut self, ctx: &mut EventCtx, new_subtitle: TString<'static>) {
+        let old_height = if self.bounds.is_empty() {
+            // Not placed yet.
+            None
+        } else {
+            Some(self.header_height())
+        };
+        match &mut self.subtitle {
+            Some(subtitle) => subtitle.set_text(new_subtitle),
+            None => {
+                self.title = self.title.clone().top_aligned();
+                self.subtitle = Some(Label::new(
+                    new_subtitle,
+                    self.title.alignment(),
+                    theme::label_subtitle(),
+                ));
+            }
+        }
+        let Some(old_height) = old_height else {
+            return;
+        };
+        if self.header_height() != old_height {
+            // The subtitle wrapped or unwrapped, make room for it.
+            self.place(self.bounds);
+            ctx.request_paint();
+        } else {
+            self.place_header(self.header_area);
+            ctx.request_paint_region(self.header_area);
+        }
+    }
+
+    /// Replace the icon of the header button. Does nothing for frames without
+    /// one.
+    pub fn update_icon(&mut self, ctx: &mut EventCtx, new_icon: Icon) {
+        if let Some(button) = &mut self.button {
+            button.set_content(ctx, ButtonContent::Icon(new_icon));
+        }
+    }
+
+    fn apply_update(&mut self, ctx: &mut EventCtx, update: FrameUpdate) {
+        match update {
+            FrameUpdate::Subtitle(subtitle) => self.update_subtitle(ctx, subtitle),
+            FrameUpdate::Icon(icon) => self.update_icon(ctx, icon),
+            FrameUpdate::Footer(instruction) => self.update_footer(ctx, instruction),
+        }
+    }
+
+    /// Replace the footer instruction. The space taken by the footer stays the
+    /// same, so the content does not move even if the instruction is cleared.
+    pub fn update_footer(&mut self, ctx: &mut EventCtx, new_instruction: TString<'static>) {
+        if let Some(footer) = &mut self.footer {
+            footer.update_instruction(ctx, new_instruction);
+        }
+    }
+
+    pub fn update_footer_icon(&mut self, ctx: &mut EventCtx, new_icon: Option<Icon>) {
+        if let Some(footer) = &mut self.footer {
+            footer.update_instruction_icon(ctx, new_icon);
+        }
+    }
+
+    /// Let the content update the header and footer through its messages.
+    /// Messages for which `func` returns an update are consumed by the frame.
+    pub fn with_updates<F>(self, func: F) -> FrameUpdates<T, F>
+    where
+        F: Fn(&T::Msg) -> Option<FrameUpdate>,
+    {
+        FrameUpdates { frame: self, func }
+    }
+
+    /// Height of the header, grows when the subtitle does not fit one line.
+    fn header_height(&self) -> i16 {
+        let Some(subtitle) = &self.subtitle else {
+            return TITLE_HEIGHT;
+        };
+        let mut width = self.bounds.width() - 2 * theme::SPACING;
+        if self.button.is_some() {
+            width -= TITLE_HEIGHT;
+        }
+        let single_line = subtitle.font().text_max_height();
+        let extra = subtitle.text_height(width) - single_line;
+        TITLE_HEIGHT + extra.max(0)
+    }
+
+    fn place_header(&mut self, header_area: Rect) {
+        if self.subtitle.is_some() {
+            let title_area = self.title.place(header_area);
+            let remaining = header_area.inset(Insets::top(title_area.height()));
+            let _subtitle_area = self.subtitle.place(remaining);
+        } else {
+            self.title.place(header_area);
+        }
+    }
+
     pub fn update_content<F, R>(&mut self, ctx: &mut EventCtx, update_fn: F) -> R
     where
         F: Fn(&mut EventCtx, &mut T) -> R,
@@ -203,24 +339,23 @@ where
     fn place(&mut self, bounds: Rect) -> Rect {
         self.bounds = bounds;
 
-        let (mut header_area, mut content_area) = bounds.split_top(TITLE_HEIGHT);
+        let (mut header_area, mut content_area) = bounds.split_top(self.header_height());
         content_area = content_area.inset(Insets::top(theme::SPACING));
         header_area = header_area.inset(Insets::sides(theme::SPACING));
 
         if let Some(b) = &mut self.button {
             let (rest, button_area) = header_area.split_right(TITLE_HEIGHT);
             header_area = rest;
-            b.place(button_area);
+            b.place(button_area.split_top(TITLE_HEIGHT).0);
         }
-
-        if self.subtitle.is_some() {
-            let title_area = self.title.place(header_area);
-            let remaining = header_area.inset(Insets::top(title_area.height()));
-            let _subtitle_area = self.subtitle.place(remaining);
-        } else {
-            self.title.place(header_area);
+        #[cfg(feature = "ui_debug")]
+        if let Some(b) = &self.button {
+            debug_target::register(&["frame", self.button_target()], b.area());
         }
 
+        self.header_area = header_area;
+        self.place_header(header_area);
+
         if let Some(footer) = &mut self.footer {
             // FIXME: spacer at the bottom might be applied also for usage without footer
             // but not for VerticalMenu
@@ -331,6 +466,78 @@ impl<T> crate::ui::flow::Swipable for Frame<T> {
     }
 }
 
+pub struct FrameUpdates<T, F> {
+    frame: Frame<T>,
+    func: F,
+}
+
+impl<T, F> FrameUpdates<T, F> {
+    pub fn inner(&self) -> &Frame<T> {
+        &self.frame
+    }
+
+    pub fn inner_mut(&mut self) -> &mut Frame<T> {
+        &mut self.frame
+    }
+}
+
+impl<T, F> Component for FrameUpdates<T, F>
+where
+    T: Component,
+    F: Fn(&T::Msg) -> Option<FrameUpdate>,
+{
+    type Msg = FrameMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.frame.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let msg = self.frame.event(ctx, event);
+        if let Some(FrameMsg::Content(content_msg)) = &msg {
+            if let Some(update) = (self.func)(content_msg) {
+                self.frame.apply_update(ctx, update);
+                return None;
+            }
+        }
+        msg
+    }
+
+    fn paint(&mut self) {
+        self.frame.paint();
+    }
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        self.frame.render(target);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.frame.bounds(sink);
+    }
+}
+
+#[cfg(feature = "micropython")]
+impl<T, F> crate::ui::flow::Swipable for FrameUpdates<T, F> {
+    fn get_swipe_config(&self) -> SwipeConfig {
+        self.frame.swipe
+    }
+
+    fn get_internal_page_count(&self) -> usize {
+        self.frame.internal_page_cnt
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, F> crate::trace::Trace for FrameUpdates<T, F>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        crate::trace::Trace::trace(&self.frame, t);
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl<T> crate::trace::Trace for Frame<T>
 where
@@ -351,3 +558,79 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{constant::screen, event::USBEvent};
+
+    /// Content reporting USB connection as its message.
+    struct Content {
+        area: Rect,
+    }
+
+    impl Component for Content {
+        type Msg = bool;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            self.area = bounds;
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            match event {
+                Event::USB(USBEvent::Connected(connected)) => Some(connected),
+                _ => None,
+            }
+        }
+
+        fn paint(&mut self) {}
+
+        fn render<'s>(&'s self, _target: &mut impl Renderer<'s>) {}
+    }
+
+    fn subtitle<T, F>(frame: &FrameUpdates<T, F>) -> TString<'static> {
+        *unwrap!(frame.inner().subtitle.as_ref()).text()
+    }
+
+    #[test]
+    fn content_message_updates_subtitle() {
+        let content = Content { area: Rect::zero() };
+        let mut frame = Frame::left_aligned("Title".into(), content)
+            .with_subtitle("Word 1 of 20".into())
+            .with_updates(|connected| {
+                connected.then_some(FrameUpdate::Subtitle("Word 2 of 20".into()))
+            });
+        frame.place(screen());
+        let content_area = frame.inner().inner().area;
+
+        // The message is consumed and only the header is repainted.
+        let mut ctx = EventCtx::new();
+        let msg = frame.event(&mut ctx, Event::USB(USBEvent::Connected(true)));
+        assert!(msg.is_none());
+        assert!(subtitle(&frame).map(|s| s == "Word 2 of 20"));
+        assert!(ctx.paint_region() == Some(frame.inner().header_area));
+        assert!(frame.inner().inner().area == content_area);
+
+        // Messages without an update go through.
+        let msg = frame.event(&mut ctx, Event::USB(USBEvent::Connected(false)));
+        assert!(matches!(msg, Some(FrameMsg::Content(false))));
+    }
+
+    #[test]
+    fn wrapping_subtitle_grows_header() {
+        let content = Content { area: Rect::zero() };
+        let mut frame = Frame::left_aligned("Title".into(), content).with_subtitle("Short".into());
+        frame.place(screen());
+        let content_area = frame.content.area;
+
+        let mut ctx = EventCtx::new();
+        frame.update_subtitle(
+            &mut ctx,
+            "A subtitle long enough to take more than a single line of the header".into(),
+        );
+        assert!(ctx.paint_region() == Some(screen()));
+        assert!(frame.content.area.y0 > content_area.y0);
+        assert!(frame.content.area.y1 == content_area.y1);
+    }
+}
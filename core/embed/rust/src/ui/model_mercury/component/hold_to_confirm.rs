@@ -171,6 +171,7 @@ pub struct HoldToConfirm {
     circle_color: Color,
     circle_pad_color: Color,
     circle_inner_color: Color,
+    icon_color: Color,
     anim: HoldToConfirmAnim,
     finalizing: bool,
 }
@@ -198,6 +199,7 @@ impl HoldToConfirm {
             circle_color: theme::GREEN,
             circle_pad_color: theme::GREY_EXTRA_DARK,
             circle_inner_color: theme::GREEN_LIGHT,
+            icon_color: theme::confirm_pad_icon_color(),
             button,
             anim: HoldToConfirmAnim::default(),
             finalizing: false,
@@ -224,6 +226,8 @@ impl Component for HoldToConfirm {
         match btn_msg {
             Some(ButtonMsg::Pressed) => {
                 if !self.anim.is_locked() {
+                    #[cfg(feature = "haptic")]
+                    haptic::play(HapticEffect::HoldStart);
                     self.anim.start();
                     ctx.request_anim_frame();
                     ctx.request_paint();
@@ -241,7 +245,7 @@ impl Component for HoldToConfirm {
             Some(ButtonMsg::Clicked) => {
                 if animation_disabled() {
                     #[cfg(feature = "haptic")]
-                    haptic::play(HapticEffect::HoldToConfirm);
+                    haptic::play(HapticEffect::HoldConfirm);
                     return Some(());
                 }
                 if !self.anim.is_locked() {
@@ -266,7 +270,7 @@ impl Component for HoldToConfirm {
                 if self.anim.is_locked() && !self.finalizing {
                     self.finalizing = true;
                     #[cfg(feature = "haptic")]
-                    haptic::play(HapticEffect::HoldToConfirm);
+                    haptic::play(HapticEffect::HoldConfirm);
                 }
             } else if self.anim.is_locked() {
                 return Some(());
@@ -347,7 +351,7 @@ impl Component for HoldToConfirm {
             .render(target);
 
         shape::ToifImage::new(center, theme::ICON_SIGN.toif)
-            .with_fg(theme::GREY)
+            .with_fg(self.icon_color)
             .with_alpha(circle_alpha)
             .with_align(Alignment2D::CENTER)
             .render(target);
@@ -5,7 +5,7 @@ use crate::{
     translations::TR,
     trezorhal::usb::usb_configured,
     ui::{
-        component::{Component, Event, EventCtx, TimerToken},
+        component::{Component, Event, EventCtx, GestureDetector, GestureMsg, TimerToken},
         display::{image::ImageInfo, toif::Icon, Color, Font},
         event::{TouchEvent, USBEvent},
         geometry::{Alignment, Alignment2D, Offset, Point, Rect},
@@ -38,6 +38,12 @@ const COINJOIN_Y: i16 = 30;
 const LOADER_OFFSET: Offset = Offset::y(-10);
 const LOADER_DELAY: Duration = Duration::from_millis(500);
 const LOADER_DURATION: Duration = Duration::from_millis(2000);
+const LABEL_HEIGHT: i16 = 38;
+const NOTIFICATION_HEIGHT: i16 = 30;
+const NOTIFICATION_ICON_SPACING: i16 = 6;
+/// Opacity of the strip under the notification, so that it stays readable over
+/// images of any brightness.
+const NOTIFICATION_DIM: u8 = 200;
 
 pub const HOMESCREEN_IMAGE_WIDTH: i16 = WIDTH;
 pub const HOMESCREEN_IMAGE_HEIGHT: i16 = HEIGHT;
@@ -83,11 +89,96 @@ fn render_default_hs<'a>(target: &mut impl Renderer<'a>) {
         .render(target);
 }
 
+/// Severity of a notification, selecting its color.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NotificationSeverity {
+    Info,
+    Warning,
+    Alert,
+}
+
+impl NotificationSeverity {
+    fn color(self) -> Color {
+        match self {
+            Self::Info => theme::GREEN_LIME,
+            Self::Warning => theme::ORANGE_LIGHT,
+            Self::Alert => theme::RED,
+        }
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn name(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Alert => "alert",
+        }
+    }
+}
+
+/// Status line along the top edge of the homescreen and the lockscreen.
 #[derive(Clone, Copy)]
 pub struct HomescreenNotification {
     pub text: TString<'static>,
     pub icon: Icon,
-    pub color: Color,
+    pub severity: NotificationSeverity,
+}
+
+impl HomescreenNotification {
+    /// Notification of the given level, as passed from the layout.
+    fn from_level(text: TString<'static>, level: u8) -> Self {
+        let (severity, icon) = match level {
+            3 => (NotificationSeverity::Info, theme::ICON_COINJOIN),
+            2 => (NotificationSeverity::Warning, theme::ICON_MAGIC),
+            1 => (NotificationSeverity::Warning, theme::ICON_WARN),
+            _ => (NotificationSeverity::Alert, theme::ICON_WARN),
+        };
+        Self {
+            text,
+            icon,
+            severity,
+        }
+    }
+
+    fn render<'s>(&self, target: &mut impl Renderer<'s>) {
+        let strip = AREA.split_top(NOTIFICATION_HEIGHT).0;
+        shape::Bar::new(strip)
+            .with_bg(Color::black())
+            .with_alpha(NOTIFICATION_DIM)
+            .render(target);
+
+        let color = self.severity.color();
+        let font = theme::TEXT_BOLD.text_font;
+        self.text.map(|t| {
+            let icon_width = self.icon.toif.width() + NOTIFICATION_ICON_SPACING;
+            let width = icon_width + font.text_width(t);
+            let left = strip.center().x - width / 2;
+            shape::ToifImage::new(Point::new(left, strip.center().y), self.icon.toif)
+                .with_align(Alignment2D::CENTER_LEFT)
+                .with_fg(color)
+                .render(target);
+            let baseline = font.vert_center(strip.y0, strip.y1, "A");
+            shape::Text::new(Point::new(left + icon_width, baseline), t)
+                .with_font(font)
+                .with_fg(color)
+                .render(target);
+        });
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.string("notification", self.text);
+        t.string("severity", self.severity.name().into());
+    }
+}
+
+/// Area of the label in the top left corner, below the notification if any.
+fn label_area(label_width: i16, notification: bool) -> Rect {
+    let top = if notification { NOTIFICATION_HEIGHT } else { 0 };
+    Rect::from_top_left_and_size(
+        Point::new(0, top),
+        Offset::new(label_width + 12, LABEL_HEIGHT),
+    )
 }
 
 pub struct Homescreen {
@@ -99,6 +190,7 @@ pub struct Homescreen {
     hold_to_lock: bool,
     loader: Loader,
     delay: Option<TimerToken>,
+    gestures: GestureDetector,
 }
 
 pub enum HomescreenMsg {
@@ -123,38 +215,28 @@ impl Homescreen {
             hold_to_lock,
             loader: Loader::with_lock_icon().with_durations(LOADER_DURATION, LOADER_DURATION / 3),
             delay: None,
-        }
-    }
-
-    fn level_to_style(level: u8) -> (Color, Icon) {
-        match level {
-            3 => (theme::ORANGE_DARK, theme::ICON_COINJOIN),
-            2 => (theme::ORANGE_DARK, theme::ICON_MAGIC),
-            1 => (theme::ORANGE_DARK, theme::ICON_WARN),
-            _ => (theme::ORANGE_DARK, theme::ICON_WARN),
+            gestures: GestureDetector::new(),
         }
     }
 
     fn get_notification(&self) -> Option<HomescreenNotification> {
         if !usb_configured() {
-            let (color, icon) = Self::level_to_style(0);
-            Some(HomescreenNotification {
-                text: TR::homescreen__title_no_usb_connection.into(),
-                icon,
-                color,
-            })
-        } else if let Some((notification, level)) = self.notification {
-            let (color, icon) = Self::level_to_style(level);
-            Some(HomescreenNotification {
-                text: notification,
-                icon,
-                color,
-            })
+            Some(HomescreenNotification::from_level(
+                TR::homescreen__title_no_usb_connection.into(),
+                0,
+            ))
         } else {
-            None
+            self.notification
+                .map(|(text, level)| HomescreenNotification::from_level(text, level))
         }
     }
 
+    /// Move the label below the notification, or back to the top.
+    fn place_label(&mut self) {
+        let notification = self.get_notification().is_some();
+        self.label.place(label_area(self.label_width, notification));
+    }
+
     fn render_loader<'s>(&'s self, target: &mut impl Renderer<'s>) {
         TR::progress__locking_device.map_translated(|t| {
             shape::Text::new(TOP_CENTER + Offset::y(HOLD_Y), t)
@@ -165,10 +247,14 @@ impl Homescreen {
         self.loader.render(target)
     }
 
-    fn event_usb(&mut self, ctx: &mut EventCtx, event: Event) {
-        if let Event::USB(USBEvent::Connected(_)) = event {
-            ctx.request_paint();
+    fn event_notification(&mut self, ctx: &mut EventCtx, event: Event) {
+        match event {
+            Event::USB(USBEvent::Connected(_)) => {}
+            Event::Notification(notification) => self.notification = notification,
+            _ => return,
         }
+        self.place_label();
+        ctx.request_paint();
     }
 
     fn event_hold(&mut self, ctx: &mut EventCtx, event: Event) -> bool {
@@ -217,15 +303,17 @@ impl Component for Homescreen {
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.loader.place(AREA.translate(LOADER_OFFSET));
-        self.label
-            .place(bounds.split_top(38).0.split_left(self.label_width + 12).0);
+        self.place_label();
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        Self::event_usb(self, ctx, event);
+        Self::event_notification(self, ctx, event);
         if self.hold_to_lock {
-            Self::event_hold(self, ctx, event).then_some(HomescreenMsg::Dismissed)
+            // Double tap locks the device as well, without waiting for the loader.
+            let double_tap = matches!(self.gestures.event(event), Some(GestureMsg::DoubleTap(_)));
+            let held = Self::event_hold(self, ctx, event);
+            (double_tap || held).then_some(HomescreenMsg::Dismissed)
         } else {
             None
         }
@@ -249,12 +337,13 @@ impl Component for Homescreen {
                 render_default_hs(target);
             }
 
-            let label_width = self
-                .label
-                .text()
-                .map(|t| theme::TEXT_DEMIBOLD.text_font.text_width(t));
+            let notification = self.get_notification();
 
-            let r = Rect::new(Point::new(-30, -30), Point::new(label_width + 12, 38));
+            // Background of the label, with its top corners hidden off the screen
+            // or under the notification.
+            let area = label_area(self.label_width, notification.is_some());
+            let top = if notification.is_some() { area.y0 } else { -30 };
+            let r = Rect::new(Point::new(-30, top), area.bottom_right());
             shape::Bar::new(r)
                 .with_bg(Color::black())
                 .with_alpha(160)
@@ -263,43 +352,8 @@ impl Component for Homescreen {
 
             self.label.render(target);
 
-            if let Some(notif) = self.get_notification() {
-                const NOTIFICATION_HEIGHT: i16 = 34;
-                const NOTIFICATION_TOP: i16 = 202;
-                const NOTIFICATION_BORDER: i16 = 16;
-
-                notif.text.map(|t| {
-                    let style = theme::TEXT_BOLD;
-
-                    let text_width = style.text_font.text_width(t);
-
-                    let banner = Rect::new(
-                        Point::new(
-                            AREA.center().x - NOTIFICATION_BORDER - text_width / 2,
-                            NOTIFICATION_TOP,
-                        ),
-                        Point::new(
-                            AREA.center().x + NOTIFICATION_BORDER + text_width / 2,
-                            NOTIFICATION_TOP + NOTIFICATION_HEIGHT,
-                        ),
-                    );
-
-                    let text_pos = Point::new(
-                        style.text_font.horz_center(banner.x0, banner.x1, t),
-                        style.text_font.vert_center(banner.y0, banner.y1, "A"),
-                    );
-
-                    shape::Bar::new(banner)
-                        .with_radius(16)
-                        .with_bg(theme::ORANGE_DARK)
-                        .with_alpha(160)
-                        .render(target);
-
-                    shape::Text::new(text_pos, t)
-                        .with_font(style.text_font)
-                        .with_fg(theme::ORANGE_LIGHT)
-                        .render(target);
-                });
+            if let Some(notification) = notification {
+                notification.render(target);
             }
         }
     }
@@ -316,6 +370,10 @@ impl crate::trace::Trace for Homescreen {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Homescreen");
         t.child("label", &self.label);
+        if let Some(notification) = self.get_notification() {
+            notification.trace(t);
+        }
+        t.child("gestures", &self.gestures);
     }
 }
 
@@ -350,20 +408,25 @@ pub struct Lockscreen {
     image: Option<BinaryData<'static>>,
     bootscreen: bool,
     coinjoin_authorized: bool,
-    bg_image: ImageBuffer<Rgb565Canvas<'static>>,
+    notification: Option<HomescreenNotification>,
+    bg_image: Option<ImageBuffer<Rgb565Canvas<'static>>>,
 }
 
 impl Lockscreen {
-    pub fn new(label: TString<'static>, bootscreen: bool, coinjoin_authorized: bool) -> Self {
+    pub fn new(
+        label: TString<'static>,
+        bootscreen: bool,
+        coinjoin_authorized: bool,
+        notification: Option<(TString<'static>, u8)>,
+    ) -> Self {
         let image = get_homescreen_image();
-        let mut buf = unwrap!(ImageBuffer::new(AREA.size()), "no image buf");
-
-        render_on_canvas(buf.canvas(), None, |target| {
-            if let Some(image) = image {
-                shape::JpegImage::new_image(Point::zero(), image).render(target);
-            } else {
-                render_default_hs(target);
-            }
+        // The image buffer can still be held by another layout, the background
+        // is then rendered directly on every frame.
+        let bg_image = ImageBuffer::new(AREA.size()).map(|mut buf| {
+            render_on_canvas(buf.canvas(), None, |target| {
+                render_lockscreen_bg(image, target);
+            });
+            buf
         });
 
         let label_width = label.map(|t| theme::TEXT_DEMIBOLD.text_font.text_width(t));
@@ -377,17 +440,23 @@ impl Lockscreen {
             image,
             bootscreen,
             coinjoin_authorized,
-            bg_image: buf,
+            notification: notification
+                .map(|(text, level)| HomescreenNotification::from_level(text, level)),
+            bg_image,
         }
     }
+
+    fn place_label(&mut self) {
+        let notification = self.notification.is_some();
+        self.label.place(label_area(self.label_width, notification));
+    }
 }
 
 impl Component for Lockscreen {
     type Msg = HomescreenMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        self.label
-            .place(bounds.split_top(38).0.split_left(self.label_width + 12).0);
+        self.place_label();
         bounds
     }
 
@@ -396,6 +465,13 @@ impl Component for Lockscreen {
             ctx.request_anim_frame();
         }
 
+        if let Event::Notification(notification) = event {
+            self.notification =
+                notification.map(|(text, level)| HomescreenNotification::from_level(text, level));
+            self.place_label();
+            ctx.request_paint();
+        }
+
         if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
             if !animation_disabled() {
                 if !self.anim.timer.is_running() {
@@ -424,7 +500,11 @@ impl Component for Lockscreen {
 
         let center = AREA.center();
 
-        shape::RawImage::new(AREA, self.bg_image.view()).render(target);
+        if let Some(bg_image) = &self.bg_image {
+            shape::RawImage::new(AREA, bg_image.view()).render(target);
+        } else {
+            render_lockscreen_bg(self.image, target);
+        }
 
         cshape::UnlockOverlay::new(center + Offset::y(OVERLAY_OFFSET), self.anim.eval())
             .render(target);
@@ -461,7 +541,7 @@ impl Component for Lockscreen {
 
         self.label.render(target);
 
-        let mut offset = 6 + self.label_height;
+        let mut offset = self.label.area().y0 + 6 + self.label_height;
 
         if let Some(t) = locked {
             t.map_translated(|t| {
@@ -492,6 +572,10 @@ impl Component for Lockscreen {
                 .render(target);
         });
 
+        if let Some(notification) = self.notification {
+            notification.render(target);
+        }
+
         // TODO coinjoin authorized text
     }
 }
@@ -507,6 +591,14 @@ pub fn check_homescreen_format(image: BinaryData) -> bool {
     }
 }
 
+fn render_lockscreen_bg<'s>(image: Option<BinaryData<'static>>, target: &mut impl Renderer<'s>) {
+    if let Some(image) = image {
+        shape::JpegImage::new_image(Point::zero(), image).render(target);
+    } else {
+        render_default_hs(target);
+    }
+}
+
 fn get_homescreen_image() -> Option<BinaryData<'static>> {
     if let Ok(image) = get_user_custom_image() {
         if check_homescreen_format(image) {
@@ -520,5 +612,8 @@ fn get_homescreen_image() -> Option<BinaryData<'static>> {
 impl crate::trace::Trace for Lockscreen {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Lockscreen");
+        if let Some(notification) = self.notification {
+            notification.trace(t);
+        }
     }
 }
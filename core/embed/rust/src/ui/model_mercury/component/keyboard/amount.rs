@@ -0,0 +1,374 @@
+use heapless::String;
+
+use crate::{
+    strutil::{ShortString, TString},
+    ui::{
+        component::{
+            base::ComponentExt, text::common::KeyboardTimings, Child, Component, Event, EventCtx,
+            Maybe, Pad,
+        },
+        display::Font,
+        geometry::{Alignment, Insets, Offset, Rect},
+        model_mercury::component::{
+            button::{Button, ButtonContent, ButtonMsg},
+            theme,
+        },
+        shape::{self, Renderer},
+    },
+};
+
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::debug_target;
+
+use super::pin::{keypad_grid, textbox_pad, DigitButtons};
+
+pub enum AmountKeyboardMsg {
+    Confirmed,
+    Cancelled,
+}
+
+/// Longest amount that can be typed, including the decimal point.
+const MAX_LENGTH: usize = 24;
+
+const DECIMAL_POINT: char = '.';
+const GROUP_SEPARATOR: char = ',';
+const GROUP_SIZE: usize = 3;
+
+const HEADER_HEIGHT: i16 = 36;
+const HEADER_PADDING_SIDE: i16 = 8;
+/// Space between the amount and its unit.
+const UNIT_SPACING: i16 = 6;
+
+/// Keypad for typing a decimal amount in a fixed unit. The amount is kept as
+/// typed and confirmed as a string, so that the host can parse it exactly.
+pub struct AmountKeyboard<'a> {
+    unit: TString<'a>,
+    max_integer_digits: usize,
+    max_decimals: usize,
+    amount: String<MAX_LENGTH>,
+    textbox_area: Rect,
+    textbox_pad: Pad,
+    erase_btn: Child<Maybe<Button>>,
+    cancel_btn: Child<Maybe<Button>>,
+    point_btn: Child<Button>,
+    confirm_btn: Child<Button>,
+    digit_btns: Child<DigitButtons>,
+}
+
+impl<'a> AmountKeyboard<'a> {
+    pub fn new(unit: TString<'a>, max_integer_digits: usize, max_decimals: usize) -> Self {
+        // Both parts and the decimal point must fit in.
+        let max_integer_digits = max_integer_digits.clamp(1, MAX_LENGTH - 1);
+        let max_decimals = max_decimals.min(MAX_LENGTH - 1 - max_integer_digits);
+
+        let erase_btn = Button::with_icon(theme::ICON_DELETE)
+            .styled(theme::button_keyboard_erase())
+            .with_long_press(KeyboardTimings::get().erase_hold);
+        let cancel_btn =
+            Button::with_icon(theme::ICON_CLOSE).styled(theme::button_keyboard_cancel());
+        Self {
+            unit,
+            max_integer_digits,
+            max_decimals,
+            amount: String::new(),
+            textbox_area: Rect::zero(),
+            textbox_pad: textbox_pad(),
+            erase_btn: Maybe::hidden(theme::BG, erase_btn).into_child(),
+            cancel_btn: Maybe::visible(theme::BG, cancel_btn).into_child(),
+            point_btn: Button::with_text(".".into())
+                .styled(theme::button_keyboard())
+                .with_text_align(Alignment::Center)
+                .initially_enabled(max_decimals > 0)
+                .into_child(),
+            confirm_btn: Button::with_icon(theme::ICON_CONFIRM)
+                .styled(theme::button_pin_confirm())
+                .initially_enabled(false)
+                .into_child(),
+            digit_btns: DigitButtons::ordered().into_child(),
+        }
+    }
+
+    /// Typed amount without a dangling decimal point, `None` if nothing was
+    /// typed.
+    pub fn value(&self) -> Option<&str> {
+        let value = self.amount.trim_end_matches(DECIMAL_POINT);
+        (!value.is_empty()).then_some(value)
+    }
+
+    fn decimal_part(&self) -> Option<&str> {
+        self.amount.split_once(DECIMAL_POINT).map(|(_, d)| d)
+    }
+
+    fn accepts_digit(&self) -> bool {
+        match self.decimal_part() {
+            Some(decimals) => decimals.len() < self.max_decimals,
+            // A lone zero gets replaced.
+            None => self.amount == "0" || self.amount.len() < self.max_integer_digits,
+        }
+    }
+
+    fn accepts_point(&self) -> bool {
+        self.max_decimals > 0 && self.decimal_part().is_none()
+    }
+
+    fn push_digit(&mut self, digit: &str) {
+        if !self.accepts_digit() {
+            // The digit buttons are disabled when full. Should not happen.
+            return;
+        }
+        // No leading zeros.
+        if self.amount == "0" {
+            self.amount.clear();
+        }
+        unwrap!(self.amount.push_str(digit));
+    }
+
+    fn push_point(&mut self) {
+        if !self.accepts_point() {
+            // The point button is disabled once used. Should not happen.
+            return;
+        }
+        // No lone decimal point, make it a zero.
+        if self.amount.is_empty() {
+            unwrap!(self.amount.push('0'));
+        }
+        unwrap!(self.amount.push(DECIMAL_POINT));
+    }
+
+    fn modified(&mut self, ctx: &mut EventCtx) {
+        let is_empty = self.amount.is_empty();
+        let accepts_digit = self.accepts_digit();
+        let accepts_point = self.accepts_point();
+
+        self.digit_btns.mutate(ctx, |ctx, digits| {
+            for btn in &mut digits.0 {
+                btn.mutate(ctx, |ctx, btn| btn.enable_if(ctx, accepts_digit));
+            }
+        });
+        self.point_btn
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, accepts_point));
+        self.erase_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, !is_empty));
+        self.cancel_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, is_empty));
+        self.confirm_btn
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_empty));
+        self.textbox_pad.clear();
+        ctx.request_paint();
+    }
+
+    fn render_textbox<'s>(&self, target: &mut impl Renderer<'s>) {
+        let font = Font::DEMIBOLD;
+        let area = self.textbox_area.inset(Insets::sides(HEADER_PADDING_SIDE));
+        let baseline = area.right_center() + Offset::y(font.visible_text_height("1") / 2);
+
+        let unit_width = self.unit.map(|unit| {
+            shape::Text::new(baseline, unit)
+                .with_align(Alignment::End)
+                .with_font(Font::NORMAL)
+                .with_fg(theme::GREY)
+                .render(target);
+            Font::NORMAL.text_width(unit)
+        });
+
+        // Placeholder zero until something is typed.
+        let (text, color) = if self.amount.is_empty() {
+            (grouped("0"), theme::GREY)
+        } else {
+            (grouped(&self.amount), theme::FG)
+        };
+        shape::Text::new(baseline - Offset::x(unit_width + UNIT_SPACING), &text)
+            .with_align(Alignment::End)
+            .with_font(font)
+            .with_fg(color)
+            .render(target);
+    }
+}
+
+/// `amount` with its integer part split into groups of thousands.
+fn grouped(amount: &str) -> ShortString {
+    let (integer, decimals) = match amount.split_once(DECIMAL_POINT) {
+        Some((integer, decimals)) => (integer, Some(decimals)),
+        None => (amount, None),
+    };
+    let mut text = ShortString::new();
+    for (i, ch) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % GROUP_SIZE == 0 {
+            unwrap!(text.push(GROUP_SEPARATOR));
+        }
+        unwrap!(text.push(ch));
+    }
+    if let Some(decimals) = decimals {
+        unwrap!(text.push(DECIMAL_POINT));
+        unwrap!(text.push_str(decimals));
+    }
+    text
+}
+
+impl Component for AmountKeyboard<'_> {
+    type Msg = AmountKeyboardMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (header, keypad) = bounds.split_top(HEADER_HEIGHT);
+        let keypad = keypad.inset(Insets::top(theme::BUTTON_SPACING));
+        let grid = keypad_grid(keypad);
+
+        // Erase and cancel share the right end of the textbox, the bottom left
+        // cell of the keypad is taken by the decimal point.
+        let (textbox, erase_cancel_area) = header.split_right(HEADER_HEIGHT);
+        self.textbox_area = textbox;
+        self.textbox_pad.place(header);
+
+        self.erase_btn.place(erase_cancel_area);
+        self.cancel_btn.place(erase_cancel_area);
+        self.point_btn.place(grid.row_col(3, 0));
+        self.confirm_btn.place(grid.row_col(3, 2));
+        self.digit_btns.place(keypad);
+
+        #[cfg(feature = "ui_debug")]
+        {
+            debug_target::register(&["erase"], erase_cancel_area);
+            debug_target::register(&["cancel"], erase_cancel_area);
+            debug_target::register(&["confirm"], grid.row_col(3, 2));
+            debug_target::register(&["amount", "point"], grid.row_col(3, 0));
+            self.digit_btns.inner().register_debug_targets("amount");
+        }
+
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Some(ButtonMsg::Clicked) = self.confirm_btn.event(ctx, event) {
+            return self.value().map(|_| AmountKeyboardMsg::Confirmed);
+        }
+        if let Some(ButtonMsg::Clicked) = self.cancel_btn.event(ctx, event) {
+            return Some(AmountKeyboardMsg::Cancelled);
+        }
+        match self.erase_btn.event(ctx, event) {
+            Some(ButtonMsg::Clicked) => {
+                // Erasing the decimal point enables it again.
+                self.amount.pop();
+                self.modified(ctx);
+                return None;
+            }
+            Some(ButtonMsg::LongPressed) => {
+                self.amount.clear();
+                self.modified(ctx);
+                return None;
+            }
+            _ => {}
+        }
+        if let Some(ButtonMsg::Clicked) = self.point_btn.event(ctx, event) {
+            self.push_point();
+            self.modified(ctx);
+            return None;
+        }
+        let clicked = self.digit_btns.mutate(ctx, |ctx, digits| {
+            digits
+                .0
+                .iter_mut()
+                .position(|btn| matches!(btn.event(ctx, event), Some(ButtonMsg::Clicked)))
+        });
+        if let Some(i) = clicked {
+            if let ButtonContent::Text(text) = self.digit_btns.inner().0[i].inner().content() {
+                let text = *text;
+                text.map(|text| self.push_digit(text));
+                self.modified(ctx);
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        todo!("remove when ui-t3t1 done");
+    }
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        self.digit_btns.render(target);
+        self.point_btn.render(target);
+        self.confirm_btn.render(target);
+        self.textbox_pad.render(target);
+        self.erase_btn.render(target);
+        self.cancel_btn.render(target);
+        self.render_textbox(target);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.textbox_area);
+        self.erase_btn.bounds(sink);
+        self.cancel_btn.bounds(sink);
+        self.point_btn.bounds(sink);
+        self.confirm_btn.bounds(sink);
+        self.digit_btns.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for AmountKeyboard<'_> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("AmountKeyboard");
+        t.string("amount", self.amount.as_str().into());
+        t.string("unit", self.unit);
+        t.int("max_integer_digits", self.max_integer_digits as i64);
+        t.int("max_decimals", self.max_decimals as i64);
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
+        t.child("point", &self.point_btn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_part_is_grouped() {
+        assert_eq!(grouped("0").as_str(), "0");
+        assert_eq!(grouped("999").as_str(), "999");
+        assert_eq!(grouped("1000").as_str(), "1,000");
+        assert_eq!(grouped("1234567").as_str(), "1,234,567");
+        assert_eq!(grouped("12345.6789").as_str(), "12,345.6789");
+        assert_eq!(grouped("0.").as_str(), "0.");
+    }
+
+    #[test]
+    fn amount_editing() {
+        let mut kbd = AmountKeyboard::new("sat/vB".into(), 3, 2);
+        assert_eq!(kbd.value(), None);
+
+        // Lone decimal point gets a leading zero.
+        kbd.push_point();
+        assert_eq!(kbd.amount.as_str(), "0.");
+        assert_eq!(kbd.value(), Some("0"));
+        assert!(!kbd.accepts_point());
+
+        kbd.push_digit("5");
+        kbd.push_digit("0");
+        assert!(!kbd.accepts_digit());
+        assert_eq!(kbd.value(), Some("0.50"));
+
+        // Deleting across the decimal point.
+        kbd.amount.pop();
+        kbd.amount.pop();
+        kbd.amount.pop();
+        assert_eq!(kbd.amount.as_str(), "0");
+        assert!(kbd.accepts_point());
+
+        // Leading zero is replaced.
+        kbd.push_digit("7");
+        assert_eq!(kbd.amount.as_str(), "7");
+        kbd.push_digit("0");
+        kbd.push_digit("0");
+        assert!(!kbd.accepts_digit());
+        assert!(kbd.accepts_point());
+        assert_eq!(kbd.value(), Some("700"));
+    }
+
+    #[test]
+    fn no_decimals() {
+        let kbd = AmountKeyboard::new("sat".into(), 8, 0);
+        assert!(!kbd.accepts_point());
+    }
+}
@@ -1,12 +1,18 @@
 use crate::{
-    trezorhal::bip39,
+    trezorhal::{
+        bip39::{word_index, ValidLastWords},
+        wordlist::{IncrementalFilter, Wordlist},
+    },
     ui::{
         component::{text::common::TextBox, Component, Event, EventCtx},
         geometry::{Alignment, Alignment2D, Offset, Point, Rect},
         model_mercury::{
             component::{
                 keyboard::{
-                    common::{render_pending_marker, render_pill_shape, MultiTapKeyboard},
+                    common::{
+                        render_pending_marker, render_pill_shape, CandidateCycle,
+                        CandidateCycleMsg, MultiTapKeyboard,
+                    },
                     mnemonic::{MnemonicInput, MnemonicInputMsg, MNEMONIC_KEY_COUNT},
                 },
                 Button, ButtonMsg,
@@ -19,8 +25,14 @@ use crate::{
     },
 };
 use heapless::String;
+use zeroize::Zeroizing;
+
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
 
 const MAX_LENGTH: usize = 8;
+/// All but the last word of the longest mnemonic.
+const MAX_PREVIOUS_WORDS: usize = 23;
 
 pub struct Bip39Input {
     button: Button,
@@ -30,6 +42,19 @@ pub struct Bip39Input {
     multi_tap: MultiTapKeyboard,
     options_num: Option<usize>,
     suggested_word: Option<&'static str>,
+    /// Cycling through all the words starting with the current input.
+    cycle: CandidateCycle,
+    /// Words starting with the current input, narrowed on every keypress.
+    filter: IncrementalFilter<MAX_LENGTH>,
+    /// Letters that can follow the current input, see
+    /// `Wordlist::letter_mask`.
+    letter_mask: u32,
+    /// Whether a uniquely identified word can be confirmed right away.
+    autofill: bool,
+    /// Whether the user cleared the auto-filled suffix.
+    autofill_dismissed: bool,
+    /// When typing the last word, only the words giving a valid checksum.
+    valid_last_words: Option<ValidLastWords>,
 }
 
 impl MnemonicInput for Bip39Input {
@@ -45,8 +70,7 @@ impl MnemonicInput for Bip39Input {
         // Currently pending key is always enabled.
         let key_is_pending = self.multi_tap.pending_key() == Some(key);
         // Keys that contain letters from the completion mask are enabled as well.
-        let key_matches_mask =
-            bip39::word_completion_mask(self.textbox.content()) & Self::key_mask(key) != 0;
+        let key_matches_mask = self.letter_mask & Self::key_mask(key) != 0;
         key_is_pending || key_matches_mask
     }
 
@@ -60,11 +84,17 @@ impl MnemonicInput for Bip39Input {
     }
 
     /// Backspace button was clicked, let's delete the last character of input
-    /// and clear the pending marker.
+    /// and clear the pending marker. If the word was auto-filled, only the
+    /// auto-filled suffix is cleared.
     fn on_backspace_click(&mut self, ctx: &mut EventCtx) {
         self.multi_tap.clear_pending_state(ctx);
-        self.textbox.delete_last(ctx);
-        self.complete_word_from_dictionary(ctx);
+        if self.autofilled_word().is_some() {
+            self.autofill_dismissed = true;
+            self.update_suggestion(ctx);
+        } else {
+            self.textbox.delete_last(ctx);
+            self.complete_word_from_dictionary(ctx);
+        }
     }
 
     /// Backspace button was long pressed, let's delete all characters of input
@@ -75,6 +105,22 @@ impl MnemonicInput for Bip39Input {
         self.complete_word_from_dictionary(ctx);
     }
 
+    /// Letter typed on the host keyboard is appended right away, without the
+    /// pending state, if it can lead to a valid word.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn on_host_char(&mut self, ctx: &mut EventCtx, _key: usize, ch: char) {
+        self.multi_tap.clear_pending_state(ctx);
+        if self.letter_mask & (1 << (ch as u8 - b'a')) != 0 {
+            self.textbox.append(ctx, ch);
+            self.complete_word_from_dictionary(ctx);
+        }
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
     fn is_empty(&self) -> bool {
         self.textbox.is_empty()
     }
@@ -82,21 +128,43 @@ impl MnemonicInput for Bip39Input {
     fn mnemonic(&self) -> Option<&'static str> {
         self.suggested_word
     }
+
+    fn for_word(&self, word: &str, previous_words: Option<&[&'static str]>) -> Self {
+        let mut input = Self::prefilled_word(word);
+        input.autofill = self.autofill;
+        match previous_words {
+            Some(previous_words) => input.with_checksum_filter(previous_words),
+            None => input,
+        }
+    }
 }
 
 impl Component for Bip39Input {
     type Msg = MnemonicInputMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
+        self.cycle.place(bounds);
         self.button.place(bounds);
         self.button_suggestion.place(bounds)
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        self.button_suggestion.event(ctx, event);
         if self.multi_tap.is_timeout_event(event) {
-            self.on_timeout(ctx)
-        } else if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
+            return self.on_timeout(ctx);
+        }
+        if let Some(msg) = self.cycle.event(ctx, event) {
+            if let CandidateCycleMsg::Changed = msg {
+                self.multi_tap.clear_pending_state(ctx);
+                self.update_suggestion(ctx);
+            }
+            return None;
+        }
+        self.button_suggestion.event(ctx, event);
+        #[cfg(feature = "host_keyboard")]
+        if let Event::HostKey(HostKey::Enter) = event {
+            return self.on_input_click(ctx);
+        }
+        if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
             self.on_input_click(ctx)
         } else {
             None
@@ -135,7 +203,8 @@ impl Component for Bip39Input {
                 style,
                 if choice_unambiguous { Some(area) } else { None },
             );
-            if choice_unambiguous {
+            // Chevrons of the candidate cycling take precedence over the checkmark.
+            if choice_unambiguous && !self.cycle.is_active() {
                 // Icon is painted in the right-center point, 10px from the right edge.
                 let icon_right_center = area.right_center() - Offset::x(10);
                 shape::ToifImage::new(icon_right_center, theme::ICON_SIMPLE_CHECKMARK24.toif)
@@ -165,6 +234,8 @@ impl Component for Bip39Input {
         if self.multi_tap.pending_key().is_some() {
             render_pending_marker(target, text_base, text, style.font, style.text_color);
         }
+
+        self.cycle.render(target, style.icon_color);
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -175,6 +246,8 @@ impl Component for Bip39Input {
 
 impl Bip39Input {
     pub fn new() -> Self {
+        let filter = IncrementalFilter::new(Wordlist::bip39());
+        let letter_mask = filter.current().letter_mask();
         Self {
             button: Button::empty(),
             textbox: TextBox::empty(),
@@ -182,9 +255,43 @@ impl Bip39Input {
             options_num: None,
             suggested_word: None,
             button_suggestion: Button::empty(),
+            cycle: CandidateCycle::new(),
+            filter,
+            letter_mask,
+            autofill: false,
+            autofill_dismissed: false,
+            valid_last_words: None,
         }
     }
 
+    /// Once the input uniquely identifies a word, let the input confirm it
+    /// right away instead of auto-completing it first.
+    pub fn with_autofill(mut self) -> Self {
+        self.autofill = true;
+        self
+    }
+
+    /// Only offer the last words of the mnemonic that give a valid checksum
+    /// together with `previous_words`. Ignored if these are not all the other
+    /// words of a mnemonic.
+    pub fn with_checksum_filter(mut self, previous_words: &[&str]) -> Self {
+        if previous_words.len() > MAX_PREVIOUS_WORDS {
+            return self;
+        }
+        // The indices give away the words, wiped once they go out of scope.
+        let mut indices = Zeroizing::new([0u16; MAX_PREVIOUS_WORDS]);
+        for (index, word) in indices.iter_mut().zip(previous_words) {
+            let Some(i) = word_index(word) else {
+                return self;
+            };
+            *index = i as u16;
+        }
+        self.valid_last_words = ValidLastWords::new(&indices[..previous_words.len()]);
+        self.refilter();
+        self.suggested_word = self.options_num.and_then(|_| self.candidate(0));
+        self
+    }
+
     pub fn prefilled_word(word: &str) -> Self {
         // Word may be empty string, fallback to normal input
         if word.is_empty() {
@@ -192,14 +299,15 @@ impl Bip39Input {
         }
 
         // Styling the input to reflect already filled word
-        Self {
+        let mut input = Self {
             button: Button::empty().styled(theme::button_recovery_confirm()),
             textbox: TextBox::new(unwrap!(String::try_from(word))),
-            multi_tap: MultiTapKeyboard::new(),
-            options_num: bip39::options_num(word),
-            suggested_word: bip39::complete_word(word),
             button_suggestion: Button::empty().styled(theme::button_suggestion_confirm()),
-        }
+            ..Self::new()
+        };
+        input.refilter();
+        input.suggested_word = input.candidate(0);
+        input
     }
 
     /// Compute a bitmask of all letters contained in given key text. Lowest bit
@@ -216,16 +324,27 @@ impl Bip39Input {
 
     fn is_choice_unambiguous(&self) -> bool {
         if let (Some(word), Some(_num)) = (self.suggested_word, self.options_num) {
-            return word.eq(self.textbox.content());
+            return word.eq(self.textbox.content()) || self.autofilled_word().is_some();
         }
         false
     }
 
+    /// The word uniquely identified by the input, if auto-filling is enabled
+    /// and the user did not clear the suffix.
+    fn autofilled_word(&self) -> Option<&'static str> {
+        if self.autofill && !self.autofill_dismissed && self.options_num == Some(1) {
+            self.suggested_word
+        } else {
+            None
+        }
+    }
+
     /// Input button was clicked.  If the content matches the suggested word,
-    /// let's confirm it, otherwise just auto-complete.
+    /// let's confirm it, otherwise just auto-complete. The suggested word is
+    /// the candidate currently selected by cycling, the first one by default.
     fn on_input_click(&mut self, ctx: &mut EventCtx) -> Option<MnemonicInputMsg> {
         if let (Some(word), Some(_num)) = (self.suggested_word, self.options_num) {
-            return if word.eq(self.textbox.content()) {
+            return if self.is_choice_unambiguous() {
                 // Confirm button.
                 self.textbox.clear(ctx);
                 Some(MnemonicInputMsg::Confirmed)
@@ -251,13 +370,46 @@ impl Bip39Input {
         Some(MnemonicInputMsg::TimedOut)
     }
 
+    /// Narrow (or widen) the filtered words to match the current input.
+    fn refilter(&mut self) {
+        let content = self.textbox.content();
+        self.autofill_dismissed = false;
+        self.filter.set_prefix(content);
+        let (letter_mask, num) = match &self.valid_last_words {
+            Some(valid) => (valid.letter_mask(content), valid.matches(content).count()),
+            None => (
+                self.filter.current().letter_mask(),
+                self.filter.current().len(),
+            ),
+        };
+        self.letter_mask = letter_mask;
+        self.options_num = (!content.is_empty()).then_some(num);
+        self.cycle.reset(self.options_num.unwrap_or(0));
+    }
+
+    /// The `n`-th word the current input can lead to.
+    fn candidate(&self, n: usize) -> Option<&'static str> {
+        match &self.valid_last_words {
+            Some(valid) => valid.matches(self.textbox.content()).nth(n),
+            None => self.filter.current().iter().nth(n),
+        }
+    }
+
     fn complete_word_from_dictionary(&mut self, ctx: &mut EventCtx) {
-        self.options_num = bip39::options_num(self.textbox.content());
-        self.suggested_word = bip39::complete_word(self.textbox.content());
+        self.refilter();
+        self.update_suggestion(ctx);
+    }
+
+    /// Suggest the candidate currently selected by cycling.
+    fn update_suggestion(&mut self, ctx: &mut EventCtx) {
+        self.suggested_word = self
+            .options_num
+            .filter(|&num| !(self.autofill_dismissed && num == 1))
+            .and_then(|_| self.candidate(self.cycle.index()));
 
         // Change the style of the button depending on the completed word.
-        if let (Some(word), Some(_num)) = (self.suggested_word, self.options_num) {
-            if word.eq(self.textbox.content()) {
+        if self.suggested_word.is_some() && self.options_num.is_some() {
+            if self.is_choice_unambiguous() {
                 // Confirm button.
                 self.button.enable(ctx);
                 self.button
@@ -289,3 +441,57 @@ impl crate::trace::Trace for Bip39Input {
         t.child("textbox", &self.textbox);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type `word` into `input` as on the host keyboard.
+    fn typed(mut input: Bip39Input, word: &str) -> Bip39Input {
+        let mut ctx = EventCtx::new();
+        for ch in word.chars() {
+            input.on_host_char(&mut ctx, 0, ch);
+        }
+        input
+    }
+
+    fn confirmed(msg: Option<MnemonicInputMsg>) -> bool {
+        matches!(msg, Some(MnemonicInputMsg::Confirmed))
+    }
+
+    #[test]
+    fn unique_prefix_autofilled() {
+        let mut ctx = EventCtx::new();
+        let mut input = typed(Bip39Input::new().with_autofill(), "acti");
+        assert_eq!(input.autofilled_word(), Some("action"));
+        assert!(confirmed(input.on_input_click(&mut ctx)));
+        assert_eq!(input.mnemonic(), Some("action"));
+    }
+
+    #[test]
+    fn unique_prefix_completed_without_autofill() {
+        let mut ctx = EventCtx::new();
+        let mut input = typed(Bip39Input::new(), "acti");
+        assert_eq!(input.autofilled_word(), None);
+        assert!(!confirmed(input.on_input_click(&mut ctx)));
+        assert_eq!(input.textbox.content(), "action");
+        assert!(confirmed(input.on_input_click(&mut ctx)));
+    }
+
+    #[test]
+    fn backspace_dismisses_autofill() {
+        let mut ctx = EventCtx::new();
+        let mut input = typed(Bip39Input::new().with_autofill(), "acti");
+        input.on_backspace_click(&mut ctx);
+        assert_eq!(input.autofilled_word(), None);
+        assert_eq!(input.textbox.content(), "acti");
+        input.on_backspace_click(&mut ctx);
+        assert_eq!(input.textbox.content(), "act");
+    }
+
+    #[test]
+    fn next_word_keeps_autofill() {
+        let input = Bip39Input::new().with_autofill().for_word("", None);
+        assert_eq!(typed(input, "acti").autofilled_word(), Some("action"));
+    }
+}
@@ -1,15 +1,22 @@
+#[cfg(feature = "haptic")]
+use crate::trezorhal::haptic::{play, HapticEffect};
 use crate::{
-    time::Duration,
+    time::{Duration, Stopwatch},
     ui::{
-        component::{text::common::TextEdit, Event, EventCtx, TimerToken},
+        component::{
+            text::common::{KeyboardTimings, TextEdit},
+            Component, Event, EventCtx, TimerToken,
+        },
         display::{self, Color, Font},
         geometry::{Alignment2D, Offset, Point, Rect},
+        model_mercury::theme,
         shape,
         shape::Renderer,
+        util::animation_disabled,
     },
 };
 
-use super::super::ButtonStyle;
+use super::super::{Button, ButtonMsg, ButtonStyle};
 
 /// Contains state commonly used in implementations multi-tap keyboards.
 pub struct MultiTapKeyboard {
@@ -33,7 +40,7 @@ impl MultiTapKeyboard {
     /// Create a new, empty, multi-tap state.
     pub fn new() -> Self {
         Self {
-            timeout: Duration::from_secs(1),
+            timeout: KeyboardTimings::get().multi_tap_timeout,
             pending: None,
         }
     }
@@ -190,3 +197,293 @@ pub fn render_pill_shape<'s>(
         .with_thickness(2)
         .render(target);
 }
+
+/// Touch zones on the left and right edge of the mnemonic input area, used to
+/// cycle through all the candidate words matching the current input.
+pub struct CandidateCycle {
+    prev: Button,
+    next: Button,
+    /// Index of the currently displayed candidate.
+    index: usize,
+    /// Number of candidates matching the current input.
+    count: usize,
+    /// Whether the user has already cycled since the last reset.
+    cycled: bool,
+}
+
+pub enum CandidateCycleMsg {
+    /// One of the cycling zones is being touched.
+    Touched,
+    /// The displayed candidate has changed.
+    Changed,
+}
+
+impl CandidateCycle {
+    /// Width of the touch zone on each side of the input area.
+    const ZONE_WIDTH: i16 = 32;
+
+    pub fn new() -> Self {
+        Self {
+            prev: Button::empty(),
+            next: Button::empty(),
+            index: 0,
+            count: 0,
+            cycled: false,
+        }
+    }
+
+    pub fn place(&mut self, bounds: Rect) {
+        let (prev_area, rest) = bounds.split_left(Self::ZONE_WIDTH);
+        let (_, next_area) = rest.split_right(Self::ZONE_WIDTH);
+        self.prev.place(prev_area);
+        self.next.place(next_area);
+    }
+
+    /// Start over with `count` candidates, displaying the first one.
+    pub fn reset(&mut self, count: usize) {
+        self.index = 0;
+        self.count = count;
+        self.cycled = false;
+    }
+
+    /// Index of the currently displayed candidate.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns `true` if there is more than one candidate to cycle through.
+    pub fn is_active(&self) -> bool {
+        self.count > 1
+    }
+
+    /// Returns `true` if the user has cycled through the candidates since the
+    /// input last changed.
+    pub fn is_cycling(&self) -> bool {
+        self.is_active() && self.cycled
+    }
+
+    /// Handle touches of the cycling zones. Returns `None` if the event was
+    /// not meant for us and should be handled by the input area.
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<CandidateCycleMsg> {
+        if !self.is_active() {
+            return None;
+        }
+        if let Some(msg) = self.prev.event(ctx, event) {
+            if let ButtonMsg::Clicked = msg {
+                self.index = self.index.checked_sub(1).unwrap_or(self.count - 1);
+                self.cycled = true;
+                ctx.request_paint();
+                return Some(CandidateCycleMsg::Changed);
+            }
+            return Some(CandidateCycleMsg::Touched);
+        }
+        if let Some(msg) = self.next.event(ctx, event) {
+            if let ButtonMsg::Clicked = msg {
+                self.index = (self.index + 1) % self.count;
+                self.cycled = true;
+                ctx.request_paint();
+                return Some(CandidateCycleMsg::Changed);
+            }
+            return Some(CandidateCycleMsg::Touched);
+        }
+        None
+    }
+
+    /// Paint the chevrons hinting that the candidates can be cycled.
+    pub fn render<'s>(&'s self, target: &mut impl Renderer<'s>, color: Color) {
+        if !self.is_active() {
+            return;
+        }
+        shape::ToifImage::new(
+            self.prev.area().left_center(),
+            theme::ICON_CHEVRON_LEFT.toif,
+        )
+        .with_align(Alignment2D::CENTER_LEFT)
+        .with_fg(color)
+        .render(target);
+        shape::ToifImage::new(
+            self.next.area().right_center(),
+            theme::ICON_CHEVRON_RIGHT.toif,
+        )
+        .with_align(Alignment2D::CENTER_RIGHT)
+        .with_fg(color)
+        .render(target);
+    }
+}
+
+/// Taps of disabled keys closer than this to the previous feedback are ignored.
+const DISABLED_KEY_INTERVAL: Duration = Duration::from_millis(500);
+const SHAKE_DURATION: Duration = Duration::from_millis(240);
+/// Duration of one swing of the shake, left, right and back.
+const SHAKE_PERIOD_MS: u32 = 80;
+const SHAKE_AMPLITUDE: i16 = 3;
+
+/// Feedback to a tap of a disabled key, an error haptic and a short horizontal
+/// shake of the input. Rate limited, so that mashing a key that cannot be used
+/// doesn't queue up a burst of effects.
+pub struct DisabledKeyFeedback {
+    /// Area of the input, repainted while shaking.
+    area: Rect,
+    /// Started by the last feedback.
+    since: Stopwatch,
+    shaking: bool,
+}
+
+impl DisabledKeyFeedback {
+    pub fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            since: Stopwatch::new_stopped(),
+            shaking: false,
+        }
+    }
+
+    pub fn place(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    /// Respond to a disabled key, unless the previous response was too recent.
+    pub fn trigger(&mut self, ctx: &mut EventCtx) {
+        if self.since.is_running_within(DISABLED_KEY_INTERVAL) {
+            return;
+        }
+        #[cfg(feature = "haptic")]
+        play(HapticEffect::Error);
+        self.since = Stopwatch::new_started();
+        if !animation_disabled() {
+            self.shaking = true;
+            ctx.request_anim_frame();
+            ctx.request_paint_region(self.area);
+        }
+    }
+
+    /// Keep the shake going, to be called with every event of the keyboard.
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if self.shaking && matches!(event, Event::Timer(EventCtx::ANIM_FRAME_TIMER)) {
+            // The last frame puts the input back in place.
+            self.shaking = self.since.is_running_within(SHAKE_DURATION);
+            if self.shaking {
+                ctx.request_anim_frame();
+            }
+            ctx.request_paint_region(self.area);
+        }
+    }
+
+    fn offset(&self) -> Offset {
+        if !self.shaking {
+            return Offset::zero();
+        }
+        // Triangle wave, starting to the right.
+        let quarter = (SHAKE_PERIOD_MS / 4) as i16;
+        let phase = (self.since.elapsed().to_millis() % SHAKE_PERIOD_MS) as i16;
+        let x = if phase < quarter {
+            phase
+        } else if phase < 3 * quarter {
+            2 * quarter - phase
+        } else {
+            phase - 4 * quarter
+        };
+        Offset::x(x * SHAKE_AMPLITUDE / quarter)
+    }
+
+    /// Render the input by `inner`, shifted while shaking.
+    pub fn render<'s, R: Renderer<'s>>(&self, target: &mut R, inner: &dyn Fn(&mut R)) {
+        let offset = self.offset();
+        if offset == Offset::zero() {
+            inner(target);
+        } else {
+            target.with_origin(offset, inner);
+        }
+    }
+}
+
+/// Second tap of the erase key following within this long clears the input.
+pub const ERASE_DOUBLE_TAP: Duration = Duration::from_millis(300);
+const CLEAR_FLASH_DURATION: Duration = Duration::from_millis(150);
+const CLEAR_FLASH_ALPHA: u32 = 96;
+
+/// Double tap of the erase key clearing the input. The input flashes briefly
+/// when the erase key is pressed for the second time in a row, hinting that
+/// releasing it clears everything. Taps of the button taking the place of
+/// erase once the input is empty, e.g. cancel, are ignored while they could be
+/// a part of the double tap.
+pub struct EraseDoubleTap {
+    /// Area of the input, covered by the flash.
+    area: Rect,
+    flash: Stopwatch,
+    /// Started by the last tap of erase.
+    last_erase: Stopwatch,
+}
+
+impl EraseDoubleTap {
+    pub fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            flash: Stopwatch::new_stopped(),
+            last_erase: Stopwatch::new_stopped(),
+        }
+    }
+
+    pub fn place(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    /// Record a tap of erase, either a single or a double one.
+    pub fn erased(&mut self) {
+        self.last_erase = Stopwatch::new_started();
+    }
+
+    /// Whether a tap of the button in place of erase should be ignored, as it
+    /// follows a tap of erase too closely.
+    pub fn swallows_tap(&mut self) -> bool {
+        let swallow = self.last_erase.is_running_within(ERASE_DOUBLE_TAP);
+        if swallow {
+            // Mashing goes on.
+            self.erased();
+        }
+        swallow
+    }
+
+    /// Start flashing, to be called when the erase button is pressed while it
+    /// awaits a double click.
+    pub fn trigger(&mut self, ctx: &mut EventCtx) {
+        if animation_disabled() {
+            return;
+        }
+        self.flash = Stopwatch::new_started();
+        ctx.request_anim_frame();
+        ctx.request_paint_region(self.area);
+    }
+
+    fn is_flashing(&self) -> bool {
+        self.flash.is_running_within(CLEAR_FLASH_DURATION)
+    }
+
+    /// Keep the flash fading out, to be called with every event of the
+    /// keyboard.
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if self.flash.is_running() && matches!(event, Event::Timer(EventCtx::ANIM_FRAME_TIMER)) {
+            if self.is_flashing() {
+                ctx.request_anim_frame();
+            } else {
+                // The last frame removes the flash.
+                self.flash = Stopwatch::new_stopped();
+            }
+            ctx.request_paint_region(self.area);
+        }
+    }
+
+    /// Render the flash over the already rendered input.
+    pub fn render<'s>(&self, target: &mut impl Renderer<'s>) {
+        if !self.is_flashing() {
+            return;
+        }
+        let elapsed = self.flash.elapsed().to_millis();
+        let remaining = CLEAR_FLASH_DURATION.to_millis().saturating_sub(elapsed);
+        let alpha = CLEAR_FLASH_ALPHA * remaining / CLEAR_FLASH_DURATION.to_millis();
+        shape::Bar::new(self.area)
+            .with_bg(theme::GREY_EXTRA_LIGHT)
+            .with_alpha(alpha as u8)
+            .render(target);
+    }
+}
@@ -0,0 +1,421 @@
+use heapless::String;
+
+use crate::{
+    strutil::ShortString,
+    ui::{
+        component::{
+            base::ComponentExt, text::common::KeyboardTimings, Child, Component, Event, EventCtx,
+            Maybe, Pad,
+        },
+        display::Font,
+        geometry::{Alignment, Insets, Offset, Rect},
+        model_mercury::component::{
+            button::{Button, ButtonContent, ButtonMsg},
+            theme,
+        },
+        shape::{self, Renderer},
+    },
+};
+
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::debug_target;
+
+use super::pin::{keypad_grid, textbox_pad, DigitButtons};
+
+pub enum DatetimeKeyboardMsg {
+    Confirmed,
+    Cancelled,
+}
+
+/// Entered value as shown to the user, letters are replaced by the digits
+/// typed so far.
+const MASK: &str = "YYYY-MM-DD hh:mm";
+/// Number of digits of a complete value.
+const DIGIT_COUNT: usize = 12;
+
+const YEAR_MIN: u32 = 1970;
+const YEAR_MAX: u32 = 2099;
+
+const HEADER_HEIGHT: i16 = 36;
+
+/// Part of the value of fixed number of digits, entered one after another.
+struct Field {
+    name: &'static str,
+    len: usize,
+    min: u32,
+    max: u32,
+}
+
+const FIELDS: [Field; 5] = [
+    Field {
+        name: "year",
+        len: 4,
+        min: YEAR_MIN,
+        max: YEAR_MAX,
+    },
+    Field {
+        name: "month",
+        len: 2,
+        min: 1,
+        max: 12,
+    },
+    // Upper bound depends on the month, see `field_range`.
+    Field {
+        name: "day",
+        len: 2,
+        min: 1,
+        max: 31,
+    },
+    Field {
+        name: "hour",
+        len: 2,
+        min: 0,
+        max: 23,
+    },
+    Field {
+        name: "minute",
+        len: 2,
+        min: 0,
+        max: 59,
+    },
+];
+
+const DAY: usize = 2;
+
+/// Keypad for typing a date and time, field by field. Digits that cannot lead
+/// to a valid value are disabled, so only an existing date can be confirmed.
+pub struct DatetimeKeyboard {
+    digits: String<DIGIT_COUNT>,
+    textbox_area: Rect,
+    textbox_pad: Pad,
+    erase_btn: Child<Maybe<Button>>,
+    cancel_btn: Child<Maybe<Button>>,
+    confirm_btn: Child<Button>,
+    digit_btns: Child<DigitButtons>,
+}
+
+impl DatetimeKeyboard {
+    pub fn new() -> Self {
+        let erase_btn = Button::with_icon(theme::ICON_DELETE)
+            .styled(theme::button_keyboard_erase())
+            .with_long_press(KeyboardTimings::get().erase_hold);
+        let cancel_btn =
+            Button::with_icon(theme::ICON_CLOSE).styled(theme::button_keyboard_cancel());
+        Self {
+            digits: String::new(),
+            textbox_area: Rect::zero(),
+            textbox_pad: textbox_pad(),
+            erase_btn: Maybe::hidden(theme::BG, erase_btn).into_child(),
+            cancel_btn: Maybe::visible(theme::BG, cancel_btn).into_child(),
+            confirm_btn: Button::with_icon(theme::ICON_CONFIRM)
+                .styled(theme::button_pin_confirm())
+                .initially_enabled(false)
+                .into_child(),
+            digit_btns: DigitButtons::ordered().into_child(),
+        }
+    }
+
+    /// Entered value formatted as `YYYY-MM-DD hh:mm`, once complete.
+    pub fn value(&self) -> Option<ShortString> {
+        self.is_complete().then(|| masked(&self.digits))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.digits.len() == DIGIT_COUNT
+    }
+
+    /// Index of the field the next digit goes to, the last one when complete.
+    fn current_field(&self) -> usize {
+        let mut start = 0;
+        for (i, field) in FIELDS.iter().enumerate() {
+            start += field.len;
+            if self.digits.len() < start {
+                return i;
+            }
+        }
+        FIELDS.len() - 1
+    }
+
+    /// Whether typing `digit` keeps the value on the way to a valid one.
+    fn accepts(&self, digit: &str) -> bool {
+        let mut next = self.digits.clone();
+        next.push_str(digit).is_ok() && is_valid_prefix(&next)
+    }
+
+    fn push(&mut self, digit: &str) {
+        if !self.accepts(digit) {
+            // The digit buttons leading nowhere are disabled. Should not happen.
+            return;
+        }
+        unwrap!(self.digits.push_str(digit));
+    }
+
+    fn modified(&mut self, ctx: &mut EventCtx) {
+        let is_empty = self.digits.is_empty();
+        let is_complete = self.is_complete();
+
+        let digits = &self.digit_btns.inner().0;
+        let accepted: [bool; 10] = core::array::from_fn(|i| match digits[i].inner().content() {
+            ButtonContent::Text(text) => text.map(|text| self.accepts(text)),
+            _ => false,
+        });
+        self.digit_btns.mutate(ctx, |ctx, digits| {
+            for (btn, enabled) in digits.0.iter_mut().zip(accepted) {
+                btn.mutate(ctx, |ctx, btn| btn.enable_if(ctx, enabled));
+            }
+        });
+        self.erase_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, !is_empty));
+        self.cancel_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, is_empty));
+        self.confirm_btn
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, is_complete));
+        self.textbox_pad.clear();
+        ctx.request_paint();
+    }
+
+    fn render_textbox<'s>(&self, target: &mut impl Renderer<'s>) {
+        let font = Font::MONO;
+        let text = masked(&self.digits);
+        // Everything up to the last typed digit is highlighted, the placeholders
+        // of the rest stay grey.
+        let split = filled_len(self.digits.len());
+        let (typed, rest) = text.split_at(split);
+
+        let width = font.text_width(&text);
+        let baseline = self.textbox_area.center() + Offset::y(font.visible_text_height("1") / 2);
+        let left = baseline - Offset::x(width / 2);
+        shape::Text::new(left, typed)
+            .with_align(Alignment::Start)
+            .with_font(font)
+            .with_fg(theme::FG)
+            .render(target);
+        shape::Text::new(left + Offset::x(font.text_width(typed)), rest)
+            .with_align(Alignment::Start)
+            .with_font(font)
+            .with_fg(theme::GREY)
+            .render(target);
+    }
+}
+
+/// `MASK` with its placeholders replaced by `digits`, as far as they go.
+fn masked(digits: &str) -> ShortString {
+    let mut digits = digits.chars();
+    let mut text = ShortString::new();
+    for ch in MASK.chars() {
+        let ch = if ch.is_ascii_alphabetic() {
+            digits.next().unwrap_or(ch)
+        } else {
+            ch
+        };
+        unwrap!(text.push(ch));
+    }
+    text
+}
+
+/// Length of the part of `MASK` covering the first `count` digits.
+fn filled_len(count: usize) -> usize {
+    MASK.char_indices()
+        .filter(|(_, ch)| ch.is_ascii_alphabetic())
+        .nth(count)
+        .map_or(MASK.len(), |(i, _)| i)
+}
+
+fn number(digits: &str) -> u32 {
+    digits
+        .bytes()
+        .fold(0, |acc, b| acc * 10 + (b - b'0') as u32)
+}
+
+fn is_leap_year(year: u32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Allowed values of the field at `index`. All the fields before it must
+/// already be complete in `digits`.
+fn field_range(index: usize, digits: &str) -> (u32, u32) {
+    let field = &FIELDS[index];
+    if index == DAY {
+        let max = days_in_month(number(&digits[0..4]), number(&digits[4..6]));
+        (field.min, max)
+    } else {
+        (field.min, field.max)
+    }
+}
+
+/// Whether `digits` can still be completed into a valid date and time.
+fn is_valid_prefix(digits: &str) -> bool {
+    let mut start = 0;
+    for (i, field) in FIELDS.iter().enumerate() {
+        if start >= digits.len() {
+            break;
+        }
+        let end = (start + field.len).min(digits.len());
+        let partial = &digits[start..end];
+        // Values the field can still end up with.
+        let scale = 10u32.pow((field.len - partial.len()) as u32);
+        let lowest = number(partial) * scale;
+        let highest = lowest + scale - 1;
+        let (min, max) = field_range(i, digits);
+        if highest < min || lowest > max {
+            return false;
+        }
+        start += field.len;
+    }
+    true
+}
+
+impl Component for DatetimeKeyboard {
+    type Msg = DatetimeKeyboardMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (header, keypad) = bounds.split_top(HEADER_HEIGHT);
+        let keypad = keypad.inset(Insets::top(theme::BUTTON_SPACING));
+        let grid = keypad_grid(keypad);
+
+        self.textbox_area = header;
+        self.textbox_pad.place(header);
+
+        let erase_cancel_area = grid.row_col(3, 0);
+        self.erase_btn.place(erase_cancel_area);
+        self.cancel_btn.place(erase_cancel_area);
+        self.confirm_btn.place(grid.row_col(3, 2));
+        self.digit_btns.place(keypad);
+
+        #[cfg(feature = "ui_debug")]
+        {
+            debug_target::register(&["erase"], erase_cancel_area);
+            debug_target::register(&["cancel"], erase_cancel_area);
+            debug_target::register(&["confirm"], grid.row_col(3, 2));
+            self.digit_btns.inner().register_debug_targets("datetime");
+        }
+
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Attach(_) = event {
+            // Only the digits starting a valid year are enabled at first.
+            self.modified(ctx);
+        }
+        if let Some(ButtonMsg::Clicked) = self.confirm_btn.event(ctx, event) {
+            return self.is_complete().then_some(DatetimeKeyboardMsg::Confirmed);
+        }
+        if let Some(ButtonMsg::Clicked) = self.cancel_btn.event(ctx, event) {
+            return Some(DatetimeKeyboardMsg::Cancelled);
+        }
+        match self.erase_btn.event(ctx, event) {
+            Some(ButtonMsg::Clicked) => {
+                self.digits.pop();
+                self.modified(ctx);
+                return None;
+            }
+            Some(ButtonMsg::LongPressed) => {
+                self.digits.clear();
+                self.modified(ctx);
+                return None;
+            }
+            _ => {}
+        }
+        let clicked = self.digit_btns.mutate(ctx, |ctx, digits| {
+            digits
+                .0
+                .iter_mut()
+                .position(|btn| matches!(btn.event(ctx, event), Some(ButtonMsg::Clicked)))
+        });
+        if let Some(i) = clicked {
+            if let ButtonContent::Text(text) = self.digit_btns.inner().0[i].inner().content() {
+                let text = *text;
+                text.map(|text| self.push(text));
+                self.modified(ctx);
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        todo!("remove when ui-t3t1 done");
+    }
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        self.digit_btns.render(target);
+        self.erase_btn.render(target);
+        self.cancel_btn.render(target);
+        self.confirm_btn.render(target);
+        self.textbox_pad.render(target);
+        self.render_textbox(target);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.textbox_area);
+        self.erase_btn.bounds(sink);
+        self.cancel_btn.bounds(sink);
+        self.confirm_btn.bounds(sink);
+        self.digit_btns.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for DatetimeKeyboard {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("DatetimeKeyboard");
+        t.string("value", masked(&self.digits).as_str().into());
+        t.string("field", FIELDS[self.current_field()].name.into());
+        t.bool("complete", self.is_complete());
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_is_filled_in_order() {
+        assert_eq!(masked("").as_str(), MASK);
+        assert_eq!(masked("20240").as_str(), "2024-0M-DD hh:mm");
+        assert_eq!(masked("202402291730").as_str(), "2024-02-29 17:30");
+        assert_eq!(filled_len(0), 0);
+        assert_eq!(filled_len(5), "2024-0".len());
+        assert_eq!(filled_len(DIGIT_COUNT), MASK.len());
+    }
+
+    #[test]
+    fn fields_are_validated() {
+        assert!(is_valid_prefix("2"));
+        assert!(!is_valid_prefix("3"));
+        assert!(!is_valid_prefix("18"));
+        assert!(is_valid_prefix("20241"));
+        assert!(!is_valid_prefix("20242"));
+        assert!(!is_valid_prefix("202400"));
+        assert!(!is_valid_prefix("202413"));
+        assert!(is_valid_prefix("2024123"));
+        assert!(!is_valid_prefix("2024124"));
+        assert!(!is_valid_prefix("20241200"));
+        assert!(is_valid_prefix("202412312359"));
+        assert!(!is_valid_prefix("2024123124"));
+        assert!(!is_valid_prefix("20241231236"));
+    }
+
+    #[test]
+    fn day_depends_on_month() {
+        assert!(is_valid_prefix("20240229"));
+        assert!(!is_valid_prefix("20230229"));
+        assert!(is_valid_prefix("20000229"));
+        assert!(is_valid_prefix("20230430"));
+        assert!(!is_valid_prefix("20230431"));
+        assert!(!is_valid_prefix("2023023"));
+    }
+}
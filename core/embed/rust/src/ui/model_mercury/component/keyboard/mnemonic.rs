@@ -1,25 +1,61 @@
+use core::sync::atomic::{compiler_fence, Ordering};
+use heapless::Vec;
+
+#[cfg(feature = "haptic")]
+use crate::trezorhal::haptic::{play, HapticEffect};
 use crate::{
     strutil::TString,
+    time::{Duration, Stopwatch},
     ui::{
         component::{
-            maybe::paint_overlapping, Child, Component, Event, EventCtx, Label, Maybe, Swipe,
-            SwipeDirection,
+            maybe::paint_overlapping, text::common::KeyboardTimings, Child, Component, Event,
+            EventCtx, Label, Maybe, Swipe, SwipeDirection, TimerToken,
         },
-        geometry::{Alignment, Grid, Insets, Rect},
+        event::TouchEvent,
+        geometry::{Alignment, Alignment2D, Grid, Insets, Offset, Point, Rect},
+        lerp::Lerp,
         model_mercury::{
-            component::{Button, ButtonMsg},
+            component::{
+                keyboard::common::{DisabledKeyFeedback, EraseDoubleTap, ERASE_DOUBLE_TAP},
+                Button, ButtonMsg,
+            },
             theme,
         },
+        shape,
         shape::Renderer,
+        util::animation_disabled,
     },
 };
 
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
+#[cfg(feature = "ui_debug")]
+use crate::{strutil, ui::component::debug_target};
+
 pub const MNEMONIC_KEY_COUNT: usize = 9;
+/// Words of the longest mnemonic, a 33-word SLIP-39 share.
+const MAX_WORDS: usize = 33;
 const BACK_BUTTON_RIGHT_EXPAND: i16 = 24;
+/// Distance a touch has to travel to the left over the input to delete a
+/// letter.
+const ERASE_SWIPE_DISTANCE: i16 = 30;
+/// Distance a touch has to travel to the left over the input to clear the
+/// whole word.
+const ERASE_SWIPE_LONG_DISTANCE: i16 = 120;
+/// Time it takes the checkmark to scale in after a word was accepted.
+const ACCEPTED_SCALE_DURATION: Duration = Duration::from_millis(250);
+/// How long the checkmark stays shown in total.
+const ACCEPTED_DURATION: Duration = Duration::from_millis(600);
+const ACCEPTED_RADIUS: i16 = 16;
+/// Height of the ticks of the word progress strip.
+const PROGRESS_TICK_HEIGHT: i16 = 2;
+const PROGRESS_TICK_SPACING: i16 = 2;
 
 pub enum MnemonicKeyboardMsg {
     Confirmed,
     Previous,
+    /// Moved on to another word, the progress text describes which one.
+    Progress(TString<'static>),
 }
 
 pub struct MnemonicKeyboard<T> {
@@ -35,8 +71,24 @@ pub struct MnemonicKeyboard<T> {
     keys: [Child<Button>; MNEMONIC_KEY_COUNT],
     /// Swipe controller - allowing for going to the previous word.
     swipe: Swipe,
+    /// Swipe left over the input, deleting letters.
+    erase_swipe: EraseSwipe,
+    /// Checkmark confirming that the previous word was accepted.
+    accepted: AcceptedAnimation,
+    /// Shaking the input when a key that cannot continue the word is tapped.
+    disabled_key: DisabledKeyFeedback,
+    /// Clearing the input by tapping erase twice.
+    erase_double_tap: EraseDoubleTap,
+    /// Ticks of the words of the share, above the input.
+    progress: WordProgress,
     /// Whether going back is allowed (is not on the very first word).
     can_go_back: bool,
+    /// Words confirmed so far, prefilled when coming back to them.
+    words: Vec<&'static str, MAX_WORDS>,
+    /// Whether to only offer the last words giving a valid checksum.
+    filter_last_word: bool,
+    /// Where the input of the next word goes.
+    input_area: Rect,
 }
 
 impl<T> MnemonicKeyboard<T>
@@ -49,14 +101,17 @@ where
         let erase_btn = Button::with_icon(theme::ICON_DELETE)
             .styled(theme::button_default())
             .with_expanded_touch_area(Insets::right(BACK_BUTTON_RIGHT_EXPAND))
-            .with_long_press(theme::ERASE_HOLD_DURATION);
+            .with_long_press(KeyboardTimings::get().erase_hold)
+            .with_eager_double_press(ERASE_DOUBLE_TAP);
         let back_btn = Button::with_icon(theme::ICON_CHEVRON_LEFT)
             .styled(theme::button_default())
             .with_expanded_touch_area(Insets::right(BACK_BUTTON_RIGHT_EXPAND));
         Self {
             prompt: Child::new(Maybe::new(
                 theme::BG,
-                Label::centered(prompt, theme::TEXT_MAIN_GREY_LIGHT).vertically_centered(),
+                Label::centered(prompt, *theme::text_main_grey_light())
+                    .vertically_centered()
+                    .with_auto_fit(theme::keyboard_prompt_fonts()),
                 prompt_visible,
             )),
             erase: Child::new(Maybe::new(theme::BG, erase_btn, !prompt_visible)),
@@ -71,14 +126,82 @@ where
                     Button::with_text(t.into())
                         .styled(theme::button_keyboard())
                         .with_text_align(Alignment::Center)
+                        .with_disabled_press()
+                        .with_debounce(KeyboardTimings::get().key_debounce)
                 })
                 .map(Child::new),
             swipe: Swipe::new().right(),
+            erase_swipe: EraseSwipe::new(),
+            // Every keyboard after the first one follows an accepted word.
+            accepted: AcceptedAnimation::new(prompt_visible && can_go_back),
+            disabled_key: DisabledKeyFeedback::new(),
+            erase_double_tap: EraseDoubleTap::new(),
+            progress: WordProgress::new(),
             can_go_back,
+            words: Vec::new(),
+            filter_last_word: false,
+            input_area: Rect::zero(),
+        }
+    }
+
+    /// Show a strip of `word_count` ticks, the first `word_index` of them
+    /// marking the words already entered.
+    pub fn with_progress(mut self, word_index: usize, word_count: usize) -> Self {
+        self.progress.word_index = word_index;
+        self.progress.word_count = word_count;
+        self
+    }
+
+    /// When moving on to the last word, only offer the words that give a valid
+    /// checksum together with the words confirmed before.
+    pub fn with_last_word_filter(mut self) -> Self {
+        self.filter_last_word = true;
+        self
+    }
+
+    /// Remember the word just confirmed, so that it can be prefilled when
+    /// coming back to it.
+    fn record_word(&mut self) {
+        let Some(word) = self.mnemonic() else {
+            return;
+        };
+        let index = self.progress.word_index;
+        if let Some(recorded) = self.words.get_mut(index) {
+            *recorded = word;
+        } else if index == self.words.len() {
+            unwrap!(self.words.push(word));
         }
     }
 
+    /// Replace the input with one for word `word_index`, prefilled with the
+    /// word confirmed there before, if any.
+    fn show_word(&mut self, ctx: &mut EventCtx, word_index: usize) {
+        let prefill = self.words.get(word_index).copied().unwrap_or("");
+        let is_last = word_index + 1 == self.progress.word_count;
+        let previous_words = (self.filter_last_word && is_last && self.words.len() >= word_index)
+            .then(|| &self.words[..word_index]);
+        let input_area = self.input_area;
+        self.input.mutate(ctx, |ctx, i| {
+            let input = i.inner().for_word(prefill, previous_words);
+            *i.inner_mut() = input;
+            i.inner_mut().place(input_area);
+            ctx.request_paint();
+        });
+        self.progress.word_index = word_index;
+        self.can_go_back = word_index > 0;
+        // Same as a new keyboard, which follows an accepted word. The layout is
+        // already attached, so the animation starts right away.
+        self.accepted = AcceptedAnimation::new(prefill.is_empty() && self.can_go_back);
+        self.accepted.start(ctx);
+        self.erase_swipe.reset();
+        self.toggle_key_buttons(ctx);
+        self.toggle_prompt_or_input(ctx);
+        ctx.request_paint();
+    }
+
     fn on_input_change(&mut self, ctx: &mut EventCtx) {
+        // Typing the next word takes over the input area right away.
+        self.accepted.stop(ctx);
         self.toggle_key_buttons(ctx);
         self.toggle_prompt_or_input(ctx);
     }
@@ -114,6 +237,61 @@ where
     pub fn mnemonic(&self) -> Option<&'static str> {
         self.input.inner().inner().mnemonic()
     }
+
+    /// Letter `ch` acts like a click of the key containing it, as long as that
+    /// key is enabled.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn type_char(&mut self, ctx: &mut EventCtx, ch: char) {
+        let ch = ch.to_ascii_lowercase();
+        let Some(key) = T::keys().iter().position(|k| k.contains(ch)) else {
+            return;
+        };
+        if self.keys[key].inner().is_enabled() {
+            self.input
+                .mutate(ctx, |ctx, i| i.inner_mut().on_host_char(ctx, key, ch));
+            self.on_input_change(ctx);
+        }
+    }
+
+    /// Key typed on the host keyboard acts like a click of the corresponding
+    /// button, as long as that button is enabled. `Enter` is handled by the
+    /// input itself.
+    #[cfg(feature = "host_keyboard")]
+    fn on_host_key(&mut self, ctx: &mut EventCtx, key: HostKey) -> Option<MnemonicKeyboardMsg> {
+        let input_empty = self.input.inner().inner().is_empty();
+        match key {
+            HostKey::Char(ch) => self.type_char(ctx, ch),
+            HostKey::Backspace if !input_empty => {
+                self.input
+                    .mutate(ctx, |ctx, i| i.inner_mut().on_backspace_click(ctx));
+                self.on_input_change(ctx);
+            }
+            HostKey::Escape if input_empty && self.can_go_back => {
+                return Some(MnemonicKeyboardMsg::Previous);
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+impl<T> MnemonicKeyboard<T> {
+    /// Forget the words confirmed so far. The references into the wordlist give
+    /// away the words, so they are overwritten before the vector is cleared.
+    fn wipe_words(&mut self) {
+        for word in self.words.iter_mut() {
+            // SAFETY: `word` is a valid and aligned reference.
+            unsafe { core::ptr::write_volatile(word, "") };
+        }
+        compiler_fence(Ordering::SeqCst);
+        self.words.clear();
+    }
+}
+
+impl<T> Drop for MnemonicKeyboard<T> {
+    fn drop(&mut self) {
+        self.wipe_words();
+    }
 }
 
 impl<T> Component for MnemonicKeyboard<T>
@@ -126,13 +304,13 @@ where
         let height_input_area: i16 = 38;
         let padding_top: i16 = 6;
         let back_btn_area_width: i16 = 32;
-        let (remaining, keyboard_area) =
-            bounds.split_bottom(3 * theme::MNEMONIC_BUTTON_HEIGHT + 2 * theme::KEYBOARD_SPACING);
-        let prompt_area = remaining
-            .split_top(padding_top)
-            .1
-            .split_top(height_input_area)
-            .0;
+        // Keys get shorter when the keyboard does not have the whole screen,
+        // e.g. under the header of a `Frame`.
+        let keyboard_height = (3 * theme::MNEMONIC_BUTTON_HEIGHT + 2 * theme::KEYBOARD_SPACING)
+            .min(bounds.height() - padding_top - height_input_area);
+        let (remaining, keyboard_area) = bounds.split_bottom(keyboard_height);
+        let (progress_area, prompt_label_area) = remaining.split_top(padding_top);
+        let prompt_area = prompt_label_area.split_top(height_input_area).0;
         assert!(prompt_area.height() == height_input_area);
 
         let (back_btn_area, input_area) = prompt_area.split_left(back_btn_area_width);
@@ -140,31 +318,111 @@ where
         let keyboard_grid = Grid::new(keyboard_area, 3, 3).with_spacing(theme::KEYBOARD_SPACING);
 
         self.swipe.place(bounds);
-        self.prompt.place(prompt_area);
+        // A prompt in the larger fonts may wrap onto all the space above the keys.
+        self.prompt.place(if theme::large_text() {
+            prompt_label_area
+        } else {
+            prompt_area
+        });
         self.erase.place(back_btn_area);
         self.back.place(back_btn_area);
         self.input.place(input_area);
+        self.input_area = input_area;
+        self.erase_swipe.place(input_area);
+        self.disabled_key.place(prompt_area);
+        self.erase_double_tap.place(input_area);
+        self.progress.area = progress_area;
 
         for (key, btn) in self.keys.iter_mut().enumerate() {
             btn.place(keyboard_grid.cell(key));
+            #[cfg(feature = "ui_debug")]
+            debug_target::register(
+                &["mnemonic", "key", strutil::format_int(key as i64).as_str()],
+                keyboard_grid.cell(key),
+            );
+        }
+
+        #[cfg(feature = "ui_debug")]
+        {
+            // Clicking the word suggested in the input confirms it.
+            debug_target::register(&["confirm"], input_area);
+            debug_target::register(&["erase"], back_btn_area);
+            debug_target::register(&["back"], back_btn_area);
         }
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::MnemonicWord(word_index, progress) = event {
+            self.show_word(ctx, word_index as usize);
+            return Some(MnemonicKeyboardMsg::Progress(progress));
+        }
+
+        self.accepted.event(ctx, event);
+        self.disabled_key.event(ctx, event);
+        self.erase_double_tap.event(ctx, event);
+
+        #[cfg(feature = "host_keyboard")]
+        if let Event::HostKey(key @ (HostKey::Char(_) | HostKey::Backspace | HostKey::Escape)) =
+            event
+        {
+            return self.on_host_key(ctx, key);
+        }
+
         // Back button or swipe will cause going back to the previous word when allowed.
         if self.can_go_back {
             if let Some(ButtonMsg::Clicked) = self.back.event(ctx, event) {
-                return Some(MnemonicKeyboardMsg::Previous);
+                // Double tap of erase that emptied the word ends up on back.
+                if !self.erase_double_tap.swallows_tap() {
+                    return Some(MnemonicKeyboardMsg::Previous);
+                }
             }
             if let Some(SwipeDirection::Right) = self.swipe.event(ctx, event) {
                 return Some(MnemonicKeyboardMsg::Previous);
             }
         }
 
+        let input_visible = !self.input.inner().inner().is_empty();
+        match self.erase_swipe.event(ctx, event, input_visible) {
+            Some(EraseSwipeMsg::Started) => {
+                // The touch is ours now, let the input release it by ending it outside of
+                // its area.
+                let outside = self.erase_swipe.outside_point();
+                self.input
+                    .event(ctx, Event::Touch(TouchEvent::TouchEnd(outside)));
+                return None;
+            }
+            Some(EraseSwipeMsg::DeleteLetter) => {
+                #[cfg(feature = "haptic")]
+                play(HapticEffect::Press);
+                self.input
+                    .mutate(ctx, |ctx, i| i.inner_mut().on_backspace_click(ctx));
+                self.on_input_change(ctx);
+                return None;
+            }
+            Some(EraseSwipeMsg::ClearWord) => {
+                #[cfg(feature = "haptic")]
+                play(HapticEffect::Press);
+                self.input
+                    .mutate(ctx, |ctx, i| i.inner_mut().on_backspace_long_press(ctx));
+                self.on_input_change(ctx);
+                return None;
+            }
+            None => {}
+        }
+        if self.erase_swipe.is_swiping() && matches!(event, Event::Touch(_)) {
+            // Touches belonging to the swipe are not forwarded any further.
+            return None;
+        }
+
         match self.input.event(ctx, event) {
             Some(MnemonicInputMsg::Confirmed) => {
                 // Confirmed, bubble up.
+                self.record_word();
+                if self.progress.word_index + 1 >= self.progress.word_count {
+                    // The whole mnemonic is entered, no coming back to the words.
+                    self.wipe_words();
+                }
                 return Some(MnemonicKeyboardMsg::Confirmed);
             }
             Some(_) => {
@@ -176,13 +434,20 @@ where
         }
 
         match self.erase.event(ctx, event) {
+            Some(ButtonMsg::Pressed) if self.erase.inner().inner().awaits_double_click() => {
+                self.erase_double_tap.trigger(ctx);
+                return None;
+            }
             Some(ButtonMsg::Clicked) => {
+                self.erase_double_tap.erased();
                 self.input
                     .mutate(ctx, |ctx, i| i.inner_mut().on_backspace_click(ctx));
                 self.on_input_change(ctx);
                 return None;
             }
-            Some(ButtonMsg::LongPressed) => {
+            // The first click already erased a letter, clear the rest.
+            Some(ButtonMsg::LongPressed | ButtonMsg::DoubleClicked) => {
+                self.erase_double_tap.erased();
                 self.input
                     .mutate(ctx, |ctx, i| i.inner_mut().on_backspace_long_press(ctx));
                 self.on_input_change(ctx);
@@ -191,11 +456,18 @@ where
             _ => {}
         }
         for (key, btn) in self.keys.iter_mut().enumerate() {
-            if let Some(ButtonMsg::Clicked) = btn.event(ctx, event) {
-                self.input
-                    .mutate(ctx, |ctx, i| i.inner_mut().on_key_click(ctx, key));
-                self.on_input_change(ctx);
-                return None;
+            match btn.event(ctx, event) {
+                Some(ButtonMsg::Clicked) => {
+                    self.input
+                        .mutate(ctx, |ctx, i| i.inner_mut().on_key_click(ctx, key));
+                    self.on_input_change(ctx);
+                    return None;
+                }
+                Some(ButtonMsg::PressedDisabled) => {
+                    self.disabled_key.trigger(ctx);
+                    return None;
+                }
+                _ => {}
             }
         }
         None
@@ -209,15 +481,24 @@ where
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        if self.input.inner().inner().is_empty() {
-            self.prompt.render(target);
-            if self.can_go_back {
-                self.back.render(target);
+        self.disabled_key.render(target, &|target| {
+            if self.input.inner().inner().is_empty() {
+                if self.accepted.is_active() {
+                    self.accepted
+                        .render(target, self.prompt.inner().inner().area().center());
+                } else {
+                    self.prompt.render(target);
+                }
+                if self.can_go_back {
+                    self.back.render(target);
+                }
+            } else {
+                self.input.render(target);
+                self.erase.render(target);
             }
-        } else {
-            self.input.render(target);
-            self.erase.render(target);
-        }
+        });
+        self.erase_double_tap.render(target);
+        self.progress.render(target);
 
         for btn in &self.keys {
             btn.render(target);
@@ -236,14 +517,237 @@ where
     }
 }
 
+enum EraseSwipeMsg {
+    /// Touch turned into a swipe, other components should stop tracking it.
+    Started,
+    DeleteLetter,
+    ClearWord,
+}
+
+/// Swipe to the left over the input area. A short swipe deletes the last
+/// letter, a long swipe or a swipe held in place clears the whole word.
+struct EraseSwipe {
+    area: Rect,
+    /// Start of the current touch, if it started over the input.
+    origin: Option<Point>,
+    /// Whether the current touch was recognized as a swipe.
+    swiping: bool,
+    /// Whether the word was already cleared by holding the swipe.
+    cleared: bool,
+    hold_timer: Option<TimerToken>,
+}
+
+impl EraseSwipe {
+    fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            origin: None,
+            swiping: false,
+            cleared: false,
+            hold_timer: None,
+        }
+    }
+
+    fn place(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    fn is_swiping(&self) -> bool {
+        self.swiping
+    }
+
+    /// Point just left of the input area, used to end touches there.
+    fn outside_point(&self) -> Point {
+        Point::new(self.area.x0 - 1, self.area.y0)
+    }
+
+    fn reset(&mut self) {
+        self.origin = None;
+        self.swiping = false;
+        self.cleared = false;
+        self.hold_timer = None;
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event, enabled: bool) -> Option<EraseSwipeMsg> {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => {
+                self.reset();
+                if enabled && self.area.contains(pos) {
+                    self.origin = Some(pos);
+                }
+            }
+            Event::Touch(TouchEvent::TouchMove(pos)) => {
+                let ofs = pos - self.origin?;
+                let past_threshold = -ofs.x >= ERASE_SWIPE_DISTANCE;
+                let mut msg = None;
+                if !self.swiping && past_threshold && ofs.x.abs() > ofs.y.abs() {
+                    self.swiping = true;
+                    msg = Some(EraseSwipeMsg::Started);
+                }
+                if !self.swiping || !past_threshold {
+                    // Holding only counts while being swiped far enough.
+                    self.hold_timer = None;
+                } else if self.hold_timer.is_none() && !self.cleared {
+                    self.hold_timer = Some(ctx.request_timer(KeyboardTimings::get().erase_hold));
+                }
+                return msg;
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) => {
+                let origin = self.origin?;
+                let (swiping, cleared) = (self.swiping, self.cleared);
+                self.reset();
+                if !swiping || cleared {
+                    return None;
+                }
+                let distance = origin.x - pos.x;
+                if distance >= ERASE_SWIPE_LONG_DISTANCE {
+                    return Some(EraseSwipeMsg::ClearWord);
+                } else if distance >= ERASE_SWIPE_DISTANCE {
+                    return Some(EraseSwipeMsg::DeleteLetter);
+                }
+            }
+            Event::Timer(token) if self.hold_timer == Some(token) => {
+                self.hold_timer = None;
+                if self.swiping {
+                    self.cleared = true;
+                    return Some(EraseSwipeMsg::ClearWord);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+/// Green checkmark scaling in over the input area when the keyboard appears or
+/// moves on to the next word, acknowledging the word entered before. Keys work as
+/// usual while it is shown, the first one pressed ends the animation.
+struct AcceptedAnimation {
+    enabled: bool,
+    timer: Stopwatch,
+}
+
+impl AcceptedAnimation {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled: enabled && !animation_disabled(),
+            timer: Stopwatch::new_stopped(),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.enabled && self.timer.is_running_within(ACCEPTED_DURATION)
+    }
+
+    fn start(&mut self, ctx: &mut EventCtx) {
+        if self.enabled {
+            self.timer.start();
+            ctx.request_anim_frame();
+            ctx.request_paint();
+        }
+    }
+
+    fn stop(&mut self, ctx: &mut EventCtx) {
+        if self.enabled {
+            self.enabled = false;
+            ctx.request_paint();
+        }
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if !self.enabled {
+            return;
+        }
+        match event {
+            Event::Attach(_) => self.start(ctx),
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) if self.timer.is_running() => {
+                if self.is_active() {
+                    ctx.request_anim_frame();
+                    ctx.request_paint();
+                } else {
+                    self.stop(ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render<'s>(&self, target: &mut impl Renderer<'s>, center: Point) {
+        let t =
+            self.timer.elapsed().to_millis() as f32 / ACCEPTED_SCALE_DURATION.to_millis() as f32;
+        let scale = pareen::constant(0.0)
+            .seq_ease_out(0.0, easer::functions::Cubic, 1.0, pareen::constant(1.0))
+            .eval(t.min(1.0));
+        shape::Circle::new(center, i16::lerp(0, ACCEPTED_RADIUS, scale))
+            .with_fg(theme::GREEN)
+            .with_bg(theme::GREEN)
+            .render(target);
+        if scale >= 1.0 {
+            shape::ToifImage::new(center, theme::ICON_SIMPLE_CHECKMARK24.toif)
+                .with_align(Alignment2D::CENTER)
+                .with_fg(theme::GREEN_LIGHT)
+                .render(target);
+        }
+    }
+}
+
+/// Word being entered out of the words of the share, as a strip of ticks.
+struct WordProgress {
+    area: Rect,
+    word_index: usize,
+    word_count: usize,
+}
+
+impl WordProgress {
+    fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            word_index: 0,
+            word_count: 0,
+        }
+    }
+
+    fn render<'s>(&self, target: &mut impl Renderer<'s>) {
+        if self.word_count == 0 {
+            return;
+        }
+        let count = self.word_count as i16;
+        let step = self.area.width() / count;
+        let tick = Offset::new(step - PROGRESS_TICK_SPACING, PROGRESS_TICK_HEIGHT);
+        let width = step * count - PROGRESS_TICK_SPACING;
+        let mut left = self.area.center() - Offset::x(width / 2);
+        for i in 0..self.word_count {
+            let color = if i < self.word_index {
+                theme::GREEN_LIGHT
+            } else {
+                theme::GREY_DARK
+            };
+            shape::Bar::new(Rect::snap(left, tick, Alignment2D::CENTER_LEFT))
+                .with_bg(color)
+                .render(target);
+            left.x += step;
+        }
+    }
+}
+
 pub trait MnemonicInput: Component<Msg = MnemonicInputMsg> {
     fn keys() -> [&'static str; MNEMONIC_KEY_COUNT];
     fn can_key_press_lead_to_a_valid_word(&self, key: usize) -> bool;
     fn on_key_click(&mut self, ctx: &mut EventCtx, key: usize);
     fn on_backspace_click(&mut self, ctx: &mut EventCtx);
     fn on_backspace_long_press(&mut self, ctx: &mut EventCtx);
+    /// Letter `ch` of `key` was typed on the host keyboard or by debuglink.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn on_host_char(&mut self, ctx: &mut EventCtx, key: usize, ch: char);
+    /// Content of the textbox.
+    #[cfg(feature = "ui_debug")]
+    fn content(&self) -> &str;
     fn is_empty(&self) -> bool;
     fn mnemonic(&self) -> Option<&'static str>;
+    /// Input of the same kind for another word of the mnemonic, prefilled with
+    /// `word` (may be empty). `previous_words` are all the other words when
+    /// moving on to the last one, if it should be checked against them.
+    fn for_word(&self, word: &str, previous_words: Option<&[&'static str]>) -> Self;
 }
 
 pub enum MnemonicInputMsg {
@@ -252,6 +756,20 @@ pub enum MnemonicInputMsg {
     TimedOut,
 }
 
+#[cfg(feature = "ui_debug")]
+impl<T> crate::ui::layout::obj::DebugKeyboard for MnemonicKeyboard<T>
+where
+    T: MnemonicInput,
+{
+    fn debug_text(&self) -> &str {
+        self.input.inner().inner().content()
+    }
+
+    fn debug_type(&mut self, ctx: &mut EventCtx, ch: char) {
+        self.type_char(ctx, ch);
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl<T> crate::trace::Trace for MnemonicKeyboard<T>
 where
@@ -259,7 +777,13 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("MnemonicKeyboard");
+        if self.progress.word_count > 0 {
+            t.int("word_index", self.progress.word_index as i64);
+            t.int("word_count", self.progress.word_count as i64);
+        }
         t.child("prompt", &self.prompt);
         t.child("input", &self.input);
+        t.child("erase", &self.erase);
+        t.child("back", &self.back);
     }
 }
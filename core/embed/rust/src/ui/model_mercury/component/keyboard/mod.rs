@@ -1,5 +1,8 @@
+pub mod amount;
 pub mod bip39;
+pub mod datetime;
 pub mod mnemonic;
+pub mod numeric;
 pub mod passphrase;
 pub mod pin;
 pub mod slip39;
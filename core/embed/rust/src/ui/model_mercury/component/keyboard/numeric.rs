@@ -0,0 +1,233 @@
+use heapless::String;
+
+use crate::{
+    strutil::{self, ShortString},
+    ui::{
+        component::{
+            base::ComponentExt, text::common::KeyboardTimings, Child, Component, Event, EventCtx,
+            Maybe, Pad,
+        },
+        display::Font,
+        geometry::{Alignment, Insets, Offset, Rect},
+        model_mercury::component::{
+            button::{Button, ButtonContent, ButtonMsg},
+            theme,
+        },
+        shape::{self, Renderer},
+    },
+};
+
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::debug_target;
+
+use super::pin::{keypad_grid, textbox_pad, DigitButtons};
+
+pub enum NumericKeyboardMsg {
+    Confirmed(u32),
+    Cancelled,
+}
+
+/// Enough for any `u32`.
+const MAX_LENGTH: usize = 10;
+
+const HEADER_HEIGHT: i16 = 36;
+
+/// Keypad for typing a number directly. Only values in `min..=max` can be
+/// confirmed.
+pub struct NumericKeyboard {
+    min: u32,
+    max: u32,
+    digits: String<MAX_LENGTH>,
+    textbox_area: Rect,
+    textbox_pad: Pad,
+    erase_btn: Child<Maybe<Button>>,
+    cancel_btn: Child<Maybe<Button>>,
+    confirm_btn: Child<Button>,
+    digit_btns: Child<DigitButtons>,
+}
+
+impl NumericKeyboard {
+    pub fn new(min: u32, max: u32) -> Self {
+        let erase_btn = Button::with_icon(theme::ICON_DELETE)
+            .styled(theme::button_keyboard_erase())
+            .with_long_press(KeyboardTimings::get().erase_hold);
+        let cancel_btn =
+            Button::with_icon(theme::ICON_CLOSE).styled(theme::button_keyboard_cancel());
+        Self {
+            min,
+            max,
+            digits: String::new(),
+            textbox_area: Rect::zero(),
+            textbox_pad: textbox_pad(),
+            erase_btn: Maybe::hidden(theme::BG, erase_btn).into_child(),
+            cancel_btn: Maybe::visible(theme::BG, cancel_btn).into_child(),
+            confirm_btn: Button::with_icon(theme::ICON_CONFIRM)
+                .styled(theme::button_pin_confirm())
+                .initially_enabled(false)
+                .into_child(),
+            digit_btns: DigitButtons::ordered().into_child(),
+        }
+    }
+
+    /// Typed value, if it is within the bounds.
+    pub fn value(&self) -> Option<u32> {
+        let value = self.digits.parse::<u32>().ok()?;
+        (self.min..=self.max).contains(&value).then_some(value)
+    }
+
+    fn push(&mut self, digit: &str) {
+        // No leading zeros.
+        if self.digits == "0" {
+            self.digits.clear();
+        }
+        if self.digits.push_str(digit).is_err() {
+            // The digit buttons are disabled when full. Should not happen.
+        }
+    }
+
+    fn modified(&mut self, ctx: &mut EventCtx) {
+        let is_empty = self.digits.is_empty();
+        let is_full = self.digits.len() == self.digits.capacity();
+        let is_valid = self.value().is_some();
+
+        self.digit_btns.mutate(ctx, |ctx, digits| {
+            for btn in &mut digits.0 {
+                btn.mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_full));
+            }
+        });
+        self.erase_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, !is_empty));
+        self.cancel_btn
+            .mutate(ctx, |ctx, btn| btn.show_if(ctx, is_empty));
+        self.confirm_btn
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, is_valid));
+        self.textbox_pad.clear();
+        ctx.request_paint();
+    }
+
+    fn render_textbox<'s>(&self, target: &mut impl Renderer<'s>) {
+        let font = Font::DEMIBOLD;
+        let baseline = self.textbox_area.center() + Offset::y(font.visible_text_height("1") / 2);
+        let mut text = ShortString::new();
+        let color = if self.digits.is_empty() {
+            // Hint the accepted range.
+            unwrap!(strutil::push_int(&mut text, self.min as i64));
+            unwrap!(text.push_str(" - "));
+            unwrap!(strutil::push_int(&mut text, self.max as i64));
+            theme::GREY
+        } else {
+            unwrap!(text.push_str(&self.digits));
+            if self.value().is_some() {
+                theme::FG
+            } else {
+                theme::GREY
+            }
+        };
+        shape::Text::new(baseline, &text)
+            .with_align(Alignment::Center)
+            .with_font(font)
+            .with_fg(color)
+            .render(target);
+    }
+}
+
+impl Component for NumericKeyboard {
+    type Msg = NumericKeyboardMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (header, keypad) = bounds.split_top(HEADER_HEIGHT);
+        let keypad = keypad.inset(Insets::top(theme::BUTTON_SPACING));
+        let grid = keypad_grid(keypad);
+
+        self.textbox_area = header;
+        self.textbox_pad.place(header);
+
+        let erase_cancel_area = grid.row_col(3, 0);
+        self.erase_btn.place(erase_cancel_area);
+        self.cancel_btn.place(erase_cancel_area);
+        self.confirm_btn.place(grid.row_col(3, 2));
+        self.digit_btns.place(keypad);
+
+        #[cfg(feature = "ui_debug")]
+        {
+            debug_target::register(&["erase"], erase_cancel_area);
+            debug_target::register(&["cancel"], erase_cancel_area);
+            debug_target::register(&["confirm"], grid.row_col(3, 2));
+            self.digit_btns.inner().register_debug_targets("numeric");
+        }
+
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Some(ButtonMsg::Clicked) = self.confirm_btn.event(ctx, event) {
+            return self.value().map(NumericKeyboardMsg::Confirmed);
+        }
+        if let Some(ButtonMsg::Clicked) = self.cancel_btn.event(ctx, event) {
+            return Some(NumericKeyboardMsg::Cancelled);
+        }
+        match self.erase_btn.event(ctx, event) {
+            Some(ButtonMsg::Clicked) => {
+                self.digits.pop();
+                self.modified(ctx);
+                return None;
+            }
+            Some(ButtonMsg::LongPressed) => {
+                self.digits.clear();
+                self.modified(ctx);
+                return None;
+            }
+            _ => {}
+        }
+        let clicked = self.digit_btns.mutate(ctx, |ctx, digits| {
+            digits
+                .0
+                .iter_mut()
+                .position(|btn| matches!(btn.event(ctx, event), Some(ButtonMsg::Clicked)))
+        });
+        if let Some(i) = clicked {
+            if let ButtonContent::Text(text) = self.digit_btns.inner().0[i].inner().content() {
+                let text = *text;
+                text.map(|text| self.push(text));
+                self.modified(ctx);
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        todo!("remove when ui-t3t1 done");
+    }
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        self.digit_btns.render(target);
+        self.erase_btn.render(target);
+        self.cancel_btn.render(target);
+        self.confirm_btn.render(target);
+        self.textbox_pad.render(target);
+        self.render_textbox(target);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.textbox_area);
+        self.erase_btn.bounds(sink);
+        self.cancel_btn.bounds(sink);
+        self.confirm_btn.bounds(sink);
+        self.digit_btns.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for NumericKeyboard {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("NumericKeyboard");
+        t.string("digits", self.digits.as_str().into());
+        t.bool("valid", self.value().is_some());
+        t.int("min", self.min as i64);
+        t.int("max", self.max as i64);
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
+    }
+}
@@ -1,16 +1,22 @@
 use crate::{
     strutil::TString,
+    time::Duration,
     translations::TR,
     ui::{
         component::{
-            base::ComponentExt, text::common::TextBox, Child, Component, Event, EventCtx, Label,
-            Maybe, Never, Swipe, SwipeDirection,
+            base::ComponentExt,
+            text::common::{KeyboardTimings, TextBox},
+            Child, Component, Event, EventCtx, Label, Maybe, Never, Swipe, SwipeDirection, Timer,
         },
         display,
+        event::TouchEvent,
         geometry::{Alignment, Grid, Insets, Offset, Rect},
         model_mercury::component::{
             button::{Button, ButtonContent, ButtonMsg},
-            keyboard::common::{render_pending_marker, MultiTapKeyboard},
+            keyboard::common::{
+                render_pending_marker, DisabledKeyFeedback, EraseDoubleTap, MultiTapKeyboard,
+                ERASE_DOUBLE_TAP,
+            },
             theme,
         },
         shape,
@@ -19,7 +25,13 @@ use crate::{
     },
 };
 
-use core::cell::Cell;
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
+#[cfg(feature = "ui_debug")]
+use crate::{strutil, ui::component::debug_target};
+
+use core::{cell::Cell, mem};
+use heapless::String;
 use num_traits::ToPrimitive;
 
 pub enum PassphraseKeyboardMsg {
@@ -70,10 +82,69 @@ impl From<KeyboardLayout> for ButtonContent {
     }
 }
 
+/// Spaces that change the wallet but are easy to miss in the input, shown as
+/// a warning on the first confirm.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum SpaceIssue {
+    Leading,
+    Trailing,
+    Repeated,
+}
+
+impl SpaceIssue {
+    fn find(passphrase: &str) -> Option<Self> {
+        if passphrase.starts_with(' ') {
+            Some(Self::Leading)
+        } else if passphrase.ends_with(' ') {
+            Some(Self::Trailing)
+        } else if passphrase.contains("  ") {
+            Some(Self::Repeated)
+        } else {
+            None
+        }
+    }
+
+    fn text(self) -> TString<'static> {
+        match self {
+            Self::Leading => TR::passphrase__space_at_start.into(),
+            Self::Trailing => TR::passphrase__space_at_end.into(),
+            Self::Repeated => TR::passphrase__double_space.into(),
+        }
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn name(self) -> &'static str {
+        match self {
+            Self::Leading => "leading",
+            Self::Trailing => "trailing",
+            Self::Repeated => "repeated",
+        }
+    }
+}
+
+/// `passphrase` without the leading and trailing spaces, and with the runs of
+/// spaces collapsed into one.
+fn trim_spaces(passphrase: &str) -> String<MAX_LENGTH> {
+    let mut trimmed = String::new();
+    for word in passphrase.split(' ').filter(|w| !w.is_empty()) {
+        if !trimmed.is_empty() {
+            unwrap!(trimmed.push(' '));
+        }
+        unwrap!(trimmed.push_str(word));
+    }
+    trimmed
+}
+
 pub struct PassphraseKeyboard {
     page_swipe: Swipe,
     input: Child<Input>,
     input_prompt: Child<Label<'static>>,
+    /// Shown in place of the input after confirming a passphrase with a
+    /// `SpaceIssue`, clicking it trims the spaces.
+    space_warning: Child<Button>,
+    space_issue: Option<SpaceIssue>,
+    disabled_key: DisabledKeyFeedback,
+    erase_double_tap: EraseDoubleTap,
     erase_btn: Child<Maybe<Button>>,
     cancel_btn: Child<Maybe<Button>>,
     confirm_btn: Child<Button>,
@@ -111,7 +182,8 @@ impl PassphraseKeyboard {
 
         let erase_btn = Button::with_icon(theme::ICON_DELETE)
             .styled(theme::button_keyboard_erase())
-            .with_long_press(theme::ERASE_HOLD_DURATION)
+            .with_long_press(KeyboardTimings::get().erase_hold)
+            .with_eager_double_press(ERASE_DOUBLE_TAP)
             .initially_enabled(false);
         let erase_btn = Maybe::hidden(theme::BG, erase_btn).into_child();
 
@@ -119,6 +191,14 @@ impl PassphraseKeyboard {
             Button::with_icon(theme::ICON_CLOSE).styled(theme::button_keyboard_cancel());
         let cancel_btn = Maybe::visible(theme::BG, cancel_btn).into_child();
 
+        let space_warning = Button::with_text_and_subtext(
+            TString::empty(),
+            TR::passphrase__tap_to_trim.into(),
+            &theme::BUTTON_SUBTEXT,
+        )
+        .styled(theme::button_passphrase_warning())
+        .into_child();
+
         Self {
             page_swipe: Swipe::horizontal(),
             input: Input::new().into_child(),
@@ -127,6 +207,10 @@ impl PassphraseKeyboard {
                 theme::label_keyboard(),
             )
             .into_child(),
+            space_warning,
+            space_issue: None,
+            disabled_key: DisabledKeyFeedback::new(),
+            erase_double_tap: EraseDoubleTap::new(),
             erase_btn,
             cancel_btn,
             confirm_btn,
@@ -135,7 +219,9 @@ impl PassphraseKeyboard {
                 Child::new(
                     Button::new(Self::key_content(text))
                         .styled(theme::button_keyboard())
-                        .with_text_align(Alignment::Center),
+                        .with_text_align(Alignment::Center)
+                        .with_disabled_press()
+                        .with_debounce(KeyboardTimings::get().key_debounce),
                 )
             }),
             active_layout,
@@ -149,6 +235,7 @@ impl PassphraseKeyboard {
             ButtonContent::Icon(theme::ICON_SPECIAL_CHARS_GROUP) => " *#".into(),
             ButtonContent::Icon(_) => " ".into(),
             ButtonContent::IconAndText(_) => " ".into(),
+            ButtonContent::TextAndSubtext { .. } => " ".into(),
             ButtonContent::Empty => "".into(),
             ButtonContent::IconBlend(_, _, _) => "".into(),
         }
@@ -205,10 +292,56 @@ impl PassphraseKeyboard {
             btn.show_if(ctx, is_empty);
             btn.inner_mut().enable_if(ctx, is_empty);
         });
+        // Any edit dismisses the warning, it is checked again on confirm.
+        self.set_space_issue(ctx, None);
 
         self.update_input_btns_state(ctx);
     }
 
+    fn set_space_issue(&mut self, ctx: &mut EventCtx, issue: Option<SpaceIssue>) {
+        if self.space_issue == issue {
+            return;
+        }
+        self.space_issue = issue;
+        if let Some(issue) = issue {
+            self.space_warning.mutate(ctx, |ctx, btn| {
+                btn.set_content(
+                    ctx,
+                    ButtonContent::TextAndSubtext {
+                        text: issue.text(),
+                        subtext: TR::passphrase__tap_to_trim.into(),
+                        subtext_style: &theme::BUTTON_SUBTEXT,
+                    },
+                )
+            });
+        }
+        self.space_warning.request_complete_repaint(ctx);
+        self.input.request_complete_repaint(ctx);
+    }
+
+    /// The first confirm of a passphrase with a `SpaceIssue` only shows the
+    /// warning, confirming again accepts the passphrase as it is.
+    fn confirm(&mut self, ctx: &mut EventCtx) -> Option<PassphraseKeyboardMsg> {
+        if self.space_issue.is_none() {
+            if let Some(issue) = SpaceIssue::find(self.passphrase()) {
+                self.input
+                    .mutate(ctx, |ctx, i| i.multi_tap.clear_pending_state(ctx));
+                self.set_space_issue(ctx, Some(issue));
+                return None;
+            }
+        }
+        Some(PassphraseKeyboardMsg::Confirmed)
+    }
+
+    fn trim(&mut self, ctx: &mut EventCtx) {
+        let trimmed = trim_spaces(self.passphrase());
+        self.input.mutate(ctx, |ctx, i| {
+            i.multi_tap.clear_pending_state(ctx);
+            i.textbox.replace(ctx, &trimmed);
+        });
+        self.after_edit(ctx);
+    }
+
     /// When the input has reached max length, disable all the input buttons.
     fn update_input_btns_state(&mut self, ctx: &mut EventCtx) {
         let active_states = self.get_buttons_active_states();
@@ -250,6 +383,47 @@ impl PassphraseKeyboard {
     pub fn passphrase(&self) -> &str {
         self.input.inner().textbox.content()
     }
+
+    /// Character typed directly, regardless of the keyboard page shown, unless
+    /// the passphrase is full.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn type_char(&mut self, ctx: &mut EventCtx, ch: char) {
+        if self.input.inner().textbox.is_full() {
+            return;
+        }
+        self.input.mutate(ctx, |ctx, i| {
+            i.multi_tap.clear_pending_state(ctx);
+            i.textbox.append(ctx, ch);
+            i.reveal_last_char(ctx);
+        });
+        self.after_edit(ctx);
+    }
+
+    /// Key typed on the host keyboard acts like a click of the corresponding
+    /// button, as long as that button is enabled. Characters are typed
+    /// directly, regardless of the keyboard page shown.
+    #[cfg(feature = "host_keyboard")]
+    fn on_host_key(&mut self, ctx: &mut EventCtx, key: HostKey) -> Option<PassphraseKeyboardMsg> {
+        let enabled = |btn: &Maybe<Button>| btn.is_visible() && btn.inner().is_enabled();
+        match key {
+            HostKey::Char(ch) => self.type_char(ctx, ch),
+            HostKey::Backspace if enabled(self.erase_btn.inner()) => {
+                self.input.mutate(ctx, |ctx, i| {
+                    i.multi_tap.clear_pending_state(ctx);
+                    i.textbox.delete_last(ctx);
+                });
+                self.after_edit(ctx);
+            }
+            HostKey::Enter if self.confirm_btn.inner().is_enabled() => {
+                return self.confirm(ctx);
+            }
+            HostKey::Escape if enabled(self.cancel_btn.inner()) => {
+                return Some(PassphraseKeyboardMsg::Cancelled);
+            }
+            _ => {}
+        }
+        None
+    }
 }
 
 impl Component for PassphraseKeyboard {
@@ -258,7 +432,7 @@ impl Component for PassphraseKeyboard {
     fn place(&mut self, bounds: Rect) -> Rect {
         const CONFIRM_BTN_WIDTH: i16 = 78;
         const INPUT_INSETS: Insets = Insets::new(10, 2, 10, 4);
-        const CONFIRM_BTN_INSETS: Insets = Insets::new(5, 0, 5, 0);
+        const CONFIRM_BTN_INSETS: Insets = Insets::symmetric(0, 5);
 
         let bounds = bounds.inset(theme::borders());
         let (top_area, key_grid_area) =
@@ -275,7 +449,10 @@ impl Component for PassphraseKeyboard {
 
         self.page_swipe.place(bounds);
         self.input.place(input_area);
+        self.disabled_key.place(input_area);
+        self.erase_double_tap.place(input_area);
         self.input_prompt.place(top_area);
+        self.space_warning.place(input_area);
 
         // control buttons
         self.confirm_btn.place(confirm_btn_area);
@@ -295,12 +472,36 @@ impl Component for PassphraseKeyboard {
                 key + 1
             });
             btn.place(area);
+            #[cfg(feature = "ui_debug")]
+            debug_target::register(
+                &[
+                    "passphrase",
+                    "key",
+                    strutil::format_int(key as i64).as_str(),
+                ],
+                area,
+            );
+        }
+
+        #[cfg(feature = "ui_debug")]
+        {
+            debug_target::register(&["confirm"], confirm_btn_area);
+            debug_target::register(&["trim"], input_area);
+            debug_target::register(&["next"], next_btn_area);
+            debug_target::register(&["erase"], erase_cancel_area);
+            debug_target::register(&["cancel"], erase_cancel_area);
         }
 
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        #[cfg(feature = "host_keyboard")]
+        if let Event::HostKey(key) = event {
+            return self.on_host_key(ctx, key);
+        }
+        self.disabled_key.event(ctx, event);
+        self.erase_double_tap.event(ctx, event);
         if self.input.inner().multi_tap.is_timeout_event(event) {
             self.input
                 .mutate(ctx, |ctx, i| i.multi_tap.clear_pending_state(ctx));
@@ -315,16 +516,33 @@ impl Component for PassphraseKeyboard {
             self.on_page_change(ctx, SwipeDirection::Left);
         }
         if let Some(ButtonMsg::Clicked) = self.confirm_btn.event(ctx, event) {
-            // Confirm button was clicked, we're done.
-            return Some(PassphraseKeyboardMsg::Confirmed);
+            // Confirm button was clicked, we're done unless there is a warning to show.
+            return self.confirm(ctx);
+        }
+        if self.space_issue.is_some() {
+            if let Some(ButtonMsg::Clicked) = self.space_warning.event(ctx, event) {
+                self.trim(ctx);
+                return None;
+            }
+        } else {
+            // Tapping the input hides or shows the passphrase.
+            self.input.event(ctx, event);
         }
         if let Some(ButtonMsg::Clicked) = self.cancel_btn.event(ctx, event) {
-            // Cancel button is visible and clicked, cancel
-            return Some(PassphraseKeyboardMsg::Cancelled);
+            // Cancel button is visible and clicked, cancel. Unless it's the double tap of
+            // erase that emptied the input.
+            if !self.erase_double_tap.swallows_tap() {
+                return Some(PassphraseKeyboardMsg::Cancelled);
+            }
         }
 
         match self.erase_btn.event(ctx, event) {
+            Some(ButtonMsg::Pressed) if self.erase_btn.inner().inner().awaits_double_click() => {
+                self.erase_double_tap.trigger(ctx);
+                return None;
+            }
             Some(ButtonMsg::Clicked) => {
+                self.erase_double_tap.erased();
                 self.input.mutate(ctx, |ctx, i| {
                     i.multi_tap.clear_pending_state(ctx);
                     i.textbox.delete_last(ctx);
@@ -332,7 +550,9 @@ impl Component for PassphraseKeyboard {
                 self.after_edit(ctx);
                 return None;
             }
-            Some(ButtonMsg::LongPressed) => {
+            // The first click already erased a character, clear the rest.
+            Some(ButtonMsg::LongPressed | ButtonMsg::DoubleClicked) => {
+                self.erase_double_tap.erased();
                 self.input.mutate(ctx, |ctx, i| {
                     i.multi_tap.clear_pending_state(ctx);
                     i.textbox.clear(ctx);
@@ -349,17 +569,23 @@ impl Component for PassphraseKeyboard {
         // Also we need to allow for cycling through the last character.
         let active_states = self.get_buttons_active_states();
         for (key, btn) in self.keys.iter_mut().enumerate() {
+            let msg = btn.event(ctx, event);
             if !active_states[key] {
-                // Button is not active
+                // Button is not active, just let the user know.
+                if let Some(ButtonMsg::PressedDisabled) = msg {
+                    self.disabled_key.trigger(ctx);
+                    return None;
+                }
                 continue;
             }
-            if let Some(ButtonMsg::Clicked) = btn.event(ctx, event) {
+            if let Some(ButtonMsg::Clicked) = msg {
                 // Key button was clicked. If this button is pending, let's cycle the pending
                 // character in textbox. If not, let's just append the first character.
                 let text = Self::key_text(btn.inner().content());
                 self.input.mutate(ctx, |ctx, i| {
                     let edit = text.map(|c| i.multi_tap.click_key(ctx, key, c));
                     i.textbox.apply(ctx, edit);
+                    i.reveal_last_char(ctx);
                 });
                 self.after_edit(ctx);
                 return None;
@@ -373,7 +599,13 @@ impl Component for PassphraseKeyboard {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        self.input.render(target);
+        if self.space_issue.is_some() {
+            self.space_warning.render(target);
+        } else {
+            self.disabled_key
+                .render(target, &|target| self.input.render(target));
+        }
+        self.erase_double_tap.render(target);
         self.next_btn.render(target);
         self.erase_btn.render(target);
         self.confirm_btn.render(target);
@@ -395,6 +627,7 @@ impl Component for PassphraseKeyboard {
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         self.input.bounds(sink);
         self.input_prompt.bounds(sink);
+        self.space_warning.bounds(sink);
         self.confirm_btn.bounds(sink);
         self.erase_btn.bounds(sink);
         self.cancel_btn.bounds(sink);
@@ -408,6 +641,14 @@ struct Input {
     area: Rect,
     textbox: TextBox<MAX_LENGTH>,
     multi_tap: MultiTapKeyboard,
+    /// Passphrase shown as `*`, toggled by tapping the input.
+    hidden: bool,
+    /// Whether the current touch started in the input.
+    pressed: bool,
+    /// Running while the last typed character of the hidden passphrase is
+    /// still readable.
+    last_char_timer: Timer,
+    last_char_reveal: Duration,
 }
 
 impl Input {
@@ -416,6 +657,29 @@ impl Input {
             area: Rect::zero(),
             textbox: TextBox::empty(),
             multi_tap: MultiTapKeyboard::new(),
+            hidden: false,
+            pressed: false,
+            last_char_timer: Timer::new(),
+            last_char_reveal: KeyboardTimings::get().last_char_reveal,
+        }
+    }
+
+    /// Keep the character just typed readable for a while if the passphrase
+    /// is hidden.
+    fn reveal_last_char(&mut self, ctx: &mut EventCtx) {
+        if self.hidden && self.last_char_reveal != Duration::ZERO {
+            self.last_char_timer.start(ctx, self.last_char_reveal);
+        }
+    }
+
+    /// Text to be shown, masked if hidden. The pending character of multi-tap
+    /// stays readable while it can still change.
+    fn display_text(&self) -> String<MAX_LENGTH> {
+        let show_last = self.last_char_timer.is_running() || self.multi_tap.pending_key().is_some();
+        if self.hidden {
+            self.textbox.masked_content(show_last)
+        } else {
+            unwrap!(String::try_from(self.textbox.content()))
         }
     }
 }
@@ -428,7 +692,23 @@ impl Component for Input {
         self.area
     }
 
-    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => {
+                self.pressed = self.area.contains(pos);
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) => {
+                if mem::take(&mut self.pressed) && self.area.contains(pos) {
+                    self.hidden = !self.hidden;
+                    self.last_char_timer.stop(ctx);
+                    ctx.request_paint();
+                }
+            }
+            _ => {}
+        }
+        if self.last_char_timer.expire(event) {
+            ctx.request_paint();
+        }
         None
     }
 
@@ -442,7 +722,7 @@ impl Component for Input {
         let text_baseline = self.area.top_left() + Offset::y(style.text_font.text_height())
             - Offset::y(style.text_font.text_baseline());
 
-        let text = self.textbox.content();
+        let text = self.display_text();
 
         shape::Bar::new(self.area).with_bg(theme::BG).render(target);
 
@@ -451,7 +731,7 @@ impl Component for Input {
         // the last character
         let available_area_width = self.area.width() - 1;
         let text_to_display =
-            long_line_content_with_ellipsis(text, "...", style.text_font, available_area_width);
+            long_line_content_with_ellipsis(&text, "...", style.text_font, available_area_width);
 
         shape::Text::new(text_baseline, &text_to_display)
             .with_font(style.text_font)
@@ -476,10 +756,49 @@ impl Component for Input {
     }
 }
 
+#[cfg(feature = "ui_debug")]
+impl crate::ui::layout::obj::DebugKeyboard for PassphraseKeyboard {
+    fn debug_text(&self) -> &str {
+        self.passphrase()
+    }
+
+    fn debug_type(&mut self, ctx: &mut EventCtx, ch: char) {
+        self.type_char(ctx, ch);
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl crate::trace::Trace for PassphraseKeyboard {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("PassphraseKeyboard");
         t.string("passphrase", self.passphrase().into());
+        if let Some(issue) = self.space_issue {
+            t.string("space_warning", issue.name().into());
+        }
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_issues() {
+        assert!(SpaceIssue::find("").is_none());
+        assert!(SpaceIssue::find("abc 123").is_none());
+        assert!(SpaceIssue::find(" abc") == Some(SpaceIssue::Leading));
+        assert!(SpaceIssue::find(" abc ") == Some(SpaceIssue::Leading));
+        assert!(SpaceIssue::find("abc ") == Some(SpaceIssue::Trailing));
+        assert!(SpaceIssue::find("abc  123") == Some(SpaceIssue::Repeated));
+    }
+
+    #[test]
+    fn trimming() {
+        assert_eq!(trim_spaces("  abc   1 2  ").as_str(), "abc 1 2");
+        assert_eq!(trim_spaces("abc 123").as_str(), "abc 123");
+        assert_eq!(trim_spaces("   ").as_str(), "");
     }
 }
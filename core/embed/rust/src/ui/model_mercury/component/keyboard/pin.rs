@@ -1,60 +1,97 @@
-use core::mem;
+use core::{cell::Cell, mem};
 use heapless::String;
 
 use crate::{
     strutil::TString,
     time::Duration,
-    trezorhal::random,
     ui::{
         component::{
-            base::ComponentExt, text::TextStyle, Child, Component, Event, EventCtx, Label, Maybe,
-            Never, Pad, TimerToken,
+            base::ComponentExt,
+            text::{common::KeyboardTimings, TextStyle},
+            CachedArea, Child, Component, Event, EventCtx, Label, Maybe, MsgMap, Never, Pad, Timer,
         },
-        display::Font,
+        display::{Color, Font},
         event::TouchEvent,
-        geometry::{Alignment, Alignment2D, Grid, Insets, Offset, Rect},
+        geometry::{Alignment, Alignment2D, Grid, Insets, Offset, Point, Rect},
+        led,
         model_mercury::component::{
-            button::{Button, ButtonContent, ButtonMsg, ButtonMsg::Clicked},
+            button::{closest_touch_target, Button, ButtonContent, ButtonMsg, ButtonMsg::Clicked},
+            keyboard::common::{DisabledKeyFeedback, EraseDoubleTap, ERASE_DOUBLE_TAP},
             theme,
         },
-        shape,
+        random, shape,
         shape::Renderer,
     },
 };
 
+#[cfg(feature = "ui_debug")]
+use crate::ui::component::debug_target;
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
+
 pub enum PinKeyboardMsg {
     Confirmed,
     Cancelled,
 }
 
+/// Translates a click of a control button to the keyboard message.
+type ClickMap = fn(ButtonMsg) -> Option<PinKeyboardMsg>;
+type ControlButton<T> = MsgMap<T, ClickMap>;
+
 const MAX_LENGTH: usize = 50;
 const MAX_VISIBLE_DOTS: usize = 18;
+/// Sizes of the dots of `PinDots` shrinking to fit longer PINs, from the
+/// largest one.
+const DOT_SIZES: [DotSize; 3] = [
+    DotSize {
+        dot: 6,
+        padding: 7,
+        max_visible: MAX_VISIBLE_DOTS,
+    },
+    DotSize {
+        dot: 5,
+        padding: 4,
+        max_visible: 25,
+    },
+    DotSize {
+        dot: 4,
+        padding: 3,
+        max_visible: 30,
+    },
+];
 const MAX_VISIBLE_DIGITS: usize = 18;
 const DIGIT_COUNT: usize = 10; // 0..10
 
-const HEADER_PADDING_TOP: i16 = 4;
 const HEADER_PADDING_SIDE: i16 = 2;
-const HEADER_PADDING_BOTTOM: i16 = 4;
+const HEADER_PADDING_VERTICAL: i16 = 4;
+
+/// Erase, cancel and confirm buttons accept touches slightly outside of them.
+const CONTROL_TOUCH_EXPAND: Insets = Insets::uniform(6);
 
-const HEADER_PADDING: Insets = Insets::new(
-    HEADER_PADDING_TOP,
-    HEADER_PADDING_SIDE,
-    HEADER_PADDING_BOTTOM,
-    HEADER_PADDING_SIDE,
-);
+/// Erase and cancel buttons swap places by fading in and out.
+const CONTROL_FADE_DURATION: Duration = Duration::from_millis(200);
+
+/// Remaining attempts at or below which the counter is shown as a warning.
+const ATTEMPTS_WARNING: u16 = 3;
+
+const HEADER_PADDING: Insets = Insets::symmetric(HEADER_PADDING_SIDE, HEADER_PADDING_VERTICAL);
 
 pub struct PinKeyboard<'a> {
     allow_cancel: bool,
+    attempts_remaining: Option<u16>,
+    wipe_code: bool,
     major_prompt: Child<Label<'a>>,
     minor_prompt: Child<Label<'a>>,
     major_warning: Option<Child<Label<'a>>>,
     textbox: Child<PinDots>,
     textbox_pad: Pad,
     erase_btn: Child<Maybe<Button>>,
-    cancel_btn: Child<Maybe<Button>>,
-    confirm_btn: Child<Button>,
-    digit_btns: [Child<Button>; DIGIT_COUNT],
-    warning_timer: Option<TimerToken>,
+    cancel_btn: Child<ControlButton<Maybe<Button>>>,
+    confirm_btn: Child<ControlButton<Button>>,
+    digit_btns: CachedArea<DigitButtons>,
+    warning_timer: Timer,
+    disabled_key: DisabledKeyFeedback,
+    erase_double_tap: EraseDoubleTap,
 }
 
 impl<'a> PinKeyboard<'a> {
@@ -63,58 +100,83 @@ impl<'a> PinKeyboard<'a> {
         minor_prompt: TString<'a>,
         major_warning: Option<TString<'a>>,
         allow_cancel: bool,
+        attempts_remaining: Option<u16>,
+        wipe_code: bool,
     ) -> Self {
         // Control buttons.
         let erase_btn = Button::with_icon(theme::ICON_DELETE)
             .styled(theme::button_keyboard_erase())
-            .with_long_press(theme::ERASE_HOLD_DURATION)
+            .with_long_press(KeyboardTimings::get().erase_hold)
+            .with_eager_double_press(ERASE_DOUBLE_TAP)
+            .with_expanded_touch_area(CONTROL_TOUCH_EXPAND)
             .initially_enabled(false);
-        let erase_btn = Maybe::hidden(theme::BG, erase_btn).into_child();
+        let erase_btn = Maybe::hidden(theme::BG, erase_btn)
+            .with_fade(CONTROL_FADE_DURATION)
+            .into_child();
 
-        let cancel_btn =
-            Button::with_icon(theme::ICON_CLOSE).styled(theme::button_keyboard_cancel());
-        let cancel_btn = Maybe::new(theme::BG, cancel_btn, allow_cancel).into_child();
+        let cancel_btn = Button::with_icon(theme::ICON_CLOSE)
+            .styled(theme::button_keyboard_cancel())
+            .with_expanded_touch_area(CONTROL_TOUCH_EXPAND);
+        let cancel_btn = Maybe::new(theme::BG, cancel_btn, allow_cancel)
+            .with_fade(CONTROL_FADE_DURATION)
+            .map((|msg| matches!(msg, Clicked).then_some(PinKeyboardMsg::Cancelled)) as ClickMap)
+            .into_child();
+
+        // A wipe code is not a PIN, make that apparent from the prompt.
+        let major_style = if wipe_code {
+            theme::label_keyboard_warning()
+        } else {
+            theme::label_keyboard()
+        };
+        let minor_style = match attempts_remaining {
+            Some(attempts) if attempts <= ATTEMPTS_WARNING => theme::label_keyboard_warning(),
+            _ => theme::label_keyboard_minor(),
+        };
 
         Self {
             allow_cancel,
-            major_prompt: Label::left_aligned(major_prompt, theme::label_keyboard()).into_child(),
-            minor_prompt: Label::right_aligned(minor_prompt, theme::label_keyboard_minor())
+            attempts_remaining,
+            wipe_code,
+            major_prompt: Label::left_aligned(major_prompt, major_style)
+                .with_auto_fit(&[Font::DEMIBOLD, Font::NORMAL])
+                .vertically_centered()
+                .into_child(),
+            minor_prompt: Label::right_aligned(minor_prompt, minor_style)
+                .vertically_centered()
                 .into_child(),
             major_warning: major_warning.map(|text| {
-                Label::left_aligned(text, theme::label_keyboard_warning()).into_child()
+                Label::left_aligned(text, theme::label_keyboard_warning())
+                    .vertically_centered()
+                    .into_child()
             }),
-            textbox: PinDots::new(theme::label_default()).into_child(),
-            textbox_pad: Pad::with_background(theme::label_default().background_color),
+            textbox: PinDots::new(theme::label_default())
+                .with_alignment(Alignment::Center)
+                .with_shrinking_dots()
+                .into_child(),
+            textbox_pad: textbox_pad(),
             erase_btn,
             cancel_btn,
             confirm_btn: Button::with_icon(theme::ICON_CONFIRM)
                 .styled(theme::button_pin_confirm())
+                .with_expanded_touch_area(CONTROL_TOUCH_EXPAND)
                 .initially_enabled(false)
+                .map(
+                    (|msg| matches!(msg, Clicked).then_some(PinKeyboardMsg::Confirmed)) as ClickMap,
+                )
                 .into_child(),
-            digit_btns: Self::generate_digit_buttons(),
-            warning_timer: None,
+            digit_btns: CachedArea::new(theme::BG, DigitButtons::generate()),
+            warning_timer: Timer::new(),
+            disabled_key: DisabledKeyFeedback::new(),
+            erase_double_tap: EraseDoubleTap::new(),
         }
     }
 
-    fn generate_digit_buttons() -> [Child<Button>; DIGIT_COUNT] {
-        // Generate a random sequence of digits from 0 to 9.
-        let mut digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
-        random::shuffle(&mut digits);
-        digits
-            .map(|c| Button::with_text(c.into()))
-            .map(|b| {
-                b.styled(theme::button_keyboard())
-                    .with_text_align(Alignment::Center)
-            })
-            .map(Child::new)
-    }
-
     fn pin_modified(&mut self, ctx: &mut EventCtx) {
         let is_full = self.textbox.inner().is_full();
         let is_empty = self.textbox.inner().is_empty();
 
+        // The textbox has already requested repainting of the dots that changed.
         self.textbox_pad.clear();
-        self.textbox.request_complete_repaint(ctx);
 
         if is_empty {
             self.major_prompt.request_complete_repaint(ctx);
@@ -123,24 +185,88 @@ impl<'a> PinKeyboard<'a> {
         }
 
         let cancel_enabled = is_empty && self.allow_cancel;
-        for btn in &mut self.digit_btns {
-            btn.mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_full));
-        }
+        // Only re-renders the cached digits if their state actually changes. A
+        // just released digit must not be reset, that would refresh the cache
+        // after every click.
+        self.digit_btns.mutate(ctx, |ctx, digits| {
+            for btn in &mut digits.0 {
+                if btn.inner().is_disabled() != is_full {
+                    btn.mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_full));
+                }
+            }
+        });
         self.erase_btn.mutate(ctx, |ctx, btn| {
             btn.show_if(ctx, !is_empty);
             btn.inner_mut().enable_if(ctx, !is_empty);
         });
         self.cancel_btn.mutate(ctx, |ctx, btn| {
+            let btn = btn.inner_mut();
             btn.show_if(ctx, cancel_enabled);
             btn.inner_mut().enable_if(ctx, is_empty);
         });
         self.confirm_btn
-            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_empty));
+            .mutate(ctx, |ctx, btn| btn.inner_mut().enable_if(ctx, !is_empty));
     }
 
     pub fn pin(&self) -> &str {
         self.textbox.inner().pin()
     }
+
+    /// Digit `ch` acts like a click of the corresponding button, as long as
+    /// the PIN is not full.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn type_char(&mut self, ctx: &mut EventCtx, ch: char) {
+        if ch.is_ascii_digit() && !self.textbox.inner().is_full() {
+            let mut buf = [0; 1];
+            let text = ch.encode_utf8(&mut buf);
+            self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
+            self.pin_modified(ctx);
+        }
+    }
+
+    /// Key typed on the host keyboard acts like a click of the corresponding
+    /// button, as long as that button is enabled.
+    #[cfg(feature = "host_keyboard")]
+    fn on_host_key(&mut self, ctx: &mut EventCtx, key: HostKey) -> Option<PinKeyboardMsg> {
+        let enabled = |btn: &Maybe<Button>| btn.is_visible() && btn.inner().is_enabled();
+        match key {
+            HostKey::Char(ch) => self.type_char(ctx, ch),
+            HostKey::Backspace if enabled(self.erase_btn.inner()) => {
+                self.textbox.mutate(ctx, |ctx, t| t.pop(ctx));
+                self.pin_modified(ctx);
+            }
+            HostKey::Enter if self.confirm_btn.inner().inner().is_enabled() => {
+                led::signal_attention(ctx);
+                return Some(PinKeyboardMsg::Confirmed);
+            }
+            HostKey::Escape if enabled(self.cancel_btn.inner().inner()) => {
+                return Some(PinKeyboardMsg::Cancelled);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Button a touch starting at `pos` belongs to, as an index into
+    /// `[confirm, cancel, erase, digits..]`. Needed because the expanded touch
+    /// areas of the control buttons reach over the neighboring digits.
+    fn touch_target(&self, pos: Point) -> Option<usize> {
+        let visible = |btn: &Maybe<Button>| Some(btn.inner()).filter(|_| btn.is_visible());
+        let buttons = [
+            Some(self.confirm_btn.inner().inner()),
+            visible(self.cancel_btn.inner().inner()),
+            visible(self.erase_btn.inner()),
+        ]
+        .into_iter()
+        .chain(
+            self.digit_btns
+                .inner()
+                .0
+                .iter()
+                .map(|btn| Some(btn.inner())),
+        );
+        closest_touch_target(buttons, pos)
+    }
 }
 
 impl Component for PinKeyboard<'_> {
@@ -150,14 +276,16 @@ impl Component for PinKeyboard<'_> {
         // Prompts and PIN dots display.
         let (header, keypad) =
             bounds.split_bottom(4 * theme::PIN_BUTTON_HEIGHT + 3 * theme::BUTTON_SPACING);
-        let prompt = header.inset(HEADER_PADDING);
+        let prompt = header.inset(Insets::sides(HEADER_PADDING_SIDE));
 
         // Control buttons.
-        let grid = Grid::new(keypad, 4, 3).with_spacing(theme::BUTTON_SPACING);
+        let grid = keypad_grid(keypad);
 
         // Prompts and PIN dots display.
         self.textbox_pad.place(header);
         self.textbox.place(header);
+        self.disabled_key.place(header);
+        self.erase_double_tap.place(header);
         self.major_prompt.place(prompt);
         self.minor_prompt.place(prompt);
         self.major_warning.as_mut().map(|c| c.place(prompt));
@@ -169,15 +297,14 @@ impl Component for PinKeyboard<'_> {
         self.confirm_btn.place(grid.row_col(3, 2));
 
         // Digit buttons.
-        for (i, btn) in self.digit_btns.iter_mut().enumerate() {
-            // Assign the digits to buttons on a 4x3 grid, starting from the first row.
-            let area = grid.cell(if i < 9 {
-                i
-            } else {
-                // For the last key (the "0" position) we skip one cell.
-                i + 1
-            });
-            btn.place(area);
+        self.digit_btns.place(keypad);
+
+        #[cfg(feature = "ui_debug")]
+        {
+            debug_target::register(&["erase"], erase_cancel_area);
+            debug_target::register(&["cancel"], erase_cancel_area);
+            debug_target::register(&["confirm"], grid.row_col(3, 2));
+            self.digit_btns.inner().register_debug_targets("pin");
         }
 
         bounds
@@ -187,47 +314,105 @@ impl Component for PinKeyboard<'_> {
         match event {
             // Set up timer to switch off warning prompt.
             Event::Attach(_) if self.major_warning.is_some() => {
-                self.warning_timer = Some(ctx.request_timer(Duration::from_secs(2)));
+                led::signal_warning(ctx);
+                self.warning_timer.start(ctx, Duration::from_secs(2));
             }
             // Hide warning, show major prompt.
-            Event::Timer(token) if Some(token) == self.warning_timer => {
+            Event::Timer(_) if self.warning_timer.expire(event) => {
                 self.major_warning = None;
                 self.textbox_pad.clear();
                 self.minor_prompt.request_complete_repaint(ctx);
                 ctx.request_paint();
             }
+            #[cfg(feature = "host_keyboard")]
+            Event::HostKey(key) => return self.on_host_key(ctx, key),
             _ => {}
         }
 
         self.textbox.event(ctx, event);
-        if let Some(Clicked) = self.confirm_btn.event(ctx, event) {
-            return Some(PinKeyboardMsg::Confirmed);
+        self.disabled_key.event(ctx, event);
+        self.erase_double_tap.event(ctx, event);
+
+        // A starting touch only goes to the one button it belongs to, so that
+        // overlapping touch areas don't press two buttons at once. A touch not
+        // belonging to any enabled button goes to the digits, a disabled one
+        // reports it.
+        let touch_target = match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => Some(self.touch_target(pos)),
+            _ => None,
+        };
+        let receives = |index: usize| {
+            touch_target.map_or(true, |target| {
+                target == Some(index) || (target.is_none() && index >= 3)
+            })
+        };
+
+        if receives(0) {
+            if let Some(msg) = self.confirm_btn.event(ctx, event) {
+                led::signal_attention(ctx);
+                return Some(msg);
+            }
         }
-        if let Some(Clicked) = self.cancel_btn.event(ctx, event) {
-            return Some(PinKeyboardMsg::Cancelled);
+        if receives(1) {
+            if let Some(msg) = self.cancel_btn.event(ctx, event) {
+                // Double tap of erase that emptied the PIN ends up on cancel.
+                if !self.erase_double_tap.swallows_tap() {
+                    return Some(msg);
+                }
+            }
         }
-        match self.erase_btn.event(ctx, event) {
+        let erase_msg = if receives(2) {
+            self.erase_btn.event(ctx, event)
+        } else {
+            None
+        };
+        match erase_msg {
+            Some(ButtonMsg::Pressed) if self.erase_btn.inner().inner().awaits_double_click() => {
+                self.erase_double_tap.trigger(ctx);
+                return None;
+            }
             Some(ButtonMsg::Clicked) => {
+                self.erase_double_tap.erased();
                 self.textbox.mutate(ctx, |ctx, t| t.pop(ctx));
                 self.pin_modified(ctx);
                 return None;
             }
-            Some(ButtonMsg::LongPressed) => {
+            // The first click already erased a digit, clear the rest.
+            Some(ButtonMsg::LongPressed | ButtonMsg::DoubleClicked) => {
+                self.erase_double_tap.erased();
                 self.textbox.mutate(ctx, |ctx, t| t.clear(ctx));
                 self.pin_modified(ctx);
                 return None;
             }
             _ => {}
         }
-        for btn in &mut self.digit_btns {
-            if let Some(Clicked) = btn.event(ctx, event) {
-                if let ButtonContent::Text(text) = btn.inner().content() {
-                    text.map(|text| {
-                        self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
-                    });
-                    self.pin_modified(ctx);
+        let mut pressed_disabled = false;
+        // Pressing a digit only changes its look until it is released, the
+        // pressed button is rendered over the cached keypad.
+        let clicked = self.digit_btns.mutate_transient(ctx, |ctx, digits| {
+            digits.0.iter_mut().enumerate().find_map(|(i, btn)| {
+                if !receives(3 + i) {
                     return None;
                 }
+                match btn.event(ctx, event) {
+                    Some(Clicked) => Some(i),
+                    Some(ButtonMsg::PressedDisabled) => {
+                        pressed_disabled = true;
+                        None
+                    }
+                    _ => None,
+                }
+            })
+        });
+        if pressed_disabled {
+            self.disabled_key.trigger(ctx);
+        }
+        if let Some(i) = clicked {
+            if let ButtonContent::Text(text) = self.digit_btns.inner().0[i].inner().content() {
+                text.map(|text| {
+                    self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
+                });
+                self.pin_modified(ctx);
             }
         }
         None
@@ -238,6 +423,15 @@ impl Component for PinKeyboard<'_> {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        // The cached digits cover the whole keypad including the cells of the
+        // control buttons, so they go first.
+        self.digit_btns.render(target);
+        for btn in self.digit_btns.inner().0.iter() {
+            if btn.inner().is_pressed() {
+                btn.render(target);
+            }
+        }
+
         self.erase_btn.render(target);
         self.textbox_pad.render(target);
 
@@ -248,16 +442,19 @@ impl Component for PinKeyboard<'_> {
                 self.major_prompt.render(target);
             }
             self.minor_prompt.render(target);
-            self.cancel_btn.render(target);
+            // Not drawn while hidden, so that the erase button below can fade out when
+            // cancel isn't allowed.
+            let cancel = self.cancel_btn.inner().inner();
+            if cancel.is_visible() || cancel.is_fading() {
+                self.cancel_btn.render(target);
+            }
         } else {
-            self.textbox.render(target);
+            self.disabled_key
+                .render(target, &|target| self.textbox.render(target));
         }
+        self.erase_double_tap.render(target);
 
         self.confirm_btn.render(target);
-
-        for btn in &self.digit_btns {
-            btn.render(target);
-        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -268,7 +465,98 @@ impl Component for PinKeyboard<'_> {
         self.cancel_btn.bounds(sink);
         self.confirm_btn.bounds(sink);
         self.textbox.bounds(sink);
-        for b in &self.digit_btns {
+        self.digit_btns.bounds(sink);
+    }
+}
+
+pub fn keypad_grid(keypad: Rect) -> Grid {
+    Grid::new(keypad, 4, 3).with_spacing(theme::BUTTON_SPACING)
+}
+
+/// Digit buttons laid out on the keypad grid. Their events are routed
+/// individually by the keyboard owning them.
+pub struct DigitButtons(pub [Child<Button>; DIGIT_COUNT]);
+
+impl DigitButtons {
+    /// Digits in a random order, as the PIN must not be guessable from the
+    /// positions of the touches.
+    fn generate() -> Self {
+        // Generate a random sequence of digits from 0 to 9.
+        let mut digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        random::shuffle(&mut digits);
+        // Digits are disabled when the PIN is full, make that noticeable.
+        Self::with_digits(digits, true)
+    }
+
+    /// Digits in the order of a phone keypad, with 0 in the bottom row.
+    pub fn ordered() -> Self {
+        Self::with_digits(["1", "2", "3", "4", "5", "6", "7", "8", "9", "0"], false)
+    }
+
+    /// Register the buttons as debug targets named `<prefix>/digit/<digit>`.
+    #[cfg(feature = "ui_debug")]
+    pub fn register_debug_targets(&self, prefix: &str) {
+        for btn in &self.0 {
+            let btn = btn.inner();
+            if let ButtonContent::Text(text) = btn.content() {
+                text.map(|digit| debug_target::register(&[prefix, "digit", digit], btn.area()));
+            }
+        }
+    }
+
+    fn with_digits(digits: [&'static str; DIGIT_COUNT], report_disabled: bool) -> Self {
+        Self(
+            digits
+                .map(|c| Button::with_text(c.into()))
+                .map(|b| {
+                    let b = b
+                        .styled(theme::button_keyboard())
+                        .with_text_align(Alignment::Center)
+                        .with_debounce(KeyboardTimings::get().key_debounce);
+                    if report_disabled {
+                        b.with_disabled_press()
+                    } else {
+                        b
+                    }
+                })
+                .map(Child::new),
+        )
+    }
+}
+
+impl Component for DigitButtons {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let grid = keypad_grid(bounds);
+        for (i, btn) in self.0.iter_mut().enumerate() {
+            // Assign the digits to buttons on a 4x3 grid, starting from the first row.
+            let area = grid.cell(if i < 9 {
+                i
+            } else {
+                // For the last key (the "0" position) we skip one cell.
+                i + 1
+            });
+            btn.place(area);
+        }
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {}
+
+    fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        for btn in &self.0 {
+            btn.render(target);
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        for b in &self.0 {
             b.bounds(sink)
         }
     }
@@ -280,28 +568,167 @@ struct PinDots {
     style: TextStyle,
     digits: String<MAX_LENGTH>,
     display_digits: bool,
+    /// Horizontal placement of the dots and digits in the header.
+    alignment: Alignment,
+    /// Make the dots smaller instead of hiding the oldest ones when they do not
+    /// fit.
+    shrink: bool,
+    dot_size: DotSize,
+    /// State of the last render, to repaint only the dots that changed since.
+    rendered: Cell<Option<DotsState>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct DotsState {
+    area: Rect,
+    color: Color,
+    digits: usize,
+    display_digits: bool,
+    dot_size: DotSize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct DotSize {
+    /// Diameter of a dot.
+    dot: i16,
+    /// Space between two dots.
+    padding: i16,
+    /// Number of dots shown at most, the oldest digits are hidden beyond that.
+    max_visible: usize,
+}
+
+/// Background of the prompts and the PIN.
+pub fn textbox_pad() -> Pad {
+    if theme::KEYBOARD_TEXTBOX_ROUNDED {
+        Pad::with_background(theme::GREY_EXTRA_DARK).with_radius(theme::KEYBOARD_TEXTBOX_RADIUS)
+    } else {
+        Pad::with_background(theme::label_default().background_color)
+    }
 }
 
 impl PinDots {
-    const DOT: i16 = 6;
-    const PADDING: i16 = 7;
     const TWITCH: i16 = 4;
 
     fn new(style: TextStyle) -> Self {
         Self {
             area: Rect::zero(),
-            pad: Pad::with_background(style.background_color),
+            pad: textbox_pad(),
             style,
             digits: String::new(),
             display_digits: false,
+            alignment: Alignment::Start,
+            shrink: false,
+            dot_size: DOT_SIZES[0],
+            rendered: Cell::new(None),
+        }
+    }
+
+    fn with_alignment(self, alignment: Alignment) -> Self {
+        Self { alignment, ..self }
+    }
+
+    fn with_shrinking_dots(self) -> Self {
+        Self {
+            shrink: true,
+            ..self
+        }
+    }
+
+    fn state(&self) -> DotsState {
+        DotsState {
+            area: self.area,
+            color: self.style.text_color,
+            digits: self.digits.len(),
+            display_digits: self.display_digits,
+            dot_size: self.dot_size,
         }
     }
 
+    /// Left end of a row `width` pixels wide placed in `area` according to
+    /// `self.alignment`.
+    fn row_start(&self, area: Rect, width: i16) -> Point {
+        match self.alignment {
+            Alignment::Start => area.left_center(),
+            Alignment::Center => area.center() - Offset::x(width / 2),
+            Alignment::End => area.right_center() - Offset::x(width),
+        }
+    }
+
+    /// Area of the `index`-th dot of a row of `ndots`, as long as the dots are
+    /// not overflowing.
+    fn dot_rect(&self, index: usize, ndots: usize) -> Rect {
+        let step = self.dot_size.dot + self.dot_size.padding;
+        let row = self.row_start(self.area.inset(HEADER_PADDING), self.row_width(ndots));
+        Rect::snap(
+            row + Offset::x(step * index as i16),
+            Offset::uniform(self.dot_size.dot),
+            Alignment2D::CENTER_LEFT,
+        )
+    }
+
+    /// Part of the area to repaint after the number of digits changed. Just
+    /// the dots appended or removed since the last render if nothing else
+    /// changed, the whole area otherwise.
+    fn damage(&self) -> Rect {
+        let current = self.state();
+        let Some(rendered) = self.rendered.get() else {
+            return self.area;
+        };
+        let lo = rendered.digits.min(current.digits);
+        let hi = rendered.digits.max(current.digits);
+        let only_count_changed = DotsState {
+            digits: current.digits,
+            ..rendered
+        } == current;
+        // Overflowing dots all move, and the first dot replaces the prompts.
+        if !only_count_changed
+            || current.display_digits
+            || lo == 0
+            || lo == hi
+            || hi > self.dot_size.max_visible
+        {
+            return self.area;
+        }
+        match self.alignment {
+            Alignment::Start => self.dot_rect(lo, hi).union(self.dot_rect(hi - 1, hi)),
+            // The whole row moves, the shorter one is within the longer one.
+            _ => self.dot_rect(0, hi).union(self.dot_rect(hi - 1, hi)),
+        }
+    }
+
+    /// Pick the largest dots that fit all the digits, or the smallest ones if
+    /// none do.
+    fn update_dot_size(&mut self) {
+        let digits = self.digits.len();
+        self.dot_size = if self.shrink {
+            DOT_SIZES
+                .iter()
+                .find(|size| digits <= size.max_visible)
+                .copied()
+                .unwrap_or(DOT_SIZES[DOT_SIZES.len() - 1])
+        } else {
+            DOT_SIZES[0]
+        };
+    }
+
+    fn request_damage(&mut self, ctx: &mut EventCtx) {
+        // A different dot size changes the state, so the whole area is repainted.
+        self.update_dot_size();
+        ctx.request_paint_region(self.damage());
+        if self.is_empty() {
+            // The prompts are shown instead, none of the dots stay on the screen.
+            self.rendered.set(None);
+        }
+    }
+
+    fn row_width(&self, ndots: usize) -> i16 {
+        let DotSize { dot, padding, .. } = self.dot_size;
+        dot * (ndots as i16) + padding * (ndots.saturating_sub(1) as i16)
+    }
+
     fn size(&self) -> Offset {
-        let ndots = self.digits.len().min(MAX_VISIBLE_DOTS);
-        let mut width = Self::DOT * (ndots as i16);
-        width += Self::PADDING * (ndots.saturating_sub(1) as i16);
-        Offset::new(width, Self::DOT)
+        let ndots = self.digits.len().min(self.dot_size.max_visible);
+        Offset::new(self.row_width(ndots), self.dot_size.dot)
     }
 
     fn is_empty(&self) -> bool {
@@ -314,7 +741,7 @@ impl PinDots {
 
     fn clear(&mut self, ctx: &mut EventCtx) {
         self.digits.clear();
-        ctx.request_paint()
+        self.request_damage(ctx)
     }
 
     fn push(&mut self, ctx: &mut EventCtx, text: &str) {
@@ -322,12 +749,12 @@ impl PinDots {
             // `self.pin` is full and wasn't able to accept all of
             // `text`. Should not happen.
         };
-        ctx.request_paint()
+        self.request_damage(ctx)
     }
 
     fn pop(&mut self, ctx: &mut EventCtx) {
         if self.digits.pop().is_some() {
-            ctx.request_paint()
+            self.request_damage(ctx)
         }
     }
 
@@ -336,65 +763,94 @@ impl PinDots {
     }
 
     fn render_digits<'s>(&self, area: Rect, target: &mut impl Renderer<'s>) {
-        let left = area.left_center() + Offset::y(Font::MONO.visible_text_height("1") / 2);
-        let digits = self.digits.len();
+        let anchor = match self.alignment {
+            Alignment::Start => area.left_center(),
+            Alignment::Center => area.center(),
+            Alignment::End => area.right_center(),
+        };
+        let baseline = anchor + Offset::y(Font::MONO.visible_text_height("1") / 2);
+        // Only the most recent digits fit, the oldest ones are cut off.
+        let offset = self.digits.len().saturating_sub(MAX_VISIBLE_DIGITS);
+
+        shape::Text::new(baseline, &self.digits[offset..])
+            .with_align(self.alignment)
+            .with_font(Font::MONO)
+            .with_fg(self.style.text_color)
+            .render(target);
+    }
 
-        if digits <= MAX_VISIBLE_DIGITS {
-            shape::Text::new(left, &self.digits)
-                .with_align(Alignment::Start)
-                .with_font(Font::MONO)
+    /// Dot of the current size with its left end at `left`.
+    fn render_dot<'s>(&self, left: Point, target: &mut impl Renderer<'s>) {
+        if self.dot_size == DOT_SIZES[0] {
+            shape::ToifImage::new(left, theme::ICON_PIN_BULLET.toif)
+                .with_align(Alignment2D::CENTER_LEFT)
                 .with_fg(self.style.text_color)
                 .render(target);
         } else {
-            let offset: usize = digits.saturating_sub(MAX_VISIBLE_DIGITS);
-            shape::Text::new(left, &self.digits[offset..])
-                .with_align(Alignment::Start)
-                .with_font(Font::MONO)
-                .with_fg(self.style.text_color)
-                .render(target);
+            let dot = self.dot_size.dot;
+            shape::Bar::new(Rect::snap(
+                left,
+                Offset::uniform(dot),
+                Alignment2D::CENTER_LEFT,
+            ))
+            .with_bg(self.style.text_color)
+            .with_radius(dot / 2)
+            .render(target);
         }
     }
 
+    /// Small dot standing for hidden digits, with its left end at `left`.
+    fn render_indicator<'s>(&self, left: Point, color: Color, target: &mut impl Renderer<'s>) {
+        shape::ToifImage::new(left, theme::DOT_SMALL.toif)
+            .with_align(Alignment2D::CENTER_LEFT)
+            .with_fg(color)
+            .render(target);
+    }
+
     fn render_dots<'s>(&self, area: Rect, target: &mut impl Renderer<'s>) {
-        let mut cursor = area.left_center();
+        let mut cursor = self.row_start(area, self.size().x);
 
         let digits = self.digits.len();
-        let dots_visible = digits.min(MAX_VISIBLE_DOTS);
-        let step = Self::DOT + Self::PADDING;
+        let max_visible = self.dot_size.max_visible;
+        let dots_visible = digits.min(max_visible);
+        let step = self.dot_size.dot + self.dot_size.padding;
 
-        // Jiggle when overflowed.
-        if digits > MAX_VISIBLE_DOTS + 1 && (digits + 1) % 2 == 0 {
-            cursor.x += Self::TWITCH
+        // Jiggle when overflowed, away from the edge the row is anchored to.
+        if digits > max_visible + 1 && (digits + 1) % 2 == 0 {
+            match self.alignment {
+                Alignment::End => cursor.x -= Self::TWITCH,
+                _ => cursor.x += Self::TWITCH,
+            }
         }
 
-        let mut digit_idx = 0;
-        // Small leftmost dot.
-        if digits > MAX_VISIBLE_DOTS + 1 {
-            shape::ToifImage::new(cursor, theme::DOT_SMALL.toif)
-                .with_align(Alignment2D::CENTER_LEFT)
-                .with_fg(theme::GREY)
-                .render(target);
-            cursor.x += step;
-            digit_idx += 1;
+        // Indicators of the overflowing digits, from the outermost one: a small
+        // grey dot and a greyed out dot. They are at the edge the row is anchored
+        // to, on the left when centered.
+        let colors = [theme::GREY, self.style.text_color];
+        let indicators = &colors[2 - digits.saturating_sub(max_visible).min(2)..];
+        let mirrored = matches!(self.alignment, Alignment::End);
+
+        if !mirrored {
+            for &color in indicators {
+                self.render_indicator(cursor, color, target);
+                cursor.x += step;
+            }
         }
 
-        // Greyed out dot.
-        if digits > MAX_VISIBLE_DOTS {
-            shape::ToifImage::new(cursor, theme::DOT_SMALL.toif)
-                .with_align(Alignment2D::CENTER_LEFT)
-                .with_fg(self.style.text_color)
-                .render(target);
+        // Draw a dot for each PIN digit. When only a few dots changed, the rest is
+        // outside of the repainted region.
+        for i in 0..dots_visible - indicators.len() {
+            if digits > max_visible || target.is_visible(self.dot_rect(i, dots_visible)) {
+                self.render_dot(cursor, target);
+            }
             cursor.x += step;
-            digit_idx += 1;
         }
 
-        // Draw a dot for each PIN digit.
-        for _ in digit_idx..dots_visible {
-            shape::ToifImage::new(cursor, theme::ICON_PIN_BULLET.toif)
-                .with_align(Alignment2D::CENTER_LEFT)
-                .with_fg(self.style.text_color)
-                .render(target);
-            cursor.x += step;
+        if mirrored {
+            for &color in indicators.iter().rev() {
+                self.render_indicator(cursor, color, target);
+                cursor.x += step;
+            }
         }
     }
 }
@@ -414,14 +870,14 @@ impl Component for PinDots {
                 if self.area.contains(pos) {
                     self.display_digits = true;
                     self.pad.clear();
-                    ctx.request_paint();
+                    ctx.request_paint_region(self.area);
                 };
                 None
             }
             Event::Touch(TouchEvent::TouchEnd(_)) => {
                 if mem::replace(&mut self.display_digits, false) {
                     self.pad.clear();
-                    ctx.request_paint();
+                    ctx.request_paint_region(self.area);
                 };
                 None
             }
@@ -435,6 +891,7 @@ impl Component for PinDots {
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
         let dot_area = self.area.inset(HEADER_PADDING);
+        self.rendered.set(Some(self.state()));
         self.pad.render(target);
         if self.display_digits {
             self.render_digits(dot_area, target)
@@ -450,13 +907,24 @@ impl Component for PinDots {
     }
 }
 
+#[cfg(feature = "ui_debug")]
+impl crate::ui::layout::obj::DebugKeyboard for PinKeyboard<'_> {
+    fn debug_text(&self) -> &str {
+        self.pin()
+    }
+
+    fn debug_type(&mut self, ctx: &mut EventCtx, ch: char) {
+        self.type_char(ctx, ch);
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl crate::trace::Trace for PinKeyboard<'_> {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("PinKeyboard");
         // So that debuglink knows the locations of the buttons
         let mut digits_order: String<10> = String::new();
-        for btn in self.digit_btns.iter() {
+        for btn in self.digit_btns.inner().0.iter() {
             let btn_content = btn.inner().content();
             if let ButtonContent::Text(text) = btn_content {
                 text.map(|text| {
@@ -467,5 +935,149 @@ impl crate::trace::Trace for PinKeyboard<'_> {
         t.string("digits_order", digits_order.as_str().into());
         t.string("pin", self.textbox.inner().pin().into());
         t.bool("display_digits", self.textbox.inner().display_digits);
+        if let Some(attempts) = self.attempts_remaining {
+            t.int("attempts_remaining", attempts as i64);
+        }
+        t.bool("wipe_code", self.wipe_code);
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        model_mercury::constant::WIDTH,
+        shape::{BoundsRenderer, Viewport},
+    };
+
+    const ALIGNMENTS: [Alignment; 3] = [Alignment::Start, Alignment::Center, Alignment::End];
+
+    /// Renders `digits` dots, returns the area for the dots and the bounds of
+    /// the dots in the order they were rendered.
+    fn render_dots(mut dots: PinDots, digits: usize) -> (Rect, std::vec::Vec<Rect>) {
+        let area = Rect::from_top_left_and_size(Point::zero(), Offset::new(WIDTH, 40));
+        dots.place(area);
+        for _ in 0..digits {
+            unwrap!(dots.digits.push('1'));
+        }
+        dots.update_dot_size();
+        let mut renderer = BoundsRenderer::new(Viewport::new(area));
+        dots.render(&mut renderer);
+        // The first one is the background.
+        assert!(renderer.shapes[0] == area);
+        (area.inset(HEADER_PADDING), renderer.shapes.split_off(1))
+    }
+
+    #[test]
+    fn dots_aligned() {
+        for alignment in ALIGNMENTS {
+            for digits in [1, 10, 25] {
+                let dots = PinDots::new(theme::label_default())
+                    .with_alignment(alignment)
+                    .with_shrinking_dots();
+                let (area, shapes) = render_dots(dots, digits);
+                assert_eq!(shapes.len(), digits);
+                let row = shapes.iter().fold(shapes[0], |row, dot| row.union(*dot));
+                assert!(area.union(row) == area);
+                match alignment {
+                    Alignment::Start => assert_eq!(row.x0, area.x0),
+                    Alignment::Center => assert!((row.center().x - area.center().x).abs() <= 1),
+                    Alignment::End => assert_eq!(row.x1, area.x1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn damage_covers_changed_dots() {
+        let mut ctx = EventCtx::new();
+        let area = Rect::from_top_left_and_size(Point::zero(), Offset::new(WIDTH, 40));
+        let render = |dots: &PinDots, clip: Rect| {
+            let mut renderer = BoundsRenderer::new(Viewport::new(clip));
+            dots.render(&mut renderer);
+            // Without the background.
+            renderer.shapes.len() - 1
+        };
+
+        let mut dots = PinDots::new(theme::label_default());
+        dots.place(area);
+        dots.push(&mut ctx, "123");
+        assert!(dots.damage() == area);
+        assert_eq!(render(&dots, area), 3);
+
+        // Only the new dot is repainted.
+        dots.push(&mut ctx, "4");
+        assert!(dots.damage() == dots.dot_rect(3, 4));
+        assert_eq!(render(&dots, dots.damage()), 1);
+
+        // Only the removed one.
+        dots.pop(&mut ctx);
+        assert!(dots.damage() == dots.dot_rect(3, 4));
+        assert_eq!(render(&dots, dots.damage()), 0);
+
+        // Revealing the digits repaints everything.
+        dots.display_digits = true;
+        assert!(dots.damage() == area);
+    }
+
+    #[test]
+    fn overflow_indicators_at_anchored_edge() {
+        for alignment in ALIGNMENTS {
+            let dots = PinDots::new(theme::label_default()).with_alignment(alignment);
+            let (area, shapes) = render_dots(dots, 25);
+            assert_eq!(shapes.len(), MAX_VISIBLE_DOTS);
+            let row = shapes.iter().fold(shapes[0], |row, dot| row.union(*dot));
+            assert!(area.union(row) == area);
+            // The indicators are rendered first, or last with the mirrored row.
+            let last = MAX_VISIBLE_DOTS - 1;
+            if matches!(alignment, Alignment::End) {
+                assert_eq!(shapes[last].x1, row.x1);
+                assert!(shapes[last - 1].x0 < shapes[last].x0);
+            } else {
+                assert_eq!(shapes[0].x0, row.x0);
+                assert!(shapes[0].x0 < shapes[1].x0);
+            }
+        }
+    }
+
+    #[test]
+    fn dot_sizes_fit() {
+        let header = WIDTH - 2 * HEADER_PADDING_SIDE;
+        for size in DOT_SIZES {
+            let width =
+                size.dot * size.max_visible as i16 + size.padding * (size.max_visible as i16 - 1);
+            assert!(width <= header);
+        }
+    }
+
+    #[test]
+    fn dots_shrink_and_grow() {
+        let mut dots = PinDots::new(theme::label_default()).with_shrinking_dots();
+        let mut sizes = [0; MAX_LENGTH + 1];
+        for (len, size) in sizes.iter_mut().enumerate().skip(1) {
+            unwrap!(dots.digits.push('1'));
+            dots.update_dot_size();
+            assert!(dots.size().x <= WIDTH - 2 * HEADER_PADDING_SIDE);
+            *size = dots.dot_size.dot;
+            if len <= DOT_SIZES[2].max_visible {
+                assert_eq!(dots.size().x, dots.row_width(len));
+            }
+        }
+        assert_eq!(sizes[MAX_VISIBLE_DOTS], 6);
+        assert_eq!(sizes[MAX_VISIBLE_DOTS + 1], 5);
+        assert_eq!(sizes[25], 5);
+        assert_eq!(sizes[26], 4);
+        assert_eq!(sizes[MAX_LENGTH], 4);
+
+        // Deleting goes back to the larger dots.
+        while dots.digits.len() > 3 {
+            dots.digits.pop();
+            dots.update_dot_size();
+            assert_eq!(dots.dot_size.dot, sizes[dots.digits.len()]);
+        }
+        assert!(dots.dot_size == DOT_SIZES[0]);
     }
 }
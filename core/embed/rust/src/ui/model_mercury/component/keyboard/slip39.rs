@@ -3,7 +3,7 @@ use core::iter;
 use heapless::String;
 
 use crate::{
-    trezorhal::slip39,
+    trezorhal::{slip39, wordlist::Wordlist},
     ui::{
         component::{
             text::common::{TextBox, TextEdit},
@@ -14,7 +14,10 @@ use crate::{
         model_mercury::{
             component::{
                 keyboard::{
-                    common::{render_pending_marker, render_pill_shape, MultiTapKeyboard},
+                    common::{
+                        render_pending_marker, render_pill_shape, CandidateCycle,
+                        CandidateCycleMsg, MultiTapKeyboard,
+                    },
                     mnemonic::{MnemonicInput, MnemonicInputMsg, MNEMONIC_KEY_COUNT},
                 },
                 Button, ButtonContent, ButtonMsg,
@@ -27,6 +30,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "host_keyboard")]
+use crate::ui::event::HostKey;
+
 const MAX_LENGTH: usize = 8;
 
 pub struct Slip39Input {
@@ -35,6 +41,10 @@ pub struct Slip39Input {
     multi_tap: MultiTapKeyboard,
     final_word: Option<&'static str>,
     input_mask: Slip39Mask,
+    /// Cycling through all the words matching the current key sequence.
+    cycle: CandidateCycle,
+    /// Whether the user cleared the auto-filled suffix of `final_word`.
+    autofill_dismissed: bool,
 }
 
 impl MnemonicInput for Slip39Input {
@@ -76,11 +86,19 @@ impl MnemonicInput for Slip39Input {
     }
 
     /// Backspace button was clicked, let's delete the last character of input
-    /// and clear the pending marker.
+    /// and clear the pending marker. If the word was auto-filled, only the
+    /// auto-filled suffix is cleared.
     fn on_backspace_click(&mut self, ctx: &mut EventCtx) {
         self.multi_tap.clear_pending_state(ctx);
-        self.textbox.delete_last(ctx);
-        self.complete_word_from_dictionary(ctx);
+        if self.autofilled_word().is_some() {
+            self.autofill_dismissed = true;
+            self.button.disable(ctx);
+            self.button.set_content(ctx, ButtonContent::Text("".into()));
+            ctx.request_paint();
+        } else {
+            self.textbox.delete_last(ctx);
+            self.complete_word_from_dictionary(ctx);
+        }
     }
 
     /// Backspace button was long pressed, let's delete all characters of input
@@ -91,12 +109,29 @@ impl MnemonicInput for Slip39Input {
         self.complete_word_from_dictionary(ctx);
     }
 
+    /// Only the key matters for the word, the letter is not kept.
+    #[cfg(any(feature = "host_keyboard", feature = "ui_debug"))]
+    fn on_host_char(&mut self, ctx: &mut EventCtx, key: usize, _ch: char) {
+        self.multi_tap.clear_pending_state(ctx);
+        self.textbox.append(ctx, Self::key_digit(key));
+        self.complete_word_from_dictionary(ctx);
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
     fn is_empty(&self) -> bool {
         self.textbox.is_empty()
     }
 
     fn mnemonic(&self) -> Option<&'static str> {
-        self.final_word
+        self.autofilled_word()
+    }
+
+    fn for_word(&self, word: &str, _previous_words: Option<&[&'static str]>) -> Self {
+        Self::prefilled_word(word)
     }
 }
 
@@ -104,6 +139,7 @@ impl Component for Slip39Input {
     type Msg = MnemonicInputMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
+        self.cycle.place(bounds);
         self.button.place(bounds)
     }
 
@@ -113,12 +149,19 @@ impl Component for Slip39Input {
             self.multi_tap.clear_pending_state(ctx);
             return Some(MnemonicInputMsg::TimedOut);
         }
-        if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
-            // Input button was clicked.  If the whole word is totally identified, let's
-            // confirm it, otherwise don't do anything.
-            if self.input_mask.is_final() {
-                return Some(MnemonicInputMsg::Confirmed);
+        if let Some(msg) = self.cycle.event(ctx, event) {
+            if let CandidateCycleMsg::Changed = msg {
+                self.multi_tap.clear_pending_state(ctx);
+                self.button.enable(ctx);
             }
+            return None;
+        }
+        #[cfg(feature = "host_keyboard")]
+        if let Event::HostKey(HostKey::Enter) = event {
+            return self.on_input_click(ctx);
+        }
+        if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
+            return self.on_input_click(ctx);
         }
         None
     }
@@ -135,24 +178,46 @@ impl Component for Slip39Input {
         let text_base_y = area.left_center().y + style.font.allcase_text_height() / 2;
         let text_center = Point::new(WIDTH / 2, text_base_y);
 
+        // To simplify things, we always copy the printed string here, even if it
+        // wouldn't be strictly necessary.
+        if let Some(word) = self.autofilled_word() {
+            // The word is identified, paint it in full with the auto-filled suffix
+            // dimmed.
+            let pill_base = Point::new(style.font.horz_center(0, WIDTH, word), text_center.y);
+            render_pill_shape(target, pill_base, word, style, Some(area));
+            // Icon is painted in the right-center point, 10px from the right edge.
+            let icon_right_center = area.right_center() - Offset::x(10);
+            shape::ToifImage::new(icon_right_center, theme::ICON_SIMPLE_CHECKMARK24.toif)
+                .with_align(Alignment2D::CENTER_RIGHT)
+                .with_fg(style.icon_color)
+                .render(target);
+
+            let (typed, suffix) = word.split_at(self.textbox.len().min(word.len()));
+            shape::Text::new(pill_base, typed)
+                .with_font(style.font)
+                .with_fg(style.text_color)
+                .with_align(Alignment::Start)
+                .render(target);
+            shape::Text::new(pill_base + Offset::x(style.font.text_width(typed)), suffix)
+                .with_font(style.font)
+                .with_fg(theme::button_suggestion_confirm().normal.text_color)
+                .with_align(Alignment::Start)
+                .render(target);
+            return;
+        }
+
         // To simplify things, we always copy the printed string here, even if it
         // wouldn't be strictly necessary.
         let mut text: String<MAX_LENGTH> = String::new();
-        if let Some(word) = self.final_word {
-            // We're done with input, paint the full word.
+        if let Some(word) = self.offered_candidate() {
+            // User is cycling through the candidates, paint the selected one.
             text.push_str(word)
                 .assert_if_debugging_ui("Text buffer is too small");
             let pill_base = Point::new(
                 style.font.horz_center(0, WIDTH, text.as_str()),
                 text_center.y,
             );
-            render_pill_shape(target, pill_base, text.as_str(), style, Some(area));
-            // Icon is painted in the right-center point, 10px from the right edge.
-            let icon_right_center = area.right_center() - Offset::x(10);
-            shape::ToifImage::new(icon_right_center, theme::ICON_SIMPLE_CHECKMARK24.toif)
-                .with_align(Alignment2D::CENTER_RIGHT)
-                .with_fg(style.icon_color)
-                .render(target);
+            render_pill_shape(target, pill_base, text.as_str(), style, None);
         } else {
             // Paint an asterisk for each letter of input.
             for ch in iter::repeat('*').take(self.textbox.content().len()) {
@@ -182,7 +247,7 @@ impl Component for Slip39Input {
             style.font.horz_center(0, WIDTH, text.as_str()),
             text_center.y,
         );
-        if self.multi_tap.pending_key().is_some() && self.final_word.is_none() {
+        if self.multi_tap.pending_key().is_some() {
             render_pending_marker(
                 target,
                 text_base,
@@ -191,6 +256,8 @@ impl Component for Slip39Input {
                 style.text_color,
             );
         }
+
+        self.cycle.render(target, style.icon_color);
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -208,6 +275,8 @@ impl Slip39Input {
             multi_tap: MultiTapKeyboard::new(),
             final_word: None,
             input_mask: Slip39Mask::full(),
+            cycle: CandidateCycle::new(),
+            autofill_dismissed: false,
         }
     }
 
@@ -226,6 +295,8 @@ impl Slip39Input {
             multi_tap: MultiTapKeyboard::new(),
             final_word,
             input_mask,
+            cycle: CandidateCycle::new(),
+            autofill_dismissed: false,
         }
     }
 
@@ -266,6 +337,22 @@ impl Slip39Input {
         (buff, Slip39Mask::full(), None)
     }
 
+    /// Input button was clicked. If the whole word is totally identified, let's
+    /// confirm it. If a candidate is being offered, let's auto-complete it.
+    fn on_input_click(&mut self, ctx: &mut EventCtx) -> Option<MnemonicInputMsg> {
+        if self.autofilled_word().is_some() {
+            return Some(MnemonicInputMsg::Confirmed);
+        }
+        if let Some(word) = self.offered_candidate() {
+            let (buff, _, _) = Self::setup_from_prefilled_word(word);
+            self.multi_tap.clear_pending_state(ctx);
+            self.textbox.replace(ctx, buff.as_str());
+            self.complete_word_from_dictionary(ctx);
+            return Some(MnemonicInputMsg::Completed);
+        }
+        None
+    }
+
     /// Convert a key index into the key digit. This is what we push into the
     /// input buffer.
     ///
@@ -281,6 +368,7 @@ impl Slip39Input {
     }
 
     fn complete_word_from_dictionary(&mut self, ctx: &mut EventCtx) {
+        self.autofill_dismissed = false;
         let sequence = self.input_sequence();
         self.input_mask = sequence
             .and_then(slip39::word_completion_mask)
@@ -292,6 +380,14 @@ impl Slip39Input {
             None
         };
 
+        // Offer the matching words for cycling, unless the word is already known.
+        let count = if self.final_word.is_none() && !self.textbox.is_empty() {
+            self.candidates().count()
+        } else {
+            0
+        };
+        self.cycle.reset(count);
+
         // Change the style of the button depending on the input.
         if self.final_word.is_some() {
             // Confirm button.
@@ -305,9 +401,60 @@ impl Slip39Input {
         }
     }
 
+    /// The word identified by the input, auto-filled unless the user cleared
+    /// the suffix.
+    fn autofilled_word(&self) -> Option<&'static str> {
+        self.final_word.filter(|_| !self.autofill_dismissed)
+    }
+
     fn input_sequence(&self) -> Option<u16> {
         self.textbox.content().parse().ok()
     }
+
+    /// Iterate, in alphabetical order, over all words that can be formed by
+    /// the current key sequence. Only the words starting with one of the
+    /// letters of the first key are visited, relying on the wordlist being
+    /// sorted.
+    fn candidates(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let sequence = self.textbox.content();
+        let first_key = sequence
+            .bytes()
+            .next()
+            .map_or("", |digit| Self::keys()[Self::digit_key(digit)]);
+        first_key
+            .chars()
+            .flat_map(|ch| {
+                let mut buf = [0; 4];
+                Wordlist::slip39().matches(ch.encode_utf8(&mut buf))
+            })
+            .filter(move |word| Self::word_matches_sequence(word, sequence))
+    }
+
+    /// The candidate word selected by cycling, if the user started cycling
+    /// through them. Until then, the input stays masked.
+    fn offered_candidate(&self) -> Option<&'static str> {
+        if self.cycle.is_cycling() {
+            self.candidates().nth(self.cycle.index())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if typing `word` could begin with the key `sequence`.
+    fn word_matches_sequence(word: &str, sequence: &str) -> bool {
+        word.len() >= sequence.len()
+            && sequence
+                .bytes()
+                .zip(word.chars())
+                .all(|(digit, ch)| Self::keys()[Self::digit_key(digit)].contains(ch))
+    }
+
+    /// Inverse of `Self::key_digit`, converts a digit of the input buffer back
+    /// to the key index.
+    fn digit_key(digit: u8) -> usize {
+        // We assume the buffer only contains digits produced by `Self::key_digit`.
+        (digit - b'1') as usize
+    }
 }
 
 struct Slip39Mask(u16);
@@ -336,5 +483,48 @@ impl crate::trace::Trace for Slip39Input {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Slip39Input");
         t.child("textbox", &self.textbox);
+        if let Some(word) = self.autofilled_word() {
+            t.string("word", word.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type `word` into a new input as on the host keyboard.
+    fn typed(word: &str) -> Slip39Input {
+        let mut ctx = EventCtx::new();
+        let mut input = Slip39Input::new();
+        for ch in word.chars() {
+            let key = unwrap!(Slip39Input::keys().iter().position(|key| key.contains(ch)));
+            input.on_host_char(&mut ctx, key, ch);
+        }
+        input
+    }
+
+    #[test]
+    fn unique_prefix_autofilled() {
+        let mut ctx = EventCtx::new();
+        let mut input = typed("acad");
+        assert_eq!(input.mnemonic(), Some("academic"));
+        assert!(matches!(
+            input.on_input_click(&mut ctx),
+            Some(MnemonicInputMsg::Confirmed)
+        ));
+    }
+
+    #[test]
+    fn backspace_dismisses_autofill() {
+        let mut ctx = EventCtx::new();
+        let mut input = typed("acad");
+        let typed_len = input.textbox.content().len();
+        input.on_backspace_click(&mut ctx);
+        assert_eq!(input.mnemonic(), None);
+        assert_eq!(input.textbox.content().len(), typed_len);
+        assert!(input.on_input_click(&mut ctx).is_none());
+        input.on_backspace_click(&mut ctx);
+        assert_eq!(input.textbox.content().len(), typed_len - 1);
     }
 }
@@ -1,6 +1,6 @@
 use crate::ui::{
     component::{Component, Event, EventCtx},
-    geometry::{Alignment, Grid, GridCellSpan, Rect},
+    geometry::{Alignment, Grid, Rect},
     model_mercury::{
         component::button::{Button, ButtonMsg},
         theme,
@@ -8,12 +8,22 @@ use crate::ui::{
     shape::Renderer,
 };
 
+use heapless::Vec;
+
 const NUMBERS: [u32; 5] = [12, 18, 20, 24, 33];
 const LABELS: [&str; 5] = ["12", "18", "20", "24", "33"];
-const CELLS: [(usize, usize); 5] = [(0, 0), (0, 2), (1, 0), (1, 2), (2, 1)];
+/// Word counts valid for a BIP-39 mnemonic.
+const NUMBERS_BIP39: [u32; 3] = [12, 18, 24];
+/// Word counts valid for a SLIP-39 share.
+const NUMBERS_SLIP39: [u32; 2] = [20, 33];
+
+/// Buttons are laid out in two columns, on a grid twice as wide so that the
+/// last button of an odd count can be centered.
+const COLS: usize = 2;
+const GRID_COLS: usize = 2 * COLS;
 
 pub struct SelectWordCount {
-    button: [Button; NUMBERS.len()],
+    button: Vec<(u32, Button), { NUMBERS.len() }>,
 }
 
 pub enum SelectWordCountMsg {
@@ -22,13 +32,36 @@ pub enum SelectWordCountMsg {
 
 impl SelectWordCount {
     pub fn new() -> Self {
-        SelectWordCount {
-            button: LABELS.map(|t| {
-                Button::with_text(t.into())
+        Self::with_numbers(&NUMBERS)
+    }
+
+    /// Only the word counts of a BIP-39 mnemonic.
+    pub fn new_bip39() -> Self {
+        Self::with_numbers(&NUMBERS_BIP39)
+    }
+
+    /// Only the word counts of a SLIP-39 share.
+    pub fn new_slip39() -> Self {
+        Self::with_numbers(&NUMBERS_SLIP39)
+    }
+
+    fn with_numbers(numbers: &[u32]) -> Self {
+        let button = NUMBERS
+            .iter()
+            .zip(LABELS)
+            .filter(|(n, _)| numbers.contains(n))
+            .map(|(&n, label)| {
+                let btn = Button::with_text(label.into())
                     .styled(theme::button_keyboard())
-                    .with_text_align(Alignment::Center)
-            }),
-        }
+                    .with_text_align(Alignment::Center);
+                (n, btn)
+            })
+            .collect();
+        Self { button }
+    }
+
+    fn rows(&self) -> usize {
+        (self.button.len() + COLS - 1) / COLS
     }
 }
 
@@ -36,46 +69,50 @@ impl Component for SelectWordCount {
     type Msg = SelectWordCountMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let n_rows: usize = 3;
-        let n_cols: usize = 4;
+        let n_rows = self.rows();
+        let count = self.button.len();
 
         let (_, bounds) = bounds.split_bottom(
             n_rows as i16 * theme::BUTTON_HEIGHT + (n_rows as i16 - 1) * theme::BUTTON_SPACING,
         );
-        let grid = Grid::new(bounds, n_rows, n_cols).with_spacing(theme::BUTTON_SPACING);
-        for (btn, (x, y)) in self.button.iter_mut().zip(CELLS) {
-            btn.place(grid.cells(GridCellSpan {
-                from: (x, y),
-                to: (x, y + 1),
-            }));
+        let grid = Grid::new(bounds, n_rows, GRID_COLS).with_spacing(theme::BUTTON_SPACING);
+        for (i, (_, btn)) in self.button.iter_mut().enumerate() {
+            let row = i / COLS;
+            let col = if i == count - 1 && count % COLS == 1 {
+                // Odd one out, centered.
+                1
+            } else {
+                (i % COLS) * 2
+            };
+            btn.place(grid.span_cols(row, col, col + 1));
         }
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        for (i, btn) in self.button.iter_mut().enumerate() {
+        for (n, btn) in self.button.iter_mut() {
             if let Some(ButtonMsg::Clicked) = btn.event(ctx, event) {
-                return Some(SelectWordCountMsg::Selected(NUMBERS[i]));
+                return Some(SelectWordCountMsg::Selected(*n));
             }
         }
         None
     }
 
     fn paint(&mut self) {
-        for btn in self.button.iter_mut() {
+        for (_, btn) in self.button.iter_mut() {
             btn.paint()
         }
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        for btn in self.button.iter() {
+        for (_, btn) in self.button.iter() {
             btn.render(target)
         }
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
-        for btn in self.button.iter() {
+        for (_, btn) in self.button.iter() {
             btn.bounds(sink)
         }
     }
@@ -85,5 +122,10 @@ impl Component for SelectWordCount {
 impl crate::trace::Trace for SelectWordCount {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("SelectWordCount");
+        t.in_list("counts", &|list| {
+            for (n, _) in self.button.iter() {
+                list.int(*n as i64);
+            }
+        });
     }
 }
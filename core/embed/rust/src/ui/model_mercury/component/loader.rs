@@ -1,12 +1,14 @@
 #[cfg(feature = "haptic")]
 use crate::trezorhal::haptic::{play, HapticEffect};
 use crate::{
+    strutil::{self, ShortString},
     time::{Duration, Instant},
     ui::{
         animation::Animation,
         component::{Component, Event, EventCtx, Pad},
-        display::{self, toif::Icon, Color, LOADER_MAX},
-        geometry::{Alignment2D, Offset, Rect},
+        display::{self, toif::Icon, Color, Font, LOADER_MAX},
+        geometry::{Alignment, Alignment2D, Offset, Rect},
+        lerp::Lerp,
         model_mercury::cshape::{render_loader, LoaderRange},
         shape::{self, Renderer},
         util::animation_disabled,
@@ -17,6 +19,13 @@ use super::{constant, theme};
 
 const GROWING_DURATION_MS: u32 = 1000;
 const SHRINKING_DURATION_MS: u32 = 500;
+/// Time of one revolution of the indeterminate spinner.
+const SPINNER_PERIOD_MS: u32 = 1000;
+/// Length of the indeterminate spinner arc, in degrees.
+const SPINNER_ARC: f32 = 60.0;
+/// Determinate progress jumping by more than this is animated.
+const CATCH_UP_THRESHOLD: u16 = 100;
+const CATCH_UP_DURATION_MS: u32 = 300;
 
 pub enum LoaderMsg {
     GrownCompletely,
@@ -27,6 +36,14 @@ enum State {
     Initial,
     Growing(Animation<u16>),
     Shrinking(Animation<u16>),
+    /// Spinner of unknown progress, started at the instant.
+    Indeterminate(Instant),
+    /// Ring filled proportionally to the progress, starting at `origin`
+    /// degrees. The value catches up with the last reported progress.
+    Determinate {
+        origin: f32,
+        value: Animation<u16>,
+    },
 }
 
 pub struct Loader {
@@ -36,6 +53,7 @@ pub struct Loader {
     shrinking_duration: Duration,
     styles: LoaderStyleSheet,
     offset_y: i16,
+    show_percentage: bool,
 }
 
 impl Loader {
@@ -59,9 +77,17 @@ impl Loader {
             shrinking_duration: Duration::from_millis(SHRINKING_DURATION_MS),
             styles,
             offset_y: 0,
+            show_percentage: false,
         }
     }
 
+    /// Show the percentage in the middle of a determinate loader instead of
+    /// the icon.
+    pub fn with_percentage(mut self) -> Self {
+        self.show_percentage = true;
+        self
+    }
+
     pub fn with_durations(
         mut self,
         growing_duration: Duration,
@@ -113,19 +139,84 @@ impl Loader {
         ctx.request_paint();
     }
 
+    /// Spin for an operation of unknown length.
+    pub fn start_indeterminate(&mut self, ctx: &mut EventCtx, now: Instant) {
+        self.state = State::Indeterminate(now);
+        ctx.request_anim_frame();
+        ctx.request_paint();
+    }
+
+    /// Fill the ring up to `value` out of `LOADER_MAX`. A spinning loader
+    /// keeps its current angle and fills from there.
+    pub fn set_progress(&mut self, ctx: &mut EventCtx, value: u16, now: Instant) {
+        let value = value.min(LOADER_MAX);
+        let (origin, shown) = match &self.state {
+            State::Determinate { origin, value } => (*origin, value.value(now)),
+            State::Indeterminate(started) => {
+                // Start with the spinner arc, catch up from there.
+                let (tail, _) = Self::spinner_range(*started, now);
+                (tail, (LOADER_MAX as f32 * SPINNER_ARC / 360.0) as u16)
+            }
+            _ => (0.0, self.progress(now).unwrap_or(display::LOADER_MIN)),
+        };
+        let from = if shown.abs_diff(value) > CATCH_UP_THRESHOLD {
+            shown
+        } else {
+            value
+        };
+        let catch_up = Duration::from_millis(CATCH_UP_DURATION_MS);
+        self.state = State::Determinate {
+            origin,
+            value: Animation::new(from, value, catch_up, now),
+        };
+        if from != value {
+            ctx.request_anim_frame();
+        }
+        ctx.request_paint();
+    }
+
     pub fn reset(&mut self) {
         self.state = State::Initial;
     }
 
+    /// Angles of the spinner arc ends at `now`.
+    fn spinner_range(started: Instant, now: Instant) -> (f32, f32) {
+        let elapsed = now.saturating_duration_since(started).to_millis();
+        let head = 360.0 * (elapsed % SPINNER_PERIOD_MS) as f32 / SPINNER_PERIOD_MS as f32;
+        (head - SPINNER_ARC, head)
+    }
+
     pub fn animation(&self) -> Option<&Animation<u16>> {
         match &self.state {
-            State::Initial => None,
             State::Growing(a) | State::Shrinking(a) => Some(a),
+            _ => None,
         }
     }
 
+    /// Current value of a growing, shrinking or determinate loader.
     pub fn progress(&self, now: Instant) -> Option<u16> {
-        self.animation().map(|a| a.value(now))
+        match &self.state {
+            State::Determinate { value, .. } => Some(value.value(now)),
+            _ => self.animation().map(|a| a.value(now)),
+        }
+    }
+
+    fn range(&self, now: Instant) -> Option<LoaderRange> {
+        let (start, progress) = match &self.state {
+            State::Initial => return None,
+            State::Indeterminate(started) => {
+                let (start, end) = Self::spinner_range(*started, now);
+                return Some(LoaderRange::FromTo(start, end));
+            }
+            State::Determinate { origin, value } => (*origin, value.value(now)),
+            State::Growing(a) | State::Shrinking(a) => (0.0, a.value(now)),
+        };
+        if progress >= LOADER_MAX {
+            Some(LoaderRange::Full)
+        } else {
+            let end = start + f32::lerp_fixed(0.0, 360.0, progress, LOADER_MAX);
+            Some(LoaderRange::FromTo(start, end))
+        }
     }
 
     pub fn is_animating(&self) -> bool {
@@ -160,6 +251,29 @@ impl Component for Loader {
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         let now = Instant::now();
 
+        match (&self.state, event) {
+            (State::Indeterminate(_) | State::Determinate { .. }, Event::Progress(value, _)) => {
+                self.set_progress(ctx, value, now);
+                return None;
+            }
+            (State::Indeterminate(_), Event::Timer(EventCtx::ANIM_FRAME_TIMER)) => {
+                if !animation_disabled() {
+                    ctx.request_paint();
+                }
+                ctx.request_anim_frame();
+                return None;
+            }
+            (State::Determinate { value, .. }, Event::Timer(EventCtx::ANIM_FRAME_TIMER)) => {
+                // Paint also the frame the catch-up finishes on.
+                ctx.request_paint();
+                if !value.finished(now) {
+                    ctx.request_anim_frame();
+                }
+                return None;
+            }
+            _ => {}
+        }
+
         if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
             if self.is_animating() {
                 // We have something to paint, so request to be painted in the next pass.
@@ -169,7 +283,7 @@ impl Component for Loader {
 
                 if self.is_completely_grown(now) {
                     #[cfg(feature = "haptic")]
-                    play(HapticEffect::HoldToConfirm);
+                    play(HapticEffect::HoldConfirm);
                     return Some(LoaderMsg::GrownCompletely);
                 } else if self.is_completely_shrunk(now) {
                     return Some(LoaderMsg::ShrunkCompletely);
@@ -212,7 +326,7 @@ impl Component for Loader {
         // other component in the tree takes a long time to draw.
         let now = Instant::now();
 
-        if let Some(progress) = self.progress(now) {
+        if let Some(range) = self.range(now) {
             let style = self.styles.active;
 
             self.pad.render(target);
@@ -223,23 +337,30 @@ impl Component for Loader {
             let active_color = style.active;
             let background_color = style.background_color;
 
-            let end = 360.0 * progress as f32 / 1000.0;
-            let start = 0.0;
-
             render_loader(
                 center,
                 inactive_color,
                 active_color,
                 background_color,
-                if progress >= LOADER_MAX {
-                    LoaderRange::Full
-                } else {
-                    LoaderRange::FromTo(start, end)
-                },
+                range,
                 target,
             );
 
-            if let Some((icon, color)) = style.icon {
+            let percentage = match self.state {
+                State::Determinate { .. } if self.show_percentage => self.progress(now),
+                _ => None,
+            };
+            if let Some(progress) = percentage {
+                let mut text = ShortString::new();
+                unwrap!(strutil::push_int(&mut text, (progress / 10) as i64));
+                unwrap!(text.push('%'));
+                let font = Font::DEMIBOLD;
+                shape::Text::new(center + Offset::y(font.visible_text_height("1") / 2), &text)
+                    .with_align(Alignment::Center)
+                    .with_font(font)
+                    .with_fg(active_color)
+                    .render(target);
+            } else if let Some((icon, color)) = style.icon {
                 shape::ToifImage::new(center, icon.toif)
                     .with_align(Alignment2D::CENTER)
                     .with_fg(color)
@@ -264,6 +385,17 @@ pub struct LoaderStyle {
 impl crate::trace::Trace for Loader {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Loader");
+        let state = match self.state {
+            State::Initial => "Initial",
+            State::Growing(_) => "Growing",
+            State::Shrinking(_) => "Shrinking",
+            State::Indeterminate(_) => "Indeterminate",
+            State::Determinate { .. } => "Determinate",
+        };
+        t.string("state", state.into());
+        if let Some(progress) = self.progress(Instant::now()) {
+            t.int("progress", progress as i64);
+        }
     }
 }
 
@@ -289,6 +421,26 @@ mod tests {
         assert_eq!(l.progress(t), Some(0));
     }
 
+    #[test]
+    fn determinate_loader_catches_up() {
+        let mut ctx = EventCtx::new();
+        let mut l = Loader::new();
+        let t = Instant::now();
+        l.set_progress(&mut ctx, 50, t);
+        assert_eq!(l.progress(t), Some(50));
+        l.set_progress(&mut ctx, 650, t);
+        assert_eq!(l.progress(t), Some(50));
+        let t = add_millis(t, 150);
+        assert_eq!(l.progress(t), Some(350));
+        let t = add_millis(t, 150);
+        assert_eq!(l.progress(t), Some(650));
+        l.set_progress(&mut ctx, 700, t);
+        assert_eq!(l.progress(t), Some(700));
+        l.set_progress(&mut ctx, 2000, t);
+        let t = add_millis(t, 300);
+        assert_eq!(l.progress(t), Some(LOADER_MAX));
+    }
+
     fn add_millis(inst: Instant, millis: u32) -> Instant {
         inst.checked_add(Duration::from_millis(millis)).unwrap()
     }
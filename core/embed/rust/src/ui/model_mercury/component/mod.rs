@@ -49,14 +49,16 @@ pub use coinjoin_progress::CoinJoinProgress;
 pub use error::ErrorScreen;
 pub use fido::{FidoConfirm, FidoMsg};
 pub use footer::Footer;
-pub use frame::{Frame, FrameMsg};
+pub use frame::{Frame, FrameMsg, FrameUpdate, FrameUpdates};
 #[cfg(feature = "translations")]
 pub use hold_to_confirm::HoldToConfirm;
 #[cfg(feature = "micropython")]
 pub use homescreen::{check_homescreen_format, Homescreen, HomescreenMsg, Lockscreen};
 #[cfg(feature = "translations")]
 pub use keyboard::{
+    amount::{AmountKeyboard, AmountKeyboardMsg},
     bip39::Bip39Input,
+    datetime::{DatetimeKeyboard, DatetimeKeyboardMsg},
     mnemonic::{MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg},
     passphrase::{PassphraseKeyboard, PassphraseKeyboardMsg},
     pin::{PinKeyboard, PinKeyboardMsg},
@@ -77,7 +79,7 @@ pub use scroll::ScrollBar;
 pub use set_brightness::SetBrightnessDialog;
 #[cfg(feature = "translations")]
 pub use share_words::ShareWords;
-pub use status_screen::StatusScreen;
+pub use status_screen::{StatusScreen, StatusScreenMsg};
 pub use swipe_content::SwipeContent;
 #[cfg(feature = "translations")]
 pub use swipe_up_screen::{SwipeUpScreen, SwipeUpScreenMsg};
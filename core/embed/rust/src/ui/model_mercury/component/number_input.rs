@@ -1,6 +1,9 @@
+use core::mem;
+
 use crate::{
     error::Error,
     strutil::{self, TString},
+    time::{Duration, Instant},
     ui::{
         component::{
             base::ComponentExt,
@@ -9,13 +12,16 @@ use crate::{
             Child, Component, Event, EventCtx, Pad, SwipeDirection,
         },
         display::Font,
-        event::SwipeEvent,
+        event::{SwipeEvent, TouchEvent},
         geometry::{Alignment, Grid, Insets, Offset, Rect},
         shape::{self, Renderer},
     },
 };
 
-use super::{theme, Button, ButtonMsg};
+use super::{
+    keyboard::numeric::{NumericKeyboard, NumericKeyboardMsg},
+    theme, Button, ButtonMsg,
+};
 
 pub struct NumberInputDialogMsg(pub u32);
 
@@ -28,6 +34,8 @@ where
     input: Child<NumberInput>,
     paragraphs: Child<Paragraphs<Paragraph<'static>>>,
     paragraphs_pad: Pad,
+    /// Keypad for direct entry of the value, covering the dialog while open.
+    keypad: Option<Child<NumericKeyboard>>,
 }
 
 impl<F> NumberInputDialog<F>
@@ -43,9 +51,32 @@ where
             paragraphs: Paragraphs::new(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, text))
                 .into_child(),
             paragraphs_pad: Pad::with_background(theme::BG),
+            keypad: None,
         })
     }
 
+    /// Tapping the value opens a keypad for typing it directly.
+    pub fn with_direct_entry(mut self) -> Self {
+        self.input = self.input.into_inner().with_direct_entry().into_child();
+        self
+    }
+
+    fn open_keypad(&mut self, ctx: &mut EventCtx) {
+        let input = self.input.inner();
+        let mut keypad = NumericKeyboard::new(input.min, input.max).into_child();
+        keypad.place(self.area);
+        self.keypad = Some(keypad);
+        ctx.request_paint();
+    }
+
+    fn close_keypad(&mut self, ctx: &mut EventCtx) {
+        self.keypad = None;
+        self.paragraphs_pad.clear();
+        self.input.request_complete_repaint(ctx);
+        self.paragraphs.request_complete_repaint(ctx);
+        ctx.request_paint();
+    }
+
     fn update_text(&mut self, ctx: &mut EventCtx, value: u32) {
         let text = (self.description_func)(value);
         self.paragraphs.mutate(ctx, move |ctx, para| {
@@ -86,8 +117,28 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        if let Some(NumberInputMsg::Changed(i)) = self.input.event(ctx, event) {
-            self.update_text(ctx, i);
+        if let Some(keypad) = &mut self.keypad {
+            // The keypad takes all the events, including swipes, while open.
+            match keypad.event(ctx, event) {
+                Some(NumericKeyboardMsg::Confirmed(value)) => {
+                    self.input
+                        .mutate(ctx, |ctx, input| input.set_value(ctx, value));
+                    self.update_text(ctx, value);
+                    self.close_keypad(ctx);
+                }
+                Some(NumericKeyboardMsg::Cancelled) => self.close_keypad(ctx),
+                None => {}
+            }
+            return None;
+        }
+
+        match self.input.event(ctx, event) {
+            Some(NumberInputMsg::Changed(i)) => self.update_text(ctx, i),
+            Some(NumberInputMsg::EntryRequested) => {
+                self.open_keypad(ctx);
+                return None;
+            }
+            None => {}
         }
 
         if let Event::Swipe(SwipeEvent::End(SwipeDirection::Up)) = event {
@@ -102,6 +153,11 @@ where
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        if let Some(keypad) = &self.keypad {
+            shape::Bar::new(self.area).with_bg(theme::BG).render(target);
+            keypad.render(target);
+            return;
+        }
         self.input.render(target);
         self.paragraphs_pad.render(target);
         self.paragraphs.render(target);
@@ -112,6 +168,9 @@ where
         sink(self.area);
         self.input.bounds(sink);
         self.paragraphs.bounds(sink);
+        if let Some(keypad) = &self.keypad {
+            keypad.bounds(sink);
+        }
     }
 }
 
@@ -124,13 +183,26 @@ where
         t.component("NumberInputDialog");
         t.child("input", &self.input);
         t.child("paragraphs", &self.paragraphs);
+        if let Some(keypad) = &self.keypad {
+            t.child("keypad", keypad);
+        }
     }
 }
 
 pub enum NumberInputMsg {
     Changed(u32),
+    /// The value was tapped, asking for its direct entry.
+    EntryRequested,
 }
 
+/// Holding the plus or minus button keeps changing the value after this long.
+const REPEAT_DELAY: Duration = Duration::from_millis(500);
+const REPEAT_INTERVAL: Duration = Duration::from_millis(100);
+/// Holding the button for this long speeds up the changes to
+/// `ACCELERATED_STEP`, until released.
+const ACCELERATE_AFTER: Duration = Duration::from_secs(2);
+const ACCELERATED_STEP: u32 = 10;
+
 pub struct NumberInput {
     area: Rect,
     dec: Child<Button>,
@@ -138,15 +210,21 @@ pub struct NumberInput {
     min: u32,
     max: u32,
     value: u32,
+    /// Start of the current press of the plus or minus button.
+    held_since: Option<Instant>,
+    direct_entry: bool,
+    value_pressed: bool,
 }
 
 impl NumberInput {
     pub fn new(min: u32, max: u32, value: u32) -> Self {
         let dec = Button::with_icon(theme::ICON_MINUS)
             .styled(theme::button_counter())
+            .with_auto_repeat(REPEAT_DELAY, REPEAT_INTERVAL)
             .into_child();
         let inc = Button::with_icon(theme::ICON_PLUS)
             .styled(theme::button_counter())
+            .with_auto_repeat(REPEAT_DELAY, REPEAT_INTERVAL)
             .into_child();
         let value = value.clamp(min, max);
         Self {
@@ -156,6 +234,47 @@ impl NumberInput {
             min,
             max,
             value,
+            held_since: None,
+            direct_entry: false,
+            value_pressed: false,
+        }
+    }
+
+    pub fn with_direct_entry(mut self) -> Self {
+        self.direct_entry = true;
+        self
+    }
+
+    pub fn set_value(&mut self, ctx: &mut EventCtx, value: u32) {
+        self.value = value.clamp(self.min, self.max);
+        // Reaching a bound in the middle of a hold also stops the repeating.
+        self.dec
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value > self.min));
+        self.inc
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value < self.max));
+        ctx.request_paint();
+    }
+
+    /// Step by which the message of the plus or minus button changes the
+    /// value, if at all.
+    fn step(&mut self, msg: ButtonMsg) -> Option<u32> {
+        match msg {
+            ButtonMsg::Pressed => {
+                self.held_since = Some(Instant::now());
+                None
+            }
+            ButtonMsg::Released => {
+                self.held_since = None;
+                None
+            }
+            ButtonMsg::Clicked => Some(1),
+            ButtonMsg::Repeated => {
+                let accelerated = self.held_since.is_some_and(|since| {
+                    Instant::now().saturating_duration_since(since) >= ACCELERATE_AFTER
+                });
+                Some(if accelerated { ACCELERATED_STEP } else { 1 })
+            }
+            _ => None,
         }
     }
 }
@@ -172,21 +291,35 @@ impl Component for NumberInput {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let mut changed = false;
-        if let Some(ButtonMsg::Clicked) = self.dec.event(ctx, event) {
-            self.value = self.min.max(self.value.saturating_sub(1));
-            changed = true;
-        };
-        if let Some(ButtonMsg::Clicked) = self.inc.event(ctx, event) {
-            self.value = self.max.min(self.value.saturating_add(1));
-            changed = true;
-        };
-        if changed {
-            self.dec
-                .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value > self.min));
-            self.inc
-                .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value < self.max));
-            ctx.request_paint();
+        if self.direct_entry {
+            match event {
+                Event::Touch(TouchEvent::TouchStart(pos)) => {
+                    self.value_pressed = self.area.contains(pos);
+                }
+                Event::Touch(TouchEvent::TouchEnd(pos))
+                    if mem::take(&mut self.value_pressed) && self.area.contains(pos) =>
+                {
+                    return Some(NumberInputMsg::EntryRequested);
+                }
+                _ => {}
+            }
+        }
+
+        // Accelerated steps snap to their multiples.
+        let mut value = None;
+        if let Some(step) = self.dec.event(ctx, event).and_then(|msg| self.step(msg)) {
+            value = Some((self.value.saturating_sub(1) / step * step).max(self.min));
+        }
+        if let Some(step) = self.inc.event(ctx, event).and_then(|msg| self.step(msg)) {
+            value = Some(
+                (self.value / step)
+                    .saturating_add(1)
+                    .saturating_mul(step)
+                    .min(self.max),
+            );
+        }
+        if let Some(value) = value {
+            self.set_value(ctx, value);
             return Some(NumberInputMsg::Changed(self.value));
         }
         None
@@ -197,19 +330,16 @@ impl Component for NumberInput {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        let mut buf = [0u8; 10];
-
-        if let Some(text) = strutil::format_i64(self.value as i64, &mut buf) {
-            let digit_font = Font::DEMIBOLD;
-            let y_offset = digit_font.text_height() / 2;
+        let text = strutil::format_int(self.value as i64);
+        let digit_font = Font::DEMIBOLD;
+        let y_offset = digit_font.text_height() / 2;
 
-            shape::Bar::new(self.area).with_bg(theme::BG).render(target);
-            shape::Text::new(self.area.center() + Offset::y(y_offset), text)
-                .with_align(Alignment::Center)
-                .with_fg(theme::FG)
-                .with_font(digit_font)
-                .render(target);
-        }
+        shape::Bar::new(self.area).with_bg(theme::BG).render(target);
+        shape::Text::new(self.area.center() + Offset::y(y_offset), &text)
+            .with_align(Alignment::Center)
+            .with_fg(theme::FG)
+            .with_font(digit_font)
+            .render(target);
 
         self.dec.render(target);
         self.inc.render(target);
@@ -228,5 +358,10 @@ impl crate::trace::Trace for NumberInput {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("NumberInput");
         t.int("value", self.value as i64);
+        t.int("min", self.min as i64);
+        t.int("max", self.max as i64);
+        t.bool("direct_entry", self.direct_entry);
+        t.child("dec", &self.dec);
+        t.child("inc", &self.inc);
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    strutil::ShortString,
+    strutil,
     ui::{
         component::{base::ComponentExt, Child, Component, Event, EventCtx},
         constant::screen,
@@ -95,10 +95,9 @@ impl Component for NumberInputSliderDialog {
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
         self.input.render(target);
 
-        let mut str = ShortString::new();
         let val_pct = (100 * (self.val - self.min)) / (self.max - self.min);
-
-        unwrap!(ufmt::uwrite!(str, "{} %", val_pct));
+        let mut str = strutil::format_int(val_pct as i64);
+        strutil::push_str_truncated(&mut str, " %");
 
         shape::Text::new(self.text_area.center(), &str)
             .with_font(theme::TEXT_NORMAL.text_font)
@@ -135,6 +134,8 @@ pub struct NumberInputSlider {
     min: u16,
     max: u16,
     value: u16,
+    /// Touch started within `touch_area` and is still in progress.
+    dragging: bool,
 }
 
 impl NumberInputSlider {
@@ -146,21 +147,27 @@ impl NumberInputSlider {
             min,
             max,
             value,
+            dragging: false,
         }
     }
 
+    /// Value corresponding to the horizontal position `x`. Positions beyond
+    /// either end of the slider map to the respective extreme, so that both are
+    /// reachable even with an imprecise touch.
+    fn value_at(&self, x: i16) -> u16 {
+        let width = self.area.width().max(1) as u32;
+        let filled = (x - self.area.x0).clamp(0, width as i16) as u32;
+        let range = (self.max - self.min) as u32;
+        // Round to the nearest value.
+        self.min + ((filled * range + width / 2) / width) as u16
+    }
+
     pub fn slider_eval(&mut self, pos: Point, ctx: &mut EventCtx) -> Option<u16> {
-        if self.touch_area.contains(pos) {
-            let filled = pos.x - self.area.x0;
-            let filled = filled.clamp(0, self.area.width());
-            let val_pct = (filled as u16 * 100) / self.area.width() as u16;
-            let val = (val_pct * (self.max - self.min)) / 100 + self.min;
-
-            if val != self.value {
-                self.value = val;
-                ctx.request_paint();
-                return Some(self.value);
-            }
+        let val = self.value_at(pos.x);
+        if val != self.value {
+            self.value = val;
+            ctx.request_paint();
+            return Some(self.value);
         }
         None
     }
@@ -171,16 +178,25 @@ impl Component for NumberInputSlider {
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
-        self.touch_area = bounds.outset(Insets::new(40, 20, 40, 20)).clamp(screen());
+        self.touch_area = bounds.outset(Insets::symmetric(20, 40)).clamp(screen());
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         if let Event::Touch(touch_event) = event {
             return match touch_event {
-                TouchEvent::TouchStart(pos) => self.slider_eval(pos, ctx),
-                TouchEvent::TouchMove(pos) => self.slider_eval(pos, ctx),
-                TouchEvent::TouchEnd(pos) => self.slider_eval(pos, ctx),
+                TouchEvent::TouchStart(pos) if self.touch_area.contains(pos) => {
+                    self.dragging = true;
+                    self.slider_eval(pos, ctx)
+                }
+                // Keep following the finger once the drag started on the slider, even
+                // when it leaves the touch area towards the screen edge.
+                TouchEvent::TouchMove(pos) if self.dragging => self.slider_eval(pos, ctx),
+                TouchEvent::TouchEnd(pos) if self.dragging => {
+                    self.dragging = false;
+                    self.slider_eval(pos, ctx)
+                }
+                _ => None,
             };
         }
         None
@@ -226,5 +242,40 @@ impl crate::trace::Trace for NumberInputSlider {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("NumberInputSlider");
         t.int("value", self.value as i64);
+        t.int("min", self.min as i64);
+        t.int("max", self.max as i64);
+        // Lets tests compute the exact touch position of a value.
+        t.int("x0", self.area.x0 as i64);
+        t.int("x1", self.area.x1 as i64);
+        t.int("y", self.area.center().y as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slider(min: u16, max: u16) -> NumberInputSlider {
+        let mut slider = NumberInputSlider::new(min, max, min);
+        slider.place(Rect::new(Point::new(20, 100), Point::new(220, 115)));
+        slider
+    }
+
+    #[test]
+    fn slider_reaches_extremes() {
+        let s = slider(80, 255);
+        assert_eq!(s.value_at(20), 80);
+        assert_eq!(s.value_at(220), 255);
+        // Touch slop beyond either end.
+        assert_eq!(s.value_at(0), 80);
+        assert_eq!(s.value_at(239), 255);
+    }
+
+    #[test]
+    fn slider_every_value_reachable() {
+        let s = slider(80, 255);
+        for value in 80..=255 {
+            assert!((20..=220).any(|x| s.value_at(x) == value));
+        }
     }
 }
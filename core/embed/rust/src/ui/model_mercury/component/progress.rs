@@ -2,6 +2,7 @@ use core::mem;
 
 use crate::{
     strutil::TString,
+    time::{Duration, Instant},
     ui::{
         component::{
             base::ComponentExt,
@@ -22,6 +23,14 @@ use crate::{
 
 use super::theme;
 
+/// Lines reserved for a non-empty description, so that it can wrap without
+/// moving the loader.
+const DESCRIPTION_LINES: i16 = 2;
+
+/// Text updates arriving sooner after the last repaint are deferred to the
+/// next animation frame.
+const INFO_REPAINT_INTERVAL: Duration = Duration::from_millis(18);
+
 pub struct Progress {
     title: Child<Label<'static>>,
     value: u16,
@@ -29,6 +38,12 @@ pub struct Progress {
     indeterminate: bool,
     description: Child<Paragraphs<Paragraph<'static>>>,
     description_pad: Pad,
+    /// Phase label and estimated time remaining below the loader, if enabled.
+    info: Option<(Label<'static>, Label<'static>)>,
+    info_area: Rect,
+    info_pad: Pad,
+    info_painted: Option<Instant>,
+    info_pending: bool,
 }
 
 impl Progress {
@@ -49,39 +64,120 @@ impl Progress {
             )
             .into_child(),
             description_pad: Pad::with_background(theme::BG),
+            info: None,
+            info_area: Rect::zero(),
+            info_pad: Pad::with_background(theme::BG),
+            info_painted: None,
+            info_pending: false,
+        }
+    }
+
+    /// Reserve room for a phase label and the estimated time remaining below
+    /// the loader. Both can be updated by `Event::ProgressInfo`.
+    pub fn with_info(mut self, phase: TString<'static>, eta: TString<'static>) -> Self {
+        self.info = Some((
+            Label::centered(phase, theme::TEXT_SUB_GREY_LIGHT).with_middle_truncation(),
+            Label::centered(eta, theme::TEXT_SUB_GREY),
+        ));
+        self
+    }
+
+    fn update_info(&mut self, ctx: &mut EventCtx, phase: TString<'static>, eta: TString<'static>) {
+        let Some((phase_label, eta_label)) = &mut self.info else {
+            return;
+        };
+        if phase_label.text() == &phase && eta_label.text() == &eta {
+            return;
+        }
+        phase_label.set_text(phase);
+        eta_label.set_text(eta);
+        self.info_pad.clear();
+        self.info_pending = true;
+        self.repaint_info(ctx);
+    }
+
+    /// Repaint the pending info update, unless the last one was just painted.
+    /// In that case, try again with the next animation frame.
+    fn repaint_info(&mut self, ctx: &mut EventCtx) {
+        if !self.info_pending {
+            return;
+        }
+        let now = Instant::now();
+        let recently = self
+            .info_painted
+            .is_some_and(|painted| now.saturating_duration_since(painted) < INFO_REPAINT_INTERVAL);
+        if recently {
+            ctx.request_anim_frame();
+        } else {
+            ctx.request_paint_region(self.info_area);
+            self.info_painted = Some(now);
+            self.info_pending = false;
         }
     }
+
+    /// Lines of the area reserved for `description`.
+    fn description_lines(description: &str) -> i16 {
+        if description.is_empty() {
+            return 0;
+        }
+        let lines = 1 + description.chars().filter(|c| *c == '\n').count() as i16;
+        lines.max(DESCRIPTION_LINES)
+    }
 }
 
 impl Component for Progress {
     type Msg = Never;
 
     fn place(&mut self, _bounds: Rect) -> Rect {
-        let description_lines = 1 + self
+        let description_lines = self
             .description
             .inner()
             .inner()
             .content()
-            .map(|t| t.chars().filter(|c| *c == '\n').count() as i16);
+            .map(Self::description_lines);
         let (title, rest) = Self::AREA.split_top(self.title.inner().max_size().y);
-        let (loader, description) =
-            rest.split_bottom(Font::NORMAL.line_height() * description_lines);
+        let (rest, description) = rest.split_bottom(Font::NORMAL.line_height() * description_lines);
+        let info_height = if self.info.is_some() {
+            2 * Font::SUB.line_height()
+        } else {
+            0
+        };
+        let (loader, info) = rest.split_bottom(info_height);
         let loader = loader.inset(Insets::top(theme::CONTENT_BORDER));
         self.title.place(title);
         self.loader_y_offset = loader.center().y - constant::screen().center().y;
+        if let Some((phase, eta)) = &mut self.info {
+            let (phase_area, eta_area) = info.split_top(Font::SUB.line_height());
+            phase.place(phase_area);
+            eta.place(eta_area);
+        }
+        self.info_area = info;
+        self.info_pad.place(info);
         self.description.place(description);
         self.description_pad.place(description);
         Self::AREA
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::ProgressInfo(phase, eta) = event {
+            self.update_info(ctx, phase, eta);
+        }
+        if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
+            self.repaint_info(ctx);
+        }
         if let Event::Progress(new_value, new_description) = event {
+            // Flush an info update deferred by the previous report.
+            self.repaint_info(ctx);
             if mem::replace(&mut self.value, new_value) != new_value {
                 if !animation_disabled() {
                     ctx.request_paint();
                 }
                 self.description.mutate(ctx, |ctx, para| {
                     if para.inner_mut().content() != &new_description {
+                        if para.inner_mut().content().is_empty() != new_description.is_empty() {
+                            // Make room for the description, or give it back.
+                            ctx.request_place();
+                        }
                         para.inner_mut().update(new_description);
                         para.change_page(0); // Recompute bounding box.
                         ctx.request_paint();
@@ -106,6 +202,11 @@ impl Component for Progress {
         } else {
             display::loader(self.value, self.loader_y_offset, theme::FG, theme::BG, None);
         }
+        self.info_pad.paint();
+        if let Some((phase, eta)) = &mut self.info {
+            phase.paint();
+            eta.paint();
+        }
         self.description_pad.paint();
         self.description.paint();
     }
@@ -142,6 +243,12 @@ impl Component for Progress {
             target,
         );
 
+        self.info_pad.render(target);
+        if let Some((phase, eta)) = &self.info {
+            phase.render(target);
+            eta.render(target);
+        }
+
         self.description_pad.render(target);
         self.description.render(target);
     }
@@ -158,5 +265,10 @@ impl Component for Progress {
 impl crate::trace::Trace for Progress {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Progress");
+        t.int("value", self.value as i64);
+        if let Some((phase, eta)) = &self.info {
+            t.string("phase", *phase.text());
+            t.string("eta", *eta.text());
+        }
     }
 }
@@ -1,8 +1,11 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx},
-    geometry::Rect,
-    model_mercury::theme,
-    shape::Renderer,
+use crate::{
+    time::Duration,
+    ui::{
+        component::{Component, Event, EventCtx},
+        geometry::Rect,
+        model_mercury::theme,
+        shape::Renderer,
+    },
 };
 
 use super::{HoldToConfirm, TapToConfirm};
@@ -20,6 +23,20 @@ impl PromptScreen {
         PromptScreen::Hold(HoldToConfirm::new())
     }
 
+    /// Confirmation of a critical action, the button has to be held until the
+    /// ring around it fills up.
+    pub fn new_hold_to_confirm_danger() -> Self {
+        PromptScreen::Tap(
+            TapToConfirm::new(
+                theme::ORANGE_LIGHT,
+                theme::ORANGE_LIGHT,
+                theme::GREY_EXTRA_DARK,
+                theme::ORANGE_DIMMED,
+            )
+            .with_hold(Duration::from_millis(1500)),
+        )
+    }
+
     pub fn new_tap_to_confirm() -> Self {
         PromptScreen::Tap(TapToConfirm::new(
             theme::GREEN,
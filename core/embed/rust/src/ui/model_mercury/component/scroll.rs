@@ -1,18 +1,24 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx, Never},
-    display::toif::Icon,
-    geometry::{Alignment2D, Axis, LinearPlacement, Offset, Rect},
-    shape,
-    shape::Renderer,
+use crate::{
+    strutil::{self, ShortString},
+    ui::{
+        component::{Component, Event, EventCtx, Never},
+        display::{self, toif::Icon, Font},
+        geometry::{Alignment, Alignment2D, Axis, LinearPlacement, Offset, Point, Rect},
+        shape,
+        shape::Renderer,
+    },
 };
 
 use super::theme;
 
+#[derive(Clone)]
 pub struct ScrollBar {
     area: Rect,
     layout: LinearPlacement,
     pub page_count: usize,
     pub active_page: usize,
+    /// Above this page count the dots are replaced by a "7/12" counter.
+    numeric_threshold: usize,
 }
 
 impl ScrollBar {
@@ -22,6 +28,12 @@ impl ScrollBar {
     const MAX_DOTS: usize = 7;
     /// Center to center.
     const DOT_INTERVAL: i16 = 18;
+    /// Page count above which the numeric counter is shown by default.
+    pub const DEFAULT_NUMERIC_THRESHOLD: usize = 8;
+    /// Font of the numeric counter.
+    const NUMERIC_FONT: Font = Font::SUB;
+    /// Widest counter the numeric representation is expected to display.
+    const NUMERIC_WIDEST: &'static str = "99/99";
 
     pub fn new(axis: Axis) -> Self {
         let layout = LinearPlacement::new(axis);
@@ -30,9 +42,51 @@ impl ScrollBar {
             layout: layout.align_at_center().with_spacing(Self::DOT_INTERVAL),
             page_count: 0,
             active_page: 0,
+            numeric_threshold: Self::DEFAULT_NUMERIC_THRESHOLD,
         }
     }
 
+    /// Show the numeric counter instead of dots when there are more than
+    /// `threshold` pages. Use `usize::MAX` to always show dots.
+    pub fn with_numeric_threshold(mut self, threshold: usize) -> Self {
+        self.numeric_threshold = threshold;
+        self
+    }
+
+    /// Size needed by either representation, so that parents can reserve a
+    /// fixed space and neighbouring elements don't shift when the scrollbar
+    /// switches between dots and the counter.
+    pub fn extent(&self) -> Offset {
+        let dots_length = Self::DOT_INTERVAL * (Self::MAX_DOTS as i16 - 1) + Self::DOT_SIZE;
+        let dots = match self.layout.axis {
+            Axis::Horizontal => Offset::new(dots_length, Self::DOT_SIZE),
+            Axis::Vertical => Offset::new(Self::DOT_SIZE, dots_length),
+        };
+        let numeric = Offset::new(
+            Self::NUMERIC_FONT.text_width(Self::NUMERIC_WIDEST),
+            Self::NUMERIC_FONT.text_height(),
+        );
+        Offset::new(dots.x.max(numeric.x), dots.y.max(numeric.y))
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.page_count > self.numeric_threshold
+    }
+
+    /// "7/12" for the current page.
+    fn counter(&self) -> ShortString {
+        let mut counter = ShortString::new();
+        unwrap!(strutil::push_int(&mut counter, self.active_page as i64 + 1));
+        unwrap!(counter.push('/'));
+        unwrap!(strutil::push_int(&mut counter, self.page_count as i64));
+        counter
+    }
+
+    /// Baseline origin of the numeric counter, centered in the area.
+    fn counter_origin(&self) -> Point {
+        self.area.center() + Offset::y(Self::NUMERIC_FONT.visible_text_height("1") / 2)
+    }
+
     pub fn vertical() -> Self {
         Self::new(Axis::Vertical)
     }
@@ -85,6 +139,17 @@ impl Component for ScrollBar {
     }
 
     fn paint(&mut self) {
+        if self.is_numeric() {
+            display::text_center(
+                self.counter_origin(),
+                &self.counter(),
+                Self::NUMERIC_FONT,
+                theme::FG,
+                theme::BG,
+            );
+            return;
+        }
+
         fn dotsize(distance: usize, nhidden: usize) -> Icon {
             match (nhidden.saturating_sub(distance)).min(2 - distance) {
                 0 => theme::DOT_INACTIVE,
@@ -125,6 +190,15 @@ impl Component for ScrollBar {
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
+        if self.is_numeric() {
+            shape::Text::new(self.counter_origin(), &self.counter())
+                .with_align(Alignment::Center)
+                .with_font(Self::NUMERIC_FONT)
+                .with_fg(theme::FG)
+                .render(target);
+            return;
+        }
+
         fn dotsize(distance: usize, nhidden: usize) -> Icon {
             match (nhidden.saturating_sub(distance)).min(2 - distance) {
                 0 => theme::DOT_INACTIVE,
@@ -177,3 +251,72 @@ impl Component for ScrollBar {
         sink(self.area);
     }
 }
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for ScrollBar {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("ScrollBar");
+        t.int("page", self.active_page as i64);
+        t.int("page_count", self.page_count as i64);
+        t.bool("numeric", self.is_numeric());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::shape::{BoundsRenderer, Viewport};
+
+    /// Area covered by `scrollbar` placed into an area of its `extent()`.
+    fn render(mut scrollbar: ScrollBar) -> (Rect, Rect) {
+        let area = Rect::from_top_left_and_size(Point::new(20, 20), scrollbar.extent());
+        scrollbar.place(area);
+        let mut renderer = BoundsRenderer::new(Viewport::new(Rect::new(
+            Point::zero(),
+            Point::new(240, 240),
+        )));
+        scrollbar.render(&mut renderer);
+        (area, unwrap!(renderer.covered()))
+    }
+
+    fn scrollbar(page_count: usize, active_page: usize) -> ScrollBar {
+        let mut scrollbar = ScrollBar::horizontal();
+        scrollbar.set_count_and_active_page(page_count, active_page);
+        scrollbar
+    }
+
+    #[test]
+    fn numeric_above_threshold() {
+        assert!(!scrollbar(5, 0).is_numeric());
+        assert!(!scrollbar(ScrollBar::DEFAULT_NUMERIC_THRESHOLD, 0).is_numeric());
+        assert!(scrollbar(20, 0).is_numeric());
+        assert!(!scrollbar(20, 0)
+            .with_numeric_threshold(usize::MAX)
+            .is_numeric());
+    }
+
+    #[test]
+    fn counter_follows_active_page() {
+        let mut s = scrollbar(20, 6);
+        assert_eq!(s.counter().as_str(), "7/20");
+        s.go_to_next_page();
+        assert_eq!(s.counter().as_str(), "8/20");
+        s.go_to(19);
+        assert_eq!(s.counter().as_str(), "20/20");
+    }
+
+    /// Both the dots (5 pages) and the counter (20 pages) stay within the width
+    /// reserved by `extent()`.
+    #[test]
+    fn rendered_within_extent() {
+        for page_count in [5, 20] {
+            for mut scrollbar in [ScrollBar::horizontal(), ScrollBar::vertical()] {
+                scrollbar.set_count_and_active_page(page_count, 3);
+                let numeric = scrollbar.is_numeric();
+                let (area, covered) = render(scrollbar);
+                assert_eq!(numeric, page_count == 20);
+                assert!(covered.x0 >= area.x0 && covered.x1 <= area.x1);
+            }
+        }
+    }
+}
@@ -14,16 +14,24 @@ use super::{
     CancelConfirmMsg,
 };
 
-pub struct SetBrightnessDialog(NumberInputSliderDialog);
+pub struct SetBrightnessDialog {
+    input: NumberInputSliderDialog,
+    /// Stored backlight level, restored on cancel. Not read from the display,
+    /// which may be in the middle of fading in.
+    original: u16,
+}
 
 impl SetBrightnessDialog {
     pub fn new(current: Option<u16>) -> Self {
-        let current = current.unwrap_or(theme::backlight::get_backlight_normal());
-        Self(NumberInputSliderDialog::new(
-            theme::backlight::get_backlight_min(),
-            theme::backlight::get_backlight_max(),
-            current,
-        ))
+        let min = theme::backlight::get_backlight_min();
+        let max = theme::backlight::get_backlight_max();
+        let current = current
+            .unwrap_or(theme::backlight::get_backlight_normal())
+            .clamp(min, max);
+        Self {
+            input: NumberInputSliderDialog::new(min, max, current),
+            original: theme::backlight::get_backlight_normal(),
+        }
     }
 }
 
@@ -31,20 +39,27 @@ impl Component for SetBrightnessDialog {
     type Msg = CancelConfirmMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        self.0.place(bounds)
+        self.input.place(bounds)
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        match self.0.event(ctx, event) {
+        match self.input.event(ctx, event) {
             Some(NumberInputSliderDialogMsg::Changed(value)) => {
+                // Preview the level live, it is only persisted on confirm.
                 display::backlight(value as _);
                 None
             }
-            Some(NumberInputSliderDialogMsg::Cancelled) => Some(CancelConfirmMsg::Cancelled),
+            Some(NumberInputSliderDialogMsg::Cancelled) => {
+                display::backlight(self.original as _);
+                Some(CancelConfirmMsg::Cancelled)
+            }
             Some(NumberInputSliderDialogMsg::Confirmed) => {
-                match storage::set_brightness(self.0.value() as _) {
+                match storage::set_brightness(self.input.value() as _) {
                     Ok(_) => Some(CancelConfirmMsg::Confirmed),
-                    Err(_) => Some(CancelConfirmMsg::Cancelled), // TODO: handle error
+                    Err(_) => {
+                        display::backlight(self.original as _);
+                        Some(CancelConfirmMsg::Cancelled) // TODO: handle error
+                    }
                 }
             }
             None => None,
@@ -52,16 +67,16 @@ impl Component for SetBrightnessDialog {
     }
 
     fn paint(&mut self) {
-        self.0.paint()
+        self.input.paint()
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        self.0.render(target);
+        self.input.render(target);
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
-        self.0.bounds(sink);
+        self.input.bounds(sink);
     }
 }
 
@@ -69,6 +84,7 @@ impl Component for SetBrightnessDialog {
 impl crate::trace::Trace for SetBrightnessDialog {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("SetBrightnessDialog");
-        t.child("input", &self.0);
+        t.child("input", &self.input);
+        t.int("original", self.original as i64);
     }
 }
@@ -1,6 +1,6 @@
 use super::theme;
 use crate::{
-    strutil::TString,
+    strutil::{format_int, format_template, ShortString, TString},
     time::Duration,
     translations::TR,
     ui::{
@@ -33,6 +33,8 @@ pub struct ShareWords<'a> {
     animation: Option<Animation<f32>>,
     /// Footer component for instructions and word counting
     footer: Footer<'static>,
+    /// Area above the footer showing the position of the word in the share
+    area_counter: Rect,
     progress: i16,
 }
 
@@ -48,6 +50,7 @@ impl<'a> ShareWords<'a> {
             area_word: Rect::zero(),
             animation: None,
             footer: Footer::new(TR::instructions__swipe_up),
+            area_counter: Rect::zero(),
             progress: 0,
         }
     }
@@ -60,6 +63,39 @@ impl<'a> ShareWords<'a> {
         self.page_index == self.share_words.len() as i16 - 1
     }
 
+    /// Indices of the words on the screen, two of them during the transition.
+    fn visible_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        let next = (self.progress > 0).then_some(self.next_index);
+        core::iter::once(self.page_index)
+            .chain(next)
+            .filter(|&i| (0..self.share_words.len() as i16).contains(&i))
+            .map(|i| i as usize)
+    }
+
+    /// "Word 13 of 24" for the current page.
+    fn counter(&self) -> ShortString {
+        let index = format_int(self.page_index as i64 + 1);
+        let total = format_int(self.share_words.len() as i64);
+        let mut counter = ShortString::new();
+        TR::recovery__word_x_of_y_template.map_translated(|t| {
+            unwrap!(format_template(t, &[&index, &total], &mut counter));
+        });
+        counter
+    }
+
+    fn render_counter<'s>(&self, target: &mut impl Renderer<'s>) {
+        let style = theme::TEXT_SUB_GREY_LIGHT;
+        let descent = style.text_font.visible_text_height_ex("Ay").1;
+        shape::Text::new(
+            self.area_counter.bottom_center() - Offset::y(descent),
+            &self.counter(),
+        )
+        .with_font(style.text_font)
+        .with_fg(theme::GREY)
+        .with_align(Alignment::Center)
+        .render(target);
+    }
+
     fn render_word<'s>(&self, word_index: i16, target: &mut impl Renderer<'s>) {
         // the share word
         if word_index >= self.share_words.len() as _ || word_index < 0 {
@@ -92,8 +128,10 @@ impl<'a> Component for ShareWords<'a> {
             Alignment2D::CENTER,
         );
 
-        self.footer
-            .place(used_area.split_bottom(Footer::HEIGHT_SIMPLE).1);
+        let footer_area = used_area.split_bottom(Footer::HEIGHT_DEFAULT).1;
+        let (area_counter, footer_area) = footer_area.split_bottom(Footer::HEIGHT_SIMPLE);
+        self.area_counter = area_counter;
+        self.footer.place(footer_area);
 
         self.area
     }
@@ -191,7 +229,8 @@ impl<'a> Component for ShareWords<'a> {
             })
         };
 
-        // footer with instructions
+        // footer with the word counter and instructions
+        self.render_counter(target);
         self.footer.render(target);
     }
 
@@ -207,6 +246,12 @@ impl<'a> crate::trace::Trace for ShareWords<'a> {
         let content =
             word.map(|w| build_string!(50, inttostr!(self.page_index as u8 + 1), ". ", w, "\n"));
         t.string("screen_content", content.as_str().into());
-        t.int("page_count", self.share_words.len() as i64)
+        t.int("page_count", self.share_words.len() as i64);
+        t.in_list("visible_indices", &|list| {
+            for i in self.visible_indices() {
+                list.int(i as i64);
+            }
+        });
+        t.string("counter", self.counter().as_str().into());
     }
 }
@@ -1,10 +1,12 @@
 use crate::{
     time::{Duration, Stopwatch},
     ui::{
-        component::{Component, Event, EventCtx, Timeout},
+        component::{Component, Event, EventCtx, SwipeDirection, Timer},
         constant::screen,
         display::{Color, Icon},
+        event::{SwipeEvent, TouchEvent},
         geometry::{Alignment2D, Insets, Rect},
+        led,
         lerp::Lerp,
         shape,
         shape::Renderer,
@@ -86,34 +88,127 @@ impl StatusAnimation {
     }
 }
 
+/// Automatic dismissal of the status screen which can be paused.
+#[derive(Clone)]
+struct Countdown {
+    timeout: Duration,
+    timer: Timer,
+    /// Running while counting down, stopped while paused.
+    stopwatch: Stopwatch,
+    /// Time counted before the last pause.
+    counted: Duration,
+}
+
+impl Countdown {
+    fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            timer: Timer::new(),
+            stopwatch: Stopwatch::new_stopped(),
+            counted: Duration::ZERO,
+        }
+    }
+
+    fn start(&mut self, ctx: &mut EventCtx) {
+        self.counted = Duration::ZERO;
+        self.stopwatch.start();
+        self.timer.start(ctx, self.timeout);
+    }
+
+    fn pause(&mut self, ctx: &mut EventCtx) {
+        if self.stopwatch.is_running() {
+            self.counted = self.elapsed();
+            self.stopwatch = Stopwatch::new_stopped();
+            self.timer.stop(ctx);
+        }
+    }
+
+    fn resume(&mut self, ctx: &mut EventCtx) {
+        if !self.stopwatch.is_running() {
+            self.stopwatch.start();
+            let remaining = self
+                .timeout
+                .checked_sub(self.counted)
+                .unwrap_or(Duration::ZERO);
+            self.timer.start(ctx, remaining);
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        unwrap!(self.counted.checked_add(self.stopwatch.elapsed()))
+    }
+
+    /// Portion of the timeout already elapsed, 0.0 to 1.0.
+    fn progress(&self) -> f32 {
+        (self.elapsed() / self.timeout).min(1.0)
+    }
+}
+
+pub enum StatusScreenMsg {
+    /// The user tapped the screen or swiped up.
+    Dismissed,
+    /// The screen was shown for the whole timeout.
+    TimedOut,
+}
+
 /// Component showing status of an operation. Most typically embedded as a
 /// content of a Frame and showing success (checkmark with a circle around).
+/// The screen can always be dismissed by a tap or a swipe up, optionally it
+/// also dismisses itself after a timeout, drawing the elapsed time around the
+/// icon.
 #[derive(Clone)]
 pub struct StatusScreen {
     area: Rect,
     icon: Icon,
     icon_color: Color,
     circle_color: Color,
-    dismiss_type: DismissType,
+    /// LED pulse shown when the screen appears.
+    signal: Option<led::Signal>,
     anim: StatusAnimation,
-}
-
-#[derive(Clone)]
-enum DismissType {
-    SwipeUp,
-    Timeout(Timeout),
+    countdown: Option<Countdown>,
+    paused: bool,
+    touch_started: bool,
 }
 
 impl StatusScreen {
-    fn new(icon: Icon, icon_color: Color, circle_color: Color, dismiss_style: DismissType) -> Self {
+    fn new(
+        icon: Icon,
+        icon_color: Color,
+        circle_color: Color,
+        signal: Option<led::Signal>,
+    ) -> Self {
         Self {
             area: Rect::zero(),
             icon,
             icon_color,
             circle_color,
-            dismiss_type: dismiss_style,
+            signal,
             anim: StatusAnimation::default(),
+            countdown: None,
+            paused: false,
+            touch_started: false,
+        }
+    }
+
+    /// Dismiss the screen automatically after `timeout`, if any.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.countdown = timeout.map(Countdown::new);
+        self
+    }
+
+    /// Stop counting down while something covers the screen, e.g. a blocking
+    /// dialog, and continue where it stopped afterwards.
+    pub fn set_paused(&mut self, ctx: &mut EventCtx, paused: bool) {
+        self.paused = paused;
+        if let Some(countdown) = &mut self.countdown {
+            if paused {
+                countdown.pause(ctx);
+            } else {
+                countdown.resume(ctx);
+                ctx.request_anim_frame();
+            }
         }
+        ctx.request_paint();
     }
 
     pub fn new_success() -> Self {
@@ -121,17 +216,12 @@ impl StatusScreen {
             theme::ICON_SIMPLE_CHECKMARK,
             theme::GREEN_LIME,
             theme::GREEN_LIGHT,
-            DismissType::SwipeUp,
+            Some(led::Signal::Success),
         )
     }
 
     pub fn new_success_timeout() -> Self {
-        Self::new(
-            theme::ICON_SIMPLE_CHECKMARK,
-            theme::GREEN_LIME,
-            theme::GREEN_LIGHT,
-            DismissType::Timeout(Timeout::new(TIMEOUT_MS)),
-        )
+        Self::new_success().with_timeout(Some(Duration::from_millis(TIMEOUT_MS)))
     }
 
     pub fn new_neutral() -> Self {
@@ -139,22 +229,26 @@ impl StatusScreen {
             theme::ICON_SIMPLE_CHECKMARK,
             theme::GREY_EXTRA_LIGHT,
             theme::GREY_DARK,
-            DismissType::SwipeUp,
+            None,
         )
     }
 
     pub fn new_neutral_timeout() -> Self {
-        Self::new(
-            theme::ICON_SIMPLE_CHECKMARK,
-            theme::GREY_EXTRA_LIGHT,
-            theme::GREY_DARK,
-            DismissType::Timeout(Timeout::new(TIMEOUT_MS)),
-        )
+        Self::new_neutral().with_timeout(Some(Duration::from_millis(TIMEOUT_MS)))
+    }
+}
+
+impl StatusScreen {
+    fn dismiss(&mut self, ctx: &mut EventCtx) -> Option<StatusScreenMsg> {
+        if let Some(countdown) = &mut self.countdown {
+            countdown.timer.stop(ctx);
+        }
+        Some(StatusScreenMsg::Dismissed)
     }
 }
 
 impl Component for StatusScreen {
-    type Msg = ();
+    type Msg = StatusScreenMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
@@ -164,20 +258,45 @@ impl Component for StatusScreen {
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         if let Event::Attach(_) = event {
             self.anim.start();
+            if let Some(signal) = self.signal {
+                led::signal(ctx, signal);
+            }
+            if let Some(countdown) = &mut self.countdown {
+                countdown.start(ctx);
+                if self.paused {
+                    countdown.pause(ctx);
+                }
+            }
+            self.touch_started = false;
             ctx.request_paint();
             ctx.request_anim_frame();
         }
         if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
-            if self.anim.is_active() {
+            let counting = self.countdown.is_some() && !self.paused && !animation_disabled();
+            if self.anim.is_active() || counting {
                 ctx.request_anim_frame();
                 ctx.request_paint();
             }
         }
+        if let Some(countdown) = &mut self.countdown {
+            if countdown.timer.expire(event) {
+                return Some(StatusScreenMsg::TimedOut);
+            }
+        }
 
-        if let DismissType::Timeout(ref mut timeout) = self.dismiss_type {
-            if timeout.event(ctx, event).is_some() {
-                return Some(());
+        match event {
+            Event::Touch(TouchEvent::TouchStart(point)) => {
+                self.touch_started = self.area.contains(point);
+            }
+            Event::Touch(TouchEvent::TouchEnd(point))
+                if self.touch_started && self.area.contains(point) =>
+            {
+                return self.dismiss(ctx);
+            }
+            Event::Swipe(SwipeEvent::End(SwipeDirection::Up)) => {
+                return self.dismiss(ctx);
             }
+            _ => {}
         }
 
         None
@@ -195,6 +314,21 @@ impl Component for StatusScreen {
             .with_bg(theme::BLACK)
             .with_thickness(2)
             .render(target);
+        if let Some(countdown) = &self.countdown {
+            // Elapsed part of the timeout, drawn over the circle once it settles.
+            if !self.anim.is_active() && !animation_disabled() {
+                let end = 360.0 * countdown.progress();
+                if end > 0.0 {
+                    shape::Circle::new(self.area.center(), self.anim.get_circle_radius(t))
+                        .with_fg(self.icon_color)
+                        .with_bg(theme::BLACK)
+                        .with_thickness(2)
+                        .with_start_angle(0.0)
+                        .with_end_angle(end)
+                        .render(target);
+                }
+            }
+        }
         shape::ToifImage::new(self.area.center(), self.icon.toif)
             .with_align(Alignment2D::CENTER)
             .with_fg(self.icon_color)
@@ -220,5 +354,9 @@ impl Component for StatusScreen {
 impl crate::trace::Trace for StatusScreen {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("StatusScreen");
+        if let Some(countdown) = &self.countdown {
+            t.int("timeout_ms", countdown.timeout.to_millis() as i64);
+        }
+        t.bool("paused", self.paused);
     }
 }
@@ -12,6 +12,8 @@ use crate::{
     },
 };
 
+use easer::functions::{Cubic, Easing};
+
 #[derive(Default, Clone)]
 struct AttachAnimation {
     pub timer: Stopwatch,
@@ -181,16 +183,9 @@ impl<T: Component> Component for SwipeContent<T> {
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
         let progress = self.progress as f32 / 1000.0;
 
-        let shift = pareen::constant(0.0).seq_ease_out(
-            0.0,
-            easer::functions::Cubic,
-            1.0,
-            pareen::constant(1.0),
-        );
-
-        let offset = i16::lerp(0, 50, shift.eval(progress));
+        let offset = i16::lerp_eased(0, 50, progress, Cubic::ease_out);
 
-        let mask = u8::lerp(0, 255, shift.eval(progress));
+        let mask = u8::lerp_eased(0, 255, progress, Cubic::ease_out);
 
         if self.progress > 0 {
             match self.dir {
@@ -1,15 +1,20 @@
 use crate::ui::{
-    component::{Component, Event, EventCtx, SwipeDetect, SwipeDetectMsg},
+    component::{
+        base::AttachType, swipe_detect::SwipeSettings, Component, Event, EventCtx, SwipeDetect,
+        SwipeDetectMsg, SwipeDirection,
+    },
     event::SwipeEvent,
     flow::Swipable,
     geometry::Rect,
     shape::Renderer,
 };
 
-/// Wrapper component adding "swipe up" handling to `content`.
+/// Wrapper component adding "swipe up" handling to `content`. Content with
+/// vertical pages is scrolled through first.
 pub struct SwipeUpScreen<T> {
     content: T,
     swipe: SwipeDetect,
+    page: usize,
 }
 
 pub enum SwipeUpScreenMsg<T> {
@@ -28,6 +33,7 @@ where
         Self {
             content,
             swipe: SwipeDetect::new(),
+            page: 0,
         }
     }
 
@@ -45,10 +51,24 @@ impl<T: Swipable + Component> Component for SwipeUpScreen<T> {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let e = match self
-            .swipe
-            .event(ctx, event, self.content.get_swipe_config())
-        {
+        let mut config = self.content.get_swipe_config();
+        let page_count = self.content.get_internal_page_count();
+        if config.has_vertical_pages() && self.page > 0 {
+            config = config.with_swipe(SwipeDirection::Down, SwipeSettings::default());
+        }
+
+        let e = match self.swipe.event(ctx, event, config) {
+            Some(SwipeDetectMsg::Trigger(dir)) if config.has_vertical_pages() => {
+                match dir {
+                    SwipeDirection::Up if self.page + 1 < page_count => self.page += 1,
+                    SwipeDirection::Down if self.page > 0 => self.page -= 1,
+                    _ => return Some(SwipeUpScreenMsg::Swiped),
+                }
+                let msg = self.content.event(ctx, Event::Swipe(SwipeEvent::End(dir)));
+                self.content
+                    .event(ctx, Event::Attach(AttachType::Swipe(dir)));
+                return msg.map(SwipeUpScreenMsg::Content);
+            }
             Some(SwipeDetectMsg::Trigger(_)) => {
                 return Some(SwipeUpScreenMsg::Swiped);
             }
@@ -82,6 +102,7 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("SwipeUpScreen");
+        t.int("page", self.page as i64);
         t.child("content", &self.content);
     }
 }
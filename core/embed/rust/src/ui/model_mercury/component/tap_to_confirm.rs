@@ -1,6 +1,7 @@
 use crate::{
-    time::Duration,
+    time::{Duration, Instant},
     ui::{
+        animation::Animation,
         component::{Component, Event, EventCtx},
         display::Color,
         geometry::{Alignment2D, Offset, Rect},
@@ -13,6 +14,8 @@ use crate::{
 
 use super::{theme, Button, ButtonContent, ButtonMsg};
 
+#[cfg(feature = "haptic")]
+use crate::trezorhal::haptic::{self, HapticEffect};
 use crate::{time::Stopwatch, ui::constant::screen};
 use pareen;
 
@@ -111,6 +114,65 @@ impl TapToConfirmAmin {
     }
 }
 
+/// Progress ring of the hold-to-confirm variant, filling while the button is
+/// held and draining back when released early.
+#[derive(Clone)]
+struct HoldRing {
+    duration: Duration,
+    /// Progress between 0.0 (empty) and 1.0 (full).
+    progress: Option<Animation<f32>>,
+}
+
+impl HoldRing {
+    /// Time to drain a full ring after an early release.
+    const DRAIN_DURATION: Duration = Duration::from_millis(400);
+
+    fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            progress: None,
+        }
+    }
+
+    fn value(&self, now: Instant) -> f32 {
+        self.progress.as_ref().map_or(0.0, |a| a.value(now))
+    }
+
+    /// Continue filling from the current value, the remaining part of the ring
+    /// takes the respective part of the hold duration.
+    fn fill(&mut self, now: Instant) {
+        let value = self.value(now);
+        self.progress = Some(Animation::new(
+            value,
+            1.0,
+            self.duration * (1.0 - value),
+            now,
+        ));
+    }
+
+    fn drain(&mut self, now: Instant) {
+        let value = self.value(now);
+        self.progress = Some(Animation::new(
+            value,
+            0.0,
+            Self::DRAIN_DURATION * value,
+            now,
+        ));
+    }
+
+    fn is_filling(&self) -> bool {
+        self.progress.as_ref().is_some_and(|a| a.to > 0.0)
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        self.is_filling() && self.progress.as_ref().is_some_and(|a| a.finished(now))
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.progress.as_ref().is_some_and(|a| !a.finished(now))
+    }
+}
+
 /// Component requesting a Tap to confirm action from a user. Most typically
 /// embedded as a content of a Frame.
 #[derive(Clone)]
@@ -121,7 +183,11 @@ pub struct TapToConfirm {
     circle_pad_color: Color,
     circle_inner_color: Color,
     mask_color: Color,
+    icon_color: Color,
     anim: TapToConfirmAmin,
+    /// Present if the button has to be held instead of tapped.
+    hold: Option<HoldRing>,
+    confirmed: bool,
 }
 
 #[derive(Clone)]
@@ -144,10 +210,72 @@ impl TapToConfirm {
             circle_inner_color,
             circle_pad_color,
             mask_color,
+            icon_color: theme::confirm_pad_icon_color(),
             button,
             anim: TapToConfirmAmin::default(),
+            hold: None,
+            confirmed: false,
         }
     }
+
+    /// Require the button to be held for `duration` instead of a tap, showing
+    /// the progress as a ring filling around the button.
+    pub fn with_hold(mut self, duration: Duration) -> Self {
+        self.button = self.button.without_haptics();
+        self.hold = Some(HoldRing::new(duration));
+        self
+    }
+
+    fn confirm(&mut self, ctx: &mut EventCtx) -> Option<()> {
+        self.confirmed = true;
+        if animation_disabled() {
+            return Some(());
+        }
+        self.anim.start();
+        ctx.request_anim_frame();
+        ctx.request_paint();
+        None
+    }
+
+    fn hold_event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<()> {
+        let Some(ring) = self.hold.as_mut() else {
+            return None;
+        };
+        let now = Instant::now();
+        match self.button.event(ctx, event) {
+            Some(ButtonMsg::Pressed) if !self.confirmed => {
+                #[cfg(feature = "haptic")]
+                haptic::play(HapticEffect::HoldStart);
+                ring.fill(now);
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            }
+            Some(ButtonMsg::Clicked) if !self.confirmed && animation_disabled() => {
+                #[cfg(feature = "haptic")]
+                haptic::play(HapticEffect::HoldConfirm);
+                return self.confirm(ctx);
+            }
+            Some(ButtonMsg::Released | ButtonMsg::Clicked) if !self.confirmed => {
+                ring.drain(now);
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+
+        if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
+            if !self.confirmed && ring.is_full(now) {
+                #[cfg(feature = "haptic")]
+                haptic::play(HapticEffect::HoldConfirm);
+                return self.confirm(ctx);
+            }
+            if ring.is_animating(now) {
+                ctx.request_anim_frame();
+                ctx.request_paint();
+            }
+        }
+        None
+    }
 }
 
 impl Component for TapToConfirm {
@@ -164,8 +292,11 @@ impl Component for TapToConfirm {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let btn_msg = self.button.event(ctx, event);
-        if let Some(ButtonMsg::Clicked) = btn_msg {
+        if self.hold.is_some() {
+            if let Some(msg) = self.hold_event(ctx, event) {
+                return Some(msg);
+            }
+        } else if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
             if animation_disabled() {
                 return Some(());
             }
@@ -217,6 +348,24 @@ impl Component for TapToConfirm {
             .with_thickness(PAD_THICKNESS)
             .with_alpha(self.anim.get_pad_opacity(t))
             .render(target);
+        if let Some(ring) = &self.hold {
+            let progress = ring.value(Instant::now());
+            if progress > 0.0 {
+                let arc = shape::Circle::new(center, PAD_RADIUS)
+                    .with_fg(self.circle_color)
+                    .with_bg(theme::BLACK)
+                    .with_thickness(PAD_THICKNESS)
+                    .with_alpha(self.anim.get_pad_opacity(t));
+                // A sector from 0° to 360° would be empty.
+                if progress < 1.0 {
+                    arc.with_start_angle(0.0)
+                        .with_end_angle(360.0 * progress)
+                        .render(target);
+                } else {
+                    arc.render(target);
+                }
+            }
+        }
         shape::Circle::new(center, CIRCLE_RADIUS)
             .with_fg(self.circle_color)
             .with_bg(theme::BLACK)
@@ -236,7 +385,7 @@ impl Component for TapToConfirm {
             .render(target);
 
         shape::ToifImage::new(center, theme::ICON_SIMPLE_CHECKMARK.toif)
-            .with_fg(theme::GREY)
+            .with_fg(self.icon_color)
             .with_alpha(255 - self.anim.get_parent_cover_opacity(t))
             .with_align(Alignment2D::CENTER)
             .render(target);
@@ -248,5 +397,8 @@ impl crate::trace::Trace for TapToConfirm {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("StatusScreen");
         t.child("button", &self.button);
+        if let Some(ring) = &self.hold {
+            t.int("hold_ms", ring.duration.to_millis() as i64);
+        }
     }
 }
@@ -1,17 +1,18 @@
 use heapless::Vec;
 
-use super::theme;
+use super::{theme, ScrollBar};
 use crate::{
     strutil::TString,
     time::{Duration, Stopwatch},
     ui::{
         component::{
             base::{AttachType, Component},
-            Event, EventCtx, SwipeDirection,
+            Event, EventCtx, Paginate, SwipeDirection,
         },
         constant::screen,
         display::{Color, Icon},
-        geometry::{Offset, Rect},
+        event::SwipeEvent,
+        geometry::{Insets, Offset, Rect},
         lerp::Lerp,
         model_mercury::component::button::{Button, ButtonMsg, IconText},
         shape::{Bar, Renderer},
@@ -23,13 +24,15 @@ pub enum VerticalMenuChoiceMsg {
     Selected(usize),
 }
 
-/// Number of buttons.
-/// Presently, VerticalMenu holds only fixed number of buttons.
-/// TODO: for scrollable menu, the implementation must change.
-const N_ITEMS: usize = 3;
+/// Number of buttons fitting one page.
+const ITEMS_PER_PAGE: usize = 3;
 
-/// Number of visual separators between buttons.
-const N_SEPS: usize = N_ITEMS - 1;
+/// Maximum number of buttons. Menus with more than `ITEMS_PER_PAGE` buttons
+/// are split into pages.
+const MAX_ITEMS: usize = 9;
+
+/// Number of visual separators between buttons of one page.
+const N_SEPS: usize = ITEMS_PER_PAGE - 1;
 
 /// Fixed height of each menu button.
 const MENU_BUTTON_HEIGHT: i16 = 64;
@@ -37,7 +40,7 @@ const MENU_BUTTON_HEIGHT: i16 = 64;
 /// Fixed height of a separator.
 const MENU_SEP_HEIGHT: i16 = 2;
 
-type VerticalMenuButtons = Vec<Button, N_ITEMS>;
+type VerticalMenuButtons = Vec<Button, MAX_ITEMS>;
 type AreasForSeparators = Vec<Rect, N_SEPS>;
 
 #[derive(Default, Clone)]
@@ -171,6 +174,9 @@ impl AttachAnimation {
     }
 }
 
+/// Menu of up to `MAX_ITEMS` buttons, `ITEMS_PER_PAGE` of them per page. Pages
+/// are changed by swiping up and down, so the enclosing `Frame` of a longer
+/// menu needs `with_vertical_pages`.
 #[derive(Clone)]
 pub struct VerticalMenu {
     area: Rect,
@@ -178,6 +184,8 @@ pub struct VerticalMenu {
     buttons: VerticalMenuButtons,
     /// areas for visual separators between buttons
     areas_sep: AreasForSeparators,
+    /// position indicator, used only if there is more than one page
+    scrollbar: ScrollBar,
 
     attach_animation: AttachAnimation,
 }
@@ -188,13 +196,52 @@ impl VerticalMenu {
             area: Rect::zero(),
             buttons,
             areas_sep: AreasForSeparators::new(),
+            scrollbar: ScrollBar::vertical(),
             attach_animation: AttachAnimation::default(),
         }
     }
+
+    fn has_pages(&self) -> bool {
+        self.buttons.len() > ITEMS_PER_PAGE
+    }
+
+    /// Indices of the buttons on the current page.
+    fn visible(&self) -> core::ops::Range<usize> {
+        let start = self.scrollbar.active_page * ITEMS_PER_PAGE;
+        let end = (start + ITEMS_PER_PAGE).min(self.buttons.len());
+        start..end
+    }
+
+    /// Separators go below every button of the current page except the last
+    /// one.
+    fn place_separators(&mut self) {
+        self.areas_sep.clear();
+        let visible = self.visible();
+        for button in &self.buttons[visible.start..visible.end.saturating_sub(1)] {
+            let area_sep = Rect::from_top_left_and_size(
+                button.area().bottom_left(),
+                Offset::new(button.area().width(), MENU_SEP_HEIGHT),
+            );
+            unwrap!(self.areas_sep.push(area_sep));
+        }
+    }
     pub fn select_word(words: [TString<'static>; 3]) -> Self {
         let mut buttons_vec = VerticalMenuButtons::new();
         for word in words {
-            let button = Button::with_text(word).styled(theme::button_default());
+            let button = Button::with_text(word).styled(theme::button_menu_item());
+            unwrap!(buttons_vec.push(button));
+        }
+        Self::new(buttons_vec)
+    }
+
+    /// Menu of buttons each showing a text with a subtext below it.
+    pub fn select_with_subtext(
+        items: impl IntoIterator<Item = (TString<'static>, TString<'static>)>,
+    ) -> Self {
+        let mut buttons_vec = VerticalMenuButtons::new();
+        for (text, subtext) in items {
+            let button = Button::with_text_and_subtext(text, subtext, &theme::BUTTON_SUBTEXT)
+                .styled(theme::button_default());
             unwrap!(buttons_vec.push(button));
         }
         Self::new(buttons_vec)
@@ -206,7 +253,7 @@ impl VerticalMenu {
 
     pub fn item(mut self, icon: Icon, text: TString<'static>) -> Self {
         unwrap!(self.buttons.push(
-            Button::with_icon_and_text(IconText::new(text, icon)).styled(theme::button_default())
+            Button::with_icon_and_text(IconText::new(text, icon)).styled(theme::button_menu_item())
         ));
         self
     }
@@ -214,7 +261,7 @@ impl VerticalMenu {
     pub fn danger(mut self, icon: Icon, text: TString<'static>) -> Self {
         unwrap!(self.buttons.push(
             Button::with_icon_and_text(IconText::new(text, icon))
-                .styled(theme::button_warning_high())
+                .styled(theme::button_menu_danger())
         ));
         self
     }
@@ -230,30 +277,61 @@ impl Component for VerticalMenu {
         assert!(bounds.height() == height_bounds_expected);
 
         self.area = bounds;
-        self.areas_sep.clear();
-        let mut remaining = bounds;
-        let n_seps = self.buttons.len() - 1;
+        let mut area_buttons = bounds;
+        if self.has_pages() {
+            let page_count = (self.buttons.len() + ITEMS_PER_PAGE - 1) / ITEMS_PER_PAGE;
+            let active_page = self.scrollbar.active_page.min(page_count - 1);
+            self.scrollbar
+                .set_count_and_active_page(page_count, active_page);
+            // Reserve the width of both the dots and the counter, spaced from
+            // the buttons and the edge of the screen.
+            let scrollbar_width = self.scrollbar.extent().x + 2 * theme::SPACING;
+            let (rest, area_scrollbar) = bounds.split_right(scrollbar_width);
+            self.scrollbar
+                .place(area_scrollbar.inset(Insets::sides(theme::SPACING)));
+            area_buttons = rest;
+        }
+
+        // Buttons of every page share the same slots.
+        let slots: Vec<Rect, ITEMS_PER_PAGE> = area_buttons
+            .split_evenly_vertical(ITEMS_PER_PAGE, MENU_SEP_HEIGHT)
+            .collect();
         for (i, button) in self.buttons.iter_mut().enumerate() {
-            let (area_button, new_remaining) = remaining.split_top(MENU_BUTTON_HEIGHT);
-            button.place(area_button);
-            remaining = new_remaining;
-            if i < n_seps {
-                let (area_sep, new_remaining) = remaining.split_top(MENU_SEP_HEIGHT);
-                unwrap!(self.areas_sep.push(area_sep));
-                remaining = new_remaining;
-            }
+            button.place(slots[i % ITEMS_PER_PAGE]);
         }
+        self.place_separators();
 
         self.area
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if self.has_pages() {
+            ctx.set_page_count(self.scrollbar.page_count);
+            match event {
+                Event::Swipe(SwipeEvent::End(SwipeDirection::Up))
+                    if self.scrollbar.has_next_page() =>
+                {
+                    self.change_page(self.scrollbar.active_page + 1);
+                    ctx.request_paint();
+                }
+                Event::Swipe(SwipeEvent::End(SwipeDirection::Down))
+                    if self.scrollbar.has_previous_page() =>
+                {
+                    self.change_page(self.scrollbar.active_page - 1);
+                    ctx.request_paint();
+                }
+                _ => {}
+            }
+        }
+
         self.attach_animation.lazy_start(ctx, event);
 
         if !self.attach_animation.is_active() {
-            for (i, button) in self.buttons.iter_mut().enumerate() {
+            let visible = self.visible();
+            for (i, button) in self.buttons[visible.clone()].iter_mut().enumerate() {
                 if let Some(ButtonMsg::Clicked) = button.event(ctx, event) {
-                    return Some(VerticalMenuChoiceMsg::Selected(i));
+                    // Index into the whole menu, not just the current page.
+                    return Some(VerticalMenuChoiceMsg::Selected(visible.start + i));
                 }
             }
         }
@@ -277,7 +355,7 @@ impl Component for VerticalMenu {
 
         target.with_origin(offset, &|target| {
             // render buttons separated by thin bars
-            for (i, button) in (&self.buttons).into_iter().enumerate() {
+            for (i, button) in self.buttons[self.visible()].iter().enumerate() {
                 button.render(target);
 
                 Bar::new(button.area())
@@ -299,6 +377,10 @@ impl Component for VerticalMenu {
                     .render(target);
             }
 
+            if self.has_pages() {
+                self.scrollbar.render(target);
+            }
+
             // todo screen here is incorrect
             let r = Rect::from_size(Offset::new(mask_width, screen().height()));
 
@@ -315,10 +397,24 @@ impl Component for VerticalMenu {
     }
 }
 
+impl Paginate for VerticalMenu {
+    fn page_count(&mut self) -> usize {
+        self.scrollbar.page_count.max(1)
+    }
+
+    fn change_page(&mut self, active_page: usize) {
+        self.scrollbar.go_to(active_page);
+        self.place_separators();
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl crate::trace::Trace for VerticalMenu {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("VerticalMenu");
+        t.int("page", self.scrollbar.active_page as i64);
+        t.int("page_count", self.scrollbar.page_count.max(1) as i64);
+        t.int("items_per_page", ITEMS_PER_PAGE as i64);
         t.in_list("buttons", &|button_list| {
             for button in &self.buttons {
                 button_list.child(button);
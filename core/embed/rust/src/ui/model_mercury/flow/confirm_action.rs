@@ -17,6 +17,29 @@ use super::super::{
 // TODO: merge with code from https://github.com/trezor/trezor-firmware/pull/3805
 // when ready
 
+/// How the action is confirmed on the prompt screen.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ConfirmHold {
+    /// Tap to confirm.
+    None,
+    /// Hold to confirm.
+    Hold,
+    /// Hold to confirm, with the ring of critical actions.
+    HoldDanger,
+}
+
+impl ConfirmHold {
+    pub fn from_flags(hold: bool, hold_danger: bool) -> Self {
+        if hold_danger {
+            Self::HoldDanger
+        } else if hold {
+            Self::Hold
+        } else {
+            Self::None
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, ToPrimitive)]
 pub enum ConfirmAction {
     Intro,
@@ -141,7 +164,7 @@ fn new_confirm_action_obj(_args: &[Obj], kwargs: &Map) -> Result<Obj, error::Err
         .try_into_option()?;
     let reverse: bool = kwargs.get_or(Qstr::MP_QSTR_reverse, false)?;
     let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
-    // let hold_danger: bool = kwargs.get_or(Qstr::MP_QSTR_hold_danger, false)?;
+    let hold_danger: bool = kwargs.get_or(Qstr::MP_QSTR_hold_danger, false)?;
     let prompt_screen: bool = kwargs.get_or(Qstr::MP_QSTR_prompt_screen, false)?;
     let prompt_title: TString = kwargs.get_or(Qstr::MP_QSTR_prompt_title, title)?;
 
@@ -151,12 +174,12 @@ fn new_confirm_action_obj(_args: &[Obj], kwargs: &Map) -> Result<Obj, error::Err
         let mut paragraphs = ParagraphVecShort::new();
         if !reverse {
             paragraphs
-                .add(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, action))
-                .add(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description));
+                .add(Paragraph::new(theme::text_main_grey_light(), action))
+                .add(Paragraph::new(theme::text_main_grey_light(), description));
         } else {
             paragraphs
-                .add(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description))
-                .add(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, action));
+                .add(Paragraph::new(theme::text_main_grey_light(), description))
+                .add(Paragraph::new(theme::text_main_grey_light(), action));
         }
         paragraphs.into_paragraphs()
     };
@@ -167,7 +190,7 @@ fn new_confirm_action_obj(_args: &[Obj], kwargs: &Map) -> Result<Obj, error::Err
         subtitle,
         verb_cancel,
         prompt_screen.then_some(prompt_title),
-        hold,
+        ConfirmHold::from_flags(hold, hold_danger),
         false,
     )
 }
@@ -179,7 +202,7 @@ pub fn new_confirm_action_simple<T: Component + Paginate + MaybeTrace + 'static>
     subtitle: Option<TString<'static>>,
     verb_cancel: Option<TString<'static>>,
     prompt_screen: Option<TString<'static>>,
-    hold: bool,
+    hold: ConfirmHold,
     info: bool,
 ) -> Result<Obj, error::Error> {
     let mut content_intro =
@@ -194,7 +217,7 @@ pub fn new_confirm_action_simple<T: Component + Paginate + MaybeTrace + 'static>
         content_intro = content_intro.with_subtitle(subtitle);
     }
 
-    let prompt_screen = prompt_screen.or_else(|| hold.then_some(title));
+    let prompt_screen = prompt_screen.or_else(|| (hold != ConfirmHold::None).then_some(title));
     let prompt_pages: usize = prompt_screen.is_some().into();
     let content_intro = content_intro
         .map(move |msg| match msg {
@@ -222,16 +245,20 @@ pub fn new_confirm_action_simple<T: Component + Paginate + MaybeTrace + 'static>
         });
 
     if let Some(prompt_title) = prompt_screen {
-        let (prompt, prompt_action) = if hold {
-            (
+        let (prompt, prompt_action) = match hold {
+            // Critical actions need the button held until the ring fills up.
+            ConfirmHold::HoldDanger => (
+                PromptScreen::new_hold_to_confirm_danger(),
+                TR::instructions__hold_to_confirm.into(),
+            ),
+            ConfirmHold::Hold => (
                 PromptScreen::new_hold_to_confirm(),
                 TR::instructions__hold_to_confirm.into(),
-            )
-        } else {
-            (
+            ),
+            ConfirmHold::None => (
                 PromptScreen::new_tap_to_confirm(),
                 TR::instructions__tap_to_confirm.into(),
-            )
+            ),
         };
 
         let mut content_confirm = Frame::left_aligned(prompt_title, SwipeContent::new(prompt))
@@ -139,7 +139,7 @@ impl ConfirmOutput {
         });
 
         // AccountInfo
-        let ad = AddressDetails::new(TR::send__send_from.into(), account, account_path)?;
+        let ad = AddressDetails::new(TR::send__send_from.into(), account, account_path, None)?;
         let content_account = ad.map(|_| Some(FlowMsg::Cancelled));
 
         // CancelTap
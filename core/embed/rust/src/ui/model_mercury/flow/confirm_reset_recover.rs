@@ -73,9 +73,9 @@ pub extern "C" fn new_confirm_reset_recover(
 impl ConfirmResetRecover {
     fn new_obj(_args: &[Obj], _kwargs: &Map) -> Result<Obj, error::Error> {
         let par_array: [Paragraph<'static>; 3] = [
-            Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, TR::reset__by_continuing)
+            Paragraph::new(theme::text_main_grey_light(), TR::reset__by_continuing)
                 .with_bottom_padding(17),
-            Paragraph::new(&theme::TEXT_SUB_GREY, TR::reset__more_info_at),
+            Paragraph::new(theme::text_sub_grey(), TR::reset__more_info_at),
             Paragraph::new(&theme::TEXT_SUB_GREY_LIGHT, TR::reset__tos_link),
         ];
         let paragraphs = Paragraphs::new(par_array);
@@ -97,7 +97,7 @@ impl SetNewPin {
         let description: TString = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
 
         let par_array: [Paragraph<'static>; 1] =
-            [Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description)];
+            [Paragraph::new(theme::text_main_grey_light(), description)];
         let paragraphs = Paragraphs::new(par_array);
         let content_intro = Frame::left_aligned(title, SwipeContent::new(paragraphs))
             .with_menu_button()
@@ -122,7 +122,7 @@ impl SetNewPin {
 
         let par_array_cancel_intro: [Paragraph<'static>; 2] = [
             Paragraph::new(&theme::TEXT_WARNING, TR::words__not_recommended),
-            Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, TR::pin__cancel_info),
+            Paragraph::new(theme::text_main_grey_light(), TR::pin__cancel_info),
         ];
         let paragraphs_cancel_intro = Paragraphs::new(par_array_cancel_intro);
         let content_cancel_intro = Frame::left_aligned(
@@ -154,6 +154,10 @@ impl GetAddress {
 
         let account: Option<TString> = kwargs.get(Qstr::MP_QSTR_account)?.try_into_option()?;
         let path: Option<TString> = kwargs.get(Qstr::MP_QSTR_path)?.try_into_option()?;
+        let script_type: Option<TString> = kwargs
+            .get(Qstr::MP_QSTR_script_type)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
         let xpubs: Obj = kwargs.get(Qstr::MP_QSTR_xpubs)?;
 
         let br_type: TString = kwargs.get(Qstr::MP_QSTR_br_type)?.try_into()?;
@@ -170,8 +174,8 @@ impl GetAddress {
             description: description.unwrap_or("".into()),
             extra: extra.unwrap_or("".into()),
             data: address.try_into()?,
-            description_font: &theme::TEXT_NORMAL,
-            extra_font: &theme::TEXT_DEMIBOLD,
+            description_font: theme::text_normal(),
+            extra_font: theme::text_demibold(),
             data_font: data_style,
         }
         .into_paragraphs();
@@ -203,6 +207,7 @@ impl GetAddress {
             StatusScreen::new_success_timeout(),
         )
         .with_footer(TR::instructions__continue_in_app.into(), None)
+        .with_swipe(SwipeDirection::Up, SwipeSettings::default())
         .map(|_| Some(FlowMsg::Confirmed));
 
         // Menu
@@ -234,7 +239,12 @@ impl GetAddress {
         .map(|msg| matches!(msg, FrameMsg::Button(_)).then_some(FlowMsg::Cancelled));
 
         // AccountInfo
-        let mut ad = AddressDetails::new(TR::address_details__account_info.into(), account, path)?;
+        let mut ad = AddressDetails::new(
+            TR::address_details__account_info.into(),
+            account,
+            path,
+            script_type,
+        )?;
         for i in IterBuf::new().try_iterate(xpubs)? {
             let [xtitle, text]: [TString; 2] = util::iter_into_array(i)?;
             ad.add_xpub(xtitle, text)?;
@@ -245,7 +255,7 @@ impl GetAddress {
         let content_cancel_info = Frame::left_aligned(
             TR::address__cancel_receive.into(),
             SwipeContent::new(Paragraphs::new(Paragraph::new(
-                &theme::TEXT_MAIN_GREY_LIGHT,
+                theme::text_main_grey_light(),
                 TR::address__cancel_contact_support,
             ))),
         )
@@ -99,7 +99,7 @@ impl PromptBackup {
         let title: TString = TR::backup__title_create_wallet_backup.into();
         let text_intro: TString = TR::backup__it_should_be_backed_up.into();
 
-        let paragraphs = Paragraphs::new(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, text_intro));
+        let paragraphs = Paragraphs::new(Paragraph::new(theme::text_main_grey_light(), text_intro));
         let content_intro = Frame::left_aligned(title, SwipeContent::new(paragraphs))
             .with_menu_button()
             .with_footer(TR::instructions__swipe_up.into(), None)
@@ -124,7 +124,7 @@ impl PromptBackup {
         let par_array_skip_intro: [Paragraph<'static>; 2] = [
             Paragraph::new(&theme::TEXT_WARNING, TR::words__not_recommended),
             Paragraph::new(
-                &theme::TEXT_MAIN_GREY_LIGHT,
+                theme::text_main_grey_light(),
                 TR::backup__create_backup_to_prevent_loss,
             ),
         ];
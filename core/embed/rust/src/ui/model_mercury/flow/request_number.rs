@@ -89,6 +89,7 @@ impl RequestNumber {
         assert!(info != Obj::const_none());
         let br_type: TString = kwargs.get(Qstr::MP_QSTR_br_type)?.try_into()?;
         let br_code: u16 = kwargs.get(Qstr::MP_QSTR_br_code)?.try_into()?;
+        let direct_entry: bool = kwargs.get_or(Qstr::MP_QSTR_direct_entry, false)?;
 
         let description_cb = move |i: u32| {
             TString::try_from(
@@ -102,8 +103,11 @@ impl RequestNumber {
             TString::try_from(info.call_with_n_args(&[i.try_into().unwrap()]).unwrap()).unwrap()
         };
 
-        let number_input_dialog =
+        let mut number_input_dialog =
             NumberInputDialog::new(min_count, max_count, count, description_cb)?;
+        if direct_entry {
+            number_input_dialog = number_input_dialog.with_direct_entry();
+        }
         let content_number_input =
             Frame::left_aligned(title, SwipeContent::new(number_input_dialog))
                 .with_menu_button()
@@ -129,7 +133,7 @@ impl RequestNumber {
         });
 
         let paragraphs_info = Paragraphs::new(Paragraph::new(
-            &theme::TEXT_MAIN_GREY_LIGHT,
+            theme::text_main_grey_light(),
             info_cb(0), // TODO: get the value
         ));
         let content_info = Frame::left_aligned(
@@ -90,7 +90,7 @@ impl ShowShareWords {
         let mut instructions_paragraphs = ParagraphVecShort::new();
         for item in IterBuf::new().try_iterate(text_info)? {
             let text: TString = item.try_into()?;
-            instructions_paragraphs.add(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, text));
+            instructions_paragraphs.add(Paragraph::new(theme::text_main_grey_light(), text));
         }
         let paragraphs_spacing = 8;
         let content_instruction = Frame::left_aligned(
@@ -124,7 +124,7 @@ impl ShowShareWords {
         let content_check_backup_intro = Frame::left_aligned(
             TR::reset__check_wallet_backup_title.into(),
             SwipeContent::new(Paragraphs::new(Paragraph::new(
-                &theme::TEXT_MAIN_GREY_LIGHT,
+                theme::text_main_grey_light(),
                 TR::reset__check_backup_instructions,
             ))),
         )
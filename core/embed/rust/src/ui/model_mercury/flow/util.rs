@@ -103,15 +103,15 @@ impl ConfirmBlobParams {
             description: self.description.unwrap_or("".into()),
             extra: self.extra.unwrap_or("".into()),
             data: self.data.try_into()?,
-            description_font: &theme::TEXT_NORMAL,
-            extra_font: &theme::TEXT_DEMIBOLD,
+            description_font: theme::text_normal(),
+            extra_font: theme::text_demibold(),
             data_font: if self.chunkify {
                 let data: TString = self.data.try_into()?;
                 theme::get_chunkified_text_style(data.len())
             } else if self.text_mono {
                 &theme::TEXT_MONO
             } else {
-                &theme::TEXT_NORMAL
+                theme::text_normal()
             },
         }
         .into_paragraphs();
@@ -224,12 +224,12 @@ impl ShowInfoParams {
             // FIXME: padding:
             if !first {
                 paragraphs.add(Paragraph::new::<TString<'static>>(
-                    &theme::TEXT_SUB_GREY,
+                    theme::text_sub_grey(),
                     " ".into(),
                 ));
             }
             first = false;
-            paragraphs.add(Paragraph::new(&theme::TEXT_SUB_GREY, item.0).no_break());
+            paragraphs.add(Paragraph::new(theme::text_sub_grey(), item.0).no_break());
             if self.chunkify {
                 paragraphs.add(Paragraph::new(
                     theme::get_chunkified_text_style(item.1.len()),
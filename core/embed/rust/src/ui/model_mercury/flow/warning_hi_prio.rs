@@ -84,7 +84,7 @@ impl WarningHiPrio {
 
         // Message
         let paragraphs = [
-            Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description),
+            Paragraph::new(theme::text_main_grey_light(), description),
             Paragraph::new(&theme::TEXT_MAIN_GREY_EXTRA_LIGHT, value)
                 .with_top_padding(Self::EXTRA_PADDING),
         ]
@@ -116,6 +116,7 @@ impl WarningHiPrio {
         let content_cancelled =
             Frame::left_aligned(done_title, StatusScreen::new_neutral_timeout())
                 .with_footer(TR::instructions__continue_in_app.into(), None)
+                .with_swipe(SwipeDirection::Up, SwipeSettings::default())
                 .map(|_| Some(FlowMsg::Cancelled));
 
         let store = flow_store()
@@ -1,12 +1,15 @@
-use core::{cmp::Ordering, convert::TryInto};
+use core::convert::TryInto;
 
 use crate::{
     error::Error,
     io::BinaryData,
+    maybe_trace::MaybeTrace,
     micropython::{
-        gc::Gc, iter::IterBuf, list::List, map::Map, module::Module, obj::Obj, qstr::Qstr, util,
+        buffer::StrBuffer, gc::Gc, iter::IterBuf, list::List, map::Map, module::Module, obj::Obj,
+        qstr::Qstr, util,
     },
     strutil::TString,
+    time::Duration,
     translations::TR,
     trezorhal::model,
     ui::{
@@ -19,19 +22,22 @@ use crate::{
             text::{
                 op::OpTextLayout,
                 paragraphs::{
-                    Checklist, Paragraph, ParagraphSource, ParagraphVecLong, ParagraphVecShort,
-                    Paragraphs, VecExt,
+                    Checklist, ChecklistStyles, Paragraph, ParagraphSource, ParagraphVecLong,
+                    ParagraphVecShort, Paragraphs, VecExt,
                 },
                 TextStyle,
             },
             Border, Component, FormattedText, Label, Never, SwipeDirection, Timeout,
         },
-        flow::Swipable,
+        flow::{Swipable, SwipePage},
         geometry,
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
             result::{CANCELLED, CONFIRMED, INFO},
-            util::{upy_disable_animation, ConfirmBlob, PropsList},
+            util::{
+                upy_debug_overlay, upy_disable_animation, upy_led_set_enabled, upy_seed_random,
+                upy_set_keyboard_timings, ConfirmBlob, ListItems, PropsList,
+            },
         },
         model_mercury::component::{check_homescreen_format, SwipeContent},
     },
@@ -39,16 +45,21 @@ use crate::{
 
 use super::{
     component::{
-        AddressDetails, Bip39Input, Button, CancelConfirmMsg, CancelInfoConfirmMsg,
-        CoinJoinProgress, FidoConfirm, FidoMsg, Frame, FrameMsg, Homescreen, HomescreenMsg,
-        Lockscreen, MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg, PassphraseKeyboard,
+        AddressDetails, AmountKeyboard, AmountKeyboardMsg, Bip39Input, Button, CancelConfirmMsg,
+        CancelInfoConfirmMsg, CoinJoinProgress, DatetimeKeyboard, DatetimeKeyboardMsg, FidoConfirm,
+        FidoMsg, Frame, FrameMsg, FrameUpdate, FrameUpdates, Homescreen, HomescreenMsg, Lockscreen,
+        MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg, PassphraseKeyboard,
         PassphraseKeyboardMsg, PinKeyboard, PinKeyboardMsg, Progress, PromptScreen,
         SelectWordCount, SelectWordCountMsg, SetBrightnessDialog, Slip39Input, StatusScreen,
         SwipeUpScreen, SwipeUpScreenMsg, VerticalMenu, VerticalMenuChoiceMsg,
     },
-    flow, theme,
+    flow::{self, ConfirmHold},
+    theme,
 };
 
+#[cfg(feature = "ui_debug")]
+use crate::ui::layout::obj::DebugKeyboard;
+
 impl TryFrom<CancelConfirmMsg> for Obj {
     type Error = Error;
 
@@ -112,6 +123,16 @@ impl ComponentMsgObj for PinKeyboard<'_> {
             PinKeyboardMsg::Cancelled => Ok(CANCELLED.as_obj()),
         }
     }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        Some(self)
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        Some(self)
+    }
 }
 
 impl ComponentMsgObj for PassphraseKeyboard {
@@ -121,6 +142,16 @@ impl ComponentMsgObj for PassphraseKeyboard {
             PassphraseKeyboardMsg::Cancelled => Ok(CANCELLED.as_obj()),
         }
     }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        Some(self)
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        Some(self)
+    }
 }
 
 impl<T> ComponentMsgObj for MnemonicKeyboard<T>
@@ -137,8 +168,20 @@ where
                 }
             }
             MnemonicKeyboardMsg::Previous => "".try_into(),
+            // Only updates the header, see `new_mnemonic_keyboard`.
+            MnemonicKeyboardMsg::Progress(_) => Ok(Obj::const_none()),
         }
     }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        Some(self)
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        Some(self)
+    }
 }
 
 impl<T> ComponentMsgObj for Frame<T>
@@ -151,6 +194,54 @@ where
             FrameMsg::Button(b) => b.try_into(),
         }
     }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        self.inner().debug_keyboard()
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        self.inner_mut().debug_keyboard_mut()
+    }
+}
+
+impl<T, F> ComponentMsgObj for FrameUpdates<T, F>
+where
+    T: ComponentMsgObj,
+    F: Fn(&T::Msg) -> Option<FrameUpdate>,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        self.inner().msg_try_into_obj(msg)
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard(&self) -> Option<&dyn DebugKeyboard> {
+        self.inner().debug_keyboard()
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn debug_keyboard_mut(&mut self) -> Option<&mut dyn DebugKeyboard> {
+        self.inner_mut().debug_keyboard_mut()
+    }
+}
+
+impl ComponentMsgObj for AmountKeyboard<'_> {
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            AmountKeyboardMsg::Confirmed => unwrap!(self.value()).try_into(),
+            AmountKeyboardMsg::Cancelled => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
+impl ComponentMsgObj for DatetimeKeyboard {
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            DatetimeKeyboardMsg::Confirmed => unwrap!(self.value()).as_str().try_into(),
+            DatetimeKeyboardMsg::Cancelled => Ok(CANCELLED.as_obj()),
+        }
+    }
 }
 
 impl ComponentMsgObj for SelectWordCount {
@@ -239,11 +330,9 @@ impl ComponentMsgObj for Lockscreen {
     }
 }
 
-// Clippy/compiler complains about conflicting implementations
-#[cfg(not(feature = "clippy"))]
-impl<T> ComponentMsgObj for (Timeout, T)
+impl<T> ComponentMsgObj for Timeout<T>
 where
-    T: Component<Msg = ()>,
+    T: Component<Msg = Never>,
 {
     fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
         Ok(CANCELLED.as_obj())
@@ -300,7 +389,7 @@ extern "C" fn new_confirm_emphasized(n_args: usize, args: *const Obj, kwargs: *m
             None,
             None,
             Some(title),
-            false,
+            ConfirmHold::None,
             false,
         )
     };
@@ -378,15 +467,15 @@ impl ConfirmBlobParams {
             description: self.description.unwrap_or("".into()),
             extra: self.extra.unwrap_or("".into()),
             data: self.data.try_into()?,
-            description_font: &theme::TEXT_NORMAL,
-            extra_font: &theme::TEXT_DEMIBOLD,
+            description_font: theme::text_normal(),
+            extra_font: theme::text_demibold(),
             data_font: if self.chunkify {
                 let data: TString = self.data.try_into()?;
                 theme::get_chunkified_text_style(data.len())
             } else if self.text_mono {
                 &theme::TEXT_MONO
             } else {
-                &theme::TEXT_NORMAL
+                theme::text_normal()
             },
         }
         .into_paragraphs();
@@ -397,7 +486,7 @@ impl ConfirmBlobParams {
             self.subtitle,
             self.verb_cancel,
             self.prompt.then_some(self.title),
-            self.hold,
+            ConfirmHold::from_flags(self.hold, false),
             self.info_button,
         )
     }
@@ -461,13 +550,43 @@ extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut
             description: description.unwrap_or("".into()),
             extra: extra.unwrap_or("".into()),
             data: data.try_into()?,
-            description_font: &theme::TEXT_NORMAL,
-            extra_font: &theme::TEXT_DEMIBOLD,
+            description_font: theme::text_normal(),
+            extra_font: theme::text_demibold(),
             data_font: data_style,
         }
         .into_paragraphs();
 
-        flow::new_confirm_action_simple(paragraphs, title, None, None, None, false, false)
+        flow::new_confirm_action_simple(
+            paragraphs,
+            title,
+            None,
+            None,
+            None,
+            ConfirmHold::None,
+            false,
+        )
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_list(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
+        let numbered: bool = kwargs.get_or(Qstr::MP_QSTR_numbered, false)?;
+        let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
+
+        let paragraphs = ListItems::new(items, theme::text_normal(), numbered)?;
+
+        flow::new_confirm_action_simple(
+            paragraphs.into_paragraphs(),
+            title,
+            None,
+            None,
+            hold.then_some(title),
+            ConfirmHold::from_flags(hold, false),
+            false,
+        )
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -480,7 +599,7 @@ extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *m
 
         let paragraphs = PropsList::new(
             items,
-            &theme::TEXT_NORMAL,
+            theme::text_normal(),
             &theme::TEXT_MONO,
             &theme::TEXT_MONO,
         )?;
@@ -491,7 +610,7 @@ extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *m
             None,
             None,
             hold.then_some(title),
-            hold,
+            ConfirmHold::from_flags(hold, false),
             false,
         )
     };
@@ -512,7 +631,7 @@ extern "C" fn new_confirm_homescreen(n_args: usize, args: *const Obj, kwargs: *m
                 Frame::centered(
                     title,
                     SwipeContent::new(Paragraphs::new([Paragraph::new(
-                        &theme::TEXT_DEMIBOLD,
+                        theme::text_demibold(),
                         TR::homescreen__set_default,
                     )
                     .centered()])),
@@ -558,7 +677,7 @@ extern "C" fn new_show_info_with_cancel(n_args: usize, args: *const Obj, kwargs:
             let [key, value]: [Obj; 2] = util::iter_into_array(para)?;
             let key: TString = key.try_into()?;
             let value: TString = value.try_into()?;
-            paragraphs.add(Paragraph::new(&theme::TEXT_NORMAL, key).no_break());
+            paragraphs.add(Paragraph::new(theme::text_normal(), key).no_break());
             if chunkify {
                 paragraphs.add(Paragraph::new(
                     theme::get_chunkified_text_style(value.len()),
@@ -618,7 +737,7 @@ extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
         for pair in IterBuf::new().try_iterate(items)? {
             let [label, value]: [TString; 2] = util::iter_into_array(pair)?;
-            paragraphs.add(Paragraph::new(&theme::TEXT_NORMAL, label).no_break());
+            paragraphs.add(Paragraph::new(theme::text_normal(), label).no_break());
             paragraphs.add(Paragraph::new(&theme::TEXT_MONO, value));
         }
 
@@ -628,7 +747,7 @@ extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Ma
             None,
             None,
             Some(title),
-            true,
+            ConfirmHold::Hold,
             true,
         )
     };
@@ -648,9 +767,9 @@ extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs:
         };
 
         let paragraphs = ParagraphVecShort::from_iter([
-            Paragraph::new(&theme::TEXT_NORMAL, description),
+            Paragraph::new(theme::text_normal(), description),
             Paragraph::new(&theme::TEXT_MONO, amount_change),
-            Paragraph::new(&theme::TEXT_NORMAL, TR::modify_amount__new_amount),
+            Paragraph::new(theme::text_normal(), TR::modify_amount__new_amount),
             Paragraph::new(&theme::TEXT_MONO, amount_new),
         ])
         .into_paragraphs();
@@ -692,9 +811,9 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
         };
 
         let paragraphs = ParagraphVecShort::from_iter([
-            Paragraph::new(&theme::TEXT_NORMAL, description),
+            Paragraph::new(theme::text_normal(), description),
             Paragraph::new(&theme::TEXT_MONO, change),
-            Paragraph::new(&theme::TEXT_NORMAL, total_label),
+            Paragraph::new(theme::text_normal(), total_label),
             Paragraph::new(&theme::TEXT_MONO, total_fee_new),
         ])
         .into_paragraphs();
@@ -716,7 +835,7 @@ extern "C" fn new_show_error(n_args: usize, args: *const Obj, kwargs: *mut Map)
         let description: TString = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
         let allow_cancel: bool = kwargs.get(Qstr::MP_QSTR_allow_cancel)?.try_into()?;
 
-        let content = Paragraphs::new(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description));
+        let content = Paragraphs::new(Paragraph::new(theme::text_main_grey_light(), description));
         let frame = if allow_cancel {
             Frame::left_aligned(title, SwipeContent::new(content))
                 .with_cancel_button()
@@ -776,7 +895,7 @@ extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map
         let action: Option<TString> = kwargs.get(Qstr::MP_QSTR_button)?.try_into_option()?;
 
         let content = ParagraphVecShort::from_iter([
-            Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description),
+            Paragraph::new(theme::text_main_grey_light(), description),
             Paragraph::new(&theme::TEXT_MAIN_GREY_EXTRA_LIGHT, value),
         ])
         .into_paragraphs();
@@ -799,7 +918,10 @@ extern "C" fn new_show_success(n_args: usize, args: *const Obj, kwargs: *mut Map
             .try_into_option()?
             .and_then(|desc: TString| if desc.is_empty() { None } else { Some(desc) });
 
-        let content = StatusScreen::new_success();
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
+
+        let timeout = (time_ms > 0).then(|| Duration::from_millis(time_ms));
+        let content = StatusScreen::new_success().with_timeout(timeout);
         let obj = LayoutObj::new(SwipeUpScreen::new(
             Frame::left_aligned(title, SwipeContent::new(content).with_normal_attach(None))
                 .with_footer(TR::instructions__swipe_up.into(), description)
@@ -814,7 +936,7 @@ extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
         let description: TString = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let content = Paragraphs::new(Paragraph::new(&theme::TEXT_MAIN_GREY_LIGHT, description));
+        let content = Paragraphs::new(Paragraph::new(theme::text_main_grey_light(), description));
         let obj = LayoutObj::new(SwipeUpScreen::new(
             Frame::left_aligned(title, SwipeContent::new(content))
                 .with_footer(TR::instructions__swipe_up.into(), None)
@@ -833,8 +955,8 @@ extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Ma
         let button: TString = TR::buttons__quit.into();
 
         let paragraphs = ParagraphVecShort::from_iter([
-            Paragraph::new(&theme::TEXT_NORMAL, description).centered(),
-            Paragraph::new(&theme::TEXT_DEMIBOLD, url).centered(),
+            Paragraph::new(theme::text_normal(), description).centered(),
+            Paragraph::new(theme::text_demibold(), url).centered(),
         ])
         .into_paragraphs();
 
@@ -856,7 +978,7 @@ extern "C" fn new_show_simple(n_args: usize, args: *const Obj, kwargs: *mut Map)
 
         let obj = LayoutObj::new(Border::new(
             theme::borders(),
-            Paragraphs::new(Paragraph::new(&theme::TEXT_DEMIBOLD, description)),
+            Paragraphs::new(Paragraph::new(theme::text_demibold(), description)),
         ))?;
 
         Ok(obj.into())
@@ -924,9 +1046,9 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
         let max_feerate: TString = kwargs.get(Qstr::MP_QSTR_max_feerate)?.try_into()?;
 
         let paragraphs = ParagraphVecShort::from_iter([
-            Paragraph::new(&theme::TEXT_NORMAL, TR::coinjoin__max_rounds),
+            Paragraph::new(theme::text_normal(), TR::coinjoin__max_rounds),
             Paragraph::new(&theme::TEXT_MONO, max_rounds),
-            Paragraph::new(&theme::TEXT_NORMAL, TR::coinjoin__max_mining_fee),
+            Paragraph::new(theme::text_normal(), TR::coinjoin__max_mining_fee),
             Paragraph::new(&theme::TEXT_MONO, max_feerate),
         ])
         .into_paragraphs();
@@ -937,7 +1059,7 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
             None,
             None,
             Some(TR::coinjoin__title.into()),
-            true,
+            ConfirmHold::Hold,
             false,
         )
     };
@@ -947,15 +1069,35 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
 extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let prompt: TString = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let subprompt: TString = kwargs.get(Qstr::MP_QSTR_subprompt)?.try_into()?;
+        let attempts_remaining: Option<u16> = kwargs
+            .get(Qstr::MP_QSTR_attempts_remaining)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
+        let subprompt: TString = match attempts_remaining {
+            Some(1) => TR::pin__last_attempt.into(),
+            Some(attempts) => {
+                let text = TR::pin__x_tries_left_template_plural
+                    .format_plural(attempts.into(), TR::plurals__x_tries_left);
+                StrBuffer::alloc(&text)?.into()
+            }
+            None => kwargs.get_or(Qstr::MP_QSTR_subprompt, TString::empty())?,
+        };
         let allow_cancel: bool = kwargs.get_or(Qstr::MP_QSTR_allow_cancel, true)?;
         let warning: bool = kwargs.get_or(Qstr::MP_QSTR_wrong_pin, false)?;
+        let wipe_code: bool = kwargs.get_or(Qstr::MP_QSTR_wipe_code, false)?;
         let warning = if warning {
             Some(TR::pin__wrong_pin.into())
         } else {
             None
         };
-        let obj = LayoutObj::new(PinKeyboard::new(prompt, subprompt, warning, allow_cancel))?;
+        let obj = LayoutObj::new(PinKeyboard::new(
+            prompt,
+            subprompt,
+            warning,
+            allow_cancel,
+            attempts_remaining,
+            wipe_code,
+        ))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -971,17 +1113,38 @@ extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *m
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+/// Mnemonic keyboard under a header, the subtitle of which follows the
+/// progress the keyboard reports when moving on to another word.
+fn new_mnemonic_keyboard<T: MnemonicInput + MaybeTrace + 'static>(
+    kwargs: &Map,
+    keyboard: MnemonicKeyboard<T>,
+) -> Result<Obj, Error> {
+    let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+    let subtitle: TString = kwargs.get(Qstr::MP_QSTR_subtitle)?.try_into()?;
+    let word_count: usize = kwargs.get(Qstr::MP_QSTR_word_count)?.try_into()?;
+    let frame = Frame::left_aligned(title, keyboard.with_progress(0, word_count))
+        .with_subtitle(subtitle)
+        .with_updates(|msg| match msg {
+            MnemonicKeyboardMsg::Progress(progress) => Some(FrameUpdate::Subtitle(*progress)),
+            _ => None,
+        });
+    Ok(LayoutObj::new(frame)?.into())
+}
+
 extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let prompt: TString = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let prefill_word: TString = kwargs.get(Qstr::MP_QSTR_prefill_word)?.try_into()?;
-        let can_go_back: bool = kwargs.get(Qstr::MP_QSTR_can_go_back)?.try_into()?;
-        let obj = LayoutObj::new(MnemonicKeyboard::new(
-            prefill_word.map(Bip39Input::prefilled_word),
-            prompt,
-            can_go_back,
-        ))?;
-        Ok(obj.into())
+        let filter_last_word: bool = kwargs.get_or(Qstr::MP_QSTR_filter_last_word, false)?;
+        let autofill: bool = kwargs.get_or(Qstr::MP_QSTR_autofill, false)?;
+        let mut input = Bip39Input::new();
+        if autofill {
+            input = input.with_autofill();
+        }
+        let mut keyboard = MnemonicKeyboard::new(input, prompt, false);
+        if filter_last_word {
+            keyboard = keyboard.with_last_word_filter();
+        }
+        new_mnemonic_keyboard(kwargs, keyboard)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -989,18 +1152,38 @@ extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Ma
 extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let prompt: TString = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let prefill_word: TString = kwargs.get(Qstr::MP_QSTR_prefill_word)?.try_into()?;
-        let can_go_back: bool = kwargs.get(Qstr::MP_QSTR_can_go_back)?.try_into()?;
-        let obj = LayoutObj::new(MnemonicKeyboard::new(
-            prefill_word.map(Slip39Input::prefilled_word),
-            prompt,
-            can_go_back,
+        new_mnemonic_keyboard(
+            kwargs,
+            MnemonicKeyboard::new(Slip39Input::new(), prompt, false),
+        )
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_request_amount(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let unit: TString = kwargs.get(Qstr::MP_QSTR_unit)?.try_into()?;
+        let max_integer_digits: usize = kwargs.get(Qstr::MP_QSTR_max_integer_digits)?.try_into()?;
+        let max_decimals: usize = kwargs.get(Qstr::MP_QSTR_max_decimals)?.try_into()?;
+        let obj = LayoutObj::new(Frame::left_aligned(
+            title,
+            AmountKeyboard::new(unit, max_integer_digits, max_decimals),
         ))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_request_datetime(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let obj = LayoutObj::new(Frame::left_aligned(title, DatetimeKeyboard::new()))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: TString = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -1036,14 +1219,9 @@ extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut M
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecLong::new();
-        for (i, item) in IterBuf::new().try_iterate(items)?.enumerate() {
-            let style = match i.cmp(&active) {
-                Ordering::Less => &theme::TEXT_CHECKLIST_DONE,
-                Ordering::Equal => &theme::TEXT_CHECKLIST_SELECTED,
-                Ordering::Greater => &theme::TEXT_CHECKLIST_DEFAULT,
-            };
+        for item in IterBuf::new().try_iterate(items)? {
             let text: TString = item.try_into()?;
-            paragraphs.add(Paragraph::new(style, text));
+            paragraphs.add(Paragraph::new(&theme::TEXT_CHECKLIST_DEFAULT, text));
         }
 
         let checklist_content = Checklist::from_paragraphs(
@@ -1055,16 +1233,23 @@ extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut M
                 .with_spacing(theme::CHECKLIST_SPACING),
         )
         .with_check_width(theme::CHECKLIST_CHECK_WIDTH)
-        .with_icon_done_color(theme::GREEN);
+        .with_icon_done_color(theme::GREEN)
+        .with_icon_pending(theme::DOT_INACTIVE, theme::CHECKLIST_PENDING_OFFSET)
+        .with_styles(ChecklistStyles {
+            done: &theme::TEXT_CHECKLIST_DONE,
+            active: &theme::TEXT_CHECKLIST_SELECTED,
+            pending: &theme::TEXT_CHECKLIST_DEFAULT,
+        });
 
         let obj = LayoutObj::new(SwipeUpScreen::new(
             Frame::left_aligned(
                 title,
-                SwipeContent::new(checklist_content)
+                SwipeContent::new(SwipePage::vertical(checklist_content))
                     .with_normal_attach(Some(AttachType::Swipe(SwipeDirection::Up))),
             )
             .with_footer(TR::instructions__swipe_up.into(), None)
-            .with_swipe(SwipeDirection::Up, SwipeSettings::default()),
+            .with_swipe(SwipeDirection::Up, SwipeSettings::default())
+            .with_vertical_pages(),
         ))?;
         Ok(obj.into())
     };
@@ -1079,7 +1264,7 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
         let recovery_type: u32 = kwargs.get(Qstr::MP_QSTR_recovery_type)?.try_into()?;
         let _info_button: bool = kwargs.get_or(Qstr::MP_QSTR_info_button, false)?;
 
-        let paragraphs = Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, description));
+        let paragraphs = Paragraphs::new(Paragraph::new(theme::text_normal(), description));
 
         let notification = match recovery_type {
             RECOVERY_TYPE_DRY_RUN => TR::recovery__title_dry_run.into(),
@@ -1100,10 +1285,18 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
 }
 
 extern "C" fn new_select_word_count(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
-    let block = move |_args: &[Obj], _kwargs: &Map| {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let recovery_type: u32 = kwargs.get(Qstr::MP_QSTR_recovery_type)?.try_into()?;
+        let bip39: bool = kwargs.get_or(Qstr::MP_QSTR_bip39, false)?;
+        let content = match recovery_type {
+            // Repeated backup is always made of SLIP-39 shares.
+            RECOVERY_TYPE_UNLOCK_REPEATED_BACKUP => SelectWordCount::new_slip39(),
+            _ if bip39 => SelectWordCount::new_bip39(),
+            _ => SelectWordCount::new(),
+        };
         let obj = LayoutObj::new(Frame::left_aligned(
             TR::recovery__num_of_words.into(),
-            SelectWordCount::new(),
+            content,
         ))?;
         Ok(obj.into())
     };
@@ -1145,10 +1338,20 @@ extern "C" fn new_show_remaining_shares(n_args: usize, args: *const Obj, kwargs:
 
         let mut paragraphs = ParagraphVecLong::new();
         for page in IterBuf::new().try_iterate(pages_iterable)? {
-            let [title, description]: [TString; 2] = util::iter_into_array(page)?;
+            let [count, groups, description]: [Obj; 3] = util::iter_into_array(page)?;
+            let count: u32 = count.try_into()?;
+            let plurals = if groups.try_into()? {
+                TR::plurals__x_groups_needed
+            } else {
+                TR::plurals__x_shares_needed
+            };
+            let title =
+                TR::recovery__x_more_items_starting_template_plural.format_plural(count, plurals);
+            let title: TString = StrBuffer::alloc(&title)?.into();
+            let description: TString = description.try_into()?;
             paragraphs
-                .add(Paragraph::new(&theme::TEXT_DEMIBOLD, title))
-                .add(Paragraph::new(&theme::TEXT_NORMAL, description).break_after());
+                .add(Paragraph::new(theme::text_demibold(), title))
+                .add(Paragraph::new(theme::text_normal(), description).break_after());
         }
 
         let obj = LayoutObj::new(SwipeUpScreen::new(
@@ -1173,13 +1376,26 @@ extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Ma
             .and_then(Obj::try_into_option)
             .unwrap_or(None);
 
+        let phase: Option<TString> = kwargs
+            .get(Qstr::MP_QSTR_phase)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
+        let eta: Option<TString> = kwargs
+            .get(Qstr::MP_QSTR_eta)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
+
         let (title, description) = if let Some(title) = title {
             (title, description)
         } else {
             (description, "".into())
         };
 
-        let obj = LayoutObj::new(Progress::new(title, indeterminate, description))?;
+        let mut progress = Progress::new(title, indeterminate, description);
+        if phase.is_some() || eta.is_some() {
+            progress = progress.with_info(phase.unwrap_or("".into()), eta.unwrap_or("".into()));
+        }
+        let obj = LayoutObj::new(progress)?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1196,8 +1412,7 @@ extern "C" fn new_show_progress_coinjoin(n_args: usize, args: *const Obj, kwargs
         // provide it.
         let progress = CoinJoinProgress::<Never>::new(title, indeterminate)?;
         let obj = if time_ms > 0 && indeterminate {
-            let timeout = Timeout::new(time_ms);
-            LayoutObj::new((timeout, progress.map(|_msg| None)))?
+            LayoutObj::new(Timeout::wrap(progress, time_ms))?
         } else {
             LayoutObj::new(progress)?
         };
@@ -1239,9 +1454,19 @@ extern "C" fn new_show_lockscreen(n_args: usize, args: *const Obj, kwargs: *mut
             .unwrap_or_else(|| model::FULL_NAME.into());
         let bootscreen: bool = kwargs.get(Qstr::MP_QSTR_bootscreen)?.try_into()?;
         let coinjoin_authorized: bool = kwargs.get_or(Qstr::MP_QSTR_coinjoin_authorized, false)?;
+        let notification: Option<TString<'static>> = kwargs
+            .get_or(Qstr::MP_QSTR_notification, Obj::const_none())?
+            .try_into_option()?;
+        let notification_level: u8 = kwargs.get_or(Qstr::MP_QSTR_notification_level, 0)?;
         let skip_first_paint: bool = kwargs.get(Qstr::MP_QSTR_skip_first_paint)?.try_into()?;
 
-        let obj = LayoutObj::new(Lockscreen::new(label, bootscreen, coinjoin_authorized))?;
+        let notification = notification.map(|w| (w, notification_level));
+        let obj = LayoutObj::new(Lockscreen::new(
+            label,
+            bootscreen,
+            coinjoin_authorized,
+            notification,
+        ))?;
         if skip_first_paint {
             obj.skip_first_paint();
         }
@@ -1259,6 +1484,22 @@ pub extern "C" fn upy_check_homescreen_format(data: Obj) -> Obj {
     unsafe { util::try_or_raise(block) }
 }
 
+extern "C" fn upy_set_high_contrast(enable: Obj) -> Obj {
+    let block = || {
+        theme::set_high_contrast(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+extern "C" fn upy_set_large_text(enable: Obj) -> Obj {
+    let block = || {
+        theme::set_large_text(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 #[no_mangle]
 extern "C" fn new_confirm_firmware_update(
     n_args: usize,
@@ -1329,9 +1570,24 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     def progress_event(self, value: int, description: str) -> T | None:
     ///         """Receive a progress event."""
     ///
+    ///     def progress_info_event(self, phase: str, eta: str) -> T | None:
+    ///         """Receive a new phase label and estimated time remaining of the
+    ///         progress, empty strings hide them."""
+    ///
     ///     def usb_event(self, connected: bool) -> T | None:
     ///         """Receive a USB connect/disconnect event."""
     ///
+    ///     def notification_event(self, text: str | None, level: int) -> T | None:
+    ///         """Receive a new notification of the homescreen, None removes it."""
+    ///
+    ///     def mnemonic_word_event(self, word_index: int, progress: str) -> T | None:
+    ///         """Move the mnemonic keyboard on to word `word_index`, showing the
+    ///         `progress` text."""
+    ///
+    ///     if utils.EMULATOR:
+    ///         def host_key_event(self, key: int) -> T | None:
+    ///             """Receive a key `key` typed on the host keyboard."""
+    ///
     ///     def timer(self, token: int) -> T | None:
     ///         """Callback for the timer set by `attach_timer_fn`.
     ///
@@ -1364,6 +1620,21 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///         def bounds(self) -> None:
     ///             """Paint bounds of individual components on screen."""
     ///
+    ///         def keyboard_text(self) -> str | None:
+    ///             """Return the text typed into the keyboard of the layout, if any."""
+    ///
+    ///         def keyboard_type(self, text: str) -> None:
+    ///             """Type `text` into the keyboard of the layout, as if entered key by key.
+    ///
+    ///             Raises `ValueError` if the layout has no keyboard.
+    ///             """
+    ///
+    ///         def debug_click(self, name: str) -> T | None:
+    ///             """Click the component registered as debug target `name`, e.g.
+    ///             `pin/digit/5` or `confirm`. The targets of the layout are listed
+    ///             in its trace under `debug_targets`.
+    ///             """
+    ///
     ///     def page_count(self) -> int:
     ///         """Return the number of pages in the layout object."""
     ///
@@ -1390,10 +1661,43 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def debug_overlay(show: bool) -> None:
+    ///     """Draw the bounds of the components and their touch areas over the
+    ///     layout, debug builds only. The layout needs a complete repaint."""
+    Qstr::MP_QSTR_debug_overlay => obj_fn_1!(upy_debug_overlay).as_obj(),
+
+    /// def seed_random(seed: int | None) -> None:
+    ///     """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    ///     reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+    Qstr::MP_QSTR_seed_random => obj_fn_1!(upy_seed_random).as_obj(),
+
+    /// def led_set_enabled(enable: bool) -> None:
+    ///     """Enable or disable the RGB LED signals, no-op without the LED."""
+    Qstr::MP_QSTR_led_set_enabled => obj_fn_1!(upy_led_set_enabled).as_obj(),
+
+    /// def set_keyboard_timings(
+    ///     erase_hold_ms: int,
+    ///     multi_tap_timeout_ms: int,
+    ///     last_char_reveal_ms: int,
+    ///     key_debounce_ms: int,
+    /// ) -> None:
+    ///     """Set the erase hold duration, the multi-tap timeout, the reveal time
+    ///     of the last passphrase character and the key debounce window of
+    ///     keyboards."""
+    Qstr::MP_QSTR_set_keyboard_timings => obj_fn_var!(4, 4, upy_set_keyboard_timings).as_obj(),
+
     /// def check_homescreen_format(data: bytes) -> bool:
     ///     """Check homescreen format and dimensions."""
     Qstr::MP_QSTR_check_homescreen_format => obj_fn_1!(upy_check_homescreen_format).as_obj(),
 
+    /// def set_high_contrast(enable: bool) -> None:
+    ///     """Use the high-contrast styles for screens created from now on."""
+    Qstr::MP_QSTR_set_high_contrast => obj_fn_1!(upy_set_high_contrast).as_obj(),
+
+    /// def set_large_text(enable: bool) -> None:
+    ///     """Use the larger fonts for screens created from now on."""
+    Qstr::MP_QSTR_set_large_text => obj_fn_1!(upy_set_large_text).as_obj(),
+
     /// def confirm_action(
     ///     *,
     ///     title: str,
@@ -1467,6 +1771,16 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     the value is to be rendered as binary with monospace font, False otherwise."""
     Qstr::MP_QSTR_confirm_properties => obj_fn_kw!(0, new_confirm_properties).as_obj(),
 
+    /// def confirm_list(
+    ///     *,
+    ///     title: str,
+    ///     items: list[str],
+    ///     numbered: bool = False,
+    ///     hold: bool = False,
+    /// ) -> LayoutObj[UiResult]:
+    ///     """Confirm a bulleted list of items, or a numbered one if `numbered` is True."""
+    Qstr::MP_QSTR_confirm_list => obj_fn_kw!(0, new_confirm_list).as_obj(),
+
     /// def flow_confirm_reset_recover() -> LayoutObj[UiResult]:
     ///     """Confirm TOS before recovery process."""
     Qstr::MP_QSTR_flow_confirm_reset_recover => obj_fn_kw!(0, flow::confirm_reset_recover::new_confirm_reset_recover).as_obj(),
@@ -1584,7 +1898,8 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     allow_cancel: bool = False,
     ///     time_ms: int = 0,
     /// ) -> LayoutObj[UiResult]:
-    ///     """Success screen. Description is used in the footer."""
+    ///     """Success screen. Description is used in the footer. Dismissed automatically
+    ///     after `time_ms` if it is non-zero."""
     Qstr::MP_QSTR_show_success => obj_fn_kw!(0, new_show_success).as_obj(),
 
     /// def show_info(
@@ -1643,11 +1958,16 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// def request_pin(
     ///     *,
     ///     prompt: str,
-    ///     subprompt: str,
+    ///     subprompt: str = "",
+    ///     attempts_remaining: int | None = None,
     ///     allow_cancel: bool = True,
     ///     wrong_pin: bool = False,
+    ///     wipe_code: bool = False,
     /// ) -> LayoutObj[str | UiResult]:
-    ///     """Request pin on device."""
+    ///     """Request pin on device. The subprompt is replaced by the remaining
+    ///     attempts counter if `attempts_remaining` is set, shown as a warning
+    ///     when only a few are left. `wipe_code` styles the prompt for entering
+    ///     a wipe code."""
     Qstr::MP_QSTR_request_pin => obj_fn_kw!(0, new_request_pin).as_obj(),
 
     /// def request_passphrase(
@@ -1660,22 +1980,53 @@ pub static mp_module_trezorui2: Module = obj_module! {
 
     /// def request_bip39(
     ///     *,
+    ///     title: str,
+    ///     subtitle: str,
     ///     prompt: str,
-    ///     prefill_word: str,
-    ///     can_go_back: bool,
+    ///     word_count: int,
+    ///     filter_last_word: bool = False,
+    ///     autofill: bool = False,
     /// ) -> LayoutObj[str]:
-    ///     """BIP39 word input keyboard."""
+    ///     """BIP39 word input keyboard for all the `word_count` words of a
+    ///     mnemonic, starting with the first one. Moves on to another word on
+    ///     `mnemonic_word_event`, which also replaces `subtitle`. Ticks along the
+    ///     top show the words entered. With `filter_last_word`, the last word is
+    ///     limited to the words giving a valid checksum. With `autofill`, a word
+    ///     identified by its prefix can be confirmed right away."""
     Qstr::MP_QSTR_request_bip39 => obj_fn_kw!(0, new_request_bip39).as_obj(),
 
     /// def request_slip39(
     ///     *,
+    ///     title: str,
+    ///     subtitle: str,
     ///     prompt: str,
-    ///     prefill_word: str,
-    ///     can_go_back: bool,
+    ///     word_count: int,
     /// ) -> LayoutObj[str]:
-    ///     """SLIP39 word input keyboard."""
+    ///     """SLIP39 word input keyboard for all the `word_count` words of a
+    ///     share, starting with the first one. Moves on to another word on
+    ///     `mnemonic_word_event`, which also replaces `subtitle`. Ticks along the
+    ///     top show the words entered."""
     Qstr::MP_QSTR_request_slip39 => obj_fn_kw!(0, new_request_slip39).as_obj(),
 
+    /// def request_amount(
+    ///     *,
+    ///     title: str,
+    ///     unit: str,
+    ///     max_integer_digits: int,
+    ///     max_decimals: int,
+    /// ) -> LayoutObj[str | UiResult]:
+    ///     """Decimal amount input keyboard. Returns the amount as typed, without
+    ///     thousands separators, to be parsed by the caller."""
+    Qstr::MP_QSTR_request_amount => obj_fn_kw!(0, new_request_amount).as_obj(),
+
+    /// def request_datetime(
+    ///     *,
+    ///     title: str,
+    /// ) -> LayoutObj[str | UiResult]:
+    ///     """Date and time input keyboard. Returns the value formatted as
+    ///     `YYYY-MM-DD hh:mm`."""
+    Qstr::MP_QSTR_request_datetime => obj_fn_kw!(0, new_request_datetime).as_obj(),
+
     /// def select_word(
     ///     *,
     ///     title: str,
@@ -1713,9 +2064,10 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     info: Callable[[int], str] | None = None,
     ///     br_code: ButtonRequestType,
     ///     br_type: str,
+    ///     direct_entry: bool = False,
     /// ) -> LayoutObj[tuple[UiResult, int]]:
     ///     """Numer input with + and - buttons, description, and context menu with cancel and
-    ///     info."""
+    ///     info. With `direct_entry`, tapping the number opens a keypad to type it."""
     Qstr::MP_QSTR_flow_request_number => obj_fn_kw!(0, flow::request_number::new_request_number).as_obj(),
 
     /// def set_brightness(
@@ -1750,8 +2102,11 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// def select_word_count(
     ///     *,
     ///     recovery_type: RecoveryType,
+    ///     bip39: bool = False,
     /// ) -> LayoutObj[int | str]:  # TT returns int
-    ///     """Select mnemonic word count from (12, 18, 20, 24, 33)."""
+    ///     """Select mnemonic word count from (12, 18, 20, 24, 33). Only the
+    ///     counts of the respective backup type are offered for `bip39` or
+    ///     unlocking a repeated backup."""
     Qstr::MP_QSTR_select_word_count => obj_fn_kw!(0, new_select_word_count).as_obj(),
 
     /// def show_group_share_success(
@@ -1763,9 +2118,11 @@ pub static mp_module_trezorui2: Module = obj_module! {
 
     /// def show_remaining_shares(
     ///     *,
-    ///     pages: Iterable[tuple[str, str]],
+    ///     pages: Iterable[tuple[int, bool, str]],
     /// ) -> LayoutObj[UiResult]:
-    ///     """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
+    ///     """Shows SLIP39 state after info button is pressed on `confirm_recovery`.
+    ///     Each page is the number of shares (or groups if the flag is set) still
+    ///     needed and the words they start with."""
     Qstr::MP_QSTR_show_remaining_shares => obj_fn_kw!(0, new_show_remaining_shares).as_obj(),
 
     /// def show_progress(
@@ -1773,10 +2130,14 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     title: str,
     ///     indeterminate: bool = False,
     ///     description: str = "",
+    ///     phase: str | None = None,
+    ///     eta: str | None = None,
     /// ) -> LayoutObj[UiResult]:
     ///     """Show progress loader. Please note that the number of lines reserved on screen for
-    ///    description is determined at construction time. If you want multiline descriptions
-    ///    make sure the initial description has at least that amount of lines."""
+    ///    description is determined at construction time, at least two. If you want multiline
+    ///    descriptions make sure the initial description has at least that amount of lines.
+    ///    Passing `phase` or `eta` reserves room for them below the loader, they are then
+    ///    updated by `progress_info_event`."""
     Qstr::MP_QSTR_show_progress => obj_fn_kw!(0, new_show_progress).as_obj(),
 
     /// def show_progress_coinjoin(
@@ -1787,7 +2148,8 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     skip_first_paint: bool = False,
     /// ) -> LayoutObj[UiResult]:
     ///     """Show progress loader for coinjoin. Returns CANCELLED after a specified time when
-    ///    time_ms timeout is passed."""
+    ///    time_ms timeout is passed. Progress value 0xFFFE switches the loader to
+    ///    indeterminate mode, 0xFFFF pauses it and shows the description, if any."""
     Qstr::MP_QSTR_show_progress_coinjoin => obj_fn_kw!(0, new_show_progress_coinjoin).as_obj(),
 
     /// def show_homescreen(
@@ -1807,6 +2169,8 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     bootscreen: bool,
     ///     skip_first_paint: bool,
     ///     coinjoin_authorized: bool = False,
+    ///     notification: str | None = None,
+    ///     notification_level: int = 0,
     /// ) -> LayoutObj[UiResult]:
     ///     """Homescreen for locked device."""
     Qstr::MP_QSTR_show_lockscreen => obj_fn_kw!(0, new_show_lockscreen).as_obj(),
@@ -1837,8 +2201,11 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     xpubs: list[tuple[str, str]],
     ///     br_code: ButtonRequestType,
     ///     br_type: str,
+    ///     script_type: str | None = None,
     /// ) -> LayoutObj[UiResult]:
-    ///     """Get address / receive funds."""
+    ///     """Get address / receive funds. Account details page is skipped when
+    ///     `account`, `path` and `script_type` are all missing and there are
+    ///     `xpubs` to show."""
     Qstr::MP_QSTR_flow_get_address => obj_fn_kw!(0, flow::get_address::new_get_address).as_obj(),
 
     /// def flow_warning_hi_prio(
@@ -2,24 +2,19 @@ pub mod bootloader;
 
 pub mod backlight;
 
-use crate::{
-    time::Duration,
-    ui::{
-        component::{
-            text::{layout::Chunks, LineBreaking, PageBreaking, TextStyle},
-            FixedHeightBar,
-        },
-        display::{Color, Font, Icon},
-        geometry::Insets,
+use crate::ui::{
+    component::{
+        text::{layout::Chunks, LineBreaking, PageBreaking, TextStyle},
+        FixedHeightBar,
     },
+    display::{Color, Font, Icon},
+    geometry::{Insets, Offset},
 };
 
 use super::component::{ButtonStyle, ButtonStyleSheet, LoaderStyle, LoaderStyleSheet, ResultStyle};
 
 use num_traits::FromPrimitive;
 
-pub const ERASE_HOLD_DURATION: Duration = Duration::from_millis(1500);
-
 // Color palette.
 pub const WHITE: Color = Color::rgb(0xFF, 0xFF, 0xFF);
 pub const BLACK: Color = Color::rgb(0, 0, 0);
@@ -157,6 +152,89 @@ include_icon!(IMAGE_BG_BACK_BTN_TALL, "model_tt/res/bg-back52.toif");
 
 include_icon!(ICON_LOGO_EMPTY, "model_tt/res/lock_empty.toif");
 
+/// Whether the high-contrast variant of the styles is in use.
+static mut HIGH_CONTRAST: bool = false;
+
+pub fn high_contrast() -> bool {
+    // SAFETY: single-threaded access
+    unsafe { HIGH_CONTRAST }
+}
+
+/// Switch the styles to the high-contrast variant: brighter text, bolder fonts
+/// and disabled buttons set apart more clearly. Only the styles that branch on
+/// `high_contrast()` are affected:
+/// - the PIN, passphrase and recovery keyboards and their prompts,
+/// - titles, subtitles and body text of the confirmation screens,
+/// - the confirm, cancel and danger buttons, the warning buttons and labels,
+/// - the footer instruction and description,
+/// - the icons of the hold-to-confirm and tap-to-confirm pads.
+///
+/// The styles are fetched when components are constructed, so every newly
+/// created screen picks the change up. The screens on display keep their look
+/// until they are entered again, i.e. the homescreen and lockscreen, a
+/// keyboard waiting for input and the settings menu the change was made from.
+pub fn set_high_contrast(enable: bool) {
+    // SAFETY: single-threaded access
+    unsafe {
+        HIGH_CONTRAST = enable;
+    }
+}
+
+/// Whether the large-text variant of the styles is in use.
+static mut LARGE_TEXT: bool = false;
+
+pub fn large_text() -> bool {
+    // SAFETY: single-threaded access
+    unsafe { LARGE_TEXT }
+}
+
+/// Switch body text, paragraphs, menu items and keyboard prompts to the next
+/// larger font, see `larger_font`. Like `set_high_contrast`, only the styles
+/// that branch on `large_text()` are affected and screens pick the change up
+/// when created. Screens where a larger font cannot fit, e.g. the keyboard
+/// keys, PIN dots or QR code captions, keep using the style constants
+/// directly.
+pub fn set_large_text(enable: bool) {
+    // SAFETY: single-threaded access
+    unsafe {
+        LARGE_TEXT = enable;
+    }
+}
+
+/// Next larger font of the model, `font` itself if there is none.
+pub const fn larger_font(font: Font) -> Font {
+    match font {
+        Font::SUB => Font::NORMAL,
+        Font::NORMAL | Font::DEMIBOLD | Font::BOLD => Font::BIG,
+        _ => font,
+    }
+}
+
+/// Fonts of the keyboard prompts, see `Label::with_auto_fit`. In the
+/// large-text mode, the prompt is shown in the larger font if it fits and
+/// wraps onto more lines in the smaller ones otherwise.
+pub fn keyboard_prompt_fonts() -> &'static [Font] {
+    if large_text() {
+        &[Font::BIG, Font::NORMAL, Font::SUB]
+    } else {
+        &[]
+    }
+}
+
+const fn larger_text(style: TextStyle) -> TextStyle {
+    TextStyle {
+        text_font: larger_font(style.text_font),
+        ..style
+    }
+}
+
+const fn with_text_color(style: TextStyle, text_color: Color) -> TextStyle {
+    TextStyle {
+        text_color,
+        ..style
+    }
+}
+
 // Default homescreen
 include_icon!(HOMESCREEN_ICON, "model_mercury/res/hs_lock.toif");
 
@@ -168,7 +246,10 @@ pub const fn label_keyboard() -> TextStyle {
     TextStyle::new(Font::DEMIBOLD, GREY_EXTRA_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
-pub const fn label_keyboard_prompt() -> TextStyle {
+pub fn label_keyboard_prompt() -> TextStyle {
+    if high_contrast() {
+        return TextStyle::new(Font::DEMIBOLD, FG, BG, GREY_LIGHT, GREY_LIGHT);
+    }
     TextStyle::new(Font::DEMIBOLD, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
@@ -176,15 +257,24 @@ pub const fn label_keyboard_warning() -> TextStyle {
     TextStyle::new(Font::DEMIBOLD, ORANGE_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
-pub const fn label_keyboard_minor() -> TextStyle {
+pub fn label_keyboard_minor() -> TextStyle {
+    if high_contrast() {
+        return TEXT_DEMIBOLD;
+    }
     TEXT_NORMAL_GREY_EXTRA_LIGHT
 }
 
-pub const fn label_warning() -> TextStyle {
+pub fn label_warning() -> TextStyle {
+    if high_contrast() {
+        return TEXT_BOLD;
+    }
     TEXT_DEMIBOLD
 }
 
-pub const fn label_warning_value() -> TextStyle {
+pub fn label_warning_value() -> TextStyle {
+    if high_contrast() {
+        return TEXT_NORMAL;
+    }
     TEXT_NORMAL_GREY_EXTRA_LIGHT
 }
 
@@ -200,14 +290,13 @@ pub const fn label_progress() -> TextStyle {
     TEXT_BOLD
 }
 
-pub const fn label_title_main() -> TextStyle {
-    TextStyle::new(
-        Font::NORMAL,
-        GREY_EXTRA_LIGHT,
-        GREY_DARK,
-        GREY_LIGHT,
-        GREY_LIGHT,
-    )
+pub fn label_title_main() -> TextStyle {
+    let text_color = if high_contrast() {
+        FG
+    } else {
+        GREY_EXTRA_LIGHT
+    };
+    TextStyle::new(Font::NORMAL, text_color, GREY_DARK, GREY_LIGHT, GREY_LIGHT)
 }
 
 pub const fn label_title_danger() -> TextStyle {
@@ -224,6 +313,14 @@ pub const fn label_title_sub() -> TextStyle {
     TextStyle::new(Font::SUB, GREY, GREY_DARK, GREY_LIGHT, GREY_LIGHT)
 }
 
+pub fn label_subtitle() -> TextStyle {
+    if high_contrast() {
+        TEXT_SUB_GREY_LIGHT
+    } else {
+        TEXT_SUB_GREY
+    }
+}
+
 pub const fn label_coinjoin_progress() -> TextStyle {
     TextStyle::new(Font::BOLD, FG, YELLOW, FG, FG)
 }
@@ -254,33 +351,140 @@ pub const fn button_default() -> ButtonStyleSheet {
     }
 }
 
-pub const fn button_warning_high() -> ButtonStyleSheet {
-    ButtonStyleSheet {
-        normal: &ButtonStyle {
-            font: Font::DEMIBOLD,
-            text_color: ORANGE_LIGHT,
-            button_color: BG,
-            icon_color: ORANGE_DIMMED,
-            background_color: BG,
-        },
-        active: &ButtonStyle {
-            font: Font::DEMIBOLD,
-            text_color: ORANGE_LIGHT,
-            button_color: BG,
-            icon_color: ORANGE_DIMMED,
-            background_color: BG,
-        },
-        disabled: &ButtonStyle {
-            font: Font::DEMIBOLD,
-            text_color: ORANGE_LIGHT,
-            button_color: BG,
-            icon_color: ORANGE_DIMMED,
-            background_color: BG,
-        },
+/// `button_default` for menu items, in the larger font in the large-text mode.
+/// Text that does not fit the button is cut with an ellipsis.
+pub fn button_menu_item() -> ButtonStyleSheet {
+    const REGULAR: ButtonStyleSheet = button_default();
+    const LARGE: ButtonStyleSheet = ButtonStyleSheet {
+        normal: &larger_button(REGULAR.normal),
+        active: &larger_button(REGULAR.active),
+        disabled: &larger_button(REGULAR.disabled),
+    };
+    if large_text() {
+        LARGE
+    } else {
+        REGULAR
     }
 }
 
-pub const fn button_warning_low() -> ButtonStyleSheet {
+/// `button_warning_high` for menu items, see `button_menu_item`.
+pub fn button_menu_danger() -> ButtonStyleSheet {
+    const LARGE: ButtonStyleSheet = ButtonStyleSheet {
+        normal: &larger_button(BUTTON_WARNING_HIGH.normal),
+        active: &larger_button(BUTTON_WARNING_HIGH.active),
+        disabled: &larger_button(BUTTON_WARNING_HIGH.disabled),
+    };
+    const LARGE_CONTRAST: ButtonStyleSheet = ButtonStyleSheet {
+        normal: &larger_button(BUTTON_WARNING_HIGH_CONTRAST.normal),
+        active: &larger_button(BUTTON_WARNING_HIGH_CONTRAST.active),
+        disabled: &larger_button(BUTTON_WARNING_HIGH_CONTRAST.disabled),
+    };
+    match (large_text(), high_contrast()) {
+        (false, false) => BUTTON_WARNING_HIGH,
+        (false, true) => BUTTON_WARNING_HIGH_CONTRAST,
+        (true, false) => LARGE,
+        (true, true) => LARGE_CONTRAST,
+    }
+}
+
+const fn larger_button(style: &ButtonStyle) -> ButtonStyle {
+    ButtonStyle {
+        font: larger_font(style.font),
+        ..*style
+    }
+}
+
+/// Style of the secondary line of a two-line button, e.g. a current value.
+pub const BUTTON_SUBTEXT: ButtonStyle = ButtonStyle {
+    font: Font::SUB,
+    text_color: GREY,
+    button_color: BG,
+    icon_color: GREY,
+    background_color: BG,
+};
+
+pub fn button_warning_high() -> ButtonStyleSheet {
+    if high_contrast() {
+        BUTTON_WARNING_HIGH_CONTRAST
+    } else {
+        BUTTON_WARNING_HIGH
+    }
+}
+
+const BUTTON_WARNING_HIGH: ButtonStyleSheet = ButtonStyleSheet {
+    normal: &ButtonStyle {
+        font: Font::DEMIBOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_DIMMED,
+        background_color: BG,
+    },
+    active: &ButtonStyle {
+        font: Font::DEMIBOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_DIMMED,
+        background_color: BG,
+    },
+    disabled: &ButtonStyle {
+        font: Font::DEMIBOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_DIMMED,
+        background_color: BG,
+    },
+};
+
+const BUTTON_WARNING_HIGH_CONTRAST: ButtonStyleSheet = ButtonStyleSheet {
+    normal: &ButtonStyle {
+        font: Font::BOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_LIGHT,
+        background_color: BG,
+    },
+    active: &ButtonStyle {
+        font: Font::BOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_LIGHT,
+        background_color: BG,
+    },
+    disabled: &ButtonStyle {
+        font: Font::BOLD,
+        text_color: ORANGE_LIGHT,
+        button_color: BG,
+        icon_color: ORANGE_LIGHT,
+        background_color: BG,
+    },
+};
+
+pub fn button_warning_low() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: BG,
+                icon_color: GREEN_LIME,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: BG,
+                icon_color: GREEN_LIME,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: BG,
+                icon_color: GREEN_LIME,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::DEMIBOLD,
@@ -307,7 +511,32 @@ pub const fn button_warning_low() -> ButtonStyleSheet {
 }
 
 // TODO: delete
-pub const fn button_confirm() -> ButtonStyleSheet {
+pub fn button_confirm() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: GREEN,
+                icon_color: FG,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: GREEN_DARK,
+                icon_color: FG,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: GREY,
+                button_color: GREY_EXTRA_DARK,
+                icon_color: GREY,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::BOLD,
@@ -334,7 +563,32 @@ pub const fn button_confirm() -> ButtonStyleSheet {
 }
 
 // TODO: delete
-pub const fn button_cancel() -> ButtonStyleSheet {
+pub fn button_cancel() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: ORANGE_LIGHT,
+                icon_color: FG,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: FG,
+                button_color: ORANGE_DIMMED,
+                icon_color: FG,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: GREY,
+                button_color: GREY_EXTRA_DARK,
+                icon_color: GREY,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::BOLD,
@@ -360,7 +614,32 @@ pub const fn button_cancel() -> ButtonStyleSheet {
     }
 }
 
-pub const fn button_danger() -> ButtonStyleSheet {
+pub fn button_danger() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: ORANGE_LIGHT,
+                button_color: BG,
+                icon_color: ORANGE_LIGHT,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: ORANGE_LIGHT,
+                button_color: BG,
+                icon_color: ORANGE_LIGHT,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::BOLD,
+                text_color: ORANGE_LIGHT,
+                button_color: BG,
+                icon_color: ORANGE_LIGHT,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::DEMIBOLD,
@@ -387,7 +666,32 @@ pub const fn button_danger() -> ButtonStyleSheet {
 }
 
 // used for PIN digit keys and passphrase/recovery letter keys
-pub const fn button_keyboard() -> ButtonStyleSheet {
+pub fn button_keyboard() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::DEMIBOLD,
+                text_color: FG,
+                button_color: GREY_DARK,
+                icon_color: FG,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::DEMIBOLD,
+                text_color: BG,
+                button_color: FG,
+                icon_color: BG,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::NORMAL,
+                text_color: GREY,
+                button_color: BG,
+                icon_color: GREY,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::NORMAL,
@@ -440,7 +744,33 @@ pub const fn button_keyboard_cancel() -> ButtonStyleSheet {
     }
 }
 
-pub const fn button_keyboard_erase() -> ButtonStyleSheet {
+pub fn button_keyboard_erase() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::MONO,
+                text_color: FG,
+                button_color: BG,
+                icon_color: FG,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::MONO,
+                text_color: BG,
+                button_color: FG,
+                icon_color: BG,
+                background_color: BG,
+            },
+            // not used
+            disabled: &ButtonStyle {
+                font: Font::MONO,
+                text_color: FG,
+                button_color: BG,
+                icon_color: GREY,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::MONO,
@@ -469,7 +799,32 @@ pub const fn button_keyboard_erase() -> ButtonStyleSheet {
 
 // TODO: merge `button_pin_confirm` and `_passphrase_confirm`. Need to render
 // button `.with_radius` correctly
-pub const fn button_pin_confirm() -> ButtonStyleSheet {
+pub fn button_pin_confirm() -> ButtonStyleSheet {
+    if high_contrast() {
+        return ButtonStyleSheet {
+            normal: &ButtonStyle {
+                font: Font::MONO,
+                text_color: FG,
+                button_color: GREEN,
+                icon_color: FG,
+                background_color: BG,
+            },
+            active: &ButtonStyle {
+                font: Font::MONO,
+                text_color: FG,
+                button_color: GREEN_LIME,
+                icon_color: BG,
+                background_color: BG,
+            },
+            disabled: &ButtonStyle {
+                font: Font::MONO,
+                text_color: GREY_DARK,
+                button_color: BG,
+                icon_color: GREY_DARK,
+                background_color: BG,
+            },
+        };
+    }
     ButtonStyleSheet {
         normal: &ButtonStyle {
             font: Font::MONO,
@@ -549,6 +904,34 @@ pub const fn button_passphrase_next() -> ButtonStyleSheet {
     }
 }
 
+/// Passphrase keyboard warning about spaces, in place of the input.
+pub const fn button_passphrase_warning() -> ButtonStyleSheet {
+    ButtonStyleSheet {
+        normal: &ButtonStyle {
+            font: Font::SUB,
+            text_color: ORANGE_LIGHT,
+            button_color: BG,
+            icon_color: ORANGE_LIGHT,
+            background_color: BG,
+        },
+        active: &ButtonStyle {
+            font: Font::SUB,
+            text_color: ORANGE_LIGHT,
+            button_color: GREY_EXTRA_DARK,
+            icon_color: ORANGE_LIGHT,
+            background_color: BG,
+        },
+        // not used
+        disabled: &ButtonStyle {
+            font: Font::SUB,
+            text_color: GREY,
+            button_color: BG,
+            icon_color: GREY,
+            background_color: BG,
+        },
+    }
+}
+
 pub const fn button_recovery_confirm() -> ButtonStyleSheet {
     ButtonStyleSheet {
         normal: &ButtonStyle {
@@ -731,6 +1114,14 @@ pub const TEXT_MONO_GREY_LIGHT: TextStyle = TextStyle {
 pub const TEXT_MONO_ADDRESS_CHUNKS: TextStyle = TEXT_MONO
     .with_chunks(Chunks::new(4, 9))
     .with_line_spacing(5);
+/// Chunked extended public keys in the address details.
+pub const TEXT_MONO_XPUB_CHUNKS: TextStyle = TEXT_MONO_GREY_LIGHT
+    .with_chunks(Chunks::new(4, 7))
+    .with_line_spacing(5);
+/// Chunked derivation path in the address details.
+pub const TEXT_MONO_PATH_CHUNKS: TextStyle = TEXT_MONO_GREY_LIGHT
+    .with_chunks(Chunks::new(4, 7))
+    .with_line_spacing(5);
 /// Smaller horizontal chunk offset, used e.g. for long Cardano addresses.
 /// Also moving the next page ellipsis to the left (as there is a space on the
 /// left). Last but not least, maximum number of rows is 4 in this case.
@@ -744,6 +1135,80 @@ pub const TEXT_NORMAL: TextStyle = TextStyle::new(Font::NORMAL, FG, BG, GREY_LIG
 pub const TEXT_DEMIBOLD: TextStyle = TextStyle::new(Font::DEMIBOLD, FG, BG, GREY_LIGHT, GREY_LIGHT);
 pub const TEXT_BOLD: TextStyle = TextStyle::new(Font::BOLD, FG, BG, GREY_LIGHT, GREY_LIGHT);
 
+// Body text of the screens, in the larger fonts in the large-text mode.
+pub fn text_normal() -> &'static TextStyle {
+    const LARGE: TextStyle = larger_text(TEXT_NORMAL);
+    if large_text() {
+        &LARGE
+    } else {
+        &TEXT_NORMAL
+    }
+}
+
+pub fn text_demibold() -> &'static TextStyle {
+    const LARGE: TextStyle = larger_text(TEXT_DEMIBOLD);
+    if large_text() {
+        &LARGE
+    } else {
+        &TEXT_DEMIBOLD
+    }
+}
+
+// Body text of the confirmation screens, also brighter in the high-contrast
+// mode.
+pub fn text_main_grey_light() -> &'static TextStyle {
+    const CONTRAST: TextStyle = with_text_color(TEXT_MAIN_GREY_LIGHT, FG);
+    const LARGE: TextStyle = larger_text(TEXT_MAIN_GREY_LIGHT);
+    const LARGE_CONTRAST: TextStyle = larger_text(CONTRAST);
+    match (large_text(), high_contrast()) {
+        (false, false) => &TEXT_MAIN_GREY_LIGHT,
+        (false, true) => &CONTRAST,
+        (true, false) => &LARGE,
+        (true, true) => &LARGE_CONTRAST,
+    }
+}
+
+pub fn text_sub_grey() -> &'static TextStyle {
+    const CONTRAST: TextStyle = with_text_color(TEXT_SUB_GREY, GREY_LIGHT);
+    const LARGE: TextStyle = larger_text(TEXT_SUB_GREY);
+    const LARGE_CONTRAST: TextStyle = larger_text(CONTRAST);
+    match (large_text(), high_contrast()) {
+        (false, false) => &TEXT_SUB_GREY,
+        (false, true) => &CONTRAST,
+        (true, false) => &LARGE,
+        (true, true) => &LARGE_CONTRAST,
+    }
+}
+
+/// Icon in the middle of the hold-to-confirm and tap-to-confirm pads.
+pub fn confirm_pad_icon_color() -> Color {
+    if high_contrast() {
+        GREY_LIGHT
+    } else {
+        GREY
+    }
+}
+
+// Footer instruction and description, brighter in the high-contrast mode. The
+// footer has a fixed height so they stay in the small font.
+pub fn text_footer_instruction() -> &'static TextStyle {
+    const CONTRAST: TextStyle = with_text_color(TEXT_SUB_GREY, GREY_LIGHT);
+    if high_contrast() {
+        &CONTRAST
+    } else {
+        &TEXT_SUB_GREY
+    }
+}
+
+pub fn text_footer_description() -> &'static TextStyle {
+    const CONTRAST: TextStyle = with_text_color(TEXT_SUB_GREY_LIGHT, FG);
+    if high_contrast() {
+        &CONTRAST
+    } else {
+        &TEXT_SUB_GREY_LIGHT
+    }
+}
+
 /// Decide the text style of chunkified text according to its length.
 pub fn get_chunkified_text_style(character_length: usize) -> &'static TextStyle {
     // Longer addresses have smaller x_offset so they fit even with scrollbar
@@ -769,7 +1234,8 @@ pub fn textstyle_number(num: i32) -> &'static TextStyle {
 
 pub const TEXT_NORMAL_GREY_EXTRA_LIGHT: TextStyle =
     TextStyle::new(Font::NORMAL, GREY_EXTRA_LIGHT, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_CHECKLIST_DEFAULT: TextStyle = TextStyle::new(Font::SUB, GREY, BG, GREY, GREY);
+pub const TEXT_CHECKLIST_DEFAULT: TextStyle =
+    TextStyle::new(Font::SUB, GREY_DARK, BG, GREY_DARK, GREY_DARK);
 pub const TEXT_CHECKLIST_SELECTED: TextStyle =
     TextStyle::new(Font::NORMAL, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT);
 pub const TEXT_CHECKLIST_DONE: TextStyle = TextStyle::new(Font::SUB, GREY, BG, GREY, GREY);
@@ -784,7 +1250,16 @@ pub const CONTENT_BORDER: i16 = 0;
 pub const BUTTON_HEIGHT: i16 = 62;
 pub const BUTTON_WIDTH: i16 = 78;
 pub const BUTTON_SPACING: i16 = SPACING;
+/// Gap between the icon and the text of a button showing both.
+pub const BUTTON_ICON_TEXT_GAP: i16 = 8;
+/// Gap between the text and the subtext of a button showing both.
+pub const BUTTON_SUBTEXT_GAP: i16 = 6;
 pub const KEYBOARD_SPACING: i16 = BUTTON_SPACING;
+/// Show a rounded background behind the keyboard prompt and input instead of
+/// the plain one. Off until the design is settled.
+pub const KEYBOARD_TEXTBOX_ROUNDED: bool = false;
+/// Corner radius of the rounded keyboard textbox background.
+pub const KEYBOARD_TEXTBOX_RADIUS: i16 = 8;
 pub const CHECKLIST_SPACING: i16 = 12;
 pub const RECOVERY_SPACING: i16 = 18;
 pub const CORNER_BUTTON_SIDE: i16 = 44;
@@ -801,6 +1276,7 @@ pub const DETAILS_SPACING: i16 = 8;
 
 // checklist settings
 pub const CHECKLIST_CHECK_WIDTH: i16 = 32; // icon width (20px) + padding (12px)
+pub const CHECKLIST_PENDING_OFFSET: Offset = Offset::new(6, 6); // dot (8px) centered under the icons
 
 pub const fn button_bar<T>(inner: T) -> FixedHeightBar<T> {
     FixedHeightBar::bottom(inner, BUTTON_HEIGHT)
@@ -814,15 +1290,15 @@ pub const fn button_bar<T>(inner: T) -> FixedHeightBar<T> {
 /// |     6    |
 /// +----------+
 pub const fn borders() -> Insets {
-    Insets::new(0, 0, 0, 0)
+    Insets::uniform(0)
 }
 
 pub const fn borders_horizontal_scroll() -> Insets {
-    Insets::new(0, 0, 0, 0)
+    Insets::uniform(0)
 }
 
 pub const fn borders_notification() -> Insets {
-    Insets::new(42, 0, 0, 0)
+    borders().add(Insets::top(42))
 }
 
 pub const RESULT_ERROR: ResultStyle =
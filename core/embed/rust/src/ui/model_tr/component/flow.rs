@@ -4,6 +4,7 @@ use crate::{
         component::{Child, Component, ComponentExt, Event, EventCtx, Pad, Paginate},
         constant::SCREEN,
         geometry::Rect,
+        led,
         shape::Renderer,
     },
 };
@@ -35,6 +36,8 @@ where
     /// Possibly enforcing the second button to be ignored after some time after
     /// pressing the first button
     ignore_second_button_ms: Option<u32>,
+    /// LED pulse shown when the flow appears.
+    led_signal: Option<led::Signal>,
 }
 
 impl<F> Flow<F>
@@ -61,6 +64,7 @@ where
             return_confirmed_index: false,
             show_scrollbar: true,
             ignore_second_button_ms: None,
+            led_signal: None,
         }
     }
 
@@ -90,6 +94,12 @@ where
         self
     }
 
+    /// Pulse the LED when the flow appears.
+    pub fn with_led_signal(mut self, signal: led::Signal) -> Self {
+        self.led_signal = Some(signal);
+        self
+    }
+
     pub fn confirmed_index(&self) -> Option<usize> {
         self.return_confirmed_index.then_some(self.page_counter)
     }
@@ -256,6 +266,9 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let (Event::Attach(_), Some(signal)) = (event, self.led_signal) {
+            led::signal(ctx, signal);
+        }
         ctx.set_page_count(self.pages.scrollbar_page_count(self.content_area));
         self.title.event(ctx, event);
         let button_event = self.buttons.event(ctx, event);
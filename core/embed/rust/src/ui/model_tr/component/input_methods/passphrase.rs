@@ -1,11 +1,11 @@
 use crate::{
     strutil::TString,
     translations::TR,
-    trezorhal::random,
     ui::{
         component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
         display::Icon,
         geometry::Rect,
+        random,
         shape::Renderer,
         util::char_to_string,
     },
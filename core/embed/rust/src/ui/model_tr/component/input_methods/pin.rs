@@ -1,11 +1,11 @@
 use crate::{
     strutil::TString,
     translations::TR,
-    trezorhal::random,
     ui::{
         component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
         display::{Font, Icon},
         geometry::Rect,
+        led, random,
         shape::Renderer,
     },
 };
@@ -265,6 +265,11 @@ impl Component for PinEntry<'_> {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        // The wrong PIN warning is shown right away.
+        if matches!(event, Event::Attach(_)) && !self.showing_real_prompt {
+            led::signal_warning(ctx);
+        }
+
         // Any non-timer event when showing real PIN should hide it
         // Same with showing last digit
         if !matches!(event, Event::Timer(_)) {
@@ -303,6 +308,7 @@ impl Component for PinEntry<'_> {
                 }
                 PinAction::Enter if !self.is_empty() => {
                     // ENTER is not valid when the PIN is empty
+                    led::signal_attention(ctx);
                     return Some(CancelConfirmMsg::Confirmed);
                 }
                 PinAction::Digit(ch) if !self.is_full() => {
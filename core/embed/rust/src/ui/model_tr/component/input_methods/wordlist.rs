@@ -1,9 +1,10 @@
 use crate::{
     translations::TR,
-    trezorhal::{random, wordlist::Wordlist},
+    trezorhal::wordlist::Wordlist,
     ui::{
         component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
         geometry::Rect,
+        random,
         shape::Renderer,
         util::char_to_string,
     },
@@ -244,5 +244,13 @@ where
             content
         };
         t.string("screen_content", content.as_str().into());
+        t.in_list("visible_indices", &|list| {
+            if !self.is_final_page() {
+                let end = (self.word_index() + WORDS_PER_PAGE).min(self.share_words.len());
+                for i in self.word_index()..end {
+                    list.int(i as i64);
+                }
+            }
+        });
     }
 }
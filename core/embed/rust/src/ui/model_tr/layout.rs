@@ -26,14 +26,18 @@ use crate::{
                 },
                 TextStyle,
             },
-            ComponentExt, FormattedText, Label, LineBreaking, Timeout,
+            ComponentExt, FormattedText, Label, LineBreaking, Never, Timeout,
         },
         geometry,
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
             result::{CANCELLED, CONFIRMED, INFO},
-            util::{upy_disable_animation, ConfirmBlob},
+            util::{
+                upy_debug_overlay, upy_disable_animation, upy_led_set_enabled, upy_seed_random,
+                ConfirmBlob,
+            },
         },
+        led,
         model_tr::component::check_homescreen_format,
     },
 };
@@ -121,9 +125,9 @@ impl ComponentMsgObj for PinEntry<'_> {
     }
 }
 
-impl<T> ComponentMsgObj for (Timeout, T)
+impl<T> ComponentMsgObj for Timeout<T>
 where
-    T: Component<Msg = ()>,
+    T: Component<Msg = Never>,
 {
     fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
         Ok(CANCELLED.as_obj())
@@ -1070,7 +1074,7 @@ extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map
             Page::new(btn_layout, btn_actions, formatted)
         };
         let pages = FlowPages::new(get_page, 1);
-        let obj = LayoutObj::new(Flow::new(pages))?;
+        let obj = LayoutObj::new(Flow::new(pages).with_led_signal(led::Signal::Warning))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1092,8 +1096,7 @@ extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -
             LayoutObj::new(content)?
         } else {
             // Timeout.
-            let timeout = Timeout::new(time_ms);
-            LayoutObj::new((timeout, content.map(|_| None)))?
+            LayoutObj::new(Timeout::wrap(content, time_ms))?
         };
 
         Ok(obj.into())
@@ -1509,8 +1512,7 @@ extern "C" fn new_show_progress_coinjoin(n_args: usize, args: *const Obj, kwargs
         // provide it.
         let progress = CoinJoinProgress::new(title, indeterminate);
         let obj = if time_ms > 0 && indeterminate {
-            let timeout = Timeout::new(time_ms);
-            LayoutObj::new((timeout, progress.map(|_msg| None)))?
+            LayoutObj::new(Timeout::wrap(progress, time_ms))?
         } else {
             LayoutObj::new(progress)?
         };
@@ -1636,6 +1638,20 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def debug_overlay(show: bool) -> None:
+    ///     """Draw the bounds of the components and their touch areas over the
+    ///     layout, debug builds only. The layout needs a complete repaint."""
+    Qstr::MP_QSTR_debug_overlay => obj_fn_1!(upy_debug_overlay).as_obj(),
+
+    /// def seed_random(seed: int | None) -> None:
+    ///     """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    ///     reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+    Qstr::MP_QSTR_seed_random => obj_fn_1!(upy_seed_random).as_obj(),
+
+    /// def led_set_enabled(enable: bool) -> None:
+    ///     """Enable or disable the RGB LED signals, no-op without the LED."""
+    Qstr::MP_QSTR_led_set_enabled => obj_fn_1!(upy_led_set_enabled).as_obj(),
+
     /// def check_homescreen_format(data: bytes) -> bool:
     ///     """Check homescreen format and dimensions."""
     Qstr::MP_QSTR_check_homescreen_format => obj_fn_1!(upy_check_homescreen_format).as_obj(),
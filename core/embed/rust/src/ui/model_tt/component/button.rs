@@ -2,7 +2,7 @@
 use crate::trezorhal::haptic::{self, HapticEffect};
 use crate::{
     strutil::TString,
-    time::Duration,
+    time::{Duration, Stopwatch},
     ui::{
         component::{
             Component, ComponentExt, Event, EventCtx, FixedHeightBar, MsgMap, Split, TimerToken,
@@ -32,6 +32,10 @@ pub struct Button {
     state: State,
     long_press: Option<Duration>,
     long_timer: Option<TimerToken>,
+    /// Presses starting this soon after the previous release are ignored.
+    debounce: Option<Duration>,
+    /// Started by the last release.
+    released: Stopwatch,
     haptics: bool,
 }
 
@@ -49,6 +53,8 @@ impl Button {
             state: State::Initial,
             long_press: None,
             long_timer: None,
+            debounce: None,
+            released: Stopwatch::new_stopped(),
             haptics: true,
         }
     }
@@ -88,6 +94,13 @@ impl Button {
         self
     }
 
+    /// Ignore presses starting within `window` after the button was released,
+    /// to filter out accidental double taps. Zero `window` disables it.
+    pub fn with_debounce(mut self, window: Duration) -> Self {
+        self.debounce = (window != Duration::ZERO).then_some(window);
+        self
+    }
+
     pub const fn without_haptics(mut self) -> Self {
         self.haptics = false;
         self
@@ -311,9 +324,15 @@ impl Component for Button {
                     _ => {
                         // Touch started in our area, transform to `Pressed` state.
                         if touch_area.contains(pos) {
+                            if self
+                                .debounce
+                                .is_some_and(|window| self.released.is_running_within(window))
+                            {
+                                return None;
+                            }
                             #[cfg(feature = "haptic")]
                             if self.haptics {
-                                haptic::play(HapticEffect::ButtonPress);
+                                haptic::play(HapticEffect::Press);
                             }
                             self.set(ctx, State::Pressed);
                             if let Some(duration) = self.long_press {
@@ -344,6 +363,7 @@ impl Component for Button {
                     State::Pressed if touch_area.contains(pos) => {
                         // Touch finished in our area, we got clicked.
                         self.set(ctx, State::Initial);
+                        self.released = Stopwatch::new_started();
                         return Some(ButtonMsg::Clicked);
                     }
                     _ => {
@@ -359,7 +379,7 @@ impl Component for Button {
                     if matches!(self.state, State::Pressed) {
                         #[cfg(feature = "haptic")]
                         if self.haptics {
-                            haptic::play(HapticEffect::ButtonPress);
+                            haptic::play(HapticEffect::Press);
                         }
                         self.set(ctx, State::Initial);
                         return Some(ButtonMsg::LongPressed);
@@ -403,6 +423,7 @@ impl crate::trace::Trace for Button {
             }
             ButtonContent::IconBlend(_, _, _) => t.bool("icon", true),
         }
+        t.bool("enabled", self.is_enabled());
     }
 }
 
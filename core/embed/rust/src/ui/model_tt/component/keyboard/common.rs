@@ -1,7 +1,10 @@
 use crate::{
     time::Duration,
     ui::{
-        component::{text::common::TextEdit, Event, EventCtx, TimerToken},
+        component::{
+            text::common::{KeyboardTimings, TextEdit},
+            Event, EventCtx, TimerToken,
+        },
         display::{self, Color, Font},
         geometry::{Offset, Point, Rect},
         shape,
@@ -31,7 +34,7 @@ impl MultiTapKeyboard {
     /// Create a new, empty, multi-tap state.
     pub fn new() -> Self {
         Self {
-            timeout: Duration::from_secs(1),
+            timeout: KeyboardTimings::get().multi_tap_timeout,
             pending: None,
         }
     }
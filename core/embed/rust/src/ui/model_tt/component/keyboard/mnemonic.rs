@@ -1,7 +1,10 @@
 use crate::{
     strutil::TString,
     ui::{
-        component::{maybe::paint_overlapping, Child, Component, Event, EventCtx, Label, Maybe},
+        component::{
+            maybe::paint_overlapping, text::common::KeyboardTimings, Child, Component, Event,
+            EventCtx, Label, Maybe,
+        },
         geometry::{Alignment2D, Grid, Offset, Rect},
         model_tt::{
             component::{Button, ButtonMsg, Swipe, SwipeDirection},
@@ -55,12 +58,16 @@ where
                     Offset::new(30, 17),
                 )
                 .styled(theme::button_reset())
-                .with_long_press(theme::ERASE_HOLD_DURATION),
+                .with_long_press(KeyboardTimings::get().erase_hold),
                 !prompt_visible,
             )),
             input: Child::new(Maybe::new(theme::BG, input, !prompt_visible)),
             keys: T::keys()
-                .map(|t| Button::with_text(t.into()).styled(theme::button_pin()))
+                .map(|t| {
+                    Button::with_text(t.into())
+                        .styled(theme::button_pin())
+                        .with_debounce(KeyboardTimings::get().key_debounce)
+                })
                 .map(Child::new),
             swipe: Swipe::new().right(),
             can_go_back,
@@ -233,5 +240,6 @@ where
         t.component("MnemonicKeyboard");
         t.child("prompt", &self.prompt);
         t.child("input", &self.input);
+        t.child("erase", &self.back);
     }
 }
@@ -1,10 +1,14 @@
 use crate::{
     strutil::TString,
+    time::Duration,
     ui::{
         component::{
-            base::ComponentExt, text::common::TextBox, Child, Component, Event, EventCtx, Never,
+            base::ComponentExt,
+            text::common::{KeyboardTimings, TextBox},
+            Child, Component, Event, EventCtx, Never, Timer,
         },
         display,
+        event::TouchEvent,
         geometry::{Grid, Offset, Rect},
         model_tt::component::{
             button::{Button, ButtonContent, ButtonMsg},
@@ -18,7 +22,8 @@ use crate::{
     },
 };
 
-use core::cell::Cell;
+use core::{cell::Cell, mem};
+use heapless::String;
 
 pub enum PassphraseKeyboardMsg {
     Confirmed,
@@ -64,10 +69,14 @@ impl PassphraseKeyboard {
             )
             .styled(theme::button_reset())
             .initially_enabled(false)
-            .with_long_press(theme::ERASE_HOLD_DURATION)
+            .with_long_press(KeyboardTimings::get().erase_hold)
             .into_child(),
             keys: KEYBOARD[STARTING_PAGE].map(|text| {
-                Child::new(Button::new(Self::key_content(text)).styled(theme::button_pin()))
+                Child::new(
+                    Button::new(Self::key_content(text))
+                        .styled(theme::button_pin())
+                        .with_debounce(KeyboardTimings::get().key_debounce),
+                )
             }),
             scrollbar: ScrollBar::horizontal(),
             fade: Cell::new(false),
@@ -231,6 +240,8 @@ impl Component for PassphraseKeyboard {
             self.on_page_swipe(ctx, swipe);
             return None;
         }
+        // Tapping the input hides or shows the passphrase.
+        self.input.event(ctx, event);
         if let Some(ButtonMsg::Clicked) = self.confirm.event(ctx, event) {
             // Confirm button was clicked, we're done.
             return Some(PassphraseKeyboardMsg::Confirmed);
@@ -279,6 +290,7 @@ impl Component for PassphraseKeyboard {
                 self.input.mutate(ctx, |ctx, i| {
                     let edit = text.map(|c| i.multi_tap.click_key(ctx, key, c));
                     i.textbox.apply(ctx, edit);
+                    i.reveal_last_char(ctx);
                 });
                 self.after_edit(ctx);
                 return None;
@@ -331,6 +343,14 @@ struct Input {
     area: Rect,
     textbox: TextBox<MAX_LENGTH>,
     multi_tap: MultiTapKeyboard,
+    /// Passphrase shown as `*`, toggled by tapping the input.
+    hidden: bool,
+    /// Whether the current touch started in the input.
+    pressed: bool,
+    /// Running while the last typed character of the hidden passphrase is
+    /// still readable.
+    last_char_timer: Timer,
+    last_char_reveal: Duration,
 }
 
 impl Input {
@@ -339,6 +359,29 @@ impl Input {
             area: Rect::zero(),
             textbox: TextBox::empty(),
             multi_tap: MultiTapKeyboard::new(),
+            hidden: false,
+            pressed: false,
+            last_char_timer: Timer::new(),
+            last_char_reveal: KeyboardTimings::get().last_char_reveal,
+        }
+    }
+
+    /// Keep the character just typed readable for a while if the passphrase
+    /// is hidden.
+    fn reveal_last_char(&mut self, ctx: &mut EventCtx) {
+        if self.hidden && self.last_char_reveal != Duration::ZERO {
+            self.last_char_timer.start(ctx, self.last_char_reveal);
+        }
+    }
+
+    /// Text to be shown, masked if hidden. The pending character of multi-tap
+    /// stays readable while it can still change.
+    fn display_text(&self) -> String<MAX_LENGTH> {
+        let show_last = self.last_char_timer.is_running() || self.multi_tap.pending_key().is_some();
+        if self.hidden {
+            self.textbox.masked_content(show_last)
+        } else {
+            unwrap!(String::try_from(self.textbox.content()))
         }
     }
 }
@@ -351,7 +394,23 @@ impl Component for Input {
         self.area
     }
 
-    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => {
+                self.pressed = self.area.contains(pos);
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) => {
+                if mem::take(&mut self.pressed) && self.area.contains(pos) {
+                    self.hidden = !self.hidden;
+                    self.last_char_timer.stop(ctx);
+                    ctx.request_paint();
+                }
+            }
+            _ => {}
+        }
+        if self.last_char_timer.expire(event) {
+            ctx.request_paint();
+        }
         None
     }
 
@@ -361,7 +420,7 @@ impl Component for Input {
         let text_baseline = self.area.top_left() + Offset::y(style.text_font.text_height())
             - Offset::y(style.text_font.text_baseline());
 
-        let text = self.textbox.content();
+        let text = self.display_text();
 
         // Preparing the new text to be displayed.
         // Possible optimization is to redraw the background only when pending character
@@ -374,7 +433,7 @@ impl Component for Input {
         // the last character
         let available_area_width = self.area.width() - 1;
         let text_to_display =
-            long_line_content_with_ellipsis(text, "...", style.text_font, available_area_width);
+            long_line_content_with_ellipsis(&text, "...", style.text_font, available_area_width);
 
         display::text_left(
             text_baseline,
@@ -401,7 +460,7 @@ impl Component for Input {
         let text_baseline = self.area.top_left() + Offset::y(style.text_font.text_height())
             - Offset::y(style.text_font.text_baseline());
 
-        let text = self.textbox.content();
+        let text = self.display_text();
 
         shape::Bar::new(self.area).with_bg(theme::BG).render(target);
 
@@ -410,7 +469,7 @@ impl Component for Input {
         // the last character
         let available_area_width = self.area.width() - 1;
         let text_to_display =
-            long_line_content_with_ellipsis(text, "...", style.text_font, available_area_width);
+            long_line_content_with_ellipsis(&text, "...", style.text_font, available_area_width);
 
         shape::Text::new(text_baseline, &text_to_display)
             .with_font(style.text_font)
@@ -440,5 +499,7 @@ impl crate::trace::Trace for PassphraseKeyboard {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("PassphraseKeyboard");
         t.string("passphrase", self.passphrase().into());
+        t.child("confirm", &self.confirm);
+        t.child("erase", &self.back);
     }
 }
@@ -4,11 +4,11 @@ use heapless::String;
 use crate::{
     strutil::TString,
     time::Duration,
-    trezorhal::random,
     ui::{
         component::{
-            base::ComponentExt, text::TextStyle, Child, Component, Event, EventCtx, Label, Maybe,
-            Never, Pad, TimerToken,
+            base::ComponentExt,
+            text::{common::KeyboardTimings, TextStyle},
+            Child, Component, Event, EventCtx, Label, Maybe, Never, Pad, TimerToken,
         },
         display::{self, Font},
         event::TouchEvent,
@@ -17,7 +17,7 @@ use crate::{
             button::{Button, ButtonContent, ButtonMsg, ButtonMsg::Clicked},
             theme,
         },
-        shape,
+        random, shape,
         shape::Renderer,
     },
 };
@@ -35,12 +35,9 @@ const DIGIT_COUNT: usize = 10; // 0..10
 const HEADER_PADDING_SIDE: i16 = 5;
 const HEADER_PADDING_BOTTOM: i16 = 12;
 
-const HEADER_PADDING: Insets = Insets::new(
-    theme::borders().top,
-    HEADER_PADDING_SIDE,
-    HEADER_PADDING_BOTTOM,
-    HEADER_PADDING_SIDE,
-);
+const HEADER_PADDING: Insets = Insets::sides(HEADER_PADDING_SIDE)
+    .add(Insets::top(theme::borders().top))
+    .add(Insets::bottom(HEADER_PADDING_BOTTOM));
 
 pub struct PinKeyboard<'a> {
     allow_cancel: bool,
@@ -74,7 +71,7 @@ impl<'a> PinKeyboard<'a> {
             Offset::new(30, 12),
         )
         .styled(theme::button_reset())
-        .with_long_press(theme::ERASE_HOLD_DURATION)
+        .with_long_press(KeyboardTimings::get().erase_hold)
         .initially_enabled(false);
         let erase_btn = Maybe::hidden(theme::BG, erase_btn).into_child();
 
@@ -108,7 +105,10 @@ impl<'a> PinKeyboard<'a> {
         random::shuffle(&mut digits);
         digits
             .map(|c| Button::with_text(c.into()))
-            .map(|b| b.styled(theme::button_pin()))
+            .map(|b| {
+                b.styled(theme::button_pin())
+                    .with_debounce(KeyboardTimings::get().key_debounce)
+            })
             .map(Child::new)
     }
 
@@ -565,5 +565,8 @@ impl crate::trace::Trace for PinKeyboard<'_> {
         t.string("digits_order", digits_order.as_str().into());
         t.string("pin", self.textbox.inner().pin().into());
         t.bool("display_digits", self.textbox.inner().display_digits);
+        t.child("confirm", &self.confirm_btn);
+        t.child("cancel", &self.cancel_btn);
+        t.child("erase", &self.erase_btn);
     }
 }
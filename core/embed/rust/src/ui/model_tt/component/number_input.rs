@@ -83,12 +83,9 @@ where
         let content_area = self.area.inset(Insets::top(2 * theme::BUTTON_SPACING));
         let (input_area, content_area) = content_area.split_top(button_height);
         let (content_area, button_area) = content_area.split_bottom(button_height);
-        let content_area = content_area.inset(Insets::new(
-            theme::BUTTON_SPACING,
-            0,
-            theme::BUTTON_SPACING,
-            theme::CONTENT_BORDER,
-        ));
+        let content_area = content_area.inset(
+            Insets::symmetric(0, theme::BUTTON_SPACING).add(Insets::left(theme::CONTENT_BORDER)),
+        );
 
         let grid = Grid::new(button_area, 1, 2).with_spacing(theme::KEYBOARD_SPACING);
         self.input.place(input_area);
@@ -219,37 +216,32 @@ impl Component for NumberInput {
     }
 
     fn paint(&mut self) {
-        let mut buf = [0u8; 10];
-        if let Some(text) = strutil::format_i64(self.value as i64, &mut buf) {
-            let digit_font = Font::DEMIBOLD;
-            let y_offset = digit_font.text_height() / 2 + Button::BASELINE_OFFSET;
-            display::rect_fill(self.area, theme::BG);
-            display::text_center(
-                self.area.center() + Offset::y(y_offset),
-                text,
-                digit_font,
-                theme::FG,
-                theme::BG,
-            );
-        }
+        let text = strutil::format_int(self.value as i64);
+        let digit_font = Font::DEMIBOLD;
+        let y_offset = digit_font.text_height() / 2 + Button::BASELINE_OFFSET;
+        display::rect_fill(self.area, theme::BG);
+        display::text_center(
+            self.area.center() + Offset::y(y_offset),
+            &text,
+            digit_font,
+            theme::FG,
+            theme::BG,
+        );
         self.dec.paint();
         self.inc.paint();
     }
 
     fn render<'s>(&'s self, target: &mut impl Renderer<'s>) {
-        let mut buf = [0u8; 10];
-
-        if let Some(text) = strutil::format_i64(self.value as i64, &mut buf) {
-            let digit_font = Font::DEMIBOLD;
-            let y_offset = digit_font.text_height() / 2 + Button::BASELINE_OFFSET;
+        let text = strutil::format_int(self.value as i64);
+        let digit_font = Font::DEMIBOLD;
+        let y_offset = digit_font.text_height() / 2 + Button::BASELINE_OFFSET;
 
-            shape::Bar::new(self.area).with_bg(theme::BG).render(target);
-            shape::Text::new(self.area.center() + Offset::y(y_offset), text)
-                .with_align(Alignment::Center)
-                .with_fg(theme::FG)
-                .with_font(digit_font)
-                .render(target);
-        }
+        shape::Bar::new(self.area).with_bg(theme::BG).render(target);
+        shape::Text::new(self.area.center() + Offset::y(y_offset), &text)
+            .with_align(Alignment::Center)
+            .with_fg(theme::FG)
+            .with_font(digit_font)
+            .render(target);
 
         self.dec.render(target);
         self.inc.render(target);
@@ -145,7 +145,7 @@ impl Component for NumberInputSlider {
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
-        self.touch_area = bounds.outset(Insets::new(40, 20, 40, 20)).clamp(screen());
+        self.touch_area = bounds.outset(Insets::symmetric(20, 40)).clamp(screen());
         bounds
     }
 
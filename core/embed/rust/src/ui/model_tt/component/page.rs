@@ -498,13 +498,16 @@ impl PageLayout {
             content.split_right(Self::SCROLLBAR_SPACE + Self::SCROLLBAR_WIDTH);
         let (_space, scrollbar) = scrollbar.split_left(Self::SCROLLBAR_SPACE);
 
-        let width = if small_left_button {
-            theme::BUTTON_WIDTH
+        let (button_left, button_right) = if small_left_button {
+            let (button_left, button_right) = button_both.split_left(theme::BUTTON_WIDTH);
+            (
+                button_left,
+                button_right.inset(Insets::left(theme::BUTTON_SPACING)),
+            )
         } else {
-            (button_both.width() - theme::BUTTON_SPACING) / 2
+            let mut halves = button_both.split_evenly_horizontal(2, theme::BUTTON_SPACING);
+            (unwrap!(halves.next()), unwrap!(halves.next()))
         };
-        let (button_left, button_right) = button_both.split_left(width);
-        let button_right = button_right.inset(Insets::left(theme::BUTTON_SPACING));
 
         Self {
             content_single_page,
@@ -850,4 +853,15 @@ mod tests {
         swipe_up(&mut page);
         assert_eq!(trace(&page), third_page);
     }
+
+    #[test]
+    fn paragraphs_line_taller_than_page() {
+        let line_height = theme::TEXT_NORMAL.text_font.text_max_height();
+        let mut paragraphs =
+            Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, "One\nTwo\nThree"));
+        paragraphs.place(SCREEN.with_height(line_height / 2));
+
+        // Every line gets a page of its own instead of the pagination getting stuck.
+        assert_eq!(paragraphs.page_count(), 3);
+    }
 }
@@ -32,7 +32,10 @@ use crate::{
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
             result::{CANCELLED, CONFIRMED, INFO},
-            util::{upy_disable_animation, ConfirmBlob, PropsList},
+            util::{
+                upy_debug_overlay, upy_disable_animation, upy_led_set_enabled, upy_seed_random,
+                upy_set_keyboard_timings, ConfirmBlob, PropsList,
+            },
         },
         model_tt::component::check_homescreen_format,
     },
@@ -262,11 +265,9 @@ where
     }
 }
 
-// Clippy/compiler complains about conflicting implementations
-#[cfg(not(feature = "clippy"))]
-impl<T> ComponentMsgObj for (Timeout, T)
+impl<T> ComponentMsgObj for Timeout<T>
 where
-    T: Component<Msg = ()>,
+    T: Component<Msg = Never>,
 {
     fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
         Ok(CANCELLED.as_obj())
@@ -1508,8 +1509,7 @@ extern "C" fn new_show_progress_coinjoin(n_args: usize, args: *const Obj, kwargs
         // provide it.
         let progress = CoinJoinProgress::<Never>::new(title, indeterminate)?;
         let obj = if time_ms > 0 && indeterminate {
-            let timeout = Timeout::new(time_ms);
-            LayoutObj::new((timeout, progress.map(|_msg| None)))?
+            LayoutObj::new(Timeout::wrap(progress, time_ms))?
         } else {
             LayoutObj::new(progress)?
         };
@@ -1641,9 +1641,24 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     def progress_event(self, value: int, description: str) -> T | None:
     ///         """Receive a progress event."""
     ///
+    ///     def progress_info_event(self, phase: str, eta: str) -> T | None:
+    ///         """Receive a new phase label and estimated time remaining of the
+    ///         progress, empty strings hide them."""
+    ///
     ///     def usb_event(self, connected: bool) -> T | None:
     ///         """Receive a USB connect/disconnect event."""
     ///
+    ///     def notification_event(self, text: str | None, level: int) -> T | None:
+    ///         """Receive a new notification of the homescreen, None removes it."""
+    ///
+    ///     def mnemonic_word_event(self, word_index: int, progress: str) -> T | None:
+    ///         """Move the mnemonic keyboard on to word `word_index`, showing the
+    ///         `progress` text."""
+    ///
+    ///     if utils.EMULATOR:
+    ///         def host_key_event(self, key: int) -> T | None:
+    ///             """Receive a key `key` typed on the host keyboard."""
+    ///
     ///     def timer(self, token: int) -> T | None:
     ///         """Callback for the timer set by `attach_timer_fn`.
     ///
@@ -1676,6 +1691,21 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///         def bounds(self) -> None:
     ///             """Paint bounds of individual components on screen."""
     ///
+    ///         def keyboard_text(self) -> str | None:
+    ///             """Return the text typed into the keyboard of the layout, if any."""
+    ///
+    ///         def keyboard_type(self, text: str) -> None:
+    ///             """Type `text` into the keyboard of the layout, as if entered key by key.
+    ///
+    ///             Raises `ValueError` if the layout has no keyboard.
+    ///             """
+    ///
+    ///         def debug_click(self, name: str) -> T | None:
+    ///             """Click the component registered as debug target `name`, e.g.
+    ///             `pin/digit/5` or `confirm`. The targets of the layout are listed
+    ///             in its trace under `debug_targets`.
+    ///             """
+    ///
     ///     def page_count(self) -> int:
     ///         """Return the number of pages in the layout object."""
     ///
@@ -1705,6 +1735,31 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def debug_overlay(show: bool) -> None:
+    ///     """Draw the bounds of the components and their touch areas over the
+    ///     layout, debug builds only. The layout needs a complete repaint."""
+    Qstr::MP_QSTR_debug_overlay => obj_fn_1!(upy_debug_overlay).as_obj(),
+
+    /// def seed_random(seed: int | None) -> None:
+    ///     """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    ///     reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+    Qstr::MP_QSTR_seed_random => obj_fn_1!(upy_seed_random).as_obj(),
+
+    /// def led_set_enabled(enable: bool) -> None:
+    ///     """Enable or disable the RGB LED signals, no-op without the LED."""
+    Qstr::MP_QSTR_led_set_enabled => obj_fn_1!(upy_led_set_enabled).as_obj(),
+
+    /// def set_keyboard_timings(
+    ///     erase_hold_ms: int,
+    ///     multi_tap_timeout_ms: int,
+    ///     last_char_reveal_ms: int,
+    ///     key_debounce_ms: int,
+    /// ) -> None:
+    ///     """Set the erase hold duration, the multi-tap timeout, the reveal time
+    ///     of the last passphrase character and the key debounce window of
+    ///     keyboards."""
+    Qstr::MP_QSTR_set_keyboard_timings => obj_fn_var!(4, 4, upy_set_keyboard_timings).as_obj(),
+
     /// def check_homescreen_format(data: bytes) -> bool:
     ///     """Check homescreen format and dimensions."""
     Qstr::MP_QSTR_check_homescreen_format => obj_fn_1!(upy_check_homescreen_format).as_obj(),
@@ -2176,6 +2231,7 @@ mod tests {
                 "text": ["Testing text layout, with", "\n", "some text, and some", "\n",
                 "more text. And ", "parame", "-", "\n", "ters!"],
                 "fits": true,
+                "area": {"x": 6, "y": 6, "width": 228, "height": 172},
             },
             "controls": {
                 "component": "FixedHeightBar",
@@ -2184,12 +2240,15 @@ mod tests {
                     "first": {
                         "component": "Button",
                         "text": "Left",
+                        "enabled": true,
                     },
                     "second": {
                         "component": "Button",
                         "text": "Right",
+                        "enabled": true,
                     },
                 },
+                "area": {"x": 6, "y": 184, "width": 228, "height": 50},
             },
         });
 
@@ -1,24 +1,19 @@
 pub mod backlight;
 pub mod bootloader;
 
-use crate::{
-    time::Duration,
-    ui::{
-        component::{
-            text::{layout::Chunks, LineBreaking, PageBreaking, TextStyle},
-            FixedHeightBar,
-        },
-        display::{Color, Font, Icon},
-        geometry::{Insets, Offset},
+use crate::ui::{
+    component::{
+        text::{layout::Chunks, LineBreaking, PageBreaking, TextStyle},
+        FixedHeightBar,
     },
+    display::{Color, Font, Icon},
+    geometry::{Insets, Offset},
 };
 
 use super::component::{ButtonStyle, ButtonStyleSheet, LoaderStyle, LoaderStyleSheet, ResultStyle};
 
 use num_traits::FromPrimitive;
 
-pub const ERASE_HOLD_DURATION: Duration = Duration::from_millis(1500);
-
 // Color palette.
 pub const WHITE: Color = Color::rgb(0xFF, 0xFF, 0xFF);
 pub const BLACK: Color = Color::rgb(0, 0, 0);
@@ -671,7 +666,7 @@ pub const fn button_bar<T>(inner: T) -> FixedHeightBar<T> {
 /// |     6    |
 /// +----------+
 pub const fn borders() -> Insets {
-    Insets::new(6, 6, 6, 6)
+    Insets::uniform(6)
 }
 
 pub const fn borders_horizontal_scroll() -> Insets {
@@ -679,7 +674,7 @@ pub const fn borders_horizontal_scroll() -> Insets {
 }
 
 pub const fn borders_notification() -> Insets {
-    Insets::new(48, 6, 6, 6)
+    borders().add(Insets::top(42))
 }
 
 pub const RESULT_ERROR: ResultStyle =
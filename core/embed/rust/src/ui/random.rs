@@ -0,0 +1,89 @@
+//! Randomness of the UI, taken from the hardware RNG. Debug builds can seed a
+//! PRNG instead, so that screenshot tests get the same layouts in every run.
+//! Only for things shown on the screen, never for anything secret.
+//!
+//! Call sites:
+//! - digit order of the PIN keyboard (model_mercury, model_tt)
+//! - initial choice of the PIN, passphrase and wordlist choice pages, and the
+//!   order of the wordlist letters (model_tr)
+
+use crate::trezorhal::random;
+
+/// Returns a random number in the range [min, max].
+pub fn uniform_between(min: u32, max: u32) -> u32 {
+    random::uniform_between_with(&mut random32, min, max)
+}
+
+pub fn shuffle<T>(slice: &mut [T]) {
+    random::shuffle_with(&mut random32, slice)
+}
+
+#[cfg(not(feature = "ui_debug"))]
+fn random32() -> u32 {
+    random::random32()
+}
+
+#[cfg(feature = "ui_debug")]
+fn random32() -> u32 {
+    // SAFETY: single-threaded access
+    match unsafe { &mut *core::ptr::addr_of_mut!(SEEDED) } {
+        Some(prng) => prng.next(),
+        None => random::random32(),
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+static mut SEEDED: Option<Prng> = None;
+
+/// Draw the UI randomness from a PRNG seeded with `seed`, `None` goes back to
+/// the hardware RNG.
+#[cfg(feature = "ui_debug")]
+pub fn set_seed(seed: Option<u32>) {
+    // SAFETY: single-threaded access
+    unsafe {
+        SEEDED = seed.map(|state| Prng { state });
+    }
+}
+
+/// Weyl sequence scrambled by the MurmurHash3 finalizer, good enough for
+/// shuffling keys and cheap enough to not care.
+#[cfg(feature = "ui_debug")]
+struct Prng {
+    state: u32,
+}
+
+#[cfg(feature = "ui_debug")]
+impl Prng {
+    fn next(&mut self) -> u32 {
+        self.state = self.state.wrapping_add(0x9E37_79B9);
+        let mut z = self.state;
+        z = (z ^ (z >> 16)).wrapping_mul(0x85EB_CA6B);
+        z = (z ^ (z >> 13)).wrapping_mul(0xC2B2_AE35);
+        z ^ (z >> 16)
+    }
+}
+
+#[cfg(all(test, feature = "ui_debug"))]
+mod tests {
+    use super::*;
+
+    fn shuffled() -> [u8; 10] {
+        let mut digits = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(&mut digits);
+        digits
+    }
+
+    #[test]
+    fn seeded_is_reproducible() {
+        set_seed(Some(42));
+        let first = shuffled();
+        let pick = uniform_between(0, 100);
+        set_seed(Some(42));
+        assert_eq!(shuffled(), first);
+        assert_eq!(uniform_between(0, 100), pick);
+
+        set_seed(Some(43));
+        assert_ne!(shuffled(), first);
+        set_seed(None);
+    }
+}
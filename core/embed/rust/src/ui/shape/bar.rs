@@ -1,4 +1,7 @@
-use crate::ui::{display::Color, geometry::Rect};
+use crate::ui::{
+    display::Color,
+    geometry::{Axis, Rect},
+};
 
 use super::{Canvas, DrawingCache, Renderer, Shape, ShapeClone};
 
@@ -16,6 +19,12 @@ pub struct Bar {
     thickness: i16,
     /// Corner radius (default 0)
     radius: i16,
+    /// Radius of each corner clockwise from the top-left one, overrides
+    /// `radius` (default None)
+    corner_radii: Option<[i16; 4]>,
+    /// Background gradient colors and direction, overrides `bg_color`
+    /// (default None)
+    gradient: Option<(Color, Color, Axis)>,
     /// Alpha (default 255)
     alpha: u8,
 }
@@ -28,6 +37,8 @@ impl Bar {
             bg_color: None,
             thickness: 1,
             radius: 0,
+            corner_radii: None,
+            gradient: None,
             alpha: 255,
         }
     }
@@ -50,6 +61,35 @@ impl Bar {
         Self { radius, ..self }
     }
 
+    /// Rounds each corner by a different radius, given clockwise from the
+    /// top-left one. Zero leaves the corner sharp.
+    pub fn with_corner_radii(self, radii: [i16; 4]) -> Self {
+        Self {
+            corner_radii: Some(radii),
+            ..self
+        }
+    }
+
+    /// Rounds only the top two corners, e.g. for bottom sheets.
+    pub fn with_top_radius(self, radius: i16) -> Self {
+        self.with_corner_radii([radius, radius, 0, 0])
+    }
+
+    /// Fills the background with a linear gradient going from `start` to
+    /// `end` color either left to right (`Axis::Horizontal`) or top to bottom
+    /// (`Axis::Vertical`).
+    ///
+    /// The colors are interpolated per scanline, but since the canvas is
+    /// RGB565 (or grayscale), neighboring scanlines often end up with the same
+    /// color. These are drawn as a single band, so subtle gradients over large
+    /// areas show visible steps. No dithering is applied.
+    pub fn with_gradient(self, start: Color, end: Color, dir: Axis) -> Self {
+        Self {
+            gradient: Some((start, end, dir)),
+            ..self
+        }
+    }
+
     pub fn with_thickness(self, thickness: i16) -> Self {
         Self { thickness, ..self }
     }
@@ -61,6 +101,75 @@ impl Bar {
     pub fn render<'s>(self, renderer: &mut impl Renderer<'s>) {
         renderer.render_shape(self);
     }
+
+    /// Fills the background area `r` with a single color, respecting
+    /// the corner radii.
+    fn fill_bg(&self, canvas: &mut dyn Canvas, r: Rect, color: Color) {
+        if let Some(radii) = self.corner_radii {
+            canvas.fill_round_rect_corners(r, radii, color, self.alpha);
+        } else if self.radius == 0 {
+            canvas.fill_rect(r, color, self.alpha);
+        } else {
+            canvas.fill_round_rect(r, self.radius, color, self.alpha);
+        }
+    }
+
+    /// Fills the background area `r` with the gradient, band by band of
+    /// scanlines of the same color. Each band is clipped, so the rounded
+    /// corners stay intact and the gradient is continuous however the bar is
+    /// clipped.
+    fn fill_gradient(
+        &self,
+        canvas: &mut dyn Canvas,
+        r: Rect,
+        (start, end, dir): (Color, Color, Axis),
+    ) {
+        let (from, to) = match dir {
+            Axis::Horizontal => (r.x0, r.x1),
+            Axis::Vertical => (r.y0, r.y1),
+        };
+        let steps = (to - from - 1).max(1) as i32;
+        let color_at = |i: i16| -> Color {
+            let alpha = ((i - from) as i32 * 255 / steps) as u8;
+            start.blend(end, alpha)
+        };
+        let band = |b0: i16, b1: i16| -> Rect {
+            match dir {
+                Axis::Horizontal => Rect {
+                    x0: b0,
+                    x1: b1,
+                    ..r
+                },
+                Axis::Vertical => Rect {
+                    y0: b0,
+                    y1: b1,
+                    ..r
+                },
+            }
+        };
+
+        // Only draw the bands inside of the clipping rectangle.
+        let vp = canvas.viewport();
+        let clip = vp.clip.translate(-vp.origin);
+        let (from_clip, to_clip) = match dir {
+            Axis::Horizontal => (clip.x0, clip.x1),
+            Axis::Vertical => (clip.y0, clip.y1),
+        };
+        let to = to.min(to_clip);
+
+        let mut b0 = from.max(from_clip);
+        while b0 < to {
+            let color = color_at(b0);
+            let mut b1 = b0 + 1;
+            while b1 < to && color_at(b1) == color {
+                b1 += 1;
+            }
+            canvas.set_clip(band(b0, b1));
+            self.fill_bg(canvas, r, color);
+            canvas.set_viewport(vp);
+            b0 = b1;
+        }
+    }
 }
 
 impl Shape<'_> for Bar {
@@ -82,37 +191,37 @@ impl Shape<'_> for Bar {
             None => 0,
         };
 
-        if self.radius == 0 {
-            if let Some(fg_color) = self.fg_color {
-                // outline
-                if th > 0 {
-                    let r = self.area;
-                    canvas.fill_rect(Rect { y1: r.y0 + th, ..r }, fg_color, self.alpha);
-                    canvas.fill_rect(Rect { x1: r.x0 + th, ..r }, fg_color, self.alpha);
-                    canvas.fill_rect(Rect { x0: r.x1 - th, ..r }, fg_color, self.alpha);
-                    canvas.fill_rect(Rect { y0: r.y1 - th, ..r }, fg_color, self.alpha);
-                }
-            }
-            if let Some(bg_color) = self.bg_color {
-                // background
-                let bg_r = self.area.shrink(th);
-                canvas.fill_rect(bg_r, bg_color, self.alpha);
-            }
-        } else {
-            if let Some(fg_color) = self.fg_color {
-                if th > 0 {
-                    if self.bg_color.is_some() {
-                        canvas.fill_round_rect(self.area, self.radius, fg_color, self.alpha);
-                    } else {
-                        #[cfg(not(feature = "ui_antialiasing"))]
-                        canvas.draw_round_rect(self.area, self.radius, fg_color);
-                    }
+        let has_bg = self.bg_color.is_some() || self.gradient.is_some();
+
+        // outline
+        if let Some(fg_color) = self.fg_color.filter(|_| th > 0) {
+            if let Some(radii) = self.corner_radii {
+                if has_bg {
+                    canvas.fill_round_rect_corners(self.area, radii, fg_color, self.alpha);
+                } else {
+                    #[cfg(not(feature = "ui_antialiasing"))]
+                    canvas.draw_round_rect_corners(self.area, radii, fg_color);
                 }
+            } else if self.radius == 0 {
+                let r = self.area;
+                canvas.fill_rect(Rect { y1: r.y0 + th, ..r }, fg_color, self.alpha);
+                canvas.fill_rect(Rect { x1: r.x0 + th, ..r }, fg_color, self.alpha);
+                canvas.fill_rect(Rect { x0: r.x1 - th, ..r }, fg_color, self.alpha);
+                canvas.fill_rect(Rect { y0: r.y1 - th, ..r }, fg_color, self.alpha);
+            } else if has_bg {
+                canvas.fill_round_rect(self.area, self.radius, fg_color, self.alpha);
+            } else {
+                #[cfg(not(feature = "ui_antialiasing"))]
+                canvas.draw_round_rect(self.area, self.radius, fg_color);
             }
-            if let Some(bg_color) = self.bg_color {
-                let bg_r = self.area.shrink(th);
-                canvas.fill_round_rect(bg_r, self.radius, bg_color, self.alpha);
-            }
+        }
+
+        // background
+        let bg_r = self.area.shrink(th);
+        if let Some(gradient) = self.gradient {
+            self.fill_gradient(canvas, bg_r, gradient);
+        } else if let Some(bg_color) = self.bg_color {
+            self.fill_bg(canvas, bg_r, bg_color);
         }
     }
 }
@@ -372,6 +372,63 @@ pub trait Canvas: BasicCanvas {
         }
     }
 
+    /// Draws an outline of a rectangle with each corner rounded by a
+    /// different radius, given clockwise from the top-left one.
+    ///
+    /// Corners look exactly like the ones drawn by `draw_round_rect()`.
+    fn draw_round_rect_corners(&mut self, r: Rect, radii: [i16; 4], color: Color) {
+        let parts = RoundRectParts::new(r, radii);
+        for corner in parts.corners {
+            if corner.radius > 0 {
+                let vp = self.set_clip(corner.clip);
+                self.draw_round_rect(corner.square, corner.radius, color);
+                self.set_viewport(vp);
+            }
+        }
+        let [tl, tr, br, bl] = parts.sizes;
+        self.fill_rect(
+            Rect::new(Point::new(r.x0 + tl, r.y0), Point::new(r.x1 - tr, r.y0 + 1)),
+            color,
+            255,
+        );
+        self.fill_rect(
+            Rect::new(Point::new(r.x1 - 1, r.y0 + tr), Point::new(r.x1, r.y1 - br)),
+            color,
+            255,
+        );
+        self.fill_rect(
+            Rect::new(Point::new(r.x0 + bl, r.y1 - 1), Point::new(r.x1 - br, r.y1)),
+            color,
+            255,
+        );
+        self.fill_rect(
+            Rect::new(Point::new(r.x0, r.y0 + tl), Point::new(r.x0 + 1, r.y1 - bl)),
+            color,
+            255,
+        );
+    }
+
+    /// Draws filled rectangle with each corner rounded by a different radius,
+    /// given clockwise from the top-left one.
+    ///
+    /// Corners look exactly like the ones drawn by `fill_round_rect()`, with
+    /// or without antialiasing, so that both can be used side by side.
+    fn fill_round_rect_corners(&mut self, r: Rect, radii: [i16; 4], color: Color, alpha: u8) {
+        let parts = RoundRectParts::new(r, radii);
+        for corner in parts.corners {
+            if corner.radius > 0 {
+                let vp = self.set_clip(corner.clip);
+                self.fill_round_rect(corner.square, corner.radius, color, alpha);
+                self.set_viewport(vp);
+            }
+        }
+        for rect in parts.rest {
+            if !rect.is_empty() {
+                self.fill_rect(rect, color, alpha);
+            }
+        }
+    }
+
     // Draws circle with the specified center and the radius.
     #[cfg(not(feature = "ui_antialiasing"))]
     fn draw_circle(&mut self, center: Point, radius: i16, color: Color, alpha: u8) {
@@ -593,7 +650,7 @@ pub trait Canvas: BasicCanvas {
         }
     }
 
-    /// Fills circle sector with a specified color.
+    /// Fills circle sector with a specified color and transparency.
     fn fill_sector(
         &mut self,
         center: Point,
@@ -601,6 +658,7 @@ pub trait Canvas: BasicCanvas {
         mut start: f32,
         mut end: f32,
         color: Color,
+        alpha: u8,
     ) {
         if radius <= 0 {
             return;
@@ -609,12 +667,11 @@ pub trait Canvas: BasicCanvas {
         start = (360.0 + start % 360.0) % 360.0;
         end = (360.0 + end % 360.0) % 360.0;
 
-        let alpha = 255;
         let alpha_mul = |a: u8| -> u8 { ((a as u16 * alpha as u16) / 255) as u8 };
 
         if start != end {
             // The algorithm fills everything except the middle point ;-)
-            self.draw_pixel(center, color);
+            self.blend_pixel(center, color, alpha);
         }
 
         const PI4: f32 = 45.0;
@@ -722,6 +779,120 @@ pub trait Canvas: BasicCanvas {
     }
 }
 
+/// Rounded corner of a rectangle with independent corner radii.
+#[derive(Clone, Copy)]
+struct RoundCorner {
+    radius: i16,
+    /// Area of the rectangle affected by the rounding.
+    clip: Rect,
+    /// Square sharing the corner with the rectangle, which drawn with the
+    /// uniform `radius` and clipped to `clip` gives the corner.
+    square: Rect,
+}
+
+/// Decomposition of a rectangle with independent corner radii into the corners
+/// and non-overlapping rectangles covering the rest of it.
+///
+/// Used internally by `Canvas::fill_round_rect_corners()` and
+/// `Canvas::draw_round_rect_corners()`.
+struct RoundRectParts {
+    /// Clockwise from the top-left corner.
+    corners: [RoundCorner; 4],
+    /// Sizes of the corner clips.
+    sizes: [i16; 4],
+    rest: [Rect; 7],
+}
+
+impl RoundRectParts {
+    fn new(r: Rect, radii: [i16; 4]) -> Self {
+        // Corners must not overlap, radii longer than half of the shorter side
+        // are clamped.
+        let max_radius = (r.width().min(r.height()) / 2 - 1).max(0);
+        let radii = radii.map(|radius| radius.clamp(0, max_radius));
+        // The rounding of `fill_round_rect()` spans `radius + 1` pixels.
+        let sizes = radii.map(|radius| if radius > 0 { radius + 1 } else { 0 });
+        let [tl, tr, br, bl] = sizes;
+
+        let corner =
+            |radius: i16, size: i16, at: fn(Point, Offset) -> Rect, pt: Point| RoundCorner {
+                radius,
+                clip: at(pt, Offset::uniform(size)),
+                square: at(pt, Offset::uniform(2 * size)),
+            };
+        let corners = [
+            corner(radii[0], tl, Rect::from_top_left_and_size, r.top_left()),
+            corner(radii[1], tr, Rect::from_top_right_and_size, r.top_right()),
+            corner(
+                radii[2],
+                br,
+                Rect::from_bottom_right_and_size,
+                r.bottom_right(),
+            ),
+            corner(
+                radii[3],
+                bl,
+                Rect::from_bottom_left_and_size,
+                r.bottom_left(),
+            ),
+        ];
+
+        let top = tl.max(tr);
+        let bottom = bl.max(br);
+        let rest = [
+            // Between the top corners, and next to the smaller of them.
+            Rect {
+                x0: r.x0 + tl,
+                x1: r.x1 - tr,
+                y1: r.y0 + top,
+                ..r
+            },
+            Rect {
+                x1: r.x0 + tl,
+                y0: r.y0 + tl,
+                y1: r.y0 + top,
+                ..r
+            },
+            Rect {
+                x0: r.x1 - tr,
+                y0: r.y0 + tr,
+                y1: r.y0 + top,
+                ..r
+            },
+            // Full width between the top and bottom corners.
+            Rect {
+                y0: r.y0 + top,
+                y1: r.y1 - bottom,
+                ..r
+            },
+            // Between the bottom corners, and next to the smaller of them.
+            Rect {
+                x0: r.x0 + bl,
+                x1: r.x1 - br,
+                y0: r.y1 - bottom,
+                ..r
+            },
+            Rect {
+                x1: r.x0 + bl,
+                y0: r.y1 - bottom,
+                y1: r.y1 - bl,
+                ..r
+            },
+            Rect {
+                x0: r.x1 - br,
+                y0: r.y1 - bottom,
+                y1: r.y1 - br,
+                ..r
+            },
+        ];
+
+        Self {
+            corners,
+            sizes,
+            rest,
+        }
+    }
+}
+
 /// Calculates endpoints of a single octant of a circle
 ///
 /// Used internally by `Canvas::fill_sector()`.
@@ -124,11 +124,11 @@ impl Shape<'_> for Circle {
 
             if let Some(color) = self.fg_color {
                 if th > 0 {
-                    canvas.fill_sector(self.center, self.radius, start, end, color);
+                    canvas.fill_sector(self.center, self.radius, start, end, color, self.alpha);
                 }
             }
             if let Some(color) = self.bg_color {
-                canvas.fill_sector(self.center, self.radius - th, start, end, color);
+                canvas.fill_sector(self.center, self.radius - th, start, end, color, self.alpha);
             }
         }
     }
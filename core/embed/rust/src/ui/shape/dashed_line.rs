@@ -0,0 +1,144 @@
+use crate::ui::{
+    display::Color,
+    geometry::{Offset, Point, Rect},
+};
+
+use super::{Canvas, DrawingCache, Renderer, Shape, ShapeClone};
+
+use without_alloc::alloc::LocalAllocLeakExt;
+
+/// A shape for rendering horizontal or vertical dashed and dotted lines.
+///
+/// Dashes are aligned to the start point, so that lines continuing one another
+/// (with the length of the first one being a multiple of `dash + gap`) make up
+/// a single seamless line.
+pub struct DashedLine {
+    /// Top-left corner of the first dash
+    start: Point,
+    /// Length of the whole line
+    length: i16,
+    /// Vertical line if set, horizontal otherwise
+    vertical: bool,
+    /// Length of each dash (default 4)
+    dash: i16,
+    /// Space between dashes (default 4)
+    gap: i16,
+    /// Thickness (default 1)
+    thickness: i16,
+    /// Color (default white)
+    color: Color,
+    /// Alpha (default 255)
+    alpha: u8,
+}
+
+impl DashedLine {
+    /// Horizontal line of `length` going right from `start`.
+    pub fn horizontal(start: Point, length: i16) -> Self {
+        Self::new(start, length, false)
+    }
+
+    /// Vertical line of `length` going down from `start`.
+    pub fn vertical(start: Point, length: i16) -> Self {
+        Self::new(start, length, true)
+    }
+
+    fn new(start: Point, length: i16, vertical: bool) -> Self {
+        Self {
+            start,
+            length,
+            vertical,
+            dash: 4,
+            gap: 4,
+            thickness: 1,
+            color: Color::white(),
+            alpha: 255,
+        }
+    }
+
+    pub fn with_dash(self, dash: i16, gap: i16) -> Self {
+        Self { dash, gap, ..self }
+    }
+
+    /// Dotted line of square dots as big as the thickness (set it first), with
+    /// `gap` between them.
+    pub fn with_dots(self, gap: i16) -> Self {
+        Self {
+            dash: self.thickness,
+            gap,
+            ..self
+        }
+    }
+
+    pub fn with_thickness(self, thickness: i16) -> Self {
+        Self { thickness, ..self }
+    }
+
+    pub fn with_fg(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+
+    pub fn with_alpha(self, alpha: u8) -> Self {
+        Self { alpha, ..self }
+    }
+
+    pub fn render<'s>(self, renderer: &mut impl Renderer<'s>) {
+        renderer.render_shape(self);
+    }
+
+    /// Rectangle of a dash between `from` and `to` along the line.
+    fn segment(&self, from: i16, to: i16) -> Rect {
+        if self.vertical {
+            Rect::new(
+                self.start + Offset::y(from),
+                self.start + Offset::new(self.thickness, to),
+            )
+        } else {
+            Rect::new(
+                self.start + Offset::x(from),
+                self.start + Offset::new(to, self.thickness),
+            )
+        }
+    }
+}
+
+impl Shape<'_> for DashedLine {
+    fn bounds(&self) -> Rect {
+        self.segment(0, self.length)
+    }
+
+    fn cleanup(&mut self, _cache: &DrawingCache) {}
+
+    fn draw(&mut self, canvas: &mut dyn Canvas, _cache: &DrawingCache) {
+        let period = self.dash + self.gap;
+        if self.dash <= 0 || period <= 0 {
+            return;
+        }
+
+        // Only iterate over the dashes inside of the clipping rectangle.
+        let vp = canvas.viewport();
+        let clip = vp.clip.translate(-vp.origin);
+        let (clip_from, clip_to) = if self.vertical {
+            (clip.y0 - self.start.y, clip.y1 - self.start.y)
+        } else {
+            (clip.x0 - self.start.x, clip.x1 - self.start.x)
+        };
+        let end = self.length.min(clip_to);
+
+        let mut from = clip_from.max(0) / period * period;
+        while from < end {
+            let to = (from + self.dash).min(self.length);
+            canvas.fill_rect(self.segment(from, to), self.color, self.alpha);
+            from += period;
+        }
+    }
+}
+
+impl<'s> ShapeClone<'s> for DashedLine {
+    fn clone_at_bump<T>(self, bump: &'s T) -> Option<&'s mut dyn Shape<'s>>
+    where
+        T: LocalAllocLeakExt<'s>,
+    {
+        let clone = bump.alloc_t()?;
+        Some(clone.uninit.init(DashedLine { ..self }))
+    }
+}
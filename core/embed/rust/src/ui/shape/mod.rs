@@ -7,9 +7,11 @@ mod cache;
 mod canvas;
 mod circle;
 mod corner_highlight;
+mod dashed_line;
 mod display;
 #[cfg(feature = "ui_jpeg_decoder")]
 mod jpeg;
+mod overlay;
 mod qrcode;
 mod rawimage;
 mod render;
@@ -28,11 +30,15 @@ pub use canvas::{
 };
 pub use circle::Circle;
 pub use corner_highlight::CornerHighlight;
+pub use dashed_line::DashedLine;
 pub use display::{render_on_canvas, render_on_display};
 #[cfg(feature = "ui_jpeg_decoder")]
 pub use jpeg::JpegImage;
+pub use overlay::Overlay;
 pub use qrcode::QrImage;
 pub use rawimage::RawImage;
+#[cfg(test)]
+pub use render::BoundsRenderer;
 pub use render::{DirectRenderer, ProgressiveRenderer, Renderer};
 pub use text::Text;
 pub use toif::ToifImage;
@@ -0,0 +1,84 @@
+use crate::ui::{display::Color, geometry::Rect};
+
+use super::{Canvas, DrawingCache, Renderer, Shape, ShapeClone};
+
+use without_alloc::alloc::LocalAllocLeakExt;
+
+/// A shape darkening already rendered content, e.g. below modal layers.
+///
+/// Multiplies the color of each pixel inside the area (except the optional
+/// hole) by `brightness / 255`.
+pub struct Overlay {
+    /// Dimmed area
+    area: Rect,
+    /// Area left intact, e.g. the modal itself (default None)
+    hole: Option<Rect>,
+    /// Remaining brightness of the content (default 102, i.e. 40%)
+    brightness: u8,
+}
+
+impl Overlay {
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            hole: None,
+            brightness: 102,
+        }
+    }
+
+    pub fn with_brightness(self, brightness: u8) -> Self {
+        Self { brightness, ..self }
+    }
+
+    pub fn with_hole(self, hole: Rect) -> Self {
+        Self {
+            hole: Some(hole),
+            ..self
+        }
+    }
+
+    pub fn render<'s>(self, renderer: &mut impl Renderer<'s>) {
+        renderer.render_shape(self);
+    }
+}
+
+impl Shape<'_> for Overlay {
+    fn bounds(&self) -> Rect {
+        self.area
+    }
+
+    fn cleanup(&mut self, _cache: &DrawingCache) {}
+
+    fn draw(&mut self, canvas: &mut dyn Canvas, _cache: &DrawingCache) {
+        // Blending black with the content scales its color down.
+        let color = Color::black();
+        let alpha = 255 - self.brightness;
+        let r = self.area;
+
+        match self.hole.map(|hole| hole.clamp(r)) {
+            Some(hole) if !hole.is_empty() => {
+                canvas.fill_rect(Rect { y1: hole.y0, ..r }, color, alpha);
+                canvas.fill_rect(Rect { y0: hole.y1, ..r }, color, alpha);
+                // Left and right of the hole
+                let m = Rect {
+                    y0: hole.y0,
+                    y1: hole.y1,
+                    ..r
+                };
+                canvas.fill_rect(Rect { x1: hole.x0, ..m }, color, alpha);
+                canvas.fill_rect(Rect { x0: hole.x1, ..m }, color, alpha);
+            }
+            _ => canvas.fill_rect(r, color, alpha),
+        }
+    }
+}
+
+impl<'s> ShapeClone<'s> for Overlay {
+    fn clone_at_bump<T>(self, bump: &'s T) -> Option<&'s mut dyn Shape<'s>>
+    where
+        T: LocalAllocLeakExt<'s>,
+    {
+        let clone = bump.alloc_t()?;
+        Some(clone.uninit.init(Overlay { ..self }))
+    }
+}
@@ -19,6 +19,13 @@ pub trait Renderer<'a> {
 
     fn set_viewport(&mut self, viewport: Viewport);
 
+    /// Checks whether anything drawn into `r` (in relative coordinates)
+    /// could end up inside the current viewport. When only a part of the
+    /// screen is being redrawn, the viewport is clipped to that part.
+    fn is_visible(&self, r: Rect) -> bool {
+        self.viewport().contains(r)
+    }
+
     fn set_window(&mut self, window: Rect) -> Viewport {
         let viewport = self.viewport();
         self.set_viewport(viewport.relative_window(window));
@@ -299,3 +306,88 @@ where
         self.renderer.render_shape(shape);
     }
 }
+
+// ==========================================================================
+// struct BoundsRenderer
+// ==========================================================================
+
+/// Renderer for tests, collecting the bounds of the rendered shapes instead
+/// of drawing them.
+#[cfg(test)]
+pub struct BoundsRenderer {
+    viewport: Viewport,
+    /// Bounds of the rendered shapes, in the order they were rendered.
+    pub shapes: std::vec::Vec<Rect>,
+}
+
+#[cfg(test)]
+impl BoundsRenderer {
+    pub fn new(viewport: Viewport) -> Self {
+        Self {
+            viewport,
+            shapes: std::vec::Vec::new(),
+        }
+    }
+
+    /// Area covered by all the rendered shapes, `None` if there were none.
+    pub fn covered(&self) -> Option<Rect> {
+        self.shapes.iter().copied().reduce(|a, b| a.union(b))
+    }
+}
+
+#[cfg(test)]
+impl<'a> Renderer<'a> for BoundsRenderer {
+    fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+    }
+
+    fn render_shape<S>(&mut self, shape: S)
+    where
+        S: Shape<'a> + ShapeClone<'a>,
+    {
+        self.shapes.push(shape.bounds());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    fn rect(x0: i16, y0: i16, x1: i16, y1: i16) -> Rect {
+        Rect::new(Point::new(x0, y0), Point::new(x1, y1))
+    }
+
+    #[test]
+    fn in_clip_nested() {
+        let screen = rect(0, 0, 240, 240);
+        let mut renderer = BoundsRenderer::new(Viewport::new(screen));
+
+        let called = Cell::new(0);
+        renderer.in_clip(rect(10, 10, 100, 50), &|target| {
+            called.set(called.get() + 1);
+            assert!(target.viewport().clip == rect(10, 10, 100, 50));
+            target.in_clip(rect(50, 0, 200, 30), &|target| {
+                called.set(called.get() + 1);
+                assert!(target.viewport().clip == rect(50, 10, 100, 30));
+            });
+            assert!(target.viewport().clip == rect(10, 10, 100, 50));
+        });
+        assert_eq!(called.get(), 2);
+        assert!(renderer.viewport().clip == screen);
+    }
+
+    #[test]
+    fn in_clip_relative_to_origin() {
+        let mut renderer = BoundsRenderer::new(
+            Viewport::new(rect(0, 0, 240, 240)).with_origin(Offset::new(20, 20)),
+        );
+        renderer.in_clip(rect(0, 0, 10, 10), &|target| {
+            assert!(target.viewport().clip == rect(20, 20, 30, 30));
+        });
+    }
+}
@@ -7,6 +7,9 @@ use super::{BitmapView, Canvas, DrawingCache, Renderer, Shape, ShapeClone};
 
 use without_alloc::alloc::LocalAllocLeakExt;
 
+/// Distance of the underline below the baseline.
+const UNDERLINE_OFFSET: i16 = 1;
+
 /// A shape for text strings rendering.
 pub struct Text<'a> {
     // Text position
@@ -21,6 +24,14 @@ pub struct Text<'a> {
     font: Font,
     // Horizontal alignment
     align: Alignment,
+    // Extra space between characters
+    letter_spacing: i16,
+    // Line below the text
+    underline: bool,
+    // Line through the middle of the text
+    strikethrough: bool,
+    // Width of the text measured beforehand (default None)
+    width: Option<i16>,
     // Final bounds calculated when rendered
     bounds: Rect,
 }
@@ -36,6 +47,10 @@ impl<'a> Text<'a> {
             alpha: 255,
             font: Font::NORMAL,
             align: Alignment::Start,
+            letter_spacing: 0,
+            underline: false,
+            strikethrough: false,
+            width: None,
             bounds: Rect::zero(),
         }
     }
@@ -56,6 +71,51 @@ impl<'a> Text<'a> {
         Self { alpha, ..self }
     }
 
+    pub fn with_letter_spacing(self, letter_spacing: i16) -> Self {
+        Self {
+            letter_spacing,
+            ..self
+        }
+    }
+
+    pub fn with_underline(self, underline: bool) -> Self {
+        Self { underline, ..self }
+    }
+
+    pub fn with_strikethrough(self, strikethrough: bool) -> Self {
+        Self {
+            strikethrough,
+            ..self
+        }
+    }
+
+    /// Width of the text in its font, without `letter_spacing`, if the caller
+    /// already knows it. Saves measuring the text again when rendering.
+    pub fn with_measured_width(self, width: i16) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// Total space added between the characters of `text` by
+    /// `letter_spacing`. There is none after the last one.
+    pub fn letter_spacing_width(text: &str, letter_spacing: i16) -> i16 {
+        let gaps = text.chars().count().saturating_sub(1) as i16;
+        gaps * letter_spacing
+    }
+
+    fn spacing_width(&self) -> i16 {
+        Self::letter_spacing_width(self.text, self.letter_spacing)
+    }
+
+    fn text_width(&self) -> i16 {
+        let width = self
+            .width
+            .unwrap_or_else(|| self.font.text_width(self.text));
+        width + self.spacing_width()
+    }
+
     pub fn render<'r>(mut self, renderer: &mut impl Renderer<'r>) {
         self.bounds = self.calc_bounds();
         renderer.render_shape(self);
@@ -65,23 +125,33 @@ impl<'a> Text<'a> {
         match self.align {
             Alignment::Start => self.pos,
             Alignment::Center => Point::new(
-                self.font.horz_center(self.pos.x, self.pos.x, self.text),
+                self.font.horz_center(self.pos.x, self.pos.x, self.text) - self.spacing_width() / 2,
                 self.pos.y,
             ),
-            Alignment::End => Point::new(self.pos.x - self.font.text_width(self.text), self.pos.y),
+            Alignment::End => Point::new(self.pos.x - self.text_width(), self.pos.y),
         }
     }
 
     fn calc_bounds(&self) -> Rect {
         let pos = self.aligned_pos();
-        let (ascent, descent) = self.font.visible_text_height_ex(self.text);
+        let (ascent, mut descent) = self.font.visible_text_height_ex(self.text);
+        if self.underline {
+            descent = descent.max(UNDERLINE_OFFSET + 1);
+        }
         Rect {
             x0: pos.x,
             y0: pos.y - ascent,
-            x1: pos.x + self.font.text_width(self.text),
+            x1: pos.x + self.text_width(),
             y1: pos.y + descent,
         }
     }
+
+    /// Horizontal 1px line across the whole text, `dy` below the baseline.
+    fn line(&self, dy: i16) -> Rect {
+        let r = self.bounds;
+        let y = self.pos.y + dy;
+        Rect::new(Point::new(r.x0, y), Point::new(r.x1, y + 1))
+    }
 }
 
 impl<'a> Shape<'_> for Text<'a> {
@@ -102,18 +172,25 @@ impl<'a> Shape<'_> for Text<'a> {
                 break;
             }
 
-            let glyph = self.font.get_glyph(ch);
-            let glyph_bitmap = glyph.bitmap();
-            let glyph_view = BitmapView::new(&glyph_bitmap)
-                .with_alpha(self.alpha)
-                .with_fg(self.color)
-                .with_offset(Offset::new(
-                    -glyph.bearing_x,
-                    -(max_ascent - glyph.bearing_y),
-                ));
-
-            canvas.blend_bitmap(r, glyph_view);
-            r.x0 += glyph.adv;
+            let adv = self.font.with_glyph_bitmap(ch, |bearing, adv, bitmap| {
+                let glyph_view = BitmapView::new(bitmap)
+                    .with_alpha(self.alpha)
+                    .with_fg(self.color)
+                    .with_offset(Offset::new(-bearing.x, -(max_ascent - bearing.y)));
+
+                canvas.blend_bitmap(r, glyph_view);
+                adv
+            });
+            r.x0 += adv + self.letter_spacing;
+        }
+
+        if self.underline {
+            canvas.fill_rect(self.line(UNDERLINE_OFFSET), self.color, self.alpha);
+        }
+        if self.strikethrough {
+            // Through the middle of lowercase letters.
+            let dy = -self.font.visible_text_height("x") / 2;
+            canvas.fill_rect(self.line(dy), self.color, self.alpha);
         }
     }
 }
@@ -68,6 +68,11 @@ impl<'a> ToifImage<'a> {
         }
     }
 
+    /// Renders the image semi-transparent, blended with the existing content.
+    ///
+    /// With a background color set, the background is blended first and the
+    /// image over it. Full-color TOIFs can't be blended by the canvas, so they
+    /// are drawn only if `alpha` is at least 128.
     pub fn with_alpha(self, alpha: u8) -> Self {
         Self { alpha, ..self }
     }
@@ -118,7 +123,14 @@ impl<'a> ToifImage<'a> {
                 .with_alpha(self.alpha);
 
             match self.bg_color {
-                Some(bg_color) => canvas.draw_bitmap(r, slice_view.with_bg(bg_color)),
+                Some(bg_color) if self.alpha == 255 => {
+                    canvas.draw_bitmap(r, slice_view.with_bg(bg_color))
+                }
+                Some(bg_color) => {
+                    // Copying ignores alpha, blend the background separately.
+                    canvas.fill_rect(r, bg_color, self.alpha);
+                    canvas.blend_bitmap(r, slice_view);
+                }
                 None => canvas.blend_bitmap(r, slice_view),
             }
 
@@ -187,10 +199,14 @@ impl<'a> Shape<'a> for ToifImage<'a> {
     }
 
     fn draw(&mut self, canvas: &mut dyn Canvas, cache: &DrawingCache<'a>) {
+        if self.alpha == 0 {
+            return;
+        }
         let info = unwrap!(ToifInfo::parse(self.toif), "Invalid image");
         if info.is_grayscale() {
             self.draw_grayscale(canvas, cache);
-        } else {
+        } else if self.alpha >= 128 {
+            // There is no blending of RGB565 bitmaps, fall back to on/off.
             self.draw_rgb(canvas, cache);
         }
     }
@@ -208,7 +208,7 @@ pub fn render_slide<'s, F0, F1, R>(
 
 #[cfg(test)]
 mod tests {
-    use crate::strutil;
+    use crate::strutil::{self, ShortString, TString};
 
     #[test]
     fn u32_to_str_valid() {
@@ -222,6 +222,140 @@ mod tests {
         }
     }
 
+    #[test]
+    fn format_int_and_push_int() {
+        assert_eq!(strutil::format_int(0).as_str(), "0");
+        assert_eq!(strutil::format_int(-37).as_str(), "-37");
+        assert_eq!(
+            strutil::format_int(i64::MAX).as_str(),
+            "9223372036854775807"
+        );
+
+        let mut s = heapless::String::<6>::new();
+        assert_eq!(strutil::push_int(&mut s, 37), Ok(()));
+        s.push('/').unwrap();
+        assert_eq!(strutil::push_int(&mut s, 500), Err(()));
+        assert_eq!(strutil::push_int(&mut s, 50), Ok(()));
+        assert_eq!(s.as_str(), "37/50");
+    }
+
+    #[test]
+    fn format_template() {
+        let format = |template: &str, args: &[&str]| {
+            let mut s = ShortString::new();
+            unwrap!(strutil::format_template(template, args, &mut s));
+            s
+        };
+        assert_eq!(format("{0} of {1}", &["3", "20"]).as_str(), "3 of 20");
+        assert_eq!(format("{1}, {0}, {1}", &["a", "b"]).as_str(), "b, a, b");
+        assert_eq!(format("", &[]).as_str(), "");
+
+        // Placeholders without an argument are kept.
+        assert_eq!(format("{0} of {1}", &["3"]).as_str(), "3 of {1}");
+        assert_eq!(
+            format("{99999999999999999999}", &["a"]).as_str(),
+            "{99999999999999999999}"
+        );
+
+        // So are non-numeric ones and unmatched braces.
+        for template in ["{}", "{name}", "{+0}", "{ 0}", "{-1}", "{0", "0}"] {
+            assert_eq!(format(template, &["a"]).as_str(), template);
+        }
+        assert_eq!(format("{{0}}", &["a"]).as_str(), "{a}");
+        assert_eq!(format("{ {0}", &["a"]).as_str(), "{ a");
+
+        // Exactly at capacity.
+        let mut s = heapless::String::<7>::new();
+        assert_eq!(
+            strutil::format_template("{0} of {1}", &["3", "20"], &mut s),
+            Ok(())
+        );
+        assert_eq!(s.as_str(), "3 of 20");
+
+        // Cut where the result stopped fitting.
+        let mut s = heapless::String::<6>::new();
+        assert_eq!(
+            strutil::format_template("{0} of {1}", &["3", "20"], &mut s),
+            Err(())
+        );
+        assert_eq!(s.as_str(), "3 of ");
+        let mut s = heapless::String::<2>::new();
+        assert_eq!(strutil::format_template("{0}", &["abc"], &mut s), Err(()));
+        assert_eq!(s.as_str(), "");
+    }
+
+    #[test]
+    fn tstring_concat_and_join() {
+        let mut s = ShortString::new();
+        TString::concat_into(&["PIN".into(), " ".into(), "3".into()], &mut s);
+        assert_eq!(s.as_str(), "PIN 3");
+
+        let mut s = ShortString::new();
+        TString::join_into(&["37".into(), "50".into()], "/", &mut s);
+        assert_eq!(s.as_str(), "37/50");
+
+        // Truncated at a char boundary when the capacity is exceeded.
+        let mut s = ShortString::new();
+        let long = "abcdefghij".repeat(4);
+        TString::join_into(&[long.as_str().into(), "čřž".into()], "-----", &mut s);
+        assert_eq!(s.len(), 49);
+        assert!(s.ends_with("-----čř"));
+    }
+
+    #[test]
+    fn hexlify_into_and_preview() {
+        let data = [0xab, 0x12, 0xcd, 0x34, 0xef, 0x90];
+
+        let mut s = ShortString::new();
+        assert!(strutil::hexlify_into(&[], &mut s, false));
+        assert!(strutil::hex_preview_into(&[], 2, &mut s, false));
+        assert_eq!(s.as_str(), "");
+
+        assert!(strutil::hexlify_into(&data, &mut s, true));
+        assert_eq!(s.as_str(), "AB12CD34EF90");
+
+        // Exactly at capacity.
+        let mut s = heapless::String::<12>::new();
+        assert!(strutil::hexlify_into(&data, &mut s, false));
+        assert_eq!(s.as_str(), "ab12cd34ef90");
+
+        // Only whole bytes are appended.
+        let mut s = heapless::String::<11>::new();
+        assert!(!strutil::hexlify_into(&data, &mut s, false));
+        assert_eq!(s.as_str(), "ab12cd34ef");
+
+        let mut s = ShortString::new();
+        assert!(strutil::hex_preview_into(&data, 2, &mut s, false));
+        assert_eq!(s.as_str(), "ab12...ef90");
+
+        let mut s = ShortString::new();
+        assert!(strutil::hex_preview_into(&data, 3, &mut s, false));
+        assert_eq!(s.as_str(), "ab12cd34ef90");
+
+        let mut s = heapless::String::<8>::new();
+        assert!(!strutil::hex_preview_into(&data, 2, &mut s, false));
+        assert_eq!(s.as_str(), "ab12...");
+    }
+
+    #[test]
+    fn uppercase() {
+        let upper =
+            |s: &str| TString::from(s).map_uppercased(|u| ShortString::try_from(u).unwrap());
+        assert_eq!(upper("").as_str(), "");
+        assert_eq!(
+            upper("Wrong PIN, 3 tries left").as_str(),
+            "WRONG PIN, 3 TRIES LEFT"
+        );
+        assert_eq!(upper("Zadejte číslo účtu").as_str(), "ZADEJTE ČÍSLO ÚČTU");
+        assert_eq!(upper("Wörter über Größe").as_str(), "WÖRTER ÜBER GRÖßE");
+        assert_eq!(upper("řeřicha ěšž ůň ÿ").as_str(), "ŘEŘICHA ĚŠŽ ŮŇ Ÿ");
+        // Unsupported chars pass through unchanged.
+        assert_eq!(upper("ı ÷ ß ©€ αβ").as_str(), "ı ÷ ß ©€ αβ");
+        // Too long to be converted.
+        let long = "abcdefghij".repeat(6);
+        assert!(TString::from(long.as_str()).map_uppercased(|u| u == long));
+    }
+
     #[test]
     fn u32_to_str_small_buffer() {
         let testcases = [1000, 31337, u32::MAX];
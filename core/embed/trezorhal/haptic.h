@@ -9,6 +9,10 @@ typedef enum {
   HAPTIC_BUTTON_PRESS = 0,
   HAPTIC_ALERT = 1,
   HAPTIC_HOLD_TO_CONFIRM = 2,
+  HAPTIC_TICK = 3,
+  HAPTIC_HOLD_START = 4,
+  HAPTIC_ERROR = 5,
+  HAPTIC_SUCCESS = 6,
 } haptic_effect_t;
 
 // Initialize haptic driver
@@ -20,7 +24,11 @@ void haptic_calibrate(void);
 // Test haptic driver, plays a maximum amplitude for the given duration
 bool haptic_test(uint16_t duration_ms);
 
-// Play haptic effect
+// Play haptic effect, without blocking
+//
+// An effect still playing is interrupted only by an effect of the same or
+// higher priority, otherwise the new effect is dropped. Nothing is played if
+// haptic feedback is disabled.
 void haptic_play(haptic_effect_t effect);
 
 // Starts the haptic motor with a specified amplitude and period
@@ -107,6 +107,11 @@ display_fb_info_t display_get_frame_buffer(void) {
   return fb;
 }
 
+bool display_set_refresh_region(int x0, int y0, int x1, int y1) {
+  // Partial updates are not supported
+  return false;
+}
+
 void display_refresh(void) {
   // Do nothing as using just a single frame buffer
 }
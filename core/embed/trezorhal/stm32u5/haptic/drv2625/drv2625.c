@@ -75,14 +75,42 @@
 #error "Must define either LRA or ERM"
 #endif
 
-#define PRESS_EFFECT_AMPLITUDE 25
-#define PRESS_EFFECT_DURATION 10
-
 #define MAX_AMPLITUDE 127
 #define PRODTEST_EFFECT_AMPLITUDE 127
 
+// Priorities of the effects, a playing effect is not interrupted by an effect
+// of a lower priority.
+#define PRIORITY_CUSTOM 0
+#define PRIORITY_LOW 1
+#define PRIORITY_MEDIUM 2
+#define PRIORITY_HIGH 3
+
+typedef struct {
+  uint8_t priority;
+  // Waveform from the library, or 0 to play `amplitude` in real time
+  uint8_t lib_effect;
+  int8_t amplitude;
+  // Duration of the real-time effect, or the approximate duration of the
+  // library waveform
+  uint16_t duration_ms;
+} haptic_effect_params_t;
+
+static const haptic_effect_params_t haptic_effects[] = {
+    [HAPTIC_BUTTON_PRESS] = {PRIORITY_LOW, 0, 25, 10},
+    [HAPTIC_ALERT] = {PRIORITY_HIGH, ALERT_750MS_100, 0, 750},
+    [HAPTIC_HOLD_TO_CONFIRM] = {PRIORITY_MEDIUM, DOUBLE_CLICK_60, 0, 150},
+    [HAPTIC_TICK] = {PRIORITY_LOW, 0, 15, 5},
+    [HAPTIC_HOLD_START] = {PRIORITY_LOW, 0, 40, 20},
+    [HAPTIC_ERROR] = {PRIORITY_HIGH, TRIPLE_CLICK_100, 0, 250},
+    [HAPTIC_SUCCESS] = {PRIORITY_MEDIUM, SHORT_DOUBLE_CLICK_MEDIUM_2_80, 0, 100},
+};
+
 bool haptic_enabled = true;
 
+// Priority and end of the last started effect
+static uint8_t playing_priority = PRIORITY_CUSTOM;
+static uint32_t playing_until = 0;
+
 static bool set_reg(uint8_t addr, uint8_t value) {
   uint8_t data[] = {addr, value};
   return i2c_transmit(DRV2625_I2C_INSTANCE, DRV2625_I2C_ADDRESS, data,
@@ -176,6 +204,24 @@ static bool haptic_play_rtp(int8_t amplitude, uint16_t duration_ms) {
   return true;
 }
 
+// Decides whether an effect of the given `priority` may start playing now,
+// and if so, records it as the playing one. This is the only place where the
+// global haptic setting is checked.
+static bool haptic_start(uint8_t priority, uint16_t duration_ms) {
+  if (!haptic_enabled) {
+    return false;
+  }
+
+  uint32_t now = HAL_GetTick();
+  if ((int32_t)(playing_until - now) > 0 && priority < playing_priority) {
+    return false;
+  }
+
+  playing_priority = priority;
+  playing_until = now + duration_ms;
+  return true;
+}
+
 static void haptic_play_lib(drv2625_lib_effect_t effect) {
   playing_rtp = false;
 
@@ -186,26 +232,27 @@ static void haptic_play_lib(drv2625_lib_effect_t effect) {
 }
 
 void haptic_play(haptic_effect_t effect) {
-  if (!haptic_enabled) {
+  if ((unsigned)effect >= sizeof(haptic_effects) / sizeof(haptic_effects[0])) {
+    return;
+  }
+
+  const haptic_effect_params_t *params = &haptic_effects[effect];
+  if (!haptic_start(params->priority, params->duration_ms)) {
     return;
   }
 
-  switch (effect) {
-    case HAPTIC_BUTTON_PRESS:
-      haptic_play_rtp(PRESS_EFFECT_AMPLITUDE, PRESS_EFFECT_DURATION);
-      break;
-    case HAPTIC_ALERT:
-      haptic_play_lib(ALERT_750MS_100);
-      break;
-    case HAPTIC_HOLD_TO_CONFIRM:
-      haptic_play_lib(DOUBLE_CLICK_60);
-      break;
-    default:
-      break;
+  if (params->lib_effect != 0) {
+    haptic_play_lib((drv2625_lib_effect_t)params->lib_effect);
+  } else {
+    haptic_play_rtp(params->amplitude, params->duration_ms);
   }
 }
 
 bool haptic_play_custom(int8_t amplitude_pct, uint16_t duration_ms) {
+  if (!haptic_start(PRIORITY_CUSTOM, duration_ms)) {
+    return false;
+  }
+
   if (amplitude_pct < 0) {
     amplitude_pct = 0;
   } else if (amplitude_pct > 100) {
@@ -158,15 +158,41 @@ uint32_t hal_ticks_ms() {
   return tv.tv_sec * 1000 + tv.tv_usec / 1000;
 }
 
+// Keys typed on the host keyboard, see `emulator_host_key_read`.
+#define HOST_KEY_QUEUE_LEN 16
+static uint32_t host_key_queue[HOST_KEY_QUEUE_LEN];
+static size_t host_key_head = 0;
+static size_t host_key_count = 0;
+
+static void host_key_push(uint32_t key) {
+  if (host_key_count == HOST_KEY_QUEUE_LEN) {
+    // Typing faster than the UI reads, drop the key.
+    return;
+  }
+  host_key_queue[(host_key_head + host_key_count) % HOST_KEY_QUEUE_LEN] = key;
+  host_key_count++;
+}
+
+uint32_t emulator_host_key_read(void) {
+  if (host_key_count == 0) {
+    return 0;
+  }
+  uint32_t key = host_key_queue[host_key_head];
+  host_key_head = (host_key_head + 1) % HOST_KEY_QUEUE_LEN;
+  host_key_count--;
+  return key;
+}
+
 static int SDLCALL emulator_event_filter(void *userdata, SDL_Event *event) {
   switch (event->type) {
     case SDL_QUIT:
       trezor_shutdown();
       return 0;
     case SDL_KEYUP:
-      if (event->key.repeat) {
-        return 0;
+      if (event->key.repeat || (event->key.keysym.mod & KMOD_CTRL) == 0) {
+        break;
       }
+      // Emulator shortcuts need Ctrl, plain keys are typed into the UI.
       switch (event->key.keysym.sym) {
         case SDLK_ESCAPE:
           trezor_shutdown();
@@ -176,6 +202,31 @@ static int SDLCALL emulator_event_filter(void *userdata, SDL_Event *event) {
           return 0;
       }
       break;
+    case SDL_KEYDOWN:
+      if (event->key.keysym.mod & KMOD_CTRL) {
+        break;
+      }
+      // Printable characters arrive as SDL_TEXTINPUT.
+      switch (event->key.keysym.sym) {
+        case SDLK_BACKSPACE:
+          host_key_push('\b');
+          return 0;
+        case SDLK_RETURN:
+        case SDLK_KP_ENTER:
+          host_key_push('\r');
+          return 0;
+        case SDLK_ESCAPE:
+          host_key_push(0x1B);
+          return 0;
+      }
+      break;
+    case SDL_TEXTINPUT:
+      for (const char *c = event->text.text; *c != '\0'; c++) {
+        if (*c >= 0x20 && *c <= 0x7E) {
+          host_key_push(*c);
+        }
+      }
+      return 0;
   }
   return 1;
 }
@@ -282,7 +282,7 @@ const char *display_save(const char *prefix) {
   if (PREV_SAVED != NULL) {
     if (memcmp(PREV_SAVED->pixels, crop->pixels, crop->pitch * crop->h) == 0) {
       SDL_FreeSurface(crop);
-      return filename;
+      return NULL;
     }
     SDL_FreeSurface(PREV_SAVED);
   }
@@ -50,6 +50,12 @@ typedef struct {
   uint8_t mono_framebuf[DISPLAY_RESX * DISPLAY_RESY];
 #endif
 
+  // Region flushed by the next refresh, valid only if `partial_refresh` is set
+  SDL_Rect refresh_region;
+  bool partial_refresh;
+  // Number of pixels flushed to the screen so far
+  uint64_t flushed_pixels;
+
 } display_driver_t;
 
 static display_driver_t g_display_driver;
@@ -212,6 +218,17 @@ int display_get_orientation(void) {
   return drv->orientation_angle;
 }
 
+// Extends the region flushed by the next refresh, if only a part of the
+// display is going to be refreshed.
+static void extend_refresh_region(display_driver_t *drv, int x0, int y0,
+                                  int x1, int y1) {
+  if (!drv->partial_refresh) {
+    return;
+  }
+  SDL_Rect r = {x0, y0, x1 - x0, y1 - y0};
+  SDL_UnionRect(&drv->refresh_region, &r, &drv->refresh_region);
+}
+
 #ifdef XFRAMEBUFFER
 display_fb_info_t display_get_frame_buffer(void) {
   display_driver_t *drv = &g_display_driver;
@@ -231,6 +248,23 @@ display_fb_info_t display_get_frame_buffer(void) {
   return fb;
 }
 
+bool display_set_refresh_region(int x0, int y0, int x1, int y1) {
+  display_driver_t *drv = &g_display_driver;
+
+  x0 = MAX(x0, 0);
+  y0 = MAX(y0, 0);
+  x1 = MIN(x1, DISPLAY_RESX);
+  y1 = MIN(y1, DISPLAY_RESY);
+
+  if (!drv->partial_refresh) {
+    drv->refresh_region = (SDL_Rect){x0, y0, MAX(x1 - x0, 0), MAX(y1 - y0, 0)};
+    drv->partial_refresh = true;
+  } else {
+    extend_refresh_region(drv, x0, y0, x1, y1);
+  }
+  return true;
+}
+
 #else  // XFRAMEBUFFER
 
 void display_wait_for_sync(void) {
@@ -271,9 +305,22 @@ void display_refresh(void) {
   } else {
     SDL_RenderClear(drv->renderer);
   }
-  // Show the display buffer
-  SDL_UpdateTexture(drv->texture, NULL, drv->buffer->pixels,
-                    drv->buffer->pitch);
+  // Show the display buffer, only the changed region of it if known
+  if (drv->partial_refresh) {
+    const SDL_Rect *r = &drv->refresh_region;
+    if (r->w > 0 && r->h > 0) {
+      const uint8_t *pixels = (uint8_t *)drv->buffer->pixels +
+                              drv->buffer->pitch * r->y +
+                              drv->buffer->format->BytesPerPixel * r->x;
+      SDL_UpdateTexture(drv->texture, r, pixels, drv->buffer->pitch);
+      drv->flushed_pixels += r->w * r->h;
+    }
+    drv->partial_refresh = false;
+  } else {
+    SDL_UpdateTexture(drv->texture, NULL, drv->buffer->pixels,
+                      drv->buffer->pitch);
+    drv->flushed_pixels += DISPLAY_RESX * DISPLAY_RESY;
+  }
 #define BACKLIGHT_NORMAL 150
   SDL_SetTextureAlphaMod(
       drv->texture, MIN(255, 255 * drv->backlight_level / BACKLIGHT_NORMAL));
@@ -291,6 +338,11 @@ void display_refresh(void) {
   SDL_RenderPresent(drv->renderer);
 }
 
+uint64_t display_flushed_pixels(void) {
+  display_driver_t *drv = &g_display_driver;
+  return drv->flushed_pixels;
+}
+
 void display_set_compatible_settings(void) {
   // not used
 }
@@ -300,6 +352,9 @@ void display_set_compatible_settings(void) {
 void display_fill(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row =
       (uint8_t *)drv->buffer->pixels + (drv->buffer->pitch * bb_new.dst_y);
@@ -311,6 +366,9 @@ void display_fill(const gfx_bitblt_t *bb) {
 void display_copy_rgb565(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row =
       (uint8_t *)drv->buffer->pixels + (drv->buffer->pitch * bb_new.dst_y);
@@ -322,6 +380,9 @@ void display_copy_rgb565(const gfx_bitblt_t *bb) {
 void display_copy_mono1p(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row =
       (uint8_t *)drv->buffer->pixels + (drv->buffer->pitch * bb_new.dst_y);
@@ -333,6 +394,9 @@ void display_copy_mono1p(const gfx_bitblt_t *bb) {
 void display_copy_mono4(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row =
       (uint8_t *)drv->buffer->pixels + (drv->buffer->pitch * bb_new.dst_y);
@@ -346,6 +410,9 @@ void display_copy_mono4(const gfx_bitblt_t *bb) {
 void display_fill(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row = drv->mono_framebuf + (DISPLAY_RESX * bb_new.dst_y);
   bb_new.dst_stride = DISPLAY_RESX;
@@ -356,6 +423,9 @@ void display_fill(const gfx_bitblt_t *bb) {
 void display_copy_mono1p(const gfx_bitblt_t *bb) {
   display_driver_t *drv = &g_display_driver;
 
+  extend_refresh_region(drv, bb->dst_x, bb->dst_y, bb->dst_x + bb->width,
+                        bb->dst_y + bb->height);
+
   gfx_bitblt_t bb_new = *bb;
   bb_new.dst_row = drv->mono_framebuf + (DISPLAY_RESX * bb_new.dst_y);
   bb_new.dst_stride = DISPLAY_RESX;
@@ -390,7 +460,7 @@ const char *display_save(const char *prefix) {
     if (memcmp(drv->prev_saved->pixels, crop->pixels, crop->pitch * crop->h) ==
         0) {
       SDL_FreeSurface(crop);
-      return filename;
+      return NULL;
     }
     SDL_FreeSurface(drv->prev_saved);
   }
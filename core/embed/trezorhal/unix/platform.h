@@ -1,2 +1,3 @@
 
 void emulator_poll_events(void);
+uint32_t emulator_host_key_read(void);
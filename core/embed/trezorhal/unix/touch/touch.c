@@ -20,6 +20,9 @@
 #include <SDL.h>
 #include <stdbool.h>
 #include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
 
 #include TREZOR_BOARD
 #ifdef USE_TOUCH
@@ -185,7 +188,128 @@ static void handle_button_events(SDL_Event event, int* ev_type, int* ev_x,
   }
 }
 
-uint32_t touch_read(void) {
+// Recording and replaying of the touch events, to reproduce gestures exactly.
+//
+// TREZOR_TOUCH_RECORD=<path> writes each event to the file as a line
+// "<ms> <start|move|end> <x> <y>", where <ms> is the time since the first
+// `touch_read` call. TREZOR_TOUCH_REPLAY=<path> reads the events from such a
+// file instead of the mouse, at the same times. TREZOR_TOUCH_REPLAY_SPEED=<n>
+// makes the time between the gestures pass n times faster, while the gestures
+// keep their original timing, so that long presses and timers of the UI behave
+// the same as when recording.
+
+static bool record_initialized = false;
+static FILE *record_file = NULL;
+static FILE *replay_file = NULL;
+static uint32_t replay_speed = 1;
+static uint32_t record_start = 0;
+
+// The next event of the replay and its recorded time.
+static bool replay_pending = false;
+static uint32_t replay_event = 0;
+static uint32_t replay_time = 0;
+// Recorded time of the previous event and the time it was due in the replay.
+static uint32_t replay_prev_time = 0;
+static uint32_t replay_prev_due = 0;
+
+static const char *event_name(uint32_t event) {
+  switch (event & 0xFF000000U) {
+    case TOUCH_START:
+      return "start";
+    case TOUCH_MOVE:
+      return "move";
+    case TOUCH_END:
+      return "end";
+    default:
+      return NULL;
+  }
+}
+
+static void record_init(void) {
+  record_initialized = true;
+  record_start = hal_ticks_ms();
+
+  const char *path = getenv("TREZOR_TOUCH_RECORD");
+  if (path != NULL && *path != '\0') {
+    record_file = fopen(path, "w");
+    if (record_file == NULL) {
+      printf("Cannot record touch events to %s\n", path);
+    }
+  }
+
+  path = getenv("TREZOR_TOUCH_REPLAY");
+  if (path != NULL && *path != '\0') {
+    replay_file = fopen(path, "r");
+    if (replay_file == NULL) {
+      printf("Cannot replay touch events from %s\n", path);
+    }
+  }
+
+  const char *speed = getenv("TREZOR_TOUCH_REPLAY_SPEED");
+  if (speed != NULL && atoi(speed) > 0) {
+    replay_speed = atoi(speed);
+  }
+}
+
+static void record_event(uint32_t event) {
+  const char *name = event_name(event);
+  if (record_file == NULL || name == NULL) {
+    return;
+  }
+  fprintf(record_file, "%u %s %u %u\n", hal_ticks_ms() - record_start, name,
+          (event >> 12) & 0xFFFU, event & 0xFFFU);
+  // The emulator may well crash on the gesture being recorded.
+  fflush(record_file);
+}
+
+// Reads the next event of the replay, closes the file at its end.
+static void replay_read_next(void) {
+  char name[8] = {0};
+  unsigned int time = 0, x = 0, y = 0;
+  while (fscanf(replay_file, "%u %7s %u %u", &time, name, &x, &y) == 4) {
+    uint32_t type = strcmp(name, "start") == 0  ? TOUCH_START
+                    : strcmp(name, "move") == 0 ? TOUCH_MOVE
+                    : strcmp(name, "end") == 0  ? TOUCH_END
+                                                : 0;
+    if (type != 0) {
+      replay_event = type | touch_pack_xy(x, y);
+      replay_time = time;
+      replay_pending = true;
+      return;
+    }
+  }
+  fclose(replay_file);
+  replay_file = NULL;
+  replay_pending = false;
+  printf("Touch replay finished\n");
+}
+
+static uint32_t replay_read(void) {
+  if (!replay_pending) {
+    replay_read_next();
+    if (!replay_pending) {
+      return 0;
+    }
+  }
+  uint32_t delay =
+      replay_time > replay_prev_time ? replay_time - replay_prev_time : 0;
+  if (replay_event & TOUCH_START) {
+    // Nothing is touched until the gesture starts, no need to wait.
+    delay /= replay_speed;
+  }
+  uint32_t due = replay_prev_due + delay;
+  if (hal_ticks_ms() - record_start < due) {
+    return 0;
+  }
+  // Relative to the due time rather than now, so that the delays of the
+  // polling do not add up over the replay.
+  replay_prev_due = due;
+  replay_prev_time = replay_time;
+  replay_pending = false;
+  return replay_event;
+}
+
+static uint32_t touch_read_input(void) {
   if (input_state == BUTTON_SWIPE_COMPLETED) {
     input_state = IDLE;
     return TOUCH_END | touch_pack_xy(_touch_x, _touch_y);
@@ -216,6 +340,27 @@ uint32_t touch_read(void) {
   return ev_type | touch_pack_xy(ev_x, ev_y);
 }
 
+uint32_t touch_read(void) {
+  if (!record_initialized) {
+    record_init();
+  }
+
+  uint32_t event = 0;
+  if (replay_file != NULL) {
+    // Still process the window events, the mouse and arrow keys are ignored.
+    emulator_poll_events();
+    SDL_FlushEvents(SDL_KEYDOWN, SDL_MOUSEWHEEL);
+    event = replay_read();
+  } else {
+    event = touch_read_input();
+  }
+
+  if (event != 0) {
+    record_event(event);
+  }
+  return event;
+}
+
 secbool touch_init(void) { return sectrue; }
 void touch_power_on(void) {}
 void touch_wait_until_ready(void) {}
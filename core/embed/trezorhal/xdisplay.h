@@ -20,6 +20,7 @@
 #ifndef TREZORHAL_XDISPLAY_H
 #define TREZORHAL_XDISPLAY_H
 
+#include <stdbool.h>
 #include <stddef.h>
 #include <stdint.h>
 #include "gfx_bitblt.h"
@@ -102,6 +103,13 @@ typedef struct {
 // the function may block until the buffer is ready to write.
 display_fb_info_t display_get_frame_buffer(void);
 
+// Limits the next `display_refresh()` to the given region of the frame buffer
+// (x1 and y1 exclusive). Regions set before one refresh are merged.
+//
+// Returns false if the driver does not support partial updates, in which
+// case the whole frame buffer has to be drawn before the refresh.
+bool display_set_refresh_region(int x0, int y0, int x1, int y1);
+
 #else  // XFRAMEBUFFER
 
 // Waits for the vertical synchronization pulse.
@@ -117,6 +125,13 @@ void display_wait_for_sync(void);
 // swaps the active (currently displayed) and the inactive frame buffers.
 void display_refresh(void);
 
+#ifdef TREZOR_EMULATOR
+// Returns the number of pixels flushed to the screen so far.
+//
+// Used to measure the effect of partial updates.
+uint64_t display_flushed_pixels(void);
+#endif
+
 // Sets display to the mode compatible with the legacy bootloader code.
 //
 // This is used when switching between the firmware and the bootloader.
@@ -143,8 +158,9 @@ void display_copy_mono4(const gfx_bitblt_t *bb);
 void display_copy_mono1p(const gfx_bitblt_t *bb);
 
 #ifdef TREZOR_EMULATOR
-// Save the screen content to a file.
-// The function is available only on the emulator.
+// Save the screen content to a PNG file, numbered after the prefix.
+// Returns the name of the file, NULL if the content is the same as in the
+// previously saved file. The function is available only on the emulator.
 const char *display_save(const char *prefix);
 void display_clear_save(void);
 #endif
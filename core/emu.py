@@ -116,6 +116,9 @@ def _from_env(name: str) -> bool:
 @click.option("-P", "--port", metavar="PORT", type=int, default=int(os.environ.get("TREZOR_UDP_PORT", 0)) or None, help="UDP port number")
 @click.option("-q", "--quiet", is_flag=True, help="Silence emulator output")
 @click.option("-r", "--record-dir", help="Directory where to record screen changes")
+@click.option("--record-touch", type=click.Path(dir_okay=False), help="File where to record touch events")
+@click.option("--replay-touch", type=click.Path(exists=True, dir_okay=False), help="Replay touch events recorded by --record-touch")
+@click.option("--replay-speed", type=int, default=1, help="Shorten the time between replayed gestures this many times")
 @click.option("-s", "--slip0014", is_flag=True, help="Initialize device with SLIP-14 seed (all all all...)")
 @click.option("-S", "--script-gdb-file", type=click.Path(exists=True, dir_okay=False), help="Run gdb with an init file")
 @click.option("-t", "--temporary-profile", is_flag=True, help="Create an empty temporary profile")
@@ -142,6 +145,9 @@ def cli(
     output: TextIO | None,
     quiet: bool,
     record_dir: Optional[str],
+    record_touch: Optional[str],
+    replay_touch: Optional[str],
+    replay_speed: int,
     slip0014: bool,
     script_gdb_file: str | Path | None,
     temporary_profile: bool,
@@ -261,6 +267,13 @@ def cli(
     if alloc_profiling:
         os.environ["TREZOR_MEMPERF"] = "1"
 
+    if record_touch:
+        os.environ["TREZOR_TOUCH_RECORD"] = record_touch
+
+    if replay_touch:
+        os.environ["TREZOR_TOUCH_REPLAY"] = replay_touch
+        os.environ["TREZOR_TOUCH_REPLAY_SPEED"] = str(replay_speed)
+
     if debugger:
         run_debugger(emulator, script_gdb_file)
         raise RuntimeError("run_debugger should not return")
@@ -207,4 +207,5 @@ BUTTON_RELEASED: int  # button up event
 BUTTON_LEFT: int  # button number of left button
 BUTTON_RIGHT: int  # button number of right button
 USB_CHECK: int # interface id for check of USB data connection
+HOST_KEY: int  # interface id of host keyboard events (emulator only)
 WireInterface = Union[HID, WebUSB]
@@ -43,12 +43,21 @@ class Display:
         Call without the val parameter to just perform the read of the value.
         """
 
-    def save(self, prefix: str) -> None:
+    def save(self, prefix: str, trace: str | None = None) -> str | None:
         """
-        Saves current display contents to PNG file with given prefix.
+        Saves current display contents to PNG file with given prefix, unless
+        they are the same as in the previous file. If `trace` is given, it is
+        written next to the PNG file, with the `.json` extension.
+        Returns the name of the PNG file, None if nothing was saved.
         """
 
     def clear_save(self) -> None:
         """
         Clears buffers in display saving.
         """
+
+    def flushed_pixels(self) -> int:
+        """
+        Returns the number of pixels flushed to the screen so far. Emulator
+        only, returns 0 elsewhere.
+        """
@@ -22,8 +22,19 @@ class LayoutObj(Generic[T]):
             """Receive a button event `event` for button `button`."""
     def progress_event(self, value: int, description: str) -> T | None:
         """Receive a progress event."""
+    def progress_info_event(self, phase: str, eta: str) -> T | None:
+        """Receive a new phase label and estimated time remaining of the
+        progress, empty strings hide them."""
     def usb_event(self, connected: bool) -> T | None:
         """Receive a USB connect/disconnect event."""
+    def notification_event(self, text: str | None, level: int) -> T | None:
+        """Receive a new notification of the homescreen, None removes it."""
+    def mnemonic_word_event(self, word_index: int, progress: str) -> T | None:
+        """Move the mnemonic keyboard on to word `word_index`, showing the
+        `progress` text."""
+    if utils.EMULATOR:
+        def host_key_event(self, key: int) -> T | None:
+            """Receive a key `key` typed on the host keyboard."""
     def timer(self, token: int) -> T | None:
         """Callback for the timer set by `attach_timer_fn`.
         This function should be called by the executor after the corresponding
@@ -47,6 +58,17 @@ class LayoutObj(Generic[T]):
             """
         def bounds(self) -> None:
             """Paint bounds of individual components on screen."""
+        def keyboard_text(self) -> str | None:
+            """Return the text typed into the keyboard of the layout, if any."""
+        def keyboard_type(self, text: str) -> None:
+            """Type `text` into the keyboard of the layout, as if entered key by key.
+            Raises `ValueError` if the layout has no keyboard.
+            """
+        def debug_click(self, name: str) -> T | None:
+            """Click the component registered as debug target `name`, e.g.
+            `pin/digit/5` or `confirm`. The targets of the layout are listed
+            in its trace under `debug_targets`.
+            """
     def page_count(self) -> int:
         """Return the number of pages in the layout object."""
     def __del__(self) -> None:
@@ -67,11 +89,50 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_mercury/layout.rs
+def debug_overlay(show: bool) -> None:
+    """Draw the bounds of the components and their touch areas over the
+    layout, debug builds only. The layout needs a complete repaint."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def seed_random(seed: int | None) -> None:
+    """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def led_set_enabled(enable: bool) -> None:
+    """Enable or disable the RGB LED signals, no-op without the LED."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def set_keyboard_timings(
+    erase_hold_ms: int,
+    multi_tap_timeout_ms: int,
+    last_char_reveal_ms: int,
+    key_debounce_ms: int,
+) -> None:
+    """Set the erase hold duration, the multi-tap timeout, the reveal time
+    of the last passphrase character and the key debounce window of
+    keyboards."""
+
+
 # rust/src/ui/model_mercury/layout.rs
 def check_homescreen_format(data: bytes) -> bool:
     """Check homescreen format and dimensions."""
 
 
+# rust/src/ui/model_mercury/layout.rs
+def set_high_contrast(enable: bool) -> None:
+    """Use the high-contrast styles for screens created from now on."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def set_large_text(enable: bool) -> None:
+    """Use the larger fonts for screens created from now on."""
+
+
 # rust/src/ui/model_mercury/layout.rs
 def confirm_action(
     *,
@@ -151,6 +212,17 @@ def confirm_properties(
     the value is to be rendered as binary with monospace font, False otherwise."""
 
 
+# rust/src/ui/model_mercury/layout.rs
+def confirm_list(
+    *,
+    title: str,
+    items: list[str],
+    numbered: bool = False,
+    hold: bool = False,
+) -> LayoutObj[UiResult]:
+    """Confirm a bulleted list of items, or a numbered one if `numbered` is True."""
+
+
 # rust/src/ui/model_mercury/layout.rs
 def flow_confirm_reset_recover() -> LayoutObj[UiResult]:
     """Confirm TOS before recovery process."""
@@ -278,7 +350,8 @@ def show_success(
     allow_cancel: bool = False,
     time_ms: int = 0,
 ) -> LayoutObj[UiResult]:
-    """Success screen. Description is used in the footer."""
+    """Success screen. Description is used in the footer. Dismissed automatically
+    after `time_ms` if it is non-zero."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -344,11 +417,16 @@ def confirm_coinjoin(
 def request_pin(
     *,
     prompt: str,
-    subprompt: str,
+    subprompt: str = "",
+    attempts_remaining: int | None = None,
     allow_cancel: bool = True,
     wrong_pin: bool = False,
+    wipe_code: bool = False,
 ) -> LayoutObj[str | UiResult]:
-    """Request pin on device."""
+    """Request pin on device. The subprompt is replaced by the remaining
+    attempts counter if `attempts_remaining` is set, shown as a warning
+    when only a few are left. `wipe_code` styles the prompt for entering
+    a wipe code."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -363,21 +441,54 @@ def request_passphrase(
 # rust/src/ui/model_mercury/layout.rs
 def request_bip39(
     *,
+    title: str,
+    subtitle: str,
     prompt: str,
-    prefill_word: str,
-    can_go_back: bool,
+    word_count: int,
+    filter_last_word: bool = False,
+    autofill: bool = False,
 ) -> LayoutObj[str]:
-    """BIP39 word input keyboard."""
+    """BIP39 word input keyboard for all the `word_count` words of a
+    mnemonic, starting with the first one. Moves on to another word on
+    `mnemonic_word_event`, which also replaces `subtitle`. Ticks along the
+    top show the words entered. With `filter_last_word`, the last word is
+    limited to the words giving a valid checksum. With `autofill`, a word
+    identified by its prefix can be confirmed right away."""
 
 
 # rust/src/ui/model_mercury/layout.rs
 def request_slip39(
     *,
+    title: str,
+    subtitle: str,
     prompt: str,
-    prefill_word: str,
-    can_go_back: bool,
+    word_count: int,
 ) -> LayoutObj[str]:
-    """SLIP39 word input keyboard."""
+    """SLIP39 word input keyboard for all the `word_count` words of a
+    share, starting with the first one. Moves on to another word on
+    `mnemonic_word_event`, which also replaces `subtitle`. Ticks along the
+    top show the words entered."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def request_amount(
+    *,
+    title: str,
+    unit: str,
+    max_integer_digits: int,
+    max_decimals: int,
+) -> LayoutObj[str | UiResult]:
+    """Decimal amount input keyboard. Returns the amount as typed, without
+    thousands separators, to be parsed by the caller."""
+
+
+# rust/src/ui/model_mercury/layout.rs
+def request_datetime(
+    *,
+    title: str,
+) -> LayoutObj[str | UiResult]:
+    """Date and time input keyboard. Returns the value formatted as
+    `YYYY-MM-DD hh:mm`."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -421,9 +532,10 @@ def flow_request_number(
     info: Callable[[int], str] | None = None,
     br_code: ButtonRequestType,
     br_type: str,
+    direct_entry: bool = False,
 ) -> LayoutObj[tuple[UiResult, int]]:
     """Numer input with + and - buttons, description, and context menu with cancel and
-    info."""
+    info. With `direct_entry`, tapping the number opens a keypad to type it."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -462,8 +574,11 @@ def confirm_recovery(
 def select_word_count(
     *,
     recovery_type: RecoveryType,
+    bip39: bool = False,
 ) -> LayoutObj[int | str]:  # TT returns int
-    """Select mnemonic word count from (12, 18, 20, 24, 33)."""
+    """Select mnemonic word count from (12, 18, 20, 24, 33). Only the
+    counts of the respective backup type are offered for `bip39` or
+    unlocking a repeated backup."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -477,9 +592,11 @@ def show_group_share_success(
 # rust/src/ui/model_mercury/layout.rs
 def show_remaining_shares(
     *,
-    pages: Iterable[tuple[str, str]],
+    pages: Iterable[tuple[int, bool, str]],
 ) -> LayoutObj[UiResult]:
-    """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
+    """Shows SLIP39 state after info button is pressed on `confirm_recovery`.
+    Each page is the number of shares (or groups if the flag is set) still
+    needed and the words they start with."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -488,10 +605,14 @@ def show_progress(
     title: str,
     indeterminate: bool = False,
     description: str = "",
+    phase: str | None = None,
+    eta: str | None = None,
 ) -> LayoutObj[UiResult]:
     """Show progress loader. Please note that the number of lines reserved on screen for
-   description is determined at construction time. If you want multiline descriptions
-   make sure the initial description has at least that amount of lines."""
+   description is determined at construction time, at least two. If you want multiline
+   descriptions make sure the initial description has at least that amount of lines.
+   Passing `phase` or `eta` reserves room for them below the loader, they are then
+   updated by `progress_info_event`."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -503,7 +624,8 @@ def show_progress_coinjoin(
     skip_first_paint: bool = False,
 ) -> LayoutObj[UiResult]:
     """Show progress loader for coinjoin. Returns CANCELLED after a specified time when
-   time_ms timeout is passed."""
+   time_ms timeout is passed. Progress value 0xFFFE switches the loader to
+   indeterminate mode, 0xFFFF pauses it and shows the description, if any."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -525,6 +647,8 @@ def show_lockscreen(
     bootscreen: bool,
     skip_first_paint: bool,
     coinjoin_authorized: bool = False,
+    notification: str | None = None,
+    notification_level: int = 0,
 ) -> LayoutObj[UiResult]:
     """Homescreen for locked device."""
 
@@ -558,8 +682,11 @@ def flow_get_address(
     xpubs: list[tuple[str, str]],
     br_code: ButtonRequestType,
     br_type: str,
+    script_type: str | None = None,
 ) -> LayoutObj[UiResult]:
-    """Get address / receive funds."""
+    """Get address / receive funds. Account details page is skipped when
+    `account`, `path` and `script_type` are all missing and there are
+    `xpubs` to show."""
 
 
 # rust/src/ui/model_mercury/layout.rs
@@ -618,6 +745,23 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def debug_overlay(show: bool) -> None:
+    """Draw the bounds of the components and their touch areas over the
+    layout, debug builds only. The layout needs a complete repaint."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def seed_random(seed: int | None) -> None:
+    """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def led_set_enabled(enable: bool) -> None:
+    """Enable or disable the RGB LED signals, no-op without the LED."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def check_homescreen_format(data: bytes) -> bool:
     """Check homescreen format and dimensions."""
@@ -1101,8 +1245,19 @@ class LayoutObj(Generic[T]):
             """Receive a button event `event` for button `button`."""
     def progress_event(self, value: int, description: str) -> T | None:
         """Receive a progress event."""
+    def progress_info_event(self, phase: str, eta: str) -> T | None:
+        """Receive a new phase label and estimated time remaining of the
+        progress, empty strings hide them."""
     def usb_event(self, connected: bool) -> T | None:
         """Receive a USB connect/disconnect event."""
+    def notification_event(self, text: str | None, level: int) -> T | None:
+        """Receive a new notification of the homescreen, None removes it."""
+    def mnemonic_word_event(self, word_index: int, progress: str) -> T | None:
+        """Move the mnemonic keyboard on to word `word_index`, showing the
+        `progress` text."""
+    if utils.EMULATOR:
+        def host_key_event(self, key: int) -> T | None:
+            """Receive a key `key` typed on the host keyboard."""
     def timer(self, token: int) -> T | None:
         """Callback for the timer set by `attach_timer_fn`.
         This function should be called by the executor after the corresponding
@@ -1126,6 +1281,17 @@ class LayoutObj(Generic[T]):
             """
         def bounds(self) -> None:
             """Paint bounds of individual components on screen."""
+        def keyboard_text(self) -> str | None:
+            """Return the text typed into the keyboard of the layout, if any."""
+        def keyboard_type(self, text: str) -> None:
+            """Type `text` into the keyboard of the layout, as if entered key by key.
+            Raises `ValueError` if the layout has no keyboard.
+            """
+        def debug_click(self, name: str) -> T | None:
+            """Click the component registered as debug target `name`, e.g.
+            `pin/digit/5` or `confirm`. The targets of the layout are listed
+            in its trace under `debug_targets`.
+            """
     def page_count(self) -> int:
         """Return the number of pages in the layout object."""
     def button_request(self) -> tuple[int, str] | None:
@@ -1148,6 +1314,35 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def debug_overlay(show: bool) -> None:
+    """Draw the bounds of the components and their touch areas over the
+    layout, debug builds only. The layout needs a complete repaint."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def seed_random(seed: int | None) -> None:
+    """Make the randomness of the UI, e.g. the PIN keyboard digit order,
+    reproducible. `None` goes back to the hardware RNG. Debug builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def led_set_enabled(enable: bool) -> None:
+    """Enable or disable the RGB LED signals, no-op without the LED."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_keyboard_timings(
+    erase_hold_ms: int,
+    multi_tap_timeout_ms: int,
+    last_char_reveal_ms: int,
+    key_debounce_ms: int,
+) -> None:
+    """Set the erase hold duration, the multi-tap timeout, the reveal time
+    of the last passphrase character and the key debounce window of
+    keyboards."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def check_homescreen_format(data: bytes) -> bool:
     """Check homescreen format and dimensions."""
@@ -128,6 +128,8 @@ USE_BACKLIGHT: bool
 """Whether the hardware supports backlight brightness control."""
 USE_HAPTIC: bool
 """Whether the hardware supports haptic feedback."""
+USE_RGB_LED: bool
+"""Whether the hardware has the RGB LED."""
 USE_OPTIGA: bool
 """Whether the hardware supports Optiga secure element."""
 MODEL: str
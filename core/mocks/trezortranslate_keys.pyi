@@ -19,6 +19,7 @@ class TR:
     address_details__account_info: str = "Account info"
     address_details__derivation_path: str = "Derivation path"
     address_details__derivation_path_colon: str = "Derivation path:"
+    address_details__script_type: str = "Script type"
     address_details__title_receive_address: str = "Receive address"
     address_details__title_receiving_to: str = "Receiving to"
     authenticate__confirm_template: str = "Allow connected computer to confirm your {0} is genuine?"
@@ -354,6 +355,9 @@ class TR:
     haptic_feedback__enable: str = "Enable haptic feedback?"
     haptic_feedback__subtitle: str = "Setting"
     haptic_feedback__title: str = "Haptic feedback"
+    high_contrast__disable: str = "Do you want to disable high contrast text?"
+    high_contrast__enable: str = "Do you want to enable high contrast text?"
+    high_contrast__title: str = "High contrast"
     homescreen__click_to_connect: str = "Click to Connect"
     homescreen__click_to_unlock: str = "Click to Unlock"
     homescreen__set_default: str = "Do you really want to set default homescreen image?"
@@ -390,10 +394,21 @@ class TR:
     joint__title: str = "Joint transaction"
     joint__to_the_total_amount: str = "To the total amount:"
     joint__you_are_contributing: str = "You are contributing:"
+    keyboard_timings__erase_hold_template: str = "Hold the erase key for {0} ms to clear the input?"
+    keyboard_timings__key_debounce_template: str = "Ignore repeated presses of a key within {0} ms?"
+    keyboard_timings__last_char_reveal_template: str = "Show the last typed character of a hidden passphrase for {0} ms?"
+    keyboard_timings__multi_tap_template: str = "Confirm a multi-tap key after {0} ms?"
+    keyboard_timings__title: str = "Keyboard timing"
     language__change_to_template: str = "Change language to {0}?"
     language__changed: str = "Language changed successfully"
     language__progress: str = "Changing language"
     language__title: str = "Language settings"
+    large_text__disable: str = "Do you want to disable large text?"
+    large_text__enable: str = "Do you want to enable large text?"
+    large_text__title: str = "Large text"
+    led_signals__disable: str = "Disable LED signals?"
+    led_signals__enable: str = "Enable LED signals?"
+    led_signals__title: str = "LED signals"
     lockscreen__tap_to_connect: str = "Tap to connect"
     lockscreen__tap_to_unlock: str = "Tap to unlock"
     lockscreen__title_locked: str = "Locked"
@@ -483,12 +498,16 @@ class TR:
     nem__unknown_mosaic: str = "Unknown mosaic!"
     passphrase__access_hidden_wallet: str = "Access hidden wallet?"
     passphrase__always_on_device: str = "Always enter your passphrase on Trezor?"
+    passphrase__double_space: str = "Double space"
     passphrase__from_host_not_shown: str = "Passphrase provided by host will be used but will not be displayed due to the device settings."
     passphrase__hidden_wallet: str = "Hidden wallet"
     passphrase__hide: str = "Hide passphrase coming from host?"
     passphrase__next_screen_will_show_passphrase: str = "The next screen shows your passphrase."
     passphrase__please_enter: str = "Please enter your passphrase."
     passphrase__revoke_on_device: str = "Do you want to revoke the passphrase on device setting?"
+    passphrase__space_at_end: str = "Space at end"
+    passphrase__space_at_start: str = "Space at start"
+    passphrase__tap_to_trim: str = "Tap to trim"
     passphrase__title_confirm: str = "Confirm passphrase"
     passphrase__title_enter: str = "Enter passphrase"
     passphrase__title_hide: str = "Hide passphrase"
@@ -525,6 +544,7 @@ class TR:
     pin__turn_off: str = "Are you sure you want to turn off PIN protection?"
     pin__turn_on: str = "Turn on PIN protection?"
     pin__wrong_pin: str = "Wrong PIN"
+    pin__x_tries_left_template_plural: str = "{count} {plural} left"
     plurals__contains_x_keys: str = "key|keys"
     plurals__lock_after_x_hours: str = "hour|hours"
     plurals__lock_after_x_milliseconds: str = "millisecond|milliseconds"
@@ -534,6 +554,7 @@ class TR:
     plurals__transaction_of_x_operations: str = "operation|operations"
     plurals__x_groups_needed: str = "group|groups"
     plurals__x_shares_needed: str = "share|shares"
+    plurals__x_tries_left: str = "try|tries"
     progress__authenticity_check: str = "Checking authenticity..."
     progress__done: str = "Done"
     progress__loading_transaction: str = "Loading transaction..."
@@ -578,6 +599,8 @@ class TR:
     recovery__share_does_not_match: str = "Share doesn't match"
     recovery__share_from_another_multi_share_backup: str = "You have entered a share from a different backup."
     recovery__share_num_template: str = "Share {0}"
+    recovery__share_num_word_x_of_y_template: str = "Share {0}, word {1}/{2}"
+    recovery__share_word_x_of_y_template: str = "Share {0}/{1}, word {2}/{3}"
     recovery__title: str = "Recover wallet"
     recovery__title_cancel_dry_run: str = "Cancel backup check"
     recovery__title_cancel_recovery: str = "Cancel recovery"
@@ -585,6 +608,7 @@ class TR:
     recovery__title_recover: str = "Recover wallet"
     recovery__title_remaining_shares: str = "Remaining shares"
     recovery__title_unlock_repeated_backup: str = "Multi-share backup"
+    recovery__type_word: str = "Type word"
     recovery__type_word_x_of_y_template: str = "Type word {0} of {1}"
     recovery__unlock_repeated_backup: str = "Create additional backup?"
     recovery__unlock_repeated_backup_verb: str = "Create backup"
@@ -3,6 +3,7 @@ from typing import TYPE_CHECKING
 from .keychain import with_keychain
 
 if TYPE_CHECKING:
+    from trezor.enums import InputScriptType
     from trezor.messages import Address, GetAddress, HDNodeType
 
     from apps.common.coininfo import CoinInfo
@@ -29,6 +30,21 @@ def _get_xpubs(
     return result
 
 
+def _script_type_name(script_type: InputScriptType, multisig: bool) -> str:
+    from trezor.enums import InputScriptType
+
+    if script_type == InputScriptType.SPENDP2SHWITNESS:
+        return "P2SH-P2WSH" if multisig else "P2SH-P2WPKH"
+    elif script_type == InputScriptType.SPENDWITNESS:
+        return "P2WSH" if multisig else "P2WPKH"
+    elif script_type == InputScriptType.SPENDTAPROOT:
+        return "P2TR"
+    elif script_type == InputScriptType.SPENDMULTISIG:
+        return "P2SH"
+    else:
+        return "P2PKH"
+
+
 @with_keychain
 async def get_address(msg: GetAddress, keychain: Keychain, coin: CoinInfo) -> Address:
     from trezor.enums import InputScriptType
@@ -112,6 +128,7 @@ async def get_address(msg: GetAddress, keychain: Keychain, coin: CoinInfo) -> Ad
                 multisig_index=multisig_index,
                 xpubs=_get_xpubs(coin, multisig_xpub_magic, pubnodes),
                 account=f"Multisig {multisig.m} of {len(pubnodes)}",
+                script_type=_script_type_name(script_type, True),
                 chunkify=bool(msg.chunkify),
             )
         else:
@@ -122,6 +139,7 @@ async def get_address(msg: GetAddress, keychain: Keychain, coin: CoinInfo) -> Ad
                 case_sensitive=address_case_sensitive,
                 path=path,
                 account=account,
+                script_type=_script_type_name(script_type, False),
                 chunkify=bool(msg.chunkify),
             )
 
@@ -43,10 +43,13 @@ async def request_pin(
     prompt: str,
     attempts_remaining: int | None = None,
     allow_cancel: bool = True,
+    wipe_code: bool = False,
 ) -> str:
     from trezor.ui.layouts import request_pin_on_device
 
-    return await request_pin_on_device(prompt, attempts_remaining, allow_cancel)
+    return await request_pin_on_device(
+        prompt, attempts_remaining, allow_cancel, wipe_code=wipe_code
+    )
 
 
 async def request_pin_confirm(*args: Any, **kwargs: Any) -> str:
@@ -22,9 +22,12 @@ if __debug__:
             DebugLinkDecision,
             DebugLinkEraseSdCard,
             DebugLinkGetState,
+            DebugLinkKeyboard,
+            DebugLinkKeyboardState,
             DebugLinkRecordScreen,
             DebugLinkReseedRandom,
             DebugLinkResetDebugEvents,
+            DebugLinkShowBounds,
             DebugLinkState,
             DebugLinkWatchLayout,
         )
@@ -34,10 +37,19 @@ if __debug__:
     result_chan = loop.chan()
     button_chan = loop.chan()
     click_chan = loop.chan()
+    target_chan = loop.chan()
+    repaint_chan = loop.chan()
+    keyboard_chan = loop.chan()
     swipe_signal = swipe_chan.take
     result_signal = result_chan.take
     button_signal = button_chan.take
     click_signal = click_chan.take
+    target_signal = target_chan.take
+    repaint_signal = repaint_chan.take
+    keyboard_signal = keyboard_chan.take
+
+    # Content of the keyboard, reported back by the layout after typing.
+    keyboard_state_chan = loop.chan()
 
     debuglink_decision_chan = loop.chan()
 
@@ -55,8 +67,10 @@ if __debug__:
         if storage.save_screen:
             # Starting with "refresh00", allowing for 100 emulator restarts
             # without losing the order of the screenshots based on filename.
+            # The trace of the layout is saved next to each screenshot.
             display.save(
-                storage.save_screen_directory + f"/refresh{REFRESH_INDEX:0>2}-"
+                storage.save_screen_directory + f"/refresh{REFRESH_INDEX:0>2}-",
+                "".join(storage.current_content_tokens),
             )
             return True
         return False
@@ -170,8 +184,11 @@ if __debug__:
         # Incrementing the counter for last events so we know what to await
         debug_events.last_event += 1
 
+        # Touchscreen devices click on components registered as debug targets
+        if msg.target is not None and utils.INTERNAL_MODEL in ("T2T1", "T3T1", "D001"):
+            target_chan.publish((debug_events.last_event, msg.target))
         # Touchscreen devices click on specific coordinates, with possible hold
-        if (
+        elif (
             x is not None
             and y is not None
             and utils.INTERNAL_MODEL in ("T2T1", "T3T1", "D001")
@@ -235,11 +252,41 @@ if __debug__:
 
     async def dispatch_DebugLinkReseedRandom(msg: DebugLinkReseedRandom) -> Success:
         if msg.value is not None:
-            from trezor.crypto import random
+            # The UI draws from its own generator, so that its randomness does
+            # not depend on what else consumed random numbers in between.
+            trezorui2.seed_random(msg.value)
+            if utils.EMULATOR:
+                from trezor.crypto import random
+
+                random.reseed(msg.value)
+        return Success()
 
-            random.reseed(msg.value)
+    async def dispatch_DebugLinkShowBounds(msg: DebugLinkShowBounds) -> Success:
+        trezorui2.debug_overlay(bool(msg.show))
+        # Only the touchscreen layouts take the signal, the others draw the
+        # overlay once they repaint on their own.
+        if utils.INTERNAL_MODEL in ("T2T1", "T3T1", "D001"):
+            repaint_chan.publish(None)
         return Success()
 
+    async def dispatch_DebugLinkKeyboard(
+        msg: DebugLinkKeyboard,
+    ) -> DebugLinkKeyboardState:
+        from trezor import ui, workflow
+        from trezor.messages import DebugLinkKeyboardState
+
+        # Only the touchscreen layouts have keyboards that can be typed into.
+        if utils.INTERNAL_MODEL not in ("T2T1", "T3T1", "D001"):
+            raise wire.ProcessError("Keyboard input not supported")
+
+        workflow.idle_timer.touch()
+        await ui.wait_until_layout_is_running()
+        keyboard_chan.publish(msg.input)
+        text = await keyboard_state_chan.take()
+        if text is None:
+            raise wire.ProcessError("Layout has no keyboard")
+        return DebugLinkKeyboardState(text=text)
+
     async def dispatch_DebugLinkEraseSdCard(msg: DebugLinkEraseSdCard) -> Success:
         from trezor import io
 
@@ -274,6 +321,22 @@ if __debug__:
         register(
             MessageType.DebugLinkResetDebugEvents, dispatch_DebugLinkResetDebugEvents
         )
+        register(MessageType.DebugLinkShowBounds, dispatch_DebugLinkShowBounds)
+        register(MessageType.DebugLinkKeyboard, dispatch_DebugLinkKeyboard)
+
+        if utils.EMULATOR:
+            import uos
+
+            # Record screenshots from the start, without debuglink.
+            save_screen_directory = uos.getenv("TREZOR_SAVE_SCREEN_DIR")
+            if save_screen_directory:
+                storage.save_screen_directory = save_screen_directory
+                storage.save_screen = True
+
+            # Same PIN keyboard and other random layouts from the start.
+            ui_seed = uos.getenv("TREZOR_UI_SEED")
+            if ui_seed:
+                trezorui2.seed_random(int(ui_seed))
 
         loop.schedule(debuglink_decision_dispatcher())
         if storage.layout_watcher is not LAYOUT_WATCHER_NONE:
@@ -51,6 +51,16 @@ async def apply_settings(msg: ApplySettings) -> Success:
     experimental_features = msg.experimental_features  # local_cache_attribute
     hide_passphrase_from_host = msg.hide_passphrase_from_host  # local_cache_attribute
     haptic_feedback = msg.haptic_feedback
+    led_signals = msg.led_signals
+    high_contrast = msg.high_contrast
+    keyboard_timings = (
+        msg.keyboard_erase_hold_ms,
+        msg.keyboard_multi_tap_timeout_ms,
+        msg.keyboard_last_char_reveal_ms,
+        msg.keyboard_key_debounce_ms,
+    )
+    large_text = msg.large_text
+    use_keyboard_timings = utils.UI_LAYOUT in ("TT", "MERCURY")
 
     if (
         homescreen is None
@@ -63,6 +73,12 @@ async def apply_settings(msg: ApplySettings) -> Success:
         and experimental_features is None
         and hide_passphrase_from_host is None
         and (haptic_feedback is None or not utils.USE_HAPTIC)
+        and (led_signals is None or not utils.USE_RGB_LED)
+        and (high_contrast is None or utils.UI_LAYOUT != "MERCURY")
+        and (large_text is None or utils.UI_LAYOUT != "MERCURY")
+        and (
+            all(t is None for t in keyboard_timings) or not use_keyboard_timings
+        )
     ):
         raise ProcessError("No setting provided")
 
@@ -123,6 +139,24 @@ async def apply_settings(msg: ApplySettings) -> Success:
         io.haptic.haptic_set_enabled(haptic_feedback)
         storage_device.set_haptic_feedback(haptic_feedback)
 
+    if led_signals is not None and utils.USE_RGB_LED:
+        await _require_confirm_led_signals(led_signals)
+        trezorui2.led_set_enabled(led_signals)
+        storage_device.set_led_signals(led_signals)
+
+    if high_contrast is not None and utils.UI_LAYOUT == "MERCURY":
+        await _require_confirm_high_contrast(high_contrast)
+        trezorui2.set_high_contrast(high_contrast)
+        storage_device.set_high_contrast(high_contrast)
+
+    if large_text is not None and utils.UI_LAYOUT == "MERCURY":
+        await _require_confirm_large_text(large_text)
+        trezorui2.set_large_text(large_text)
+        storage_device.set_large_text(large_text)
+
+    if use_keyboard_timings and any(t is not None for t in keyboard_timings):
+        await _apply_keyboard_timings(keyboard_timings)
+
     reload_settings_from_storage()
 
     return Success(message="Settings applied")
@@ -285,3 +319,96 @@ if utils.USE_HAPTIC:
             br_code=BRT_PROTECT_CALL,
             prompt_screen=True,
         )
+
+
+if utils.USE_RGB_LED:
+
+    async def _require_confirm_led_signals(enable: bool) -> None:
+        await confirm_action(
+            "led_signals__settings",
+            TR.led_signals__title,
+            TR.led_signals__enable if enable else TR.led_signals__disable,
+            br_code=BRT_PROTECT_CALL,
+            prompt_screen=True,
+        )
+
+
+async def _apply_keyboard_timings(requested: tuple[int | None, ...]) -> None:
+    # Name, range and confirmation of each timing, in the storage order.
+    limits = (
+        (
+            "erase hold",
+            storage_device.KEYBOARD_ERASE_HOLD_MINIMUM,
+            storage_device.KEYBOARD_ERASE_HOLD_MAXIMUM,
+            TR.keyboard_timings__erase_hold_template,
+        ),
+        (
+            "multi-tap timeout",
+            storage_device.KEYBOARD_MULTI_TAP_TIMEOUT_MINIMUM,
+            storage_device.KEYBOARD_MULTI_TAP_TIMEOUT_MAXIMUM,
+            TR.keyboard_timings__multi_tap_template,
+        ),
+        (
+            "last character reveal",
+            storage_device.KEYBOARD_LAST_CHAR_REVEAL_MINIMUM,
+            storage_device.KEYBOARD_LAST_CHAR_REVEAL_MAXIMUM,
+            TR.keyboard_timings__last_char_reveal_template,
+        ),
+        (
+            "key debounce",
+            storage_device.KEYBOARD_KEY_DEBOUNCE_MINIMUM,
+            storage_device.KEYBOARD_KEY_DEBOUNCE_MAXIMUM,
+            TR.keyboard_timings__key_debounce_template,
+        ),
+    )
+    timings = list(
+        storage_device.get_keyboard_timings()
+        or (
+            storage_device.KEYBOARD_ERASE_HOLD_DEFAULT,
+            storage_device.KEYBOARD_MULTI_TAP_TIMEOUT_DEFAULT,
+            storage_device.KEYBOARD_LAST_CHAR_REVEAL_DEFAULT,
+            storage_device.KEYBOARD_KEY_DEBOUNCE_DEFAULT,
+        )
+    )
+    for i, value_ms in enumerate(requested):
+        if value_ms is None:
+            continue
+        name, minimum, maximum, template = limits[i]
+        if not minimum <= value_ms <= maximum:
+            raise ProcessError(f"Keyboard {name} out of range")
+        await _require_confirm_keyboard_timing(template, value_ms)
+        timings[i] = value_ms
+    trezorui2.set_keyboard_timings(*timings)
+    storage_device.set_keyboard_timings(tuple(timings))
+
+
+async def _require_confirm_keyboard_timing(template: str, value_ms: int) -> None:
+    await confirm_action(
+        "set_keyboard_timings",
+        TR.keyboard_timings__title,
+        description=template,
+        description_param=str(value_ms),
+        br_code=BRT_PROTECT_CALL,
+        prompt_screen=True,
+    )
+
+
+if utils.UI_LAYOUT == "MERCURY":
+
+    async def _require_confirm_high_contrast(enable: bool) -> None:
+        await confirm_action(
+            "high_contrast__settings",
+            TR.high_contrast__title,
+            TR.high_contrast__enable if enable else TR.high_contrast__disable,
+            br_code=BRT_PROTECT_CALL,
+            prompt_screen=True,
+        )
+
+    async def _require_confirm_large_text(enable: bool) -> None:
+        await confirm_action(
+            "large_text__settings",
+            TR.large_text__title,
+            TR.large_text__enable if enable else TR.large_text__disable,
+            br_code=BRT_PROTECT_CALL,
+            prompt_screen=True,
+        )
@@ -99,8 +99,15 @@ async def _continue_recovery_process() -> Success:
                 await layout.homescreen_dialog(
                     TR.buttons__continue, TR.recovery__num_of_words
                 )
-            # ask for the number of words
-            word_count = await layout.request_word_count(recovery_type)
+            # ask for the number of words, a dry run can only check a backup
+            # of the type the device was set up with
+            bip39 = (
+                recovery_type == RecoveryType.DryRun
+                and not backup_types.is_slip39_backup_type(
+                    storage_device.get_backup_type()
+                )
+            )
+            word_count = await layout.request_word_count(recovery_type, bip39)
             # ...and only then show the starting screen with word count.
             await _request_share_first_screen(word_count, recovery_type)
         assert word_count is not None
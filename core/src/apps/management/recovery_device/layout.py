@@ -1,7 +1,7 @@
 from typing import TYPE_CHECKING
 
 from trezor import TR
-from trezor.enums import ButtonRequestType
+from trezor.enums import ButtonRequestType, RecoveryType
 from trezor.ui.layouts import confirm_action
 from trezor.ui.layouts.recovery import (  # noqa: F401
     request_word_count,
@@ -40,16 +40,56 @@ async def _confirm_abort(dry_run: bool = False) -> None:
         )
 
 
+def _share_progress(word_count: int) -> tuple[int, int | None] | None:
+    """Number of the SLIP-39 share being entered, once known from the first
+    share. For a single group also the number of shares needed, multiple groups
+    need a different number of shares from each group."""
+    import storage.recovery as storage_recovery
+    import storage.recovery_shares as storage_recovery_shares
+
+    if not backup_types.is_slip39_word_count(word_count):
+        return None
+    group_count = storage_recovery.get_slip39_group_count()
+    if group_count == 1:
+        remaining = storage_recovery.get_slip39_remaining_shares(0)
+        if remaining is None:
+            return None
+        entered = len(storage_recovery_shares.fetch_group(0))
+        return entered + 1, entered + remaining
+    entered = sum(
+        len(storage_recovery_shares.fetch_group(i)) for i in range(group_count)
+    )
+    return entered + 1, None
+
+
+def _filter_last_word(word_count: int, recovery_type: RecoveryType) -> bool:
+    """Whether to only offer the last BIP-39 words giving a valid checksum. A
+    dry run lets the user type an invalid seed, to tell them it is invalid."""
+    if backup_types.is_slip39_word_count(word_count):
+        return False
+    return recovery_type != RecoveryType.DryRun
+
+
 async def request_mnemonic(
     word_count: int, backup_type: BackupType | None
 ) -> str | None:
+    import storage.recovery as storage_recovery
     from trezor.ui.layouts.common import button_request
-    from trezor.ui.layouts.recovery import request_word
+    from trezor.ui.layouts.recovery import MnemonicKeyboard
 
     from . import word_validity
 
     await button_request("mnemonic", code=ButtonRequestType.MnemonicInput)
 
+    recovery_type = storage_recovery.get_type()
+    keyboard = MnemonicKeyboard(
+        word_count,
+        recovery_type,
+        is_slip39=backup_types.is_slip39_word_count(word_count),
+        share=_share_progress(word_count),
+        filter_last_word=_filter_last_word(word_count, recovery_type),
+    )
+
     # Allowing to go back to previous words, therefore cannot use just loop over range(word_count)
     words: list[str] = [""] * word_count
     i = 0
@@ -58,13 +98,8 @@ async def request_mnemonic(
         if i >= word_count:
             break
 
-        # Prefilling the previously inputted word in case of going back
-        word = await request_word(
-            i,
-            word_count,
-            is_slip39=backup_types.is_slip39_word_count(word_count),
-            prefill_word=words[i],
-        )
+        # The keyboard prefills the previously inputted word in case of going back
+        word = await keyboard.request_word(i)
 
         # User has decided to go back
         if not word:
@@ -10,6 +10,7 @@ welcome_screen_start_ms = utime.ticks_ms()
 
 import storage
 import storage.device
+import trezorui2
 from trezor import config, io, log, loop, ui, utils, wire, translations
 from trezor.pin import (
     allow_all_loader_messages,
@@ -40,6 +41,20 @@ def enforce_welcome_screen_duration() -> None:
         utime.sleep_ms(100)
 
 
+def apply_ui_settings() -> None:
+    """Set up the UI according to the settings in storage."""
+    if utils.USE_HAPTIC:
+        io.haptic.haptic_set_enabled(storage.device.get_haptic_feedback())
+    trezorui2.led_set_enabled(storage.device.get_led_signals())
+    if utils.UI_LAYOUT == "MERCURY":
+        trezorui2.set_high_contrast(storage.device.get_high_contrast())
+        trezorui2.set_large_text(storage.device.get_large_text())
+    if utils.UI_LAYOUT in ("TT", "MERCURY"):
+        timings = storage.device.get_keyboard_timings()
+        if timings is not None:
+            trezorui2.set_keyboard_timings(*timings)
+
+
 async def bootscreen() -> None:
     """Sequence of actions to be done on boot (after device is connected).
 
@@ -56,8 +71,7 @@ async def bootscreen() -> None:
                 enforce_welcome_screen_duration()
                 ui.backlight_fade(ui.BacklightLevels.NONE)
                 ui.display.orientation(storage.device.get_rotation())
-                if utils.USE_HAPTIC:
-                    io.haptic.haptic_set_enabled(storage.device.get_haptic_feedback())
+                apply_ui_settings()
                 lockscreen = Lockscreen(
                     label=storage.device.get_label(), bootscreen=True
                 )
@@ -72,8 +86,7 @@ async def bootscreen() -> None:
                 storage.init_unlocked()
                 enforce_welcome_screen_duration()
                 rotation = storage.device.get_rotation()
-                if utils.USE_HAPTIC:
-                    io.haptic.haptic_set_enabled(storage.device.get_haptic_feedback())
+                apply_ui_settings()
 
                 if rotation != ui.display.orientation():
                     # there is a slight delay before next screen is shown,
@@ -42,6 +42,10 @@ if utils.USE_THP:
 # unused from python:
 # _BRIGHTNESS                = const(0x19)  # int
 _DISABLE_HAPTIC_FEEDBACK   = const(0x20)  # bool (0x01 or empty)
+_DISABLE_LED_SIGNALS       = const(0x21)  # bool (0x01 or empty)
+_HIGH_CONTRAST             = const(0x22)  # bool (0x01 or empty)
+_KEYBOARD_TIMINGS          = const(0x23)  # bytes (2 or 4 x uint16 in ms)
+_LARGE_TEXT                = const(0x24)  # bool (0x01 or empty)
 
 
 SAFETY_CHECK_LEVEL_STRICT  : Literal[0] = const(0)
@@ -62,6 +66,20 @@ AUTOLOCK_DELAY_DEFAULT = const(10 * 60 * 1000)  # 10 minutes
 # autolock intervals larger than AUTOLOCK_DELAY_MAXIMUM cause issues in the scheduler
 AUTOLOCK_DELAY_MAXIMUM = const(0x2000_0000)  # ~6 days
 
+# Keep in sync with KeyboardTimings in core/embed/rust/src/ui/component/text/common.rs
+KEYBOARD_ERASE_HOLD_DEFAULT = const(1500)
+KEYBOARD_ERASE_HOLD_MINIMUM = const(500)
+KEYBOARD_ERASE_HOLD_MAXIMUM = const(5000)
+KEYBOARD_MULTI_TAP_TIMEOUT_DEFAULT = const(1000)
+KEYBOARD_MULTI_TAP_TIMEOUT_MINIMUM = const(300)
+KEYBOARD_MULTI_TAP_TIMEOUT_MAXIMUM = const(3000)
+KEYBOARD_LAST_CHAR_REVEAL_DEFAULT = const(1000)
+KEYBOARD_LAST_CHAR_REVEAL_MINIMUM = const(0)
+KEYBOARD_LAST_CHAR_REVEAL_MAXIMUM = const(3000)
+KEYBOARD_KEY_DEBOUNCE_DEFAULT = const(0)
+KEYBOARD_KEY_DEBOUNCE_MINIMUM = const(0)
+KEYBOARD_KEY_DEBOUNCE_MAXIMUM = const(300)
+
 # Length of SD salt auth tag.
 # Other SD-salt-related constants are in sd_salt.py
 SD_SALT_AUTH_KEY_LEN_BYTES = const(16)
@@ -396,3 +414,78 @@ def get_haptic_feedback() -> bool:
     Get haptic feedback enable, default to true if not set.
     """
     return not common.get_bool(_NAMESPACE, _DISABLE_HAPTIC_FEEDBACK, True)
+
+
+def set_led_signals(enable: bool) -> None:
+    """
+    Enable or disable the RGB LED signals.
+    """
+    common.set_bool(_NAMESPACE, _DISABLE_LED_SIGNALS, not enable, True)
+
+
+def get_led_signals() -> bool:
+    """
+    Get RGB LED signals enable, default to true if not set.
+    """
+    return not common.get_bool(_NAMESPACE, _DISABLE_LED_SIGNALS, True)
+
+
+def set_high_contrast(enable: bool) -> None:
+    """
+    Enable or disable the high-contrast UI theme.
+    """
+    common.set_bool(_NAMESPACE, _HIGH_CONTRAST, enable, True)
+
+
+def get_high_contrast() -> bool:
+    """
+    Get high-contrast UI theme enable, default to false if not set.
+    """
+    return common.get_bool(_NAMESPACE, _HIGH_CONTRAST, True)
+
+
+def set_large_text(enable: bool) -> None:
+    """
+    Enable or disable the larger fonts of the UI.
+    """
+    common.set_bool(_NAMESPACE, _LARGE_TEXT, enable, True)
+
+
+def get_large_text() -> bool:
+    """
+    Get larger UI fonts enable, default to false if not set.
+    """
+    return common.get_bool(_NAMESPACE, _LARGE_TEXT, True)
+
+
+def set_keyboard_timings(timings: tuple[int, int, int, int]) -> None:
+    """
+    Set the erase hold duration, the multi-tap timeout, the last character
+    reveal time and the key debounce window of keyboards.
+    """
+    b = b""
+    for ms in timings:
+        if not 0 <= ms <= 0xFFFF:
+            raise ValueError  # invalid timing
+        b += ms.to_bytes(2, "big")
+    common.set(_NAMESPACE, _KEYBOARD_TIMINGS, b, True)  # public
+
+
+def get_keyboard_timings() -> tuple[int, int, int, int] | None:
+    """
+    Get the erase hold duration, the multi-tap timeout, the last character
+    reveal time and the key debounce window of keyboards, None if not set.
+    Timings stored before the last two were added get their defaults.
+    """
+    b = common.get(_NAMESPACE, _KEYBOARD_TIMINGS, True)
+    if b is None or len(b) not in (4, 8):
+        return None
+    if len(b) == 4:
+        b += KEYBOARD_LAST_CHAR_REVEAL_DEFAULT.to_bytes(2, "big")
+        b += KEYBOARD_KEY_DEBOUNCE_DEFAULT.to_bytes(2, "big")
+    return (
+        int.from_bytes(b[0:2], "big"),
+        int.from_bytes(b[2:4], "big"),
+        int.from_bytes(b[4:6], "big"),
+        int.from_bytes(b[6:8], "big"),
+    )
@@ -98,6 +98,9 @@ DebugLinkRecordScreen = 9003
 DebugLinkEraseSdCard = 9005
 DebugLinkWatchLayout = 9006
 DebugLinkResetDebugEvents = 9007
+DebugLinkShowBounds = 9008
+DebugLinkKeyboard = 9009
+DebugLinkKeyboardState = 9010
 if not utils.BITCOIN_ONLY:
     SetU2FCounter = 63
     GetNextU2FCounter = 80
@@ -121,6 +121,9 @@ if TYPE_CHECKING:
         DebugLinkEraseSdCard = 9005
         DebugLinkWatchLayout = 9006
         DebugLinkResetDebugEvents = 9007
+        DebugLinkShowBounds = 9008
+        DebugLinkKeyboard = 9009
+        DebugLinkKeyboardState = 9010
         EthereumGetPublicKey = 450
         EthereumPublicKey = 451
         EthereumGetAddress = 56
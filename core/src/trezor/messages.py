@@ -2266,6 +2266,13 @@ if TYPE_CHECKING:
         experimental_features: "bool | None"
         hide_passphrase_from_host: "bool | None"
         haptic_feedback: "bool | None"
+        led_signals: "bool | None"
+        high_contrast: "bool | None"
+        keyboard_erase_hold_ms: "int | None"
+        keyboard_multi_tap_timeout_ms: "int | None"
+        large_text: "bool | None"
+        keyboard_last_char_reveal_ms: "int | None"
+        keyboard_key_debounce_ms: "int | None"
 
         def __init__(
             self,
@@ -2280,6 +2287,13 @@ if TYPE_CHECKING:
             experimental_features: "bool | None" = None,
             hide_passphrase_from_host: "bool | None" = None,
             haptic_feedback: "bool | None" = None,
+            led_signals: "bool | None" = None,
+            high_contrast: "bool | None" = None,
+            keyboard_erase_hold_ms: "int | None" = None,
+            keyboard_multi_tap_timeout_ms: "int | None" = None,
+            large_text: "bool | None" = None,
+            keyboard_last_char_reveal_ms: "int | None" = None,
+            keyboard_key_debounce_ms: "int | None" = None,
         ) -> None:
             pass
 
@@ -2824,6 +2838,7 @@ if TYPE_CHECKING:
         wait: "bool | None"
         hold_ms: "int | None"
         physical_button: "DebugPhysicalButton | None"
+        target: "str | None"
 
         def __init__(
             self,
@@ -2836,6 +2851,7 @@ if TYPE_CHECKING:
             wait: "bool | None" = None,
             hold_ms: "int | None" = None,
             physical_button: "DebugPhysicalButton | None" = None,
+            target: "str | None" = None,
         ) -> None:
             pass
 
@@ -3063,6 +3079,48 @@ if TYPE_CHECKING:
         def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkResetDebugEvents"]:
             return isinstance(msg, cls)
 
+    class DebugLinkShowBounds(protobuf.MessageType):
+        show: "bool | None"
+
+        def __init__(
+            self,
+            *,
+            show: "bool | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkShowBounds"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkKeyboard(protobuf.MessageType):
+        input: "str | None"
+
+        def __init__(
+            self,
+            *,
+            input: "str | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkKeyboard"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkKeyboardState(protobuf.MessageType):
+        text: "str | None"
+
+        def __init__(
+            self,
+            *,
+            text: "str | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkKeyboardState"]:
+            return isinstance(msg, cls)
+
     class EosGetPublicKey(protobuf.MessageType):
         address_n: "list[int]"
         show_display: "bool | None"
@@ -15,7 +15,14 @@ if TYPE_CHECKING:
     ExceptionType = BaseException | type[BaseException]
 
     class ProgressLayout(Protocol):
-        def report(self, value: int, description: str | None = None) -> None: ...
+        def report(
+            self,
+            value: int,
+            description: str | None = None,
+            *,
+            phase: str | None = None,
+            eta: str | None = None,
+        ) -> None: ...
 
 
 async def button_request(
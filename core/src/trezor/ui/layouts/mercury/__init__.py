@@ -63,23 +63,36 @@ class RustLayout(ui.Layout):
     if __debug__:
 
         def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
+            tasks = (
+                self.handle_timers(),
+                self.handle_input_and_rendering(),
+                self.handle_swipe(),
+                self.handle_click_signal(),
+                self.handle_target_signal(),
+                self.handle_repaint_signal(),
+                self.handle_keyboard_signal(),
+                self.handle_result_signal(),
+            )
+            if utils.EMULATOR:
+                tasks += (self.handle_host_keys(),)
             if context.CURRENT_CONTEXT:
-                return (
-                    self.handle_timers(),
-                    self.handle_input_and_rendering(),
-                    self.handle_swipe(),
-                    self.handle_click_signal(),
-                    self.handle_result_signal(),
-                    self.handle_usb(context.get_context()),
-                )
-            else:
-                return (
-                    self.handle_timers(),
-                    self.handle_input_and_rendering(),
-                    self.handle_swipe(),
-                    self.handle_click_signal(),
-                    self.handle_result_signal(),
-                )
+                tasks += (self.handle_usb(context.get_context()),)
+            return tasks
+
+        def handle_host_keys(self) -> loop.Task:
+            """Forwards keys typed on the host keyboard into the layout."""
+            from trezor import workflow
+
+            host_key = loop.wait(io.HOST_KEY)
+            while True:
+                # Using `yield` instead of `await` to avoid allocations.
+                key = yield host_key
+                workflow.idle_timer.touch()
+                msg = self.layout.host_key_event(key)
+                self._send_button_request()
+                if msg is not None:
+                    raise ui.Result(msg)
+                self._paint()
 
         async def handle_result_signal(self) -> None:
             """Enables sending arbitrary input - ui.Result.
@@ -175,6 +188,63 @@ class RustLayout(ui.Layout):
                 event_id, x, y, hold_ms = await click_signal()
                 await self._click(event_id, x, y, hold_ms)
 
+        async def handle_target_signal(self) -> None:
+            """Enables clicking a component by the name of its debug target.
+
+            Waits for `target_signal` and carries it out.
+            """
+            from storage import debug as debug_storage
+            from trezor import workflow
+
+            from apps.debug import notify_layout_change, target_signal
+
+            while True:
+                event_id, name = await target_signal()
+                try:
+                    msg = self.layout.debug_click(name)
+                except ValueError:
+                    log.warning(__name__, "Unknown debug target: %s", name)
+                    msg = None
+                self._send_button_request()
+
+                if msg is not None:
+                    debug_storage.new_layout_event_id = event_id
+                    raise ui.Result(msg)
+
+                workflow.idle_timer.touch()
+                self._paint()
+                notify_layout_change(self, event_id)
+
+        async def handle_repaint_signal(self) -> None:
+            """Repaints the whole layout on `repaint_signal`, e.g. after toggling
+            the debug overlay."""
+            from apps.debug import repaint_signal
+
+            while True:
+                await repaint_signal()
+                self.request_complete_repaint()
+                self._paint()
+
+        async def handle_keyboard_signal(self) -> None:
+            """Types into the keyboard of the layout on `keyboard_signal`.
+
+            Reports the content of the keyboard back, `None` if there is none.
+            """
+            from apps.debug import (
+                keyboard_signal,
+                keyboard_state_chan,
+                notify_layout_change,
+            )
+
+            while True:
+                text = await keyboard_signal()
+                if text and self.layout.keyboard_text() is not None:
+                    self.layout.keyboard_type(text)
+                    self._send_button_request()
+                    self._paint()
+                    notify_layout_change(self)
+                keyboard_state_chan.publish(self.layout.keyboard_text())
+
     else:
 
         def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
@@ -447,6 +517,7 @@ async def show_address(
     case_sensitive: bool = True,
     path: str | None = None,
     account: str | None = None,
+    script_type: str | None = None,
     network: str | None = None,
     multisig_index: int | None = None,
     xpubs: Sequence[str] = (),
@@ -480,6 +551,7 @@ async def show_address(
                 xpubs=[(xpub_title(i), xpub) for i, xpub in enumerate(xpubs)],
                 br_type=br_type,
                 br_code=br_code,
+                script_type=script_type,
             )
         )
     )
@@ -1386,23 +1458,18 @@ async def request_pin_on_device(
     attempts_remaining: int | None,
     allow_cancel: bool,
     wrong_pin: bool = False,
+    wipe_code: bool = False,
 ) -> str:
     from trezor.wire import PinCancelled
 
-    if attempts_remaining is None:
-        subprompt = ""
-    elif attempts_remaining == 1:
-        subprompt = TR.pin__last_attempt
-    else:
-        subprompt = f"{attempts_remaining} {TR.pin__tries_left}"
-
     result = await interact(
         RustLayout(
             trezorui2.request_pin(
                 prompt=prompt,
-                subprompt=subprompt,
+                attempts_remaining=attempts_remaining,
                 allow_cancel=allow_cancel,
                 wrong_pin=wrong_pin,
+                wipe_code=wipe_code,
             )
         ),
         "pin_device",
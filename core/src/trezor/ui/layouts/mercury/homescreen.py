@@ -12,6 +12,16 @@ if TYPE_CHECKING:
     from trezor import loop
 
 
+def _notification_level(notification: str | None, notification_is_error: bool) -> int:
+    if notification == TR.homescreen__title_coinjoin_authorized:
+        return 3
+    elif notification == TR.homescreen__title_experimental_mode:
+        return 2
+    elif notification is not None and notification_is_error:
+        return 0
+    return 1
+
+
 class HomescreenBase(RustLayout):
     RENDER_INDICATOR: object | None = None
 
@@ -49,26 +59,28 @@ class Homescreen(HomescreenBase):
         notification_is_error: bool,
         hold_to_lock: bool,
     ) -> None:
-        level = 1
-        if notification is not None:
-            if notification == TR.homescreen__title_coinjoin_authorized:
-                level = 3
-            elif notification == TR.homescreen__title_experimental_mode:
-                level = 2
-            elif notification_is_error:
-                level = 0
-
         skip = storage_cache.homescreen_shown is self.RENDER_INDICATOR
         super().__init__(
             layout=trezorui2.show_homescreen(
                 label=label,
                 notification=notification,
-                notification_level=level,
+                notification_level=_notification_level(
+                    notification, notification_is_error
+                ),
                 hold=hold_to_lock,
                 skip_first_paint=skip,
             ),
         )
 
+    def set_notification(
+        self, notification: str | None, notification_is_error: bool
+    ) -> None:
+        """Replace the notification without rebuilding the homescreen."""
+        level = _notification_level(notification, notification_is_error)
+        self.layout.notification_event(notification, level)
+        if self.layout.paint():
+            ui.refresh()
+
     async def usb_checker_task(self) -> None:
         from trezor import io, loop
 
@@ -25,32 +25,82 @@ async def _is_confirmed_info(
             return result is CONFIRMED
 
 
-async def request_word_count(recovery_type: RecoveryType) -> int:
-    selector = RustLayout(trezorui2.select_word_count(recovery_type=recovery_type))
+async def request_word_count(recovery_type: RecoveryType, bip39: bool = False) -> int:
+    selector = RustLayout(
+        trezorui2.select_word_count(recovery_type=recovery_type, bip39=bip39)
+    )
     count = await interact(selector, "word_count", ButtonRequestType.MnemonicWordCount)
     return int(count)
 
 
-async def request_word(
-    word_index: int, word_count: int, is_slip39: bool, prefill_word: str = ""
-) -> str:
-    prompt = TR.recovery__word_x_of_y_template.format(word_index + 1, word_count)
-    can_go_back = word_index > 0
-    if is_slip39:
-        keyboard = RustLayout(
-            trezorui2.request_slip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+class MnemonicKeyboard:
+    """Keyboard for the words of one mnemonic. It stays on screen from word to
+    word, with the header telling which word is being entered."""
+
+    def __init__(
+        self,
+        word_count: int,
+        recovery_type: RecoveryType,
+        is_slip39: bool,
+        share: tuple[int, int | None] | None = None,  # share number + share count
+        filter_last_word: bool = False,  # checksum filter for the last word
+        autofill: bool = False,  # BIP-39 only, SLIP-39 words are always auto-filled
+    ) -> None:
+        self.word_count = word_count
+        self.recovery_type = recovery_type
+        self.is_slip39 = is_slip39
+        self.share = share
+        self.filter_last_word = filter_last_word
+        self.autofill = autofill
+        self.layout: RustLayout | None = None
+
+    def _progress(self, word_index: int) -> str:
+        if self.share is None:
+            return TR.recovery__word_x_of_y_template.format(
+                word_index + 1, self.word_count
             )
-        )
-    else:
-        keyboard = RustLayout(
-            trezorui2.request_bip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+        share_number, share_count = self.share
+        if share_count is None:
+            return TR.recovery__share_num_word_x_of_y_template.format(
+                share_number, word_index + 1, self.word_count
             )
+        return TR.recovery__share_word_x_of_y_template.format(
+            share_number, share_count, word_index + 1, self.word_count
         )
 
-    word: str = await keyboard
-    return word
+    async def request_word(self, word_index: int) -> str:
+        """Word `word_index` typed by the user, empty when going back. Words
+        confirmed before are prefilled by the keyboard itself."""
+        progress = self._progress(word_index)
+        if self.layout is None:
+            if self.recovery_type == RecoveryType.DryRun:
+                title = TR.recovery__title_dry_run
+            else:
+                title = TR.recovery__title
+            if self.is_slip39:
+                keyboard = trezorui2.request_slip39(
+                    title=title,
+                    subtitle=progress,
+                    prompt=TR.recovery__type_word,
+                    word_count=self.word_count,
+                )
+            else:
+                keyboard = trezorui2.request_bip39(
+                    title=title,
+                    subtitle=progress,
+                    prompt=TR.recovery__type_word,
+                    word_count=self.word_count,
+                    filter_last_word=self.filter_last_word,
+                    autofill=self.autofill,
+                )
+            # The keyboard starts with the first word.
+            self.layout = RustLayout(keyboard)
+        else:
+            msg = self.layout.layout.mnemonic_word_event(word_index, progress)
+            assert msg is None
+
+        word: str = await self.layout
+        return word
 
 
 async def show_remaining_shares(
@@ -58,30 +108,20 @@ async def show_remaining_shares(
     shares_remaining: list[int],
     group_threshold: int,
 ) -> None:
-    from trezor import strings
     from trezor.crypto.slip39 import MAX_SHARE_COUNT
 
-    pages: list[tuple[str, str]] = []
+    # The titles are formatted in Rust, with the plural form of the language.
+    pages: list[tuple[int, bool, str]] = []
     for remaining, group in groups:
         if 0 < remaining < MAX_SHARE_COUNT:
-            title = strings.format_plural(
-                TR.recovery__x_more_items_starting_template_plural,
-                remaining,
-                TR.plurals__x_shares_needed,
-            )
             words = "\n".join(group)
-            pages.append((title, words))
+            pages.append((remaining, False, words))
         elif (
             remaining == MAX_SHARE_COUNT and shares_remaining.count(0) < group_threshold
         ):
             groups_remaining = group_threshold - shares_remaining.count(0)
-            title = strings.format_plural(
-                TR.recovery__x_more_items_starting_template_plural,
-                groups_remaining,
-                TR.plurals__x_groups_needed,
-            )
             words = "\n".join(group)
-            pages.append((title, words))
+            pages.append((groups_remaining, True, words))
 
     await raise_if_not_confirmed(
         interact(
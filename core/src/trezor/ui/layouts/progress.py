@@ -1,6 +1,8 @@
 from typing import TYPE_CHECKING
 
 import trezorui2
+import utime
+from micropython import const
 from trezor import TR, config, ui, utils
 
 if TYPE_CHECKING:
@@ -8,6 +10,10 @@ if TYPE_CHECKING:
 
     from .common import ProgressLayout
 
+# Special values reported to the coinjoin progress layout (model Mercury only).
+COINJOIN_PROGRESS_INDETERMINATE = const(0xFFFE)
+COINJOIN_PROGRESS_PAUSED = const(0xFFFF)
+
 
 def _storage_message_to_str(message: config.StorageMessage | None) -> str | None:
     from trezor import TR
@@ -34,6 +40,10 @@ class RustProgress:
         layout: Any,
     ):
         self.layout = layout
+        # Progress runs without the event loop, timers requested by the layout
+        # (animation frames coalescing the text updates) are fired by the
+        # first report after their deadline.
+        self.timers: list[tuple[int, int]] = []
         ui.backlight_fade(ui.BacklightLevels.DIM)
         self.layout.attach_timer_fn(self.set_timer)
         if self.layout.paint():
@@ -41,9 +51,30 @@ class RustProgress:
         ui.backlight_fade(ui.BacklightLevels.NORMAL)
 
     def set_timer(self, token: int, deadline: int) -> None:
-        raise RuntimeError  # progress layouts should not set timers
-
-    def report(self, value: int, description: str | None = None):
+        self.timers.append((token, utime.ticks_add(utime.ticks_ms(), deadline)))
+
+    def _fire_timers(self) -> None:
+        now = utime.ticks_ms()
+        timers = self.timers  # local_cache_attribute
+        for timer in timers[:]:
+            token, deadline = timer
+            if utime.ticks_diff(now, deadline) >= 0:
+                timers.remove(timer)
+                msg = self.layout.timer(token)
+                assert msg is None
+
+    def report(
+        self,
+        value: int,
+        description: str | None = None,
+        *,
+        phase: str | None = None,
+        eta: str | None = None,
+    ):
+        self._fire_timers()
+        if phase is not None or eta is not None:
+            msg = self.layout.progress_info_event(phase or "", eta or "")
+            assert msg is None
         msg = self.layout.progress_event(value, description or "")
         assert msg is None
         if self.layout.paint():
@@ -54,6 +85,8 @@ def progress(
     description: str | None = None,
     title: str | None = None,
     indeterminate: bool = False,
+    phase: str | None = None,
+    eta: str | None = None,
 ) -> ProgressLayout:
     if description is None:
         description = TR.progress__please_wait  # def_arg
@@ -63,6 +96,8 @@ def progress(
             description=description,
             title=title,
             indeterminate=indeterminate,
+            phase=phase,
+            eta=eta,
         )
     )
 
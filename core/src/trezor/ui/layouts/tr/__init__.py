@@ -536,6 +536,7 @@ async def show_address(
     case_sensitive: bool = True,
     path: str | None = None,
     account: str | None = None,
+    script_type: str | None = None,  # only shown on Mercury
     network: str | None = None,
     multisig_index: int | None = None,
     xpubs: Sequence[str] = (),
@@ -1500,6 +1501,7 @@ async def request_pin_on_device(
     attempts_remaining: int | None,
     allow_cancel: bool,
     wrong_pin: bool = False,
+    wipe_code: bool = False,  # unused on TR
 ) -> str:
     from trezor import wire
 
@@ -8,7 +8,9 @@ from ..common import interact
 from . import RustLayout, raise_if_not_confirmed, show_warning
 
 
-async def request_word_count(recovery_type: RecoveryType) -> int:
+async def request_word_count(
+    recovery_type: RecoveryType, bip39: bool = False  # unused on TR
+) -> int:
     count = await interact(
         RustLayout(trezorui2.select_word_count(recovery_type=recovery_type)),
         "word_count",
@@ -18,30 +20,51 @@ async def request_word_count(recovery_type: RecoveryType) -> int:
     return int(count)
 
 
-async def request_word(
-    word_index: int, word_count: int, is_slip39: bool, prefill_word: str = ""
-) -> str:
-    from trezor.wire.context import wait
-
-    prompt = TR.recovery__word_x_of_y_template.format(word_index + 1, word_count)
+class MnemonicKeyboard:
+    """Keyboard for the words of one mnemonic, a new one for every word."""
+
+    def __init__(
+        self,
+        word_count: int,
+        recovery_type: RecoveryType,  # unused on TR
+        is_slip39: bool,
+        share: tuple[int, int | None] | None = None,  # not shown on TR
+        filter_last_word: bool = False,  # not used on TR
+        autofill: bool = False,  # not used on TR
+    ) -> None:
+        self.word_count = word_count
+        self.is_slip39 = is_slip39
+        self.words = [""] * word_count
+
+    async def request_word(self, word_index: int) -> str:
+        """Word `word_index` typed by the user, empty when going back. Words
+        confirmed before are prefilled."""
+        from trezor.wire.context import wait
+
+        prompt = TR.recovery__word_x_of_y_template.format(
+            word_index + 1, self.word_count
+        )
+        prefill_word = self.words[word_index]
 
-    can_go_back = word_index > 0
+        can_go_back = word_index > 0
 
-    if is_slip39:
-        word_choice = RustLayout(
-            trezorui2.request_slip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+        if self.is_slip39:
+            word_choice = RustLayout(
+                trezorui2.request_slip39(
+                    prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+                )
             )
-        )
-    else:
-        word_choice = RustLayout(
-            trezorui2.request_bip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+        else:
+            word_choice = RustLayout(
+                trezorui2.request_bip39(
+                    prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+                )
             )
-        )
 
-    word: str = await wait(word_choice)
-    return word
+        word: str = await wait(word_choice)
+        if word:
+            self.words[word_index] = word
+        return word
 
 
 async def show_remaining_shares(
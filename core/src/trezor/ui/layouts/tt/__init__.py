@@ -75,6 +75,9 @@ class RustLayout(LayoutParentType[T]):
                     self.handle_input_and_rendering(),
                     self.handle_swipe(),
                     self.handle_click_signal(),
+                    self.handle_target_signal(),
+                    self.handle_repaint_signal(),
+                    self.handle_keyboard_signal(),
                     self.handle_result_signal(),
                     self.handle_usb(context.get_context()),
                 )
@@ -84,6 +87,9 @@ class RustLayout(LayoutParentType[T]):
                     self.handle_input_and_rendering(),
                     self.handle_swipe(),
                     self.handle_click_signal(),
+                    self.handle_target_signal(),
+                    self.handle_repaint_signal(),
+                    self.handle_keyboard_signal(),
                     self.handle_result_signal(),
                 )
 
@@ -181,6 +187,63 @@ class RustLayout(LayoutParentType[T]):
                 event_id, x, y, hold_ms = await click_signal()
                 await self._click(event_id, x, y, hold_ms)
 
+        async def handle_target_signal(self) -> None:
+            """Enables clicking a component by the name of its debug target.
+
+            Waits for `target_signal` and carries it out.
+            """
+            from storage import debug as debug_storage
+            from trezor import workflow
+
+            from apps.debug import notify_layout_change, target_signal
+
+            while True:
+                event_id, name = await target_signal()
+                try:
+                    msg = self.layout.debug_click(name)
+                except ValueError:
+                    log.warning(__name__, "Unknown debug target: %s", name)
+                    msg = None
+                self._send_button_request()
+
+                if msg is not None:
+                    debug_storage.new_layout_event_id = event_id
+                    raise ui.Result(msg)
+
+                workflow.idle_timer.touch()
+                self._paint()
+                notify_layout_change(self, event_id)
+
+        async def handle_repaint_signal(self) -> None:
+            """Repaints the whole layout on `repaint_signal`, e.g. after toggling
+            the debug overlay."""
+            from apps.debug import repaint_signal
+
+            while True:
+                await repaint_signal()
+                self.request_complete_repaint()
+                self._paint()
+
+        async def handle_keyboard_signal(self) -> None:
+            """Types into the keyboard of the layout on `keyboard_signal`.
+
+            Reports the content of the keyboard back, `None` if there is none.
+            """
+            from apps.debug import (
+                keyboard_signal,
+                keyboard_state_chan,
+                notify_layout_change,
+            )
+
+            while True:
+                text = await keyboard_signal()
+                if text and self.layout.keyboard_text() is not None:
+                    self.layout.keyboard_type(text)
+                    self._send_button_request()
+                    self._paint()
+                    notify_layout_change(self)
+                keyboard_state_chan.publish(self.layout.keyboard_text())
+
     else:
 
         def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
@@ -475,6 +538,7 @@ async def show_address(
     case_sensitive: bool = True,
     path: str | None = None,
     account: str | None = None,
+    script_type: str | None = None,  # only shown on Mercury
     network: str | None = None,
     multisig_index: int | None = None,
     xpubs: Sequence[str] = (),
@@ -1486,6 +1550,7 @@ async def request_pin_on_device(
     attempts_remaining: int | None,
     allow_cancel: bool,
     wrong_pin: bool = False,
+    wipe_code: bool = False,  # unused on TT
 ) -> str:
     from trezor.wire import PinCancelled
 
@@ -25,32 +25,55 @@ async def _is_confirmed_info(
             return result is CONFIRMED
 
 
-async def request_word_count(recovery_type: RecoveryType) -> int:
+async def request_word_count(
+    recovery_type: RecoveryType, bip39: bool = False  # unused on TT
+) -> int:
     selector = RustLayout(trezorui2.select_word_count(recovery_type=recovery_type))
     count = await interact(selector, "word_count", ButtonRequestType.MnemonicWordCount)
     return int(count)
 
 
-async def request_word(
-    word_index: int, word_count: int, is_slip39: bool, prefill_word: str = ""
-) -> str:
-    prompt = TR.recovery__type_word_x_of_y_template.format(word_index + 1, word_count)
-    can_go_back = word_index > 0
-    if is_slip39:
-        keyboard = RustLayout(
-            trezorui2.request_slip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
-            )
+class MnemonicKeyboard:
+    """Keyboard for the words of one mnemonic, a new one for every word."""
+
+    def __init__(
+        self,
+        word_count: int,
+        recovery_type: RecoveryType,  # unused on TT
+        is_slip39: bool,
+        share: tuple[int, int | None] | None = None,  # not shown on TT
+        filter_last_word: bool = False,  # not used on TT
+        autofill: bool = False,  # not used on TT
+    ) -> None:
+        self.word_count = word_count
+        self.is_slip39 = is_slip39
+        self.words = [""] * word_count
+
+    async def request_word(self, word_index: int) -> str:
+        """Word `word_index` typed by the user, empty when going back. Words
+        confirmed before are prefilled."""
+        prompt = TR.recovery__type_word_x_of_y_template.format(
+            word_index + 1, self.word_count
         )
-    else:
-        keyboard = RustLayout(
-            trezorui2.request_bip39(
-                prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+        prefill_word = self.words[word_index]
+        can_go_back = word_index > 0
+        if self.is_slip39:
+            keyboard = RustLayout(
+                trezorui2.request_slip39(
+                    prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+                )
+            )
+        else:
+            keyboard = RustLayout(
+                trezorui2.request_bip39(
+                    prompt=prompt, prefill_word=prefill_word, can_go_back=can_go_back
+                )
             )
-        )
 
-    word: str = await keyboard
-    return word
+        word: str = await keyboard
+        if word:
+            self.words[word_index] = word
+        return word
 
 
 async def show_remaining_shares(
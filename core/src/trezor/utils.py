@@ -13,6 +13,7 @@ from trezorutils import (  # noqa: F401
     USE_BACKLIGHT,
     USE_HAPTIC,
     USE_OPTIGA,
+    USE_RGB_LED,
     USE_SD_CARD,
     USE_THP,
     VERSION,
@@ -221,6 +221,30 @@ def haptic_feedback(client: "TrezorClient", enable: bool) -> str:
     return device.apply_settings(client, haptic_feedback=enable)
 
 
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def led_signals(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable RGB LED signals."""
+    return device.apply_settings(client, led_signals=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def high_contrast(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable high contrast text."""
+    return device.apply_settings(client, high_contrast=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def large_text(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable large text."""
+    return device.apply_settings(client, large_text=enable)
+
+
 @cli.command()
 @click.argument("path_or_url", required=False)
 @click.option(
@@ -288,6 +312,35 @@ def auto_lock_delay(client: "TrezorClient", delay: str) -> str:
     return device.apply_settings(client, auto_lock_delay_ms=int(seconds * 1000))
 
 
+@cli.command()
+@click.option("-e", "--erase-hold", type=int, help="Hold to erase all, in ms")
+@click.option("-m", "--multi-tap", type=int, help="Multi-tap key timeout, in ms")
+@click.option(
+    "-r", "--reveal", type=int, help="Show last typed passphrase character, in ms"
+)
+@click.option("-d", "--debounce", type=int, help="Ignore repeated key presses, in ms")
+@with_client
+def keyboard_timings(
+    client: "TrezorClient",
+    erase_hold: Optional[int],
+    multi_tap: Optional[int],
+    reveal: Optional[int],
+    debounce: Optional[int],
+) -> str:
+    """Set keyboard timings (in milliseconds)."""
+    if erase_hold is None and multi_tap is None and reveal is None and debounce is None:
+        raise click.ClickException(
+            "Specify --erase-hold, --multi-tap, --reveal or --debounce"
+        )
+    return device.apply_settings(
+        client,
+        keyboard_erase_hold_ms=erase_hold,
+        keyboard_multi_tap_timeout_ms=multi_tap,
+        keyboard_last_char_reveal_ms=reveal,
+        keyboard_key_debounce_ms=debounce,
+    )
+
+
 @cli.command()
 @click.argument("flags")
 @with_client
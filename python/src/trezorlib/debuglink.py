@@ -92,6 +92,19 @@ class UnstructuredJSONReader:
 
         return list(recursively_find(self.dict))
 
+    def find_objects_with_key(self, key: str) -> List["AnyDict"]:
+        def recursively_find(data: Any) -> Iterator[Any]:
+            if isinstance(data, dict):
+                if key in data:
+                    yield data
+                for val in data.values():
+                    yield from recursively_find(val)
+            elif isinstance(data, list):
+                for item in data:
+                    yield from recursively_find(item)
+
+        return list(recursively_find(self.dict))
+
     def find_unique_object_with_key_and_value(
         self, key: str, value: Any
     ) -> Optional["AnyDict"]:
@@ -302,6 +315,25 @@ class LayoutContent(UnstructuredJSONReader):
         button_keys = ("left_btn", "middle_btn", "right_btn")
         return [get_button_content(btn_key) for btn_key in button_keys]
 
+    def component_areas(self) -> List[Tuple[str, "AnyDict"]]:
+        """Placed areas of the components, as reported in ui_debug builds.
+
+        Example: [("Button", {"x": 6, "y": 184, "width": 228, "height": 50})]
+        """
+        return [
+            (obj.get("component", ""), obj["area"])
+            for obj in self.find_objects_with_key("area")
+            if isinstance(obj["area"], dict)
+        ]
+
+    def debug_targets(self) -> List[str]:
+        """Names of the components that can be clicked by
+        `DebugLink.click_target`, as reported in ui_debug builds.
+
+        Example: ["pin/digit/5", "confirm", "erase"]
+        """
+        return self.top_level_value("debug_targets") or []
+
     def seed_words(self) -> List[str]:
         """Get all the seed words on the screen in order.
 
@@ -321,11 +353,62 @@ class LayoutContent(UnstructuredJSONReader):
         assert "PinKeyboard" in self.all_components()
         return self.find_unique_value_by_key("pin", default="", only_type=str)
 
+    def pin_attempts_remaining(self) -> Optional[int]:
+        """Get the remaining PIN attempts shown by the PIN keyboard, if any."""
+        assert "PinKeyboard" in self.all_components()
+        return self.find_unique_value_by_key(
+            "attempts_remaining", default=None, only_type=int
+        )
+
+    def pin_is_wipe_code(self) -> bool:
+        """Whether the PIN keyboard is entering a wipe code."""
+        assert "PinKeyboard" in self.all_components()
+        return self.find_unique_value_by_key("wipe_code", default=False, only_type=bool)
+
     def passphrase(self) -> str:
         """Get passphrase from the layout."""
         assert "PassphraseKeyboard" in self.all_components()
         return self.find_unique_value_by_key("passphrase", default="", only_type=str)
 
+    def passphrase_space_warning(self) -> Optional[str]:
+        """Get the kind of the spaces warning shown by the passphrase keyboard, if any."""
+        assert "PassphraseKeyboard" in self.all_components()
+        return self.find_unique_value_by_key(
+            "space_warning", default=None, only_type=str
+        )
+
+    def mnemonic_progress(self) -> Optional[Tuple[int, int]]:
+        """Get the number of words entered and the word count shown by
+        the mnemonic keyboard, if any."""
+        assert "MnemonicKeyboard" in self.all_components()
+        word_count = self.find_unique_value_by_key(
+            "word_count", default=None, only_type=int
+        )
+        if word_count is None:
+            return None
+        word_index = self.find_unique_value_by_key(
+            "word_index", default=0, only_type=int
+        )
+        return word_index, word_count
+
+    def keyboard_button(self, name: str) -> "AnyDict":
+        """Get a control button of the keyboard, e.g. "confirm", "cancel"
+        or "erase". Contains its "enabled" and "visible" state.
+        """
+        keyboards = [
+            keyboard
+            for component in ("PinKeyboard", "PassphraseKeyboard", "MnemonicKeyboard")
+            for keyboard in self.find_objects_with_key_and_value("component", component)
+        ]
+        assert len(keyboards) == 1
+        button = keyboards[0].get(name)
+        if button is None:
+            raise RuntimeError(f"No {name} button in keyboard trace")
+        # Buttons that can be hidden are wrapped in `Maybe`
+        if button.get("component") == "Maybe":
+            return {**button["inner"], "visible": button["visible"]}
+        return {**button, "visible": True}
+
     def page_count(self) -> int:
         """Get number of pages for the layout."""
         return (
@@ -474,6 +557,19 @@ class DebugLink:
             return self._call(messages.DebugLinkResetDebugEvents())
         return None
 
+    def show_bounds(self, show: bool = True) -> None:
+        """Draw the outlines of the components and their touch areas over the
+        layout, to see what a click is going to hit."""
+        self._call(messages.DebugLinkShowBounds(show=show))
+
+    def keyboard(self, input: Optional[str] = None) -> str:
+        """Type `input` into the keyboard of the current layout, as if its keys
+        were tapped, and return what the keyboard holds afterwards."""
+        state = self._call(messages.DebugLinkKeyboard(input=input))
+        if isinstance(state, messages.Failure):
+            raise TrezorFailure(state)
+        return state.text or ""
+
     def synchronize_at(self, layout_text: str, timeout: float = 5) -> LayoutContent:
         now = time.monotonic()
         while True:
@@ -521,14 +617,17 @@ class DebugLink:
         y: Optional[int] = None,
         wait: Optional[bool] = None,
         hold_ms: Optional[int] = None,
+        target: Optional[str] = None,
     ) -> Optional[LayoutContent]:
         if not self.allow_interactions:
             return None
 
-        args = sum(a is not None for a in (word, button, physical_button, swipe, x))
+        args = sum(
+            a is not None for a in (word, button, physical_button, swipe, x, target)
+        )
         if args != 1:
             raise ValueError(
-                "Invalid input - must use one of word, button, physical_button, swipe, click(x,y)"
+                "Invalid input - must use one of word, button, physical_button, swipe, click(x,y), target"
             )
 
         decision = messages.DebugLinkDecision(
@@ -540,6 +639,7 @@ class DebugLink:
             y=y,
             wait=wait,
             hold_ms=hold_ms,
+            target=target,
         )
 
         ret = self._call(decision, nowait=not wait)
@@ -594,6 +694,17 @@ class DebugLink:
         x, y = click
         return self.input(x=x, y=y, wait=wait)
 
+    @overload
+    def click_target(self, name: str) -> None: ...
+
+    @overload
+    def click_target(self, name: str, wait: Literal[True]) -> LayoutContent: ...
+
+    def click_target(self, name: str, wait: bool = False) -> Optional[LayoutContent]:
+        """Click the component registered under the debug target `name`, see
+        `LayoutContent.debug_targets`."""
+        return self.input(target=name, wait=wait)
+
     # Made into separate function as `hold_ms: Optional[int]` in `click`
     # was causing problems with @overload
     def click_hold(
@@ -48,6 +48,13 @@ def apply_settings(
     experimental_features: Optional[bool] = None,
     hide_passphrase_from_host: Optional[bool] = None,
     haptic_feedback: Optional[bool] = None,
+    led_signals: Optional[bool] = None,
+    high_contrast: Optional[bool] = None,
+    keyboard_erase_hold_ms: Optional[int] = None,
+    keyboard_multi_tap_timeout_ms: Optional[int] = None,
+    keyboard_last_char_reveal_ms: Optional[int] = None,
+    keyboard_key_debounce_ms: Optional[int] = None,
+    large_text: Optional[bool] = None,
 ) -> "MessageType":
     if language is not None:
         warnings.warn(
@@ -65,6 +72,13 @@ def apply_settings(
         experimental_features=experimental_features,
         hide_passphrase_from_host=hide_passphrase_from_host,
         haptic_feedback=haptic_feedback,
+        led_signals=led_signals,
+        high_contrast=high_contrast,
+        keyboard_erase_hold_ms=keyboard_erase_hold_ms,
+        keyboard_multi_tap_timeout_ms=keyboard_multi_tap_timeout_ms,
+        keyboard_last_char_reveal_ms=keyboard_last_char_reveal_ms,
+        keyboard_key_debounce_ms=keyboard_key_debounce_ms,
+        large_text=large_text,
     )
 
     out = client.call(settings)
@@ -129,6 +129,9 @@ class MessageType(IntEnum):
     DebugLinkEraseSdCard = 9005
     DebugLinkWatchLayout = 9006
     DebugLinkResetDebugEvents = 9007
+    DebugLinkShowBounds = 9008
+    DebugLinkKeyboard = 9009
+    DebugLinkKeyboardState = 9010
     EthereumGetPublicKey = 450
     EthereumPublicKey = 451
     EthereumGetAddress = 56
@@ -3431,6 +3434,13 @@ class ApplySettings(protobuf.MessageType):
         10: protobuf.Field("experimental_features", "bool", repeated=False, required=False, default=None),
         11: protobuf.Field("hide_passphrase_from_host", "bool", repeated=False, required=False, default=None),
         13: protobuf.Field("haptic_feedback", "bool", repeated=False, required=False, default=None),
+        14: protobuf.Field("led_signals", "bool", repeated=False, required=False, default=None),
+        15: protobuf.Field("high_contrast", "bool", repeated=False, required=False, default=None),
+        16: protobuf.Field("keyboard_erase_hold_ms", "uint32", repeated=False, required=False, default=None),
+        17: protobuf.Field("keyboard_multi_tap_timeout_ms", "uint32", repeated=False, required=False, default=None),
+        18: protobuf.Field("large_text", "bool", repeated=False, required=False, default=None),
+        19: protobuf.Field("keyboard_last_char_reveal_ms", "uint32", repeated=False, required=False, default=None),
+        20: protobuf.Field("keyboard_key_debounce_ms", "uint32", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3448,6 +3458,13 @@ class ApplySettings(protobuf.MessageType):
         experimental_features: Optional["bool"] = None,
         hide_passphrase_from_host: Optional["bool"] = None,
         haptic_feedback: Optional["bool"] = None,
+        led_signals: Optional["bool"] = None,
+        high_contrast: Optional["bool"] = None,
+        keyboard_erase_hold_ms: Optional["int"] = None,
+        keyboard_multi_tap_timeout_ms: Optional["int"] = None,
+        large_text: Optional["bool"] = None,
+        keyboard_last_char_reveal_ms: Optional["int"] = None,
+        keyboard_key_debounce_ms: Optional["int"] = None,
     ) -> None:
         self.language = language
         self.label = label
@@ -3461,6 +3478,13 @@ class ApplySettings(protobuf.MessageType):
         self.experimental_features = experimental_features
         self.hide_passphrase_from_host = hide_passphrase_from_host
         self.haptic_feedback = haptic_feedback
+        self.led_signals = led_signals
+        self.high_contrast = high_contrast
+        self.keyboard_erase_hold_ms = keyboard_erase_hold_ms
+        self.keyboard_multi_tap_timeout_ms = keyboard_multi_tap_timeout_ms
+        self.large_text = large_text
+        self.keyboard_last_char_reveal_ms = keyboard_last_char_reveal_ms
+        self.keyboard_key_debounce_ms = keyboard_key_debounce_ms
 
 
 class ChangeLanguage(protobuf.MessageType):
@@ -4022,6 +4046,7 @@ class DebugLinkDecision(protobuf.MessageType):
         6: protobuf.Field("wait", "bool", repeated=False, required=False, default=None),
         7: protobuf.Field("hold_ms", "uint32", repeated=False, required=False, default=None),
         8: protobuf.Field("physical_button", "DebugPhysicalButton", repeated=False, required=False, default=None),
+        9: protobuf.Field("target", "string", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -4035,6 +4060,7 @@ class DebugLinkDecision(protobuf.MessageType):
         wait: Optional["bool"] = None,
         hold_ms: Optional["int"] = None,
         physical_button: Optional["DebugPhysicalButton"] = None,
+        target: Optional["str"] = None,
     ) -> None:
         self.button = button
         self.swipe = swipe
@@ -4044,6 +4070,7 @@ class DebugLinkDecision(protobuf.MessageType):
         self.wait = wait
         self.hold_ms = hold_ms
         self.physical_button = physical_button
+        self.target = target
 
 
 class DebugLinkLayout(protobuf.MessageType):
@@ -4282,6 +4309,48 @@ class DebugLinkResetDebugEvents(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 9007
 
 
+class DebugLinkShowBounds(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9008
+    FIELDS = {
+        1: protobuf.Field("show", "bool", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        show: Optional["bool"] = None,
+    ) -> None:
+        self.show = show
+
+
+class DebugLinkKeyboard(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9009
+    FIELDS = {
+        1: protobuf.Field("input", "string", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        input: Optional["str"] = None,
+    ) -> None:
+        self.input = input
+
+
+class DebugLinkKeyboardState(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9010
+    FIELDS = {
+        1: protobuf.Field("text", "string", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        text: Optional["str"] = None,
+    ) -> None:
+        self.text = text
+
+
 class EosGetPublicKey(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 600
     FIELDS = {
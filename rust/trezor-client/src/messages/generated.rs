@@ -83,6 +83,9 @@ trezor_message_impl! {
     DebugLinkEraseSdCard => MessageType_DebugLinkEraseSdCard,
     DebugLinkWatchLayout => MessageType_DebugLinkWatchLayout,
     DebugLinkResetDebugEvents => MessageType_DebugLinkResetDebugEvents,
+    DebugLinkShowBounds => MessageType_DebugLinkShowBounds,
+    DebugLinkKeyboard => MessageType_DebugLinkKeyboard,
+    DebugLinkKeyboardState => MessageType_DebugLinkKeyboardState,
 }
 
 #[cfg(feature = "binance")]
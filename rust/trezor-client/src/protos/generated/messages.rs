@@ -228,6 +228,12 @@ pub enum MessageType {
     MessageType_DebugLinkWatchLayout = 9006,
     // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_DebugLinkResetDebugEvents)
     MessageType_DebugLinkResetDebugEvents = 9007,
+    // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_DebugLinkShowBounds)
+    MessageType_DebugLinkShowBounds = 9008,
+    // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_DebugLinkKeyboard)
+    MessageType_DebugLinkKeyboard = 9009,
+    // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_DebugLinkKeyboardState)
+    MessageType_DebugLinkKeyboardState = 9010,
     // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_EthereumGetPublicKey)
     MessageType_EthereumGetPublicKey = 450,
     // @@protoc_insertion_point(enum_value:hw.trezor.messages.MessageType.MessageType_EthereumPublicKey)
@@ -627,6 +633,9 @@ impl ::protobuf::Enum for MessageType {
             9005 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkEraseSdCard),
             9006 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkWatchLayout),
             9007 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkResetDebugEvents),
+            9008 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkShowBounds),
+            9009 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkKeyboard),
+            9010 => ::std::option::Option::Some(MessageType::MessageType_DebugLinkKeyboardState),
             450 => ::std::option::Option::Some(MessageType::MessageType_EthereumGetPublicKey),
             451 => ::std::option::Option::Some(MessageType::MessageType_EthereumPublicKey),
             56 => ::std::option::Option::Some(MessageType::MessageType_EthereumGetAddress),
@@ -877,6 +886,9 @@ impl ::protobuf::Enum for MessageType {
             "MessageType_DebugLinkEraseSdCard" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkEraseSdCard),
             "MessageType_DebugLinkWatchLayout" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkWatchLayout),
             "MessageType_DebugLinkResetDebugEvents" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkResetDebugEvents),
+            "MessageType_DebugLinkShowBounds" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkShowBounds),
+            "MessageType_DebugLinkKeyboard" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkKeyboard),
+            "MessageType_DebugLinkKeyboardState" => ::std::option::Option::Some(MessageType::MessageType_DebugLinkKeyboardState),
             "MessageType_EthereumGetPublicKey" => ::std::option::Option::Some(MessageType::MessageType_EthereumGetPublicKey),
             "MessageType_EthereumPublicKey" => ::std::option::Option::Some(MessageType::MessageType_EthereumPublicKey),
             "MessageType_EthereumGetAddress" => ::std::option::Option::Some(MessageType::MessageType_EthereumGetAddress),
@@ -1126,6 +1138,9 @@ impl ::protobuf::Enum for MessageType {
         MessageType::MessageType_DebugLinkEraseSdCard,
         MessageType::MessageType_DebugLinkWatchLayout,
         MessageType::MessageType_DebugLinkResetDebugEvents,
+        MessageType::MessageType_DebugLinkShowBounds,
+        MessageType::MessageType_DebugLinkKeyboard,
+        MessageType::MessageType_DebugLinkKeyboardState,
         MessageType::MessageType_EthereumGetPublicKey,
         MessageType::MessageType_EthereumPublicKey,
         MessageType::MessageType_EthereumGetAddress,
@@ -1381,150 +1396,153 @@ impl ::protobuf::EnumFull for MessageType {
             MessageType::MessageType_DebugLinkEraseSdCard => 97,
             MessageType::MessageType_DebugLinkWatchLayout => 98,
             MessageType::MessageType_DebugLinkResetDebugEvents => 99,
-            MessageType::MessageType_EthereumGetPublicKey => 100,
-            MessageType::MessageType_EthereumPublicKey => 101,
-            MessageType::MessageType_EthereumGetAddress => 102,
-            MessageType::MessageType_EthereumAddress => 103,
-            MessageType::MessageType_EthereumSignTx => 104,
-            MessageType::MessageType_EthereumSignTxEIP1559 => 105,
-            MessageType::MessageType_EthereumTxRequest => 106,
-            MessageType::MessageType_EthereumTxAck => 107,
-            MessageType::MessageType_EthereumSignMessage => 108,
-            MessageType::MessageType_EthereumVerifyMessage => 109,
-            MessageType::MessageType_EthereumMessageSignature => 110,
-            MessageType::MessageType_EthereumSignTypedData => 111,
-            MessageType::MessageType_EthereumTypedDataStructRequest => 112,
-            MessageType::MessageType_EthereumTypedDataStructAck => 113,
-            MessageType::MessageType_EthereumTypedDataValueRequest => 114,
-            MessageType::MessageType_EthereumTypedDataValueAck => 115,
-            MessageType::MessageType_EthereumTypedDataSignature => 116,
-            MessageType::MessageType_EthereumSignTypedHash => 117,
-            MessageType::MessageType_NEMGetAddress => 118,
-            MessageType::MessageType_NEMAddress => 119,
-            MessageType::MessageType_NEMSignTx => 120,
-            MessageType::MessageType_NEMSignedTx => 121,
-            MessageType::MessageType_NEMDecryptMessage => 122,
-            MessageType::MessageType_NEMDecryptedMessage => 123,
-            MessageType::MessageType_TezosGetAddress => 124,
-            MessageType::MessageType_TezosAddress => 125,
-            MessageType::MessageType_TezosSignTx => 126,
-            MessageType::MessageType_TezosSignedTx => 127,
-            MessageType::MessageType_TezosGetPublicKey => 128,
-            MessageType::MessageType_TezosPublicKey => 129,
-            MessageType::MessageType_StellarSignTx => 130,
-            MessageType::MessageType_StellarTxOpRequest => 131,
-            MessageType::MessageType_StellarGetAddress => 132,
-            MessageType::MessageType_StellarAddress => 133,
-            MessageType::MessageType_StellarCreateAccountOp => 134,
-            MessageType::MessageType_StellarPaymentOp => 135,
-            MessageType::MessageType_StellarPathPaymentStrictReceiveOp => 136,
-            MessageType::MessageType_StellarManageSellOfferOp => 137,
-            MessageType::MessageType_StellarCreatePassiveSellOfferOp => 138,
-            MessageType::MessageType_StellarSetOptionsOp => 139,
-            MessageType::MessageType_StellarChangeTrustOp => 140,
-            MessageType::MessageType_StellarAllowTrustOp => 141,
-            MessageType::MessageType_StellarAccountMergeOp => 142,
-            MessageType::MessageType_StellarManageDataOp => 143,
-            MessageType::MessageType_StellarBumpSequenceOp => 144,
-            MessageType::MessageType_StellarManageBuyOfferOp => 145,
-            MessageType::MessageType_StellarPathPaymentStrictSendOp => 146,
-            MessageType::MessageType_StellarClaimClaimableBalanceOp => 147,
-            MessageType::MessageType_StellarSignedTx => 148,
-            MessageType::MessageType_CardanoGetPublicKey => 149,
-            MessageType::MessageType_CardanoPublicKey => 150,
-            MessageType::MessageType_CardanoGetAddress => 151,
-            MessageType::MessageType_CardanoAddress => 152,
-            MessageType::MessageType_CardanoTxItemAck => 153,
-            MessageType::MessageType_CardanoTxAuxiliaryDataSupplement => 154,
-            MessageType::MessageType_CardanoTxWitnessRequest => 155,
-            MessageType::MessageType_CardanoTxWitnessResponse => 156,
-            MessageType::MessageType_CardanoTxHostAck => 157,
-            MessageType::MessageType_CardanoTxBodyHash => 158,
-            MessageType::MessageType_CardanoSignTxFinished => 159,
-            MessageType::MessageType_CardanoSignTxInit => 160,
-            MessageType::MessageType_CardanoTxInput => 161,
-            MessageType::MessageType_CardanoTxOutput => 162,
-            MessageType::MessageType_CardanoAssetGroup => 163,
-            MessageType::MessageType_CardanoToken => 164,
-            MessageType::MessageType_CardanoTxCertificate => 165,
-            MessageType::MessageType_CardanoTxWithdrawal => 166,
-            MessageType::MessageType_CardanoTxAuxiliaryData => 167,
-            MessageType::MessageType_CardanoPoolOwner => 168,
-            MessageType::MessageType_CardanoPoolRelayParameters => 169,
-            MessageType::MessageType_CardanoGetNativeScriptHash => 170,
-            MessageType::MessageType_CardanoNativeScriptHash => 171,
-            MessageType::MessageType_CardanoTxMint => 172,
-            MessageType::MessageType_CardanoTxCollateralInput => 173,
-            MessageType::MessageType_CardanoTxRequiredSigner => 174,
-            MessageType::MessageType_CardanoTxInlineDatumChunk => 175,
-            MessageType::MessageType_CardanoTxReferenceScriptChunk => 176,
-            MessageType::MessageType_CardanoTxReferenceInput => 177,
-            MessageType::MessageType_RippleGetAddress => 178,
-            MessageType::MessageType_RippleAddress => 179,
-            MessageType::MessageType_RippleSignTx => 180,
-            MessageType::MessageType_RippleSignedTx => 181,
-            MessageType::MessageType_MoneroTransactionInitRequest => 182,
-            MessageType::MessageType_MoneroTransactionInitAck => 183,
-            MessageType::MessageType_MoneroTransactionSetInputRequest => 184,
-            MessageType::MessageType_MoneroTransactionSetInputAck => 185,
-            MessageType::MessageType_MoneroTransactionInputViniRequest => 186,
-            MessageType::MessageType_MoneroTransactionInputViniAck => 187,
-            MessageType::MessageType_MoneroTransactionAllInputsSetRequest => 188,
-            MessageType::MessageType_MoneroTransactionAllInputsSetAck => 189,
-            MessageType::MessageType_MoneroTransactionSetOutputRequest => 190,
-            MessageType::MessageType_MoneroTransactionSetOutputAck => 191,
-            MessageType::MessageType_MoneroTransactionAllOutSetRequest => 192,
-            MessageType::MessageType_MoneroTransactionAllOutSetAck => 193,
-            MessageType::MessageType_MoneroTransactionSignInputRequest => 194,
-            MessageType::MessageType_MoneroTransactionSignInputAck => 195,
-            MessageType::MessageType_MoneroTransactionFinalRequest => 196,
-            MessageType::MessageType_MoneroTransactionFinalAck => 197,
-            MessageType::MessageType_MoneroKeyImageExportInitRequest => 198,
-            MessageType::MessageType_MoneroKeyImageExportInitAck => 199,
-            MessageType::MessageType_MoneroKeyImageSyncStepRequest => 200,
-            MessageType::MessageType_MoneroKeyImageSyncStepAck => 201,
-            MessageType::MessageType_MoneroKeyImageSyncFinalRequest => 202,
-            MessageType::MessageType_MoneroKeyImageSyncFinalAck => 203,
-            MessageType::MessageType_MoneroGetAddress => 204,
-            MessageType::MessageType_MoneroAddress => 205,
-            MessageType::MessageType_MoneroGetWatchKey => 206,
-            MessageType::MessageType_MoneroWatchKey => 207,
-            MessageType::MessageType_DebugMoneroDiagRequest => 208,
-            MessageType::MessageType_DebugMoneroDiagAck => 209,
-            MessageType::MessageType_MoneroGetTxKeyRequest => 210,
-            MessageType::MessageType_MoneroGetTxKeyAck => 211,
-            MessageType::MessageType_MoneroLiveRefreshStartRequest => 212,
-            MessageType::MessageType_MoneroLiveRefreshStartAck => 213,
-            MessageType::MessageType_MoneroLiveRefreshStepRequest => 214,
-            MessageType::MessageType_MoneroLiveRefreshStepAck => 215,
-            MessageType::MessageType_MoneroLiveRefreshFinalRequest => 216,
-            MessageType::MessageType_MoneroLiveRefreshFinalAck => 217,
-            MessageType::MessageType_EosGetPublicKey => 218,
-            MessageType::MessageType_EosPublicKey => 219,
-            MessageType::MessageType_EosSignTx => 220,
-            MessageType::MessageType_EosTxActionRequest => 221,
-            MessageType::MessageType_EosTxActionAck => 222,
-            MessageType::MessageType_EosSignedTx => 223,
-            MessageType::MessageType_BinanceGetAddress => 224,
-            MessageType::MessageType_BinanceAddress => 225,
-            MessageType::MessageType_BinanceGetPublicKey => 226,
-            MessageType::MessageType_BinancePublicKey => 227,
-            MessageType::MessageType_BinanceSignTx => 228,
-            MessageType::MessageType_BinanceTxRequest => 229,
-            MessageType::MessageType_BinanceTransferMsg => 230,
-            MessageType::MessageType_BinanceOrderMsg => 231,
-            MessageType::MessageType_BinanceCancelMsg => 232,
-            MessageType::MessageType_BinanceSignedTx => 233,
-            MessageType::MessageType_WebAuthnListResidentCredentials => 234,
-            MessageType::MessageType_WebAuthnCredentials => 235,
-            MessageType::MessageType_WebAuthnAddResidentCredential => 236,
-            MessageType::MessageType_WebAuthnRemoveResidentCredential => 237,
-            MessageType::MessageType_SolanaGetPublicKey => 238,
-            MessageType::MessageType_SolanaPublicKey => 239,
-            MessageType::MessageType_SolanaGetAddress => 240,
-            MessageType::MessageType_SolanaAddress => 241,
-            MessageType::MessageType_SolanaSignTx => 242,
-            MessageType::MessageType_SolanaTxSignature => 243,
+            MessageType::MessageType_DebugLinkShowBounds => 100,
+            MessageType::MessageType_DebugLinkKeyboard => 101,
+            MessageType::MessageType_DebugLinkKeyboardState => 102,
+            MessageType::MessageType_EthereumGetPublicKey => 103,
+            MessageType::MessageType_EthereumPublicKey => 104,
+            MessageType::MessageType_EthereumGetAddress => 105,
+            MessageType::MessageType_EthereumAddress => 106,
+            MessageType::MessageType_EthereumSignTx => 107,
+            MessageType::MessageType_EthereumSignTxEIP1559 => 108,
+            MessageType::MessageType_EthereumTxRequest => 109,
+            MessageType::MessageType_EthereumTxAck => 110,
+            MessageType::MessageType_EthereumSignMessage => 111,
+            MessageType::MessageType_EthereumVerifyMessage => 112,
+            MessageType::MessageType_EthereumMessageSignature => 113,
+            MessageType::MessageType_EthereumSignTypedData => 114,
+            MessageType::MessageType_EthereumTypedDataStructRequest => 115,
+            MessageType::MessageType_EthereumTypedDataStructAck => 116,
+            MessageType::MessageType_EthereumTypedDataValueRequest => 117,
+            MessageType::MessageType_EthereumTypedDataValueAck => 118,
+            MessageType::MessageType_EthereumTypedDataSignature => 119,
+            MessageType::MessageType_EthereumSignTypedHash => 120,
+            MessageType::MessageType_NEMGetAddress => 121,
+            MessageType::MessageType_NEMAddress => 122,
+            MessageType::MessageType_NEMSignTx => 123,
+            MessageType::MessageType_NEMSignedTx => 124,
+            MessageType::MessageType_NEMDecryptMessage => 125,
+            MessageType::MessageType_NEMDecryptedMessage => 126,
+            MessageType::MessageType_TezosGetAddress => 127,
+            MessageType::MessageType_TezosAddress => 128,
+            MessageType::MessageType_TezosSignTx => 129,
+            MessageType::MessageType_TezosSignedTx => 130,
+            MessageType::MessageType_TezosGetPublicKey => 131,
+            MessageType::MessageType_TezosPublicKey => 132,
+            MessageType::MessageType_StellarSignTx => 133,
+            MessageType::MessageType_StellarTxOpRequest => 134,
+            MessageType::MessageType_StellarGetAddress => 135,
+            MessageType::MessageType_StellarAddress => 136,
+            MessageType::MessageType_StellarCreateAccountOp => 137,
+            MessageType::MessageType_StellarPaymentOp => 138,
+            MessageType::MessageType_StellarPathPaymentStrictReceiveOp => 139,
+            MessageType::MessageType_StellarManageSellOfferOp => 140,
+            MessageType::MessageType_StellarCreatePassiveSellOfferOp => 141,
+            MessageType::MessageType_StellarSetOptionsOp => 142,
+            MessageType::MessageType_StellarChangeTrustOp => 143,
+            MessageType::MessageType_StellarAllowTrustOp => 144,
+            MessageType::MessageType_StellarAccountMergeOp => 145,
+            MessageType::MessageType_StellarManageDataOp => 146,
+            MessageType::MessageType_StellarBumpSequenceOp => 147,
+            MessageType::MessageType_StellarManageBuyOfferOp => 148,
+            MessageType::MessageType_StellarPathPaymentStrictSendOp => 149,
+            MessageType::MessageType_StellarClaimClaimableBalanceOp => 150,
+            MessageType::MessageType_StellarSignedTx => 151,
+            MessageType::MessageType_CardanoGetPublicKey => 152,
+            MessageType::MessageType_CardanoPublicKey => 153,
+            MessageType::MessageType_CardanoGetAddress => 154,
+            MessageType::MessageType_CardanoAddress => 155,
+            MessageType::MessageType_CardanoTxItemAck => 156,
+            MessageType::MessageType_CardanoTxAuxiliaryDataSupplement => 157,
+            MessageType::MessageType_CardanoTxWitnessRequest => 158,
+            MessageType::MessageType_CardanoTxWitnessResponse => 159,
+            MessageType::MessageType_CardanoTxHostAck => 160,
+            MessageType::MessageType_CardanoTxBodyHash => 161,
+            MessageType::MessageType_CardanoSignTxFinished => 162,
+            MessageType::MessageType_CardanoSignTxInit => 163,
+            MessageType::MessageType_CardanoTxInput => 164,
+            MessageType::MessageType_CardanoTxOutput => 165,
+            MessageType::MessageType_CardanoAssetGroup => 166,
+            MessageType::MessageType_CardanoToken => 167,
+            MessageType::MessageType_CardanoTxCertificate => 168,
+            MessageType::MessageType_CardanoTxWithdrawal => 169,
+            MessageType::MessageType_CardanoTxAuxiliaryData => 170,
+            MessageType::MessageType_CardanoPoolOwner => 171,
+            MessageType::MessageType_CardanoPoolRelayParameters => 172,
+            MessageType::MessageType_CardanoGetNativeScriptHash => 173,
+            MessageType::MessageType_CardanoNativeScriptHash => 174,
+            MessageType::MessageType_CardanoTxMint => 175,
+            MessageType::MessageType_CardanoTxCollateralInput => 176,
+            MessageType::MessageType_CardanoTxRequiredSigner => 177,
+            MessageType::MessageType_CardanoTxInlineDatumChunk => 178,
+            MessageType::MessageType_CardanoTxReferenceScriptChunk => 179,
+            MessageType::MessageType_CardanoTxReferenceInput => 180,
+            MessageType::MessageType_RippleGetAddress => 181,
+            MessageType::MessageType_RippleAddress => 182,
+            MessageType::MessageType_RippleSignTx => 183,
+            MessageType::MessageType_RippleSignedTx => 184,
+            MessageType::MessageType_MoneroTransactionInitRequest => 185,
+            MessageType::MessageType_MoneroTransactionInitAck => 186,
+            MessageType::MessageType_MoneroTransactionSetInputRequest => 187,
+            MessageType::MessageType_MoneroTransactionSetInputAck => 188,
+            MessageType::MessageType_MoneroTransactionInputViniRequest => 189,
+            MessageType::MessageType_MoneroTransactionInputViniAck => 190,
+            MessageType::MessageType_MoneroTransactionAllInputsSetRequest => 191,
+            MessageType::MessageType_MoneroTransactionAllInputsSetAck => 192,
+            MessageType::MessageType_MoneroTransactionSetOutputRequest => 193,
+            MessageType::MessageType_MoneroTransactionSetOutputAck => 194,
+            MessageType::MessageType_MoneroTransactionAllOutSetRequest => 195,
+            MessageType::MessageType_MoneroTransactionAllOutSetAck => 196,
+            MessageType::MessageType_MoneroTransactionSignInputRequest => 197,
+            MessageType::MessageType_MoneroTransactionSignInputAck => 198,
+            MessageType::MessageType_MoneroTransactionFinalRequest => 199,
+            MessageType::MessageType_MoneroTransactionFinalAck => 200,
+            MessageType::MessageType_MoneroKeyImageExportInitRequest => 201,
+            MessageType::MessageType_MoneroKeyImageExportInitAck => 202,
+            MessageType::MessageType_MoneroKeyImageSyncStepRequest => 203,
+            MessageType::MessageType_MoneroKeyImageSyncStepAck => 204,
+            MessageType::MessageType_MoneroKeyImageSyncFinalRequest => 205,
+            MessageType::MessageType_MoneroKeyImageSyncFinalAck => 206,
+            MessageType::MessageType_MoneroGetAddress => 207,
+            MessageType::MessageType_MoneroAddress => 208,
+            MessageType::MessageType_MoneroGetWatchKey => 209,
+            MessageType::MessageType_MoneroWatchKey => 210,
+            MessageType::MessageType_DebugMoneroDiagRequest => 211,
+            MessageType::MessageType_DebugMoneroDiagAck => 212,
+            MessageType::MessageType_MoneroGetTxKeyRequest => 213,
+            MessageType::MessageType_MoneroGetTxKeyAck => 214,
+            MessageType::MessageType_MoneroLiveRefreshStartRequest => 215,
+            MessageType::MessageType_MoneroLiveRefreshStartAck => 216,
+            MessageType::MessageType_MoneroLiveRefreshStepRequest => 217,
+            MessageType::MessageType_MoneroLiveRefreshStepAck => 218,
+            MessageType::MessageType_MoneroLiveRefreshFinalRequest => 219,
+            MessageType::MessageType_MoneroLiveRefreshFinalAck => 220,
+            MessageType::MessageType_EosGetPublicKey => 221,
+            MessageType::MessageType_EosPublicKey => 222,
+            MessageType::MessageType_EosSignTx => 223,
+            MessageType::MessageType_EosTxActionRequest => 224,
+            MessageType::MessageType_EosTxActionAck => 225,
+            MessageType::MessageType_EosSignedTx => 226,
+            MessageType::MessageType_BinanceGetAddress => 227,
+            MessageType::MessageType_BinanceAddress => 228,
+            MessageType::MessageType_BinanceGetPublicKey => 229,
+            MessageType::MessageType_BinancePublicKey => 230,
+            MessageType::MessageType_BinanceSignTx => 231,
+            MessageType::MessageType_BinanceTxRequest => 232,
+            MessageType::MessageType_BinanceTransferMsg => 233,
+            MessageType::MessageType_BinanceOrderMsg => 234,
+            MessageType::MessageType_BinanceCancelMsg => 235,
+            MessageType::MessageType_BinanceSignedTx => 236,
+            MessageType::MessageType_WebAuthnListResidentCredentials => 237,
+            MessageType::MessageType_WebAuthnCredentials => 238,
+            MessageType::MessageType_WebAuthnAddResidentCredential => 239,
+            MessageType::MessageType_WebAuthnRemoveResidentCredential => 240,
+            MessageType::MessageType_SolanaGetPublicKey => 241,
+            MessageType::MessageType_SolanaPublicKey => 242,
+            MessageType::MessageType_SolanaGetAddress => 243,
+            MessageType::MessageType_SolanaAddress => 244,
+            MessageType::MessageType_SolanaSignTx => 245,
+            MessageType::MessageType_SolanaTxSignature => 246,
         };
         Self::enum_descriptor().value_by_index(index)
     }
@@ -1574,7 +1592,7 @@ pub mod exts {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0emessages.proto\x12\x12hw.trezor.messages\x1a\x20google/protobuf/de\
-    scriptor.proto*\xc4T\n\x0bMessageType\x12(\n\x16MessageType_Initialize\
+    scriptor.proto*\xd5U\n\x0bMessageType\x12(\n\x16MessageType_Initialize\
     \x10\0\x1a\x0c\x80\xa6\x1d\x01\xb0\xb5\x18\x01\x90\xb5\x18\x01\x12\x1e\n\
     \x10MessageType_Ping\x10\x01\x1a\x08\x80\xa6\x1d\x01\x90\xb5\x18\x01\x12\
     %\n\x13MessageType_Success\x10\x02\x1a\x0c\x80\xa6\x1d\x01\xa8\xb5\x18\
@@ -1693,27 +1711,31 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     DebugLinkEraseSdCard\x10\xadF\x1a\x08\x80\xa6\x1d\x01\xa0\xb5\x18\x01\
     \x12/\n\x20MessageType_DebugLinkWatchLayout\x10\xaeF\x1a\x08\x80\xa6\x1d\
     \x01\xa0\xb5\x18\x01\x124\n%MessageType_DebugLinkResetDebugEvents\x10\
-    \xafF\x1a\x08\x80\xa6\x1d\x01\xa0\xb5\x18\x01\x12+\n\x20MessageType_Ethe\
-    reumGetPublicKey\x10\xc2\x03\x1a\x04\x90\xb5\x18\x01\x12(\n\x1dMessageTy\
-    pe_EthereumPublicKey\x10\xc3\x03\x1a\x04\x98\xb5\x18\x01\x12(\n\x1eMessa\
-    geType_EthereumGetAddress\x108\x1a\x04\x90\xb5\x18\x01\x12%\n\x1bMessage\
-    Type_EthereumAddress\x109\x1a\x04\x98\xb5\x18\x01\x12$\n\x1aMessageType_\
-    EthereumSignTx\x10:\x1a\x04\x90\xb5\x18\x01\x12,\n!MessageType_EthereumS\
-    ignTxEIP1559\x10\xc4\x03\x1a\x04\x90\xb5\x18\x01\x12'\n\x1dMessageType_E\
-    thereumTxRequest\x10;\x1a\x04\x98\xb5\x18\x01\x12#\n\x19MessageType_Ethe\
-    reumTxAck\x10<\x1a\x04\x90\xb5\x18\x01\x12)\n\x1fMessageType_EthereumSig\
-    nMessage\x10@\x1a\x04\x90\xb5\x18\x01\x12+\n!MessageType_EthereumVerifyM\
-    essage\x10A\x1a\x04\x90\xb5\x18\x01\x12.\n$MessageType_EthereumMessageSi\
-    gnature\x10B\x1a\x04\x98\xb5\x18\x01\x12,\n!MessageType_EthereumSignType\
-    dData\x10\xd0\x03\x1a\x04\x90\xb5\x18\x01\x125\n*MessageType_EthereumTyp\
-    edDataStructRequest\x10\xd1\x03\x1a\x04\x98\xb5\x18\x01\x121\n&MessageTy\
-    pe_EthereumTypedDataStructAck\x10\xd2\x03\x1a\x04\x90\xb5\x18\x01\x124\n\
-    )MessageType_EthereumTypedDataValueRequest\x10\xd3\x03\x1a\x04\x98\xb5\
-    \x18\x01\x120\n%MessageType_EthereumTypedDataValueAck\x10\xd4\x03\x1a\
-    \x04\x90\xb5\x18\x01\x121\n&MessageType_EthereumTypedDataSignature\x10\
-    \xd5\x03\x1a\x04\x98\xb5\x18\x01\x12,\n!MessageType_EthereumSignTypedHas\
-    h\x10\xd6\x03\x1a\x04\x90\xb5\x18\x01\x12#\n\x19MessageType_NEMGetAddres\
-    s\x10C\x1a\x04\x90\xb5\x18\x01\x12\x20\n\x16MessageType_NEMAddress\x10D\
+    \xafF\x1a\x08\x80\xa6\x1d\x01\xa0\xb5\x18\x01\x12.\n\x1fMessageType_Debu\
+    gLinkShowBounds\x10\xb0F\x1a\x08\x80\xa6\x1d\x01\xa0\xb5\x18\x01\x12,\n\
+    \x1dMessageType_DebugLinkKeyboard\x10\xb1F\x1a\x08\x80\xa6\x1d\x01\xa0\
+    \xb5\x18\x01\x121\n\"MessageType_DebugLinkKeyboardState\x10\xb2F\x1a\x08\
+    \x80\xa6\x1d\x01\xa8\xb5\x18\x01\x12+\n\x20MessageType_EthereumGetPublic\
+    Key\x10\xc2\x03\x1a\x04\x90\xb5\x18\x01\x12(\n\x1dMessageType_EthereumPu\
+    blicKey\x10\xc3\x03\x1a\x04\x98\xb5\x18\x01\x12(\n\x1eMessageType_Ethere\
+    umGetAddress\x108\x1a\x04\x90\xb5\x18\x01\x12%\n\x1bMessageType_Ethereum\
+    Address\x109\x1a\x04\x98\xb5\x18\x01\x12$\n\x1aMessageType_EthereumSignT\
+    x\x10:\x1a\x04\x90\xb5\x18\x01\x12,\n!MessageType_EthereumSignTxEIP1559\
+    \x10\xc4\x03\x1a\x04\x90\xb5\x18\x01\x12'\n\x1dMessageType_EthereumTxReq\
+    uest\x10;\x1a\x04\x98\xb5\x18\x01\x12#\n\x19MessageType_EthereumTxAck\
+    \x10<\x1a\x04\x90\xb5\x18\x01\x12)\n\x1fMessageType_EthereumSignMessage\
+    \x10@\x1a\x04\x90\xb5\x18\x01\x12+\n!MessageType_EthereumVerifyMessage\
+    \x10A\x1a\x04\x90\xb5\x18\x01\x12.\n$MessageType_EthereumMessageSignatur\
+    e\x10B\x1a\x04\x98\xb5\x18\x01\x12,\n!MessageType_EthereumSignTypedData\
+    \x10\xd0\x03\x1a\x04\x90\xb5\x18\x01\x125\n*MessageType_EthereumTypedDat\
+    aStructRequest\x10\xd1\x03\x1a\x04\x98\xb5\x18\x01\x121\n&MessageType_Et\
+    hereumTypedDataStructAck\x10\xd2\x03\x1a\x04\x90\xb5\x18\x01\x124\n)Mess\
+    ageType_EthereumTypedDataValueRequest\x10\xd3\x03\x1a\x04\x98\xb5\x18\
+    \x01\x120\n%MessageType_EthereumTypedDataValueAck\x10\xd4\x03\x1a\x04\
+    \x90\xb5\x18\x01\x121\n&MessageType_EthereumTypedDataSignature\x10\xd5\
+    \x03\x1a\x04\x98\xb5\x18\x01\x12,\n!MessageType_EthereumSignTypedHash\
+    \x10\xd6\x03\x1a\x04\x90\xb5\x18\x01\x12#\n\x19MessageType_NEMGetAddress\
+    \x10C\x1a\x04\x90\xb5\x18\x01\x12\x20\n\x16MessageType_NEMAddress\x10D\
     \x1a\x04\x98\xb5\x18\x01\x12\x1f\n\x15MessageType_NEMSignTx\x10E\x1a\x04\
     \x90\xb5\x18\x01\x12!\n\x17MessageType_NEMSignedTx\x10F\x1a\x04\x98\xb5\
     \x18\x01\x12'\n\x1dMessageType_NEMDecryptMessage\x10K\x1a\x04\x90\xb5\
@@ -45,6 +45,8 @@ pub struct DebugLinkDecision {
     pub hold_ms: ::std::option::Option<u32>,
     // @@protoc_insertion_point(field:hw.trezor.messages.debug.DebugLinkDecision.physical_button)
     pub physical_button: ::std::option::Option<::protobuf::EnumOrUnknown<debug_link_decision::DebugPhysicalButton>>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.debug.DebugLinkDecision.target)
+    pub target: ::std::option::Option<::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:hw.trezor.messages.debug.DebugLinkDecision.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -239,8 +241,44 @@ impl DebugLinkDecision {
         self.physical_button = ::std::option::Option::Some(::protobuf::EnumOrUnknown::new(v));
     }
 
+    // optional string target = 9;
+
+    pub fn target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_target(&mut self) {
+        self.target = ::std::option::Option::None;
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut fields = ::std::vec::Vec::with_capacity(9);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "button",
@@ -282,6 +320,11 @@ impl DebugLinkDecision {
             |m: &DebugLinkDecision| { &m.physical_button },
             |m: &mut DebugLinkDecision| { &mut m.physical_button },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "target",
+            |m: &DebugLinkDecision| { &m.target },
+            |m: &mut DebugLinkDecision| { &mut m.target },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DebugLinkDecision>(
             "DebugLinkDecision",
             fields,
@@ -324,6 +367,9 @@ impl ::protobuf::Message for DebugLinkDecision {
                 64 => {
                     self.physical_button = ::std::option::Option::Some(is.read_enum_or_unknown()?);
                 },
+                74 => {
+                    self.target = ::std::option::Option::Some(is.read_string()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -360,6 +406,9 @@ impl ::protobuf::Message for DebugLinkDecision {
         if let Some(v) = self.physical_button {
             my_size += ::protobuf::rt::int32_size(8, v.value());
         }
+        if let Some(v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(9, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -390,6 +439,9 @@ impl ::protobuf::Message for DebugLinkDecision {
         if let Some(v) = self.physical_button {
             os.write_enum(8, ::protobuf::EnumOrUnknown::value(&v))?;
         }
+        if let Some(v) = self.target.as_ref() {
+            os.write_string(9, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -415,6 +467,7 @@ impl ::protobuf::Message for DebugLinkDecision {
         self.wait = ::std::option::Option::None;
         self.hold_ms = ::std::option::Option::None;
         self.physical_button = ::std::option::Option::None;
+        self.target = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -428,6 +481,7 @@ impl ::protobuf::Message for DebugLinkDecision {
             wait: ::std::option::Option::None,
             hold_ms: ::std::option::Option::None,
             physical_button: ::std::option::Option::None,
+            target: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -3454,10 +3508,467 @@ impl ::protobuf::reflect::ProtobufValue for DebugLinkResetDebugEvents {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:hw.trezor.messages.debug.DebugLinkShowBounds)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DebugLinkShowBounds {
+    // message fields
+    // @@protoc_insertion_point(field:hw.trezor.messages.debug.DebugLinkShowBounds.show)
+    pub show: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:hw.trezor.messages.debug.DebugLinkShowBounds.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DebugLinkShowBounds {
+    fn default() -> &'a DebugLinkShowBounds {
+        <DebugLinkShowBounds as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DebugLinkShowBounds {
+    pub fn new() -> DebugLinkShowBounds {
+        ::std::default::Default::default()
+    }
+
+    // optional bool show = 1;
+
+    pub fn show(&self) -> bool {
+        self.show.unwrap_or(false)
+    }
+
+    pub fn clear_show(&mut self) {
+        self.show = ::std::option::Option::None;
+    }
+
+    pub fn has_show(&self) -> bool {
+        self.show.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_show(&mut self, v: bool) {
+        self.show = ::std::option::Option::Some(v);
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "show",
+            |m: &DebugLinkShowBounds| { &m.show },
+            |m: &mut DebugLinkShowBounds| { &mut m.show },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DebugLinkShowBounds>(
+            "DebugLinkShowBounds",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DebugLinkShowBounds {
+    const NAME: &'static str = "DebugLinkShowBounds";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.show = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.show {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.show {
+            os.write_bool(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DebugLinkShowBounds {
+        DebugLinkShowBounds::new()
+    }
+
+    fn clear(&mut self) {
+        self.show = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DebugLinkShowBounds {
+        static instance: DebugLinkShowBounds = DebugLinkShowBounds {
+            show: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DebugLinkShowBounds {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DebugLinkShowBounds").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DebugLinkShowBounds {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DebugLinkShowBounds {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:hw.trezor.messages.debug.DebugLinkKeyboard)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DebugLinkKeyboard {
+    // message fields
+    // @@protoc_insertion_point(field:hw.trezor.messages.debug.DebugLinkKeyboard.input)
+    pub input: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:hw.trezor.messages.debug.DebugLinkKeyboard.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DebugLinkKeyboard {
+    fn default() -> &'a DebugLinkKeyboard {
+        <DebugLinkKeyboard as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DebugLinkKeyboard {
+    pub fn new() -> DebugLinkKeyboard {
+        ::std::default::Default::default()
+    }
+
+    // optional string input = 1;
+
+    pub fn input(&self) -> &str {
+        match self.input.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_input(&mut self) {
+        self.input = ::std::option::Option::None;
+    }
+
+    pub fn has_input(&self) -> bool {
+        self.input.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input(&mut self, v: ::std::string::String) {
+        self.input = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_input(&mut self) -> &mut ::std::string::String {
+        if self.input.is_none() {
+            self.input = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.input.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_input(&mut self) -> ::std::string::String {
+        self.input.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "input",
+            |m: &DebugLinkKeyboard| { &m.input },
+            |m: &mut DebugLinkKeyboard| { &mut m.input },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DebugLinkKeyboard>(
+            "DebugLinkKeyboard",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DebugLinkKeyboard {
+    const NAME: &'static str = "DebugLinkKeyboard";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.input = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.input.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.input.as_ref() {
+            os.write_string(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DebugLinkKeyboard {
+        DebugLinkKeyboard::new()
+    }
+
+    fn clear(&mut self) {
+        self.input = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DebugLinkKeyboard {
+        static instance: DebugLinkKeyboard = DebugLinkKeyboard {
+            input: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DebugLinkKeyboard {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DebugLinkKeyboard").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DebugLinkKeyboard {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DebugLinkKeyboard {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:hw.trezor.messages.debug.DebugLinkKeyboardState)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DebugLinkKeyboardState {
+    // message fields
+    // @@protoc_insertion_point(field:hw.trezor.messages.debug.DebugLinkKeyboardState.text)
+    pub text: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:hw.trezor.messages.debug.DebugLinkKeyboardState.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DebugLinkKeyboardState {
+    fn default() -> &'a DebugLinkKeyboardState {
+        <DebugLinkKeyboardState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DebugLinkKeyboardState {
+    pub fn new() -> DebugLinkKeyboardState {
+        ::std::default::Default::default()
+    }
+
+    // optional string text = 1;
+
+    pub fn text(&self) -> &str {
+        match self.text.as_ref() {
+            Some(v) => v,
+            None => "",
+        }
+    }
+
+    pub fn clear_text(&mut self) {
+        self.text = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        self.text.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = ::std::option::Option::Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        if self.text.is_none() {
+            self.text = ::std::option::Option::Some(::std::string::String::new());
+        }
+        self.text.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        self.text.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "text",
+            |m: &DebugLinkKeyboardState| { &m.text },
+            |m: &mut DebugLinkKeyboardState| { &mut m.text },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DebugLinkKeyboardState>(
+            "DebugLinkKeyboardState",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DebugLinkKeyboardState {
+    const NAME: &'static str = "DebugLinkKeyboardState";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.text = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.text.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.text.as_ref() {
+            os.write_string(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DebugLinkKeyboardState {
+        DebugLinkKeyboardState::new()
+    }
+
+    fn clear(&mut self) {
+        self.text = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DebugLinkKeyboardState {
+        static instance: DebugLinkKeyboardState = DebugLinkKeyboardState {
+            text: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DebugLinkKeyboardState {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DebugLinkKeyboardState").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DebugLinkKeyboardState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DebugLinkKeyboardState {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x14messages-debug.proto\x12\x18hw.trezor.messages.debug\x1a\x0emessag\
     es.proto\x1a\x15messages-common.proto\x1a\x19messages-management.proto\"\
-    \xb0\x04\n\x11DebugLinkDecision\x12O\n\x06button\x18\x01\x20\x01(\x0e27.\
+    \xc8\x04\n\x11DebugLinkDecision\x12O\n\x06button\x18\x01\x20\x01(\x0e27.\
     hw.trezor.messages.debug.DebugLinkDecision.DebugButtonR\x06button\x12U\n\
     \x05swipe\x18\x02\x20\x01(\x0e2?.hw.trezor.messages.debug.DebugLinkDecis\
     ion.DebugSwipeDirectionR\x05swipe\x12\x14\n\x05input\x18\x03\x20\x01(\tR\
@@ -3465,44 +3976,48 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\rR\x01y\x12\x12\n\x04wait\x18\x06\x20\x01(\x08R\x04wait\x12\
     \x17\n\x07hold_ms\x18\x07\x20\x01(\rR\x06holdMs\x12h\n\x0fphysical_butto\
     n\x18\x08\x20\x01(\x0e2?.hw.trezor.messages.debug.DebugLinkDecision.Debu\
-    gPhysicalButtonR\x0ephysicalButton\"<\n\x13DebugSwipeDirection\x12\x06\n\
-    \x02UP\x10\0\x12\x08\n\x04DOWN\x10\x01\x12\x08\n\x04LEFT\x10\x02\x12\t\n\
-    \x05RIGHT\x10\x03\"(\n\x0bDebugButton\x12\x06\n\x02NO\x10\0\x12\x07\n\
-    \x03YES\x10\x01\x12\x08\n\x04INFO\x10\x02\"B\n\x13DebugPhysicalButton\
-    \x12\x0c\n\x08LEFT_BTN\x10\0\x12\x0e\n\nMIDDLE_BTN\x10\x01\x12\r\n\tRIGH\
-    T_BTN\x10\x02\")\n\x0fDebugLinkLayout\x12\x16\n\x06tokens\x18\x01\x20\
-    \x03(\tR\x06tokens\"-\n\x15DebugLinkReseedRandom\x12\x14\n\x05value\x18\
-    \x01\x20\x01(\rR\x05value\"j\n\x15DebugLinkRecordScreen\x12)\n\x10target\
-    _directory\x18\x01\x20\x01(\tR\x0ftargetDirectory\x12&\n\rrefresh_index\
-    \x18\x02\x20\x01(\r:\x010R\x0crefreshIndex\"~\n\x11DebugLinkGetState\x12\
-    $\n\x0ewait_word_list\x18\x01\x20\x01(\x08R\x0cwaitWordList\x12\"\n\rwai\
-    t_word_pos\x18\x02\x20\x01(\x08R\x0bwaitWordPos\x12\x1f\n\x0bwait_layout\
-    \x18\x03\x20\x01(\x08R\nwaitLayout\"\x97\x04\n\x0eDebugLinkState\x12\x16\
-    \n\x06layout\x18\x01\x20\x01(\x0cR\x06layout\x12\x10\n\x03pin\x18\x02\
-    \x20\x01(\tR\x03pin\x12\x16\n\x06matrix\x18\x03\x20\x01(\tR\x06matrix\
-    \x12'\n\x0fmnemonic_secret\x18\x04\x20\x01(\x0cR\x0emnemonicSecret\x129\
-    \n\x04node\x18\x05\x20\x01(\x0b2%.hw.trezor.messages.common.HDNodeTypeR\
-    \x04node\x123\n\x15passphrase_protection\x18\x06\x20\x01(\x08R\x14passph\
-    raseProtection\x12\x1d\n\nreset_word\x18\x07\x20\x01(\tR\tresetWord\x12#\
-    \n\rreset_entropy\x18\x08\x20\x01(\x0cR\x0cresetEntropy\x12,\n\x12recove\
-    ry_fake_word\x18\t\x20\x01(\tR\x10recoveryFakeWord\x12*\n\x11recovery_wo\
-    rd_pos\x18\n\x20\x01(\rR\x0frecoveryWordPos\x12$\n\x0ereset_word_pos\x18\
-    \x0b\x20\x01(\rR\x0cresetWordPos\x12N\n\rmnemonic_type\x18\x0c\x20\x01(\
-    \x0e2).hw.trezor.messages.management.BackupTypeR\x0cmnemonicType\x12\x16\
-    \n\x06tokens\x18\r\x20\x03(\tR\x06tokens\"\x0f\n\rDebugLinkStop\"P\n\x0c\
-    DebugLinkLog\x12\x14\n\x05level\x18\x01\x20\x01(\rR\x05level\x12\x16\n\
-    \x06bucket\x18\x02\x20\x01(\tR\x06bucket\x12\x12\n\x04text\x18\x03\x20\
-    \x01(\tR\x04text\"G\n\x13DebugLinkMemoryRead\x12\x18\n\x07address\x18\
-    \x01\x20\x01(\rR\x07address\x12\x16\n\x06length\x18\x02\x20\x01(\rR\x06l\
-    ength\")\n\x0fDebugLinkMemory\x12\x16\n\x06memory\x18\x01\x20\x01(\x0cR\
-    \x06memory\"^\n\x14DebugLinkMemoryWrite\x12\x18\n\x07address\x18\x01\x20\
-    \x01(\rR\x07address\x12\x16\n\x06memory\x18\x02\x20\x01(\x0cR\x06memory\
-    \x12\x14\n\x05flash\x18\x03\x20\x01(\x08R\x05flash\"-\n\x13DebugLinkFlas\
-    hErase\x12\x16\n\x06sector\x18\x01\x20\x01(\rR\x06sector\".\n\x14DebugLi\
-    nkEraseSdCard\x12\x16\n\x06format\x18\x01\x20\x01(\x08R\x06format\",\n\
-    \x14DebugLinkWatchLayout\x12\x14\n\x05watch\x18\x01\x20\x01(\x08R\x05wat\
-    ch\"\x1b\n\x19DebugLinkResetDebugEventsB=\n#com.satoshilabs.trezor.lib.p\
-    rotobufB\x12TrezorMessageDebug\x80\xa6\x1d\x01\
+    gPhysicalButtonR\x0ephysicalButton\x12\x16\n\x06target\x18\t\x20\x01(\tR\
+    \x06target\"<\n\x13DebugSwipeDirection\x12\x06\n\x02UP\x10\0\x12\x08\n\
+    \x04DOWN\x10\x01\x12\x08\n\x04LEFT\x10\x02\x12\t\n\x05RIGHT\x10\x03\"(\n\
+    \x0bDebugButton\x12\x06\n\x02NO\x10\0\x12\x07\n\x03YES\x10\x01\x12\x08\n\
+    \x04INFO\x10\x02\"B\n\x13DebugPhysicalButton\x12\x0c\n\x08LEFT_BTN\x10\0\
+    \x12\x0e\n\nMIDDLE_BTN\x10\x01\x12\r\n\tRIGHT_BTN\x10\x02\")\n\x0fDebugL\
+    inkLayout\x12\x16\n\x06tokens\x18\x01\x20\x03(\tR\x06tokens\"-\n\x15Debu\
+    gLinkReseedRandom\x12\x14\n\x05value\x18\x01\x20\x01(\rR\x05value\"j\n\
+    \x15DebugLinkRecordScreen\x12)\n\x10target_directory\x18\x01\x20\x01(\tR\
+    \x0ftargetDirectory\x12&\n\rrefresh_index\x18\x02\x20\x01(\r:\x010R\x0cr\
+    efreshIndex\"~\n\x11DebugLinkGetState\x12$\n\x0ewait_word_list\x18\x01\
+    \x20\x01(\x08R\x0cwaitWordList\x12\"\n\rwait_word_pos\x18\x02\x20\x01(\
+    \x08R\x0bwaitWordPos\x12\x1f\n\x0bwait_layout\x18\x03\x20\x01(\x08R\nwai\
+    tLayout\"\x97\x04\n\x0eDebugLinkState\x12\x16\n\x06layout\x18\x01\x20\
+    \x01(\x0cR\x06layout\x12\x10\n\x03pin\x18\x02\x20\x01(\tR\x03pin\x12\x16\
+    \n\x06matrix\x18\x03\x20\x01(\tR\x06matrix\x12'\n\x0fmnemonic_secret\x18\
+    \x04\x20\x01(\x0cR\x0emnemonicSecret\x129\n\x04node\x18\x05\x20\x01(\x0b\
+    2%.hw.trezor.messages.common.HDNodeTypeR\x04node\x123\n\x15passphrase_pr\
+    otection\x18\x06\x20\x01(\x08R\x14passphraseProtection\x12\x1d\n\nreset_\
+    word\x18\x07\x20\x01(\tR\tresetWord\x12#\n\rreset_entropy\x18\x08\x20\
+    \x01(\x0cR\x0cresetEntropy\x12,\n\x12recovery_fake_word\x18\t\x20\x01(\t\
+    R\x10recoveryFakeWord\x12*\n\x11recovery_word_pos\x18\n\x20\x01(\rR\x0fr\
+    ecoveryWordPos\x12$\n\x0ereset_word_pos\x18\x0b\x20\x01(\rR\x0cresetWord\
+    Pos\x12N\n\rmnemonic_type\x18\x0c\x20\x01(\x0e2).hw.trezor.messages.mana\
+    gement.BackupTypeR\x0cmnemonicType\x12\x16\n\x06tokens\x18\r\x20\x03(\tR\
+    \x06tokens\"\x0f\n\rDebugLinkStop\"P\n\x0cDebugLinkLog\x12\x14\n\x05leve\
+    l\x18\x01\x20\x01(\rR\x05level\x12\x16\n\x06bucket\x18\x02\x20\x01(\tR\
+    \x06bucket\x12\x12\n\x04text\x18\x03\x20\x01(\tR\x04text\"G\n\x13DebugLi\
+    nkMemoryRead\x12\x18\n\x07address\x18\x01\x20\x01(\rR\x07address\x12\x16\
+    \n\x06length\x18\x02\x20\x01(\rR\x06length\")\n\x0fDebugLinkMemory\x12\
+    \x16\n\x06memory\x18\x01\x20\x01(\x0cR\x06memory\"^\n\x14DebugLinkMemory\
+    Write\x12\x18\n\x07address\x18\x01\x20\x01(\rR\x07address\x12\x16\n\x06m\
+    emory\x18\x02\x20\x01(\x0cR\x06memory\x12\x14\n\x05flash\x18\x03\x20\x01\
+    (\x08R\x05flash\"-\n\x13DebugLinkFlashErase\x12\x16\n\x06sector\x18\x01\
+    \x20\x01(\rR\x06sector\".\n\x14DebugLinkEraseSdCard\x12\x16\n\x06format\
+    \x18\x01\x20\x01(\x08R\x06format\",\n\x14DebugLinkWatchLayout\x12\x14\n\
+    \x05watch\x18\x01\x20\x01(\x08R\x05watch\"\x1b\n\x19DebugLinkResetDebugE\
+    vents\")\n\x13DebugLinkShowBounds\x12\x12\n\x04show\x18\x01\x20\x01(\x08\
+    R\x04show\")\n\x11DebugLinkKeyboard\x12\x14\n\x05input\x18\x01\x20\x01(\
+    \tR\x05input\",\n\x16DebugLinkKeyboardState\x12\x12\n\x04text\x18\x01\
+    \x20\x01(\tR\x04textB=\n#com.satoshilabs.trezor.lib.protobufB\x12TrezorM\
+    essageDebug\x80\xa6\x1d\x01\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -3523,7 +4038,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             deps.push(super::messages::file_descriptor().clone());
             deps.push(super::messages_common::file_descriptor().clone());
             deps.push(super::messages_management::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(15);
+            let mut messages = ::std::vec::Vec::with_capacity(18);
             messages.push(DebugLinkDecision::generated_message_descriptor_data());
             messages.push(DebugLinkLayout::generated_message_descriptor_data());
             messages.push(DebugLinkReseedRandom::generated_message_descriptor_data());
@@ -3539,6 +4054,9 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(DebugLinkEraseSdCard::generated_message_descriptor_data());
             messages.push(DebugLinkWatchLayout::generated_message_descriptor_data());
             messages.push(DebugLinkResetDebugEvents::generated_message_descriptor_data());
+            messages.push(DebugLinkShowBounds::generated_message_descriptor_data());
+            messages.push(DebugLinkKeyboard::generated_message_descriptor_data());
+            messages.push(DebugLinkKeyboardState::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(3);
             enums.push(debug_link_decision::DebugSwipeDirection::generated_enum_descriptor_data());
             enums.push(debug_link_decision::DebugButton::generated_enum_descriptor_data());
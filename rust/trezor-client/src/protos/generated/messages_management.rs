@@ -3224,6 +3224,20 @@ pub struct ApplySettings {
     pub hide_passphrase_from_host: ::std::option::Option<bool>,
     // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.haptic_feedback)
     pub haptic_feedback: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.led_signals)
+    pub led_signals: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.high_contrast)
+    pub high_contrast: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.keyboard_erase_hold_ms)
+    pub keyboard_erase_hold_ms: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.keyboard_multi_tap_timeout_ms)
+    pub keyboard_multi_tap_timeout_ms: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.large_text)
+    pub large_text: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.keyboard_last_char_reveal_ms)
+    pub keyboard_last_char_reveal_ms: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:hw.trezor.messages.management.ApplySettings.keyboard_key_debounce_ms)
+    pub keyboard_key_debounce_ms: ::std::option::Option<u32>,
     // special fields
     // @@protoc_insertion_point(special_field:hw.trezor.messages.management.ApplySettings.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -3522,8 +3536,141 @@ impl ApplySettings {
         self.haptic_feedback = ::std::option::Option::Some(v);
     }
 
+    // optional bool led_signals = 14;
+
+    pub fn led_signals(&self) -> bool {
+        self.led_signals.unwrap_or(false)
+    }
+
+    pub fn clear_led_signals(&mut self) {
+        self.led_signals = ::std::option::Option::None;
+    }
+
+    pub fn has_led_signals(&self) -> bool {
+        self.led_signals.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_led_signals(&mut self, v: bool) {
+        self.led_signals = ::std::option::Option::Some(v);
+    }
+
+    // optional bool high_contrast = 15;
+
+    pub fn high_contrast(&self) -> bool {
+        self.high_contrast.unwrap_or(false)
+    }
+
+    pub fn clear_high_contrast(&mut self) {
+        self.high_contrast = ::std::option::Option::None;
+    }
+
+    pub fn has_high_contrast(&self) -> bool {
+        self.high_contrast.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_high_contrast(&mut self, v: bool) {
+        self.high_contrast = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 keyboard_erase_hold_ms = 16;
+
+    pub fn keyboard_erase_hold_ms(&self) -> u32 {
+        self.keyboard_erase_hold_ms.unwrap_or(0)
+    }
+
+    pub fn clear_keyboard_erase_hold_ms(&mut self) {
+        self.keyboard_erase_hold_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_keyboard_erase_hold_ms(&self) -> bool {
+        self.keyboard_erase_hold_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keyboard_erase_hold_ms(&mut self, v: u32) {
+        self.keyboard_erase_hold_ms = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 keyboard_multi_tap_timeout_ms = 17;
+
+    pub fn keyboard_multi_tap_timeout_ms(&self) -> u32 {
+        self.keyboard_multi_tap_timeout_ms.unwrap_or(0)
+    }
+
+    pub fn clear_keyboard_multi_tap_timeout_ms(&mut self) {
+        self.keyboard_multi_tap_timeout_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_keyboard_multi_tap_timeout_ms(&self) -> bool {
+        self.keyboard_multi_tap_timeout_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keyboard_multi_tap_timeout_ms(&mut self, v: u32) {
+        self.keyboard_multi_tap_timeout_ms = ::std::option::Option::Some(v);
+    }
+
+    // optional bool large_text = 18;
+
+    pub fn large_text(&self) -> bool {
+        self.large_text.unwrap_or(false)
+    }
+
+    pub fn clear_large_text(&mut self) {
+        self.large_text = ::std::option::Option::None;
+    }
+
+    pub fn has_large_text(&self) -> bool {
+        self.large_text.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_large_text(&mut self, v: bool) {
+        self.large_text = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 keyboard_last_char_reveal_ms = 19;
+
+    pub fn keyboard_last_char_reveal_ms(&self) -> u32 {
+        self.keyboard_last_char_reveal_ms.unwrap_or(0)
+    }
+
+    pub fn clear_keyboard_last_char_reveal_ms(&mut self) {
+        self.keyboard_last_char_reveal_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_keyboard_last_char_reveal_ms(&self) -> bool {
+        self.keyboard_last_char_reveal_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keyboard_last_char_reveal_ms(&mut self, v: u32) {
+        self.keyboard_last_char_reveal_ms = ::std::option::Option::Some(v);
+    }
+
+    // optional uint32 keyboard_key_debounce_ms = 20;
+
+    pub fn keyboard_key_debounce_ms(&self) -> u32 {
+        self.keyboard_key_debounce_ms.unwrap_or(0)
+    }
+
+    pub fn clear_keyboard_key_debounce_ms(&mut self) {
+        self.keyboard_key_debounce_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_keyboard_key_debounce_ms(&self) -> bool {
+        self.keyboard_key_debounce_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keyboard_key_debounce_ms(&mut self, v: u32) {
+        self.keyboard_key_debounce_ms = ::std::option::Option::Some(v);
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(12);
+        let mut fields = ::std::vec::Vec::with_capacity(19);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "language",
@@ -3585,6 +3732,41 @@ impl ApplySettings {
             |m: &ApplySettings| { &m.haptic_feedback },
             |m: &mut ApplySettings| { &mut m.haptic_feedback },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "led_signals",
+            |m: &ApplySettings| { &m.led_signals },
+            |m: &mut ApplySettings| { &mut m.led_signals },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "high_contrast",
+            |m: &ApplySettings| { &m.high_contrast },
+            |m: &mut ApplySettings| { &mut m.high_contrast },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "keyboard_erase_hold_ms",
+            |m: &ApplySettings| { &m.keyboard_erase_hold_ms },
+            |m: &mut ApplySettings| { &mut m.keyboard_erase_hold_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "keyboard_multi_tap_timeout_ms",
+            |m: &ApplySettings| { &m.keyboard_multi_tap_timeout_ms },
+            |m: &mut ApplySettings| { &mut m.keyboard_multi_tap_timeout_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "large_text",
+            |m: &ApplySettings| { &m.large_text },
+            |m: &mut ApplySettings| { &mut m.large_text },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "keyboard_last_char_reveal_ms",
+            |m: &ApplySettings| { &m.keyboard_last_char_reveal_ms },
+            |m: &mut ApplySettings| { &mut m.keyboard_last_char_reveal_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "keyboard_key_debounce_ms",
+            |m: &ApplySettings| { &m.keyboard_key_debounce_ms },
+            |m: &mut ApplySettings| { &mut m.keyboard_key_debounce_ms },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ApplySettings>(
             "ApplySettings",
             fields,
@@ -3639,6 +3821,27 @@ impl ::protobuf::Message for ApplySettings {
                 104 => {
                     self.haptic_feedback = ::std::option::Option::Some(is.read_bool()?);
                 },
+                112 => {
+                    self.led_signals = ::std::option::Option::Some(is.read_bool()?);
+                },
+                120 => {
+                    self.high_contrast = ::std::option::Option::Some(is.read_bool()?);
+                },
+                128 => {
+                    self.keyboard_erase_hold_ms = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                136 => {
+                    self.keyboard_multi_tap_timeout_ms = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                144 => {
+                    self.large_text = ::std::option::Option::Some(is.read_bool()?);
+                },
+                152 => {
+                    self.keyboard_last_char_reveal_ms = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                160 => {
+                    self.keyboard_key_debounce_ms = ::std::option::Option::Some(is.read_uint32()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -3687,6 +3890,27 @@ impl ::protobuf::Message for ApplySettings {
         if let Some(v) = self.haptic_feedback {
             my_size += 1 + 1;
         }
+        if let Some(v) = self.led_signals {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.high_contrast {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.keyboard_erase_hold_ms {
+            my_size += ::protobuf::rt::uint32_size(16, v);
+        }
+        if let Some(v) = self.keyboard_multi_tap_timeout_ms {
+            my_size += ::protobuf::rt::uint32_size(17, v);
+        }
+        if let Some(v) = self.large_text {
+            my_size += 2 + 1;
+        }
+        if let Some(v) = self.keyboard_last_char_reveal_ms {
+            my_size += ::protobuf::rt::uint32_size(19, v);
+        }
+        if let Some(v) = self.keyboard_key_debounce_ms {
+            my_size += ::protobuf::rt::uint32_size(20, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -3729,6 +3953,27 @@ impl ::protobuf::Message for ApplySettings {
         if let Some(v) = self.haptic_feedback {
             os.write_bool(13, v)?;
         }
+        if let Some(v) = self.led_signals {
+            os.write_bool(14, v)?;
+        }
+        if let Some(v) = self.high_contrast {
+            os.write_bool(15, v)?;
+        }
+        if let Some(v) = self.keyboard_erase_hold_ms {
+            os.write_uint32(16, v)?;
+        }
+        if let Some(v) = self.keyboard_multi_tap_timeout_ms {
+            os.write_uint32(17, v)?;
+        }
+        if let Some(v) = self.large_text {
+            os.write_bool(18, v)?;
+        }
+        if let Some(v) = self.keyboard_last_char_reveal_ms {
+            os.write_uint32(19, v)?;
+        }
+        if let Some(v) = self.keyboard_key_debounce_ms {
+            os.write_uint32(20, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3758,6 +4003,13 @@ impl ::protobuf::Message for ApplySettings {
         self.experimental_features = ::std::option::Option::None;
         self.hide_passphrase_from_host = ::std::option::Option::None;
         self.haptic_feedback = ::std::option::Option::None;
+        self.led_signals = ::std::option::Option::None;
+        self.high_contrast = ::std::option::Option::None;
+        self.keyboard_erase_hold_ms = ::std::option::Option::None;
+        self.keyboard_multi_tap_timeout_ms = ::std::option::Option::None;
+        self.large_text = ::std::option::Option::None;
+        self.keyboard_last_char_reveal_ms = ::std::option::Option::None;
+        self.keyboard_key_debounce_ms = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
@@ -3775,6 +4027,13 @@ impl ::protobuf::Message for ApplySettings {
             experimental_features: ::std::option::Option::None,
             hide_passphrase_from_host: ::std::option::Option::None,
             haptic_feedback: ::std::option::Option::None,
+            led_signals: ::std::option::Option::None,
+            high_contrast: ::std::option::Option::None,
+            keyboard_erase_hold_ms: ::std::option::Option::None,
+            keyboard_multi_tap_timeout_ms: ::std::option::Option::None,
+            large_text: ::std::option::Option::None,
+            keyboard_last_char_reveal_ms: ::std::option::Option::None,
+            keyboard_key_debounce_ms: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -11158,7 +11417,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x1d\x01\x12\x1f\n\x15Capability_Brightness\x10\x14\x1a\x04\x80\xa6\x1d\
     \x01\x12\x1b\n\x11Capability_Haptic\x10\x15\x1a\x04\x80\xa6\x1d\x01\x1a\
     \x04\xc8\xf3\x18\x01\"\x0c\n\nLockDevice\"&\n\x07SetBusy\x12\x1b\n\texpi\
-    ry_ms\x18\x01\x20\x01(\rR\x08expiryMs\"\x0c\n\nEndSession\"\xc4\x04\n\rA\
+    ry_ms\x18\x01\x20\x01(\rR\x08expiryMs\"\x0c\n\nEndSession\"\x99\x07\n\rA\
     pplySettings\x12\x1e\n\x08language\x18\x01\x20\x01(\tR\x08languageB\x02\
     \x18\x01\x12\x14\n\x05label\x18\x02\x20\x01(\tR\x05label\x12%\n\x0euse_p\
     assphrase\x18\x03\x20\x01(\x08R\rusePassphrase\x12\x1e\n\nhomescreen\x18\
@@ -11171,13 +11430,20 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x0csafetyChecks\x123\n\x15experimental_features\x18\n\x20\x01(\x08R\x14\
     experimentalFeatures\x129\n\x19hide_passphrase_from_host\x18\x0b\x20\x01\
     (\x08R\x16hidePassphraseFromHost\x12'\n\x0fhaptic_feedback\x18\r\x20\x01\
-    (\x08R\x0ehapticFeedback\"T\n\x0eChangeLanguage\x12\x1f\n\x0bdata_length\
-    \x18\x01\x20\x02(\rR\ndataLength\x12!\n\x0cshow_display\x18\x02\x20\x01(\
-    \x08R\x0bshowDisplay\"Z\n\x16TranslationDataRequest\x12\x1f\n\x0bdata_le\
-    ngth\x18\x01\x20\x02(\rR\ndataLength\x12\x1f\n\x0bdata_offset\x18\x02\
-    \x20\x02(\rR\ndataOffset\"3\n\x12TranslationDataAck\x12\x1d\n\ndata_chun\
-    k\x18\x01\x20\x02(\x0cR\tdataChunk\"\"\n\nApplyFlags\x12\x14\n\x05flags\
-    \x18\x01\x20\x02(\rR\x05flags\"#\n\tChangePin\x12\x16\n\x06remove\x18\
+    (\x08R\x0ehapticFeedback\x12\x1f\n\x0bled_signals\x18\x0e\x20\x01(\x08R\
+    \nledSignals\x12#\n\rhigh_contrast\x18\x0f\x20\x01(\x08R\x0chighContrast\
+    \x123\n\x16keyboard_erase_hold_ms\x18\x10\x20\x01(\rR\x13keyboardEraseHo\
+    ldMs\x12@\n\x1dkeyboard_multi_tap_timeout_ms\x18\x11\x20\x01(\rR\x19keyb\
+    oardMultiTapTimeoutMs\x12\x1d\n\nlarge_text\x18\x12\x20\x01(\x08R\tlarge\
+    Text\x12>\n\x1ckeyboard_last_char_reveal_ms\x18\x13\x20\x01(\rR\x18keybo\
+    ardLastCharRevealMs\x127\n\x18keyboard_key_debounce_ms\x18\x14\x20\x01(\
+    \rR\x15keyboardKeyDebounceMs\"T\n\x0eChangeLanguage\x12\x1f\n\x0bdata_le\
+    ngth\x18\x01\x20\x02(\rR\ndataLength\x12!\n\x0cshow_display\x18\x02\x20\
+    \x01(\x08R\x0bshowDisplay\"Z\n\x16TranslationDataRequest\x12\x1f\n\x0bda\
+    ta_length\x18\x01\x20\x02(\rR\ndataLength\x12\x1f\n\x0bdata_offset\x18\
+    \x02\x20\x02(\rR\ndataOffset\"3\n\x12TranslationDataAck\x12\x1d\n\ndata_\
+    chunk\x18\x01\x20\x02(\x0cR\tdataChunk\"\"\n\nApplyFlags\x12\x14\n\x05fl\
+    ags\x18\x01\x20\x02(\rR\x05flags\"#\n\tChangePin\x12\x16\n\x06remove\x18\
     \x01\x20\x01(\x08R\x06remove\"(\n\x0eChangeWipeCode\x12\x16\n\x06remove\
     \x18\x01\x20\x01(\x08R\x06remove\"\xaa\x01\n\tSdProtect\x12]\n\toperatio\
     n\x18\x01\x20\x02(\x0e2?.hw.trezor.messages.management.SdProtect.SdProte\
@@ -50,6 +50,23 @@ def reset_plus(model_internal_name: str) -> Coords:
         return RESET_PLUS
 
 
+def confirm_word(model_internal_name: str) -> Coords:
+    # T3T1 recovery keyboard is below a header
+    CONFIRM_WORD_T3T1 = (MID, grid(DISPLAY_HEIGHT, 6, 1))
+    if model_internal_name == "T3T1":
+        return CONFIRM_WORD_T3T1
+    else:
+        return CONFIRM_WORD
+
+
+def recovery_delete(model_internal_name: str) -> Coords:
+    RECOVERY_DELETE_T3T1 = (LEFT, grid(DISPLAY_HEIGHT, 6, 1))
+    if model_internal_name == "T3T1":
+        return RECOVERY_DELETE_T3T1
+    else:
+        return RECOVERY_DELETE
+
+
 RESET_WORD_CHECK = [
     (MID, grid(DISPLAY_HEIGHT, 5, 2)),
     (MID, grid(DISPLAY_HEIGHT, 5, 3)),
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import time
 from enum import Enum
 from typing import TYPE_CHECKING
 
@@ -26,6 +27,10 @@ class CommonPass:
     EMPTY_ADDRESS = "mvbu1Gdy8SUjTenqerxUaZyYjmveZvt33q"
 
 
+# Second tap of erase within this many seconds clears the whole input (T3T1)
+ERASE_DOUBLE_TAP = 0.3
+
+
 class PassphraseCategory(Enum):
     MENU = "MENU"
     DIGITS = "123"
@@ -34,6 +39,13 @@ class PassphraseCategory(Enum):
     SPECIAL = "#$!"
 
 
+def click_erase(debug: "DebugLink", coords: buttons.Coords) -> None:
+    """Tap erase once, not fast enough for the next tap to clear everything"""
+    debug.click(coords, wait=True)
+    if debug.model in (models.T3T1,):
+        time.sleep(ERASE_DOUBLE_TAP + 0.05)
+
+
 def get_char_category(char: str) -> PassphraseCategory:
     """What is the category of a character"""
     if char.isdigit():
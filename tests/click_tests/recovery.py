@@ -4,7 +4,7 @@ from trezorlib import models
 
 from .. import buttons
 from .. import translations as TR
-from .common import get_possible_btn_texts, go_next
+from .common import click_erase, get_possible_btn_texts, go_next
 
 if TYPE_CHECKING:
     from trezorlib.debuglink import DebugLink, LayoutContent
@@ -24,8 +24,8 @@ def enter_word(
             debug.click(coords)
         if debug.model is models.T3T1 and not is_slip39 and len(word) > 4:
             # T3T1 (mercury) BIP39 keyboard allows to "confirm" only if the word is fully written, you need to click the word to auto-complete
-            debug.click(buttons.CONFIRM_WORD, wait=True)
-        return debug.click(buttons.CONFIRM_WORD, wait=True)
+            debug.click(buttons.confirm_word(debug.model.internal_name), wait=True)
+        return debug.click(buttons.confirm_word(debug.model.internal_name), wait=True)
     elif debug.model in (models.T2B1,):
         letter_index = 0
         layout = debug.read_layout()
@@ -152,7 +152,15 @@ def enter_share(
 
     assert "MnemonicKeyboard" in layout.all_components()
 
-    for word in share.split(" "):
+    words = share.split(" ")
+    if is_first:
+        progress_template = "recovery__word_x_of_y_template"
+    else:
+        progress_template = "recovery__share_word_x_of_y_template"
+    for index, word in enumerate(words):
+        if debug.model in (models.T3T1,):
+            assert layout.mnemonic_progress() == (index, len(words))
+            TR.assert_template(layout.subtitle(), progress_template)
         layout = enter_word(debug, word, is_slip39=True)
 
     return layout
@@ -225,7 +233,9 @@ def enter_seed_previous_correct(
             if debug.model in (models.T2T1, models.T3T1):
                 debug.swipe_right(wait=True)
                 for _ in range(len(bad_word)):
-                    debug.click(buttons.RECOVERY_DELETE, wait=True)
+                    click_erase(
+                        debug, buttons.recovery_delete(debug.model.internal_name)
+                    )
             elif debug.model in (models.T2B1,):
                 layout = debug.read_layout()
 
@@ -24,7 +24,12 @@ from trezorlib import exceptions
 
 from .. import buttons
 from ..common import get_test_address
-from .common import CommonPass, PassphraseCategory, get_char_category
+from .common import (
+    CommonPass,
+    PassphraseCategory,
+    click_erase,
+    get_char_category,
+)
 
 if TYPE_CHECKING:
     from trezorlib.debuglink import DebugLink
@@ -172,10 +177,16 @@ def enter_passphrase(debug: "DebugLink") -> None:
     debug.click(coords, wait=True)
 
 
+def trim_spaces(debug: "DebugLink") -> None:
+    """Trims the spaces named by the warning"""
+    coords = buttons.grid35(0, 0)  # top-left corner
+    debug.click(coords, wait=True)
+
+
 def delete_char(debug: "DebugLink") -> None:
     """Deletes the last char"""
     coords = buttons.pin_passphrase_grid(9)
-    debug.click(coords, wait=True)
+    click_erase(debug, coords)
 
 
 VECTORS = (  # passphrase, address
@@ -218,6 +229,25 @@ def test_passphrase_delete(device_handler: "BackgroundDeviceHandler"):
         enter_passphrase(debug)
 
 
+@pytest.mark.setup_client(passphrase=True)
+def test_passphrase_erase_double_tap(device_handler: "BackgroundDeviceHandler"):
+    with prepare_passphrase_dialogue(device_handler, CommonPass.EMPTY_ADDRESS) as debug:
+        input_passphrase(debug, "trezor")
+
+        # Taps far enough apart delete a character each
+        delete_char(debug)
+        delete_char(debug)
+        assert debug.read_layout().passphrase() == "trez"
+
+        # A quick second tap clears the rest
+        coords = buttons.pin_passphrase_grid(9)
+        debug.click(coords)
+        layout = debug.click(coords, wait=True)
+        assert layout.passphrase() == ""
+
+        enter_passphrase(debug)
+
+
 @pytest.mark.setup_client(passphrase=True)
 def test_passphrase_delete_all(
     device_handler: "BackgroundDeviceHandler",
@@ -296,6 +326,60 @@ def test_passphrase_long_spaces_deletion(
         for _ in range(12):
             delete_char(debug)
 
+        # Ends with spaces, the first confirm only shows the warning
+        enter_passphrase(debug)
+        assert debug.read_layout().passphrase_space_warning() == "trailing"
+        enter_passphrase(debug)
+
+
+@pytest.mark.parametrize(
+    "passphrase, warning",
+    (
+        (" abc 123", "leading"),
+        ("abc 123 ", "trailing"),
+        ("abc  123", "repeated"),
+    ),
+)
+@pytest.mark.setup_client(passphrase=True)
+def test_passphrase_space_warning_trim(
+    device_handler: "BackgroundDeviceHandler", passphrase: str, warning: str
+):
+    with prepare_passphrase_dialogue(
+        device_handler, CommonPass.WITH_SPACE_ADDRESS
+    ) as debug:
+        input_passphrase(debug, passphrase)
+
+        enter_passphrase(debug)
+        layout = debug.read_layout()
+        assert layout.main_component() == "PassphraseKeyboard"
+        assert layout.passphrase_space_warning() == warning
+
+        trim_spaces(debug)
+        layout = debug.read_layout()
+        assert layout.passphrase() == CommonPass.WITH_SPACE
+        assert layout.passphrase_space_warning() is None
+
+        enter_passphrase(debug)
+
+
+@pytest.mark.setup_client(passphrase=True)
+def test_passphrase_space_warning_confirm_again(
+    device_handler: "BackgroundDeviceHandler",
+):
+    passphrase = CommonPass.WITH_SPACE + " "
+    with prepare_passphrase_dialogue(device_handler) as debug:
+        input_passphrase(debug, passphrase)
+
+        enter_passphrase(debug)
+        assert debug.read_layout().passphrase_space_warning() == "trailing"
+
+        # Editing dismisses the warning
+        delete_char(debug)
+        input_passphrase(debug, " ")
+        assert debug.read_layout().passphrase_space_warning() is None
+
+        enter_passphrase(debug)
+        assert debug.read_layout().passphrase_space_warning() == "trailing"
         enter_passphrase(debug)
 
 
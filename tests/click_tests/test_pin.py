@@ -25,6 +25,7 @@ from trezorlib import device, exceptions, models
 from .. import buttons
 from .. import translations as TR
 from .common import (
+    click_erase,
     get_possible_btn_texts,
     go_back,
     go_next,
@@ -186,7 +187,7 @@ def _delete_pin(debug: "DebugLink", digits_to_delete: int, check: bool = True) -
 
     for _ in range(digits_to_delete):
         if debug.model in (models.T2T1, models.T3T1):
-            debug.click(buttons.pin_passphrase_grid(9), wait=True)
+            click_erase(debug, buttons.pin_passphrase_grid(9))
         elif debug.model in (models.T2B1,):
             navigate_to_action_and_press(debug, DELETE, TR_PIN_ACTIONS)
 
@@ -250,6 +251,17 @@ def test_pin_long(device_handler: "BackgroundDeviceHandler"):
         _input_see_confirm(debug, PIN24)
 
 
+@pytest.mark.parametrize("length", (1, 10, 25))
+@pytest.mark.setup_client(pin=PIN4)
+def test_pin_dots_length(device_handler: "BackgroundDeviceHandler", length: int):
+    with prepare(device_handler) as debug:
+        _input_pin(debug, PIN50[:length])
+        _see_pin(debug)
+
+        _delete_all(debug)
+        _input_see_confirm(debug, PIN4)
+
+
 @pytest.mark.setup_client(pin=PIN4)
 def test_pin_empty_cannot_send(device_handler: "BackgroundDeviceHandler"):
     with prepare(device_handler) as debug:
@@ -257,6 +269,55 @@ def test_pin_empty_cannot_send(device_handler: "BackgroundDeviceHandler"):
         _input_see_confirm(debug, PIN4)
 
 
+@pytest.mark.skip_t2b1("no touch buttons to trace on T2B1")
+@pytest.mark.setup_client(pin=PIN4)
+def test_pin_empty_confirm_disabled(device_handler: "BackgroundDeviceHandler"):
+    with prepare(device_handler) as debug:
+        layout = debug.read_layout()
+        assert layout.keyboard_button("confirm")["enabled"] is False
+        assert layout.keyboard_button("erase")["visible"] is False
+
+        _input_pin(debug, PIN4[:1])
+        layout = debug.read_layout()
+        assert layout.keyboard_button("confirm")["enabled"] is True
+        assert layout.keyboard_button("erase")["visible"] is True
+
+        _input_see_confirm(debug, PIN4[1:])
+
+
+@pytest.mark.skip_t2b1("keyboard input through debuglink only on touchscreens")
+@pytest.mark.setup_client(pin=PIN4)
+def test_pin_debuglink_keyboard(device_handler: "BackgroundDeviceHandler"):
+    with prepare(device_handler) as debug:
+        assert debug.keyboard() == ""
+
+        assert debug.keyboard(PIN4[:1]) == PIN4[:1]
+        layout = debug.read_layout()
+        assert layout.keyboard_button("confirm")["enabled"] is True
+        assert layout.keyboard_button("erase")["visible"] is True
+
+        assert debug.keyboard(PIN4[1:]) == PIN4
+        _see_pin(debug)
+        _confirm_pin(debug)
+
+
+@pytest.mark.skip_t2t1("debug targets registered only on T3T1")
+@pytest.mark.skip_t2b1("debug targets registered only on T3T1")
+@pytest.mark.setup_client(pin=PIN4)
+def test_pin_click_targets(device_handler: "BackgroundDeviceHandler"):
+    with prepare(device_handler) as debug:
+        targets = debug.read_layout().debug_targets()
+        assert "confirm" in targets
+        assert "erase" in targets
+
+        for digit in PIN4:
+            debug.click_target(f"pin/digit/{digit}", wait=True)
+        assert debug.read_layout().pin() == PIN4
+
+        _see_pin(debug)
+        debug.click_target("confirm", wait=True)
+
+
 @pytest.mark.setup_client(pin=PIN24)
 def test_pin_long_delete(device_handler: "BackgroundDeviceHandler"):
     with prepare(device_handler) as debug:
@@ -269,6 +330,22 @@ def test_pin_long_delete(device_handler: "BackgroundDeviceHandler"):
         _input_see_confirm(debug, PIN24[-10:])
 
 
+@pytest.mark.skip_t2t1("double tap of erase only on T3T1")
+@pytest.mark.skip_t2b1("double tap of erase only on T3T1")
+@pytest.mark.setup_client(pin=PIN4)
+def test_pin_erase_double_tap(device_handler: "BackgroundDeviceHandler"):
+    with prepare(device_handler) as debug:
+        _input_pin(debug, PIN24[:6])
+        _delete_pin(debug, 1)
+
+        # A quick second tap clears the rest
+        debug.click(buttons.pin_passphrase_grid(9))
+        layout = debug.click(buttons.pin_passphrase_grid(9), wait=True)
+        assert layout.pin() == ""
+
+        _input_see_confirm(debug, PIN4)
+
+
 @pytest.mark.setup_client(pin=PIN4)
 def test_pin_delete_hold(device_handler: "BackgroundDeviceHandler"):
     with prepare(device_handler) as debug:
@@ -21,6 +21,8 @@ import pytest
 
 from trezorlib import device, messages, models
 
+from .. import buttons
+from .. import translations as TR
 from ..common import MNEMONIC12, MNEMONIC_SLIP39_BASIC_20_3of6
 from . import recovery
 
@@ -62,6 +64,38 @@ def test_recovery_slip39_basic(device_handler: "BackgroundDeviceHandler"):
         recovery.finalize(debug)
 
 
+@pytest.mark.skip_t2t1
+@pytest.mark.skip_t2b1
+@pytest.mark.setup_client(uninitialized=True)
+def test_recovery_slip39_autofill(device_handler: "BackgroundDeviceHandler"):
+    with prepare_recovery_and_evaluate(device_handler) as debug:
+        recovery.confirm_recovery(debug)
+        recovery.select_number_of_words(debug)
+
+        first_share, *other_shares = MNEMONIC_SLIP39_BASIC_20_3of6
+        words = first_share.split(" ")
+        assert words[2] == "academic"
+
+        layout = debug.swipe_up(wait=True)
+        for word in words[:2]:
+            layout = recovery.enter_word(debug, word, is_slip39=True)
+
+        # The prefix identifies the word, a single press confirms it.
+        for coords in buttons.type_word("acad", is_slip39=True):
+            layout = debug.click(coords, wait=True)
+        assert layout.find_unique_value_by_key("word", default=None) == "academic"
+        layout = debug.click(buttons.confirm_word(debug.model.internal_name), wait=True)
+        assert layout.mnemonic_progress() == (3, len(words))
+
+        for word in words[3:]:
+            recovery.enter_word(debug, word, is_slip39=True)
+        for share in other_shares:
+            recovery.enter_share(debug, share, is_first=False)
+
+        TR.assert_in(debug.read_layout().text_content(), "recovery__wallet_recovered")
+        recovery.finalize(debug)
+
+
 @pytest.mark.setup_client(uninitialized=True)
 def test_recovery_bip39(device_handler: "BackgroundDeviceHandler"):
     with prepare_recovery_and_evaluate(device_handler) as debug:
@@ -86,3 +120,27 @@ def test_recovery_bip39_previous_word(device_handler: "BackgroundDeviceHandler")
         bad_indexes = {1: seed_words[-1], 7: seed_words[0]}
         recovery.enter_seed_previous_correct(debug, seed_words, bad_indexes)
         recovery.finalize(debug)
+
+
+@pytest.mark.skip_t2t1
+@pytest.mark.skip_t2b1
+@pytest.mark.setup_client(uninitialized=True)
+def test_recovery_bip39_last_word_checksum(device_handler: "BackgroundDeviceHandler"):
+    with prepare_recovery_and_evaluate(device_handler) as debug:
+        recovery.confirm_recovery(debug)
+
+        recovery.select_number_of_words(debug, num_of_words=12)
+        seed_words: list[str] = MNEMONIC12.split()
+        recovery.prepare_enter_seed(debug)
+        for word in seed_words[:-1]:
+            recovery.enter_word(debug, word)
+
+        # "aim" and "air" come first in the wordlist, but only "aisle" gives
+        # a valid checksum with the previous words.
+        for coords in buttons.type_word("ai"):
+            debug.click(coords)
+        debug.click(buttons.confirm_word(debug.model.internal_name), wait=True)
+        debug.click(buttons.confirm_word(debug.model.internal_name), wait=True)
+
+        TR.assert_in(debug.read_layout().text_content(), "recovery__wallet_recovered")
+        recovery.finalize(debug)
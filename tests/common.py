@@ -324,6 +324,15 @@ def click_info_button_mercury(debug: "DebugLink"):
     debug.click(buttons.CORNER_BUTTON)
 
 
+def click_vertical_menu_item(debug: "DebugLink", index: int) -> None:
+    """Click Mercury menu item by its index in the whole menu, swiping to its
+    page first. Expects the menu to be on its first page."""
+    per_page = len(buttons.VERTICAL_MENU)
+    for _ in range(index // per_page):
+        debug.swipe_up(wait=True)
+    debug.click(buttons.VERTICAL_MENU[index % per_page], wait=True)
+
+
 def check_pin_backoff_time(attempts: int, start: float) -> None:
     """Helper to assert the exponentially growing delay after incorrect PIN attempts"""
     expected = (2**attempts) - 1
@@ -158,6 +158,38 @@ def test_one_one_fee(client: Client):
     )
 
 
+
+@pytest.mark.skip_t1b1
+@pytest.mark.skip_t2t1
+@pytest.mark.skip_t2b1
+@pytest.mark.parametrize("large_text", (False, True))
+def test_one_one_fee_large_text(client: Client, large_text: bool):
+    # Screenshots of the same transaction confirmation in both text sizes.
+    device.apply_settings(client, large_text=large_text)
+
+    inp1 = messages.TxInputType(
+        address_n=parse_path("m/44h/0h/5h/0/9"),  # 1H2CRJBrDMhkvCGZMW7T4oQwYbL8eVuh7p
+        amount=63_988,
+        prev_hash=TXHASH_0dac36,
+        prev_index=0,
+    )
+
+    out1 = messages.TxOutputType(
+        address="13Hbso8zgV5Wmqn3uA7h3QVtmPzs47wcJ7",
+        amount=50_248,
+        script_type=messages.OutputScriptType.PAYTOADDRESS,
+    )
+
+    _, serialized_tx = btc.sign_tx(
+        client, "Bitcoin", [inp1], [out1], prev_txes=TX_CACHE_MAINNET
+    )
+
+    assert_tx_matches(
+        serialized_tx,
+        hash_link="https://btc1.trezor.io/api/tx/b893aeed4b12227b6f5348d7f6cb84ba2cda2ba70a41933a25f363b9d2fc2cf9",
+        tx_hex="0100000001b5f59e2273c85b93aa9deff9bba5d7deace78610d3b0fb892a7ba6d86f36ac0d000000006b483045022100dd4dd136a70371bc9884c3c51fd52f4aed9ab8ee98f3ac7367bb19e6538096e702200c56be09c4359fc7eb494b4bdf8f2b72706b0575c4021373345b593e9661c7b6012103d7f3a07085bee09697cf03125d5c8760dfed65403dba787f1d1d8b1251af2cbeffffffff0148c40000000000001976a91419140511436e947448be994ab7fda9f98623e68e88ac00000000",
+    )
+
 def test_testnet_one_two_fee(client: Client):
     # input tx: e5040e1bc1ae7667ffb9e5248e90b2fb93cd9150234151ce90e14ab2f5933bcd
 
@@ -79,6 +79,85 @@ def test_apply_settings_rotation(client: Client):
     assert client.features.display_rotation == 270
 
 
+@pytest.mark.skip_t1b1
+@pytest.mark.skip_t2t1
+@pytest.mark.skip_t2b1
+def test_apply_settings_high_contrast(client: Client):
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, high_contrast=True)
+
+    # the confirmation screen of the next setting is rendered in high contrast
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, label="new label")
+
+    assert client.features.label == "new label"
+
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, high_contrast=False)
+
+
+@pytest.mark.skip_t1b1
+@pytest.mark.skip_t2t1
+@pytest.mark.skip_t2b1
+@pytest.mark.parametrize("high_contrast", (False, True))
+def test_apply_settings_high_contrast_screens(client: Client, high_contrast: bool):
+    # Screenshots of the PIN keyboard and a confirmation screen in both variants.
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, high_contrast=high_contrast)
+
+    # the PIN keyboard and the confirmation screen are created after the change
+    client.lock()
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, label="new label")
+
+    assert client.features.label == "new label"
+
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, high_contrast=False)
+
+
+@pytest.mark.skip_t1b1
+@pytest.mark.skip_t2b1
+def test_apply_settings_keyboard_timings(client: Client):
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, keyboard_erase_hold_ms=3000)
+
+    with client:
+        client.set_expected_responses(
+            [messages.ButtonRequest()] + EXPECTED_RESPONSES_NOPIN
+        )
+        device.apply_settings(
+            client, keyboard_erase_hold_ms=800, keyboard_multi_tap_timeout_ms=2000
+        )
+
+    with client:
+        client.set_expected_responses(
+            [messages.ButtonRequest()] + EXPECTED_RESPONSES_NOPIN
+        )
+        device.apply_settings(
+            client, keyboard_last_char_reveal_ms=0, keyboard_key_debounce_ms=150
+        )
+
+    with pytest.raises(exceptions.TrezorFailure, match="out of range"), client:
+        client.set_expected_responses([messages.Failure])
+        device.apply_settings(client, keyboard_erase_hold_ms=0)
+
+    with pytest.raises(exceptions.TrezorFailure, match="out of range"), client:
+        client.set_expected_responses([messages.Failure])
+        device.apply_settings(client, keyboard_last_char_reveal_ms=3001)
+
+    with pytest.raises(exceptions.TrezorFailure, match="out of range"), client:
+        client.set_expected_responses([messages.Failure])
+        device.apply_settings(client, keyboard_key_debounce_ms=301)
+
+
 @pytest.mark.setup_client(pin=PIN4, passphrase=False)
 def test_apply_settings_passphrase(client: Client):
     with client:
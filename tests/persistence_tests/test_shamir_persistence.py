@@ -140,7 +140,7 @@ def test_recovery_on_old_wallet(core_emulator: Emulator):
     # start entering first share
     assert "Enter each word of your wallet backup" in debug.read_layout().text_content()
     debug.press_yes()
-    assert debug.wait_layout().main_component() == "MnemonicKeyboard"
+    assert "MnemonicKeyboard" in debug.wait_layout().all_components()
 
     # enter first word
     debug.input(words[0])
@@ -152,7 +152,7 @@ def test_recovery_on_old_wallet(core_emulator: Emulator):
 
     # try entering remaining 19 words
     for word in words[1:]:
-        assert layout.main_component() == "MnemonicKeyboard"
+        assert "MnemonicKeyboard" in layout.all_components()
         debug.input(word)
         layout = debug.wait_layout()
 
@@ -113,8 +113,11 @@ def write_fixtures_complete(
 
 def _rename_records(screen_path: Path) -> None:
     IMAGES_DIR.mkdir(exist_ok=True)
-    # rename screenshots
-    for index, record in enumerate(sorted(screen_path.iterdir())):
+    # rename screenshots, together with the layout traces saved next to them
+    for index, record in enumerate(sorted(screen_path.glob("*.png"))):
+        trace = record.with_suffix(".json")
+        if trace.exists():
+            trace.replace(screen_path / f"{index:08}.json")
         record.replace(screen_path / f"{index:08}.png")
 
 
@@ -124,7 +127,7 @@ def screens_and_hashes(screen_path: Path) -> tuple[list[Path], list[str]]:
 
     paths: list[Path] = []
     hashes: list[str] = []
-    for file in sorted(screen_path.iterdir()):
+    for file in sorted(screen_path.glob("*.png")):
         paths.append(file)
         hashes.append(_get_image_hash(file))
     return paths, hashes
@@ -144,7 +147,7 @@ def _get_bytes_from_png(png_file: Path) -> bytes:
 
 
 def _hash_files(path: Path) -> str:
-    files = path.iterdir()
+    files = path.glob("*.png")
     hasher = hashlib.sha256()
     for file in sorted(files):
         hasher.update(_get_bytes_from_png(file))